@@ -47,6 +47,13 @@ fn bench_refresh_processes(b: &mut test::Bencher) {
     });
 }
 
+#[bench]
+fn bench_top_processes_by_cpu(b: &mut test::Bencher) {
+    let s = sysinfo::System::new_all();
+
+    b.iter(|| s.top_processes_by_cpu(10).len());
+}
+
 #[bench]
 fn bench_first_refresh_processes(b: &mut test::Bencher) {
     b.iter(move || {
@@ -96,6 +103,17 @@ fn bench_refresh_disks_list(b: &mut test::Bencher) {
     });
 }
 
+#[bench]
+fn bench_refresh_all_and_disks(b: &mut test::Bencher) {
+    let mut s = sysinfo::System::new_all();
+    let mut disks = sysinfo::Disks::new_with_refreshed_list();
+
+    b.iter(move || {
+        s.refresh_all();
+        disks.refresh();
+    });
+}
+
 #[bench]
 fn bench_refresh_networks(b: &mut test::Bencher) {
     let mut n = sysinfo::Networks::new_with_refreshed_list();
@@ -8,19 +8,69 @@
 #include "sysinfo.h"
 
 void print_process(CProcess process) {
+    RString name = sysinfo_process_name(process);
     RString exe = sysinfo_process_executable_path(process);
-    printf("process[%d]: parent: %d,\n"
+    RString user = sysinfo_process_user_name(process);
+    RString *cmd = sysinfo_process_cmd(process);
+    CDiskUsage disk_usage = sysinfo_process_disk_usage(process);
+    printf("process[%d]: name: '%s',\n"
+           "             parent: %d,\n"
            "             cpu_usage: %f,\n"
            "             memory: %ld,\n"
            "             virtual memory: %ld,\n"
-           "             executable path: '%s'\n",
+           "             disk usage: %lu read, %lu written,\n"
+           "             user: '%s',\n"
+           "             executable path: '%s'\n"
+           "             command:",
            sysinfo_process_pid(process),
+           name,
            sysinfo_process_parent_pid(process),
            sysinfo_process_cpu_usage(process),
            sysinfo_process_memory(process),
            sysinfo_process_virtual_memory(process),
+           disk_usage.total_read_bytes,
+           disk_usage.total_written_bytes,
+           user,
            exe);
+    for (RString *arg = cmd; *arg != NULL; ++arg) {
+        printf(" '%s'", *arg);
+    }
+    printf("\n");
+    sysinfo_rstrings_free(cmd);
+    sysinfo_rstring_free(user);
     sysinfo_rstring_free(exe);
+    sysinfo_rstring_free(name);
+}
+
+bool disk_loop(CDisk disk, void *data) {
+    (void)data;
+    RString name = sysinfo_disk_name(disk);
+    RString mount_point = sysinfo_disk_mount_point(disk);
+    printf("disk '%s' mounted on '%s': %lu/%lu bytes available\n",
+           name,
+           mount_point,
+           sysinfo_disk_available_space(disk),
+           sysinfo_disk_total_space(disk));
+    sysinfo_rstring_free(mount_point);
+    sysinfo_rstring_free(name);
+    return true;
+}
+
+bool network_loop(RString interface_name, CNetworkData network, void *data) {
+    (void)data;
+    printf("network '%s': %lu bytes received, %lu bytes transmitted\n",
+           interface_name,
+           sysinfo_network_total_received(network),
+           sysinfo_network_total_transmitted(network));
+    return true;
+}
+
+bool component_loop(CComponent component, void *data) {
+    (void)data;
+    RString label = sysinfo_component_label(component);
+    printf("component '%s': %f°C\n", label, sysinfo_component_temperature(component));
+    sysinfo_rstring_free(label);
+    return true;
 }
 
 void check_tasks(CSystem system) {
@@ -59,9 +109,13 @@ bool process_loop(pid_t pid, CProcess process, void *data) {
 int main() {
     CSystem system = sysinfo_init();
     CNetworks networks = sysinfo_networks_init();
+    CDisks disks = sysinfo_disks_init();
+    CComponents components = sysinfo_components_init();
 
     sysinfo_refresh_all(system);
     sysinfo_networks_refresh_list(networks);
+    sysinfo_disks_refresh_list(disks);
+    sysinfo_components_refresh_list(components);
 
     printf("os name:              %s\n", sysinfo_system_name(system));
     printf("os version:           %s\n", sysinfo_system_version(system));
@@ -89,12 +143,18 @@ int main() {
     }
     free(procs);
 
+    sysinfo_disks(disks, disk_loop, NULL);
+    sysinfo_networks(networks, network_loop, NULL);
+    sysinfo_components(components, component_loop, NULL);
+
     // processes part
     i = 0;
     printf("For a total of %ld processes.\n", sysinfo_processes(system, process_loop, &i));
     check_tasks(system);
-    // we can now free the CSystem and the CNetworks objects.
+    // we can now free the CSystem, CNetworks, CDisks and CComponents objects.
     sysinfo_destroy(system);
     sysinfo_networks_destroy(networks);
+    sysinfo_disks_destroy(disks);
+    sysinfo_components_destroy(components);
     return 0;
 }
@@ -183,11 +183,8 @@ fn interpret_input(
             writeln!(&mut io::stdout(), "Done.");
         }
         "signals" => {
-            let mut nb = 1i32;
-
-            for sig in signals {
-                writeln!(&mut io::stdout(), "{nb:2}:{sig:?}");
-                nb += 1;
+            for (nb, sig) in signals.iter().enumerate() {
+                writeln!(&mut io::stdout(), "{:2}:{sig:?}", nb + 1);
             }
         }
         "cpus" => {
@@ -0,0 +1,168 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::System;
+
+use std::any::Any;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+
+type Job = Box<dyn FnOnce() + Send>;
+type Refresh = Box<dyn FnOnce(&mut System) + Send>;
+
+// The maximum number of threads running the refreshes.
+const MAX_WORKERS: usize = 4;
+
+// Started the first time a refresh is run.
+static POOL: Mutex<Option<Sender<Job>>> = Mutex::new(None);
+
+/// Runs `job` on one of the worker threads.
+fn spawn(job: Job) {
+    let mut pool = POOL.lock().unwrap_or_else(|e| e.into_inner());
+    let sender = pool.get_or_insert_with(|| {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            .min(MAX_WORKERS);
+        for _ in 0..workers {
+            let receiver = Arc::clone(&receiver);
+            std::thread::spawn(move || loop {
+                let job = match receiver.lock() {
+                    Ok(receiver) => receiver.recv(),
+                    Err(_) => return,
+                };
+                match job {
+                    Ok(job) => job(),
+                    Err(_) => return,
+                }
+            });
+        }
+        sender
+    });
+    if let Err(mpsc::SendError(job)) = sender.send(job) {
+        // All the workers are gone (which shouldn't happen), so we run it here instead.
+        sysinfo_debug!("the refresh workers stopped");
+        job();
+    }
+}
+
+#[derive(Default)]
+struct State {
+    system: Option<System>,
+    // The payload of the panic which happened during the refresh, if any.
+    panic: Option<Box<dyn Any + Send>>,
+    waker: Option<Waker>,
+}
+
+#[derive(Default)]
+struct Shared {
+    state: Mutex<State>,
+    done: Condvar,
+}
+
+/// Future returned by the async refresh methods of [`System`], like
+/// [`System::refresh_all_async`].
+///
+/// The refresh is started the first time the future is polled. While it runs on a worker
+/// thread, the `System` is replaced with an empty one. It is put back once the refresh is done,
+/// even if it panicked, in which case the panic is resumed when polling the future.
+///
+/// ⚠️ Dropping the future while the refresh is running blocks until it is done.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RefreshFuture<'a> {
+    system: &'a mut System,
+    refresh: Option<Refresh>,
+    shared: Arc<Shared>,
+    finished: bool,
+}
+
+impl<'a> RefreshFuture<'a> {
+    pub(crate) fn new<F>(system: &'a mut System, refresh: F) -> Self
+    where
+        F: FnOnce(&mut System) + Send + 'static,
+    {
+        Self {
+            system,
+            refresh: Some(Box::new(refresh)),
+            shared: Arc::default(),
+            finished: false,
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.shared.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Future for RefreshFuture<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        if let Some(refresh) = this.refresh.take() {
+            this.state().waker = Some(cx.waker().clone());
+            let mut system = std::mem::take(this.system);
+            let shared = Arc::clone(&this.shared);
+            spawn(Box::new(move || {
+                // The `System` is put back even if the refresh panics, otherwise the future
+                // would never be ready and dropping it would block forever.
+                let result = panic::catch_unwind(AssertUnwindSafe(|| refresh(&mut system)));
+                let waker = {
+                    let mut state = shared.state.lock().unwrap_or_else(|e| e.into_inner());
+                    state.system = Some(system);
+                    state.panic = result.err();
+                    state.waker.take()
+                };
+                shared.done.notify_all();
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            }));
+            return Poll::Pending;
+        }
+        let mut state = this.state();
+        match state.system.take() {
+            Some(system) => {
+                let panic = state.panic.take();
+                drop(state);
+                *this.system = system;
+                this.finished = true;
+                if let Some(panic) = panic {
+                    panic::resume_unwind(panic);
+                }
+                Poll::Ready(())
+            }
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for RefreshFuture<'_> {
+    fn drop(&mut self) {
+        if self.refresh.is_some() || self.finished {
+            return;
+        }
+        // The `System` has to be put back once the refresh is done.
+        let mut state = self.state();
+        let system = loop {
+            if let Some(system) = state.system.take() {
+                break system;
+            }
+            state = self
+                .shared
+                .done
+                .wait(state)
+                .unwrap_or_else(|e| e.into_inner());
+        };
+        drop(state);
+        *self.system = system;
+    }
+}
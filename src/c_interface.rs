@@ -1,9 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Disks, Networks, Pid, Process, System};
+use crate::{Component, Components, Disk, Disks, NetworkData, Networks, Pid, Process, System};
 use libc::{self, c_char, c_float, c_uint, c_void, size_t};
 use std::borrow::BorrowMut;
-use std::ffi::CString;
+use std::ffi::{CString, OsStr};
 
 /// on windows, libc has not include pid_t.
 #[cfg(target_os = "windows")]
@@ -25,6 +25,60 @@ pub type ProcessLoop = extern "C" fn(pid: PID, process: CProcess, data: *mut c_v
 pub type CNetworks = *mut c_void;
 /// Equivalent of [`Disks`][crate::Disks] struct.
 pub type CDisks = *mut c_void;
+/// Equivalent of [`Disk`][crate::Disk] struct.
+pub type CDisk = *const c_void;
+/// Callback used by [`sysinfo_disks`].
+pub type DiskLoop = extern "C" fn(disk: CDisk, data: *mut c_void) -> bool;
+/// Equivalent of [`NetworkData`][crate::NetworkData] struct.
+pub type CNetworkData = *const c_void;
+/// Callback used by [`sysinfo_networks`]. `interface_name` is only valid during the call.
+pub type NetworkLoop =
+    extern "C" fn(interface_name: RString, network: CNetworkData, data: *mut c_void) -> bool;
+/// Equivalent of [`Components`][crate::Components] struct.
+pub type CComponents = *mut c_void;
+/// Equivalent of [`Component`][crate::Component] struct.
+pub type CComponent = *const c_void;
+/// Callback used by [`sysinfo_components`].
+pub type ComponentLoop = extern "C" fn(component: CComponent, data: *mut c_void) -> bool;
+
+/// Equivalent of [`DiskUsage`][crate::DiskUsage] struct.
+#[repr(C)]
+pub struct CDiskUsage {
+    /// Total number of written bytes.
+    pub total_written_bytes: u64,
+    /// Number of written bytes since the last refresh.
+    pub written_bytes: u64,
+    /// Total number of read bytes.
+    pub total_read_bytes: u64,
+    /// Number of read bytes since the last refresh.
+    pub read_bytes: u64,
+}
+
+fn to_rstring(s: &str) -> RString {
+    match CString::new(s) {
+        Ok(c) => c.into_raw() as _,
+        Err(_) => std::ptr::null(),
+    }
+}
+
+fn os_str_to_rstring(s: &OsStr) -> RString {
+    match s.to_str() {
+        Some(s) => to_rstring(s),
+        None => std::ptr::null(),
+    }
+}
+
+/// Returns an array of C strings ended by a null pointer, to be freed with
+/// [`sysinfo_rstrings_free`].
+fn to_rstrings(strings: &[String]) -> *mut RString {
+    let array = strings
+        .iter()
+        .map(|s| to_rstring(s))
+        .filter(|s| !s.is_null())
+        .chain(Some(std::ptr::null()))
+        .collect::<Box<[RString]>>();
+    Box::into_raw(array) as *mut RString
+}
 
 /// Equivalent of [`System::new()`][crate::System#method.new].
 #[no_mangle]
@@ -52,7 +106,7 @@ pub extern "C" fn sysinfo_refresh_system(system: CSystem) {
             let system: &mut System = system.borrow_mut();
             system.refresh_system();
         }
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
     }
 }
 
@@ -66,7 +120,7 @@ pub extern "C" fn sysinfo_refresh_memory(system: CSystem) {
             let system: &mut System = system.borrow_mut();
             system.refresh_memory();
         }
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
     }
 }
 
@@ -80,7 +134,7 @@ pub extern "C" fn sysinfo_refresh_cpu(system: CSystem) {
             let system: &mut System = system.borrow_mut();
             system.refresh_cpu_usage();
         }
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
     }
 }
 
@@ -94,7 +148,7 @@ pub extern "C" fn sysinfo_refresh_all(system: CSystem) {
             let system: &mut System = system.borrow_mut();
             system.refresh_all();
         }
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
     }
 }
 
@@ -108,7 +162,7 @@ pub extern "C" fn sysinfo_refresh_processes(system: CSystem) {
             let system: &mut System = system.borrow_mut();
             system.refresh_processes();
         }
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
     }
 }
 
@@ -123,7 +177,7 @@ pub extern "C" fn sysinfo_refresh_process(system: CSystem, pid: PID) {
             let system: &mut System = system.borrow_mut();
             system.refresh_process(Pid(pid));
         }
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
     }
 }
 
@@ -153,7 +207,7 @@ pub extern "C" fn sysinfo_disks_refresh(disks: CDisks) {
             let disks: &mut Disks = disks.borrow_mut();
             disks.refresh();
         }
-        Box::into_raw(disks);
+        let _ = Box::into_raw(disks);
     }
 }
 
@@ -167,10 +221,82 @@ pub extern "C" fn sysinfo_disks_refresh_list(disks: CDisks) {
             let disks: &mut Disks = disks.borrow_mut();
             disks.refresh_list();
         }
-        Box::into_raw(disks);
+        let _ = Box::into_raw(disks);
     }
 }
 
+/// Equivalent of iterating over [`Disks::list()`][crate::Disks#method.list]. Returns the number
+/// of disks.
+///
+/// # ⚠️ WARNING ⚠️
+///
+/// The `CDisk` values are only valid until the next refresh of `disks`!
+#[no_mangle]
+pub extern "C" fn sysinfo_disks(
+    disks: CDisks,
+    fn_pointer: Option<DiskLoop>,
+    data: *mut c_void,
+) -> size_t {
+    assert!(!disks.is_null());
+    let disks = unsafe { &*(disks as *const Disks) };
+    if let Some(fn_pointer) = fn_pointer {
+        for disk in disks.list() {
+            if !fn_pointer(disk as *const Disk as CDisk, data) {
+                break;
+            }
+        }
+    }
+    disks.list().len() as size_t
+}
+
+/// Equivalent of [`Disk::name()`][crate::Disk#method.name].
+#[no_mangle]
+pub extern "C" fn sysinfo_disk_name(disk: CDisk) -> RString {
+    assert!(!disk.is_null());
+    let disk = disk as *const Disk;
+    unsafe { os_str_to_rstring((*disk).name()) }
+}
+
+/// Equivalent of [`Disk::file_system()`][crate::Disk#method.file_system].
+#[no_mangle]
+pub extern "C" fn sysinfo_disk_file_system(disk: CDisk) -> RString {
+    assert!(!disk.is_null());
+    let disk = disk as *const Disk;
+    unsafe { os_str_to_rstring((*disk).file_system()) }
+}
+
+/// Equivalent of [`Disk::mount_point()`][crate::Disk#method.mount_point].
+#[no_mangle]
+pub extern "C" fn sysinfo_disk_mount_point(disk: CDisk) -> RString {
+    assert!(!disk.is_null());
+    let disk = disk as *const Disk;
+    unsafe { os_str_to_rstring((*disk).mount_point().as_os_str()) }
+}
+
+/// Equivalent of [`Disk::total_space()`][crate::Disk#method.total_space].
+#[no_mangle]
+pub extern "C" fn sysinfo_disk_total_space(disk: CDisk) -> u64 {
+    assert!(!disk.is_null());
+    let disk = disk as *const Disk;
+    unsafe { (*disk).total_space() }
+}
+
+/// Equivalent of [`Disk::available_space()`][crate::Disk#method.available_space].
+#[no_mangle]
+pub extern "C" fn sysinfo_disk_available_space(disk: CDisk) -> u64 {
+    assert!(!disk.is_null());
+    let disk = disk as *const Disk;
+    unsafe { (*disk).available_space() }
+}
+
+/// Equivalent of [`Disk::is_removable()`][crate::Disk#method.is_removable].
+#[no_mangle]
+pub extern "C" fn sysinfo_disk_is_removable(disk: CDisk) -> bool {
+    assert!(!disk.is_null());
+    let disk = disk as *const Disk;
+    unsafe { (*disk).is_removable() }
+}
+
 /// Equivalent of [`System::total_memory()`][crate::System#method.total_memory].
 #[no_mangle]
 pub extern "C" fn sysinfo_total_memory(system: CSystem) -> size_t {
@@ -178,7 +304,7 @@ pub extern "C" fn sysinfo_total_memory(system: CSystem) -> size_t {
     unsafe {
         let system: Box<System> = Box::from_raw(system as *mut System);
         let ret = system.total_memory() as size_t;
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         ret
     }
 }
@@ -190,7 +316,7 @@ pub extern "C" fn sysinfo_free_memory(system: CSystem) -> size_t {
     unsafe {
         let system: Box<System> = Box::from_raw(system as *mut System);
         let ret = system.free_memory() as size_t;
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         ret
     }
 }
@@ -201,7 +327,7 @@ pub extern "C" fn sysinfo_used_memory(system: CSystem) -> size_t {
     assert!(!system.is_null());
     let system: Box<System> = unsafe { Box::from_raw(system as *mut System) };
     let ret = system.used_memory() as size_t;
-    Box::into_raw(system);
+    let _ = Box::into_raw(system);
     ret
 }
 
@@ -212,7 +338,7 @@ pub extern "C" fn sysinfo_total_swap(system: CSystem) -> size_t {
     unsafe {
         let system: Box<System> = Box::from_raw(system as *mut System);
         let ret = system.total_swap() as size_t;
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         ret
     }
 }
@@ -224,7 +350,7 @@ pub extern "C" fn sysinfo_free_swap(system: CSystem) -> size_t {
     unsafe {
         let system: Box<System> = Box::from_raw(system as *mut System);
         let ret = system.free_swap() as size_t;
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         ret
     }
 }
@@ -236,7 +362,7 @@ pub extern "C" fn sysinfo_used_swap(system: CSystem) -> size_t {
     unsafe {
         let system: Box<System> = Box::from_raw(system as *mut System);
         let ret = system.used_swap() as size_t;
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         ret
     }
 }
@@ -267,7 +393,7 @@ pub extern "C" fn sysinfo_networks_refresh_list(networks: CNetworks) {
             let networks: &mut Networks = networks.borrow_mut();
             networks.refresh_list();
         }
-        Box::into_raw(networks);
+        let _ = Box::into_raw(networks);
     }
 }
 
@@ -281,7 +407,7 @@ pub extern "C" fn sysinfo_networks_refresh(networks: CNetworks) {
             let networks: &mut Networks = networks.borrow_mut();
             networks.refresh();
         }
-        Box::into_raw(networks);
+        let _ = Box::into_raw(networks);
     }
 }
 
@@ -295,7 +421,7 @@ pub extern "C" fn sysinfo_networks_received(networks: CNetworks) -> size_t {
         let ret = networks.iter().fold(0, |acc: size_t, (_, data)| {
             acc.saturating_add(data.received() as size_t)
         });
-        Box::into_raw(networks);
+        let _ = Box::into_raw(networks);
         ret
     }
 }
@@ -310,16 +436,203 @@ pub extern "C" fn sysinfo_networks_transmitted(networks: CNetworks) -> size_t {
         let ret = networks.iter().fold(0, |acc: size_t, (_, data)| {
             acc.saturating_add(data.transmitted() as size_t)
         });
-        Box::into_raw(networks);
+        let _ = Box::into_raw(networks);
         ret
     }
 }
 
+/// Equivalent of iterating over [`Networks::list()`][crate::Networks#method.list]. Returns the
+/// number of network interfaces.
+///
+/// # ⚠️ WARNING ⚠️
+///
+/// The `CNetworkData` values are only valid until the next refresh of `networks`!
+#[no_mangle]
+pub extern "C" fn sysinfo_networks(
+    networks: CNetworks,
+    fn_pointer: Option<NetworkLoop>,
+    data: *mut c_void,
+) -> size_t {
+    assert!(!networks.is_null());
+    let networks = unsafe { &*(networks as *const Networks) };
+    if let Some(fn_pointer) = fn_pointer {
+        for (name, network) in networks.list() {
+            let name = match CString::new(name.as_str()) {
+                Ok(name) => name,
+                Err(_) => continue,
+            };
+            if !fn_pointer(
+                name.as_ptr(),
+                network as *const NetworkData as CNetworkData,
+                data,
+            ) {
+                break;
+            }
+        }
+    }
+    networks.list().len() as size_t
+}
+
+/// Equivalent of [`NetworkData::received()`][crate::NetworkData#method.received].
+#[no_mangle]
+pub extern "C" fn sysinfo_network_received(network: CNetworkData) -> u64 {
+    assert!(!network.is_null());
+    let network = network as *const NetworkData;
+    unsafe { (*network).received() }
+}
+
+/// Equivalent of [`NetworkData::total_received()`][crate::NetworkData#method.total_received].
+#[no_mangle]
+pub extern "C" fn sysinfo_network_total_received(network: CNetworkData) -> u64 {
+    assert!(!network.is_null());
+    let network = network as *const NetworkData;
+    unsafe { (*network).total_received() }
+}
+
+/// Equivalent of [`NetworkData::transmitted()`][crate::NetworkData#method.transmitted].
+#[no_mangle]
+pub extern "C" fn sysinfo_network_transmitted(network: CNetworkData) -> u64 {
+    assert!(!network.is_null());
+    let network = network as *const NetworkData;
+    unsafe { (*network).transmitted() }
+}
+
+/// Equivalent of [`NetworkData::total_transmitted()`][crate::NetworkData#method.total_transmitted].
+#[no_mangle]
+pub extern "C" fn sysinfo_network_total_transmitted(network: CNetworkData) -> u64 {
+    assert!(!network.is_null());
+    let network = network as *const NetworkData;
+    unsafe { (*network).total_transmitted() }
+}
+
+/// Equivalent of [`NetworkData::packets_received()`][crate::NetworkData#method.packets_received].
+#[no_mangle]
+pub extern "C" fn sysinfo_network_packets_received(network: CNetworkData) -> u64 {
+    assert!(!network.is_null());
+    let network = network as *const NetworkData;
+    unsafe { (*network).packets_received() }
+}
+
+/// Equivalent of [`NetworkData::total_packets_received()`][crate::NetworkData#method.total_packets_received].
+#[no_mangle]
+pub extern "C" fn sysinfo_network_total_packets_received(network: CNetworkData) -> u64 {
+    assert!(!network.is_null());
+    let network = network as *const NetworkData;
+    unsafe { (*network).total_packets_received() }
+}
+
+/// Equivalent of [`NetworkData::packets_transmitted()`][crate::NetworkData#method.packets_transmitted].
+#[no_mangle]
+pub extern "C" fn sysinfo_network_packets_transmitted(network: CNetworkData) -> u64 {
+    assert!(!network.is_null());
+    let network = network as *const NetworkData;
+    unsafe { (*network).packets_transmitted() }
+}
+
+/// Equivalent of [`NetworkData::total_packets_transmitted()`][crate::NetworkData#method.total_packets_transmitted].
+#[no_mangle]
+pub extern "C" fn sysinfo_network_total_packets_transmitted(network: CNetworkData) -> u64 {
+    assert!(!network.is_null());
+    let network = network as *const NetworkData;
+    unsafe { (*network).total_packets_transmitted() }
+}
+
+/// Equivalent of [`Components::new()`][crate::Components#method.new].
+#[no_mangle]
+pub extern "C" fn sysinfo_components_init() -> CComponents {
+    let components = Box::new(Components::new());
+    Box::into_raw(components) as CComponents
+}
+
+/// Equivalent of `Components::drop()`. Important in C to cleanup memory.
+#[no_mangle]
+pub extern "C" fn sysinfo_components_destroy(components: CComponents) {
+    assert!(!components.is_null());
+    unsafe {
+        drop(Box::from_raw(components as *mut Components));
+    }
+}
+
+/// Equivalent of [`Components::refresh()`][crate::Components#method.refresh].
+#[no_mangle]
+pub extern "C" fn sysinfo_components_refresh(components: CComponents) {
+    assert!(!components.is_null());
+    let components = unsafe { &mut *(components as *mut Components) };
+    components.refresh();
+}
+
+/// Equivalent of [`Components::refresh_list()`][crate::Components#method.refresh_list].
+#[no_mangle]
+pub extern "C" fn sysinfo_components_refresh_list(components: CComponents) {
+    assert!(!components.is_null());
+    let components = unsafe { &mut *(components as *mut Components) };
+    components.refresh_list();
+}
+
+/// Equivalent of iterating over [`Components::list()`][crate::Components#method.list]. Returns
+/// the number of components.
+///
+/// # ⚠️ WARNING ⚠️
+///
+/// The `CComponent` values are only valid until the next refresh of `components`!
+#[no_mangle]
+pub extern "C" fn sysinfo_components(
+    components: CComponents,
+    fn_pointer: Option<ComponentLoop>,
+    data: *mut c_void,
+) -> size_t {
+    assert!(!components.is_null());
+    let components = unsafe { &*(components as *const Components) };
+    if let Some(fn_pointer) = fn_pointer {
+        for component in components.list() {
+            if !fn_pointer(component as *const Component as CComponent, data) {
+                break;
+            }
+        }
+    }
+    components.list().len() as size_t
+}
+
+/// Equivalent of [`Component::label()`][crate::Component#method.label].
+#[no_mangle]
+pub extern "C" fn sysinfo_component_label(component: CComponent) -> RString {
+    assert!(!component.is_null());
+    let component = component as *const Component;
+    unsafe { to_rstring((*component).label()) }
+}
+
+/// Equivalent of [`Component::temperature()`][crate::Component#method.temperature].
+#[no_mangle]
+pub extern "C" fn sysinfo_component_temperature(component: CComponent) -> c_float {
+    assert!(!component.is_null());
+    let component = component as *const Component;
+    unsafe { (*component).temperature() }
+}
+
+/// Equivalent of [`Component::max()`][crate::Component#method.max].
+#[no_mangle]
+pub extern "C" fn sysinfo_component_max(component: CComponent) -> c_float {
+    assert!(!component.is_null());
+    let component = component as *const Component;
+    unsafe { (*component).max() }
+}
+
+/// Equivalent of [`Component::critical()`][crate::Component#method.critical].
+///
+/// In case there is no known critical temperature, it returns `NaN`.
+#[no_mangle]
+pub extern "C" fn sysinfo_component_critical(component: CComponent) -> c_float {
+    assert!(!component.is_null());
+    let component = component as *const Component;
+    unsafe { (*component).critical().unwrap_or(f32::NAN) }
+}
+
 /// Equivalent of [`System::cpus_usage()`][crate::System#method.cpus_usage].
 ///
 /// * `length` will contain the number of CPU usage added into `procs`.
 /// * `procs` will be allocated if it's null and will contain of CPU usage.
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn sysinfo_cpus_usage(
     system: CSystem,
     length: *mut c_uint,
@@ -338,11 +651,11 @@ pub extern "C" fn sysinfo_cpus_usage(
                     libc::malloc(::std::mem::size_of::<c_float>() * cpus.len()) as *mut c_float;
             }
             for (pos, cpu) in cpus.iter().skip(1).enumerate() {
-                (*(*procs).offset(pos as isize)) = cpu.cpu_usage();
+                (*(*procs).add(pos)) = cpu.cpu_usage();
             }
             *length = cpus.len() as c_uint - 1;
         }
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
     }
 }
 
@@ -371,7 +684,7 @@ pub extern "C" fn sysinfo_processes(
                 }
                 entries.len() as size_t
             };
-            Box::into_raw(system);
+            let _ = Box::into_raw(system);
             len
         }
     } else {
@@ -395,7 +708,7 @@ pub extern "C" fn sysinfo_process_by_pid(system: CSystem, pid: PID) -> CProcess
         } else {
             std::ptr::null()
         };
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         ret
     }
 }
@@ -470,6 +783,90 @@ pub extern "C" fn sysinfo_process_virtual_memory(process: CProcess) -> size_t {
     unsafe { (*process).virtual_memory() as usize }
 }
 
+/// Equivalent of [`Process::name()`][crate::Process#method.name].
+#[no_mangle]
+pub extern "C" fn sysinfo_process_name(process: CProcess) -> RString {
+    assert!(!process.is_null());
+    let process = process as *const Process;
+    unsafe { to_rstring((*process).name()) }
+}
+
+/// Equivalent of [`Process::cmd()`][crate::Process#method.cmd]. Returns an array ended by a
+/// null pointer, which must be freed with [`sysinfo_rstrings_free`].
+#[no_mangle]
+pub extern "C" fn sysinfo_process_cmd(process: CProcess) -> *mut RString {
+    assert!(!process.is_null());
+    let process = process as *const Process;
+    unsafe { to_rstrings((*process).cmd()) }
+}
+
+/// Equivalent of [`Process::environ()`][crate::Process#method.environ]. Returns an array ended
+/// by a null pointer, which must be freed with [`sysinfo_rstrings_free`].
+#[no_mangle]
+pub extern "C" fn sysinfo_process_environ(process: CProcess) -> *mut RString {
+    assert!(!process.is_null());
+    let process = process as *const Process;
+    unsafe { to_rstrings((*process).environ()) }
+}
+
+/// Equivalent of [`Process::start_time()`][crate::Process#method.start_time].
+#[no_mangle]
+pub extern "C" fn sysinfo_process_start_time(process: CProcess) -> u64 {
+    assert!(!process.is_null());
+    let process = process as *const Process;
+    unsafe { (*process).start_time() }
+}
+
+/// Equivalent of [`Process::run_time()`][crate::Process#method.run_time].
+#[no_mangle]
+pub extern "C" fn sysinfo_process_run_time(process: CProcess) -> u64 {
+    assert!(!process.is_null());
+    let process = process as *const Process;
+    unsafe { (*process).run_time() }
+}
+
+/// Equivalent of [`Process::disk_usage()`][crate::Process#method.disk_usage].
+#[no_mangle]
+pub extern "C" fn sysinfo_process_disk_usage(process: CProcess) -> CDiskUsage {
+    assert!(!process.is_null());
+    let process = process as *const Process;
+    let usage = unsafe { (*process).disk_usage() };
+    CDiskUsage {
+        total_written_bytes: usage.total_written_bytes,
+        written_bytes: usage.written_bytes,
+        total_read_bytes: usage.total_read_bytes,
+        read_bytes: usage.read_bytes,
+    }
+}
+
+/// Equivalent of [`Process::user_id()`][crate::Process#method.user_id], formatted as a string
+/// (like `1000`, or `S-1-5-18` on Windows).
+///
+/// In case there is no known user, it returns `NULL`.
+#[no_mangle]
+pub extern "C" fn sysinfo_process_user_id(process: CProcess) -> RString {
+    assert!(!process.is_null());
+    let process = process as *const Process;
+    match unsafe { (*process).user_id() } {
+        Some(uid) => to_rstring(&(**uid).to_string()),
+        None => std::ptr::null(),
+    }
+}
+
+/// Equivalent of [`Uid::resolve_name()`][crate::Uid#method.resolve_name] on the user of the
+/// process.
+///
+/// In case there is no known user, it returns `NULL`.
+#[no_mangle]
+pub extern "C" fn sysinfo_process_user_name(process: CProcess) -> RString {
+    assert!(!process.is_null());
+    let process = process as *const Process;
+    match unsafe { (*process).user_id() }.and_then(|uid| uid.resolve_name()) {
+        Some(name) => to_rstring(&name),
+        None => std::ptr::null(),
+    }
+}
+
 /// Equivalent of [`Process::exe()`][crate::Process#method.exe].
 #[no_mangle]
 pub extern "C" fn sysinfo_process_executable_path(process: CProcess) -> RString {
@@ -525,6 +922,28 @@ pub extern "C" fn sysinfo_rstring_free(s: RString) {
     }
 }
 
+/// Frees an array of C strings ended by a null pointer, like the ones returned by
+/// [`sysinfo_process_cmd`] and [`sysinfo_process_environ`].
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn sysinfo_rstrings_free(strings: *mut RString) {
+    if strings.is_null() {
+        return;
+    }
+    unsafe {
+        let mut len = 0;
+        while !(*strings.add(len)).is_null() {
+            sysinfo_rstring_free(*strings.add(len));
+            len += 1;
+        }
+        // The null pointer at the end is part of the array too.
+        drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+            strings,
+            len + 1,
+        )));
+    }
+}
+
 /// Equivalent of [`cpu::vendor_id()`].
 #[no_mangle]
 pub extern "C" fn sysinfo_cpu_vendor_id(system: CSystem) -> RString {
@@ -540,7 +959,7 @@ pub extern "C" fn sysinfo_cpu_vendor_id(system: CSystem) -> RString {
         } else {
             std::ptr::null()
         };
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         c_string
     }
 }
@@ -560,7 +979,7 @@ pub extern "C" fn sysinfo_cpu_brand(system: CSystem) -> RString {
         } else {
             std::ptr::null()
         };
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         c_string
     }
 }
@@ -572,7 +991,7 @@ pub extern "C" fn sysinfo_cpu_physical_cores(system: CSystem) -> u32 {
     unsafe {
         let system: Box<System> = Box::from_raw(system as *mut System);
         let count = system.physical_core_count().unwrap_or(0);
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         count as u32
     }
 }
@@ -588,7 +1007,7 @@ pub extern "C" fn sysinfo_cpu_frequency(system: CSystem) -> u64 {
             .first()
             .map(|cpu| cpu.frequency())
             .unwrap_or(0);
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         freq
     }
 }
@@ -604,7 +1023,7 @@ pub extern "C" fn sysinfo_system_name(system: CSystem) -> RString {
         } else {
             std::ptr::null()
         };
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         c_string
     }
 }
@@ -620,7 +1039,7 @@ pub extern "C" fn sysinfo_system_version(system: CSystem) -> RString {
         } else {
             std::ptr::null()
         };
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         c_string
     }
 }
@@ -637,7 +1056,7 @@ pub extern "C" fn sysinfo_system_kernel_version(system: CSystem) -> RString {
             std::ptr::null()
         };
 
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         c_string
     }
 }
@@ -653,7 +1072,7 @@ pub extern "C" fn sysinfo_system_host_name(system: CSystem) -> RString {
         } else {
             std::ptr::null()
         };
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         c_string
     }
 }
@@ -670,7 +1089,7 @@ pub extern "C" fn sysinfo_system_long_version(system: CSystem) -> RString {
         } else {
             std::ptr::null()
         };
-        Box::into_raw(system);
+        let _ = Box::into_raw(system);
         c_string
     }
 }
@@ -1,7 +1,12 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+#[cfg(feature = "energy")]
+use crate::PowerInner;
+#[cfg(feature = "async")]
+use crate::RefreshFuture;
 use crate::{
-    ComponentInner, ComponentsInner, CpuInner, NetworkDataInner, NetworksInner, ProcessInner,
+    ComponentInner, ComponentsInner, ContainerInner, ContainersInner, CpuInner, GpuInner,
+    GpusInner, NetworkDataInner, NetworksInner, PerformanceCountersInner, ProcessInner,
     SystemInner, UserInner,
 };
 
@@ -10,8 +15,10 @@ use std::collections::HashMap;
 use std::convert::{From, TryFrom};
 use std::ffi::OsStr;
 use std::fmt;
-use std::path::Path;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::{Duration, Instant, SystemTime};
 
 /// Structs containing system's information such as processes, memory and CPU.
 ///
@@ -26,6 +33,10 @@ use std::str::FromStr;
 /// ```
 pub struct System {
     pub(crate) inner: SystemInner,
+    cpu_usage_smoothing: Option<CpuUsageSmoothing>,
+    use_cgroup_limits: bool,
+    applied_cgroup_limits: Option<CGroupLimits>,
+    process_filter: Option<ProcessFilter>,
 }
 
 impl Default for System {
@@ -85,11 +96,22 @@ impl System {
     /// # }
     /// ```
     pub fn new_with_specifics(refreshes: RefreshKind) -> Self {
-        let mut s = Self {
-            inner: SystemInner::new(),
-        };
-        s.refresh_specifics(refreshes);
-        s
+        SystemBuilder::new().with_refreshes(refreshes).build()
+    }
+
+    /// Returns a [`SystemBuilder`], to configure how the `System` is refreshed before creating
+    /// it.
+    ///
+    /// ```no_run
+    /// use sysinfo::{RefreshKind, System};
+    ///
+    /// let s = System::builder()
+    ///     .with_refreshes(RefreshKind::everything())
+    ///     .with_cpu_usage_smoothing(5)
+    ///     .build();
+    /// ```
+    pub fn builder() -> SystemBuilder {
+        SystemBuilder::new()
     }
 
     /// Refreshes according to the given [`RefreshKind`]. It calls the corresponding
@@ -119,6 +141,10 @@ impl System {
 
     /// Refreshes all system and processes information.
     ///
+    /// With the `parallel` feature, the memory is read at the same time as the CPUs and the
+    /// processes are refreshed (the processes being refreshed in parallel with the `multithread`
+    /// feature). On FreeBSD, the memory is still read after them.
+    ///
     /// ```no_run
     /// use sysinfo::System;
     ///
@@ -126,8 +152,124 @@ impl System {
     /// s.refresh_all();
     /// ```
     pub fn refresh_all(&mut self) {
-        self.refresh_system();
-        self.refresh_processes();
+        #[cfg(feature = "parallel")]
+        {
+            // The memory doesn't depend on the CPUs and the processes, so it's read at the same
+            // time and applied afterwards.
+            let mut memory = self.inner.memory_reader();
+            let errors = crate::utils::ErrorCollector::current();
+            rayon::join(
+                || errors.run(|| memory.read()),
+                || {
+                    errors.run(|| {
+                        self.refresh_cpu_usage();
+                        self.refresh_processes();
+                    })
+                },
+            );
+            self.inner.apply_memory(memory);
+            self.refresh_cgroup_limits();
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            self.refresh_system();
+            self.refresh_processes();
+        }
+    }
+
+    /// Same as [`System::refresh_all`] but returns what failed during the refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// if let Err(report) = s.try_refresh_all() {
+    ///     eprintln!("some information couldn't be retrieved: {report}");
+    /// }
+    /// ```
+    pub fn try_refresh_all(&mut self) -> Result<(), RefreshReport> {
+        crate::utils::collect_errors(|| self.refresh_all())
+    }
+
+    /// Same as [`System::refresh_specifics`] but returns what failed during the refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessRefreshKind, RefreshErrorKind, RefreshKind, System};
+    ///
+    /// let mut s = System::new();
+    /// let kind = RefreshKind::new().with_processes(ProcessRefreshKind::new());
+    /// if let Err(report) = s.try_refresh_specifics(kind) {
+    ///     let denied = report
+    ///         .errors()
+    ///         .iter()
+    ///         .filter(|error| error.kind() == RefreshErrorKind::PermissionDenied)
+    ///         .count();
+    ///     println!("{denied} processes couldn't be read");
+    /// }
+    /// ```
+    pub fn try_refresh_specifics(&mut self, refreshes: RefreshKind) -> Result<(), RefreshReport> {
+        crate::utils::collect_errors(|| self.refresh_specifics(refreshes))
+    }
+
+    /// Same as [`System::refresh_all`] but runs the refresh on a worker thread, so it doesn't
+    /// block the async runtime.
+    ///
+    /// While the refresh is running, `self` is replaced with an empty `System`.
+    ///
+    /// ⚠️ Only available with the `async` feature.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// async fn refresh(s: &mut System) {
+    ///     s.refresh_all_async().await;
+    ///     println!("{} processes", s.processes().len());
+    /// }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn refresh_all_async(&mut self) -> RefreshFuture<'_> {
+        RefreshFuture::new(self, System::refresh_all)
+    }
+
+    /// Same as [`System::refresh_specifics`] but runs the refresh on a worker thread, so it
+    /// doesn't block the async runtime.
+    ///
+    /// While the refresh is running, `self` is replaced with an empty `System`.
+    ///
+    /// ⚠️ Only available with the `async` feature.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessRefreshKind, RefreshKind, System};
+    ///
+    /// async fn refresh(s: &mut System) {
+    ///     s.refresh_specifics_async(
+    ///         RefreshKind::new().with_processes(ProcessRefreshKind::everything()),
+    ///     )
+    ///     .await;
+    /// }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn refresh_specifics_async(&mut self, refreshes: RefreshKind) -> RefreshFuture<'_> {
+        RefreshFuture::new(self, move |s| s.refresh_specifics(refreshes))
+    }
+
+    /// Same as [`System::refresh_processes`] but runs the refresh on a worker thread, so it
+    /// doesn't block the async runtime.
+    ///
+    /// While the refresh is running, `self` is replaced with an empty `System`.
+    ///
+    /// ⚠️ Only available with the `async` feature.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// async fn refresh(s: &mut System) {
+    ///     s.refresh_processes_async().await;
+    /// }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn refresh_processes_async(&mut self) -> RefreshFuture<'_> {
+        RefreshFuture::new(self, System::refresh_processes)
     }
 
     /// Refreshes system information (RAM, swap, CPU usage and components' temperature).
@@ -158,7 +300,8 @@ impl System {
     /// s.refresh_memory();
     /// ```
     pub fn refresh_memory(&mut self) {
-        self.inner.refresh_memory()
+        self.inner.refresh_memory();
+        self.refresh_cgroup_limits();
     }
 
     /// Refreshes CPUs usage.
@@ -185,6 +328,12 @@ impl System {
 
     /// Refreshes CPUs frequency information.
     ///
+    /// The current frequency of each CPU is read again on every call. On Linux, the cpufreq
+    /// governor and scaling limits are refreshed as well.
+    ///
+    /// ⚠️ On macOS, the per-core frequency is computed from the time spent in each performance
+    /// state since the previous call, so the first call only returns the maximum frequency.
+    ///
     /// Calling this method is the same as calling
     /// `refresh_cpu_specifics(CpuRefreshKind::new().with_frequency())`.
     ///
@@ -198,6 +347,70 @@ impl System {
         self.refresh_cpu_specifics(CpuRefreshKind::new().with_frequency())
     }
 
+    /// Refreshes only the global CPU usage.
+    ///
+    /// Unlike [`System::refresh_cpu_usage`], it doesn't read the information of each CPU, so it
+    /// is much cheaper to call (on Linux, only the first line of `/proc/stat` is read). It's
+    /// useful for health checks running very frequently. The result is available with
+    /// `global_cpu_info().cpu_usage()`.
+    ///
+    /// ⚠️ Just like for [`System::refresh_cpu_usage`], the first call doesn't have a previous
+    /// value to compute the usage from, so you need to call this method at least twice.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_global_cpu_usage();
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_global_cpu_usage();
+    /// println!("{}%", s.global_cpu_info().cpu_usage());
+    /// ```
+    pub fn refresh_global_cpu_usage(&mut self) {
+        self.inner.refresh_global_cpu_usage();
+        if let Some(smoothing) = self.cpu_usage_smoothing.as_mut() {
+            smoothing.update_global_cpu(&mut self.inner);
+        }
+    }
+
+    /// Refreshes CPUs temperature information.
+    ///
+    /// Calling this method is the same as calling
+    /// `refresh_cpu_specifics(CpuRefreshKind::new().with_temperature())`.
+    ///
+    /// On Linux, the hwmon sensors matching each CPU are only looked for during the first
+    /// temperature refresh, the next ones only read them.
+    ///
+    /// ⚠️ Not supported on Windows, it doesn't do anything there.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_cpu_temperature();
+    /// ```
+    pub fn refresh_cpu_temperature(&mut self) {
+        self.refresh_cpu_specifics(CpuRefreshKind::new().with_temperature())
+    }
+
+    /// Refreshes CPUs idle states residency.
+    ///
+    /// Like for the CPU usage, the residency is computed from the time spent in each idle state
+    /// since the previous call, so you need to call this method at least twice.
+    ///
+    /// Calling this method is the same as calling
+    /// `refresh_cpu_specifics(CpuRefreshKind::new().with_idle_states())`.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_cpu_idle_states();
+    /// ```
+    pub fn refresh_cpu_idle_states(&mut self) {
+        self.refresh_cpu_specifics(CpuRefreshKind::new().with_idle_states())
+    }
+
     /// Refreshes all information related to CPUs information.
     ///
     /// ⚠️ Please note that the result will very likely be inaccurate at the first call.
@@ -229,7 +442,13 @@ impl System {
     /// s.refresh_cpu_specifics(CpuRefreshKind::everything());
     /// ```
     pub fn refresh_cpu_specifics(&mut self, refresh_kind: CpuRefreshKind) {
-        self.inner.refresh_cpu_specifics(refresh_kind)
+        self.inner.refresh_cpu_specifics(refresh_kind);
+        if refresh_kind.cpu_usage() {
+            if let Some(smoothing) = self.cpu_usage_smoothing.as_mut() {
+                smoothing.update_cpus(&mut self.inner);
+            }
+        }
+        self.refresh_cgroup_limits();
     }
 
     /// Gets all processes and updates their information.
@@ -261,7 +480,13 @@ impl System {
     /// s.refresh_processes_specifics(ProcessRefreshKind::new());
     /// ```
     pub fn refresh_processes_specifics(&mut self, refresh_kind: ProcessRefreshKind) {
-        self.inner.refresh_processes_specifics(refresh_kind)
+        self.inner.refresh_processes_specifics(refresh_kind);
+        filter_processes(&self.process_filter, self.inner.processes_mut());
+        if refresh_kind.cpu() {
+            if let Some(smoothing) = self.cpu_usage_smoothing.as_mut() {
+                smoothing.update_processes(self.inner.processes_mut());
+            }
+        }
     }
 
     /// Refreshes *only* the process corresponding to `pid`. Returns `false` if the process doesn't
@@ -296,7 +521,89 @@ impl System {
         pid: Pid,
         refresh_kind: ProcessRefreshKind,
     ) -> bool {
-        self.inner.refresh_process_specifics(pid, refresh_kind)
+        let mut ret = self.inner.refresh_process_specifics(pid, refresh_kind);
+        if let Some(filter) = self.process_filter.as_ref() {
+            let processes = self.inner.processes_mut();
+            if processes
+                .get(&pid)
+                .map_or(false, |process| !filter(process))
+            {
+                processes.remove(&pid);
+                ret = false;
+            }
+        }
+        if ret && refresh_kind.cpu() {
+            if let Some(smoothing) = self.cpu_usage_smoothing.as_mut() {
+                if let Some(process) = self.inner.processes_mut().get_mut(&pid) {
+                    smoothing.update_process(pid, process);
+                }
+            }
+        }
+        ret
+    }
+
+    /// Refreshes the process of `child`, which was spawned by the current process, and returns
+    /// it.
+    ///
+    /// The PID of `child` can be reused by another process once it was waited for, so the
+    /// process is only returned (and kept in the process list) if its parent is the current
+    /// process and if it didn't start before it.
+    ///
+    /// ⚠️ Like for the other processes, the CPU usage is only computed once the process was
+    /// refreshed twice.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    /// use std::process::Command;
+    ///
+    /// let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+    /// let mut s = System::new();
+    /// while child.try_wait().unwrap().is_none() {
+    ///     if let Some(process) = s.refresh_child(&child) {
+    ///         println!("{}% CPU, {} bytes", process.cpu_usage(), process.memory());
+    ///     }
+    ///     std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// }
+    /// ```
+    pub fn refresh_child(&mut self, child: &std::process::Child) -> Option<&Process> {
+        let pid = Pid::from_u32(child.id());
+        if !self.refresh_process(pid) {
+            return None;
+        }
+        if !self.process(pid).map_or(false, is_current_process_child) {
+            sysinfo_debug!("PID {} isn't a child of the current process anymore", pid);
+            self.inner.processes_mut().remove(&pid);
+            return None;
+        }
+        self.process(pid)
+    }
+
+    /// Smooths the CPU usage values over the last `samples` refreshes.
+    ///
+    /// Once enabled, [`Cpu::cpu_usage`], [`Process::cpu_usage`] and
+    /// [`Process::normalized_cpu_usage`] return an exponential moving average (with a smoothing
+    /// factor of `2 / (samples + 1)`) instead of the value computed since the previous refresh.
+    /// It is useful to get less noisy values when refreshing often.
+    ///
+    /// Passing `0` or `1` disables the smoothing (which is the default).
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.set_cpu_usage_smoothing(5);
+    /// for _ in 0..10 {
+    ///     std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    ///     s.refresh_cpu_usage();
+    ///     println!("{}%", s.global_cpu_info().cpu_usage());
+    /// }
+    /// ```
+    pub fn set_cpu_usage_smoothing(&mut self, samples: usize) {
+        self.cpu_usage_smoothing = if samples > 1 {
+            Some(CpuUsageSmoothing::new(samples))
+        } else {
+            None
+        };
     }
 
     /// Returns the process list.
@@ -389,6 +696,45 @@ impl System {
         )
     }
 
+    /// Returns the `n` processes using the most CPU, sorted from the one using the most.
+    ///
+    /// Only the `n` processes are kept while going through the process list, so it's cheaper
+    /// than sorting the whole list.
+    ///
+    /// ⚠️ The CPU usage is only computed once the processes were refreshed twice (see
+    /// [`Process::cpu_usage`]).
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new_all();
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_processes();
+    /// for process in s.top_processes_by_cpu(5) {
+    ///     println!("{} {}%", process.name(), process.cpu_usage());
+    /// }
+    /// ```
+    pub fn top_processes_by_cpu(&self, n: usize) -> Vec<&Process> {
+        top_processes(self.processes(), n, |process| process.cpu_usage() as f64)
+    }
+
+    /// Returns the `n` processes using the most memory, sorted from the one using the most.
+    ///
+    /// Only the `n` processes are kept while going through the process list, so it's cheaper
+    /// than sorting the whole list.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for process in s.top_processes_by_memory(5) {
+    ///     println!("{} {} bytes", process.name(), process.memory());
+    /// }
+    /// ```
+    pub fn top_processes_by_memory(&self, n: usize) -> Vec<&Process> {
+        top_processes(self.processes(), n, |process| process.memory() as f64)
+    }
+
     /// Returns "global" CPUs information (aka the addition of all the CPUs).
     ///
     /// To have up-to-date information, you need to call [`System::refresh_cpu`] or
@@ -420,6 +766,9 @@ impl System {
     /// By default, the list of CPUs is empty until you call [`System::refresh_cpu`] or
     /// [`System::refresh_specifics`] with `cpu` enabled.
     ///
+    /// ⚠️ It isn't limited by [`System::set_use_cgroup_limits`], take a look at
+    /// [`System::physical_core_count`] for that.
+    ///
     /// ```no_run
     /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
     ///
@@ -438,6 +787,9 @@ impl System {
     ///
     /// In case there are multiple CPUs, it will combine the physical core count of all the CPUs.
     ///
+    /// It is limited by the CPU quota of the current cgroup when
+    /// [`set_use_cgroup_limits`](System::set_use_cgroup_limits) is enabled.
+    ///
     /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
@@ -447,7 +799,118 @@ impl System {
     /// println!("{:?}", s.physical_core_count());
     /// ```
     pub fn physical_core_count(&self) -> Option<usize> {
-        self.inner.physical_core_count()
+        let count = self.inner.physical_core_count();
+        match self
+            .applied_cgroup_limits
+            .as_ref()
+            .and_then(|limits| limits.cpu_count())
+        {
+            Some(limit) => count.map(|count| count.min(limit)),
+            None => count,
+        }
+    }
+
+    /// Returns the number of physical CPU packages (sockets) or `None` if it couldn't get it.
+    ///
+    /// ⚠️ Not supported on FreeBSD and iOS.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// println!("{:?}", s.socket_count());
+    /// ```
+    pub fn socket_count(&self) -> Option<usize> {
+        self.inner.socket_count()
+    }
+
+    /// Returns the instruction set extensions supported by the CPU (like `avx2`, `avx512f`,
+    /// `neon` or `sve`).
+    ///
+    /// Only the extensions that can actually be used are listed: for example, AVX is not
+    /// listed if the OS doesn't save the AVX registers. Feature names follow the ones used by
+    /// the `is_x86_feature_detected` and `is_aarch64_feature_detected` macros.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// if !s.cpu_features().iter().any(|feature| feature == "avx2") {
+    ///     eprintln!("AVX2 is not supported, falling back to a slower implementation");
+    /// }
+    /// ```
+    pub fn cpu_features(&self) -> Vec<String> {
+        self.inner.cpu_features()
+    }
+
+    /// Returns the number of interrupts and context switches handled by the system.
+    ///
+    /// It is updated when calling [`System::refresh_cpu_usage`] (or any refresh using
+    /// [`CpuRefreshKind::cpu_usage`]). Like for the CPU usage, you need to refresh it at least
+    /// twice to get the rates.
+    ///
+    /// ⚠️ Not supported on macOS and iOS, where all values are always `0`.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_cpu_usage();
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_cpu_usage();
+    ///
+    /// let activity = s.cpu_activity();
+    /// println!("{} context switches/s", activity.context_switches_per_second);
+    /// ```
+    pub fn cpu_activity(&self) -> CpuActivity {
+        self.inner.cpu_activity()
+    }
+
+    /// Refreshes the energy counters of the CPU packages.
+    ///
+    /// The power consumption is computed from the difference between two refreshes, so you need
+    /// to call this method at least twice to get it.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_energy();
+    /// ```
+    #[cfg(feature = "energy")]
+    pub fn refresh_energy(&mut self) {
+        self.inner.refresh_energy()
+    }
+
+    /// Returns the energy consumed by each RAPL (Running Average Power Limit) domain of the
+    /// CPU packages.
+    ///
+    /// It is updated when calling [`System::refresh_energy`].
+    ///
+    /// ⚠️ Only available with the `energy` feature, and only supported on Linux (through the
+    /// `powercap` interface) and Windows (through the Energy Meter Interface of the Energy
+    /// Estimation Engine). On recent Linux kernels, reading the counters requires root
+    /// privileges.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_energy();
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// s.refresh_energy();
+    ///
+    /// for domain in s.energy_domains() {
+    ///     println!("package {} {}: {:.2} W", domain.package, domain.name, domain.power);
+    /// }
+    /// ```
+    #[cfg(feature = "energy")]
+    pub fn energy_domains(&self) -> &[EnergyDomain] {
+        self.inner.energy_domains()
     }
 
     /// Returns the RAM size in bytes.
@@ -460,9 +923,14 @@ impl System {
     /// ```
     ///
     /// On Linux, if you want to see this information with the limit of your cgroup, take a look
-    /// at [`cgroup_limits`](System::cgroup_limits).
+    /// at [`cgroup_limits`](System::cgroup_limits) or
+    /// [`set_use_cgroup_limits`](System::set_use_cgroup_limits).
     pub fn total_memory(&self) -> u64 {
-        self.inner.total_memory()
+        let total = self.inner.total_memory();
+        match self.applied_cgroup_limits.as_ref() {
+            Some(limits) => limits.total_memory.min(total),
+            None => total,
+        }
     }
 
     /// Returns the amount of free RAM in bytes.
@@ -480,7 +948,11 @@ impl System {
     /// println!("{} bytes", s.free_memory());
     /// ```
     pub fn free_memory(&self) -> u64 {
-        self.inner.free_memory()
+        let free = self.inner.free_memory();
+        match self.applied_cgroup_limits.as_ref() {
+            Some(limits) => limits.free_memory.min(free),
+            None => free,
+        }
     }
 
     /// Returns the amount of available RAM in bytes.
@@ -498,7 +970,40 @@ impl System {
     /// println!("{} bytes", s.available_memory());
     /// ```
     pub fn available_memory(&self) -> u64 {
-        self.inner.available_memory()
+        let available = self.inner.available_memory();
+        match self.applied_cgroup_limits.as_ref() {
+            Some(limits) => limits.free_memory.min(available),
+            None => available,
+        }
+    }
+
+    /// Returns how the available memory is split between free and reclaimable memory, and how
+    /// it was computed.
+    ///
+    /// It is updated when calling [`System::refresh_memory`]. The `available` field is always
+    /// the same as [`System::available_memory`].
+    ///
+    /// ⚠️ On Windows, getting the free memory requires administrator privileges. Without them,
+    /// `free` is the same as `available` and `reclaimable` is `0`.
+    ///
+    /// ⚠️ This method always returns `None` on unsupported systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// if let Some(available) = s.available_memory_breakdown() {
+    ///     println!(
+    ///         "{} bytes available ({} free, {} reclaimable, {:?})",
+    ///         available.available,
+    ///         available.free,
+    ///         available.reclaimable,
+    ///         available.source,
+    ///     );
+    /// }
+    /// ```
+    pub fn available_memory_breakdown(&self) -> Option<AvailableMemory> {
+        self.inner.available_memory_breakdown()
     }
 
     /// Returns the amount of used RAM in bytes.
@@ -510,7 +1015,10 @@ impl System {
     /// println!("{} bytes", s.used_memory());
     /// ```
     pub fn used_memory(&self) -> u64 {
-        self.inner.used_memory()
+        match self.applied_cgroup_limits.as_ref() {
+            Some(limits) => limits.total_memory.saturating_sub(limits.free_memory),
+            None => self.inner.used_memory(),
+        }
     }
 
     /// Returns the SWAP size in bytes.
@@ -534,7 +1042,11 @@ impl System {
     /// println!("{} bytes", s.free_swap());
     /// ```
     pub fn free_swap(&self) -> u64 {
-        self.inner.free_swap()
+        let free = self.inner.free_swap();
+        match self.applied_cgroup_limits.as_ref() {
+            Some(limits) => limits.free_swap.min(free),
+            None => free,
+        }
     }
 
     /// Returns the amount of used SWAP in bytes.
@@ -546,2829 +1058,10728 @@ impl System {
     /// println!("{} bytes", s.used_swap());
     /// ```
     pub fn used_swap(&self) -> u64 {
-        self.inner.used_swap()
+        match self.applied_cgroup_limits {
+            Some(_) => self.total_swap().saturating_sub(self.free_swap()),
+            None => self.inner.used_swap(),
+        }
     }
 
-    /// Retrieves the limits for the current cgroup (if any), otherwise it returns `None`.
+    /// Returns how the RAM is used by the kernel (buffers, caches, ...).
     ///
-    /// This information is computed every time the method is called.
+    /// It is updated when calling [`System::refresh_memory`]. It's useful to understand why the
+    /// "used" memory doesn't match what tools like `free` display: on Linux, these tools don't
+    /// count the page cache and the reclaimable slab as used.
     ///
-    /// ⚠️ You need to have run [`refresh_memory`](System::refresh_memory) at least once before
-    /// calling this method.
+    /// ⚠️ Not all values are available on all platforms, the missing ones are `0`:
     ///
-    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
-    /// systems.
+    /// * On macOS, only `cached` (file-backed pages) is available.
+    /// * On Windows, `cached` is the system cache and `slab_reclaimable`/`slab_unreclaimable`
+    ///   are the paged/non-paged kernel pools. `dirty` is the size of the modified page list,
+    ///   which requires administrator privileges, and `writeback` is not available.
+    /// * On FreeBSD, only `buffers` and `cached` (which includes the ZFS ARC) are available.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// println!("limits: {:?}", s.cgroup_limits());
+    /// let breakdown = s.memory_breakdown();
+    /// println!("cached: {} bytes", breakdown.cached);
     /// ```
-    pub fn cgroup_limits(&self) -> Option<CGroupLimits> {
-        self.inner.cgroup_limits()
+    pub fn memory_breakdown(&self) -> MemoryBreakdown {
+        self.inner.memory_breakdown()
     }
 
-    /// Returns system uptime (in seconds).
+    /// Returns the commit charge of the system, which is the amount of virtual memory the
+    /// system promised to the processes.
+    ///
+    /// It is updated when calling [`System::refresh_memory`]. If the commit charge reaches the
+    /// limit, new allocations fail (on Windows) or may be refused (on Linux, depending on the
+    /// overcommit policy).
+    ///
+    /// On Windows, the paged and non-paged kernel pool sizes are available through
+    /// [`System::memory_breakdown`] (`slab_reclaimable` and `slab_unreclaimable`).
+    ///
+    /// ⚠️ This method is only implemented for Linux and Windows. All values are `0` on the other
+    /// systems. On Linux, `peak` is always `0`.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// println!("System running since {} seconds", s.uptime());
+    /// let commit = s.commit_charge();
+    /// println!("committed {} of {} bytes", commit.total, commit.limit);
     /// ```
-    pub fn uptime(&self) -> u64 {
-        self.inner.uptime()
+    pub fn commit_charge(&self) -> CommitCharge {
+        self.inner.commit_charge()
     }
 
-    /// Returns the time (in seconds) when the system booted since UNIX epoch.
+    /// Returns information about the memory compressor.
+    ///
+    /// It is updated when calling [`System::refresh_memory`].
+    ///
+    /// On Linux, it is the zswap pool (requires Linux 5.19 or later). zram devices are listed
+    /// by [`System::zram_devices`].
+    ///
+    /// ⚠️ This method is only implemented for Linux, macOS and iOS. All values are `0` on the
+    /// other systems.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// let s = System::new();
-    /// println!("System booted at {} seconds", s.boot_time());
+    /// let s = System::new_all();
+    /// let compressed = s.compressed_memory();
+    /// println!(
+    ///     "{} bytes are compressed into {} bytes",
+    ///     compressed.uncompressed,
+    ///     compressed.compressed,
+    /// );
     /// ```
-    pub fn boot_time(&self) -> u64 {
-        self.inner.boot_time()
+    pub fn compressed_memory(&self) -> CompressedMemory {
+        self.inner.compressed_memory()
     }
 
-    /// Returns the system load average value.
+    /// Returns the memory pressure level computed by the kernel.
     ///
-    /// ⚠️ This is currently not working on **Windows**.
+    /// This information is computed every time the method is called.
+    ///
+    /// ⚠️ This method is only implemented for macOS and iOS. It always returns `None` for all
+    /// other systems.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{MemoryPressureLevel, System};
     ///
-    /// let s = System::new_all();
-    /// let load_avg = s.load_average();
-    /// println!(
-    ///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
-    ///     load_avg.one,
-    ///     load_avg.five,
-    ///     load_avg.fifteen,
-    /// );
+    /// let s = System::new();
+    /// if s.memory_pressure_level() == Some(MemoryPressureLevel::Critical) {
+    ///     eprintln!("memory is running low, dropping caches");
+    /// }
     /// ```
-    pub fn load_average(&self) -> LoadAvg {
-        self.inner.load_average()
+    pub fn memory_pressure_level(&self) -> Option<MemoryPressureLevel> {
+        self.inner.memory_pressure_level()
     }
 
-    /// Returns the system name.
+    /// Returns the zram devices, which are compressed block devices in RAM mostly used as swap.
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// It is updated when calling [`System::refresh_memory`]. When zram is used, the swap
+    /// values don't tell how much RAM is used to store the swapped data.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns an empty slice for all
+    /// other systems.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// let s = System::new();
-    /// println!("OS: {:?}", s.name());
+    /// let s = System::new_all();
+    /// for device in s.zram_devices() {
+    ///     println!(
+    ///         "{}: {} bytes stored in {} bytes of RAM",
+    ///         device.name,
+    ///         device.uncompressed,
+    ///         device.memory_used,
+    ///     );
+    /// }
     /// ```
-    pub fn name(&self) -> Option<String> {
-        self.inner.name()
+    pub fn zram_devices(&self) -> &[ZramDevice] {
+        self.inner.zram_devices()
     }
 
-    /// Returns the system's kernel version.
+    /// Returns the physical memory modules installed in the system, read from the SMBIOS
+    /// tables. Empty slots are not listed.
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
+    /// ⚠️ This method is only implemented for Linux and Windows. It always returns an empty
+    /// list for all other systems. On Linux, reading the SMBIOS tables requires root privileges.
+    ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new();
-    /// println!("kernel version: {:?}", s.kernel_version());
+    /// for module in s.memory_modules() {
+    ///     println!(
+    ///         "{}: {} bytes of {} at {} MT/s",
+    ///         module.locator,
+    ///         module.size,
+    ///         module.kind,
+    ///         module.speed,
+    ///     );
+    /// }
     /// ```
-    pub fn kernel_version(&self) -> Option<String> {
-        self.inner.kernel_version()
+    pub fn memory_modules(&self) -> Vec<MemoryModule> {
+        self.inner.memory_modules()
     }
 
-    /// Returns the system version (e.g. for MacOS this will return 11.1 rather than the kernel version).
+    /// Returns the Kernel Samepage Merging (KSM) statistics, or `None` if KSM isn't available.
+    ///
+    /// KSM merges identical memory pages (mostly used by virtual machines hosts), so the memory
+    /// used by processes can be higher than the memory actually used.
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new();
-    /// println!("OS version: {:?}", s.os_version());
+    /// if let Some(ksm) = s.ksm() {
+    ///     println!("KSM saved {} bytes", ksm.saved);
+    /// }
     /// ```
-    pub fn os_version(&self) -> Option<String> {
-        self.inner.os_version()
+    pub fn ksm(&self) -> Option<Ksm> {
+        self.inner.ksm()
     }
 
-    /// Returns the system long os version (e.g "MacOS 11.2 BigSur").
+    /// Returns the amount of memory (in bytes) taken back by the hypervisor through the memory
+    /// balloon driver (like virtio-balloon or Hyper-V dynamic memory), or `None` if it couldn't
+    /// be retrieved.
+    ///
+    /// This memory is still counted in [`System::total_memory`] but cannot be used by the
+    /// system, which explains why the used memory can grow without any process using more
+    /// memory.
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems. Before Linux 6.15, it is only available if the balloon driver supports page
+    /// migration (`CONFIG_BALLOON_COMPACTION`).
+    ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new();
-    /// println!("Long OS Version: {:?}", s.long_os_version());
+    /// if let Some(balloon) = s.balloon_memory() {
+    ///     println!("the hypervisor took back {} bytes", balloon);
+    /// }
     /// ```
-    pub fn long_os_version(&self) -> Option<String> {
-        self.inner.long_os_version()
+    pub fn balloon_memory(&self) -> Option<u64> {
+        self.inner.balloon_memory()
     }
 
-    /// Returns the distribution id as defined by os-release,
-    /// or [`std::env::consts::OS`].
-    ///
-    /// See also
-    /// - <https://www.freedesktop.org/software/systemd/man/os-release.html#ID=>
-    /// - <https://doc.rust-lang.org/std/env/consts/constant.OS.html>
+    /// Returns the number of processes killed by the out-of-memory (OOM) killer since the system
+    /// booted, or `None` if it couldn't be retrieved.
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
+    /// ⚠️ This method is only implemented for Linux (4.13 and later). It always returns `None`
+    /// for all other systems.
+    ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new();
-    /// println!("Distribution ID: {:?}", s.distribution_id());
+    /// let before = s.oom_kills().unwrap_or(0);
+    /// // Do some work...
+    /// if s.oom_kills().unwrap_or(0) > before {
+    ///     eprintln!("the OOM killer killed a process!");
+    /// }
     /// ```
-    pub fn distribution_id(&self) -> String {
-        self.inner.distribution_id()
+    pub fn oom_kills(&self) -> Option<u64> {
+        self.inner.oom_kills()
     }
 
-    /// Returns the system hostname based off DNS
+    /// Retrieves the limits for the current cgroup (if any), otherwise it returns `None`.
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// This information is computed every time the method is called.
+    ///
+    /// ⚠️ You need to have run [`refresh_memory`](System::refresh_memory) at least once before
+    /// calling this method.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// let s = System::new();
-    /// println!("Hostname: {:?}", s.host_name());
+    /// let s = System::new_all();
+    /// println!("limits: {:?}", s.cgroup_limits());
     /// ```
-    pub fn host_name(&self) -> Option<String> {
-        self.inner.host_name()
+    pub fn cgroup_limits(&self) -> Option<CGroupLimits> {
+        self.inner.cgroup_limits()
     }
-}
-
-/// Struct containing information of a process.
-///
-/// ## iOS
-///
-/// This information cannot be retrieved on iOS due to sandboxing.
-///
-/// ## Apple app store
-///
-/// If you are building a macOS Apple app store, it won't be able
-/// to retrieve this information.
-///
-/// ```no_run
-/// use sysinfo::{Pid, System};
-///
-/// let s = System::new_all();
-/// if let Some(process) = s.process(Pid::from(1337)) {
-///     println!("{}", process.name());
-/// }
-/// ```
-pub struct Process {
-    pub(crate) inner: ProcessInner,
-}
 
-impl Process {
-    /// Sends [`Signal::Kill`] to the process (which is the only signal supported on all supported
-    /// platforms by this crate).
+    /// Makes the memory and CPU getters take the limits of the current cgroup (see
+    /// [`System::cgroup_limits`]) into account when they are lower than the host capacity. It
+    /// is disabled by default.
     ///
-    /// If you want to send another signal, take a look at [`Process::kill_with`].
+    /// The affected getters are:
+    ///  * [`System::total_memory`], [`System::free_memory`], [`System::available_memory`] and
+    ///    [`System::used_memory`], computed from the memory limit and usage of the cgroup.
+    ///  * [`System::free_swap`] and [`System::used_swap`], computed from the swap usage of the
+    ///    cgroup. [`System::total_swap`] isn't limited.
+    ///  * [`System::physical_core_count`], limited by [`CGroupLimits::cpu_count`].
     ///
-    /// To get the list of the supported signals on this system, use
-    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    /// [`System::cpus`] still lists all the CPUs of the host, so use
+    /// [`System::physical_core_count`] to know how many of them can be used.
+    ///
+    /// It is useful for containerized services which size their thread pools or caches from
+    /// these values.
+    ///
+    /// The limits are read when enabling this mode, then every time the memory or the CPUs are
+    /// refreshed, so the getters don't read any file.
+    ///
+    /// ⚠️ The limits are only applied once [`refresh_memory`](System::refresh_memory) was run,
+    /// and only on Linux.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     process.kill();
-    /// }
+    /// let mut s = System::new_all();
+    /// s.set_use_cgroup_limits(true);
+    /// println!("{} bytes", s.total_memory());
+    /// println!("{:?} cores", s.physical_core_count());
     /// ```
-    pub fn kill(&self) -> bool {
-        self.kill_with(Signal::Kill).unwrap_or(false)
+    pub fn set_use_cgroup_limits(&mut self, enabled: bool) {
+        self.use_cgroup_limits = enabled;
+        self.refresh_cgroup_limits();
     }
 
-    /// Sends the given `signal` to the process. If the signal doesn't exist on this platform,
-    /// it'll do nothing and will return `None`. Otherwise it'll return if the signal was sent
-    /// successfully.
-    ///
-    /// If you just want to kill the process, use [`Process::kill`] directly.
-    ///
-    /// To get the list of the supported signals on this system, use
-    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    fn refresh_cgroup_limits(&mut self) {
+        // `cgroup_limits` requires the memory to be refreshed.
+        self.applied_cgroup_limits = if self.use_cgroup_limits && self.inner.total_memory() != 0 {
+            self.cgroup_limits()
+        } else {
+            None
+        };
+    }
+
+    /// Returns system uptime (in seconds).
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, Signal, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     if process.kill_with(Signal::Kill).is_none() {
-    ///         eprintln!("This signal isn't supported on this platform");
-    ///     }
-    /// }
+    /// println!("System running since {} seconds", s.uptime());
     /// ```
-    pub fn kill_with(&self, signal: Signal) -> Option<bool> {
-        self.inner.kill_with(signal)
+    pub fn uptime(&self) -> u64 {
+        self.inner.uptime()
     }
 
-    /// Returns the name of the process.
+    /// Returns system uptime, with sub-second precision.
     ///
-    /// **⚠️ Important ⚠️**
+    /// It is read from a monotonic clock, so it isn't affected by the adjustments of the system
+    /// clock. The time spent suspended is included:
     ///
-    /// On **Linux**, there are two things to know about processes' name:
-    ///  1. It is limited to 15 characters.
-    ///  2. It is not always the exe name.
+    /// | OS | Source |
+    /// |---|---|
+    /// | Linux | `CLOCK_BOOTTIME` |
+    /// | macOS, iOS | `CLOCK_MONOTONIC` (`mach_continuous_time`) |
+    /// | FreeBSD | Time elapsed since `kern.boottime`, which the kernel adjusts on resume |
+    /// | Windows | `GetTickCount64` (millisecond precision) |
     ///
-    /// If you are looking for a specific process, unless you know what you are
-    /// doing, in most cases it's better to use [`Process::exe`] instead (which
-    /// can be empty sometimes!).
+    /// It returns a zero duration if it cannot be retrieved.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}", process.name());
-    /// }
+    /// let s = System::new();
+    /// println!("System running since {:?}", s.uptime_duration());
     /// ```
-    pub fn name(&self) -> &str {
-        self.inner.name()
+    pub fn uptime_duration(&self) -> Duration {
+        self.inner.uptime_duration()
     }
 
-    /// Returns the command line.
+    /// Returns the time (in seconds) when the system booted since UNIX epoch.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.cmd());
-    /// }
+    /// let s = System::new();
+    /// println!("System booted at {} seconds", s.boot_time());
     /// ```
-    pub fn cmd(&self) -> &[String] {
-        self.inner.cmd()
+    pub fn boot_time(&self) -> u64 {
+        self.inner.boot_time()
     }
 
-    /// Returns the path to the process.
+    /// Returns the time when the system booted, with sub-second precision.
     ///
-    /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// Unlike [`System::boot_time`], it is not truncated to the second: it is computed from the
+    /// current time and a monotonic clock which keeps running while the system is suspended.
+    /// It returns [`UNIX_EPOCH`] if it cannot be retrieved.
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}", process.exe().display());
-    /// }
-    /// ```
+    /// **Important**: this information is computed every time this function is called, so it
+    /// follows the adjustments of the system clock.
     ///
-    /// ### Implementation notes
+    /// ```no_run
+    /// use sysinfo::System;
     ///
-    /// On Linux, this method will return an empty path if there
-    /// was an error trying to read `/proc/<pid>/exe`. This can
-    /// happen, for example, if the permission levels or UID namespaces
-    /// between the caller and target processes are different.
+    /// let s = System::new();
+    /// println!("System booted at {:?}", s.boot_time_sys());
+    /// ```
     ///
-    /// It is also the case that `cmd[0]` is _not_ usually a correct
-    /// replacement for this.
-    /// A process [may change its `cmd[0]` value](https://man7.org/linux/man-pages/man5/proc.5.html)
-    /// freely, making this an untrustworthy source of information.
-    pub fn exe(&self) -> &Path {
-        self.inner.exe()
+    /// [`UNIX_EPOCH`]: std::time::UNIX_EPOCH
+    pub fn boot_time_sys(&self) -> SystemTime {
+        self.inner.boot_time_sys()
     }
 
-    /// Returns the PID of the process.
+    /// Returns the number of processes, threads and open handles of the whole system.
+    ///
+    /// They are read from cheap global counters, without listing the processes, so they don't
+    /// depend on [`System::processes`]. Counters which are not available are `None`.
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}", process.pid());
-    /// }
+    /// let s = System::new();
+    /// let counts = s.counts();
+    /// println!("{:?} processes, {:?} threads", counts.processes, counts.threads);
     /// ```
-    pub fn pid(&self) -> Pid {
-        self.inner.pid()
+    pub fn counts(&self) -> SystemCounts {
+        self.inner.counts()
     }
 
-    /// Returns the environment variables of the process.
+    /// Returns the number of file handles allocated by the kernel and their system-wide limit.
+    ///
+    /// Reaching the limit makes opening files fail (with "too many open files" errors) in all
+    /// processes. It is `fs.file-nr` on Linux, `kern.openfiles` and `kern.maxfiles` on FreeBSD,
+    /// `kern.num_files` and `kern.maxfiles` on macOS, and the number of handles on Windows.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ Windows has no system-wide limit, so it is always `None` there.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.environ());
+    /// let s = System::new();
+    /// if let Some(handles) = s.file_handles() {
+    ///     println!("{} allocated, {:?}% of the limit", handles.allocated, handles.usage());
     /// }
     /// ```
-    pub fn environ(&self) -> &[String] {
-        self.inner.environ()
+    pub fn file_handles(&self) -> Option<FileHandles> {
+        self.inner.file_handles()
     }
 
-    /// Returns the current working directory.
+    /// Returns the state of the kernel random number generator.
+    ///
+    /// Until it is initialized, reading random data blocks, which can stall TLS handshakes on
+    /// headless virtual machines with few entropy sources.
+    ///
+    /// | OS      | Readiness                              | Entropy                                |
+    /// |---------|----------------------------------------|----------------------------------------|
+    /// | Linux   | non-blocking `getrandom`               | `/proc/sys/kernel/random/entropy_avail` |
+    /// | FreeBSD | non-blocking `getrandom`               | -                                      |
+    /// | macOS   | always ready (seeded before userland)  | -                                      |
+    /// | Windows | `BCryptGenRandom` with the system RNG  | -                                      |
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ Since Linux 5.18, the entropy is always reported as full once the generator is ready.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}", process.cwd().display());
+    /// let s = System::new();
+    /// if let Some(entropy) = s.entropy() {
+    ///     println!("ready: {}, available: {:?} bits", entropy.ready, entropy.available);
     /// }
     /// ```
-    pub fn cwd(&self) -> &Path {
-        self.inner.cwd()
+    pub fn entropy(&self) -> Option<Entropy> {
+        self.inner.entropy()
     }
 
-    /// Returns the path of the root directory.
+    /// Returns the system load average value.
+    ///
+    /// ⚠️ This is currently not working on **Windows**.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}", process.root().display());
-    /// }
-    /// ```
-    pub fn root(&self) -> &Path {
-        self.inner.root()
+    /// let load_avg = s.load_average();
+    /// println!(
+    ///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
+    ///     load_avg.one,
+    ///     load_avg.five,
+    ///     load_avg.fifteen,
+    /// );
+    /// ```
+    pub fn load_average(&self) -> LoadAvg {
+        self.inner.load_average()
     }
 
-    /// Returns the memory usage (in bytes).
+    /// Returns the Pressure Stall Information (PSI) of the system, which tells how much time
+    /// tasks were stalled waiting for the CPU, the memory or the I/O.
     ///
-    /// This method returns the [size of the resident set], that is, the amount of memory that the
-    /// process allocated and which is currently mapped in physical RAM. It does not include memory
-    /// that is swapped out, or, in some operating systems, that has been allocated but never used.
+    /// This information is computed every time the method is called.
     ///
-    /// Thus, it represents exactly the amount of physical RAM that the process is using at the
-    /// present time, but it might not be a good indicator of the total memory that the process will
-    /// be using over its lifetime. For that purpose, you can try and use
-    /// [`virtual_memory`](Process::virtual_memory).
+    /// ⚠️ This method is only implemented for Linux (4.20 and later, with PSI enabled). It always
+    /// returns `None` for all other systems.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{} bytes", process.memory());
+    /// let s = System::new();
+    /// if let Some(pressure) = s.pressure() {
+    ///     println!(
+    ///         "some tasks were waiting for memory {}% of the last 10 seconds",
+    ///         pressure.memory.some.avg10,
+    ///     );
     /// }
     /// ```
-    ///
-    /// [size of the resident set]: https://en.wikipedia.org/wiki/Resident_set_size
-    pub fn memory(&self) -> u64 {
-        self.inner.memory()
+    pub fn pressure(&self) -> Option<Pressure> {
+        self.inner.pressure()
     }
 
-    /// Returns the virtual memory usage (in bytes).
-    ///
-    /// This method returns the [size of virtual memory], that is, the amount of memory that the
-    /// process can access, whether it is currently mapped in physical RAM or not. It includes
-    /// physical RAM, allocated but not used regions, swapped-out regions, and even memory
-    /// associated with [memory-mapped files](https://en.wikipedia.org/wiki/Memory-mapped_file).
+    /// Returns the system name.
     ///
-    /// This value has limitations though. Depending on the operating system and type of process,
-    /// this value might be a good indicator of the total memory that the process will be using over
-    /// its lifetime. However, for example, in the version 14 of MacOS this value is in the order of
-    /// the hundreds of gigabytes for every process, and thus not very informative. Moreover, if a
-    /// process maps into memory a very large file, this value will increase accordingly, even if
-    /// the process is not actively using the memory.
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{} bytes", process.virtual_memory());
-    /// }
+    /// let s = System::new();
+    /// println!("OS: {:?}", s.name());
     /// ```
-    ///
-    /// [size of virtual memory]: https://en.wikipedia.org/wiki/Virtual_memory
-    pub fn virtual_memory(&self) -> u64 {
-        self.inner.virtual_memory()
+    pub fn name(&self) -> Option<String> {
+        self.inner.name()
     }
 
-    /// Returns the parent PID.
+    /// Returns the system's kernel version.
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.parent());
-    /// }
+    /// let s = System::new();
+    /// println!("kernel version: {:?}", s.kernel_version());
     /// ```
-    pub fn parent(&self) -> Option<Pid> {
-        self.inner.parent()
+    pub fn kernel_version(&self) -> Option<String> {
+        self.inner.kernel_version()
     }
 
-    /// Returns the status of the process.
+    /// Returns the system version (e.g. for MacOS this will return 11.1 rather than the kernel version).
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.status());
-    /// }
+    /// let s = System::new();
+    /// println!("OS version: {:?}", s.os_version());
     /// ```
-    pub fn status(&self) -> ProcessStatus {
-        self.inner.status()
+    pub fn os_version(&self) -> Option<String> {
+        self.inner.os_version()
     }
 
-    /// Returns the time where the process was started (in seconds) from epoch.
+    /// Returns the system long os version (e.g "MacOS 11.2 BigSur").
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Started at {} seconds", process.start_time());
-    /// }
+    /// let s = System::new();
+    /// println!("Long OS Version: {:?}", s.long_os_version());
     /// ```
-    pub fn start_time(&self) -> u64 {
-        self.inner.start_time()
+    pub fn long_os_version(&self) -> Option<String> {
+        self.inner.long_os_version()
     }
 
-    /// Returns for how much time the process has been running (in seconds).
+    /// Returns the architecture of the kernel (like `x86_64` or `aarch64`).
+    ///
+    /// Names are the same as [`std::env::consts::ARCH`]. It is the native architecture, even if
+    /// the current process is emulated (like x86_64 processes on Windows ARM64 or under Rosetta
+    /// on macOS).
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Running since {} seconds", process.run_time());
-    /// }
+    /// let s = System::new();
+    /// println!("kernel architecture: {:?}", s.kernel_arch());
     /// ```
-    pub fn run_time(&self) -> u64 {
-        self.inner.run_time()
+    pub fn kernel_arch(&self) -> Option<String> {
+        self.inner.kernel_arch()
     }
 
-    /// Returns the total CPU usage (in %). Notice that it might be bigger than
-    /// 100 if run on a multi-core machine.
-    ///
-    /// If you want a value between 0% and 100%, divide the returned value by
-    /// the number of CPUs.
+    /// Returns the architecture of the system userland (like `x86` for a 32-bit userland running
+    /// on a 64-bit kernel).
     ///
-    /// ⚠️ To start to have accurate CPU usage, a process needs to be refreshed
-    /// **twice** because CPU usage computation is based on time diff (process
-    /// time on a given time period divided by total system time on the same
-    /// time period).
+    /// Names are the same as [`std::env::consts::ARCH`]. On Linux and FreeBSD, it is the
+    /// architecture of `/bin/sh`. On other systems, it is the same as [`System::kernel_arch`].
     ///
-    /// ⚠️ If you want accurate CPU usage number, better leave a bit of time
-    /// between two calls of this method (take a look at
-    /// [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL] for
-    /// more information).
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}%", process.cpu_usage());
-    /// }
+    /// let s = System::new();
+    /// println!("userland architecture: {:?}", s.userland_arch());
     /// ```
-    pub fn cpu_usage(&self) -> f32 {
-        self.inner.cpu_usage()
+    pub fn userland_arch(&self) -> Option<String> {
+        self.inner.userland_arch()
     }
 
-    /// Returns number of bytes read and written to disk.
+    /// Returns the architecture the current process was compiled for (like `x86_64`).
     ///
-    /// ⚠️ On Windows and FreeBSD, this method actually returns **ALL** I/O
-    /// read and written bytes.
+    /// It is [`std::env::consts::ARCH`].
     ///
-    /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// ```
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     let disk_usage = process.disk_usage();
-    ///     println!("read bytes   : new/total => {}/{}",
-    ///         disk_usage.read_bytes,
-    ///         disk_usage.total_read_bytes,
-    ///     );
-    ///     println!("written bytes: new/total => {}/{}",
-    ///         disk_usage.written_bytes,
-    ///         disk_usage.total_written_bytes,
-    ///     );
-    /// }
+    /// println!("process architecture: {}", System::process_arch());
     /// ```
-    pub fn disk_usage(&self) -> DiskUsage {
-        self.inner.disk_usage()
+    pub fn process_arch() -> &'static str {
+        std::env::consts::ARCH
     }
 
-    /// Returns the ID of the owner user of this process or `None` if this
-    /// information couldn't be retrieved. If you want to get the [`User`] from
-    /// it, take a look at [`Users::get_user_by_id`].
+    /// Returns the pointer width of the current process in bits (like `64`).
     ///
-    /// ```no_run
-    /// use sysinfo::{Pid, System};
-    ///
-    /// let mut s = System::new_all();
+    /// ```
+    /// use sysinfo::System;
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     eprintln!("User id for process 1337: {:?}", process.user_id());
-    /// }
+    /// assert_eq!(System::process_pointer_width() as usize, std::mem::size_of::<usize>() * 8);
     /// ```
-    pub fn user_id(&self) -> Option<&Uid> {
-        self.inner.user_id()
+    pub fn process_pointer_width() -> u32 {
+        usize::BITS
     }
 
-    /// Returns the user ID of the effective owner of this process or `None` if
-    /// this information couldn't be retrieved. If you want to get the [`User`]
-    /// from it, take a look at [`Users::get_user_by_id`].
+    /// Returns the detailed Windows version, including the display version (like `23H2`), the
+    /// update build revision and the edition.
     ///
-    /// If you run something with `sudo`, the real user ID of the launched
-    /// process will be the ID of the user you are logged in as but effective
-    /// user ID will be `0` (i-e root).
+    /// **Important**: this information is computed every time this function is called.
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// ⚠️ It always returns `None` on other systems.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
-    ///
-    /// let mut s = System::new_all();
+    /// use sysinfo::System;
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     eprintln!("User id for process 1337: {:?}", process.effective_user_id());
+    /// let s = System::new();
+    /// if let Some(version) = s.windows_version() {
+    ///     println!(
+    ///         "{}.{}.{}.{} ({:?})",
+    ///         version.major,
+    ///         version.minor,
+    ///         version.build,
+    ///         version.revision.unwrap_or(0),
+    ///         version.display_version,
+    ///     );
     /// }
     /// ```
-    pub fn effective_user_id(&self) -> Option<&Uid> {
-        self.inner.effective_user_id()
+    pub fn windows_version(&self) -> Option<WindowsVersion> {
+        self.inner.windows_version()
     }
 
-    /// Returns the process group ID of the process.
+    /// Returns the distribution id as defined by os-release,
+    /// or [`std::env::consts::OS`].
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// See also
+    /// - <https://www.freedesktop.org/software/systemd/man/os-release.html#ID=>
+    /// - <https://doc.rust-lang.org/std/env/consts/constant.OS.html>
     ///
-    /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// **Important**: this information is computed every time this function is called.
     ///
-    /// let mut s = System::new_all();
+    /// ```no_run
+    /// use sysinfo::System;
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     eprintln!("Group ID for process 1337: {:?}", process.group_id());
-    /// }
+    /// let s = System::new();
+    /// println!("Distribution ID: {:?}", s.distribution_id());
     /// ```
-    pub fn group_id(&self) -> Option<Gid> {
-        self.inner.group_id()
+    pub fn distribution_id(&self) -> String {
+        self.inner.distribution_id()
     }
 
-    /// Returns the effective group ID of the process.
+    /// Returns the content of the `os-release` file, or `None` if there is none.
     ///
-    /// If you run something with `sudo`, the real group ID of the launched
-    /// process will be the primary group ID you are logged in as but effective
-    /// group ID will be `0` (i-e root).
+    /// See <https://www.freedesktop.org/software/systemd/man/os-release.html>.
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// **Important**: this information is computed every time this function is called.
     ///
-    /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// ⚠️ It is only available on Linux and FreeBSD.
     ///
-    /// let mut s = System::new_all();
+    /// ```no_run
+    /// use sysinfo::System;
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     eprintln!("User id for process 1337: {:?}", process.effective_group_id());
+    /// let s = System::new();
+    /// if let Some(release) = s.os_release() {
+    ///     let is_debian_like = release.id.as_deref() == Some("debian")
+    ///         || release.id_like.iter().any(|id| id == "debian");
+    ///     println!("Debian-like: {is_debian_like}");
     /// }
     /// ```
-    pub fn effective_group_id(&self) -> Option<Gid> {
-        self.inner.effective_group_id()
+    pub fn os_release(&self) -> Option<OsRelease> {
+        self.inner.os_release()
     }
 
-    /// Wait for process termination.
+    /// Returns the system hostname based off DNS
     ///
-    /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// **Important**: this information is computed every time this function is called.
     ///
-    /// let mut s = System::new_all();
+    /// ```no_run
+    /// use sysinfo::System;
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     eprintln!("Waiting for pid 1337");
-    ///     process.wait();
-    ///     eprintln!("Pid 1337 exited");
-    /// }
+    /// let s = System::new();
+    /// println!("Hostname: {:?}", s.host_name());
     /// ```
-    pub fn wait(&self) {
-        self.inner.wait()
+    pub fn host_name(&self) -> Option<String> {
+        self.inner.host_name()
     }
 
-    /// Returns the session ID for the current process or `None` if it couldn't
-    /// be retrieved.
+    /// Returns the fully qualified domain name of the system (like `host.example.com`).
     ///
-    /// ⚠️ This information is computed every time this method is called.
+    /// On Unix systems, it is the canonical name of the host name, resolved with `getaddrinfo`
+    /// (so it depends on `/etc/hosts` and the DNS configuration). On Windows, it is the DNS host
+    /// name followed by the primary DNS suffix.
     ///
-    /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// **Important**: this information is computed every time this function is called.
     ///
-    /// let mut s = System::new_all();
+    /// ```no_run
+    /// use sysinfo::System;
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     eprintln!("Session ID for process 1337: {:?}", process.session_id());
-    /// }
+    /// let s = System::new();
+    /// println!("FQDN: {:?}", s.fqdn());
     /// ```
-    pub fn session_id(&self) -> Option<Pid> {
-        self.inner.session_id()
+    pub fn fqdn(&self) -> Option<String> {
+        self.inner.fqdn()
     }
 
-    /// Tasks run by this process.
+    /// Returns the DNS domain the system belongs to (like `example.com`), `None` if there is
+    /// none.
     ///
-    /// ⚠️ This method is only available on Linux.
-    #[cfg(all(
-        any(target_os = "linux", target_os = "android"),
-        not(feature = "unknown-ci")
-    ))]
-    pub fn tasks(&self) -> &HashMap<Pid, Process> {
-        &self.inner.tasks
+    /// On Unix systems, it is the fully qualified domain name without the host name. On Windows,
+    /// it is the primary DNS suffix, or the NetBIOS name of the Active Directory domain if the
+    /// computer is joined to one without it.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// println!("domain: {:?}", s.domain_name());
+    /// ```
+    pub fn domain_name(&self) -> Option<String> {
+        self.inner.domain_name()
     }
-}
 
-macro_rules! pid_decl {
-    ($typ:ty) => {
-        #[doc = include_str!("../md_doc/pid.md")]
-        #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-        #[repr(transparent)]
-        pub struct Pid(pub(crate) $typ);
+    /// Returns the workgroup of the system, `None` if it is joined to a domain.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ It always returns `None` on systems other than Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// println!("workgroup: {:?}", s.workgroup());
+    /// ```
+    pub fn workgroup(&self) -> Option<String> {
+        self.inner.workgroup()
+    }
 
-        impl From<usize> for Pid {
-            fn from(v: usize) -> Self {
-                Self(v as _)
-            }
-        }
-        impl From<Pid> for usize {
-            fn from(v: Pid) -> Self {
-                v.0 as _
-            }
-        }
-        impl FromStr for Pid {
-            type Err = <$typ as FromStr>::Err;
-            fn from_str(s: &str) -> Result<Self, Self::Err> {
-                Ok(Self(<$typ>::from_str(s)?))
-            }
-        }
-        impl fmt::Display for Pid {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(f, "{}", self.0)
-            }
-        }
-        impl Pid {
-            /// Allows to convert [`Pid`][crate::Pid] into [`u32`].
-            ///
-            /// ```
-            /// use sysinfo::Pid;
-            ///
-            /// let pid = Pid::from_u32(0);
-            /// let value: u32 = pid.as_u32();
-            /// ```
-            pub fn as_u32(self) -> u32 {
-                self.0 as _
-            }
-            /// Allows to convert a [`u32`] into [`Pid`][crate::Pid].
-            ///
-            /// ```
-            /// use sysinfo::Pid;
-            ///
-            /// let pid = Pid::from_u32(0);
-            /// ```
-            pub fn from_u32(v: u32) -> Self {
-                Self(v as _)
-            }
-        }
-    };
-}
+    /// Returns information about the hardware of the system (manufacturer, motherboard,
+    /// firmware...), as provided by the firmware.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// The chassis comes from the SMBIOS "System Enclosure" table, from the model on macOS, and
+    /// is [`ChassisKind::VirtualMachine`] when a hypervisor is detected.
+    ///
+    /// ⚠️ On Linux, reading the serial numbers requires root privileges. On macOS, the
+    /// motherboard serial number is not available, nor is the firmware release date on Apple
+    /// Silicon. Nothing is returned on iOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// let info = s.hardware_info();
+    /// println!("{} {} (BIOS {})", info.manufacturer, info.product_name, info.bios_version);
+    /// ```
+    pub fn hardware_info(&self) -> HardwareInfo {
+        self.inner.hardware_info()
+    }
 
-cfg_if::cfg_if! {
-    if #[cfg(all(
-        not(feature = "unknown-ci"),
-        any(
-            target_os = "freebsd",
-            target_os = "linux",
-            target_os = "android",
-            target_os = "macos",
-            target_os = "ios",
-        )
-    ))] {
-        use libc::pid_t;
+    /// Returns an identifier of the machine which doesn't change across reboots, which can be
+    /// used to tell machines apart.
+    ///
+    /// It comes from `/etc/machine-id` on Linux (falling back to the SMBIOS UUID), from the
+    /// `MachineGuid` registry value on Windows, from the `IOPlatformUUID` on macOS and from
+    /// `kern.hostuuid` on FreeBSD.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ Cloned virtual machines or containers may share the same identifier. It always returns
+    /// `None` on iOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// println!("Machine ID: {:?}", s.machine_id());
+    /// ```
+    pub fn machine_id(&self) -> Option<String> {
+        self.inner.machine_id()
+    }
 
-        pid_decl!(pid_t);
-    } else {
-        pid_decl!(usize);
+    /// Returns the hypervisor and the container runtime the system is running in, if any.
+    ///
+    /// The hypervisor is detected with the `cpuid` hypervisor leaves on x86, and with the
+    /// SMBIOS information otherwise. The container runtime is detected by looking at the files
+    /// created by the runtimes and at the control groups of the init process.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ Containers are only detected on Linux (and jails on FreeBSD). On Windows, Hyper-V isn't
+    /// reported when the system runs in the root partition (when virtualization-based security
+    /// or WSL are enabled for example), since it is then the host.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// let virtualization = s.virtualization();
+    /// if let Some(hypervisor) = virtualization.hypervisor {
+    ///     println!("Running on {hypervisor}");
+    /// }
+    /// if let Some(container) = virtualization.container {
+    ///     println!("Running in a {container} container");
+    /// }
+    /// ```
+    pub fn virtualization(&self) -> Virtualization {
+        self.inner.virtualization()
     }
-}
 
-macro_rules! impl_get_set {
-    ($ty_name:ident, $name:ident, $with:ident, $without:ident $(, $extra_doc:literal)? $(,)?) => {
-        #[doc = concat!("Returns the value of the \"", stringify!($name), "\" refresh kind.")]
-        $(#[doc = concat!("
-", $extra_doc, "
-")])?
-        #[doc = concat!("
-```
-use sysinfo::", stringify!($ty_name), ";
+    /// Returns the value of the given kernel parameter, or `None` if it doesn't exist or can't
+    /// be read.
+    ///
+    /// The parameters are read-only. Depending on the system, the name is:
+    ///
+    /// | OS | Name |
+    /// |---|---|
+    /// | Linux | Path relative to `/proc/sys`, with either `.` or `/` as separator (like `vm.swappiness` or `fs/file-max`) |
+    /// | FreeBSD, macOS, iOS | sysctl name (like `kern.maxfiles`) |
+    /// | Windows | Registry value path relative to `HKEY_LOCAL_MACHINE\SYSTEM\CurrentControlSet` (like `Services\Tcpip\Parameters\DefaultTTL`) |
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// Only integer and text values are supported: sysctl nodes and opaque values return `None`.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// if let Some(swappiness) = s.kernel_parameter("vm.swappiness") {
+    ///     println!("swappiness: {swappiness}");
+    /// }
+    /// ```
+    pub fn kernel_parameter(&self, name: &str) -> Option<KernelParameter> {
+        self.inner.kernel_parameter(name)
+    }
 
-let r = ", stringify!($ty_name), "::new();
-assert_eq!(r.", stringify!($name), "(), false);
+    /// Returns the configured time zone and its current offset from UTC.
+    ///
+    /// On Unix systems, the `TZ` environment variable takes precedence over the system
+    /// configuration (`/etc/localtime`).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ On Windows, the name is the Windows time zone ID (like `Romance Standard Time`) and not
+    /// the IANA name.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let time_zone = System::time_zone();
+    /// println!("{:?} (UTC{:+}s)", time_zone.name, time_zone.utc_offset);
+    /// ```
+    pub fn time_zone() -> TimeZone {
+        crate::sys::get_time_zone()
+    }
 
-let r = r.with_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "(), true);
+    /// Returns the locale of the system (like `en_US.UTF-8`, or `en-US` on Windows).
+    ///
+    /// On Unix systems, it comes from the `LC_ALL` and `LANG` environment variables and, on
+    /// Linux, falls back to the system configuration (`/etc/locale.conf`). On Windows, it is the
+    /// default locale of the current user.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("locale: {:?}", System::locale());
+    /// ```
+    pub fn locale() -> Option<String> {
+        crate::sys::get_locale()
+    }
 
-let r = r.without_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "(), false);
-```")]
-        pub fn $name(&self) -> bool {
-            self.$name
+    /// Returns a snapshot of the system's information, holding what was retrieved by the last
+    /// refreshes.
+    ///
+    /// The snapshot doesn't depend on the system anymore: it can be kept to compare it with
+    /// later states or, with the `serde` feature, be saved and loaded again (on any system) for
+    /// offline analysis. Its getters have the same names as the ones of `System`, `Process` and
+    /// `Cpu`.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// let snapshot = s.to_snapshot();
+    ///
+    /// for (pid, process) in snapshot.processes() {
+    ///     println!("[{}] {} {:?}", pid, process.name(), process.disk_usage());
+    /// }
+    /// ```
+    pub fn to_snapshot(&self) -> SystemSnapshot {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|timestamp| timestamp.as_secs())
+            .unwrap_or(0);
+
+        SystemSnapshot {
+            version: SystemSnapshot::VERSION,
+            timestamp,
+            name: self.name(),
+            kernel_version: self.kernel_version(),
+            os_version: self.os_version(),
+            long_os_version: self.long_os_version(),
+            host_name: self.host_name(),
+            uptime: self.uptime(),
+            boot_time: self.boot_time(),
+            load_average: self.load_average(),
+            total_memory: self.total_memory(),
+            free_memory: self.free_memory(),
+            available_memory: self.available_memory(),
+            used_memory: self.used_memory(),
+            total_swap: self.total_swap(),
+            free_swap: self.free_swap(),
+            used_swap: self.used_swap(),
+            physical_core_count: self.physical_core_count(),
+            global_cpu_info: CpuSnapshot::new(self.global_cpu_info()),
+            cpus: self.cpus().iter().map(CpuSnapshot::new).collect(),
+            processes: self
+                .processes()
+                .iter()
+                .map(|(pid, process)| (*pid, ProcessSnapshot::new(process)))
+                .collect(),
         }
+    }
+}
 
-        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `true`.
-
-```
-use sysinfo::", stringify!($ty_name), ";
-
-let r = ", stringify!($ty_name), "::new();
-assert_eq!(r.", stringify!($name), "(), false);
+/// Exponential moving averages of the CPU usage values, used by
+/// [`System::set_cpu_usage_smoothing`].
+struct CpuUsageSmoothing {
+    /// Weight of the new value in the average.
+    factor: f32,
+    global_cpu: Option<f32>,
+    cpus: Vec<f32>,
+    /// Start time, CPU usage and normalized CPU usage of each process. The start time is used
+    /// to detect when a PID is reused.
+    processes: HashMap<Pid, (u64, f32, f32)>,
+}
 
-let r = r.with_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "(), true);
-```")]
-        #[must_use]
-        pub fn $with(mut self) -> Self {
-            self.$name = true;
-            self
+impl CpuUsageSmoothing {
+    fn new(samples: usize) -> Self {
+        Self {
+            factor: 2. / (samples as f32 + 1.),
+            global_cpu: None,
+            cpus: Vec::new(),
+            processes: HashMap::new(),
         }
+    }
 
-        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `false`.
-
-```
-use sysinfo::", stringify!($ty_name), ";
-
-let r = ", stringify!($ty_name), "::everything();
-assert_eq!(r.", stringify!($name), "(), true);
-
-let r = r.without_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "(), false);
-```")]
-        #[must_use]
-        pub fn $without(mut self) -> Self {
-            self.$name = false;
-            self
+    fn smooth(&self, previous: Option<f32>, value: f32) -> f32 {
+        match previous {
+            Some(previous) => previous + self.factor * (value - previous),
+            None => value,
         }
-    };
-
-    ($ty_name:ident, $name:ident, $with:ident, $without:ident, $typ:ty $(,)?) => {
-        #[doc = concat!("Returns the value of the \"", stringify!($name), "\" refresh kind.
-
-```
-use sysinfo::{", stringify!($ty_name), ", ", stringify!($typ), "};
+    }
 
-let r = ", stringify!($ty_name), "::new();
-assert_eq!(r.", stringify!($name), "().is_some(), false);
+    fn update_global_cpu(&mut self, inner: &mut SystemInner) {
+        let global_cpu = inner.global_cpu_info_mut();
+        let value = self.smooth(self.global_cpu, global_cpu.inner.cpu_usage());
+        global_cpu.inner.set_cpu_usage(value);
+        self.global_cpu = Some(value);
+    }
 
-let r = r.with_", stringify!($name), "(", stringify!($typ), "::everything());
-assert_eq!(r.", stringify!($name), "().is_some(), true);
+    fn update_cpus(&mut self, inner: &mut SystemInner) {
+        self.update_global_cpu(inner);
 
-let r = r.without_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "().is_some(), false);
-```")]
-        pub fn $name(&self) -> Option<$typ> {
-            self.$name
+        let cpus = inner.cpus_mut();
+        self.cpus.truncate(cpus.len());
+        for (pos, cpu) in cpus.iter_mut().enumerate() {
+            let value = self.smooth(self.cpus.get(pos).copied(), cpu.inner.cpu_usage());
+            cpu.inner.set_cpu_usage(value);
+            match self.cpus.get_mut(pos) {
+                Some(previous) => *previous = value,
+                None => self.cpus.push(value),
+            }
         }
+    }
 
-        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `true`.
+    fn update_processes(&mut self, processes: &mut HashMap<Pid, Process>) {
+        self.processes.retain(|pid, _| processes.contains_key(pid));
+        for (pid, process) in processes.iter_mut() {
+            self.update_process(*pid, process);
+        }
+    }
 
-```
-use sysinfo::{", stringify!($ty_name), ", ", stringify!($typ), "};
-
-let r = ", stringify!($ty_name), "::new();
-assert_eq!(r.", stringify!($name), "().is_some(), false);
-
-let r = r.with_", stringify!($name), "(", stringify!($typ), "::everything());
-assert_eq!(r.", stringify!($name), "().is_some(), true);
-```")]
-        #[must_use]
-        pub fn $with(mut self, kind: $typ) -> Self {
-            self.$name = Some(kind);
-            self
-        }
-
-        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `false`.
-
-```
-use sysinfo::", stringify!($ty_name), ";
-
-let r = ", stringify!($ty_name), "::everything();
-assert_eq!(r.", stringify!($name), "().is_some(), true);
-
-let r = r.without_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "().is_some(), false);
-```")]
-        #[must_use]
-        pub fn $without(mut self) -> Self {
-            self.$name = None;
-            self
-        }
-    };
+    fn update_process(&mut self, pid: Pid, process: &mut Process) {
+        let start_time = process.start_time();
+        let (cpu_usage, normalized_cpu_usage) = match self.processes.get(&pid) {
+            Some(&(previous_start_time, cpu_usage, normalized_cpu_usage))
+                if previous_start_time == start_time =>
+            {
+                (
+                    self.smooth(Some(cpu_usage), process.cpu_usage()),
+                    self.smooth(Some(normalized_cpu_usage), process.normalized_cpu_usage()),
+                )
+            }
+            _ => (process.cpu_usage(), process.normalized_cpu_usage()),
+        };
+        process.inner.set_cpu_usage(cpu_usage, normalized_cpu_usage);
+        self.processes
+            .insert(pid, (start_time, cpu_usage, normalized_cpu_usage));
+    }
 }
 
-/// Used to determine what you want to refresh specifically on the [`Process`] type.
+/// Struct containing information of a process.
 ///
-/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
-/// the information won't be retrieved if the information is accessible without needing
-/// extra computation.
+/// ## iOS
 ///
-/// ```
-/// use sysinfo::{ProcessRefreshKind, System};
+/// This information cannot be retrieved on iOS due to sandboxing.
 ///
-/// let mut system = System::new();
+/// ## Apple app store
 ///
-/// // We don't want to update the CPU information.
-/// system.refresh_processes_specifics(ProcessRefreshKind::everything().without_cpu());
+/// If you are building a macOS Apple app store, it won't be able
+/// to retrieve this information.
 ///
-/// for (_, proc_) in system.processes() {
-///     // We use a `==` comparison on float only because we know it's set to 0 here.
-///     assert_eq!(proc_.cpu_usage(), 0.);
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     println!("{}", process.name());
 /// }
 /// ```
-///
-/// [`Process`]: crate::Process
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub struct ProcessRefreshKind {
-    cpu: bool,
-    disk_usage: bool,
-    user: bool,
+pub struct Process {
+    pub(crate) inner: ProcessInner,
 }
 
-impl ProcessRefreshKind {
-    /// Creates a new `ProcessRefreshKind` with every refresh set to `false`.
+impl Process {
+    /// Retrieves the process of `child`, which was spawned by the current process.
     ///
-    /// ```
-    /// use sysinfo::ProcessRefreshKind;
+    /// The returned process isn't part of a [`System`], so it can't be refreshed: use
+    /// [`System::refresh_child`] to follow its usage over time. Like with `refresh_child`,
+    /// `None` is returned if the PID of `child` was reused by another process.
     ///
-    /// let r = ProcessRefreshKind::new();
+    /// ⚠️ The CPU usage needs two refreshes to be computed, so it is always `0`.
     ///
-    /// assert_eq!(r.cpu(), false);
-    /// assert_eq!(r.disk_usage(), false);
+    /// ```no_run
+    /// use sysinfo::Process;
+    /// use std::process::Command;
+    ///
+    /// let child = Command::new("sleep").arg("10").spawn().unwrap();
+    /// if let Some(process) = Process::from_child(&child) {
+    ///     println!("{:?} uses {} bytes", process.cmd(), process.memory());
+    /// }
     /// ```
-    pub fn new() -> Self {
-        Self::default()
+    pub fn from_child(child: &std::process::Child) -> Option<Process> {
+        let mut s = System::new();
+        let pid = s.refresh_child(child)?.pid();
+        s.inner.processes_mut().remove(&pid)
     }
 
-    /// Creates a new `ProcessRefreshKind` with every refresh set to `true`.
+    /// Sends [`Signal::Kill`] to the process (which is the only signal supported on all supported
+    /// platforms by this crate).
     ///
-    /// ```
-    /// use sysinfo::ProcessRefreshKind;
+    /// If you want to send another signal, take a look at [`Process::kill_with`].
     ///
-    /// let r = ProcessRefreshKind::everything();
+    /// To get the list of the supported signals on this system, use
+    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
     ///
-    /// assert_eq!(r.cpu(), true);
-    /// assert_eq!(r.disk_usage(), true);
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     process.kill();
+    /// }
     /// ```
-    pub fn everything() -> Self {
-        Self {
-            cpu: true,
-            disk_usage: true,
-            user: true,
-        }
+    pub fn kill(&self) -> bool {
+        self.kill_with(Signal::Kill).unwrap_or(false)
     }
 
-    impl_get_set!(ProcessRefreshKind, cpu, with_cpu, without_cpu);
-    impl_get_set!(
-        ProcessRefreshKind,
-        disk_usage,
-        with_disk_usage,
-        without_disk_usage
-    );
-    impl_get_set!(
-        ProcessRefreshKind,
-        user,
-        with_user,
-        without_user,
-        r#"This refresh is about `user_id` and `group_id`. Please note that it has an effect mostly
-on Windows as other platforms get this information alongside the Process information directly."#,
-    );
-}
-
-/// Used to determine what you want to refresh specifically on the [`Cpu`] type.
-///
-/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
-/// the information won't be retrieved if the information is accessible without needing
-/// extra computation.
-///
-/// ```
-/// use sysinfo::{CpuRefreshKind, System};
-///
-/// let mut system = System::new();
-///
-/// // We don't want to update all the CPU information.
-/// system.refresh_cpu_specifics(CpuRefreshKind::everything().without_frequency());
-///
-/// for cpu in system.cpus() {
-///     assert_eq!(cpu.frequency(), 0);
-/// }
-/// ```
-///
-/// [`Cpu`]: crate::Cpu
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub struct CpuRefreshKind {
-    cpu_usage: bool,
-    frequency: bool,
-}
-
-impl CpuRefreshKind {
-    /// Creates a new `CpuRefreshKind` with every refresh set to `false`.
+    /// Sends the given `signal` to the process. If the signal doesn't exist on this platform,
+    /// it'll do nothing and will return `None`. Otherwise it'll return if the signal was sent
+    /// successfully.
     ///
-    /// ```
-    /// use sysinfo::CpuRefreshKind;
+    /// If you just want to kill the process, use [`Process::kill`] directly.
     ///
-    /// let r = CpuRefreshKind::new();
+    /// To get the list of the supported signals on this system, use
+    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
     ///
-    /// assert_eq!(r.frequency(), false);
+    /// ```no_run
+    /// use sysinfo::{Pid, Signal, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if process.kill_with(Signal::Kill).is_none() {
+    ///         eprintln!("This signal isn't supported on this platform");
+    ///     }
+    /// }
     /// ```
-    pub fn new() -> Self {
-        Self::default()
+    pub fn kill_with(&self, signal: Signal) -> Option<bool> {
+        self.inner.kill_with(signal)
     }
 
-    /// Creates a new `CpuRefreshKind` with every refresh set to `true`.
+    /// Returns the name of the process.
     ///
-    /// ```
-    /// use sysinfo::CpuRefreshKind;
+    /// **⚠️ Important ⚠️**
     ///
-    /// let r = CpuRefreshKind::everything();
+    /// On **Linux**, there are two things to know about processes' name:
+    ///  1. It is limited to 15 characters.
+    ///  2. It is not always the exe name.
     ///
-    /// assert_eq!(r.frequency(), true);
+    /// If you are looking for a specific process, unless you know what you are
+    /// doing, in most cases it's better to use [`Process::exe`] instead (which
+    /// can be empty sometimes!).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.name());
+    /// }
     /// ```
-    pub fn everything() -> Self {
-        Self {
-            cpu_usage: true,
-            frequency: true,
-        }
+    pub fn name(&self) -> &str {
+        self.inner.name()
     }
 
-    impl_get_set!(CpuRefreshKind, cpu_usage, with_cpu_usage, without_cpu_usage);
-    impl_get_set!(CpuRefreshKind, frequency, with_frequency, without_frequency);
-}
-
-/// Used to determine what you want to refresh specifically on the [`System`][crate::System] type.
-///
-/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
-/// the information won't be retrieved if the information is accessible without needing
-/// extra computation.
-///
-/// ```
-/// use sysinfo::{RefreshKind, System};
-///
-/// // We want everything except memory.
-/// let mut system = System::new_with_specifics(RefreshKind::everything().without_memory());
-///
-/// assert_eq!(system.total_memory(), 0);
-/// # if sysinfo::IS_SUPPORTED && !cfg!(feature = "apple-sandbox") {
-/// assert!(system.processes().len() > 0);
-/// # }
-/// ```
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub struct RefreshKind {
-    processes: Option<ProcessRefreshKind>,
-    memory: bool,
-    cpu: Option<CpuRefreshKind>,
-}
-
-impl RefreshKind {
-    /// Creates a new `RefreshKind` with every refresh set to `false`/`None`.
-    ///
-    /// ```
-    /// use sysinfo::RefreshKind;
+    /// Returns the command line.
     ///
-    /// let r = RefreshKind::new();
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
     ///
-    /// assert_eq!(r.processes().is_some(), false);
-    /// assert_eq!(r.memory(), false);
-    /// assert_eq!(r.cpu().is_some(), false);
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.cmd());
+    /// }
     /// ```
-    pub fn new() -> Self {
-        Self::default()
+    pub fn cmd(&self) -> &[String] {
+        self.inner.cmd()
     }
 
-    /// Creates a new `RefreshKind` with every refresh set to `true`/`Some(...)`.
+    /// Returns the path to the process.
     ///
-    /// ```
-    /// use sysinfo::RefreshKind;
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
     ///
-    /// let r = RefreshKind::everything();
-    ///
-    /// assert_eq!(r.processes().is_some(), true);
-    /// assert_eq!(r.memory(), true);
-    /// assert_eq!(r.cpu().is_some(), true);
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.exe().display());
+    /// }
     /// ```
-    pub fn everything() -> Self {
-        Self {
-            processes: Some(ProcessRefreshKind::everything()),
-            memory: true,
-            cpu: Some(CpuRefreshKind::everything()),
-        }
-    }
-
-    impl_get_set!(
-        RefreshKind,
-        processes,
-        with_processes,
-        without_processes,
-        ProcessRefreshKind
-    );
-    impl_get_set!(RefreshKind, memory, with_memory, without_memory);
-    impl_get_set!(RefreshKind, cpu, with_cpu, without_cpu, CpuRefreshKind);
-}
-
-/// Interacting with network interfaces.
-///
-/// ```no_run
-/// use sysinfo::Networks;
-///
-/// let networks = Networks::new_with_refreshed_list();
-/// for (interface_name, network) in &networks {
-///     println!("[{interface_name}]: {network:?}");
-/// }
-/// ```
-pub struct Networks {
-    pub(crate) inner: NetworksInner,
-}
-
-impl<'a> IntoIterator for &'a Networks {
-    type Item = (&'a String, &'a NetworkData);
-    type IntoIter = std::collections::hash_map::Iter<'a, String, NetworkData>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
-    }
-}
-
-impl Default for Networks {
-    fn default() -> Self {
-        Networks::new()
+    ///
+    /// ### Implementation notes
+    ///
+    /// On Linux, this method will return an empty path if there
+    /// was an error trying to read `/proc/<pid>/exe`. This can
+    /// happen, for example, if the permission levels or UID namespaces
+    /// between the caller and target processes are different.
+    ///
+    /// It is also the case that `cmd[0]` is _not_ usually a correct
+    /// replacement for this.
+    /// A process [may change its `cmd[0]` value](https://man7.org/linux/man-pages/man5/proc.5.html)
+    /// freely, making this an untrustworthy source of information.
+    pub fn exe(&self) -> &Path {
+        self.inner.exe()
     }
-}
 
-impl Networks {
-    /// Creates a new empty [`Networks`][crate::Networks] type.
-    ///
-    /// If you want it to be filled directly, take a look at [`Networks::new_with_refreshed_list`].
+    /// Returns the PID of the process.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let mut networks = Networks::new();
-    /// networks.refresh_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("[{interface_name}]: {network:?}");
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.pid());
     /// }
     /// ```
-    pub fn new() -> Self {
-        Self {
-            inner: NetworksInner::new(),
-        }
+    pub fn pid(&self) -> Pid {
+        self.inner.pid()
     }
 
-    /// Creates a new [`Networks`][crate::Networks] type with the disk list
-    /// loaded. It is a combination of [`Networks::new`] and
-    /// [`Networks::refresh_list`].
+    /// Returns the environment variables of the process.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for network in &networks {
-    ///     eprintln!("{network:?}");
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.environ());
     /// }
     /// ```
-    pub fn new_with_refreshed_list() -> Self {
-        let mut networks = Self::new();
-        networks.refresh_list();
-        networks
+    pub fn environ(&self) -> &[String] {
+        self.inner.environ()
     }
 
-    /// Returns the network interfaces map.
+    /// Returns the current working directory.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for network in networks.list() {
-    ///     eprintln!("{network:?}");
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.cwd().display());
     /// }
     /// ```
-    pub fn list(&self) -> &HashMap<String, NetworkData> {
-        self.inner.list()
+    pub fn cwd(&self) -> &Path {
+        self.inner.cwd()
     }
 
-    /// Refreshes the network interfaces list.
+    /// Returns the path of the root directory.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let mut networks = Networks::new();
-    /// networks.refresh_list();
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.root().display());
+    /// }
     /// ```
-    pub fn refresh_list(&mut self) {
-        self.inner.refresh_list()
+    pub fn root(&self) -> &Path {
+        self.inner.root()
     }
 
-    /// Refreshes the network interfaces' content. If you didn't run [`Networks::refresh_list`]
-    /// before, calling this method won't do anything as no interfaces are present.
+    /// Returns the memory usage (in bytes).
     ///
-    /// ⚠️ If a user is added or removed, this method won't take it into account. Use
-    /// [`Networks::refresh_list`] instead.
+    /// This method returns the [size of the resident set], that is, the amount of memory that the
+    /// process allocated and which is currently mapped in physical RAM. It does not include memory
+    /// that is swapped out, or, in some operating systems, that has been allocated but never used.
     ///
-    /// ⚠️ If you didn't call [`Networks::refresh_list`] beforehand, this method will do nothing
-    /// as the network list will be empty.
+    /// Thus, it represents exactly the amount of physical RAM that the process is using at the
+    /// present time, but it might not be a good indicator of the total memory that the process will
+    /// be using over its lifetime. For that purpose, you can try and use
+    /// [`virtual_memory`](Process::virtual_memory).
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let mut networks = Networks::new_with_refreshed_list();
-    /// // Wait some time...? Then refresh the data of each network.
-    /// networks.refresh();
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} bytes", process.memory());
+    /// }
     /// ```
-    pub fn refresh(&mut self) {
-        self.inner.refresh()
-    }
-}
-
-impl std::ops::Deref for Networks {
-    type Target = HashMap<String, NetworkData>;
-
-    fn deref(&self) -> &Self::Target {
-        self.list()
+    ///
+    /// [size of the resident set]: https://en.wikipedia.org/wiki/Resident_set_size
+    pub fn memory(&self) -> u64 {
+        self.inner.memory()
     }
-}
-
-/// Getting volume of received and transmitted data.
-///
-/// ```no_run
-/// use sysinfo::Networks;
-///
-/// let networks = Networks::new_with_refreshed_list();
-/// for (interface_name, network) in &networks {
-///     println!("[{interface_name}] {network:?}");
-/// }
-/// ```
-pub struct NetworkData {
-    pub(crate) inner: NetworkDataInner,
-}
 
-impl NetworkData {
-    /// Returns the number of received bytes since the last refresh.
+    /// Returns the virtual memory usage (in bytes).
+    ///
+    /// This method returns the [size of virtual memory], that is, the amount of memory that the
+    /// process can access, whether it is currently mapped in physical RAM or not. It includes
+    /// physical RAM, allocated but not used regions, swapped-out regions, and even memory
+    /// associated with [memory-mapped files](https://en.wikipedia.org/wiki/Memory-mapped_file).
+    ///
+    /// This value has limitations though. Depending on the operating system and type of process,
+    /// this value might be a good indicator of the total memory that the process will be using over
+    /// its lifetime. However, for example, in the version 14 of MacOS this value is in the order of
+    /// the hundreds of gigabytes for every process, and thus not very informative. Moreover, if a
+    /// process maps into memory a very large file, this value will increase accordingly, even if
+    /// the process is not actively using the memory.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("in: {} B", network.received());
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} bytes", process.virtual_memory());
     /// }
     /// ```
-    pub fn received(&self) -> u64 {
-        self.inner.received()
+    ///
+    /// [size of virtual memory]: https://en.wikipedia.org/wiki/Virtual_memory
+    pub fn virtual_memory(&self) -> u64 {
+        self.inner.virtual_memory()
     }
 
-    /// Returns the total number of received bytes.
+    /// Returns the parent PID.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("in: {} B", network.total_received());
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.parent());
     /// }
     /// ```
-    pub fn total_received(&self) -> u64 {
-        self.inner.total_received()
+    pub fn parent(&self) -> Option<Pid> {
+        self.inner.parent()
     }
 
-    /// Returns the number of transmitted bytes since the last refresh.
+    /// Returns the status of the process.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("out: {} B", network.transmitted());
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.status());
     /// }
     /// ```
-    pub fn transmitted(&self) -> u64 {
-        self.inner.transmitted()
+    pub fn status(&self) -> ProcessStatus {
+        self.inner.status()
     }
 
-    /// Returns the total number of transmitted bytes.
+    /// Returns the time where the process was started (in seconds) from epoch.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("out: {} B", network.total_transmitted());
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Started at {} seconds", process.start_time());
     /// }
     /// ```
-    pub fn total_transmitted(&self) -> u64 {
-        self.inner.total_transmitted()
-    }
+    pub fn start_time(&self) -> u64 {
+        self.inner.start_time()
+    }
 
-    /// Returns the number of incoming packets since the last refresh.
+    /// Returns for how much time the process has been running (in seconds).
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("in: {}", network.packets_received());
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Running since {} seconds", process.run_time());
     /// }
     /// ```
-    pub fn packets_received(&self) -> u64 {
-        self.inner.packets_received()
+    pub fn run_time(&self) -> u64 {
+        self.inner.run_time()
     }
 
-    /// Returns the total number of incoming packets.
+    /// Returns the total CPU usage (in %). Notice that it might be bigger than
+    /// 100 if run on a multi-core machine.
+    ///
+    /// If you want a value between 0% and 100%, use [`Process::normalized_cpu_usage`].
+    ///
+    /// ⚠️ To start to have accurate CPU usage, a process needs to be refreshed
+    /// **twice** because CPU usage computation is based on time diff (process
+    /// time on a given time period divided by total system time on the same
+    /// time period).
+    ///
+    /// ⚠️ If you want accurate CPU usage number, better leave a bit of time
+    /// between two calls of this method (take a look at
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL] for
+    /// more information).
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("in: {}", network.total_packets_received());
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}%", process.cpu_usage());
     /// }
     /// ```
-    pub fn total_packets_received(&self) -> u64 {
-        self.inner.total_packets_received()
+    pub fn cpu_usage(&self) -> f32 {
+        self.inner.cpu_usage()
     }
 
-    /// Returns the number of outcoming packets since the last refresh.
+    /// Returns the CPU usage (in %) relative to the total capacity of the machine, so it is
+    /// always between 0 and 100, whatever the number of CPUs.
+    ///
+    /// It is the same as [`Process::cpu_usage`] divided by the number of CPUs, and the same
+    /// ⚠️ notes apply.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("out: {}", network.packets_transmitted());
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}%", process.normalized_cpu_usage());
     /// }
     /// ```
-    pub fn packets_transmitted(&self) -> u64 {
-        self.inner.packets_transmitted()
+    pub fn normalized_cpu_usage(&self) -> f32 {
+        self.inner.normalized_cpu_usage()
     }
 
-    /// Returns the total number of outcoming packets.
+    /// Returns the total amount of CPU time this process spent in user mode since it started.
+    ///
+    /// Unlike [`Process::cpu_usage`], this is an accumulated value and doesn't need the process
+    /// to be refreshed twice.
+    ///
+    /// ⚠️ On Windows, this value is only updated when the process CPU usage is refreshed.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("out: {}", network.total_packets_transmitted());
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.user_time());
     /// }
     /// ```
-    pub fn total_packets_transmitted(&self) -> u64 {
-        self.inner.total_packets_transmitted()
+    pub fn user_time(&self) -> Duration {
+        self.inner.user_time()
     }
 
-    /// Returns the number of incoming errors since the last refresh.
+    /// Returns the total amount of CPU time this process spent in kernel mode since it started.
+    ///
+    /// Unlike [`Process::cpu_usage`], this is an accumulated value and doesn't need the process
+    /// to be refreshed twice.
+    ///
+    /// ⚠️ On Windows, this value is only updated when the process CPU usage is refreshed.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("in: {}", network.errors_on_received());
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.kernel_time());
     /// }
     /// ```
-    pub fn errors_on_received(&self) -> u64 {
-        self.inner.errors_on_received()
+    pub fn kernel_time(&self) -> Duration {
+        self.inner.kernel_time()
     }
 
-    /// Returns the total number of incoming errors.
+    /// Returns number of bytes read and written to disk.
+    ///
+    /// ⚠️ On Windows and FreeBSD, this method actually returns **ALL** I/O
+    /// read and written bytes.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("in: {}", network.total_errors_on_received());
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     let disk_usage = process.disk_usage();
+    ///     println!("read bytes   : new/total => {}/{}",
+    ///         disk_usage.read_bytes,
+    ///         disk_usage.total_read_bytes,
+    ///     );
+    ///     println!("written bytes: new/total => {}/{}",
+    ///         disk_usage.written_bytes,
+    ///         disk_usage.total_written_bytes,
+    ///     );
     /// }
     /// ```
-    pub fn total_errors_on_received(&self) -> u64 {
-        self.inner.total_errors_on_received()
+    pub fn disk_usage(&self) -> DiskUsage {
+        self.inner.disk_usage()
     }
 
-    /// Returns the number of outcoming errors since the last refresh.
+    /// Returns the ID of the owner user of this process or `None` if this
+    /// information couldn't be retrieved. If you want to get the [`User`] from
+    /// it, take a look at [`Users::get_user_by_id`].
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("out: {}", network.errors_on_transmitted());
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     eprintln!("User id for process 1337: {:?}", process.user_id());
     /// }
     /// ```
-    pub fn errors_on_transmitted(&self) -> u64 {
-        self.inner.errors_on_transmitted()
+    pub fn user_id(&self) -> Option<&Uid> {
+        self.inner.user_id()
     }
 
-    /// Returns the total number of outcoming errors.
+    /// Returns the user ID of the effective owner of this process or `None` if
+    /// this information couldn't be retrieved. If you want to get the [`User`]
+    /// from it, take a look at [`Users::get_user_by_id`].
+    ///
+    /// If you run something with `sudo`, the real user ID of the launched
+    /// process will be the ID of the user you are logged in as but effective
+    /// user ID will be `0` (i-e root).
+    ///
+    /// ⚠️ It always returns `None` on Windows.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("out: {}", network.total_errors_on_transmitted());
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     eprintln!("User id for process 1337: {:?}", process.effective_user_id());
     /// }
     /// ```
-    pub fn total_errors_on_transmitted(&self) -> u64 {
-        self.inner.total_errors_on_transmitted()
+    pub fn effective_user_id(&self) -> Option<&Uid> {
+        self.inner.effective_user_id()
     }
 
-    /// Returns the MAC address associated to current interface.
+    /// Returns the process group ID of the process.
+    ///
+    /// ⚠️ It always returns `None` on Windows.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let mut networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("MAC address: {}", network.mac_address());
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     eprintln!("Group ID for process 1337: {:?}", process.group_id());
     /// }
     /// ```
-    pub fn mac_address(&self) -> MacAddr {
-        self.inner.mac_address()
+    pub fn group_id(&self) -> Option<Gid> {
+        self.inner.group_id()
     }
-}
-
-/// Struct containing a disk information.
-///
-/// ```no_run
-/// use sysinfo::Disks;
-///
-/// let disks = Disks::new_with_refreshed_list();
-/// for disk in disks.list() {
-///     println!("{:?}: {:?}", disk.name(), disk.kind());
-/// }
-/// ```
-pub struct Disk {
-    pub(crate) inner: crate::DiskInner,
-}
 
-impl Disk {
-    /// Returns the kind of disk.
+    /// Returns the effective group ID of the process.
+    ///
+    /// If you run something with `sudo`, the real group ID of the launched
+    /// process will be the primary group ID you are logged in as but effective
+    /// group ID will be `0` (i-e root).
+    ///
+    /// ⚠️ It always returns `None` on Windows.
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list() {
-    ///     println!("[{:?}] {:?}", disk.name(), disk.kind());
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     eprintln!("User id for process 1337: {:?}", process.effective_group_id());
     /// }
     /// ```
-    pub fn kind(&self) -> DiskKind {
-        self.inner.kind()
+    pub fn effective_group_id(&self) -> Option<Gid> {
+        self.inner.effective_group_id()
     }
 
-    /// Returns the disk name.
+    /// Wait for process termination.
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list() {
-    ///     println!("{:?}", disk.name());
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     eprintln!("Waiting for pid 1337");
+    ///     process.wait();
+    ///     eprintln!("Pid 1337 exited");
     /// }
     /// ```
-    pub fn name(&self) -> &OsStr {
-        self.inner.name()
+    pub fn wait(&self) {
+        self.inner.wait()
     }
 
-    /// Returns the file system used on this disk (so for example: `EXT4`, `NTFS`, etc...).
+    /// Returns the session ID for the current process or `None` if it couldn't
+    /// be retrieved.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list() {
-    ///     println!("[{:?}] {:?}", disk.name(), disk.file_system());
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     eprintln!("Session ID for process 1337: {:?}", process.session_id());
     /// }
     /// ```
-    pub fn file_system(&self) -> &OsStr {
-        self.inner.file_system()
+    pub fn session_id(&self) -> Option<Pid> {
+        self.inner.session_id()
     }
 
-    /// Returns the mount point of the disk (`/` for example).
+    /// Returns `true` if the process is running through a binary translation or emulation
+    /// layer instead of natively.
+    ///
+    /// It detects:
+    ///  * Rosetta 2 on Apple Silicon.
+    ///  * WOW64 and x64 emulation on Windows (including ARM64).
+    ///  * `qemu-user` registered as a `binfmt_misc` interpreter on Linux.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ It always returns `false` on FreeBSD and iOS.
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::{Pid, System};
     ///
-    /// let disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list() {
-    ///     println!("[{:?}] {:?}", disk.name(), disk.mount_point());
+    /// let s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("is translated: {}", process.is_translated());
     /// }
     /// ```
-    pub fn mount_point(&self) -> &Path {
-        self.inner.mount_point()
+    pub fn is_translated(&self) -> bool {
+        self.inner.is_translated()
     }
 
-    /// Returns the total disk size, in bytes.
+    /// Returns `true` if the process belongs to a remote login session.
+    ///
+    /// On Windows, the session of the process is remote if its client uses the RDP (or ICA)
+    /// protocol. On Unix systems, the process is remote if it inherited the `SSH_CONNECTION`
+    /// (or `SSH_CLIENT`) environment variable from the SSH server, so the processes clearing
+    /// their environment aren't detected.
+    ///
+    /// ⚠️ VNC sessions share the local display, so they aren't detected as remote.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::System;
     ///
-    /// let disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list() {
+    /// let s = System::new_all();
+    ///
+    /// for (pid, process) in s.processes() {
+    ///     if process.is_remote_session() {
+    ///         println!("[{pid}] {:?} is run remotely", process.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn is_remote_session(&self) -> bool {
+        self.inner.is_remote_session()
+    }
+
+    /// Tasks run by this process.
+    ///
+    /// ⚠️ This method is only available on Linux.
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        not(feature = "unknown-ci")
+    ))]
+    pub fn tasks(&self) -> &HashMap<Pid, Process> {
+        &self.inner.tasks
+    }
+}
+
+macro_rules! pid_decl {
+    ($typ:ty) => {
+        #[doc = include_str!("../md_doc/pid.md")]
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        #[repr(transparent)]
+        pub struct Pid(pub(crate) $typ);
+
+        impl From<usize> for Pid {
+            fn from(v: usize) -> Self {
+                Self(v as _)
+            }
+        }
+        impl From<Pid> for usize {
+            fn from(v: Pid) -> Self {
+                v.0 as _
+            }
+        }
+        impl FromStr for Pid {
+            type Err = <$typ as FromStr>::Err;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self(<$typ>::from_str(s)?))
+            }
+        }
+        impl fmt::Display for Pid {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+        impl Pid {
+            /// Allows to convert [`Pid`][crate::Pid] into [`u32`].
+            ///
+            /// ```
+            /// use sysinfo::Pid;
+            ///
+            /// let pid = Pid::from_u32(0);
+            /// let value: u32 = pid.as_u32();
+            /// ```
+            pub fn as_u32(self) -> u32 {
+                self.0 as _
+            }
+            /// Allows to convert a [`u32`] into [`Pid`][crate::Pid].
+            ///
+            /// ```
+            /// use sysinfo::Pid;
+            ///
+            /// let pid = Pid::from_u32(0);
+            /// ```
+            pub fn from_u32(v: u32) -> Self {
+                Self(v as _)
+            }
+        }
+    };
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(
+        not(feature = "unknown-ci"),
+        any(
+            target_os = "freebsd",
+            target_os = "linux",
+            target_os = "android",
+            target_os = "macos",
+            target_os = "ios",
+        )
+    ))] {
+        use libc::pid_t;
+
+        pid_decl!(pid_t);
+    } else {
+        pid_decl!(usize);
+    }
+}
+
+macro_rules! impl_get_set {
+    ($ty_name:ident, $name:ident, $with:ident, $without:ident $(, $extra_doc:literal)? $(,)?) => {
+        #[doc = concat!("Returns the value of the \"", stringify!($name), "\" refresh kind.")]
+        $(#[doc = concat!("
+", $extra_doc, "
+")])?
+        #[doc = concat!("
+```
+use sysinfo::", stringify!($ty_name), ";
+
+let r = ", stringify!($ty_name), "::new();
+assert_eq!(r.", stringify!($name), "(), false);
+
+let r = r.with_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "(), true);
+
+let r = r.without_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "(), false);
+```")]
+        pub fn $name(&self) -> bool {
+            self.$name
+        }
+
+        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `true`.
+
+```
+use sysinfo::", stringify!($ty_name), ";
+
+let r = ", stringify!($ty_name), "::new();
+assert_eq!(r.", stringify!($name), "(), false);
+
+let r = r.with_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "(), true);
+```")]
+        #[must_use]
+        pub fn $with(mut self) -> Self {
+            self.$name = true;
+            self
+        }
+
+        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `false`.
+
+```
+use sysinfo::", stringify!($ty_name), ";
+
+let r = ", stringify!($ty_name), "::everything();
+assert_eq!(r.", stringify!($name), "(), true);
+
+let r = r.without_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "(), false);
+```")]
+        #[must_use]
+        pub fn $without(mut self) -> Self {
+            self.$name = false;
+            self
+        }
+    };
+
+    ($ty_name:ident, $name:ident, $with:ident, $without:ident, $typ:ty $(,)?) => {
+        #[doc = concat!("Returns the value of the \"", stringify!($name), "\" refresh kind.
+
+```
+use sysinfo::{", stringify!($ty_name), ", ", stringify!($typ), "};
+
+let r = ", stringify!($ty_name), "::new();
+assert_eq!(r.", stringify!($name), "().is_some(), false);
+
+let r = r.with_", stringify!($name), "(", stringify!($typ), "::everything());
+assert_eq!(r.", stringify!($name), "().is_some(), true);
+
+let r = r.without_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "().is_some(), false);
+```")]
+        pub fn $name(&self) -> Option<$typ> {
+            self.$name
+        }
+
+        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `true`.
+
+```
+use sysinfo::{", stringify!($ty_name), ", ", stringify!($typ), "};
+
+let r = ", stringify!($ty_name), "::new();
+assert_eq!(r.", stringify!($name), "().is_some(), false);
+
+let r = r.with_", stringify!($name), "(", stringify!($typ), "::everything());
+assert_eq!(r.", stringify!($name), "().is_some(), true);
+```")]
+        #[must_use]
+        pub fn $with(mut self, kind: $typ) -> Self {
+            self.$name = Some(kind);
+            self
+        }
+
+        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `false`.
+
+```
+use sysinfo::", stringify!($ty_name), ";
+
+let r = ", stringify!($ty_name), "::everything();
+assert_eq!(r.", stringify!($name), "().is_some(), true);
+
+let r = r.without_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "().is_some(), false);
+```")]
+        #[must_use]
+        pub fn $without(mut self) -> Self {
+            self.$name = None;
+            self
+        }
+    };
+}
+
+/// Used to determine what you want to refresh specifically on the [`Process`] type.
+///
+/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
+/// the information won't be retrieved if the information is accessible without needing
+/// extra computation.
+///
+/// ```
+/// use sysinfo::{ProcessRefreshKind, System};
+///
+/// let mut system = System::new();
+///
+/// // We don't want to update the CPU information.
+/// system.refresh_processes_specifics(ProcessRefreshKind::everything().without_cpu());
+///
+/// for (_, proc_) in system.processes() {
+///     // We use a `==` comparison on float only because we know it's set to 0 here.
+///     assert_eq!(proc_.cpu_usage(), 0.);
+/// }
+/// ```
+///
+/// [`Process`]: crate::Process
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProcessRefreshKind {
+    cpu: bool,
+    disk_usage: bool,
+    user: bool,
+}
+
+impl ProcessRefreshKind {
+    /// Creates a new `ProcessRefreshKind` with every refresh set to `false`.
+    ///
+    /// ```
+    /// use sysinfo::ProcessRefreshKind;
+    ///
+    /// let r = ProcessRefreshKind::new();
+    ///
+    /// assert_eq!(r.cpu(), false);
+    /// assert_eq!(r.disk_usage(), false);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `ProcessRefreshKind` with every refresh set to `true`.
+    ///
+    /// ```
+    /// use sysinfo::ProcessRefreshKind;
+    ///
+    /// let r = ProcessRefreshKind::everything();
+    ///
+    /// assert_eq!(r.cpu(), true);
+    /// assert_eq!(r.disk_usage(), true);
+    /// ```
+    pub fn everything() -> Self {
+        Self {
+            cpu: true,
+            disk_usage: true,
+            user: true,
+        }
+    }
+
+    impl_get_set!(ProcessRefreshKind, cpu, with_cpu, without_cpu);
+    impl_get_set!(
+        ProcessRefreshKind,
+        disk_usage,
+        with_disk_usage,
+        without_disk_usage
+    );
+    impl_get_set!(
+        ProcessRefreshKind,
+        user,
+        with_user,
+        without_user,
+        r#"This refresh is about `user_id` and `group_id`. Please note that it has an effect mostly
+on Windows as other platforms get this information alongside the Process information directly."#,
+    );
+}
+
+/// Used to determine what you want to refresh specifically on the [`Cpu`] type.
+///
+/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
+/// the information won't be retrieved if the information is accessible without needing
+/// extra computation.
+///
+/// ```
+/// use sysinfo::{CpuRefreshKind, System};
+///
+/// let mut system = System::new();
+///
+/// // We don't want to update all the CPU information.
+/// system.refresh_cpu_specifics(CpuRefreshKind::everything().without_frequency());
+///
+/// for cpu in system.cpus() {
+///     assert_eq!(cpu.frequency(), 0);
+/// }
+/// ```
+///
+/// [`Cpu`]: crate::Cpu
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CpuRefreshKind {
+    cpu_usage: bool,
+    frequency: bool,
+    temperature: bool,
+    idle_states: bool,
+}
+
+impl CpuRefreshKind {
+    /// Creates a new `CpuRefreshKind` with every refresh set to `false`.
+    ///
+    /// ```
+    /// use sysinfo::CpuRefreshKind;
+    ///
+    /// let r = CpuRefreshKind::new();
+    ///
+    /// assert_eq!(r.frequency(), false);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `CpuRefreshKind` with every refresh set to `true`.
+    ///
+    /// ```
+    /// use sysinfo::CpuRefreshKind;
+    ///
+    /// let r = CpuRefreshKind::everything();
+    ///
+    /// assert_eq!(r.frequency(), true);
+    /// ```
+    pub fn everything() -> Self {
+        Self {
+            cpu_usage: true,
+            frequency: true,
+            temperature: true,
+            idle_states: true,
+        }
+    }
+
+    impl_get_set!(CpuRefreshKind, cpu_usage, with_cpu_usage, without_cpu_usage);
+    impl_get_set!(CpuRefreshKind, frequency, with_frequency, without_frequency);
+    impl_get_set!(
+        CpuRefreshKind,
+        temperature,
+        with_temperature,
+        without_temperature
+    );
+    impl_get_set!(
+        CpuRefreshKind,
+        idle_states,
+        with_idle_states,
+        without_idle_states
+    );
+}
+
+/// Returns `true` if `process` was started by the current process, checking its start time in
+/// case the parent PID was reused.
+fn is_current_process_child(process: &Process) -> bool {
+    // The start time of the current process doesn't change, so it's only retrieved once.
+    static START_TIME: std::sync::Mutex<Option<u64>> = std::sync::Mutex::new(None);
+
+    let current_pid = match get_current_pid() {
+        Ok(pid) => pid,
+        Err(_) => return false,
+    };
+    if process.parent() != Some(current_pid) {
+        return false;
+    }
+    let mut start_time = START_TIME.lock().unwrap_or_else(|e| e.into_inner());
+    if start_time.is_none() {
+        let mut s = System::new();
+        if s.refresh_process_specifics(current_pid, ProcessRefreshKind::new()) {
+            *start_time = s.process(current_pid).map(Process::start_time);
+        }
+    }
+    start_time.map_or(true, |start_time| process.start_time() >= start_time)
+}
+
+/// Returns the `n` processes with the biggest `key`, sorted in descending order. The processes
+/// with the same `key` are sorted by PID.
+fn top_processes<F>(processes: &HashMap<Pid, Process>, n: usize, key: F) -> Vec<&Process>
+where
+    F: Fn(&Process) -> f64,
+{
+    struct Entry<'a>(f64, &'a Process);
+
+    // The "smallest" entry is the one which would be the first of the list.
+    impl Ord for Entry<'_> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            other
+                .0
+                .total_cmp(&self.0)
+                .then_with(|| self.1.pid().cmp(&other.1.pid()))
+        }
+    }
+    impl PartialOrd for Entry<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl PartialEq for Entry<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+    impl Eq for Entry<'_> {}
+
+    if n == 0 {
+        return Vec::new();
+    }
+    // The heap keeps the `n` biggest entries, with the smallest one on top so it can be
+    // replaced.
+    let mut heap = std::collections::BinaryHeap::with_capacity(n.min(processes.len()) + 1);
+    for process in processes.values() {
+        let entry = Entry(key(process), process);
+        if heap.len() < n {
+            heap.push(entry);
+        } else if let Some(mut top) = heap.peek_mut() {
+            if entry < *top {
+                *top = entry;
+            }
+        }
+    }
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Entry(_, process)| process)
+        .collect()
+}
+
+type ProcessFilter = std::sync::Arc<dyn Fn(&Process) -> bool + Send + Sync>;
+
+fn filter_processes(filter: &Option<ProcessFilter>, processes: &mut HashMap<Pid, Process>) {
+    if let Some(filter) = filter {
+        processes.retain(|_, process| filter(process));
+    }
+}
+
+/// What to do with the strings which aren't valid UTF-8, like the command line arguments or the
+/// environment variables of a process.
+///
+/// It is set with [`SystemBuilder::with_string_encoding_policy`].
+///
+/// ⚠️ It is only used on Linux: the other systems already return UTF-8 strings or replace the
+/// invalid sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StringEncodingPolicy {
+    /// The invalid strings are skipped. This is the default.
+    #[default]
+    Skip,
+    /// The invalid sequences are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    Lossy,
+}
+
+/// Configures a [`System`] before creating it, with [`SystemBuilder::build`].
+///
+/// ```no_run
+/// use sysinfo::{ProcessRefreshKind, RefreshKind, StringEncodingPolicy, SystemBuilder};
+///
+/// let s = SystemBuilder::new()
+///     .with_refreshes(RefreshKind::new().with_processes(ProcessRefreshKind::everything()))
+///     .with_process_filter(|process| process.name().starts_with("cargo"))
+///     .with_string_encoding_policy(StringEncodingPolicy::Lossy)
+///     .with_open_files(false)
+///     .build();
+///
+/// for process in s.processes().values() {
+///     println!("{:?}", process.cmd());
+/// }
+/// ```
+#[derive(Clone)]
+pub struct SystemBuilder {
+    pub(crate) refreshes: RefreshKind,
+    pub(crate) process_filter: Option<ProcessFilter>,
+    pub(crate) string_encoding_policy: StringEncodingPolicy,
+    pub(crate) cpu_usage_smoothing: usize,
+    pub(crate) use_cgroup_limits: bool,
+    pub(crate) keep_open_files: bool,
+}
+
+impl Default for SystemBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SystemBuilder {
+    /// Creates a new `SystemBuilder` with the default configuration, which creates the same
+    /// `System` as [`System::new`].
+    ///
+    /// ```
+    /// use sysinfo::SystemBuilder;
+    ///
+    /// let s = SystemBuilder::new().build();
+    /// assert!(s.processes().is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            refreshes: RefreshKind::new(),
+            process_filter: None,
+            string_encoding_policy: StringEncodingPolicy::Skip,
+            cpu_usage_smoothing: 0,
+            use_cgroup_limits: false,
+            keep_open_files: true,
+        }
+    }
+
+    /// Sets what is refreshed when the `System` is created, like with
+    /// [`System::new_with_specifics`].
+    #[must_use]
+    pub fn with_refreshes(mut self, refreshes: RefreshKind) -> Self {
+        self.refreshes = refreshes;
+        self
+    }
+
+    /// Only keeps the processes for which `filter` returns `true` when they are refreshed.
+    ///
+    /// All the processes are still retrieved by the refreshes (so the new ones can be checked),
+    /// but the other ones are dropped right away, which saves memory when only a few of them
+    /// are needed.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessRefreshKind, RefreshKind, SystemBuilder};
+    ///
+    /// // Only keeps the children of the PID 1.
+    /// let s = SystemBuilder::new()
+    ///     .with_refreshes(RefreshKind::new().with_processes(ProcessRefreshKind::new()))
+    ///     .with_process_filter(|process| process.parent() == Some(Pid::from(1)))
+    ///     .build();
+    /// ```
+    #[must_use]
+    pub fn with_process_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&Process) -> bool + Send + Sync + 'static,
+    {
+        self.process_filter = Some(std::sync::Arc::new(filter));
+        self
+    }
+
+    /// Sets what to do with the strings which aren't valid UTF-8. The default is
+    /// [`StringEncodingPolicy::Skip`].
+    #[must_use]
+    pub fn with_string_encoding_policy(mut self, policy: StringEncodingPolicy) -> Self {
+        self.string_encoding_policy = policy;
+        self
+    }
+
+    /// Smooths the CPU usage values over the last `samples` refreshes, like with
+    /// [`System::set_cpu_usage_smoothing`].
+    #[must_use]
+    pub fn with_cpu_usage_smoothing(mut self, samples: usize) -> Self {
+        self.cpu_usage_smoothing = samples;
+        self
+    }
+
+    /// Applies the cgroup limits to the memory and CPU information, like with
+    /// [`System::set_use_cgroup_limits`].
+    #[must_use]
+    pub fn with_cgroup_limits(mut self, enabled: bool) -> Self {
+        self.use_cgroup_limits = enabled;
+        self
+    }
+
+    /// Sets if the files of the processes are kept open between refreshes, which makes the
+    /// refreshes faster. It is the case by default, within the limit set with
+    /// [`set_open_files_limit`][crate::set_open_files_limit].
+    ///
+    /// ⚠️ It is only used on Linux.
+    #[must_use]
+    pub fn with_open_files(mut self, enabled: bool) -> Self {
+        self.keep_open_files = enabled;
+        self
+    }
+
+    /// Creates the `System` and refreshes what was set with
+    /// [`with_refreshes`](SystemBuilder::with_refreshes).
+    ///
+    /// ```no_run
+    /// use sysinfo::{RefreshKind, SystemBuilder};
+    ///
+    /// let s = SystemBuilder::new()
+    ///     .with_refreshes(RefreshKind::new().with_memory())
+    ///     .with_cgroup_limits(true)
+    ///     .build();
+    /// println!("{} bytes", s.total_memory());
+    /// ```
+    pub fn build(self) -> System {
+        let mut inner = SystemInner::new();
+        inner.set_string_encoding_policy(self.string_encoding_policy);
+        inner.set_keep_open_files(self.keep_open_files);
+        let mut s = System {
+            inner,
+            cpu_usage_smoothing: None,
+            use_cgroup_limits: self.use_cgroup_limits,
+            applied_cgroup_limits: None,
+            process_filter: self.process_filter,
+        };
+        s.set_cpu_usage_smoothing(self.cpu_usage_smoothing);
+        s.refresh_specifics(self.refreshes);
+        s
+    }
+}
+
+/// Used to determine what you want to refresh specifically on the [`System`][crate::System] type.
+///
+/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
+/// the information won't be retrieved if the information is accessible without needing
+/// extra computation.
+///
+/// ```
+/// use sysinfo::{RefreshKind, System};
+///
+/// // We want everything except memory.
+/// let mut system = System::new_with_specifics(RefreshKind::everything().without_memory());
+///
+/// assert_eq!(system.total_memory(), 0);
+/// # if sysinfo::IS_SUPPORTED && !cfg!(feature = "apple-sandbox") {
+/// assert!(system.processes().len() > 0);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RefreshKind {
+    processes: Option<ProcessRefreshKind>,
+    memory: bool,
+    cpu: Option<CpuRefreshKind>,
+}
+
+impl RefreshKind {
+    /// Creates a new `RefreshKind` with every refresh set to `false`/`None`.
+    ///
+    /// ```
+    /// use sysinfo::RefreshKind;
+    ///
+    /// let r = RefreshKind::new();
+    ///
+    /// assert_eq!(r.processes().is_some(), false);
+    /// assert_eq!(r.memory(), false);
+    /// assert_eq!(r.cpu().is_some(), false);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `RefreshKind` with every refresh set to `true`/`Some(...)`.
+    ///
+    /// ```
+    /// use sysinfo::RefreshKind;
+    ///
+    /// let r = RefreshKind::everything();
+    ///
+    /// assert_eq!(r.processes().is_some(), true);
+    /// assert_eq!(r.memory(), true);
+    /// assert_eq!(r.cpu().is_some(), true);
+    /// ```
+    pub fn everything() -> Self {
+        Self {
+            processes: Some(ProcessRefreshKind::everything()),
+            memory: true,
+            cpu: Some(CpuRefreshKind::everything()),
+        }
+    }
+
+    /// Creates a new `RefreshKind` with what is usually collected by monitoring agents: the
+    /// memory, the CPU usage and the processes with their CPU and disk usage.
+    ///
+    /// It is parsed from and displayed as `monitoring-agent`.
+    ///
+    /// ```
+    /// use sysinfo::{CpuRefreshKind, ProcessRefreshKind, RefreshKind};
+    ///
+    /// let r = RefreshKind::monitoring_agent();
+    ///
+    /// assert_eq!(r.memory(), true);
+    /// assert_eq!(r.cpu(), Some(CpuRefreshKind::new().with_cpu_usage()));
+    /// assert_eq!(r.processes().map(|p| p.user()), Some(false));
+    /// ```
+    pub fn monitoring_agent() -> Self {
+        Self {
+            processes: Some(ProcessRefreshKind::new().with_cpu().with_disk_usage()),
+            memory: true,
+            cpu: Some(CpuRefreshKind::new().with_cpu_usage()),
+        }
+    }
+
+    /// Creates a new `RefreshKind` with the global resources usage: the memory and the CPU
+    /// usage, without the processes.
+    ///
+    /// It is parsed from and displayed as `resource-usage`.
+    ///
+    /// ```
+    /// use sysinfo::RefreshKind;
+    ///
+    /// let r = RefreshKind::resource_usage();
+    ///
+    /// assert_eq!(r.memory(), true);
+    /// assert_eq!(r.processes().is_some(), false);
+    /// ```
+    pub fn resource_usage() -> Self {
+        Self::monitoring_agent().without_processes()
+    }
+
+    impl_get_set!(
+        RefreshKind,
+        processes,
+        with_processes,
+        without_processes,
+        ProcessRefreshKind
+    );
+    impl_get_set!(RefreshKind, memory, with_memory, without_memory);
+    impl_get_set!(RefreshKind, cpu, with_cpu, without_cpu, CpuRefreshKind);
+}
+
+fn cpu_refresh_kind_field<'a>(kind: &'a mut CpuRefreshKind, name: &str) -> Option<&'a mut bool> {
+    Some(match name {
+        "usage" => &mut kind.cpu_usage,
+        "frequency" => &mut kind.frequency,
+        "temperature" => &mut kind.temperature,
+        "idle-states" => &mut kind.idle_states,
+        _ => return None,
+    })
+}
+
+fn process_refresh_kind_field<'a>(
+    kind: &'a mut ProcessRefreshKind,
+    name: &str,
+) -> Option<&'a mut bool> {
+    Some(match name {
+        "cpu" => &mut kind.cpu,
+        "disk-usage" => &mut kind.disk_usage,
+        "user" => &mut kind.user,
+        _ => return None,
+    })
+}
+
+const CPU_REFRESH_KIND_FIELDS: &[&str] = &["usage", "frequency", "temperature", "idle-states"];
+const PROCESS_REFRESH_KIND_FIELDS: &[&str] = &["cpu", "disk-usage", "user"];
+
+/// Writes `kind` as `prefix` if everything is set, `prefix.list` if nothing is set, and as the
+/// list of the set fields otherwise.
+fn write_sub_refresh_kind<T: Copy + PartialEq>(
+    items: &mut Vec<String>,
+    prefix: &str,
+    kind: T,
+    (new, everything): (T, T),
+    fields: &[&str],
+    get: impl Fn(&mut T, &str) -> Option<bool>,
+) {
+    if kind == everything {
+        items.push(prefix.to_owned());
+    } else if kind == new {
+        items.push(format!("{prefix}.list"));
+    } else {
+        let mut kind = kind;
+        for field in fields {
+            if get(&mut kind, field) == Some(true) {
+                items.push(format!("{prefix}.{field}"));
+            }
+        }
+    }
+}
+
+/// The format is the same as the one parsed with [`FromStr`].
+impl fmt::Display for RefreshKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == Self::everything() {
+            return f.write_str("everything");
+        } else if *self == Self::monitoring_agent() {
+            return f.write_str("monitoring-agent");
+        } else if *self == Self::resource_usage() {
+            return f.write_str("resource-usage");
+        }
+        let mut items = Vec::new();
+        if let Some(cpu) = self.cpu {
+            write_sub_refresh_kind(
+                &mut items,
+                "cpu",
+                cpu,
+                (CpuRefreshKind::new(), CpuRefreshKind::everything()),
+                CPU_REFRESH_KIND_FIELDS,
+                |kind, name| cpu_refresh_kind_field(kind, name).map(|field| *field),
+            );
+        }
+        if self.memory {
+            items.push("memory".to_owned());
+        }
+        if let Some(processes) = self.processes {
+            write_sub_refresh_kind(
+                &mut items,
+                "processes",
+                processes,
+                (ProcessRefreshKind::new(), ProcessRefreshKind::everything()),
+                PROCESS_REFRESH_KIND_FIELDS,
+                |kind, name| process_refresh_kind_field(kind, name).map(|field| *field),
+            );
+        }
+        f.write_str(&items.join(","))
+    }
+}
+
+/// Parses a comma-separated list of refreshes, applied from left to right, so CLI tools can
+/// expose them directly (like with `--collect=cpu,memory`).
+///
+/// The refreshes are:
+///
+/// * `everything` (or `all`), `monitoring-agent` and `resource-usage`, for
+///   [`RefreshKind::everything`], [`RefreshKind::monitoring_agent`] and
+///   [`RefreshKind::resource_usage`].
+/// * `memory` (or `mem`).
+/// * `cpu` for [`CpuRefreshKind::everything`], `cpu.list` for [`CpuRefreshKind::new`], or
+///   `cpu.usage`, `cpu.frequency`, `cpu.temperature` and `cpu.idle-states`.
+/// * `processes` (or `procs`) for [`ProcessRefreshKind::everything`], `processes.list` for
+///   [`ProcessRefreshKind::new`], or `processes.cpu`, `processes.disk-usage` and
+///   `processes.user`.
+///
+/// A refresh starting with `-` is removed instead of added. An empty string gives
+/// [`RefreshKind::new`].
+///
+/// ```
+/// use sysinfo::{CpuRefreshKind, RefreshKind};
+///
+/// let r: RefreshKind = "everything,-processes,-cpu.temperature".parse().unwrap();
+/// assert_eq!(
+///     r,
+///     RefreshKind::everything()
+///         .without_processes()
+///         .with_cpu(CpuRefreshKind::everything().without_temperature()),
+/// );
+/// // It can be displayed back in the same format.
+/// assert_eq!(r.to_string().parse::<RefreshKind>(), Ok(r));
+///
+/// assert!("cpu,disks".parse::<RefreshKind>().is_err());
+/// ```
+impl FromStr for RefreshKind {
+    type Err = ParseRefreshKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut kind = Self::new();
+        for item in s.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+            let (enable, name) = match item.strip_prefix('-') {
+                Some(name) => (false, name.trim()),
+                None => (true, item),
+            };
+            let error = || ParseRefreshKindError {
+                item: item.to_owned(),
+            };
+            let preset = match name {
+                "everything" | "all" => Some(Self::everything()),
+                "monitoring-agent" => Some(Self::monitoring_agent()),
+                "resource-usage" => Some(Self::resource_usage()),
+                _ => None,
+            };
+            if let Some(preset) = preset {
+                if enable {
+                    kind.memory |= preset.memory;
+                    kind.cpu =
+                        merge_sub_refresh_kind(kind.cpu, preset.cpu, |a, b| CpuRefreshKind {
+                            cpu_usage: a.cpu_usage || b.cpu_usage,
+                            frequency: a.frequency || b.frequency,
+                            temperature: a.temperature || b.temperature,
+                            idle_states: a.idle_states || b.idle_states,
+                        });
+                    kind.processes =
+                        merge_sub_refresh_kind(kind.processes, preset.processes, |a, b| {
+                            ProcessRefreshKind {
+                                cpu: a.cpu || b.cpu,
+                                disk_usage: a.disk_usage || b.disk_usage,
+                                user: a.user || b.user,
+                            }
+                        });
+                } else {
+                    // Removing a preset removes everything it enables.
+                    if preset.memory {
+                        kind.memory = false;
+                    }
+                    if preset.cpu.is_some() {
+                        kind.cpu = None;
+                    }
+                    if preset.processes.is_some() {
+                        kind.processes = None;
+                    }
+                }
+                continue;
+            }
+            let (name, field) = match name.split_once('.') {
+                Some((name, field)) => (name, Some(field)),
+                None => (name, None),
+            };
+            match name {
+                "memory" | "mem" if field.is_none() => kind.memory = enable,
+                "cpu" => {
+                    kind.cpu = update_sub_refresh_kind(
+                        kind.cpu,
+                        (CpuRefreshKind::new(), CpuRefreshKind::everything()),
+                        enable,
+                        field,
+                        cpu_refresh_kind_field,
+                    )
+                    .ok_or_else(error)?;
+                }
+                "processes" | "procs" => {
+                    kind.processes = update_sub_refresh_kind(
+                        kind.processes,
+                        (ProcessRefreshKind::new(), ProcessRefreshKind::everything()),
+                        enable,
+                        field,
+                        process_refresh_kind_field,
+                    )
+                    .ok_or_else(error)?;
+                }
+                _ => return Err(error()),
+            }
+        }
+        Ok(kind)
+    }
+}
+
+fn merge_sub_refresh_kind<T>(a: Option<T>, b: Option<T>, merge: impl Fn(T, T) -> T) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(merge(a, b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Applies a `prefix`, `prefix.list` or `prefix.field` item to `kind`. Returns `None` if the
+/// field doesn't exist.
+fn update_sub_refresh_kind<T: Copy>(
+    kind: Option<T>,
+    (new, everything): (T, T),
+    enable: bool,
+    field: Option<&str>,
+    get: impl for<'a> Fn(&'a mut T, &str) -> Option<&'a mut bool>,
+) -> Option<Option<T>> {
+    Some(match (field, enable) {
+        (None, true) => Some(everything),
+        (None, false) => None,
+        (Some("list"), true) => Some(kind.unwrap_or(new)),
+        (Some("list"), false) => None,
+        (Some(field), true) => {
+            let mut kind = kind.unwrap_or(new);
+            *get(&mut kind, field)? = true;
+            Some(kind)
+        }
+        (Some(field), false) => match kind {
+            Some(mut kind) => {
+                *get(&mut kind, field)? = false;
+                Some(kind)
+            }
+            None => {
+                // Checks that the field exists.
+                let mut new = new;
+                get(&mut new, field)?;
+                None
+            }
+        },
+    })
+}
+
+/// Error returned when parsing a [`RefreshKind`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRefreshKindError {
+    item: String,
+}
+
+impl ParseRefreshKindError {
+    /// Returns the item which isn't a known refresh.
+    pub fn item(&self) -> &str {
+        &self.item
+    }
+}
+
+impl fmt::Display for ParseRefreshKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown refresh kind `{}`", self.item)
+    }
+}
+
+impl std::error::Error for ParseRefreshKindError {}
+
+/// Reason why a part of a refresh failed, returned by [`RefreshError::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshErrorKind {
+    /// The current user isn't allowed to retrieve the information. Running the program with
+    /// more privileges might help.
+    PermissionDenied,
+    /// What was being refreshed disappeared in the meantime, like a process which stopped.
+    Vanished,
+    /// The source of the information isn't available on this system, like a missing file
+    /// system or WMI not responding, or this system isn't supported.
+    Unavailable,
+    /// Any other failure.
+    Other,
+}
+
+impl fmt::Display for RefreshErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::PermissionDenied => "permission denied",
+            Self::Vanished => "vanished",
+            Self::Unavailable => "unavailable",
+            Self::Other => "failed",
+        })
+    }
+}
+
+/// Failure of a part of a refresh, listed in a [`RefreshReport`].
+///
+/// ```no_run
+/// use sysinfo::{RefreshErrorKind, System};
+///
+/// let mut s = System::new();
+/// if let Err(report) = s.try_refresh_all() {
+///     for error in report.errors() {
+///         if error.kind() == RefreshErrorKind::PermissionDenied {
+///             println!("{} needs more privileges", error.target());
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshError {
+    pub(crate) kind: RefreshErrorKind,
+    pub(crate) target: String,
+    pub(crate) message: String,
+}
+
+impl RefreshError {
+    /// Returns why the refresh failed.
+    pub fn kind(&self) -> RefreshErrorKind {
+        self.kind
+    }
+
+    /// Returns what couldn't be refreshed, like a file path, a process or an API.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Returns the error returned by the system, or a description of what went wrong.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for RefreshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.target, self.kind, self.message)
+    }
+}
+
+impl std::error::Error for RefreshError {}
+
+/// Failures of a refresh, returned by the `try_refresh_*` methods like
+/// [`System::try_refresh_all`].
+///
+/// The refresh isn't stopped by the failures, so everything which could be retrieved is
+/// updated as usual. It allows to tell apart an empty list (like no components) from a list
+/// which couldn't be retrieved.
+///
+/// ⚠️ On macOS, iOS and FreeBSD, only the failures to list the processes and to read the memory
+/// are reported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshReport {
+    pub(crate) errors: Vec<RefreshError>,
+}
+
+impl RefreshReport {
+    /// Returns the failures, in the order they happened.
+    pub fn errors(&self) -> &[RefreshError] {
+        &self.errors
+    }
+
+    /// Returns the failures, in the order they happened.
+    pub fn into_errors(self) -> Vec<RefreshError> {
+        self.errors
+    }
+}
+
+impl fmt::Display for RefreshReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Display::fmt(error, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for RefreshReport {}
+
+/// Used to determine what you want to refresh specifically on the [`Disks`] type.
+///
+/// Network disks (like NFS or SMB mounts) are not listed by default because retrieving their
+/// information requires to contact the remote server, which may hang if it is unreachable.
+///
+/// ```no_run
+/// use sysinfo::{DiskRefreshKind, Disks};
+///
+/// // We want the network disks, but we don't want to get their space.
+/// let disks = Disks::new_with_refreshed_list_specifics(
+///     DiskRefreshKind::new().with_network_disks(),
+/// );
+///
+/// for disk in disks.list().iter().filter(|disk| disk.is_network()) {
+///     println!("{:?}: {:?}", disk.mount_point(), disk.remote_host());
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DiskRefreshKind {
+    network_disks: bool,
+    network_disks_space: bool,
+}
+
+impl DiskRefreshKind {
+    /// Creates a new `DiskRefreshKind` with every refresh set to `false`.
+    ///
+    /// ```
+    /// use sysinfo::DiskRefreshKind;
+    ///
+    /// let r = DiskRefreshKind::new();
+    ///
+    /// assert_eq!(r.network_disks(), false);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `DiskRefreshKind` with every refresh set to `true`.
+    ///
+    /// ```
+    /// use sysinfo::DiskRefreshKind;
+    ///
+    /// let r = DiskRefreshKind::everything();
+    ///
+    /// assert_eq!(r.network_disks(), true);
+    /// ```
+    pub fn everything() -> Self {
+        Self {
+            network_disks: true,
+            network_disks_space: true,
+        }
+    }
+
+    impl_get_set!(
+        DiskRefreshKind,
+        network_disks,
+        with_network_disks,
+        without_network_disks,
+        "If `false`, network disks are not listed at all."
+    );
+    impl_get_set!(
+        DiskRefreshKind,
+        network_disks_space,
+        with_network_disks_space,
+        without_network_disks_space,
+        r#"If `false`, the total and available space of network disks are not retrieved (they are
+set to `0`), neither when listing them nor when refreshing them. It has no effect if the
+"network_disks" refresh kind is `false`."#,
+    );
+}
+
+/// Used to determine what you want to retrieve when listing users with
+/// [`Users::refresh_list_specifics`].
+///
+/// Only the names and IDs of the users are retrieved by default, which is cheap. The other
+/// information can be slow to retrieve, in particular on Windows machines joined to a domain.
+///
+/// ```no_run
+/// use sysinfo::{UserRefreshKind, Users};
+///
+/// let mut users = Users::new();
+/// // Only retrieves the names and IDs of the users.
+/// users.refresh_list_specifics(UserRefreshKind::new());
+/// for user in users.list() {
+///     println!("{}", user.name());
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UserRefreshKind {
+    groups: bool,
+    home_dir: bool,
+}
+
+impl UserRefreshKind {
+    /// Creates a new `UserRefreshKind` with every refresh set to `false`.
+    ///
+    /// ```
+    /// use sysinfo::UserRefreshKind;
+    ///
+    /// let r = UserRefreshKind::new();
+    ///
+    /// assert_eq!(r.groups(), false);
+    /// assert_eq!(r.home_dir(), false);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `UserRefreshKind` with every refresh set to `true`.
+    ///
+    /// ```
+    /// use sysinfo::UserRefreshKind;
+    ///
+    /// let r = UserRefreshKind::everything();
+    ///
+    /// assert_eq!(r.groups(), true);
+    /// assert_eq!(r.home_dir(), true);
+    /// ```
+    pub fn everything() -> Self {
+        Self {
+            groups: true,
+            home_dir: true,
+        }
+    }
+
+    impl_get_set!(
+        UserRefreshKind,
+        groups,
+        with_groups,
+        without_groups,
+        r#"If `true`, the groups of the users are resolved while listing them, and [`User::groups`]
+returns them without computing them again."#
+    );
+    impl_get_set!(
+        UserRefreshKind,
+        home_dir,
+        with_home_dir,
+        without_home_dir,
+        r#"If `true`, the home directory and the login shell of the users are retrieved. Otherwise,
+[`User::home_dir`] and [`User::shell`] return `None`."#
+    );
+}
+
+/// Used to determine what you want to refresh specifically on the [`Gpus`] type.
+///
+/// The names, driver versions and total memory of the GPUs are always retrieved when listing
+/// them.
+///
+/// ```no_run
+/// use sysinfo::{GpuRefreshKind, Gpus};
+///
+/// // We only want the utilization of the GPUs.
+/// let gpus = Gpus::new_with_refreshed_list_specifics(GpuRefreshKind::new().with_usage());
+/// for gpu in gpus.list() {
+///     println!("{}: {:?}%", gpu.name(), gpu.usage());
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GpuRefreshKind {
+    usage: bool,
+    memory: bool,
+    temperature: bool,
+    power: bool,
+}
+
+impl GpuRefreshKind {
+    /// Creates a new `GpuRefreshKind` with every refresh set to `false`.
+    ///
+    /// ```
+    /// use sysinfo::GpuRefreshKind;
+    ///
+    /// let r = GpuRefreshKind::new();
+    ///
+    /// assert_eq!(r.usage(), false);
+    /// assert_eq!(r.memory(), false);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `GpuRefreshKind` with every refresh set to `true`.
+    ///
+    /// ```
+    /// use sysinfo::GpuRefreshKind;
+    ///
+    /// let r = GpuRefreshKind::everything();
+    ///
+    /// assert_eq!(r.usage(), true);
+    /// assert_eq!(r.memory(), true);
+    /// ```
+    pub fn everything() -> Self {
+        Self {
+            usage: true,
+            memory: true,
+            temperature: true,
+            power: true,
+        }
+    }
+
+    impl_get_set!(GpuRefreshKind, usage, with_usage, without_usage);
+    impl_get_set!(
+        GpuRefreshKind,
+        memory,
+        with_memory,
+        without_memory,
+        "If `true`, the used memory of the GPUs is refreshed."
+    );
+    impl_get_set!(
+        GpuRefreshKind,
+        temperature,
+        with_temperature,
+        without_temperature
+    );
+    impl_get_set!(GpuRefreshKind, power, with_power, without_power);
+}
+
+/// Used to determine which groups [`User::groups_specifics`] resolves.
+///
+/// The primary group of the user is always returned on Unix systems. Resolving the nested groups
+/// can be slow, since it may require to contact the domain controller.
+///
+/// ```no_run
+/// use sysinfo::{GroupRefreshKind, Users};
+///
+/// let users = Users::new_with_refreshed_list();
+/// for user in users.list() {
+///     // Includes the Active Directory groups the user is a member of through other groups.
+///     let groups = user.groups_specifics(GroupRefreshKind::everything());
+///     println!("{}: {} groups", user.name(), groups.len());
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GroupRefreshKind {
+    supplementary_groups: bool,
+    nested_groups: bool,
+}
+
+impl GroupRefreshKind {
+    /// Creates a new `GroupRefreshKind` with every refresh set to `false`.
+    ///
+    /// ```
+    /// use sysinfo::GroupRefreshKind;
+    ///
+    /// let r = GroupRefreshKind::new();
+    ///
+    /// assert_eq!(r.supplementary_groups(), false);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `GroupRefreshKind` with every refresh set to `true`.
+    ///
+    /// ```
+    /// use sysinfo::GroupRefreshKind;
+    ///
+    /// let r = GroupRefreshKind::everything();
+    ///
+    /// assert_eq!(r.supplementary_groups(), true);
+    /// ```
+    pub fn everything() -> Self {
+        Self {
+            supplementary_groups: true,
+            nested_groups: true,
+        }
+    }
+
+    impl_get_set!(
+        GroupRefreshKind,
+        supplementary_groups,
+        with_supplementary_groups,
+        without_supplementary_groups,
+        r#"If `true`, the supplementary groups (from `/etc/group` or the directory service) are
+returned on Unix systems, and the local groups on Windows."#
+    );
+    impl_get_set!(
+        GroupRefreshKind,
+        nested_groups,
+        with_nested_groups,
+        without_nested_groups,
+        r#"If `true`, the groups the user is a member of through other groups, including the
+Active Directory domain groups, are returned on Windows. Groups can't be nested on Unix systems,
+so it has no effect there."#
+    );
+}
+
+/// Interacting with network interfaces.
+///
+/// ```no_run
+/// use sysinfo::Networks;
+///
+/// let networks = Networks::new_with_refreshed_list();
+/// for (interface_name, network) in &networks {
+///     println!("[{interface_name}]: {network:?}");
+/// }
+/// ```
+pub struct Networks {
+    pub(crate) inner: NetworksInner,
+}
+
+impl<'a> IntoIterator for &'a Networks {
+    type Item = (&'a String, &'a NetworkData);
+    type IntoIter = std::collections::hash_map::Iter<'a, String, NetworkData>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Default for Networks {
+    fn default() -> Self {
+        Networks::new()
+    }
+}
+
+impl Networks {
+    /// Creates a new empty [`Networks`][crate::Networks] type.
+    ///
+    /// If you want it to be filled directly, take a look at [`Networks::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new();
+    /// networks.refresh_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}]: {network:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            inner: NetworksInner::new(),
+        }
+    }
+
+    /// Creates a new [`Networks`][crate::Networks] type with the disk list
+    /// loaded. It is a combination of [`Networks::new`] and
+    /// [`Networks::refresh_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for network in &networks {
+    ///     eprintln!("{network:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut networks = Self::new();
+        networks.refresh_list();
+        networks
+    }
+
+    /// Returns the network interfaces map.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for network in networks.list() {
+    ///     eprintln!("{network:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &HashMap<String, NetworkData> {
+        self.inner.list()
+    }
+
+    /// Refreshes the network interfaces list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new();
+    /// networks.refresh_list();
+    /// ```
+    pub fn refresh_list(&mut self) {
+        self.inner.refresh_list()
+    }
+
+    /// Same as [`Networks::refresh_list`] but returns what failed during the refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new();
+    /// if let Err(report) = networks.try_refresh_list() {
+    ///     eprintln!("failed to list the network interfaces: {report}");
+    /// }
+    /// ```
+    pub fn try_refresh_list(&mut self) -> Result<(), RefreshReport> {
+        crate::utils::collect_errors(|| self.refresh_list())
+    }
+
+    /// Refreshes the network interfaces' content. If you didn't run [`Networks::refresh_list`]
+    /// before, calling this method won't do anything as no interfaces are present.
+    ///
+    /// ⚠️ If a user is added or removed, this method won't take it into account. Use
+    /// [`Networks::refresh_list`] instead.
+    ///
+    /// ⚠️ If you didn't call [`Networks::refresh_list`] beforehand, this method will do nothing
+    /// as the network list will be empty.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Wait some time...? Then refresh the data of each network.
+    /// networks.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        self.inner.refresh()
+    }
+
+    /// Refreshes the content of the network interfaces whose names are in `interface_names`.
+    /// It is cheaper than [`Networks::refresh`] when only a few interfaces out of many are
+    /// needed.
+    ///
+    /// ⚠️ Interfaces which aren't in the list yet are ignored. Use [`Networks::refresh_list`]
+    /// to add them.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Wait some time...? Then refresh the data of `eth0` only.
+    /// networks.refresh_interfaces(&["eth0"]);
+    /// ```
+    pub fn refresh_interfaces(&mut self, interface_names: &[&str]) {
+        self.inner.refresh_interfaces(interface_names)
+    }
+
+    /// Sets whether the counters going backwards between two refreshes are considered to be
+    /// 32-bit counters which wrapped around. It is enabled by default.
+    ///
+    /// Some drivers only provide 32-bit counters, which wrap every few seconds on fast links.
+    /// When enabled, the number of bytes, packets and errors since the last refresh (and the
+    /// rates computed from them) take the wrap into account. When disabled, they are `0` for
+    /// this refresh.
+    ///
+    /// ⚠️ A counter which is reset (when the driver is reloaded for example) while its value is
+    /// below 2^32 is mistaken for a wrapped one, returning a wrong value for one refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// networks.set_counter_wrap_handling(false);
+    /// ```
+    pub fn set_counter_wrap_handling(&mut self, enabled: bool) {
+        self.inner.set_counter_wrap_handling(enabled)
+    }
+}
+
+impl std::ops::Deref for Networks {
+    type Target = HashMap<String, NetworkData>;
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+/// Getting volume of received and transmitted data.
+///
+/// ```no_run
+/// use sysinfo::Networks;
+///
+/// let networks = Networks::new_with_refreshed_list();
+/// for (interface_name, network) in &networks {
+///     println!("[{interface_name}] {network:?}");
+/// }
+/// ```
+pub struct NetworkData {
+    pub(crate) inner: NetworkDataInner,
+}
+
+impl NetworkData {
+    /// Returns the number of received bytes since the last refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {} B", network.received());
+    /// }
+    /// ```
+    pub fn received(&self) -> u64 {
+        self.inner.received()
+    }
+
+    /// Returns the total number of received bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {} B", network.total_received());
+    /// }
+    /// ```
+    pub fn total_received(&self) -> u64 {
+        self.inner.total_received()
+    }
+
+    /// Returns the number of received bytes per second, computed from the bytes received
+    /// between the two last refreshes and the time elapsed between them.
+    ///
+    /// It returns `0` until the interface has been refreshed at least once after being listed.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// networks.refresh();
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}] in: {:.0} B/s", network.received_rate());
+    /// }
+    /// ```
+    pub fn received_rate(&self) -> f64 {
+        self.inner.received_rate()
+    }
+
+    /// Returns the number of transmitted bytes since the last refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {} B", network.transmitted());
+    /// }
+    /// ```
+    pub fn transmitted(&self) -> u64 {
+        self.inner.transmitted()
+    }
+
+    /// Returns the total number of transmitted bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {} B", network.total_transmitted());
+    /// }
+    /// ```
+    pub fn total_transmitted(&self) -> u64 {
+        self.inner.total_transmitted()
+    }
+
+    /// Returns the number of transmitted bytes per second, computed from the bytes transmitted
+    /// between the two last refreshes and the time elapsed between them.
+    ///
+    /// It returns `0` until the interface has been refreshed at least once after being listed.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// networks.refresh();
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}] out: {:.0} B/s", network.transmitted_rate());
+    /// }
+    /// ```
+    pub fn transmitted_rate(&self) -> f64 {
+        self.inner.transmitted_rate()
+    }
+
+    /// Returns the number of incoming packets since the last refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.packets_received());
+    /// }
+    /// ```
+    pub fn packets_received(&self) -> u64 {
+        self.inner.packets_received()
+    }
+
+    /// Returns the total number of incoming packets.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.total_packets_received());
+    /// }
+    /// ```
+    pub fn total_packets_received(&self) -> u64 {
+        self.inner.total_packets_received()
+    }
+
+    /// Returns the number of outcoming packets since the last refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.packets_transmitted());
+    /// }
+    /// ```
+    pub fn packets_transmitted(&self) -> u64 {
+        self.inner.packets_transmitted()
+    }
+
+    /// Returns the total number of outcoming packets.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.total_packets_transmitted());
+    /// }
+    /// ```
+    pub fn total_packets_transmitted(&self) -> u64 {
+        self.inner.total_packets_transmitted()
+    }
+
+    /// Returns the number of incoming errors since the last refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.errors_on_received());
+    /// }
+    /// ```
+    pub fn errors_on_received(&self) -> u64 {
+        self.inner.errors_on_received()
+    }
+
+    /// Returns the total number of incoming errors.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.total_errors_on_received());
+    /// }
+    /// ```
+    pub fn total_errors_on_received(&self) -> u64 {
+        self.inner.total_errors_on_received()
+    }
+
+    /// Returns the number of outcoming errors since the last refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.errors_on_transmitted());
+    /// }
+    /// ```
+    pub fn errors_on_transmitted(&self) -> u64 {
+        self.inner.errors_on_transmitted()
+    }
+
+    /// Returns the total number of outcoming errors.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.total_errors_on_transmitted());
+    /// }
+    /// ```
+    pub fn total_errors_on_transmitted(&self) -> u64 {
+        self.inner.total_errors_on_transmitted()
+    }
+
+    /// Returns the detailed packet counters of current interface, which are more precise than
+    /// [`NetworkData::total_errors_on_received`] and
+    /// [`NetworkData::total_errors_on_transmitted`].
+    ///
+    /// Like the other counters, they are updated by [`Networks::refresh`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     let counters = network.detailed_counters();
+    ///     if let Some(collisions) = counters.collisions {
+    ///         println!("[{interface_name}] collisions: {collisions}");
+    ///     }
+    /// }
+    /// ```
+    pub fn detailed_counters(&self) -> NetworkCounters {
+        self.inner.detailed_counters()
+    }
+
+    /// Returns the MAC address associated to current interface.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("MAC address: {}", network.mac_address());
+    /// }
+    /// ```
+    pub fn mac_address(&self) -> MacAddr {
+        self.inner.mac_address()
+    }
+
+    /// Returns the IP networks (addresses and prefix lengths) associated to current interface.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     for ip_network in network.ip_networks() {
+    ///         println!("{interface_name}: {ip_network}");
+    ///     }
+    /// }
+    /// ```
+    pub fn ip_networks(&self) -> &[IpNetwork] {
+        self.inner.ip_networks()
+    }
+
+    /// Returns the Maximum Transmission Unit (in bytes) of current interface.
+    ///
+    /// This value is updated when [`Networks::refresh_list`] is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: MTU {}", network.mtu());
+    /// }
+    /// ```
+    pub fn mtu(&self) -> u64 {
+        self.inner.mtu()
+    }
+
+    /// Returns the negotiated link speed (in bits per second) of current interface, if known.
+    ///
+    /// It can be used with [`NetworkData::received`] and [`NetworkData::transmitted`] to compute
+    /// the bandwidth usage of the interface.
+    ///
+    /// This value is updated when [`Networks::refresh_list`] is called.
+    ///
+    /// ⚠️ Virtual interfaces (like loopback or bridges) usually don't have a link speed.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(speed) = network.link_speed() {
+    ///         println!("{interface_name}: {} Mb/s", speed / 1_000_000);
+    ///     }
+    /// }
+    /// ```
+    pub fn link_speed(&self) -> Option<u64> {
+        self.inner.link_speed()
+    }
+
+    /// Returns the duplex mode of current interface.
+    ///
+    /// This value is updated when [`Networks::refresh_list`] is called.
+    ///
+    /// ⚠️ Always returns [`DuplexMode::Unknown`] on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: {:?}", network.duplex());
+    /// }
+    /// ```
+    pub fn duplex(&self) -> DuplexMode {
+        self.inner.duplex()
+    }
+
+    /// Returns the operational state of current interface, in other words whether it can pass
+    /// packets.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: {:?}", network.operational_state());
+    /// }
+    /// ```
+    pub fn operational_state(&self) -> OperationalState {
+        self.inner.operational_state()
+    }
+
+    /// Returns `true` if current interface has been enabled by the administrator (with
+    /// `ip link set up` or `ifconfig up` for example).
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: enabled: {}", network.is_admin_up());
+    /// }
+    /// ```
+    pub fn is_admin_up(&self) -> bool {
+        self.inner.is_admin_up()
+    }
+
+    /// Returns whether a carrier (physical link) is detected on current interface, if known.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: carrier: {:?}", network.has_carrier());
+    /// }
+    /// ```
+    pub fn has_carrier(&self) -> Option<bool> {
+        self.inner.has_carrier()
+    }
+
+    /// Returns how many times the carrier of current interface was lost or detected since it was
+    /// created.
+    ///
+    /// Comparing this value between two refreshes allows to detect a "flapping" link even if the
+    /// changes happened in between.
+    ///
+    /// ⚠️ Only available on Linux, returns `None` on other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: {:?} carrier changes", network.carrier_changes());
+    /// }
+    /// ```
+    pub fn carrier_changes(&self) -> Option<u64> {
+        self.inner.carrier_changes()
+    }
+
+    /// Returns the time (in seconds since UNIX epoch) at which a change of the operational state,
+    /// of the administrative state or of the carrier of current interface was detected.
+    ///
+    /// The change is detected by comparing the state between two calls to
+    /// [`Networks::refresh`] or [`Networks::refresh_list`], so it returns `None` until the state
+    /// changes once after the interface was added to the list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// std::thread::sleep(std::time::Duration::from_secs(10));
+    /// networks.refresh();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(last_change) = network.last_state_change() {
+    ///         println!("{interface_name}: state changed at {last_change}");
+    ///     }
+    /// }
+    /// ```
+    pub fn last_state_change(&self) -> Option<u64> {
+        self.inner.last_state_change()
+    }
+
+    /// Returns the kind of current interface.
+    ///
+    /// ```no_run
+    /// use sysinfo::{InterfaceKind, Networks};
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if network.kind() == InterfaceKind::Vlan {
+    ///         println!("{interface_name} is a VLAN");
+    ///     }
+    /// }
+    /// ```
+    pub fn kind(&self) -> InterfaceKind {
+        self.inner.kind()
+    }
+
+    /// Returns the description of current interface.
+    ///
+    /// ⚠️ On Windows, it is the name of the adapter (like "Intel(R) Ethernet Connection"), the
+    /// interface name being the one displayed in the settings. On Linux, it is the alias set with
+    /// `ip link set <interface> alias <description>`. It is always `None` on other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(description) = network.description() {
+    ///         println!("{interface_name}: {description}");
+    ///     }
+    /// }
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.inner.description()
+    }
+
+    /// Returns the name of the bond or bridge current interface is a member of.
+    ///
+    /// ⚠️ It is only supported on Linux and Windows, it always returns `None` on other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(master) = network.master() {
+    ///         println!("{interface_name} is a member of {master}");
+    ///     }
+    /// }
+    /// ```
+    pub fn master(&self) -> Option<&str> {
+        self.inner.master()
+    }
+
+    /// Returns the names of the interfaces current interface sits on: the parent interface of a
+    /// VLAN or the members of a bond or a bridge.
+    ///
+    /// The traffic of an interface is also counted on the interfaces it sits on, so the
+    /// interfaces returned by this method can be used to avoid counting it twice.
+    ///
+    /// ⚠️ On macOS and FreeBSD, only the parent interface of VLANs is returned.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// // Only count the traffic of the interfaces which don't sit on other interfaces.
+    /// let received: u64 = networks
+    ///     .iter()
+    ///     .filter(|(_, network)| network.lower_interfaces().is_empty())
+    ///     .map(|(_, network)| network.total_received())
+    ///     .sum();
+    /// println!("received: {received} B");
+    /// ```
+    pub fn lower_interfaces(&self) -> &[String] {
+        self.inner.lower_interfaces()
+    }
+
+    /// Returns the details of the Wi-Fi connection of current interface, or `None` if it is not
+    /// a connected wireless interface.
+    ///
+    /// It is updated when calling [`Networks::refresh`] or [`Networks::refresh_list`].
+    ///
+    /// ⚠️ Only available with the `wireless` feature, and only supported on Linux (through
+    /// nl80211), Windows (through the WLAN API) and macOS (through CoreWLAN). On macOS 14 and
+    /// later, the SSID and the BSSID are only available if the application is allowed to access
+    /// the location of the user.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(wireless) = network.wireless() {
+    ///         println!("{interface_name}: {:?} ({:?} dBm)", wireless.ssid, wireless.signal);
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "wireless")]
+    pub fn wireless(&self) -> Option<&WirelessInfo> {
+        self.inner.wireless()
+    }
+}
+
+/// Event returned by [`NetworkEvents::wait`].
+///
+/// ```no_run
+/// use sysinfo::{NetworkEvent, NetworkEvents};
+///
+/// if let Some(mut events) = NetworkEvents::new() {
+///     for event in events.wait(None) {
+///         match event {
+///             NetworkEvent::Added(name) => println!("{name} was added"),
+///             NetworkEvent::Removed(name) => println!("{name} was removed"),
+///             NetworkEvent::AddressChanged(name) => println!("{name} addresses changed"),
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkEvent {
+    /// The network interface with the given name appeared.
+    Added(String),
+    /// The network interface with the given name was removed.
+    Removed(String),
+    /// The IP addresses of the network interface with the given name changed.
+    AddressChanged(String),
+}
+
+/// Notifies when network interfaces appear, disappear or when their IP addresses change, so you
+/// don't need to call [`Networks::refresh_list`] periodically to find out (when a VPN is
+/// connected for example).
+///
+/// The interfaces are the ones listed by [`Networks::refresh_list`].
+///
+/// ```no_run
+/// use sysinfo::{NetworkEvents, Networks};
+///
+/// let mut networks = Networks::new_with_refreshed_list();
+/// if let Some(mut events) = NetworkEvents::new() {
+///     loop {
+///         if !events.wait(None).is_empty() {
+///             networks.refresh_list();
+///         }
+///     }
+/// }
+/// ```
+pub struct NetworkEvents {
+    inner: crate::NetworkEventsInner,
+    interfaces: HashMap<String, Vec<IpNetwork>>,
+}
+
+impl NetworkEvents {
+    /// Creates a new `NetworkEvents`. Returns `None` if the system doesn't support it.
+    ///
+    /// ```no_run
+    /// use sysinfo::NetworkEvents;
+    ///
+    /// let events = NetworkEvents::new();
+    /// println!("network events supported: {}", events.is_some());
+    /// ```
+    pub fn new() -> Option<Self> {
+        let inner = crate::NetworkEventsInner::new()?;
+        Some(Self {
+            inner,
+            interfaces: get_interfaces(),
+        })
+    }
+
+    /// Waits until network interfaces appear, disappear or have their IP addresses changed and
+    /// returns what changed since the previous call (or since [`NetworkEvents::new`]). If
+    /// `timeout` elapses before, an empty list is returned.
+    ///
+    /// ```no_run
+    /// use sysinfo::NetworkEvents;
+    /// use std::time::Duration;
+    ///
+    /// if let Some(mut events) = NetworkEvents::new() {
+    ///     for event in events.wait(Some(Duration::from_secs(10))) {
+    ///         println!("{event:?}");
+    ///     }
+    /// }
+    /// ```
+    pub fn wait(&mut self, timeout: Option<Duration>) -> Vec<NetworkEvent> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            let timeout =
+                deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+            if timeout == Some(Duration::ZERO) || !self.inner.wait(timeout) {
+                return Vec::new();
+            }
+            // The notifications are also sent for changes we don't report (like routes or
+            // flags), so we compare the interfaces.
+            let interfaces = get_interfaces();
+            let mut events = Vec::new();
+            for (name, addresses) in &self.interfaces {
+                match interfaces.get(name) {
+                    None => events.push(NetworkEvent::Removed(name.clone())),
+                    Some(new_addresses) if new_addresses != addresses => {
+                        events.push(NetworkEvent::AddressChanged(name.clone()))
+                    }
+                    Some(_) => {}
+                }
+            }
+            events.extend(
+                interfaces
+                    .keys()
+                    .filter(|name| !self.interfaces.contains_key(*name))
+                    .map(|name| NetworkEvent::Added(name.clone())),
+            );
+            self.interfaces = interfaces;
+            if !events.is_empty() {
+                return events;
+            }
+        }
+    }
+}
+
+fn get_interfaces() -> HashMap<String, Vec<IpNetwork>> {
+    Networks::new_with_refreshed_list()
+        .list()
+        .iter()
+        .map(|(name, network)| {
+            let mut addresses = network.ip_networks().to_vec();
+            // The order of the addresses isn't meaningful.
+            addresses.sort_unstable();
+            (name.clone(), addresses)
+        })
+        .collect()
+}
+
+/// Struct containing a disk information.
+///
+/// ```no_run
+/// use sysinfo::Disks;
+///
+/// let disks = Disks::new_with_refreshed_list();
+/// for disk in disks.list() {
+///     println!("{:?}: {:?}", disk.name(), disk.kind());
+/// }
+/// ```
+pub struct Disk {
+    pub(crate) inner: crate::DiskInner,
+}
+
+impl Disk {
+    /// Returns the kind of disk.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.kind());
+    /// }
+    /// ```
+    pub fn kind(&self) -> DiskKind {
+        self.inner.kind()
+    }
+
+    /// Returns the disk name.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("{:?}", disk.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &OsStr {
+        self.inner.name()
+    }
+
+    /// Returns the file system used on this disk (so for example: `EXT4`, `NTFS`, etc...).
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.file_system());
+    /// }
+    /// ```
+    pub fn file_system(&self) -> &OsStr {
+        self.inner.file_system()
+    }
+
+    /// Returns the mount point of the disk (`/` for example).
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.mount_point());
+    /// }
+    /// ```
+    pub fn mount_point(&self) -> &Path {
+        self.inner.mount_point()
+    }
+
+    /// Returns the total disk size, in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
     ///     println!("[{:?}] {}B", disk.name(), disk.total_space());
     /// }
     /// ```
-    pub fn total_space(&self) -> u64 {
-        self.inner.total_space()
+    pub fn total_space(&self) -> u64 {
+        self.inner.total_space()
+    }
+
+    /// Returns the available disk size, in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {}B", disk.name(), disk.available_space());
+    /// }
+    /// ```
+    pub fn available_space(&self) -> u64 {
+        self.inner.available_space()
+    }
+
+    /// Returns `true` if the disk is removable.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {}", disk.name(), disk.is_removable());
+    /// }
+    /// ```
+    pub fn is_removable(&self) -> bool {
+        self.inner.is_removable()
+    }
+
+    /// Returns `true` if the disk is a network file system (like NFS or SMB).
+    ///
+    /// Network disks are only listed if [`DiskRefreshKind::network_disks`] is enabled.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskRefreshKind, Disks};
+    ///
+    /// let disks = Disks::new_with_refreshed_list_specifics(DiskRefreshKind::everything());
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {}", disk.name(), disk.is_network());
+    /// }
+    /// ```
+    pub fn is_network(&self) -> bool {
+        self.inner.is_network()
+    }
+
+    /// Returns the host serving this network disk (like `nas.local` for `nas.local:/export`
+    /// or `//nas.local/share`).
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskRefreshKind, Disks};
+    ///
+    /// let disks = Disks::new_with_refreshed_list_specifics(DiskRefreshKind::everything());
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.remote_host());
+    /// }
+    /// ```
+    pub fn remote_host(&self) -> Option<&str> {
+        self.inner.remote_host()
+    }
+
+    /// Returns the path of this network disk on its host (like `/export` for
+    /// `nas.local:/export` or `/share` for `//nas.local/share`).
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskRefreshKind, Disks};
+    ///
+    /// let disks = Disks::new_with_refreshed_list_specifics(DiskRefreshKind::everything());
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.remote_path());
+    /// }
+    /// ```
+    pub fn remote_path(&self) -> Option<&str> {
+        self.inner.remote_path()
+    }
+
+    /// Returns the UUID of the disk's file system (or the GUID of the volume on Windows), if
+    /// available.
+    ///
+    /// Unlike the mount point, it doesn't change when the disk is remounted somewhere else (or
+    /// gets another drive letter on Windows), so it can be used to identify a disk.
+    ///
+    /// ⚠️ Not available on FreeBSD.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.mount_point(), disk.uuid());
+    /// }
+    /// ```
+    pub fn uuid(&self) -> Option<&str> {
+        self.inner.uuid()
+    }
+
+    /// Returns the vendor of the device containing this disk, if available.
+    ///
+    /// ⚠️ Not available on iOS and FreeBSD.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.vendor());
+    /// }
+    /// ```
+    pub fn vendor(&self) -> Option<&str> {
+        self.inner.vendor()
+    }
+
+    /// Returns the model of the device containing this disk, if available.
+    ///
+    /// ⚠️ Not available on iOS and FreeBSD.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.model());
+    /// }
+    /// ```
+    pub fn model(&self) -> Option<&str> {
+        self.inner.model()
+    }
+
+    /// Returns the serial number of the device containing this disk, if available.
+    ///
+    /// ⚠️ Not available on iOS and FreeBSD.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.serial_number());
+    /// }
+    /// ```
+    pub fn serial_number(&self) -> Option<&str> {
+        self.inner.serial_number()
+    }
+
+    /// Returns the name of the physical disk containing this disk, if any. It matches
+    /// [`PhysicalDisk::name`] of one of the entries returned by [`Disks::physical_disks`].
+    ///
+    /// `None` is returned if the disk isn't backed by a physical disk (like a network mount or a
+    /// loop device) or if it is backed by more than one (like a RAID array or a LVM volume).
+    ///
+    /// ⚠️ Not available on iOS and FreeBSD.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("{:?} is on {:?}", disk.mount_point(), disk.physical_disk());
+    /// }
+    /// ```
+    pub fn physical_disk(&self) -> Option<&str> {
+        self.inner.physical_disk()
+    }
+
+    /// Returns the name of the storage pool containing this disk, if any. It matches
+    /// [`StoragePool::name`] of one of the entries returned by [`Disks::storage_pools`].
+    ///
+    /// The space returned by [`Disk::total_space`] and [`Disk::available_space`] is often
+    /// misleading for disks in a storage pool (the space is shared between all the file systems
+    /// of a pool and redundancy isn't taken into account), so it's better to use the pool's
+    /// information.
+    ///
+    /// ⚠️ Only available on Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("{:?} is in {:?}", disk.mount_point(), disk.storage_pool());
+    /// }
+    /// ```
+    pub fn storage_pool(&self) -> Option<&str> {
+        self.inner.storage_pool()
+    }
+
+    /// Returns the block device this disk is mounted from, along with the block devices it is
+    /// built on (like LUKS containers, LVM volumes or RAID arrays), down to the physical disks.
+    ///
+    /// ⚠️ Only available on Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     if let Some(block_device) = disk.block_device() {
+    ///         println!(
+    ///             "{:?} is backed by {:?}",
+    ///             disk.mount_point(),
+    ///             block_device.physical_disks(),
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    pub fn block_device(&self) -> Option<&BlockDevice> {
+        self.inner.block_device()
+    }
+
+    /// Returns `true` if the disk is rotational (like a HDD), `false` if it isn't (like a SSD)
+    /// and `None` if it is unknown.
+    ///
+    /// ⚠️ Not available on FreeBSD.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.is_rotational());
+    /// }
+    /// ```
+    pub fn is_rotational(&self) -> Option<bool> {
+        self.inner.is_rotational()
+    }
+
+    /// Returns the logical sector size of the disk in bytes, which is the smallest unit the disk
+    /// can address.
+    ///
+    /// ⚠️ Not available on iOS and FreeBSD.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.logical_sector_size());
+    /// }
+    /// ```
+    pub fn logical_sector_size(&self) -> Option<u64> {
+        self.inner.logical_sector_size()
+    }
+
+    /// Returns the physical sector size of the disk in bytes, which is the smallest unit the disk
+    /// can write without a read-modify-write cycle. It can be bigger than
+    /// [`Disk::logical_sector_size`] (like on "Advanced Format" disks).
+    ///
+    /// ⚠️ Not available on iOS and FreeBSD.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.physical_sector_size());
+    /// }
+    /// ```
+    pub fn physical_sector_size(&self) -> Option<u64> {
+        self.inner.physical_sector_size()
+    }
+
+    /// Returns `true` if the disk supports TRIM (also known as discard or unmap), `None` if it is
+    /// unknown.
+    ///
+    /// ⚠️ Not available on iOS and FreeBSD.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.supports_trim());
+    /// }
+    /// ```
+    pub fn supports_trim(&self) -> Option<bool> {
+        self.inner.supports_trim()
+    }
+
+    /// Returns the quota of the given user on this disk.
+    ///
+    /// `None` is returned if quotas aren't enabled on the disk or if they can't be retrieved
+    /// (only privileged users can get the quota of other users).
+    ///
+    /// ⚠️ Only available on Linux and Windows (NTFS).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Disks, Users};
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     for disk in disks.list() {
+    ///         println!("[{}] {:?}", user.name(), disk.user_quota(user.id()));
+    ///     }
+    /// }
+    /// ```
+    pub fn user_quota(&self, user_id: &Uid) -> Option<DiskQuota> {
+        self.inner.user_quota(user_id)
+    }
+
+    /// Returns the quota of the given group on this disk.
+    ///
+    /// `None` is returned if quotas aren't enabled on the disk or if they can't be retrieved
+    /// (only privileged users can get the quota of groups they're not member of).
+    ///
+    /// ⚠️ Only available on Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Disks, Users};
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     for disk in disks.list() {
+    ///         println!("[{}] {:?}", user.name(), disk.group_quota(user.group_id()));
+    ///     }
+    /// }
+    /// ```
+    pub fn group_quota(&self, group_id: Gid) -> Option<DiskQuota> {
+        self.inner.group_quota(group_id)
+    }
+
+    /// Updates the disk' information.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list_mut() {
+    ///     disk.refresh();
+    /// }
+    /// ```
+    pub fn refresh(&mut self) -> bool {
+        self.inner.refresh()
+    }
+}
+
+/// Disks interface.
+///
+/// ```no_run
+/// use sysinfo::Disks;
+///
+/// let disks = Disks::new_with_refreshed_list();
+/// for disk in disks.list() {
+///     println!("{disk:?}");
+/// }
+/// ```
+pub struct Disks {
+    inner: crate::DisksInner,
+}
+
+impl Default for Disks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Disks> for Vec<Disk> {
+    fn from(disks: Disks) -> Vec<Disk> {
+        disks.inner.into_vec()
+    }
+}
+
+impl From<Vec<Disk>> for Disks {
+    fn from(disks: Vec<Disk>) -> Self {
+        Self {
+            inner: crate::DisksInner::from_vec(disks),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Disks {
+    type Item = &'a Disk;
+    type IntoIter = std::slice::Iter<'a, Disk>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Disks {
+    type Item = &'a mut Disk;
+    type IntoIter = std::slice::IterMut<'a, Disk>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl Disks {
+    /// Creates a new empty [`Disks`][crate::Disks] type.
+    ///
+    /// If you want it to be filled directly, take a look at [`Disks::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new();
+    /// disks.refresh_list();
+    /// for disk in disks.list() {
+    ///     eprintln!("{disk:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            inner: crate::DisksInner::new(),
+        }
+    }
+
+    /// Creates a new [`Disks`][crate::Disks] type with the disk list loaded.
+    /// It is a combination of [`Disks::new`] and [`Disks::refresh_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     eprintln!("{disk:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut disks = Self::new();
+        disks.refresh_list();
+        disks
+    }
+
+    /// Creates a new [`Disks`][crate::Disks] type with the disk list loaded according to the
+    /// given [`DiskRefreshKind`]. It is a combination of [`Disks::new`] and
+    /// [`Disks::refresh_list_specifics`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskRefreshKind, Disks};
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list_specifics(DiskRefreshKind::everything());
+    /// for disk in disks.list() {
+    ///     eprintln!("{disk:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list_specifics(refresh_kind: DiskRefreshKind) -> Self {
+        let mut disks = Self::new();
+        disks.refresh_list_specifics(refresh_kind);
+        disks
+    }
+
+    /// Returns the disks list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     eprintln!("{disk:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[Disk] {
+        self.inner.list()
+    }
+
+    /// Returns the disks list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list_mut() {
+    ///     disk.refresh();
+    ///     eprintln!("{disk:?}");
+    /// }
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [Disk] {
+        self.inner.list_mut()
+    }
+
+    /// Returns the physical disks list. Use [`Disk::physical_disk`] to know on which of them a
+    /// disk is.
+    ///
+    /// This list is updated by [`Disks::refresh_list`].
+    ///
+    /// ⚠️ Not available on iOS and FreeBSD.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for physical_disk in disks.physical_disks() {
+    ///     println!("{}:", physical_disk.name);
+    ///     for disk in disks.list() {
+    ///         if disk.physical_disk() == Some(physical_disk.name.as_str()) {
+    ///             println!("  {:?}", disk.mount_point());
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn physical_disks(&self) -> &[PhysicalDisk] {
+        self.inner.physical_disks()
+    }
+
+    /// Returns the storage pools (like ZFS pools or btrfs file systems) list. Use
+    /// [`Disk::storage_pool`] to know in which of them a disk is.
+    ///
+    /// This list is updated by [`Disks::refresh_list`].
+    ///
+    /// ⚠️ Only available on Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for pool in disks.storage_pools() {
+    ///     println!("{} ({:?}): {:?}", pool.name, pool.kind, pool.health);
+    /// }
+    /// ```
+    pub fn storage_pools(&self) -> &[StoragePool] {
+        self.inner.storage_pools()
+    }
+
+    /// Refreshes the listed disks' information.
+    ///
+    /// ⚠️ If a disk is added or removed, this method won't take it into account. Use
+    /// [`Disks::refresh_list`] instead.
+    ///
+    /// ⚠️ If you didn't call [`Disks::refresh_list`] beforehand, this method will do nothing as
+    /// the disk list will be empty.
+    ///
+    /// With the `parallel` feature, the disks are refreshed at the same time.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// // We wait some time...?
+    /// disks.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+
+            self.list_mut().par_iter_mut().for_each(|disk| {
+                disk.refresh();
+            });
+        }
+        #[cfg(not(feature = "parallel"))]
+        for disk in self.list_mut() {
+            disk.refresh();
+        }
+    }
+
+    /// The disk list will be emptied then completely recomputed.
+    ///
+    /// ⚠️ Network disks (like [NFS](https://en.wikipedia.org/wiki/Network_File_System) mounts)
+    /// are ignored. This is due to the fact that I/O function `statvfs` used by
+    /// [`Disks::refresh_list`] is blocking and
+    /// [may hang](https://github.com/GuillaumeGomez/sysinfo/pull/876) in some cases,
+    /// requiring to call `systemctl stop` to terminate the NFS service from the remote
+    /// server in some cases. Use [`Disks::refresh_list_specifics`] to list them.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new();
+    /// disks.refresh_list();
+    /// ```
+    pub fn refresh_list(&mut self) {
+        self.refresh_list_specifics(DiskRefreshKind::new());
+    }
+
+    /// The disk list will be emptied then completely recomputed according to the given
+    /// [`DiskRefreshKind`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskRefreshKind, Disks};
+    ///
+    /// let mut disks = Disks::new();
+    /// // Lists the network disks without calling `statvfs` on them.
+    /// disks.refresh_list_specifics(DiskRefreshKind::new().with_network_disks());
+    /// ```
+    pub fn refresh_list_specifics(&mut self, refresh_kind: DiskRefreshKind) {
+        self.inner.refresh_list(refresh_kind);
+    }
+
+    /// Same as [`Disks::refresh_list_specifics`] but returns what failed during the refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskRefreshKind, Disks};
+    ///
+    /// let mut disks = Disks::new();
+    /// if let Err(report) = disks.try_refresh_list_specifics(DiskRefreshKind::new()) {
+    ///     for error in report.errors() {
+    ///         eprintln!("{error}");
+    ///     }
+    /// }
+    /// ```
+    pub fn try_refresh_list_specifics(
+        &mut self,
+        refresh_kind: DiskRefreshKind,
+    ) -> Result<(), RefreshReport> {
+        crate::utils::collect_errors(|| self.refresh_list_specifics(refresh_kind))
+    }
+}
+
+impl std::ops::Deref for Disks {
+    type Target = [Disk];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for Disks {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+/// Enum containing the different supported kinds of disks.
+///
+/// This type is returned by [`Disk::kind`](`crate::Disk::kind`).
+///
+/// ```no_run
+/// use sysinfo::Disks;
+///
+/// let disks = Disks::new_with_refreshed_list();
+/// for disk in disks.list() {
+///     println!("{:?}: {:?}", disk.name(), disk.kind());
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DiskKind {
+    /// HDD type.
+    HDD,
+    /// SSD type.
+    SSD,
+    /// Unknown type.
+    Unknown(isize),
+}
+
+impl fmt::Display for DiskKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            DiskKind::HDD => "HDD",
+            DiskKind::SSD => "SSD",
+            _ => "Unknown",
+        })
+    }
+}
+
+/// Event returned by [`DiskEvents::wait`].
+///
+/// ```no_run
+/// use sysinfo::{DiskEvent, DiskEvents};
+///
+/// if let Some(mut events) = DiskEvents::new() {
+///     for event in events.wait(None) {
+///         match event {
+///             DiskEvent::Added(mount_point) => println!("{mount_point:?} was added"),
+///             DiskEvent::Removed(mount_point) => println!("{mount_point:?} was removed"),
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiskEvent {
+    /// A disk was mounted on the given mount point.
+    Added(PathBuf),
+    /// The disk mounted on the given mount point was removed.
+    Removed(PathBuf),
+}
+
+/// Notifies when disks are added or removed, so you don't need to call
+/// [`Disks::refresh_list`] periodically to find out.
+///
+/// The disks are the ones listed by [`Disks::refresh_list`], so network disks are ignored.
+///
+/// ```no_run
+/// use sysinfo::{DiskEvents, Disks};
+///
+/// let mut disks = Disks::new_with_refreshed_list();
+/// if let Some(mut events) = DiskEvents::new() {
+///     loop {
+///         if !events.wait(None).is_empty() {
+///             disks.refresh_list();
+///         }
+///     }
+/// }
+/// ```
+pub struct DiskEvents {
+    inner: crate::DiskEventsInner,
+    mount_points: Vec<PathBuf>,
+}
+
+impl DiskEvents {
+    /// Creates a new `DiskEvents`. Returns `None` if the system doesn't support it.
+    ///
+    /// ```no_run
+    /// use sysinfo::DiskEvents;
+    ///
+    /// let events = DiskEvents::new();
+    /// println!("disk events supported: {}", events.is_some());
+    /// ```
+    pub fn new() -> Option<Self> {
+        let inner = crate::DiskEventsInner::new()?;
+        Some(Self {
+            inner,
+            mount_points: get_mount_points(),
+        })
+    }
+
+    /// Waits until disks are added or removed and returns what changed since the previous call
+    /// (or since [`DiskEvents::new`]). If `timeout` elapses before, an empty list is returned.
+    ///
+    /// ```no_run
+    /// use sysinfo::DiskEvents;
+    /// use std::time::Duration;
+    ///
+    /// if let Some(mut events) = DiskEvents::new() {
+    ///     for event in events.wait(Some(Duration::from_secs(10))) {
+    ///         println!("{event:?}");
+    ///     }
+    /// }
+    /// ```
+    pub fn wait(&mut self, timeout: Option<Duration>) -> Vec<DiskEvent> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            let timeout =
+                deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+            if timeout == Some(Duration::ZERO) || !self.inner.wait(timeout) {
+                return Vec::new();
+            }
+            // The notification doesn't say what changed, and it may be about something else
+            // (like a network disk), so we compare the mount points.
+            let mount_points = get_mount_points();
+            let events = self
+                .mount_points
+                .iter()
+                .filter(|mount_point| !mount_points.contains(mount_point))
+                .map(|mount_point| DiskEvent::Removed(mount_point.clone()))
+                .chain(
+                    mount_points
+                        .iter()
+                        .filter(|mount_point| !self.mount_points.contains(mount_point))
+                        .map(|mount_point| DiskEvent::Added(mount_point.clone())),
+                )
+                .collect::<Vec<_>>();
+            self.mount_points = mount_points;
+            if !events.is_empty() {
+                return events;
+            }
+        }
+    }
+}
+
+fn get_mount_points() -> Vec<PathBuf> {
+    Disks::new_with_refreshed_list()
+        .list()
+        .iter()
+        .map(|disk| disk.mount_point().to_path_buf())
+        .collect()
+}
+
+/// Information about a physical disk.
+///
+/// It is returned by [`Disks::physical_disks`].
+///
+/// ```no_run
+/// use sysinfo::Disks;
+///
+/// let disks = Disks::new_with_refreshed_list();
+/// for physical_disk in disks.physical_disks() {
+///     println!("{}: {} bytes", physical_disk.name, physical_disk.total_space);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhysicalDisk {
+    /// Name of the disk (like `sda` on Linux, `disk0` on macOS or `PhysicalDrive0` on Windows).
+    pub name: String,
+    /// Kind of the disk.
+    pub kind: DiskKind,
+    /// Size of the disk in bytes.
+    pub total_space: u64,
+    /// Whether the disk is removable.
+    pub is_removable: bool,
+}
+
+/// A block device, along with the block devices it is built on.
+///
+/// It is returned by [`Disk::block_device`].
+///
+/// ```no_run
+/// use sysinfo::{BlockDevice, Disks};
+///
+/// fn print_stack(block_device: &BlockDevice, depth: usize) {
+///     println!("{:depth$}{} ({:?})", "", block_device.name, block_device.kind);
+///     for backing_device in &block_device.backing_devices {
+///         print_stack(backing_device, depth + 2);
+///     }
+/// }
+///
+/// let disks = Disks::new_with_refreshed_list();
+/// for disk in disks.list() {
+///     if let Some(block_device) = disk.block_device() {
+///         print_stack(block_device, 0);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockDevice {
+    /// Name of the block device (like `dm-0`, `md0` or `sda1`).
+    pub name: String,
+    /// Kind of the block device.
+    pub kind: BlockDeviceKind,
+    /// Block devices this one is built on. It is empty for physical disks.
+    pub backing_devices: Vec<BlockDevice>,
+}
+
+impl BlockDevice {
+    /// Returns the names of the physical disks this block device is built on. They match
+    /// [`PhysicalDisk::name`] of entries returned by [`Disks::physical_disks`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     if let Some(block_device) = disk.block_device() {
+    ///         println!("{:?}: {:?}", disk.mount_point(), block_device.physical_disks());
+    ///     }
+    /// }
+    /// ```
+    pub fn physical_disks(&self) -> Vec<&str> {
+        fn add<'a>(block_device: &'a BlockDevice, physical_disks: &mut Vec<&'a str>) {
+            if block_device.kind == BlockDeviceKind::Disk {
+                if !physical_disks.contains(&block_device.name.as_str()) {
+                    physical_disks.push(&block_device.name);
+                }
+            } else {
+                for backing_device in &block_device.backing_devices {
+                    add(backing_device, physical_disks);
+                }
+            }
+        }
+
+        let mut physical_disks = Vec::new();
+        add(self, &mut physical_disks);
+        physical_disks
+    }
+}
+
+/// Kind of a [`BlockDevice`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockDeviceKind {
+    /// Physical disk.
+    Disk,
+    /// Partition of another block device.
+    Partition,
+    /// Encrypted device (like a LUKS container).
+    Crypt,
+    /// LVM logical volume.
+    Lvm,
+    /// Software RAID array.
+    Raid,
+    /// Other device-mapper device (like a multipath device).
+    DeviceMapper,
+    /// Loop device, backed by a file.
+    Loop,
+    /// Unknown kind.
+    Unknown,
+}
+
+/// Information about a storage pool, which is a file system spanning several devices and managing
+/// their redundancy (like ZFS pools or btrfs file systems).
+///
+/// It is returned by [`Disks::storage_pools`]. The space information is `None` if it isn't
+/// available (which is the case for ZFS pools on Linux).
+///
+/// ```no_run
+/// use sysinfo::Disks;
+///
+/// let disks = Disks::new_with_refreshed_list();
+/// for pool in disks.storage_pools() {
+///     println!("{}: {:?} / {:?} bytes", pool.name, pool.used_space, pool.usable_space);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoragePool {
+    /// Name of the pool. For btrfs, it is the label of the file system or its UUID if it has no
+    /// label.
+    pub name: String,
+    /// Kind of the pool.
+    pub kind: StoragePoolKind,
+    /// Health of the pool.
+    pub health: StoragePoolHealth,
+    /// Names of the block devices in the pool (like `sda1`).
+    pub devices: Vec<String>,
+    /// Raw size (in bytes) of all the devices in the pool.
+    pub total_space: Option<u64>,
+    /// Raw space (in bytes) allocated on the devices, including the redundancy.
+    pub allocated_space: Option<u64>,
+    /// Estimated space (in bytes) to store data once the redundancy is taken into account.
+    pub usable_space: Option<u64>,
+    /// Space (in bytes) used by data, without the redundancy.
+    pub used_space: Option<u64>,
+}
+
+/// Kind of a [`StoragePool`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoragePoolKind {
+    /// ZFS pool.
+    ZFS,
+    /// btrfs file system.
+    Btrfs,
+}
+
+/// Health of a [`StoragePool`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoragePoolHealth {
+    /// All the devices are working.
+    Online,
+    /// Some devices are missing or failing but data is still available thanks to redundancy.
+    Degraded,
+    /// Data isn't available anymore.
+    Faulted,
+    /// The pool was taken offline.
+    Offline,
+    /// The pool can't be opened.
+    Unavailable,
+    /// The devices of the pool were removed.
+    Removed,
+    /// I/O on the pool is suspended after failures.
+    Suspended,
+    /// Unknown health.
+    Unknown,
+}
+
+/// Quota of a user or of a group on a [`Disk`].
+///
+/// It is returned by [`Disk::user_quota`] and [`Disk::group_quota`]. Limits are `None` when
+/// there is no limit.
+///
+/// ```no_run
+/// use sysinfo::{Disks, Users};
+///
+/// let users = Users::new_with_refreshed_list();
+/// let disks = Disks::new_with_refreshed_list();
+/// for user in users.list() {
+///     for disk in disks.list() {
+///         if let Some(quota) = disk.user_quota(user.id()) {
+///             println!("[{}] {} bytes used", user.name(), quota.used_space);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DiskQuota {
+    /// Space used, in bytes.
+    pub used_space: u64,
+    /// Space limit (in bytes) which can be exceeded for a grace period. On Windows, it is the
+    /// warning threshold.
+    pub soft_space_limit: Option<u64>,
+    /// Space limit (in bytes) which can't be exceeded.
+    pub hard_space_limit: Option<u64>,
+    /// Number of inodes used. It is `None` if the file system doesn't track them.
+    pub used_inodes: Option<u64>,
+    /// Inodes limit which can be exceeded for a grace period.
+    pub soft_inode_limit: Option<u64>,
+    /// Inodes limit which can't be exceeded.
+    pub hard_inode_limit: Option<u64>,
+}
+
+/// Interacting with users.
+///
+/// ```no_run
+/// use sysinfo::Users;
+///
+/// let mut users = Users::new();
+/// for user in users.list() {
+///     println!("{} is in {} groups", user.name(), user.groups().len());
+/// }
+/// ```
+pub struct Users {
+    users: Vec<User>,
+}
+
+impl Default for Users {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Users> for Vec<User> {
+    fn from(users: Users) -> Self {
+        users.users
+    }
+}
+
+impl From<Vec<User>> for Users {
+    fn from(users: Vec<User>) -> Self {
+        Self { users }
+    }
+}
+
+impl std::ops::Deref for Users {
+    type Target = [User];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for Users {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a Users {
+    type Item = &'a User;
+    type IntoIter = std::slice::Iter<'a, User>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Users {
+    type Item = &'a mut User;
+    type IntoIter = std::slice::IterMut<'a, User>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl Users {
+    /// Creates a new empty [`Users`][crate::Users] type.
+    ///
+    /// If you want it to be filled directly, take a look at [`Users::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let mut users = Users::new();
+    /// users.refresh_list();
+    /// for user in users.list() {
+    ///     eprintln!("{user:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self { users: Vec::new() }
+    }
+
+    /// Creates a new [`Users`][crate::Users] type with the user list loaded.
+    /// It is a combination of [`Users::new`] and [`Users::refresh_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let mut users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     eprintln!("{user:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut users = Self::new();
+        users.refresh_list();
+        users
+    }
+
+    /// Creates a new [`Users`][crate::Users] type with the user list loaded according to the
+    /// given [`UserRefreshKind`]. It is a combination of [`Users::new`] and
+    /// [`Users::refresh_list_specifics`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{UserRefreshKind, Users};
+    ///
+    /// let users = Users::new_with_refreshed_list_specifics(UserRefreshKind::new());
+    /// for user in users.list() {
+    ///     eprintln!("{}", user.name());
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list_specifics(refresh_kind: UserRefreshKind) -> Self {
+        let mut users = Self::new();
+        users.refresh_list_specifics(refresh_kind);
+        users
+    }
+
+    /// Returns the users list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     eprintln!("{user:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[User] {
+        &self.users
+    }
+
+    /// Returns the users list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let mut users = Users::new_with_refreshed_list();
+    /// users.list_mut().sort_by(|user1, user2| {
+    ///     user1.name().partial_cmp(user2.name()).unwrap()
+    /// });
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [User] {
+        &mut self.users
+    }
+
+    /// The user list will be emptied then completely recomputed.
+    ///
+    /// It retrieves the home directories but not the groups of the users. If you want to
+    /// control what is retrieved, use [`Users::refresh_list_specifics`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let mut users = Users::new();
+    /// users.refresh_list();
+    /// ```
+    pub fn refresh_list(&mut self) {
+        self.refresh_list_specifics(UserRefreshKind::new().with_home_dir());
+    }
+
+    /// The user list will be emptied then completely recomputed according to the given
+    /// [`UserRefreshKind`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{UserRefreshKind, Users};
+    ///
+    /// let mut users = Users::new();
+    /// // Resolves the groups once, so `User::groups` doesn't compute them again.
+    /// users.refresh_list_specifics(UserRefreshKind::new().with_groups());
+    /// ```
+    pub fn refresh_list_specifics(&mut self, refresh_kind: UserRefreshKind) {
+        crate::sys::get_users(&mut self.users, refresh_kind);
+        if refresh_kind.groups() {
+            for user in self.users.iter_mut() {
+                user.groups = Some(user.inner.groups(DEFAULT_GROUP_REFRESH_KIND));
+            }
+        }
+    }
+
+    /// Returns the [`User`] matching the given `user_id`.
+    ///
+    /// **Important**: The user list must be filled before using this method, otherwise it will
+    /// always return `None` (through the `refresh_*` methods).
+    ///
+    /// Only the listed users can be found. To get the name of any user, like a domain user,
+    /// take a look at [`Uid::resolve_name`].
+    ///
+    /// It is a shorthand for:
+    ///
+    /// ```ignore
+    /// # use sysinfo::Users;
+    /// let users = Users::new_with_refreshed_list();
+    /// users.list().find(|user| user.id() == user_id);
+    /// ```
+    ///
+    /// Full example:
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System, Users};
+    ///
+    /// let mut s = System::new_all();
+    /// let users = Users::new_with_refreshed_list();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(user_id) = process.user_id() {
+    ///         eprintln!("User for process 1337: {:?}", users.get_user_by_id(user_id));
+    ///     }
+    /// }
+    /// ```
+    pub fn get_user_by_id(&self, user_id: &Uid) -> Option<&User> {
+        self.users.iter().find(|user| user.id() == user_id)
+    }
+}
+
+/// Interacting with the network connections (TCP and UDP sockets) of the system.
+///
+/// ```no_run
+/// use sysinfo::Connections;
+///
+/// let connections = Connections::new_with_refreshed_list();
+/// for connection in connections.list() {
+///     println!(
+///         "{:?} {} -> {:?} ({:?})",
+///         connection.protocol, connection.local_address, connection.remote_address, connection.pid,
+///     );
+/// }
+/// ```
+pub struct Connections {
+    connections: Vec<Connection>,
+}
+
+impl Default for Connections {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Connections> for Vec<Connection> {
+    fn from(connections: Connections) -> Self {
+        connections.connections
+    }
+}
+
+impl From<Vec<Connection>> for Connections {
+    fn from(connections: Vec<Connection>) -> Self {
+        Self { connections }
+    }
+}
+
+impl std::ops::Deref for Connections {
+    type Target = [Connection];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for Connections {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a Connections {
+    type Item = &'a Connection;
+    type IntoIter = std::slice::Iter<'a, Connection>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Connections {
+    type Item = &'a mut Connection;
+    type IntoIter = std::slice::IterMut<'a, Connection>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl Connections {
+    /// Creates a new empty [`Connections`][crate::Connections] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`Connections::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let mut connections = Connections::new();
+    /// connections.refresh_list();
+    /// for connection in connections.list() {
+    ///     eprintln!("{connection:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            connections: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`Connections`][crate::Connections] type with the connection list loaded.
+    /// It is a combination of [`Connections::new`] and [`Connections::refresh_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let connections = Connections::new_with_refreshed_list();
+    /// for connection in connections.list() {
+    ///     eprintln!("{connection:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut connections = Self::new();
+        connections.refresh_list();
+        connections
+    }
+
+    /// Returns the connection list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let connections = Connections::new_with_refreshed_list();
+    /// for connection in connections.list() {
+    ///     eprintln!("{connection:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[Connection] {
+        &self.connections
+    }
+
+    /// Returns the connection list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let mut connections = Connections::new_with_refreshed_list();
+    /// connections
+    ///     .list_mut()
+    ///     .sort_by_key(|connection| connection.local_address.port());
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [Connection] {
+        &mut self.connections
+    }
+
+    /// The connection list will be emptied then completely recomputed.
+    ///
+    /// ⚠️ On Linux and macOS, the owning process of the sockets can only be found for the
+    /// processes of the current user, unless running as root. It is not supported on FreeBSD and
+    /// iOS, where the list is always empty.
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let mut connections = Connections::new();
+    /// connections.refresh_list();
+    /// ```
+    pub fn refresh_list(&mut self) {
+        self.connections.clear();
+        crate::sys::get_connections(&mut self.connections);
+    }
+
+    /// Counts the sockets of the system by state, without building the connection list. It is
+    /// much cheaper than [`Connections::refresh_list`] if only the totals are needed.
+    ///
+    /// ⚠️ On macOS, the connection list is built anyway. It is not supported on FreeBSD and iOS,
+    /// where all the counts are `0`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let summary = Connections::summary();
+    /// println!(
+    ///     "{} established, {} listening TCP sockets",
+    ///     summary.tcp_established, summary.tcp_listening,
+    /// );
+    /// ```
+    pub fn summary() -> ConnectionSummary {
+        crate::sys::get_connection_summary()
+    }
+}
+
+/// Number of sockets of the system by state.
+///
+/// It is returned by [`Connections::summary`].
+///
+/// ```no_run
+/// use sysinfo::Connections;
+///
+/// let summary = Connections::summary();
+/// println!("{} TCP sockets, {} UDP sockets", summary.tcp_total, summary.udp);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ConnectionSummary {
+    /// Number of TCP sockets in the [`TcpState::Established`] state.
+    pub tcp_established: usize,
+    /// Number of TCP sockets in the [`TcpState::Listen`] state.
+    pub tcp_listening: usize,
+    /// Number of TCP sockets in the [`TcpState::TimeWait`] state.
+    pub tcp_time_wait: usize,
+    /// Number of TCP sockets, whatever their state.
+    pub tcp_total: usize,
+    /// Number of UDP sockets.
+    pub udp: usize,
+}
+
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "windows",
+        all(target_os = "macos", not(feature = "apple-sandbox")),
+    ),
+    not(feature = "unknown-ci")
+))]
+impl ConnectionSummary {
+    pub(crate) fn add(&mut self, protocol: ConnectionProtocol, state: Option<TcpState>) {
+        match protocol {
+            ConnectionProtocol::Tcp => {
+                self.tcp_total += 1;
+                match state {
+                    Some(TcpState::Established) => self.tcp_established += 1,
+                    Some(TcpState::Listen) => self.tcp_listening += 1,
+                    Some(TcpState::TimeWait) => self.tcp_time_wait += 1,
+                    _ => {}
+                }
+            }
+            ConnectionProtocol::Udp => self.udp += 1,
+        }
+    }
+}
+
+/// A TCP or UDP socket.
+///
+/// It is returned by [`Connections::list`].
+///
+/// ```no_run
+/// use sysinfo::{Connections, TcpState};
+///
+/// let connections = Connections::new_with_refreshed_list();
+/// for connection in connections.list() {
+///     if connection.state == Some(TcpState::Listen) {
+///         println!("listening on {}", connection.local_address);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Connection {
+    /// Protocol of the socket.
+    pub protocol: ConnectionProtocol,
+    /// Local address and port of the socket.
+    pub local_address: SocketAddr,
+    /// Remote address and port of the socket. It is `None` for listening TCP sockets and for
+    /// unconnected UDP sockets.
+    pub remote_address: Option<SocketAddr>,
+    /// State of the TCP connection. It is `None` for UDP sockets.
+    pub state: Option<TcpState>,
+    /// PID of the process owning the socket, if it could be found.
+    pub pid: Option<Pid>,
+    /// Traffic statistics of the connection since it was opened. It is only available for
+    /// established TCP connections on Linux and Windows.
+    ///
+    /// ⚠️ On Windows, the statistics are only collected once enabled for the connection, which
+    /// is done the first time it is listed and requires administrator privileges. It is `None`
+    /// until then.
+    pub traffic: Option<ConnectionTraffic>,
+}
+
+/// Traffic statistics of a TCP [`Connection`].
+///
+/// ```no_run
+/// use sysinfo::Connections;
+///
+/// let connections = Connections::new_with_refreshed_list();
+/// for connection in connections.list() {
+///     if let (Some(remote), Some(traffic)) = (connection.remote_address, connection.traffic) {
+///         println!(
+///             "{remote}: {} B sent, {} B received",
+///             traffic.bytes_sent, traffic.bytes_received,
+///         );
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ConnectionTraffic {
+    /// Number of bytes sent.
+    pub bytes_sent: u64,
+    /// Number of bytes received.
+    pub bytes_received: u64,
+    /// Number of retransmitted segments.
+    pub retransmitted_segments: u64,
+}
+
+/// Protocol of a [`Connection`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConnectionProtocol {
+    /// Transmission Control Protocol.
+    Tcp,
+    /// User Datagram Protocol.
+    Udp,
+}
+
+/// State of a TCP [`Connection`], as defined in
+/// [RFC 9293](https://www.rfc-editor.org/rfc/rfc9293#section-3.3.2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TcpState {
+    /// Waiting for a connection request.
+    Listen,
+    /// Waiting for a matching connection request after having sent one.
+    SynSent,
+    /// Waiting for the acknowledgment of a connection request.
+    SynReceived,
+    /// The connection is open.
+    Established,
+    /// Waiting for a connection termination request, or for the acknowledgment of the one
+    /// previously sent.
+    FinWait1,
+    /// Waiting for a connection termination request from the remote side.
+    FinWait2,
+    /// Waiting for a connection termination request from the local user.
+    CloseWait,
+    /// Waiting for the acknowledgment of a connection termination request from the remote side.
+    Closing,
+    /// Waiting for the acknowledgment of the connection termination request previously sent.
+    LastAck,
+    /// Waiting to be sure that the remote side received the acknowledgment of its connection
+    /// termination request.
+    TimeWait,
+    /// The connection is closed.
+    Closed,
+}
+
+/// Interacting with the neighbor cache of the system (ARP for IPv4, NDP for IPv6), which
+/// associates the IP addresses of the machines of the local networks with their MAC address.
+///
+/// ```no_run
+/// use sysinfo::Neighbors;
+///
+/// let neighbors = Neighbors::new_with_refreshed_list();
+/// for neighbor in neighbors.list() {
+///     println!(
+///         "{} is at {} on {} ({:?})",
+///         neighbor.ip_address, neighbor.mac_address, neighbor.interface, neighbor.state,
+///     );
+/// }
+/// ```
+pub struct Neighbors {
+    neighbors: Vec<Neighbor>,
+}
+
+impl Default for Neighbors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Neighbors> for Vec<Neighbor> {
+    fn from(neighbors: Neighbors) -> Self {
+        neighbors.neighbors
+    }
+}
+
+impl From<Vec<Neighbor>> for Neighbors {
+    fn from(neighbors: Vec<Neighbor>) -> Self {
+        Self { neighbors }
+    }
+}
+
+impl std::ops::Deref for Neighbors {
+    type Target = [Neighbor];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for Neighbors {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a Neighbors {
+    type Item = &'a Neighbor;
+    type IntoIter = std::slice::Iter<'a, Neighbor>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Neighbors {
+    type Item = &'a mut Neighbor;
+    type IntoIter = std::slice::IterMut<'a, Neighbor>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl Neighbors {
+    /// Creates a new empty [`Neighbors`][crate::Neighbors] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`Neighbors::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Neighbors;
+    ///
+    /// let mut neighbors = Neighbors::new();
+    /// neighbors.refresh_list();
+    /// for neighbor in neighbors.list() {
+    ///     eprintln!("{neighbor:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            neighbors: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`Neighbors`][crate::Neighbors] type with the neighbor list loaded.
+    /// It is a combination of [`Neighbors::new`] and [`Neighbors::refresh_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Neighbors;
+    ///
+    /// let neighbors = Neighbors::new_with_refreshed_list();
+    /// for neighbor in neighbors.list() {
+    ///     eprintln!("{neighbor:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut neighbors = Self::new();
+        neighbors.refresh_list();
+        neighbors
+    }
+
+    /// Returns the neighbor list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Neighbors;
+    ///
+    /// let neighbors = Neighbors::new_with_refreshed_list();
+    /// for neighbor in neighbors.list() {
+    ///     eprintln!("{neighbor:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[Neighbor] {
+        &self.neighbors
+    }
+
+    /// Returns the neighbor list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Neighbors;
+    ///
+    /// let mut neighbors = Neighbors::new_with_refreshed_list();
+    /// neighbors.list_mut().sort_by_key(|neighbor| neighbor.ip_address);
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [Neighbor] {
+        &mut self.neighbors
+    }
+
+    /// The neighbor list will be emptied then completely recomputed.
+    ///
+    /// ⚠️ On macOS, iOS and FreeBSD, the state of the entries isn't available: it is
+    /// [`NeighborState::Incomplete`] if the MAC address isn't resolved yet,
+    /// [`NeighborState::Permanent`] if the entry never expires and [`NeighborState::Reachable`]
+    /// otherwise.
+    ///
+    /// ```no_run
+    /// use sysinfo::Neighbors;
+    ///
+    /// let mut neighbors = Neighbors::new();
+    /// neighbors.refresh_list();
+    /// ```
+    pub fn refresh_list(&mut self) {
+        self.neighbors.clear();
+        crate::sys::get_neighbors(&mut self.neighbors);
+    }
+}
+
+/// An entry of the neighbor cache.
+///
+/// It is returned by [`Neighbors::list`].
+///
+/// ```no_run
+/// use sysinfo::{NeighborState, Neighbors};
+///
+/// let neighbors = Neighbors::new_with_refreshed_list();
+/// for neighbor in neighbors.list() {
+///     if neighbor.state == NeighborState::Reachable {
+///         println!("{} is at {}", neighbor.ip_address, neighbor.mac_address);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Neighbor {
+    /// IP address of the neighbor.
+    pub ip_address: IpAddr,
+    /// MAC address of the neighbor. It is [`MacAddr::UNSPECIFIED`] if it isn't resolved yet.
+    pub mac_address: MacAddr,
+    /// State of the entry.
+    pub state: NeighborState,
+    /// Name of the network interface through which the neighbor is reachable.
+    pub interface: String,
+}
+
+/// State of a [`Neighbor`] entry, as defined in
+/// [RFC 4861](https://www.rfc-editor.org/rfc/rfc4861#section-7.3.2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NeighborState {
+    /// The address resolution is in progress.
+    Incomplete,
+    /// The neighbor was recently confirmed to be reachable.
+    Reachable,
+    /// The neighbor isn't known to be reachable anymore, it will be checked the next time
+    /// traffic is sent to it.
+    Stale,
+    /// Waiting a bit before checking if the neighbor is still reachable.
+    Delay,
+    /// Checking if the neighbor is still reachable.
+    Probe,
+    /// The address resolution failed.
+    Failed,
+    /// The entry was configured statically and never expires.
+    Permanent,
+    /// Unknown state.
+    Unknown,
+}
+
+/// An enum representing signals on UNIX-like systems.
+///
+/// On non-unix systems, this enum is mostly useless and is only there to keep coherency between
+/// the different OSes.
+///
+/// If you want the list of the supported signals on the current system, use
+/// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Debug)]
+pub enum Signal {
+    /// Hangup detected on controlling terminal or death of controlling process.
+    Hangup,
+    /// Interrupt from keyboard.
+    Interrupt,
+    /// Quit from keyboard.
+    Quit,
+    /// Illegal instruction.
+    Illegal,
+    /// Trace/breakpoint trap.
+    Trap,
+    /// Abort signal from C abort function.
+    Abort,
+    /// IOT trap. A synonym for SIGABRT.
+    IOT,
+    /// Bus error (bad memory access).
+    Bus,
+    /// Floating point exception.
+    FloatingPointException,
+    /// Kill signal.
+    Kill,
+    /// User-defined signal 1.
+    User1,
+    /// Invalid memory reference.
+    Segv,
+    /// User-defined signal 2.
+    User2,
+    /// Broken pipe: write to pipe with no readers.
+    Pipe,
+    /// Timer signal from C alarm function.
+    Alarm,
+    /// Termination signal.
+    Term,
+    /// Child stopped or terminated.
+    Child,
+    /// Continue if stopped.
+    Continue,
+    /// Stop process.
+    Stop,
+    /// Stop typed at terminal.
+    TSTP,
+    /// Terminal input for background process.
+    TTIN,
+    /// Terminal output for background process.
+    TTOU,
+    /// Urgent condition on socket.
+    Urgent,
+    /// CPU time limit exceeded.
+    XCPU,
+    /// File size limit exceeded.
+    XFSZ,
+    /// Virtual alarm clock.
+    VirtualAlarm,
+    /// Profiling time expired.
+    Profiling,
+    /// Windows resize signal.
+    Winch,
+    /// I/O now possible.
+    IO,
+    /// Pollable event (Sys V). Synonym for IO
+    Poll,
+    /// Power failure (System V).
+    ///
+    /// Doesn't exist on apple systems so will be ignored.
+    Power,
+    /// Bad argument to routine (SVr4).
+    Sys,
+}
+
+impl std::fmt::Display for Signal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match *self {
+            Self::Hangup => "Hangup",
+            Self::Interrupt => "Interrupt",
+            Self::Quit => "Quit",
+            Self::Illegal => "Illegal",
+            Self::Trap => "Trap",
+            Self::Abort => "Abort",
+            Self::IOT => "IOT",
+            Self::Bus => "Bus",
+            Self::FloatingPointException => "FloatingPointException",
+            Self::Kill => "Kill",
+            Self::User1 => "User1",
+            Self::Segv => "Segv",
+            Self::User2 => "User2",
+            Self::Pipe => "Pipe",
+            Self::Alarm => "Alarm",
+            Self::Term => "Term",
+            Self::Child => "Child",
+            Self::Continue => "Continue",
+            Self::Stop => "Stop",
+            Self::TSTP => "TSTP",
+            Self::TTIN => "TTIN",
+            Self::TTOU => "TTOU",
+            Self::Urgent => "Urgent",
+            Self::XCPU => "XCPU",
+            Self::XFSZ => "XFSZ",
+            Self::VirtualAlarm => "VirtualAlarm",
+            Self::Profiling => "Profiling",
+            Self::Winch => "Winch",
+            Self::IO => "IO",
+            Self::Poll => "Poll",
+            Self::Power => "Power",
+            Self::Sys => "Sys",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Contains memory and CPU limits for the current process.
+///
+/// It is returned by [`System::cgroup_limits`]. New limits may be added in the future, so it
+/// can't be built outside of this crate.
+#[derive(Default, Debug, Clone)]
+#[non_exhaustive]
+pub struct CGroupLimits {
+    /// Total memory (in bytes) for the current cgroup.
+    pub total_memory: u64,
+    /// Free memory (in bytes) for the current cgroup.
+    pub free_memory: u64,
+    /// Free swap (in bytes) for the current cgroup.
+    pub free_swap: u64,
+    pub(crate) cpu_quota: Option<f64>,
+    pub(crate) cpus: Option<usize>,
+}
+
+impl CGroupLimits {
+    /// Returns the number of CPUs worth of time the current cgroup can use, computed from its
+    /// CPU bandwidth limit (like `1.5` for a quota of 150ms every 100ms), or `None` if it isn't
+    /// limited.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// if let Some(quota) = s.cgroup_limits().and_then(|limits| limits.cpu_quota()) {
+    ///     println!("{quota} CPUs worth of time");
+    /// }
+    /// ```
+    pub fn cpu_quota(&self) -> Option<f64> {
+        self.cpu_quota
+    }
+
+    /// Returns the number of CPUs the current cgroup can run on, from its cpuset, or `None` if
+    /// it couldn't be read.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// if let Some(cpus) = s.cgroup_limits().and_then(|limits| limits.cpus()) {
+    ///     println!("can run on {cpus} CPUs");
+    /// }
+    /// ```
+    pub fn cpus(&self) -> Option<usize> {
+        self.cpus
+    }
+
+    /// Returns the number of CPUs the current cgroup can effectively use, which is the CPU quota
+    /// rounded up, limited by the number of CPUs of the cpuset. It is `None` if neither is set.
+    ///
+    /// It is useful to size thread pools in containers.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// if let Some(cpus) = s.cgroup_limits().and_then(|limits| limits.cpu_count()) {
+    ///     println!("{cpus} CPUs available");
+    /// }
+    /// ```
+    pub fn cpu_count(&self) -> Option<usize> {
+        let quota = self.cpu_quota.map(|quota| (quota.ceil() as usize).max(1));
+        match (quota, self.cpus) {
+            (Some(quota), Some(cpus)) => Some(quota.min(cpus)),
+            (quota, cpus) => quota.or(cpus),
+        }
+    }
+}
+
+/// Details of how the RAM is used. All values are in bytes.
+///
+/// It is returned by [`System::memory_breakdown`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// let breakdown = s.memory_breakdown();
+/// println!(
+///     "buffers: {} bytes, cached: {} bytes, shared: {} bytes",
+///     breakdown.buffers,
+///     breakdown.cached,
+///     breakdown.shared,
+/// );
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryBreakdown {
+    /// Memory used by the kernel buffers.
+    pub buffers: u64,
+    /// Memory used by the page cache.
+    pub cached: u64,
+    /// Memory used by shared memory and `tmpfs`.
+    pub shared: u64,
+    /// Memory used by the kernel data structures which can be reclaimed.
+    pub slab_reclaimable: u64,
+    /// Memory used by the kernel data structures which cannot be reclaimed.
+    pub slab_unreclaimable: u64,
+    /// Memory used by files mapped into memory (like libraries).
+    pub mapped: u64,
+    /// Memory waiting to be written back to the disk.
+    pub dirty: u64,
+    /// Memory currently being written back to the disk.
+    pub writeback: u64,
+}
+
+/// Breakdown of the available memory. All values are in bytes.
+///
+/// It is returned by [`System::available_memory_breakdown`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// if let Some(available) = s.available_memory_breakdown() {
+///     println!("{:?}", available);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AvailableMemory {
+    /// Memory which isn't used at all.
+    pub free: u64,
+    /// Memory used (mostly by caches) which can be reclaimed by the system when needed.
+    pub reclaimable: u64,
+    /// Memory available to start new applications without swapping. It is usually `free` +
+    /// `reclaimable` but the kernel estimate can be lower.
+    pub available: u64,
+    /// How `available` was computed.
+    pub source: AvailableMemorySource,
+}
+
+/// How the available memory was computed.
+///
+/// It is used in [`AvailableMemory`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AvailableMemorySource {
+    /// The estimate is provided by the kernel: `MemAvailable` on Linux and the sum of the free,
+    /// zero and standby page lists on Windows.
+    Kernel,
+    /// The estimate is computed by sysinfo from the memory counters:
+    ///
+    /// * On Linux before 3.14: free + buffers + page cache + reclaimable slab - shared memory.
+    /// * On macOS and iOS: free + inactive + purgeable - compressed pages.
+    /// * On FreeBSD: free + inactive + cache + buffers.
+    Computed,
+}
+
+/// Kernel Samepage Merging statistics. All values are in bytes.
+///
+/// It is returned by [`System::ksm`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new();
+/// if let Some(ksm) = s.ksm() {
+///     println!(
+///         "{} bytes are shared, saving {} bytes",
+///         ksm.shared,
+///         ksm.saved,
+///     );
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ksm {
+    /// Whether KSM is currently merging pages.
+    pub running: bool,
+    /// Memory used by the merged pages.
+    pub shared: u64,
+    /// Memory saved by merging pages.
+    pub saved: u64,
+    /// Memory which is checked for merging but has no identical page.
+    pub unshared: u64,
+}
+
+/// Commit charge of the system. All values are in bytes.
+///
+/// It is returned by [`System::commit_charge`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// let commit = s.commit_charge();
+/// println!(
+///     "committed {} bytes (peak: {} bytes, limit: {} bytes)",
+///     commit.total,
+///     commit.peak,
+///     commit.limit,
+/// );
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommitCharge {
+    /// Virtual memory currently committed.
+    pub total: u64,
+    /// Maximum amount of virtual memory which can be committed.
+    pub limit: u64,
+    /// Highest commit charge since the system booted.
+    pub peak: u64,
+}
+
+/// Information about the memory compressor. All values are in bytes.
+///
+/// It is returned by [`System::compressed_memory`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// let compressed = s.compressed_memory();
+/// if compressed.compressed != 0 {
+///     println!(
+///         "compression ratio: {:.2}",
+///         compressed.uncompressed as f64 / compressed.compressed as f64,
+///     );
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressedMemory {
+    /// Size of the data stored in the compressor, before compression.
+    pub uncompressed: u64,
+    /// Physical memory used by the compressor to store this data.
+    pub compressed: u64,
+}
+
+/// Information about a zram device. All sizes are in bytes.
+///
+/// It is returned by [`System::zram_devices`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// for device in s.zram_devices() {
+///     println!("{} uses {}", device.name, device.algorithm);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZramDevice {
+    /// Name of the device (like `zram0`).
+    pub name: String,
+    /// Compression algorithm used by the device.
+    pub algorithm: String,
+    /// Maximum amount of uncompressed data the device can store.
+    pub disk_size: u64,
+    /// Size of the data stored in the device, before compression.
+    pub uncompressed: u64,
+    /// Size of the data stored in the device, after compression.
+    pub compressed: u64,
+    /// RAM used by the device, including the allocator overhead.
+    pub memory_used: u64,
+}
+
+/// Information about a physical memory module.
+///
+/// It is returned by [`System::memory_modules`]. Strings are empty if the firmware doesn't
+/// provide them.
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new();
+/// for module in s.memory_modules() {
+///     println!("{} {}", module.manufacturer, module.part_number);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryModule {
+    /// Label of the slot on the motherboard (like `DIMM_A1`).
+    pub locator: String,
+    /// Label of the bank containing the slot.
+    pub bank_locator: String,
+    /// Type of the memory.
+    pub kind: MemoryModuleKind,
+    /// Size of the module (in bytes), `0` if unknown.
+    pub size: u64,
+    /// Configured speed of the module (in MT/s), `0` if unknown.
+    pub speed: u32,
+    /// Manufacturer of the module.
+    pub manufacturer: String,
+    /// Serial number of the module.
+    pub serial_number: String,
+    /// Part number of the module.
+    pub part_number: String,
+}
+
+/// Type of a memory module.
+///
+/// It is used in [`MemoryModule`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MemoryModuleKind {
+    /// DRAM.
+    DRAM,
+    /// SDRAM.
+    SDRAM,
+    /// DDR SDRAM.
+    DDR,
+    /// DDR2 SDRAM.
+    DDR2,
+    /// DDR3 SDRAM.
+    DDR3,
+    /// DDR4 SDRAM.
+    DDR4,
+    /// DDR5 SDRAM.
+    DDR5,
+    /// Low-power DDR SDRAM.
+    LPDDR,
+    /// Low-power DDR2 SDRAM.
+    LPDDR2,
+    /// Low-power DDR3 SDRAM.
+    LPDDR3,
+    /// Low-power DDR4 SDRAM.
+    LPDDR4,
+    /// Low-power DDR5 SDRAM.
+    LPDDR5,
+    /// Other type, with its SMBIOS value.
+    Unknown(u8),
+}
+
+impl fmt::Display for MemoryModuleKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            MemoryModuleKind::DRAM => "DRAM",
+            MemoryModuleKind::SDRAM => "SDRAM",
+            MemoryModuleKind::DDR => "DDR",
+            MemoryModuleKind::DDR2 => "DDR2",
+            MemoryModuleKind::DDR3 => "DDR3",
+            MemoryModuleKind::DDR4 => "DDR4",
+            MemoryModuleKind::DDR5 => "DDR5",
+            MemoryModuleKind::LPDDR => "LPDDR",
+            MemoryModuleKind::LPDDR2 => "LPDDR2",
+            MemoryModuleKind::LPDDR3 => "LPDDR3",
+            MemoryModuleKind::LPDDR4 => "LPDDR4",
+            MemoryModuleKind::LPDDR5 => "LPDDR5",
+            MemoryModuleKind::Unknown(_) => "Unknown",
+        })
+    }
+}
+
+/// Information about the hardware of the system, read from the SMBIOS tables (or from IOKit on
+/// macOS).
+///
+/// It is returned by [`System::hardware_info`]. Strings are empty if the firmware doesn't provide
+/// them.
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new();
+/// let info = s.hardware_info();
+/// println!("{} {} ({})", info.manufacturer, info.product_name, info.serial_number);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HardwareInfo {
+    /// Manufacturer of the system.
+    pub manufacturer: String,
+    /// Product name of the system (like `ThinkPad X1 Carbon Gen 9` or `MacBookPro18,3`).
+    pub product_name: String,
+    /// Version of the product.
+    pub product_version: String,
+    /// Serial number of the system.
+    pub serial_number: String,
+    /// Manufacturer of the motherboard.
+    pub board_vendor: String,
+    /// Model of the motherboard.
+    pub board_name: String,
+    /// Serial number of the motherboard.
+    pub board_serial_number: String,
+    /// Vendor of the BIOS or UEFI firmware.
+    pub bios_vendor: String,
+    /// Version of the BIOS or UEFI firmware.
+    pub bios_version: String,
+    /// Release date of the BIOS or UEFI firmware, in the format used by the firmware (usually
+    /// `MM/DD/YYYY`).
+    pub bios_release_date: String,
+    /// Kind of chassis of the system.
+    pub chassis: ChassisKind,
+}
+
+impl HardwareInfo {
+    /// Returns a human-readable model name (like `ThinkPad X1 Carbon Gen 11`), or an empty string
+    /// if the firmware doesn't provide one.
+    ///
+    /// Unlike [`HardwareInfo::product_name`], it skips the placeholders some firmwares use (like
+    /// `To Be Filled By O.E.M.`) and, on Lenovo systems, returns the product version since the
+    /// product name is the machine type (like `21HMCTO1WW`).
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// let info = s.hardware_info();
+    /// println!("{} ({})", info.model(), info.chassis);
+    /// ```
+    pub fn model(&self) -> &str {
+        let is_set = |value: &str| {
+            !value.is_empty()
+                && ![
+                    "to be filled by o.e.m.",
+                    "system product name",
+                    "system version",
+                    "default string",
+                    "not applicable",
+                    "none",
+                ]
+                .iter()
+                .any(|placeholder| value.eq_ignore_ascii_case(placeholder))
+        };
+
+        if self.manufacturer.eq_ignore_ascii_case("lenovo") && is_set(&self.product_version) {
+            &self.product_version
+        } else if is_set(&self.product_name) {
+            &self.product_name
+        } else if is_set(&self.board_name) {
+            &self.board_name
+        } else {
+            ""
+        }
+    }
+}
+
+/// Kind of chassis of the system.
+///
+/// It is used in [`HardwareInfo`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChassisKind {
+    /// Desktop computer, including towers and mini PCs.
+    Desktop,
+    /// Laptop, including notebooks and convertibles.
+    Laptop,
+    /// Tablet or handheld computer.
+    Tablet,
+    /// All-in-one computer, where the computer is built into the screen.
+    AllInOne,
+    /// Server, including rack-mounted and blade ones.
+    Server,
+    /// Virtual machine.
+    VirtualMachine,
+    /// Unknown chassis, or one which doesn't fit the other kinds.
+    #[default]
+    Unknown,
+}
+
+impl fmt::Display for ChassisKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            ChassisKind::Desktop => "Desktop",
+            ChassisKind::Laptop => "Laptop",
+            ChassisKind::Tablet => "Tablet",
+            ChassisKind::AllInOne => "All-in-one",
+            ChassisKind::Server => "Server",
+            ChassisKind::VirtualMachine => "Virtual machine",
+            ChassisKind::Unknown => "Unknown",
+        })
+    }
+}
+
+/// Virtualization environment of the system.
+///
+/// It is returned by [`System::virtualization`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new();
+/// let virtualization = s.virtualization();
+/// println!("{:?} {:?}", virtualization.hypervisor, virtualization.container);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Virtualization {
+    /// Hypervisor the system is running on, `None` if it runs on bare metal.
+    pub hypervisor: Option<Hypervisor>,
+    /// Container runtime the system is running in, `None` if it isn't in a container.
+    pub container: Option<ContainerRuntime>,
+}
+
+/// Hypervisor running the system.
+///
+/// It is used in [`Virtualization`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hypervisor {
+    /// KVM, including the cloud providers based on it (like AWS Nitro or Google Compute Engine).
+    Kvm,
+    /// QEMU without hardware acceleration.
+    Qemu,
+    /// Microsoft Hyper-V (including Azure).
+    HyperV,
+    /// VMware.
+    VMware,
+    /// Oracle VirtualBox.
+    VirtualBox,
+    /// Xen.
+    Xen,
+    /// Parallels.
+    Parallels,
+    /// FreeBSD bhyve.
+    Bhyve,
+    /// Apple Virtualization framework.
+    Apple,
+    /// Another hypervisor, with its vendor identifier.
+    Other(String),
+}
+
+impl fmt::Display for Hypervisor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Hypervisor::Kvm => "KVM",
+            Hypervisor::Qemu => "QEMU",
+            Hypervisor::HyperV => "Hyper-V",
+            Hypervisor::VMware => "VMware",
+            Hypervisor::VirtualBox => "VirtualBox",
+            Hypervisor::Xen => "Xen",
+            Hypervisor::Parallels => "Parallels",
+            Hypervisor::Bhyve => "bhyve",
+            Hypervisor::Apple => "Apple",
+            Hypervisor::Other(vendor) => vendor,
+        })
+    }
+}
+
+/// Container runtime running the system.
+///
+/// It is used in [`Virtualization`] and [`Container`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContainerRuntime {
+    /// Docker.
+    Docker,
+    /// Podman.
+    Podman,
+    /// LXC or LXD.
+    Lxc,
+    /// systemd-nspawn.
+    SystemdNspawn,
+    /// Windows Subsystem for Linux.
+    Wsl,
+    /// FreeBSD jail.
+    Jail,
+    /// Another container runtime, with its name.
+    Other(String),
+}
+
+impl fmt::Display for ContainerRuntime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ContainerRuntime::Docker => "Docker",
+            ContainerRuntime::Podman => "Podman",
+            ContainerRuntime::Lxc => "LXC",
+            ContainerRuntime::SystemdNspawn => "systemd-nspawn",
+            ContainerRuntime::Wsl => "WSL",
+            ContainerRuntime::Jail => "jail",
+            ContainerRuntime::Other(name) => name,
+        })
+    }
+}
+
+/// Operating system identification, from the `os-release` file.
+///
+/// It is returned by [`System::os_release`]. Empty values are considered missing.
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new();
+/// if let Some(release) = s.os_release() {
+///     println!("{:?} (like {:?})", release.id, release.id_like);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OsRelease {
+    /// Lower-case identifier of the operating system (`ID`, like `ubuntu`).
+    pub id: Option<String>,
+    /// Identifiers of the operating systems this one is derived from, closest first (`ID_LIKE`,
+    /// like `["rhel", "fedora"]` on CentOS).
+    pub id_like: Vec<String>,
+    /// Name of the operating system (`NAME`).
+    pub name: Option<String>,
+    /// Name of the operating system, suitable for presentation to the user (`PRETTY_NAME`).
+    pub pretty_name: Option<String>,
+    /// Version of the operating system (`VERSION`, like `22.04.3 LTS (Jammy Jellyfish)`).
+    pub version: Option<String>,
+    /// Machine-parseable version of the operating system (`VERSION_ID`, like `22.04`).
+    pub version_id: Option<String>,
+    /// Lower-case codename of the release (`VERSION_CODENAME`, like `jammy`).
+    pub version_codename: Option<String>,
+    /// Variant or edition of the operating system (`VARIANT`, like `Server Edition`).
+    pub variant: Option<String>,
+    /// Lower-case identifier of the variant (`VARIANT_ID`).
+    pub variant_id: Option<String>,
+    /// Identifier of the system image (`BUILD_ID`).
+    pub build_id: Option<String>,
+    /// All the fields of the file, including the ones above, by name.
+    pub fields: HashMap<String, String>,
+}
+
+/// Detailed Windows version.
+///
+/// It is returned by [`System::windows_version`]. The version numbers come from `RtlGetVersion`,
+/// which isn't affected by the compatibility manifest of the application, and the other
+/// information from the registry.
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new();
+/// if let Some(version) = s.windows_version() {
+///     println!("{:?} {:?}", version.edition, version.display_version);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WindowsVersion {
+    /// Major version (`10` for both Windows 10 and Windows 11).
+    pub major: u32,
+    /// Minor version.
+    pub minor: u32,
+    /// Build number (like `22631`).
+    pub build: u32,
+    /// Update build revision (UBR), incremented by the cumulative updates.
+    pub revision: Option<u32>,
+    /// Feature update version (like `23H2` or `1909`).
+    pub display_version: Option<String>,
+    /// Edition (like `Professional` or `ServerDatacenter`).
+    pub edition: Option<String>,
+    /// Latest service pack installed (like `Service Pack 1`), `None` if there is none.
+    pub service_pack: Option<String>,
+    /// Major version of the latest service pack installed.
+    pub service_pack_major: u16,
+    /// Minor version of the latest service pack installed.
+    pub service_pack_minor: u16,
+    /// Product suites available on the system (`VER_SUITE_*` flags).
+    pub suite_mask: u16,
+    /// Product type: `1` for workstations, `2` for domain controllers and `3` for servers.
+    pub product_type: u8,
+}
+
+/// Time zone of the system.
+///
+/// It is returned by [`System::time_zone`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let time_zone = System::time_zone();
+/// println!("{:?}", time_zone.name);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimeZone {
+    /// Name of the time zone (like `Europe/Paris`), `None` if it couldn't be retrieved.
+    pub name: Option<String>,
+    /// Current offset from UTC in seconds, daylight saving time included. It is positive east of
+    /// Greenwich.
+    pub utc_offset: i32,
+}
+
+/// Value of a kernel parameter.
+///
+/// It is returned by [`System::kernel_parameter`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new();
+/// let max_files = s.kernel_parameter("fs.file-max").and_then(|value| value.as_integer());
+/// println!("{max_files:?}");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KernelParameter {
+    /// Integer value.
+    Integer(i64),
+    /// List of integers (like `net.ipv4.ip_local_port_range` on Linux).
+    Integers(Vec<i64>),
+    /// Text value. Multi-string registry values are separated by `\n`.
+    String(String),
+}
+
+impl KernelParameter {
+    /// Returns the value if it is a single integer.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// if let Some(value) = s.kernel_parameter("vm.swappiness") {
+    ///     println!("{:?}", value.as_integer());
+    /// }
+    /// ```
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            Self::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it is a text.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// if let Some(value) = s.kernel_parameter("kernel.hostname") {
+    ///     println!("{:?}", value.as_str());
+    /// }
+    /// ```
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for KernelParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Integers(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    if index != 0 {
+                        f.write_str(" ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                Ok(())
+            }
+            Self::String(value) => f.write_str(value),
+        }
+    }
+}
+
+/// Memory pressure level, as computed by the kernel.
+///
+/// It is returned by [`System::memory_pressure_level`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new();
+/// if let Some(level) = s.memory_pressure_level() {
+///     println!("memory pressure: {}", level);
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MemoryPressureLevel {
+    /// There is enough free memory.
+    Normal,
+    /// The system is starting to run out of memory and applications should free memory.
+    Warning,
+    /// The system is running out of memory and applications should free as much memory as
+    /// possible.
+    Critical,
+}
+
+impl fmt::Display for MemoryPressureLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            MemoryPressureLevel::Normal => "Normal",
+            MemoryPressureLevel::Warning => "Warning",
+            MemoryPressureLevel::Critical => "Critical",
+        })
+    }
+}
+
+/// Number of processes, threads and open handles of the whole system.
+///
+/// It is returned by [`System::counts`].
+///
+/// | OS | Processes | Threads | Handles |
+/// |---|---|---|---|
+/// | Linux | Entries of `/proc` | `/proc/loadavg` (kernel threads included) | `/proc/sys/fs/file-nr` |
+/// | FreeBSD | `kvm_getprocs` | `kvm_getprocs` | `kern.openfiles` |
+/// | macOS | `proc_listallpids` | ❌ | `kern.num_files` |
+/// | Windows | `GetPerformanceInfo` | `GetPerformanceInfo` | `GetPerformanceInfo` |
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new();
+/// println!("{:?} open handles", s.counts().handles);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemCounts {
+    /// Number of processes.
+    pub processes: Option<u64>,
+    /// Number of threads.
+    pub threads: Option<u64>,
+    /// Number of open handles on Windows, or of open files on Unix systems.
+    pub handles: Option<u64>,
+}
+
+/// File handles allocated by the kernel.
+///
+/// It is returned by [`System::file_handles`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new();
+/// if let Some(handles) = s.file_handles() {
+///     println!("{}/{:?}", handles.allocated, handles.limit);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FileHandles {
+    /// Number of file handles currently allocated.
+    pub allocated: u64,
+    /// System-wide limit of file handles, `None` if there is none.
+    pub limit: Option<u64>,
+}
+
+impl FileHandles {
+    /// Returns the percentage of the limit in use, `None` if there is no limit.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// if let Some(usage) = s.file_handles().and_then(|handles| handles.usage()) {
+    ///     println!("{usage}% of the file handles are in use");
+    /// }
+    /// ```
+    pub fn usage(&self) -> Option<f32> {
+        match self.limit {
+            Some(limit) if limit != 0 => Some(self.allocated as f32 * 100. / limit as f32),
+            _ => None,
+        }
+    }
+}
+
+/// State of the kernel random number generator.
+///
+/// It is returned by [`System::entropy`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new();
+/// if let Some(entropy) = s.entropy() {
+///     println!("{:?}/{:?} bits", entropy.available, entropy.pool_size);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Entropy {
+    /// Whether the generator is initialized, so reading random data doesn't block.
+    pub ready: bool,
+    /// Estimated number of bits of entropy available in the pool.
+    pub available: Option<u32>,
+    /// Size of the entropy pool in bits.
+    pub pool_size: Option<u32>,
+}
+
+/// A struct representing system load average value.
+///
+/// It is returned by [`System::load_average`][crate::System::load_average].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// let load_avg = s.load_average();
+/// println!(
+///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
+///     load_avg.one,
+///     load_avg.five,
+///     load_avg.fifteen,
+/// );
+/// ```
+#[repr(C)]
+#[derive(Default, Debug, Clone)]
+pub struct LoadAvg {
+    /// Average load within one minute.
+    pub one: f64,
+    /// Average load within five minutes.
+    pub five: f64,
+    /// Average load within fifteen minutes.
+    pub fifteen: f64,
+}
+
+/// Pressure Stall Information of the system.
+///
+/// It is returned by [`System::pressure`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new();
+/// if let Some(pressure) = s.pressure() {
+///     println!("CPU: {:?}", pressure.cpu);
+///     println!("memory: {:?}", pressure.memory);
+///     println!("I/O: {:?}", pressure.io);
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Pressure {
+    /// Pressure on the CPU.
+    pub cpu: PressureStats,
+    /// Pressure on the memory.
+    pub memory: PressureStats,
+    /// Pressure on the I/O.
+    pub io: PressureStats,
+}
+
+/// Pressure Stall Information of a resource.
+///
+/// It is used in [`Pressure`].
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct PressureStats {
+    /// Time during which at least one task was stalled on the resource.
+    pub some: PressureValues,
+    /// Time during which all non-idle tasks were stalled on the resource at the same time.
+    ///
+    /// ⚠️ For the CPU, it is only available since Linux 5.13 and is always `0` at the system
+    /// level.
+    pub full: PressureValues,
+}
+
+/// Stall time ratios over different time windows.
+///
+/// It is used in [`PressureStats`].
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct PressureValues {
+    /// Percentage of the last 10 seconds.
+    pub avg10: f32,
+    /// Percentage of the last 60 seconds.
+    pub avg60: f32,
+    /// Percentage of the last 300 seconds.
+    pub avg300: f32,
+    /// Total stall time (in microseconds).
+    pub total: u64,
+}
+
+/// Time spent by a CPU in each state since the system boot.
+///
+/// The different states don't overlap: for example, `guest` isn't included in `user` and
+/// `irq` isn't included in `system`. States which aren't provided by the OS are set to zero.
+///
+/// It is returned by [`Cpu::times`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// for cpu in s.cpus() {
+///     let times = cpu.times();
+///     println!(
+///         "{}: user {:?}, system {:?}, idle {:?}, steal {:?}",
+///         cpu.name(),
+///         times.user,
+///         times.system,
+///         times.idle,
+///         times.steal,
+///     );
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CpuTimes {
+    /// Time spent running user code (with a normal priority).
+    pub user: Duration,
+    /// Time spent running user code with a lowered priority.
+    pub nice: Duration,
+    /// Time spent running kernel code.
+    pub system: Duration,
+    /// Time spent doing nothing.
+    pub idle: Duration,
+    /// Time spent waiting for I/O to complete (Linux only).
+    pub iowait: Duration,
+    /// Time spent handling hardware interrupts.
+    pub irq: Duration,
+    /// Time spent handling software interrupts (DPCs on Windows).
+    pub softirq: Duration,
+    /// Time spent in other operating systems when running in a virtualized environment
+    /// (Linux only).
+    pub steal: Duration,
+    /// Time spent running a virtual CPU for guest operating systems (Linux only).
+    pub guest: Duration,
+    /// Time spent running a niced guest (Linux only).
+    pub guest_nice: Duration,
+}
+
+impl CpuTimes {
+    /// Returns the sum of the time spent in all states.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// let times = s.global_cpu_info().times();
+    /// println!("idle: {:?} / {:?}", times.idle, times.total());
+    /// ```
+    pub fn total(&self) -> Duration {
+        self.user
+            + self.nice
+            + self.system
+            + self.idle
+            + self.iowait
+            + self.irq
+            + self.softirq
+            + self.steal
+            + self.guest
+            + self.guest_nice
+    }
+}
+
+/// Number of interrupts and context switches handled by the system.
+///
+/// It is returned by [`System::cpu_activity`][crate::System::cpu_activity]. Counters are the
+/// values since the system boot whereas rates are computed between the last two refreshes.
+///
+/// On Windows, `soft_interrupts` is the number of deferred procedure calls (DPC).
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// let activity = s.cpu_activity();
+/// println!(
+///     "interrupts: {}/s, context switches: {}/s",
+///     activity.interrupts_per_second,
+///     activity.context_switches_per_second,
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CpuActivity {
+    /// Number of interrupts handled since the system boot.
+    pub interrupts: u64,
+    /// Number of soft interrupts handled since the system boot.
+    pub soft_interrupts: u64,
+    /// Number of context switches since the system boot.
+    pub context_switches: u64,
+    /// Number of interrupts handled per second.
+    pub interrupts_per_second: f64,
+    /// Number of soft interrupts handled per second.
+    pub soft_interrupts_per_second: f64,
+    /// Number of context switches per second.
+    pub context_switches_per_second: f64,
+}
+
+/// Residency of a CPU in one of its idle states (C-states).
+///
+/// It is returned by [`Cpu::idle_states`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let mut s = System::new();
+/// s.refresh_cpu_idle_states();
+/// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+/// s.refresh_cpu_idle_states();
+///
+/// for cpu in s.cpus() {
+///     for state in cpu.idle_states() {
+///         println!("{} {}: {:.1}%", cpu.name(), state.name, state.residency);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CpuIdleState {
+    /// Name of the idle state (like `POLL`, `C1`, `C1E` or `C6`).
+    pub name: String,
+    /// Percentage of time spent in this idle state between the two last refreshes.
+    pub residency: f32,
+}
+
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "windows",
+        target_os = "freebsd",
+    ),
+    not(feature = "unknown-ci")
+))]
+impl CpuActivity {
+    /// Updates the counters and computes the rates from the `elapsed` time since the previous
+    /// update (if any).
+    pub(crate) fn update(
+        &mut self,
+        interrupts: u64,
+        soft_interrupts: u64,
+        context_switches: u64,
+        elapsed: Option<Duration>,
+    ) {
+        let elapsed = elapsed.map(|elapsed| elapsed.as_secs_f64()).unwrap_or(0.);
+        let rate = |new: u64, old: u64| {
+            if elapsed > 0. {
+                new.saturating_sub(old) as f64 / elapsed
+            } else {
+                0.
+            }
+        };
+        self.interrupts_per_second = rate(interrupts, self.interrupts);
+        self.soft_interrupts_per_second = rate(soft_interrupts, self.soft_interrupts);
+        self.context_switches_per_second = rate(context_switches, self.context_switches);
+        self.interrupts = interrupts;
+        self.soft_interrupts = soft_interrupts;
+        self.context_switches = context_switches;
+    }
+}
+
+/// Energy consumed by a RAPL (Running Average Power Limit) domain of a CPU package.
+///
+/// It is returned by [`System::energy_domains`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let mut s = System::new();
+/// s.refresh_energy();
+/// for domain in s.energy_domains() {
+///     println!("package {} {}: {:.2} J", domain.package, domain.name, domain.energy);
+/// }
+/// ```
+#[cfg(feature = "energy")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnergyDomain {
+    /// Name of the domain: `package`, `core`, `uncore`, `dram` or `psys`.
+    pub name: String,
+    /// Index of the CPU package this domain belongs to.
+    pub package: usize,
+    /// Energy consumed (in joules) since the first call to [`System::refresh_energy`].
+    pub energy: f64,
+    /// Average power (in watts) between the two last calls to [`System::refresh_energy`].
+    pub power: f64,
+}
+
+#[cfg(all(
+    feature = "energy",
+    any(target_os = "linux", target_os = "android", target_os = "windows"),
+    not(feature = "unknown-ci")
+))]
+impl EnergyDomain {
+    pub(crate) fn new(name: String, package: usize) -> Self {
+        Self {
+            name,
+            package,
+            energy: 0.,
+            power: 0.,
+        }
+    }
+
+    /// Converts the domain into a power rail, named like `package-0`.
+    pub(crate) fn to_power_rail(&self) -> PowerRail {
+        PowerRail {
+            name: format!("{}-{}", self.name, self.package),
+            kind: match self.name.as_str() {
+                "package" | "core" => PowerRailKind::Cpu,
+                "uncore" => PowerRailKind::Gpu,
+                "dram" => PowerRailKind::Memory,
+                "psys" => PowerRailKind::System,
+                _ => PowerRailKind::Other,
+            },
+            power: self.power as f32,
+        }
+    }
+
+    /// Adds the `consumed` joules and computes the power from the `elapsed` time since the
+    /// previous update (if any).
+    pub(crate) fn update(&mut self, consumed: f64, elapsed: Option<Duration>) {
+        self.energy += consumed;
+        self.power = match elapsed.map(|elapsed| elapsed.as_secs_f64()) {
+            Some(elapsed) if elapsed > 0. => consumed / elapsed,
+            _ => 0.,
+        };
+    }
+}
+
+/// Interacting with the power drawn by the system.
+///
+/// The power of the RAPL (Running Average Power Limit) domains is computed from the energy
+/// consumed between two refreshes, so it is `0` after the first one.
+///
+/// ```no_run
+/// use sysinfo::Power;
+///
+/// let mut power = Power::new_with_refreshed();
+/// std::thread::sleep(std::time::Duration::from_secs(1));
+/// power.refresh();
+/// println!("system: {:?} W", power.system_power());
+/// for rail in power.rails() {
+///     println!("{} ({:?}): {:.2} W", rail.name, rail.kind, rail.power);
+/// }
+/// ```
+#[cfg(feature = "energy")]
+pub struct Power {
+    pub(crate) inner: PowerInner,
+}
+
+#[cfg(feature = "energy")]
+impl Default for Power {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "energy")]
+impl Power {
+    /// Creates a new [`Power`][crate::Power] type without any rail.
+    ///
+    /// If you want it to be filled directly, take a look at [`Power::new_with_refreshed`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Power;
+    ///
+    /// let mut power = Power::new();
+    /// power.refresh();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            inner: PowerInner::new(),
+        }
+    }
+
+    /// Creates a new [`Power`][crate::Power] type and refreshes it. It is a combination of
+    /// [`Power::new`] and [`Power::refresh`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Power;
+    ///
+    /// let power = Power::new_with_refreshed();
+    /// println!("{:?} W", power.system_power());
+    /// ```
+    pub fn new_with_refreshed() -> Self {
+        let mut power = Self::new();
+        power.refresh();
+        power
+    }
+
+    /// Refreshes the power rails.
+    ///
+    /// ```no_run
+    /// use sysinfo::Power;
+    ///
+    /// let mut power = Power::new();
+    /// power.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        self.inner.refresh()
+    }
+
+    /// Returns the power rails, like the RAPL domains of the CPU packages or the discharging
+    /// batteries.
+    ///
+    /// The rails come from the RAPL domains and the batteries on Linux, from the Energy Meter
+    /// Interface on Windows, from the batteries and the SMC (on Intel only) on macOS and from
+    /// the batteries on FreeBSD.
+    ///
+    /// ⚠️ Rails can overlap: the `core` domain is part of the `package` one, which is part of
+    /// the `psys` one.
+    ///
+    /// ```no_run
+    /// use sysinfo::Power;
+    ///
+    /// let power = Power::new_with_refreshed();
+    /// for rail in power.rails() {
+    ///     println!("{}: {:.2} W", rail.name, rail.power);
+    /// }
+    /// ```
+    pub fn rails(&self) -> &[PowerRail] {
+        self.inner.rails()
+    }
+
+    /// Returns the power drawn by the whole system (in watts).
+    ///
+    /// It is the sum of the [`PowerRailKind::System`] rails if there are any, otherwise the sum
+    /// of the [`PowerRailKind::Battery`] rails (which are only listed while discharging).
+    ///
+    /// ```no_run
+    /// use sysinfo::Power;
+    ///
+    /// let power = Power::new_with_refreshed();
+    /// println!("{:?} W", power.system_power());
+    /// ```
+    pub fn system_power(&self) -> Option<f32> {
+        let sum = |kind: PowerRailKind| {
+            let mut rails = self
+                .rails()
+                .iter()
+                .filter(|rail| rail.kind == kind)
+                .peekable();
+            rails.peek()?;
+            Some(rails.map(|rail| rail.power).sum())
+        };
+        sum(PowerRailKind::System).or_else(|| sum(PowerRailKind::Battery))
+    }
+}
+
+/// Power drawn by a rail of the system.
+///
+/// It is returned by [`Power::rails`].
+///
+/// ```no_run
+/// use sysinfo::Power;
+///
+/// let power = Power::new_with_refreshed();
+/// for rail in power.rails() {
+///     println!("{}: {:.2} W", rail.name, rail.power);
+/// }
+/// ```
+#[cfg(feature = "energy")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerRail {
+    /// Name of the rail, like `package-0`, `dram-0` or `BAT0`.
+    pub name: String,
+    /// What the rail powers.
+    pub kind: PowerRailKind,
+    /// Power drawn (in watts).
+    pub power: f32,
+}
+
+/// What a [`PowerRail`] powers.
+///
+/// ```no_run
+/// use sysinfo::{Power, PowerRailKind};
+///
+/// let power = Power::new_with_refreshed();
+/// for rail in power.rails() {
+///     if rail.kind == PowerRailKind::Cpu {
+///         println!("{}: {:.2} W", rail.name, rail.power);
+///     }
+/// }
+/// ```
+#[cfg(feature = "energy")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PowerRailKind {
+    /// The whole system, like the RAPL `psys` domain or the SMC `PSTR` key.
+    System,
+    /// A CPU package or its cores.
+    Cpu,
+    /// An integrated GPU, like the RAPL `uncore` domain.
+    Gpu,
+    /// The memory.
+    Memory,
+    /// A discharging battery.
+    Battery,
+    /// Anything else.
+    #[default]
+    Other,
+}
+
+macro_rules! xid {
+    ($(#[$outer:meta])+ $name:ident, $type:ty $(, $trait:ty)?) => {
+        $(#[$outer])+
+        #[repr(transparent)]
+        #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+        pub struct $name(pub(crate) $type);
+
+        impl std::ops::Deref for $name {
+            type Target = $type;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        $(
+        impl TryFrom<usize> for $name {
+            type Error = <$type as TryFrom<usize>>::Error;
+
+            fn try_from(t: usize) -> Result<Self, <$type as TryFrom<usize>>::Error> {
+                Ok(Self(<$type>::try_from(t)?))
+            }
+        }
+
+        impl $trait for $name {
+            type Err = <$type as FromStr>::Err;
+
+            fn from_str(t: &str) -> Result<Self, <$type as FromStr>::Err> {
+                Ok(Self(<$type>::from_str(t)?))
+            }
+        }
+        )?
+    };
+}
+
+macro_rules! uid {
+    ($type:ty$(, $trait:ty)?) => {
+        xid!(
+            /// A user id wrapping a platform specific type.
+            Uid,
+            $type
+            $(, $trait)?
+        );
+    };
+}
+
+macro_rules! gid {
+    ($type:ty) => {
+        xid!(
+            /// A group id wrapping a platform specific type.
+            #[derive(Copy)]
+            Gid,
+            $type,
+            FromStr
+        );
+    };
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(
+        not(feature = "unknown-ci"),
+        any(
+            target_os = "freebsd",
+            target_os = "linux",
+            target_os = "android",
+            target_os = "macos",
+            target_os = "ios",
+        )
+    ))] {
+        uid!(libc::uid_t, FromStr);
+        gid!(libc::gid_t);
+    } else if #[cfg(windows)] {
+        uid!(crate::windows::Sid);
+        gid!(u32);
+        // Manual implementation outside of the macro...
+        impl FromStr for Uid {
+            type Err = <crate::windows::Sid as FromStr>::Err;
+
+            fn from_str(t: &str) -> Result<Self, Self::Err> {
+                Ok(Self(t.parse()?))
+            }
+        }
+    } else {
+        uid!(u32, FromStr);
+        gid!(u32);
+    }
+}
+
+impl Uid {
+    /// Returns the name of the user with this ID.
+    ///
+    /// Unlike [`Users::get_user_by_id`], it asks the system directly, so it also works for the
+    /// users which aren't listed by [`Users`], like the domain users on Windows or the users
+    /// provided by LDAP or NIS on Unix systems.
+    ///
+    /// ⚠️ This is computed every time this method is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for (pid, process) in s.processes() {
+    ///     if let Some(user_id) = process.user_id() {
+    ///         println!("[{pid}] {:?}", user_id.resolve_name());
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve_name(&self) -> Option<String> {
+        crate::sys::resolve_user_name(self)
+    }
+}
+
+impl Gid {
+    /// Returns the name of the group with this ID.
+    ///
+    /// ⚠️ This is computed every time this method is called.
+    ///
+    /// ⚠️ Group IDs are not set on Windows, so it always returns `None` there.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for (pid, process) in s.processes() {
+    ///     if let Some(group_id) = process.group_id() {
+    ///         println!("[{pid}] {:?}", group_id.resolve_name());
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve_name(&self) -> Option<String> {
+        crate::sys::resolve_group_name(*self)
+    }
+}
+
+/// Type containing user information.
+///
+/// It is returned by [`Users`][crate::Users].
+///
+/// ```no_run
+/// use sysinfo::Users;
+///
+/// let users = Users::new_with_refreshed_list();
+/// for user in users.list() {
+///     println!("{:?}", user);
+/// }
+/// ```
+pub struct User {
+    pub(crate) inner: UserInner,
+    pub(crate) groups: Option<Vec<Group>>,
+}
+
+// The groups returned by `User::groups`, which are cached by `Users::refresh_list_specifics`.
+const DEFAULT_GROUP_REFRESH_KIND: GroupRefreshKind = GroupRefreshKind {
+    supplementary_groups: true,
+    nested_groups: false,
+};
+
+impl PartialEq for User {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+            && self.group_id() == other.group_id()
+            && self.name() == other.name()
+    }
+}
+
+impl Eq for User {}
+
+impl PartialOrd for User {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for User {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.name().cmp(other.name())
+    }
+}
+
+impl User {
+    /// Returns the ID of the user.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     println!("{:?}", *user.id());
+    /// }
+    /// ```
+    pub fn id(&self) -> &Uid {
+        self.inner.id()
+    }
+
+    /// Returns the group ID of the user.
+    ///
+    /// ⚠️ This information is not set on Windows.  Windows doesn't have a `username` specific
+    /// group assigned to the user. They do however have unique
+    /// [Security Identifiers](https://docs.microsoft.com/en-us/windows/win32/secauthz/security-identifiers)
+    /// made up of various [Components](https://docs.microsoft.com/en-us/windows/win32/secauthz/sid-components).
+    /// Pieces of the SID may be a candidate for this field, but it doesn't map well to a single
+    /// group ID.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     println!("{}", *user.group_id());
+    /// }
+    /// ```
+    pub fn group_id(&self) -> Gid {
+        self.inner.group_id()
+    }
+
+    /// Returns the name of the user.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     println!("{}", user.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    /// Returns the groups of the user.
+    ///
+    /// ⚠️ This is computed every time this method is called, unless the groups were retrieved
+    /// when listing the users (see [`UserRefreshKind::groups`]).
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     println!("{} is in {:?}", user.name(), user.groups());
+    /// }
+    /// ```
+    pub fn groups(&self) -> Vec<Group> {
+        match self.groups {
+            Some(ref groups) => groups.clone(),
+            None => self.groups_specifics(DEFAULT_GROUP_REFRESH_KIND),
+        }
+    }
+
+    /// Returns the groups of the user, resolved according to the given [`GroupRefreshKind`].
+    ///
+    /// ⚠️ This is computed every time this method is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::{GroupRefreshKind, Users};
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     let groups = user.groups_specifics(GroupRefreshKind::everything());
+    ///     println!("{} is in {:?}", user.name(), groups);
+    /// }
+    /// ```
+    pub fn groups_specifics(&self, refresh_kind: GroupRefreshKind) -> Vec<Group> {
+        self.inner.groups(refresh_kind)
+    }
+
+    /// Returns the home directory of the user.
+    ///
+    /// On Windows, it is the home directory set for the account if any, otherwise the
+    /// directory of the user profile.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     println!("{}: {:?}", user.name(), user.home_dir());
+    /// }
+    /// ```
+    pub fn home_dir(&self) -> Option<&Path> {
+        self.inner.home_dir()
+    }
+
+    /// Returns the login shell of the user.
+    ///
+    /// ⚠️ Windows users don't have a login shell, so it always returns `None` there.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     println!("{}: {:?}", user.name(), user.shell());
+    /// }
+    /// ```
+    pub fn shell(&self) -> Option<&Path> {
+        self.inner.shell()
+    }
+
+    /// Returns the domain of the user.
+    ///
+    /// For local accounts, it is the name of the computer (or `NT AUTHORITY` and `BUILTIN` for
+    /// the well-known accounts).
+    ///
+    /// ⚠️ Domains only exist on Windows, so it always returns `None` on other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     println!("{:?}\\{}", user.domain(), user.name());
+    /// }
+    /// ```
+    pub fn domain(&self) -> Option<&str> {
+        self.inner.domain()
+    }
+
+    /// Returns `true` if the user is an account of an Active Directory domain, rather than an
+    /// account local to this computer.
+    ///
+    /// ⚠️ Domains only exist on Windows, so it always returns `false` on other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     if user.is_domain_account() {
+    ///         println!("{} is a domain account", user.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn is_domain_account(&self) -> bool {
+        self.inner.is_domain_account()
+    }
+}
+
+/// Type containing group information.
+///
+/// It is returned by [`User::groups`].
+///
+/// ```no_run
+/// use sysinfo::Users;
+///
+/// let mut users = Users::new_with_refreshed_list();
+///
+/// for user in users.list() {
+///     println!(
+///         "user: (ID: {:?}, group ID: {:?}, name: {:?})",
+///         user.id(),
+///         user.group_id(),
+///         user.name(),
+///     );
+///     for group in user.groups() {
+///         println!("group: (ID: {:?}, name: {:?})", group.id(), group.name());
+///     }
+/// }
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct Group {
+    pub(crate) id: Gid,
+    pub(crate) name: String,
+}
+
+impl Group {
+    /// Returns the ID of the group.
+    ///
+    /// ⚠️ This information is not set on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let mut users = Users::new_with_refreshed_list();
+    ///
+    /// for user in users.list() {
+    ///     for group in user.groups() {
+    ///         println!("{:?}", group.id());
+    ///     }
+    /// }
+    /// ```
+    pub fn id(&self) -> &Gid {
+        &self.id
+    }
+
+    /// Returns the name of the group.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let mut users = Users::new_with_refreshed_list();
+    ///
+    /// for user in users.list() {
+    ///     for group in user.groups() {
+    ///         println!("{}", group.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Interacting with the login sessions of the system.
+///
+/// ```no_run
+/// use sysinfo::Sessions;
+///
+/// let sessions = Sessions::new_with_refreshed_list();
+/// for session in sessions.list() {
+///     println!("{} logged in at {}", session.user_name, session.login_time);
+/// }
+/// ```
+pub struct Sessions {
+    sessions: Vec<Session>,
+}
+
+impl Default for Sessions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Sessions> for Vec<Session> {
+    fn from(sessions: Sessions) -> Self {
+        sessions.sessions
+    }
+}
+
+impl From<Vec<Session>> for Sessions {
+    fn from(sessions: Vec<Session>) -> Self {
+        Self { sessions }
+    }
+}
+
+impl std::ops::Deref for Sessions {
+    type Target = [Session];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for Sessions {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a Sessions {
+    type Item = &'a Session;
+    type IntoIter = std::slice::Iter<'a, Session>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Sessions {
+    type Item = &'a mut Session;
+    type IntoIter = std::slice::IterMut<'a, Session>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl Sessions {
+    /// Creates a new empty [`Sessions`][crate::Sessions] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`Sessions::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let mut sessions = Sessions::new();
+    /// sessions.refresh_list();
+    /// for session in sessions.list() {
+    ///     eprintln!("{session:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            sessions: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`Sessions`][crate::Sessions] type with the session list loaded.
+    /// It is a combination of [`Sessions::new`] and [`Sessions::refresh_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let sessions = Sessions::new_with_refreshed_list();
+    /// for session in sessions.list() {
+    ///     eprintln!("{session:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut sessions = Self::new();
+        sessions.refresh_list();
+        sessions
+    }
+
+    /// Returns the session list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let sessions = Sessions::new_with_refreshed_list();
+    /// for session in sessions.list() {
+    ///     eprintln!("{session:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[Session] {
+        &self.sessions
+    }
+
+    /// Returns the session list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let mut sessions = Sessions::new_with_refreshed_list();
+    /// sessions.list_mut().sort_by_key(|session| session.login_time);
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [Session] {
+        &mut self.sessions
+    }
+
+    /// The session list will be emptied then completely recomputed.
+    ///
+    /// On Unix systems, the sessions are read from the `utmpx` database, which isn't available
+    /// on Android. On Windows, they are listed with `WTSEnumerateSessions`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let mut sessions = Sessions::new();
+    /// sessions.refresh_list();
+    /// ```
+    pub fn refresh_list(&mut self) {
+        self.sessions.clear();
+        crate::sys::get_sessions(&mut self.sessions);
+    }
+}
+
+/// Information about a login session.
+///
+/// It is returned by [`Sessions::list`].
+///
+/// ```no_run
+/// use sysinfo::Sessions;
+///
+/// let sessions = Sessions::new_with_refreshed_list();
+/// for session in sessions.list() {
+///     if session.remote {
+///         println!("{} is logged in from {:?}", session.user_name, session.host);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    /// Name of the user who logged in.
+    pub user_name: String,
+    /// Time of the login, in seconds since UNIX epoch.
+    pub login_time: u64,
+    /// Terminal or display of the session, like `pts/0` or `:0` on Unix systems, or the name of
+    /// the window station, like `Console` or `RDP-Tcp#0`, on Windows.
+    pub terminal: Option<String>,
+    /// Host the user logged in from. It is `None` for local sessions.
+    pub host: Option<String>,
+    /// Whether the user logged in remotely, through SSH or RDP for example.
+    ///
+    /// ⚠️ VNC sessions share the local display, so they are considered local.
+    pub remote: bool,
+}
+
+/// Type containing read and written bytes.
+///
+/// It is returned by [`Process::disk_usage`][crate::Process::disk_usage].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// for (pid, process) in s.processes() {
+///     let disk_usage = process.disk_usage();
+///     println!("[{}] read bytes   : new/total => {}/{} B",
+///         pid,
+///         disk_usage.read_bytes,
+///         disk_usage.total_read_bytes,
+///     );
+///     println!("[{}] written bytes: new/total => {}/{} B",
+///         pid,
+///         disk_usage.written_bytes,
+///         disk_usage.total_written_bytes,
+///     );
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd)]
+pub struct DiskUsage {
+    /// Total number of written bytes.
+    pub total_written_bytes: u64,
+    /// Number of written bytes since the last refresh.
+    pub written_bytes: u64,
+    /// Total number of read bytes.
+    pub total_read_bytes: u64,
+    /// Number of read bytes since the last refresh.
+    pub read_bytes: u64,
+}
+
+/// Enum describing the different status of a process.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProcessStatus {
+    /// ## Linux
+    ///
+    /// Idle kernel thread.
+    ///
+    /// ## macOs/FreeBSD
+    ///
+    /// Process being created by fork.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Idle,
+    /// Running.
+    Run,
+    /// ## Linux
+    ///
+    /// Sleeping in an interruptible waiting.
+    ///
+    /// ## macOS/FreeBSD
+    ///
+    /// Sleeping on an address.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Sleep,
+    /// ## Linux
+    ///
+    /// Stopped (on a signal) or (before Linux 2.6.33) trace stopped.
+    ///
+    /// ## macOS/FreeBSD
+    ///
+    /// Process debugging or suspension.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Stop,
+    /// ## Linux/FreeBSD/macOS
+    ///
+    /// Zombie process. Terminated but not reaped by its parent.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Zombie,
+    /// ## Linux
+    ///
+    /// Tracing stop (Linux 2.6.33 onward). Stopped by debugger during the tracing.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Tracing,
+    /// ## Linux
+    ///
+    /// Dead/uninterruptible sleep (usually IO).
+    ///
+    /// ## FreeBSD
+    ///
+    /// A process should never end up in this state.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Dead,
+    /// ## Linux
+    ///
+    /// Wakekill (Linux 2.6.33 to 3.13 only).
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Wakekill,
+    /// ## Linux
+    ///
+    /// Waking (Linux 2.6.33 to 3.13 only).
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Waking,
+    /// ## Linux
+    ///
+    /// Parked (Linux 3.9 to 3.13 only).
+    ///
+    /// ## macOS
+    ///
+    /// Halted at a clean point.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Parked,
+    /// ## FreeBSD
+    ///
+    /// Blocked on a lock.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    LockBlocked,
+    /// ## Linux
+    ///
+    /// Waiting in uninterruptible disk sleep.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    UninterruptibleDiskSleep,
+    /// Unknown.
+    Unknown(u32),
+}
+
+/// Returns the pid for the current process.
+///
+/// `Err` is returned in case the platform isn't supported.
+///
+/// ```no_run
+/// use sysinfo::get_current_pid;
+///
+/// match get_current_pid() {
+///     Ok(pid) => {
+///         println!("current pid: {}", pid);
+///     }
+///     Err(e) => {
+///         eprintln!("failed to get current pid: {}", e);
+///     }
+/// }
+/// ```
+#[allow(clippy::unnecessary_wraps)]
+pub fn get_current_pid() -> Result<Pid, &'static str> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "unknown-ci")] {
+            fn inner() -> Result<Pid, &'static str> {
+                Err("Unknown platform (CI)")
+            }
+        } else if #[cfg(any(
+            target_os = "freebsd",
+            target_os = "linux",
+            target_os = "android",
+            target_os = "macos",
+            target_os = "ios",
+        ))] {
+            fn inner() -> Result<Pid, &'static str> {
+                unsafe { Ok(Pid(libc::getpid())) }
+            }
+        } else if #[cfg(windows)] {
+            fn inner() -> Result<Pid, &'static str> {
+                use windows::Win32::System::Threading::GetCurrentProcessId;
+
+                unsafe { Ok(Pid(GetCurrentProcessId() as _)) }
+            }
+        } else {
+            fn inner() -> Result<Pid, &'static str> {
+                Err("Unknown platform")
+            }
+        }
+    }
+    inner()
+}
+
+/// MAC address for network interface.
+///
+/// It is returned by [`NetworkData::mac_address`][crate::NetworkData::mac_address].
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct MacAddr(pub [u8; 6]);
+
+impl MacAddr {
+    /// A `MacAddr` with all bytes set to `0`.
+    pub const UNSPECIFIED: Self = MacAddr([0; 6]);
+
+    /// Checks if this `MacAddr` has all bytes equal to `0`.
+    pub fn is_unspecified(&self) -> bool {
+        self == &MacAddr::UNSPECIFIED
+    }
+}
+
+impl fmt::Display for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data = &self.0;
+        write!(
+            f,
+            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+            data[0], data[1], data[2], data[3], data[4], data[5],
+        )
+    }
+}
+
+/// IP network (address and prefix length) of a network interface.
+///
+/// It is returned by [`NetworkData::ip_networks`][crate::NetworkData::ip_networks].
+///
+/// ```no_run
+/// use sysinfo::IpNetwork;
+/// use std::net::{IpAddr, Ipv4Addr};
+///
+/// let ip_network = IpNetwork {
+///     addr: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 12)),
+///     prefix: 24,
+/// };
+/// assert_eq!(ip_network.to_string(), "192.168.1.12/24");
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug)]
+pub struct IpNetwork {
+    /// IP address of the interface.
+    pub addr: IpAddr,
+    /// Length of the network prefix, in bits.
+    pub prefix: u8,
+}
+
+impl fmt::Display for IpNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix)
+    }
+}
+
+/// Duplex mode of a network interface.
+///
+/// It is returned by [`NetworkData::duplex`][crate::NetworkData::duplex].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DuplexMode {
+    /// Data can be sent and received at the same time.
+    Full,
+    /// Data can only be sent or received at a time.
+    Half,
+    /// The duplex mode is unknown, or the link is down.
+    Unknown,
+}
+
+/// Detailed packet counters of a network interface.
+///
+/// The values are totals since the interface was brought up. They are `None` when the system
+/// doesn't provide them.
+///
+/// It is returned by [`NetworkData::detailed_counters`][crate::NetworkData::detailed_counters].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NetworkCounters {
+    /// Number of multicast packets received.
+    ///
+    /// ⚠️ Not available on Windows.
+    pub multicast_received: Option<u64>,
+    /// Number of multicast packets transmitted.
+    ///
+    /// ⚠️ Only available on macOS and FreeBSD.
+    pub multicast_transmitted: Option<u64>,
+    /// Number of collisions detected while transmitting.
+    ///
+    /// ⚠️ Not available on Windows.
+    pub collisions: Option<u64>,
+    /// Number of received packets which were dropped, for example because of a lack of
+    /// resources.
+    pub dropped_on_received: Option<u64>,
+    /// Number of packets to transmit which were dropped.
+    ///
+    /// ⚠️ Not available on macOS.
+    pub dropped_on_transmitted: Option<u64>,
+    /// Number of receive FIFO (ring buffer) overruns.
+    ///
+    /// ⚠️ Only available on Linux.
+    pub fifo_errors_on_received: Option<u64>,
+    /// Number of transmit FIFO underruns.
+    ///
+    /// ⚠️ Only available on Linux.
+    pub fifo_errors_on_transmitted: Option<u64>,
+    /// Number of received frames with an alignment error.
+    ///
+    /// ⚠️ Only available on Linux.
+    pub frame_errors_on_received: Option<u64>,
+    /// Number of transmit errors caused by the loss of the carrier.
+    ///
+    /// ⚠️ Only available on Linux.
+    pub carrier_errors_on_transmitted: Option<u64>,
+}
+
+/// Kind of a network interface.
+///
+/// It is returned by [`NetworkData::kind`][crate::NetworkData::kind].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InterfaceKind {
+    /// Virtual LAN (802.1Q) interface, sitting on a parent interface.
+    Vlan,
+    /// Bridge, forwarding the traffic between its member interfaces.
+    Bridge,
+    /// Bond (or team, or link aggregation), combining its member interfaces.
+    Bond,
+    /// Any other kind of interface, physical or virtual.
+    Other,
+}
+
+/// Details of the Wi-Fi connection of a network interface.
+///
+/// It is returned by [`NetworkData::wireless`][crate::NetworkData::wireless].
+///
+/// ```no_run
+/// use sysinfo::Networks;
+///
+/// let networks = Networks::new_with_refreshed_list();
+/// for (interface_name, network) in &networks {
+///     if let Some(wireless) = network.wireless() {
+///         println!("{interface_name}: {wireless:?}");
+///     }
+/// }
+/// ```
+#[cfg(feature = "wireless")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WirelessInfo {
+    /// Name of the network the interface is connected to.
+    pub ssid: Option<String>,
+    /// MAC address of the access point the interface is connected to.
+    pub bssid: Option<MacAddr>,
+    /// Strength of the received signal, in dBm.
+    ///
+    /// ⚠️ On Windows, it is estimated from the signal quality.
+    pub signal: Option<i32>,
+    /// Channel used by the connection.
+    pub channel: Option<u32>,
+    /// Transmit bitrate, in bits per second.
+    pub bitrate: Option<u64>,
+}
+
+/// Operational state of a network interface, as defined in
+/// [RFC 2863](https://www.rfc-editor.org/rfc/rfc2863#section-3.1.14).
+///
+/// It is returned by [`NetworkData::operational_state`][crate::NetworkData::operational_state].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OperationalState {
+    /// The interface is ready to pass packets.
+    Up,
+    /// The interface can't pass packets, for example because it is disabled or has no carrier.
+    Down,
+    /// The interface is in a test mode.
+    Testing,
+    /// The interface is waiting for an external event, like an authentication on Wi-Fi networks.
+    Dormant,
+    /// A component of the interface (typically hardware) is missing.
+    NotPresent,
+    /// The interface is down because of a lower-layer interface, like the members of a bond.
+    LowerLayerDown,
+    /// The state is unknown.
+    Unknown,
+}
+
+/// Interacting with components.
+///
+/// ```no_run
+/// use sysinfo::Components;
+///
+/// let components = Components::new_with_refreshed_list();
+/// for component in &components {
+///     eprintln!("{component:?}");
+/// }
+/// ```
+pub struct Components {
+    pub(crate) inner: ComponentsInner,
+}
+
+impl Default for Components {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Components> for Vec<Component> {
+    fn from(components: Components) -> Self {
+        components.inner.into_vec()
+    }
+}
+
+impl From<Vec<Component>> for Components {
+    fn from(components: Vec<Component>) -> Self {
+        Self {
+            inner: ComponentsInner::from_vec(components),
+        }
+    }
+}
+
+impl std::ops::Deref for Components {
+    type Target = [Component];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for Components {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a Components {
+    type Item = &'a Component;
+    type IntoIter = std::slice::Iter<'a, Component>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Components {
+    type Item = &'a mut Component;
+    type IntoIter = std::slice::IterMut<'a, Component>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl Components {
+    /// Creates a new empty [`Components`][crate::Components] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`Components::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new();
+    /// components.refresh_list();
+    /// for component in &components {
+    ///     eprintln!("{component:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            inner: ComponentsInner::new(),
+        }
+    }
+
+    /// Creates a new [`Components`][crate::Components] type with the user list
+    /// loaded. It is a combination of [`Components::new`] and
+    /// [`Components::refresh_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new_with_refreshed_list();
+    /// for component in components.list() {
+    ///     eprintln!("{component:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut components = Self::new();
+        components.refresh_list();
+        components
+    }
+
+    /// Returns the components list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in components.list() {
+    ///     eprintln!("{component:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[Component] {
+        self.inner.list()
+    }
+
+    /// Returns the components list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new_with_refreshed_list();
+    /// for component in components.list_mut() {
+    ///     component.refresh();
+    ///     eprintln!("{component:?}");
+    /// }
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [Component] {
+        self.inner.list_mut()
+    }
+
+    /// Refreshes the listed components' information.
+    ///
+    /// ⚠️ If a component is added or removed, this method won't take it into account. Use
+    /// [`Components::refresh_list`] instead.
+    ///
+    /// ⚠️ If you didn't call [`Components::refresh_list`] beforehand, this method will do
+    /// nothing as the component list will be empty.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new_with_refreshed_list();
+    /// // We wait some time...?
+    /// components.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        for component in self.list_mut() {
+            component.refresh();
+        }
+    }
+
+    /// Refreshes the component whose [`Component::id`] is `id`. Returns `false` if there is no
+    /// such component in the list.
+    ///
+    /// Only the sensor of this component is read, which makes it the cheapest way to follow
+    /// one temperature.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new_with_refreshed_list();
+    /// if let Some(id) = components.list().first().map(|c| c.id().to_owned()) {
+    ///     std::thread::sleep(std::time::Duration::from_secs(1));
+    ///     components.refresh_component(&id);
+    /// }
+    /// ```
+    pub fn refresh_component(&mut self, id: &str) -> bool {
+        match self.list_mut().iter_mut().find(|c| c.id() == id) {
+            Some(component) => {
+                component.refresh();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The component list will be emptied then completely recomputed.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new();
+    /// components.refresh_list();
+    /// ```
+    pub fn refresh_list(&mut self) {
+        self.inner.refresh_list()
+    }
+
+    /// Same as [`Components::refresh_list`] but returns what failed during the refresh, to
+    /// tell apart a system without components from a system where they couldn't be listed.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new();
+    /// match components.try_refresh_list() {
+    ///     Ok(()) => println!("{} components", components.len()),
+    ///     Err(report) => eprintln!("failed to list the components: {report}"),
+    /// }
+    /// ```
+    pub fn try_refresh_list(&mut self) -> Result<(), RefreshReport> {
+        crate::utils::collect_errors(|| self.refresh_list())
+    }
+}
+
+/// Kind of hardware a [`Component`] measures the temperature of.
+///
+/// ```no_run
+/// use sysinfo::{ComponentKind, Components};
+///
+/// let components = Components::new_with_refreshed_list();
+/// for component in components.iter().filter(|c| c.kind() == ComponentKind::Gpu) {
+///     println!("{} {}°C", component.label(), component.temperature());
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ComponentKind {
+    /// A CPU (or one of its cores).
+    Cpu,
+    /// A discrete or integrated GPU.
+    Gpu,
+    /// A storage device, like a NVMe drive.
+    Storage,
+    /// A battery.
+    Battery,
+    /// Anything else, like the motherboard or an ACPI thermal zone.
+    Other,
+}
+
+/// Unit of the values of a [`Component`].
+///
+/// ```no_run
+/// use sysinfo::{ComponentUnit, Components};
+///
+/// let components = Components::new_with_refreshed_list();
+/// for component in &components {
+///     if component.unit() == ComponentUnit::Celsius {
+///         println!("{} {}°C", component.label(), component.temperature());
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ComponentUnit {
+    /// Degree Celsius, for temperatures.
+    Celsius,
+    /// Volt, for voltages.
+    Volt,
+    /// Ampere, for currents.
+    Ampere,
+    /// Watt, for powers.
+    Watt,
+    /// Revolutions per minute, for fan speeds.
+    Rpm,
+}
+
+impl ComponentUnit {
+    /// Returns the symbol of the unit, like `°C`.
+    ///
+    /// ```
+    /// use sysinfo::ComponentUnit;
+    ///
+    /// assert_eq!(ComponentUnit::Celsius.symbol(), "°C");
+    /// ```
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Celsius => "°C",
+            Self::Volt => "V",
+            Self::Ampere => "A",
+            Self::Watt => "W",
+            Self::Rpm => "RPM",
+        }
+    }
+}
+
+/// Getting a component temperature information.
+///
+/// ```no_run
+/// use sysinfo::Components;
+///
+/// let components = Components::new_with_refreshed_list();
+/// for component in &components {
+///     println!("{} {}°C", component.label(), component.temperature());
+/// }
+/// ```
+pub struct Component {
+    pub(crate) inner: ComponentInner,
+}
+
+impl Component {
+    /// Returns the temperature of the component (in celsius degree).
+    ///
+    /// ## Linux
+    ///
+    /// Returns `f32::NAN` if it failed to retrieve it.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{}°C", component.temperature());
+    /// }
+    /// ```
+    pub fn temperature(&self) -> f32 {
+        self.inner.temperature()
+    }
+
+    /// Returns the maximum temperature of the component (in celsius degree).
+    ///
+    /// Note: if `temperature` is higher than the current `max`,
+    /// `max` value will be updated on refresh.
+    ///
+    /// ## Linux
+    ///
+    /// May be computed by `sysinfo` from kernel.
+    /// Returns `f32::NAN` if it failed to retrieve it.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{}°C", component.max());
+    /// }
+    /// ```
+    pub fn max(&self) -> f32 {
+        self.inner.max()
+    }
+
+    /// Returns the highest temperature before the component halts (in celsius degree).
+    ///
+    /// ## Linux
+    ///
+    /// Critical threshold defined by chip or kernel.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{:?}°C", component.critical());
+    /// }
+    /// ```
+    pub fn critical(&self) -> Option<f32> {
+        self.inner.critical()
+    }
+
+    /// Returns the label of the component.
+    ///
+    /// ## Linux
+    ///
+    /// Since components information is retrieved thanks to `hwmon`,
+    /// the labels are generated as follows.
+    /// Note: it may change and it was inspired by `sensors` own formatting.
+    ///
+    /// | name | label | device_model | id_sensor | Computed label by `sysinfo` |
+    /// |---------|--------|------------|----------|----------------------|
+    /// | ✓    | ✓    | ✓  | ✓ | `"{name} {label} {device_model} temp{id}"` |
+    /// | ✓    | ✓    | ✗  | ✓ | `"{name} {label} {id}"` |
+    /// | ✓    | ✗    | ✓  | ✓ | `"{name} {device_model}"` |
+    /// | ✓    | ✗    | ✗  | ✓ | `"{name} temp{id}"` |
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{}", component.label());
+    /// }
+    /// ```
+    pub fn label(&self) -> &str {
+        self.inner.label()
+    }
+
+    /// Returns a machine-readable identifier of the component's sensor.
+    ///
+    /// Unlike [`Component::label`], it doesn't change across reboots nor with the system
+    /// language, so it can be used to store settings associated to a component. Its format
+    /// depends on the system:
+    ///
+    /// | OS | Example |
+    /// |----|---------|
+    /// | Linux | `pci0000:00/0000:00:18.3/temp1` (`hwmon` device and channel), `nvml/GPU-<uuid>` |
+    /// | macOS (x86) | `TC0P` (SMC key) |
+    /// | macOS (arm) | `PMU tdie1` (HID sensor name) |
+    /// | FreeBSD | `dev.cpu.0.temperature` (sysctl name) |
+    /// | Windows | `ACPI\ThermalZone\TZ00_0` (WMI instance), `PhysicalDrive0`, `PCI 01:00.0` |
+    ///
+    /// It is empty on unsupported systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{}: {}", component.id(), component.label());
+    /// }
+    /// ```
+    pub fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    /// Returns the kind of hardware the component measures the temperature of.
+    ///
+    /// Unlike the label, it doesn't depend on the driver, so it can be used to find the GPU
+    /// temperatures for example.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{}: {:?}", component.label(), component.kind());
+    /// }
+    /// ```
+    pub fn kind(&self) -> ComponentKind {
+        self.inner.kind()
+    }
+
+    /// Returns the unit of the values returned by [`Component::temperature`],
+    /// [`Component::max`], [`Component::critical`] and the limits of the component.
+    ///
+    /// Only temperature sensors are listed for now, so it is always
+    /// [`ComponentUnit::Celsius`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{} {}", component.temperature(), component.unit().symbol());
+    /// }
+    /// ```
+    pub fn unit(&self) -> ComponentUnit {
+        ComponentUnit::Celsius
+    }
+
+    /// Returns the lowest value the hardware is specified for (in [`Component::unit`]).
+    ///
+    /// A reading below it is likely to be wrong, or to trigger an alarm.
+    ///
+    /// ## Linux
+    ///
+    /// Read from `hwmon` `tempN_min`.
+    ///
+    /// ## Other systems
+    ///
+    /// It is not available, so `None` is returned.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{:?}", component.lower_limit());
+    /// }
+    /// ```
+    pub fn lower_limit(&self) -> Option<f32> {
+        self.inner.lower_limit()
+    }
+
+    /// Returns the highest value the hardware is specified for (in [`Component::unit`]).
+    ///
+    /// Unlike [`Component::critical`], going above it doesn't halt the component, but it is
+    /// usually considered as an alarm. Unlike [`Component::max`], it isn't a reading.
+    ///
+    /// ## Linux
+    ///
+    /// Read from `hwmon` `tempN_max`.
+    ///
+    /// ## Other systems
+    ///
+    /// It is not available, so `None` is returned.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{:?}", component.upper_limit());
+    /// }
+    /// ```
+    pub fn upper_limit(&self) -> Option<f32> {
+        self.inner.upper_limit()
+    }
+
+    /// Returns the value of the last reading, as provided by the system before being converted
+    /// to [`Component::unit`].
+    ///
+    /// It allows to check suspicious readings, or to use a more precise conversion. Its scale
+    /// depends on the system:
+    ///
+    /// | OS | Scale |
+    /// |----|-------|
+    /// | Linux | millidegree Celsius (`hwmon`), degree Celsius (NVML) |
+    /// | FreeBSD | tenth of degree Kelvin |
+    ///
+    /// `None` is returned on other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{}: {:?}", component.temperature(), component.raw_value());
+    /// }
+    /// ```
+    pub fn raw_value(&self) -> Option<i64> {
+        self.inner.raw_value()
+    }
+
+    /// Refreshes component.
+    ///
+    /// Only the sensor of this component is read, the other components aren't listed again.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new_with_refreshed_list();
+    /// for component in components.iter_mut() {
+    ///     component.refresh();
+    /// }
+    /// ```
+    pub fn refresh(&mut self) {
+        self.inner.refresh()
     }
+}
 
-    /// Returns the available disk size, in bytes.
+/// Temperature above which a [`ComponentEvents`] reports a component.
+///
+/// ```no_run
+/// use sysinfo::{ComponentEvents, ComponentThreshold};
+///
+/// let mut events = ComponentEvents::new(ComponentThreshold::Critical);
+/// events.set_threshold("coretemp Package id 0", ComponentThreshold::Temperature(85.));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComponentThreshold {
+    /// The threshold defined by the hardware, returned by [`Component::critical`]. Components
+    /// which don't have one are never reported.
+    Critical,
+    /// The given temperature (in celsius degree).
+    Temperature(f32),
+}
+
+/// Event returned by [`ComponentEvents`].
+///
+/// ```no_run
+/// use sysinfo::{ComponentEvent, ComponentEvents, ComponentThreshold};
+/// use std::time::Duration;
+///
+/// let mut events = ComponentEvents::new(ComponentThreshold::Critical);
+/// for event in events.wait(Duration::from_secs(1), None) {
+///     match event {
+///         ComponentEvent::Exceeded { label, temperature, .. } => {
+///             println!("{label} is too hot: {temperature}°C");
+///         }
+///         ComponentEvent::Recovered { label, .. } => println!("{label} cooled down"),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentEvent {
+    /// The temperature of the component went above its threshold.
+    Exceeded {
+        /// Label of the component.
+        label: String,
+        /// Temperature of the component (in celsius degree).
+        temperature: f32,
+        /// Threshold which was crossed (in celsius degree).
+        threshold: f32,
+    },
+    /// The temperature of the component went back below its threshold (minus the hysteresis).
+    Recovered {
+        /// Label of the component.
+        label: String,
+        /// Temperature of the component (in celsius degree).
+        temperature: f32,
+        /// Threshold which was crossed (in celsius degree).
+        threshold: f32,
+    },
+}
+
+/// Notifies when the temperature of components crosses a threshold, so you don't need to
+/// compare the temperatures yourself after each refresh.
+///
+/// The components are the ones listed by [`Components::refresh_list`] when the
+/// `ComponentEvents` is created. A component already above its threshold is reported by the
+/// first check.
+///
+/// ```no_run
+/// use sysinfo::{ComponentEvents, ComponentThreshold};
+/// use std::time::Duration;
+///
+/// let mut events = ComponentEvents::new(ComponentThreshold::Temperature(90.));
+/// loop {
+///     for event in events.wait(Duration::from_secs(2), None) {
+///         println!("{event:?}");
+///     }
+/// }
+/// ```
+pub struct ComponentEvents {
+    components: Components,
+    default_threshold: ComponentThreshold,
+    thresholds: HashMap<String, ComponentThreshold>,
+    hysteresis: f32,
+    /// Whether each component of `components` is above its threshold.
+    exceeded: Vec<bool>,
+}
+
+impl ComponentEvents {
+    /// Creates a new `ComponentEvents` reporting the components crossing `threshold`.
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::{ComponentEvents, ComponentThreshold};
     ///
-    /// let disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list() {
-    ///     println!("[{:?}] {}B", disk.name(), disk.available_space());
+    /// let events = ComponentEvents::new(ComponentThreshold::Critical);
+    /// ```
+    pub fn new(threshold: ComponentThreshold) -> Self {
+        let components = Components::new_with_refreshed_list();
+        Self {
+            exceeded: vec![false; components.len()],
+            components,
+            default_threshold: threshold,
+            thresholds: HashMap::new(),
+            hysteresis: 0.,
+        }
+    }
+
+    /// Uses `threshold` instead of the one given to [`ComponentEvents::new`] for the components
+    /// whose label is `label`.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ComponentEvents, ComponentThreshold};
+    ///
+    /// let mut events = ComponentEvents::new(ComponentThreshold::Critical);
+    /// events.set_threshold("acpitz temp1", ComponentThreshold::Temperature(70.));
+    /// ```
+    pub fn set_threshold(&mut self, label: &str, threshold: ComponentThreshold) {
+        self.thresholds.insert(label.to_owned(), threshold);
+    }
+
+    /// Sets by how many degrees the temperature must go below the threshold for the component
+    /// to be reported as [`ComponentEvent::Recovered`]. It avoids getting events at each check
+    /// when the temperature oscillates around the threshold. It is `0` by default.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ComponentEvents, ComponentThreshold};
+    ///
+    /// let mut events = ComponentEvents::new(ComponentThreshold::Critical);
+    /// events.set_hysteresis(5.);
+    /// ```
+    pub fn set_hysteresis(&mut self, hysteresis: f32) {
+        self.hysteresis = hysteresis;
+    }
+
+    /// Returns the watched components, as of the last check.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ComponentEvents, ComponentThreshold};
+    ///
+    /// let events = ComponentEvents::new(ComponentThreshold::Critical);
+    /// for component in events.components() {
+    ///     println!("{} {}°C", component.label(), component.temperature());
     /// }
     /// ```
-    pub fn available_space(&self) -> u64 {
-        self.inner.available_space()
+    pub fn components(&self) -> &Components {
+        &self.components
     }
 
-    /// Returns `true` if the disk is removable.
+    /// Refreshes the components and returns the thresholds crossed since the previous check.
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::{ComponentEvents, ComponentThreshold};
     ///
-    /// let disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list() {
-    ///     println!("[{:?}] {}", disk.name(), disk.is_removable());
+    /// let mut events = ComponentEvents::new(ComponentThreshold::Critical);
+    /// for event in events.check() {
+    ///     println!("{event:?}");
     /// }
     /// ```
-    pub fn is_removable(&self) -> bool {
-        self.inner.is_removable()
+    pub fn check(&mut self) -> Vec<ComponentEvent> {
+        self.components.refresh();
+        let mut events = Vec::new();
+        for (component, exceeded) in self.components.iter().zip(self.exceeded.iter_mut()) {
+            let threshold = match self
+                .thresholds
+                .get(component.label())
+                .unwrap_or(&self.default_threshold)
+            {
+                ComponentThreshold::Critical => match component.critical() {
+                    Some(critical) => critical,
+                    None => continue,
+                },
+                ComponentThreshold::Temperature(threshold) => *threshold,
+            };
+            let temperature = component.temperature();
+            match threshold_crossing(*exceeded, temperature, threshold, self.hysteresis) {
+                Some(true) => events.push(ComponentEvent::Exceeded {
+                    label: component.label().to_owned(),
+                    temperature,
+                    threshold,
+                }),
+                Some(false) => events.push(ComponentEvent::Recovered {
+                    label: component.label().to_owned(),
+                    temperature,
+                    threshold,
+                }),
+                None => continue,
+            }
+            *exceeded = !*exceeded;
+        }
+        events
     }
 
-    /// Updates the disk' information.
+    /// Checks the components every `interval` until a threshold is crossed and returns what
+    /// changed. If `timeout` elapses before, an empty list is returned.
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::{ComponentEvents, ComponentThreshold};
+    /// use std::time::Duration;
     ///
-    /// let mut disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list_mut() {
-    ///     disk.refresh();
+    /// let mut events = ComponentEvents::new(ComponentThreshold::Critical);
+    /// for event in events.wait(Duration::from_secs(1), Some(Duration::from_secs(60))) {
+    ///     println!("{event:?}");
     /// }
     /// ```
-    pub fn refresh(&mut self) -> bool {
-        self.inner.refresh()
+    pub fn wait(&mut self, interval: Duration, timeout: Option<Duration>) -> Vec<ComponentEvent> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            let events = self.check();
+            if !events.is_empty() {
+                return events;
+            }
+            let sleep = match deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        return Vec::new();
+                    }
+                    remaining.min(interval)
+                }
+                None => interval,
+            };
+            std::thread::sleep(sleep);
+        }
     }
 }
 
-/// Disks interface.
+/// Returns `Some(true)` if the temperature went above `threshold`, `Some(false)` if it went back
+/// below `threshold - hysteresis` and `None` if nothing changed. Unknown temperatures (`NaN`)
+/// don't change anything.
+fn threshold_crossing(
+    exceeded: bool,
+    temperature: f32,
+    threshold: f32,
+    hysteresis: f32,
+) -> Option<bool> {
+    if !exceeded && temperature > threshold {
+        Some(true)
+    } else if exceeded && temperature < threshold - hysteresis {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Interacting with the batteries of the system.
 ///
 /// ```no_run
-/// use sysinfo::Disks;
+/// use sysinfo::Batteries;
 ///
-/// let disks = Disks::new_with_refreshed_list();
-/// for disk in disks.list() {
-///     println!("{disk:?}");
+/// let batteries = Batteries::new_with_refreshed_list();
+/// for battery in batteries.list() {
+///     println!("{}: {}% ({:?})", battery.name, battery.charge, battery.state);
 /// }
 /// ```
-pub struct Disks {
-    inner: crate::DisksInner,
+pub struct Batteries {
+    batteries: Vec<Battery>,
 }
 
-impl Default for Disks {
+impl Default for Batteries {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl From<Disks> for Vec<Disk> {
-    fn from(disks: Disks) -> Vec<Disk> {
-        disks.inner.into_vec()
+impl From<Batteries> for Vec<Battery> {
+    fn from(batteries: Batteries) -> Self {
+        batteries.batteries
     }
 }
 
-impl From<Vec<Disk>> for Disks {
-    fn from(disks: Vec<Disk>) -> Self {
-        Self {
-            inner: crate::DisksInner::from_vec(disks),
-        }
+impl From<Vec<Battery>> for Batteries {
+    fn from(batteries: Vec<Battery>) -> Self {
+        Self { batteries }
     }
 }
 
-impl<'a> IntoIterator for &'a Disks {
-    type Item = &'a Disk;
-    type IntoIter = std::slice::Iter<'a, Disk>;
+impl std::ops::Deref for Batteries {
+    type Target = [Battery];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for Batteries {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a Batteries {
+    type Item = &'a Battery;
+    type IntoIter = std::slice::Iter<'a, Battery>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.list().iter()
     }
 }
 
-impl<'a> IntoIterator for &'a mut Disks {
-    type Item = &'a mut Disk;
-    type IntoIter = std::slice::IterMut<'a, Disk>;
+impl<'a> IntoIterator for &'a mut Batteries {
+    type Item = &'a mut Battery;
+    type IntoIter = std::slice::IterMut<'a, Battery>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.list_mut().iter_mut()
     }
 }
 
-impl Disks {
-    /// Creates a new empty [`Disks`][crate::Disks] type.
+impl Batteries {
+    /// Creates a new empty [`Batteries`][crate::Batteries] type.
     ///
-    /// If you want it to be filled directly, take a look at [`Disks::new_with_refreshed_list`].
+    /// If you want it to be filled directly, take a look at
+    /// [`Batteries::new_with_refreshed_list`].
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::Batteries;
     ///
-    /// let mut disks = Disks::new();
-    /// disks.refresh_list();
-    /// for disk in disks.list() {
-    ///     eprintln!("{disk:?}");
+    /// let mut batteries = Batteries::new();
+    /// batteries.refresh_list();
+    /// for battery in batteries.list() {
+    ///     eprintln!("{battery:?}");
     /// }
     /// ```
     pub fn new() -> Self {
         Self {
-            inner: crate::DisksInner::new(),
+            batteries: Vec::new(),
         }
     }
 
-    /// Creates a new [`Disks`][crate::Disks] type with the disk list loaded.
-    /// It is a combination of [`Disks::new`] and [`Disks::refresh_list`].
+    /// Creates a new [`Batteries`][crate::Batteries] type with the battery list loaded.
+    /// It is a combination of [`Batteries::new`] and [`Batteries::refresh_list`].
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::Batteries;
     ///
-    /// let mut disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list() {
-    ///     eprintln!("{disk:?}");
+    /// let batteries = Batteries::new_with_refreshed_list();
+    /// for battery in batteries.list() {
+    ///     eprintln!("{battery:?}");
     /// }
     /// ```
     pub fn new_with_refreshed_list() -> Self {
-        let mut disks = Self::new();
-        disks.refresh_list();
-        disks
+        let mut batteries = Self::new();
+        batteries.refresh_list();
+        batteries
     }
 
-    /// Returns the disks list.
+    /// Returns the battery list.
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::Batteries;
     ///
-    /// let disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list() {
-    ///     eprintln!("{disk:?}");
+    /// let batteries = Batteries::new_with_refreshed_list();
+    /// for battery in batteries.list() {
+    ///     eprintln!("{battery:?}");
     /// }
     /// ```
-    pub fn list(&self) -> &[Disk] {
-        self.inner.list()
+    pub fn list(&self) -> &[Battery] {
+        &self.batteries
     }
 
-    /// Returns the disks list.
+    /// Returns the battery list.
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::Batteries;
     ///
-    /// let mut disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list_mut() {
-    ///     disk.refresh();
-    ///     eprintln!("{disk:?}");
-    /// }
+    /// let mut batteries = Batteries::new_with_refreshed_list();
+    /// batteries.list_mut().sort_by(|a, b| a.name.cmp(&b.name));
     /// ```
-    pub fn list_mut(&mut self) -> &mut [Disk] {
-        self.inner.list_mut()
+    pub fn list_mut(&mut self) -> &mut [Battery] {
+        &mut self.batteries
     }
 
-    /// Refreshes the listed disks' information.
-    ///
-    /// ⚠️ If a disk is added or removed, this method won't take it into account. Use
-    /// [`Disks::refresh_list`] instead.
+    /// The battery list will be emptied then completely recomputed.
     ///
-    /// ⚠️ If you didn't call [`Disks::refresh_list`] beforehand, this method will do nothing as
-    /// the disk list will be empty.
+    /// ⚠️ On FreeBSD, all the batteries are combined into one.
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::Batteries;
     ///
-    /// let mut disks = Disks::new_with_refreshed_list();
-    /// // We wait some time...?
-    /// disks.refresh();
+    /// let mut batteries = Batteries::new();
+    /// batteries.refresh_list();
     /// ```
-    pub fn refresh(&mut self) {
-        for disk in self.list_mut() {
-            disk.refresh();
-        }
+    pub fn refresh_list(&mut self) {
+        self.batteries.clear();
+        crate::sys::get_batteries(&mut self.batteries);
     }
+}
 
-    /// The disk list will be emptied then completely recomputed.
-    ///
-    /// ## Linux
-    ///
-    /// ⚠️ On Linux, the [NFS](https://en.wikipedia.org/wiki/Network_File_System) file
-    /// systems are ignored and the information of a mounted NFS **cannot** be obtained
-    /// via [`Disks::refresh_list`]. This is due to the fact that I/O function
-    /// `statvfs` used by [`Disks::refresh_list`] is blocking and
-    /// [may hang](https://github.com/GuillaumeGomez/sysinfo/pull/876) in some cases,
-    /// requiring to call `systemctl stop` to terminate the NFS service from the remote
-    /// server in some cases.
+/// Information about a battery.
+///
+/// It is returned by [`Batteries::list`].
+///
+/// ```no_run
+/// use sysinfo::{Batteries, BatteryState};
+///
+/// let batteries = Batteries::new_with_refreshed_list();
+/// for battery in batteries.list() {
+///     if battery.state == BatteryState::Discharging {
+///         println!("{}: {:?} left", battery.name, battery.time_to_empty);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Battery {
+    /// Name of the battery, like `BAT0`.
+    pub name: String,
+    /// Whether the battery is charging or not.
+    pub state: BatteryState,
+    /// Charge of the battery, in percent.
+    pub charge: f32,
+    /// Energy currently stored in the battery, in milliwatt-hours.
+    pub energy: Option<u64>,
+    /// Energy the battery can store when it is full, in milliwatt-hours. It decreases as the
+    /// battery wears out.
+    pub full_capacity: Option<u64>,
+    /// Energy the battery was designed to store, in milliwatt-hours.
+    pub design_capacity: Option<u64>,
+    /// Number of charge cycles the battery went through.
+    pub cycle_count: Option<u32>,
+    /// Estimated time until the battery is empty, only known when it is discharging.
+    pub time_to_empty: Option<Duration>,
+    /// Estimated time until the battery is full, only known when it is charging.
+    pub time_to_full: Option<Duration>,
+}
+
+impl Battery {
+    /// Returns the capacity of the battery compared to its design capacity, in percent.
     ///
     /// ```no_run
-    /// use sysinfo::Disks;
+    /// use sysinfo::Batteries;
     ///
-    /// let mut disks = Disks::new();
-    /// disks.refresh_list();
+    /// let batteries = Batteries::new_with_refreshed_list();
+    /// for battery in batteries.list() {
+    ///     if let Some(health) = battery.health() {
+    ///         println!("{}: {health}%", battery.name);
+    ///     }
+    /// }
     /// ```
-    pub fn refresh_list(&mut self) {
-        self.inner.refresh_list();
+    pub fn health(&self) -> Option<f32> {
+        match (self.full_capacity, self.design_capacity) {
+            (Some(full), Some(design)) if design != 0 => Some(full as f32 * 100. / design as f32),
+            _ => None,
+        }
     }
 }
 
-impl std::ops::Deref for Disks {
-    type Target = [Disk];
+/// State of a [`Battery`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BatteryState {
+    /// The battery is charging.
+    Charging,
+    /// The battery is discharging.
+    Discharging,
+    /// The battery is full.
+    Full,
+    /// The battery is plugged in but isn't charging, for example because a charge limit is set.
+    NotCharging,
+    /// Unknown state.
+    Unknown,
+}
+
+/// Interacting with the thermal zones of the system and their trip points, which are the
+/// temperatures at which the platform starts cooling (or shuts down).
+///
+/// ```no_run
+/// use sysinfo::ThermalZones;
+///
+/// let zones = ThermalZones::new_with_refreshed_list();
+/// for zone in zones.list() {
+///     println!("{} ({:?}°C)", zone.name, zone.temperature);
+///     for trip_point in &zone.trip_points {
+///         println!("  {:?} at {}°C", trip_point.kind, trip_point.temperature);
+///     }
+/// }
+/// ```
+pub struct ThermalZones {
+    zones: Vec<ThermalZone>,
+}
+
+impl Default for ThermalZones {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<ThermalZones> for Vec<ThermalZone> {
+    fn from(zones: ThermalZones) -> Self {
+        zones.zones
+    }
+}
+
+impl From<Vec<ThermalZone>> for ThermalZones {
+    fn from(zones: Vec<ThermalZone>) -> Self {
+        Self { zones }
+    }
+}
+
+impl std::ops::Deref for ThermalZones {
+    type Target = [ThermalZone];
 
     fn deref(&self) -> &Self::Target {
         self.list()
     }
 }
 
-impl std::ops::DerefMut for Disks {
+impl std::ops::DerefMut for ThermalZones {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.list_mut()
     }
 }
 
-/// Enum containing the different supported kinds of disks.
+impl<'a> IntoIterator for &'a ThermalZones {
+    type Item = &'a ThermalZone;
+    type IntoIter = std::slice::Iter<'a, ThermalZone>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut ThermalZones {
+    type Item = &'a mut ThermalZone;
+    type IntoIter = std::slice::IterMut<'a, ThermalZone>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl ThermalZones {
+    /// Creates a new empty [`ThermalZones`][crate::ThermalZones] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`ThermalZones::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::ThermalZones;
+    ///
+    /// let mut zones = ThermalZones::new();
+    /// zones.refresh_list();
+    /// for zone in zones.list() {
+    ///     eprintln!("{zone:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self { zones: Vec::new() }
+    }
+
+    /// Creates a new [`ThermalZones`][crate::ThermalZones] type with the thermal zone list
+    /// loaded. It is a combination of [`ThermalZones::new`] and [`ThermalZones::refresh_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::ThermalZones;
+    ///
+    /// let zones = ThermalZones::new_with_refreshed_list();
+    /// for zone in zones.list() {
+    ///     eprintln!("{zone:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut zones = Self::new();
+        zones.refresh_list();
+        zones
+    }
+
+    /// Returns the thermal zone list.
+    ///
+    /// ```no_run
+    /// use sysinfo::ThermalZones;
+    ///
+    /// let zones = ThermalZones::new_with_refreshed_list();
+    /// for zone in zones.list() {
+    ///     eprintln!("{zone:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[ThermalZone] {
+        &self.zones
+    }
+
+    /// Returns the thermal zone list.
+    ///
+    /// ```no_run
+    /// use sysinfo::ThermalZones;
+    ///
+    /// let mut zones = ThermalZones::new_with_refreshed_list();
+    /// zones.list_mut().sort_by(|a, b| a.name.cmp(&b.name));
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [ThermalZone] {
+        &mut self.zones
+    }
+
+    /// The thermal zone list will be emptied then completely recomputed.
+    ///
+    /// ⚠️ On Windows, the thermal zones are read from `MSAcpi_ThermalZoneTemperature`, which
+    /// often requires to be administrator. On FreeBSD, the cooling devices aren't available.
+    /// Thermal zones aren't available on macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::ThermalZones;
+    ///
+    /// let mut zones = ThermalZones::new();
+    /// zones.refresh_list();
+    /// ```
+    pub fn refresh_list(&mut self) {
+        self.zones.clear();
+        crate::sys::get_thermal_zones(&mut self.zones);
+    }
+}
+
+/// A thermal zone, as defined by ACPI.
 ///
-/// This type is returned by [`Disk::kind`](`crate::Disk::kind`).
+/// It is returned by [`ThermalZones::list`].
 ///
 /// ```no_run
-/// use sysinfo::Disks;
+/// use sysinfo::{ThermalZones, TripPointKind};
 ///
-/// let disks = Disks::new_with_refreshed_list();
-/// for disk in disks.list() {
-///     println!("{:?}: {:?}", disk.name(), disk.kind());
+/// let zones = ThermalZones::new_with_refreshed_list();
+/// for zone in zones.list() {
+///     let critical = zone.trip_points.iter().find(|t| t.kind == TripPointKind::Critical);
+///     if let Some(critical) = critical {
+///         println!("{} shuts down at {}°C", zone.name, critical.temperature);
+///     }
 /// }
 /// ```
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub enum DiskKind {
-    /// HDD type.
-    HDD,
-    /// SSD type.
-    SSD,
-    /// Unknown type.
-    Unknown(isize),
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThermalZone {
+    /// Name of the zone, like `thermal_zone0` on Linux, `tz0` on FreeBSD or
+    /// `ACPI\ThermalZone\TZ00_0` on Windows.
+    pub name: String,
+    /// Kind of the zone as reported by its driver, like `acpitz` or `x86_pkg_temp`. It is only
+    /// available on Linux.
+    pub kind: Option<String>,
+    /// Current temperature of the zone, in celsius degree.
+    pub temperature: Option<f32>,
+    /// Trip points of the zone.
+    pub trip_points: Vec<TripPoint>,
 }
 
-impl fmt::Display for DiskKind {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(match *self {
-            DiskKind::HDD => "HDD",
-            DiskKind::SSD => "SSD",
-            _ => "Unknown",
-        })
-    }
+/// A temperature at which the platform takes an action.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TripPoint {
+    /// Action taken when this trip point is reached.
+    pub kind: TripPointKind,
+    /// Temperature of the trip point, in celsius degree.
+    pub temperature: f32,
+    /// How much the temperature needs to go below the trip point before the action stops, in
+    /// celsius degree. It is only available on Linux.
+    pub hysteresis: Option<f32>,
+    /// Cooling devices bound to this trip point. It is only available on Linux.
+    pub cooling_devices: Vec<CoolingDevice>,
 }
 
-/// Interacting with users.
+/// Action taken when a [`TripPoint`] is reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TripPointKind {
+    /// Active cooling, like turning on a fan.
+    Active,
+    /// Passive cooling, like throttling the CPU.
+    Passive,
+    /// The system should be suspended.
+    Hot,
+    /// The system shuts down.
+    Critical,
+}
+
+/// A device used to cool a [`ThermalZone`], like a fan or the CPU (throttled).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoolingDevice {
+    /// Kind of the device, like `Fan` or `Processor`.
+    pub name: String,
+    /// Current cooling state, `0` meaning that it doesn't cool.
+    pub state: u64,
+    /// Maximum cooling state.
+    pub max_state: u64,
+}
+
+/// Interacting with the GPUs of the system.
 ///
 /// ```no_run
-/// use sysinfo::Users;
+/// use sysinfo::Gpus;
 ///
-/// let mut users = Users::new();
-/// for user in users.list() {
-///     println!("{} is in {} groups", user.name(), user.groups().len());
+/// let gpus = Gpus::new_with_refreshed_list();
+/// for gpu in gpus.list() {
+///     println!(
+///         "{}: {:?}% ({:?}/{:?} bytes)",
+///         gpu.name(),
+///         gpu.usage(),
+///         gpu.used_memory(),
+///         gpu.total_memory(),
+///     );
 /// }
 /// ```
-pub struct Users {
-    users: Vec<User>,
+pub struct Gpus {
+    pub(crate) inner: GpusInner,
 }
 
-impl Default for Users {
+impl Default for Gpus {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl From<Users> for Vec<User> {
-    fn from(users: Users) -> Self {
-        users.users
-    }
-}
-
-impl From<Vec<User>> for Users {
-    fn from(users: Vec<User>) -> Self {
-        Self { users }
-    }
-}
-
-impl std::ops::Deref for Users {
-    type Target = [User];
+impl std::ops::Deref for Gpus {
+    type Target = [Gpu];
 
     fn deref(&self) -> &Self::Target {
         self.list()
     }
 }
 
-impl std::ops::DerefMut for Users {
+impl std::ops::DerefMut for Gpus {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.list_mut()
     }
 }
 
-impl<'a> IntoIterator for &'a Users {
-    type Item = &'a User;
-    type IntoIter = std::slice::Iter<'a, User>;
+impl<'a> IntoIterator for &'a Gpus {
+    type Item = &'a Gpu;
+    type IntoIter = std::slice::Iter<'a, Gpu>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.list().iter()
     }
 }
 
-impl<'a> IntoIterator for &'a mut Users {
-    type Item = &'a mut User;
-    type IntoIter = std::slice::IterMut<'a, User>;
+impl<'a> IntoIterator for &'a mut Gpus {
+    type Item = &'a mut Gpu;
+    type IntoIter = std::slice::IterMut<'a, Gpu>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.list_mut().iter_mut()
     }
 }
 
-impl Users {
-    /// Creates a new empty [`Users`][crate::Users] type.
+impl Gpus {
+    /// Creates a new empty [`Gpus`][crate::Gpus] type.
     ///
-    /// If you want it to be filled directly, take a look at [`Users::new_with_refreshed_list`].
+    /// If you want it to be filled directly, take a look at [`Gpus::new_with_refreshed_list`].
     ///
     /// ```no_run
-    /// use sysinfo::Users;
+    /// use sysinfo::Gpus;
     ///
-    /// let mut users = Users::new();
-    /// users.refresh_list();
-    /// for user in users.list() {
-    ///     eprintln!("{user:?}");
+    /// let mut gpus = Gpus::new();
+    /// gpus.refresh_list();
+    /// for gpu in gpus.list() {
+    ///     eprintln!("{gpu:?}");
     /// }
     /// ```
     pub fn new() -> Self {
-        Self { users: Vec::new() }
+        Self {
+            inner: GpusInner::new(),
+        }
     }
 
-    /// Creates a new [`Users`][crate::Users] type with the user list loaded.
-    /// It is a combination of [`Users::new`] and [`Users::refresh_list`].
+    /// Creates a new [`Gpus`][crate::Gpus] type with the GPU list loaded and everything
+    /// refreshed. It is a combination of [`Gpus::new`] and [`Gpus::refresh_list`].
     ///
     /// ```no_run
-    /// use sysinfo::Users;
+    /// use sysinfo::Gpus;
     ///
-    /// let mut users = Users::new_with_refreshed_list();
-    /// for user in users.list() {
-    ///     eprintln!("{user:?}");
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list() {
+    ///     eprintln!("{gpu:?}");
     /// }
     /// ```
     pub fn new_with_refreshed_list() -> Self {
-        let mut users = Self::new();
-        users.refresh_list();
-        users
+        Self::new_with_refreshed_list_specifics(GpuRefreshKind::everything())
     }
 
-    /// Returns the users list.
+    /// Creates a new [`Gpus`][crate::Gpus] type with the GPU list loaded and the given
+    /// information refreshed. It is a combination of [`Gpus::new`] and
+    /// [`Gpus::refresh_list_specifics`].
     ///
     /// ```no_run
-    /// use sysinfo::Users;
+    /// use sysinfo::{GpuRefreshKind, Gpus};
     ///
-    /// let users = Users::new_with_refreshed_list();
-    /// for user in users.list() {
-    ///     eprintln!("{user:?}");
+    /// let gpus = Gpus::new_with_refreshed_list_specifics(GpuRefreshKind::new().with_memory());
+    /// for gpu in gpus.list() {
+    ///     eprintln!("{:?}", gpu.used_memory());
     /// }
     /// ```
-    pub fn list(&self) -> &[User] {
-        &self.users
+    pub fn new_with_refreshed_list_specifics(refresh_kind: GpuRefreshKind) -> Self {
+        let mut gpus = Self::new();
+        gpus.refresh_list_specifics(refresh_kind);
+        gpus
     }
 
-    /// Returns the users list.
+    /// Returns the GPU list.
     ///
     /// ```no_run
-    /// use sysinfo::Users;
+    /// use sysinfo::Gpus;
     ///
-    /// let mut users = Users::new_with_refreshed_list();
-    /// users.list_mut().sort_by(|user1, user2| {
-    ///     user1.name().partial_cmp(user2.name()).unwrap()
-    /// });
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list() {
+    ///     eprintln!("{gpu:?}");
+    /// }
     /// ```
-    pub fn list_mut(&mut self) -> &mut [User] {
-        &mut self.users
+    pub fn list(&self) -> &[Gpu] {
+        self.inner.list()
     }
 
-    /// The user list will be emptied then completely recomputed.
+    /// Returns the GPU list.
     ///
     /// ```no_run
-    /// use sysinfo::Users;
+    /// use sysinfo::Gpus;
     ///
-    /// let mut users = Users::new();
-    /// users.refresh_list();
+    /// let mut gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list_mut() {
+    ///     gpu.refresh();
+    /// }
     /// ```
-    pub fn refresh_list(&mut self) {
-        crate::sys::get_users(&mut self.users);
+    pub fn list_mut(&mut self) -> &mut [Gpu] {
+        self.inner.list_mut()
     }
 
-    /// Returns the [`User`] matching the given `user_id`.
-    ///
-    /// **Important**: The user list must be filled before using this method, otherwise it will
-    /// always return `None` (through the `refresh_*` methods).
+    /// Refreshes everything about the listed GPUs. It is the same as calling
+    /// `Gpus::refresh_specifics(GpuRefreshKind::everything())`.
     ///
-    /// It is a shorthand for:
+    /// ```no_run
+    /// use sysinfo::Gpus;
     ///
-    /// ```ignore
-    /// # use sysinfo::Users;
-    /// let users = Users::new_with_refreshed_list();
-    /// users.list().find(|user| user.id() == user_id);
+    /// let mut gpus = Gpus::new_with_refreshed_list();
+    /// // Wait some time...
+    /// gpus.refresh();
     /// ```
-    ///
-    /// Full example:
+    pub fn refresh(&mut self) {
+        self.refresh_specifics(GpuRefreshKind::everything());
+    }
+
+    /// Refreshes the given information of the listed GPUs.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System, Users};
-    ///
-    /// let mut s = System::new_all();
-    /// let users = Users::new_with_refreshed_list();
+    /// use sysinfo::{GpuRefreshKind, Gpus};
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     if let Some(user_id) = process.user_id() {
-    ///         eprintln!("User for process 1337: {:?}", users.get_user_by_id(user_id));
-    ///     }
-    /// }
+    /// let mut gpus = Gpus::new_with_refreshed_list();
+    /// // Wait some time...
+    /// gpus.refresh_specifics(GpuRefreshKind::new().with_usage());
     /// ```
-    pub fn get_user_by_id(&self, user_id: &Uid) -> Option<&User> {
-        self.users.iter().find(|user| user.id() == user_id)
+    pub fn refresh_specifics(&mut self, refresh_kind: GpuRefreshKind) {
+        for gpu in self.list_mut() {
+            gpu.refresh_specifics(refresh_kind);
+        }
     }
-}
 
-/// An enum representing signals on UNIX-like systems.
-///
-/// On non-unix systems, this enum is mostly useless and is only there to keep coherency between
-/// the different OSes.
-///
-/// If you want the list of the supported signals on the current system, use
-/// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Debug)]
-pub enum Signal {
-    /// Hangup detected on controlling terminal or death of controlling process.
-    Hangup,
-    /// Interrupt from keyboard.
-    Interrupt,
-    /// Quit from keyboard.
-    Quit,
-    /// Illegal instruction.
-    Illegal,
-    /// Trace/breakpoint trap.
-    Trap,
-    /// Abort signal from C abort function.
-    Abort,
-    /// IOT trap. A synonym for SIGABRT.
-    IOT,
-    /// Bus error (bad memory access).
-    Bus,
-    /// Floating point exception.
-    FloatingPointException,
-    /// Kill signal.
-    Kill,
-    /// User-defined signal 1.
-    User1,
-    /// Invalid memory reference.
-    Segv,
-    /// User-defined signal 2.
-    User2,
-    /// Broken pipe: write to pipe with no readers.
-    Pipe,
-    /// Timer signal from C alarm function.
-    Alarm,
-    /// Termination signal.
-    Term,
-    /// Child stopped or terminated.
-    Child,
-    /// Continue if stopped.
-    Continue,
-    /// Stop process.
-    Stop,
-    /// Stop typed at terminal.
-    TSTP,
-    /// Terminal input for background process.
-    TTIN,
-    /// Terminal output for background process.
-    TTOU,
-    /// Urgent condition on socket.
-    Urgent,
-    /// CPU time limit exceeded.
-    XCPU,
-    /// File size limit exceeded.
-    XFSZ,
-    /// Virtual alarm clock.
-    VirtualAlarm,
-    /// Profiling time expired.
-    Profiling,
-    /// Windows resize signal.
-    Winch,
-    /// I/O now possible.
-    IO,
-    /// Pollable event (Sys V). Synonym for IO
-    Poll,
-    /// Power failure (System V).
+    /// The GPU list will be emptied then completely recomputed, with everything refreshed.
     ///
-    /// Doesn't exist on apple systems so will be ignored.
-    Power,
-    /// Bad argument to routine (SVr4).
-    Sys,
-}
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let mut gpus = Gpus::new();
+    /// gpus.refresh_list();
+    /// ```
+    pub fn refresh_list(&mut self) {
+        self.refresh_list_specifics(GpuRefreshKind::everything());
+    }
 
-impl std::fmt::Display for Signal {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = match *self {
-            Self::Hangup => "Hangup",
-            Self::Interrupt => "Interrupt",
-            Self::Quit => "Quit",
-            Self::Illegal => "Illegal",
-            Self::Trap => "Trap",
-            Self::Abort => "Abort",
-            Self::IOT => "IOT",
-            Self::Bus => "Bus",
-            Self::FloatingPointException => "FloatingPointException",
-            Self::Kill => "Kill",
-            Self::User1 => "User1",
-            Self::Segv => "Segv",
-            Self::User2 => "User2",
-            Self::Pipe => "Pipe",
-            Self::Alarm => "Alarm",
-            Self::Term => "Term",
-            Self::Child => "Child",
-            Self::Continue => "Continue",
-            Self::Stop => "Stop",
-            Self::TSTP => "TSTP",
-            Self::TTIN => "TTIN",
-            Self::TTOU => "TTOU",
-            Self::Urgent => "Urgent",
-            Self::XCPU => "XCPU",
-            Self::XFSZ => "XFSZ",
-            Self::VirtualAlarm => "VirtualAlarm",
-            Self::Profiling => "Profiling",
-            Self::Winch => "Winch",
-            Self::IO => "IO",
-            Self::Poll => "Poll",
-            Self::Power => "Power",
-            Self::Sys => "Sys",
-        };
-        f.write_str(s)
+    /// The GPU list will be emptied then completely recomputed, with the given information
+    /// refreshed.
+    ///
+    /// The GPUs come from NVML (for the NVIDIA proprietary driver) and from the DRM devices on
+    /// Linux, from DXGI on Windows and from the `IOAccelerator` services on macOS.
+    ///
+    /// ⚠️ On Windows, only the names, driver versions and total memory are available. On macOS,
+    /// only the names, usage and used memory are available, and the used memory is taken from
+    /// the system memory on Apple Silicon. GPUs aren't available on FreeBSD and iOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{GpuRefreshKind, Gpus};
+    ///
+    /// let mut gpus = Gpus::new();
+    /// gpus.refresh_list_specifics(GpuRefreshKind::new());
+    /// ```
+    pub fn refresh_list_specifics(&mut self, refresh_kind: GpuRefreshKind) {
+        self.inner.refresh_list(refresh_kind);
     }
 }
 
-/// Contains memory limits for the current process.
-#[derive(Default, Debug, Clone)]
-pub struct CGroupLimits {
-    /// Total memory (in bytes) for the current cgroup.
-    pub total_memory: u64,
-    /// Free memory (in bytes) for the current cgroup.
-    pub free_memory: u64,
-    /// Free swap (in bytes) for the current cgroup.
-    pub free_swap: u64,
-}
-
-/// A struct representing system load average value.
+/// Getting information about a GPU.
 ///
-/// It is returned by [`System::load_average`][crate::System::load_average].
+/// It is returned by [`Gpus::list`].
 ///
 /// ```no_run
-/// use sysinfo::System;
+/// use sysinfo::Gpus;
 ///
-/// let s = System::new_all();
-/// let load_avg = s.load_average();
-/// println!(
-///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
-///     load_avg.one,
-///     load_avg.five,
-///     load_avg.fifteen,
-/// );
+/// let gpus = Gpus::new_with_refreshed_list();
+/// for gpu in gpus.list() {
+///     println!("{} ({:?})", gpu.name(), gpu.driver_version());
+/// }
 /// ```
-#[repr(C)]
-#[derive(Default, Debug, Clone)]
-pub struct LoadAvg {
-    /// Average load within one minute.
-    pub one: f64,
-    /// Average load within five minutes.
-    pub five: f64,
-    /// Average load within fifteen minutes.
-    pub fifteen: f64,
+pub struct Gpu {
+    pub(crate) inner: GpuInner,
 }
 
-macro_rules! xid {
-    ($(#[$outer:meta])+ $name:ident, $type:ty $(, $trait:ty)?) => {
-        $(#[$outer])+
-        #[repr(transparent)]
-        #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
-        pub struct $name(pub(crate) $type);
+impl Gpu {
+    /// Returns the name of the GPU, like `NVIDIA GeForce RTX 3080` or `Apple M2 Pro`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list() {
+    ///     println!("{}", gpu.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        self.inner.name()
+    }
 
-        impl std::ops::Deref for $name {
-            type Target = $type;
+    /// Returns the version of the driver of the GPU.
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list() {
+    ///     println!("{:?}", gpu.driver_version());
+    /// }
+    /// ```
+    pub fn driver_version(&self) -> Option<&str> {
+        self.inner.driver_version()
+    }
 
-            fn deref(&self) -> &Self::Target {
-                &self.0
-            }
-        }
+    /// Returns how busy the GPU is (in %).
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list() {
+    ///     println!("{:?}%", gpu.usage());
+    /// }
+    /// ```
+    pub fn usage(&self) -> Option<f32> {
+        self.inner.usage()
+    }
 
-        $(
-        impl TryFrom<usize> for $name {
-            type Error = <$type as TryFrom<usize>>::Error;
+    /// Returns the amount of memory of the GPU (in bytes).
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list() {
+    ///     println!("{:?} bytes", gpu.total_memory());
+    /// }
+    /// ```
+    pub fn total_memory(&self) -> Option<u64> {
+        self.inner.total_memory()
+    }
 
-            fn try_from(t: usize) -> Result<Self, <$type as TryFrom<usize>>::Error> {
-                Ok(Self(<$type>::try_from(t)?))
-            }
-        }
+    /// Returns the amount of used memory of the GPU (in bytes).
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list() {
+    ///     println!("{:?} bytes", gpu.used_memory());
+    /// }
+    /// ```
+    pub fn used_memory(&self) -> Option<u64> {
+        self.inner.used_memory()
+    }
 
-        impl $trait for $name {
-            type Err = <$type as FromStr>::Err;
+    /// Returns the temperature of the GPU (in celsius degree).
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list() {
+    ///     println!("{:?}°C", gpu.temperature());
+    /// }
+    /// ```
+    pub fn temperature(&self) -> Option<f32> {
+        self.inner.temperature()
+    }
 
-            fn from_str(t: &str) -> Result<Self, <$type as FromStr>::Err> {
-                Ok(Self(<$type>::from_str(t)?))
-            }
-        }
-        )?
-    };
+    /// Returns the power drawn by the GPU (in watts).
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list() {
+    ///     println!("{:?} W", gpu.power());
+    /// }
+    /// ```
+    pub fn power(&self) -> Option<f32> {
+        self.inner.power()
+    }
+
+    /// Refreshes everything about the GPU. It is the same as calling
+    /// `Gpu::refresh_specifics(GpuRefreshKind::everything())`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Gpus;
+    ///
+    /// let mut gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list_mut() {
+    ///     gpu.refresh();
+    /// }
+    /// ```
+    pub fn refresh(&mut self) {
+        self.refresh_specifics(GpuRefreshKind::everything());
+    }
+
+    /// Refreshes the given information of the GPU.
+    ///
+    /// ```no_run
+    /// use sysinfo::{GpuRefreshKind, Gpus};
+    ///
+    /// let mut gpus = Gpus::new_with_refreshed_list();
+    /// for gpu in gpus.list_mut() {
+    ///     gpu.refresh_specifics(GpuRefreshKind::new().with_temperature());
+    /// }
+    /// ```
+    pub fn refresh_specifics(&mut self, refresh_kind: GpuRefreshKind) {
+        self.inner.refresh(refresh_kind);
+    }
 }
 
-macro_rules! uid {
-    ($type:ty$(, $trait:ty)?) => {
-        xid!(
-            /// A user id wrapping a platform specific type.
-            Uid,
-            $type
-            $(, $trait)?
-        );
-    };
+/// Interacting with the services of the system: the systemd units on Linux, the services of the
+/// Service Control Manager on Windows and the launchd jobs on macOS.
+///
+/// ```no_run
+/// use sysinfo::{ServiceState, Services};
+///
+/// let services = Services::new_with_refreshed_list();
+/// for service in services.list() {
+///     if service.state == ServiceState::Running {
+///         println!("{} (PID {:?})", service.name, service.pid);
+///     }
+/// }
+/// ```
+pub struct Services {
+    services: Vec<Service>,
 }
 
-macro_rules! gid {
-    ($type:ty) => {
-        xid!(
-            /// A group id wrapping a platform specific type.
-            #[derive(Copy)]
-            Gid,
-            $type,
-            FromStr
-        );
-    };
+impl Default for Services {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-cfg_if::cfg_if! {
-    if #[cfg(all(
-        not(feature = "unknown-ci"),
-        any(
-            target_os = "freebsd",
-            target_os = "linux",
-            target_os = "android",
-            target_os = "macos",
-            target_os = "ios",
-        )
-    ))] {
-        uid!(libc::uid_t, FromStr);
-        gid!(libc::gid_t);
-    } else if #[cfg(windows)] {
-        uid!(crate::windows::Sid);
-        gid!(u32);
-        // Manual implementation outside of the macro...
-        impl FromStr for Uid {
-            type Err = <crate::windows::Sid as FromStr>::Err;
+impl From<Services> for Vec<Service> {
+    fn from(services: Services) -> Self {
+        services.services
+    }
+}
 
-            fn from_str(t: &str) -> Result<Self, Self::Err> {
-                Ok(Self(t.parse()?))
-            }
+impl From<Vec<Service>> for Services {
+    fn from(services: Vec<Service>) -> Self {
+        Self { services }
+    }
+}
+
+impl std::ops::Deref for Services {
+    type Target = [Service];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for Services {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a Services {
+    type Item = &'a Service;
+    type IntoIter = std::slice::Iter<'a, Service>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Services {
+    type Item = &'a mut Service;
+    type IntoIter = std::slice::IterMut<'a, Service>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl Services {
+    /// Creates a new empty [`Services`][crate::Services] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`Services::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let mut services = Services::new();
+    /// services.refresh_list();
+    /// for service in services.list() {
+    ///     eprintln!("{service:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            services: Vec::new(),
         }
-    } else {
-        uid!(u32, FromStr);
-        gid!(u32);
     }
+
+    /// Creates a new [`Services`][crate::Services] type with the service list loaded. It is a
+    /// combination of [`Services::new`] and [`Services::refresh_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let services = Services::new_with_refreshed_list();
+    /// for service in services.list() {
+    ///     eprintln!("{service:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut services = Self::new();
+        services.refresh_list();
+        services
+    }
+
+    /// Returns the service list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let services = Services::new_with_refreshed_list();
+    /// for service in services.list() {
+    ///     eprintln!("{service:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[Service] {
+        &self.services
+    }
+
+    /// Returns the service list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let mut services = Services::new_with_refreshed_list();
+    /// services.list_mut().sort_by(|a, b| a.name.cmp(&b.name));
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [Service] {
+        &mut self.services
+    }
+
+    /// Returns the service with the given name.
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let services = Services::new_with_refreshed_list();
+    /// if let Some(service) = services.get("sshd.service") {
+    ///     println!("{:?}", service.state);
+    /// }
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&Service> {
+        self.services.iter().find(|service| service.name == name)
+    }
+
+    /// The service list will be emptied then completely recomputed, updating the state of the
+    /// services.
+    ///
+    /// ⚠️ On Linux, the units are read from the unit files and the control groups of systemd,
+    /// without going through D-Bus: a service is running when its control group contains
+    /// processes and its PID is the lowest one of the group, services which have exited
+    /// (including the failed ones) are stopped and the drop-in files aren't taken into account.
+    /// On macOS, only the jobs loaded in the system domain are listed. Services aren't available
+    /// on FreeBSD and iOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::Services;
+    ///
+    /// let mut services = Services::new();
+    /// services.refresh_list();
+    /// ```
+    pub fn refresh_list(&mut self) {
+        self.services.clear();
+        crate::sys::get_services(&mut self.services);
+    }
+}
+
+/// A service (or daemon) of the system.
+///
+/// It is returned by [`Services::list`].
+///
+/// ```no_run
+/// use sysinfo::{ServiceStartKind, Services};
+///
+/// let services = Services::new_with_refreshed_list();
+/// for service in services.list() {
+///     if service.start_kind == ServiceStartKind::Automatic {
+///         println!("{} is started at boot: {:?}", service.name, service.command);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Service {
+    /// Name of the service, like `sshd.service` on Linux, `wuauserv` on Windows or
+    /// `com.openssh.sshd` on macOS.
+    pub name: String,
+    /// Human-readable description of the service: the `Description` of the unit on Linux and
+    /// the display name on Windows. It isn't available on macOS.
+    pub description: Option<String>,
+    /// Current state of the service.
+    pub state: ServiceState,
+    /// How the service is started.
+    pub start_kind: ServiceStartKind,
+    /// Command line starting the service, like `/usr/sbin/sshd -D` or
+    /// `C:\Windows\system32\svchost.exe -k netsvcs`.
+    pub command: Option<String>,
+    /// PID of the main process of the service, `None` if it isn't running. On Windows, several
+    /// services can be hosted by the same process.
+    pub pid: Option<Pid>,
 }
 
-/// Type containing user information.
+/// State of a [`Service`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ServiceState {
+    /// The service is running.
+    Running,
+    /// The service isn't running.
+    Stopped,
+    /// The service is starting.
+    Starting,
+    /// The service is stopping.
+    Stopping,
+    /// The service is paused, or is being paused or resumed. It is only used on Windows.
+    Paused,
+    /// Unknown state.
+    Unknown,
+}
+
+/// How a [`Service`] is started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ServiceStartKind {
+    /// The service is started at boot (the unit is enabled on Linux, the job is kept alive on
+    /// macOS).
+    Automatic,
+    /// The service is started on demand, by a user or another service.
+    Manual,
+    /// The service can't be started (the unit is masked on Linux).
+    Disabled,
+    /// Unknown start kind.
+    Unknown,
+}
+
+/// Interacting with the containers running on the system.
 ///
-/// It is returned by [`Users`][crate::Users].
+/// The containers are found from their control groups, so their processes can be looked up in
+/// the [`System`] process list.
 ///
 /// ```no_run
-/// use sysinfo::Users;
+/// use sysinfo::Containers;
 ///
-/// let users = Users::new_with_refreshed_list();
-/// for user in users.list() {
-///     println!("{:?}", user);
+/// let containers = Containers::new_with_refreshed_list();
+/// for container in containers.list() {
+///     println!("{} ({}): {} bytes", container.id(), container.runtime(), container.memory());
 /// }
 /// ```
-pub struct User {
-    pub(crate) inner: UserInner,
+pub struct Containers {
+    pub(crate) inner: ContainersInner,
 }
 
-impl PartialEq for User {
-    fn eq(&self, other: &Self) -> bool {
-        self.id() == other.id()
-            && self.group_id() == other.group_id()
-            && self.name() == other.name()
+impl Default for Containers {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl Eq for User {}
+impl std::ops::Deref for Containers {
+    type Target = [Container];
 
-impl PartialOrd for User {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    fn deref(&self) -> &Self::Target {
+        self.list()
     }
 }
 
-impl Ord for User {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.name().cmp(other.name())
+impl std::ops::DerefMut for Containers {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
     }
 }
 
-impl User {
-    /// Returns the ID of the user.
+impl<'a> IntoIterator for &'a Containers {
+    type Item = &'a Container;
+    type IntoIter = std::slice::Iter<'a, Container>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Containers {
+    type Item = &'a mut Container;
+    type IntoIter = std::slice::IterMut<'a, Container>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl Containers {
+    /// Creates a new empty [`Containers`][crate::Containers] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`Containers::new_with_refreshed_list`].
     ///
     /// ```no_run
-    /// use sysinfo::Users;
+    /// use sysinfo::Containers;
     ///
-    /// let users = Users::new_with_refreshed_list();
-    /// for user in users.list() {
-    ///     println!("{:?}", *user.id());
+    /// let mut containers = Containers::new();
+    /// containers.refresh_list();
+    /// for container in containers.list() {
+    ///     eprintln!("{container:?}");
     /// }
     /// ```
-    pub fn id(&self) -> &Uid {
-        self.inner.id()
+    pub fn new() -> Self {
+        Self {
+            inner: ContainersInner::new(),
+        }
     }
 
-    /// Returns the group ID of the user.
-    ///
-    /// ⚠️ This information is not set on Windows.  Windows doesn't have a `username` specific
-    /// group assigned to the user. They do however have unique
-    /// [Security Identifiers](https://docs.microsoft.com/en-us/windows/win32/secauthz/security-identifiers)
-    /// made up of various [Components](https://docs.microsoft.com/en-us/windows/win32/secauthz/sid-components).
-    /// Pieces of the SID may be a candidate for this field, but it doesn't map well to a single
-    /// group ID.
+    /// Creates a new [`Containers`][crate::Containers] type with the container list loaded. It
+    /// is a combination of [`Containers::new`] and [`Containers::refresh_list`].
     ///
     /// ```no_run
-    /// use sysinfo::Users;
+    /// use sysinfo::Containers;
     ///
-    /// let users = Users::new_with_refreshed_list();
-    /// for user in users.list() {
-    ///     println!("{}", *user.group_id());
+    /// let containers = Containers::new_with_refreshed_list();
+    /// for container in containers.list() {
+    ///     eprintln!("{container:?}");
     /// }
     /// ```
-    pub fn group_id(&self) -> Gid {
-        self.inner.group_id()
+    pub fn new_with_refreshed_list() -> Self {
+        let mut containers = Self::new();
+        containers.refresh_list();
+        containers
     }
 
-    /// Returns the name of the user.
+    /// Returns the container list.
     ///
     /// ```no_run
-    /// use sysinfo::Users;
+    /// use sysinfo::Containers;
     ///
-    /// let users = Users::new_with_refreshed_list();
-    /// for user in users.list() {
-    ///     println!("{}", user.name());
+    /// let containers = Containers::new_with_refreshed_list();
+    /// for container in containers.list() {
+    ///     eprintln!("{container:?}");
     /// }
     /// ```
-    pub fn name(&self) -> &str {
-        self.inner.name()
+    pub fn list(&self) -> &[Container] {
+        self.inner.list()
     }
 
-    /// Returns the groups of the user.
-    ///
-    /// ⚠️ This is computed every time this method is called.
+    /// Returns the container list.
     ///
     /// ```no_run
-    /// use sysinfo::Users;
+    /// use sysinfo::Containers;
     ///
-    /// let users = Users::new_with_refreshed_list();
-    /// for user in users.list() {
-    ///     println!("{} is in {:?}", user.name(), user.groups());
-    /// }
+    /// let mut containers = Containers::new_with_refreshed_list();
+    /// containers.list_mut().sort_by(|a, b| a.memory().cmp(&b.memory()));
     /// ```
-    pub fn groups(&self) -> Vec<Group> {
-        self.inner.groups()
+    pub fn list_mut(&mut self) -> &mut [Container] {
+        self.inner.list_mut()
     }
-}
-
-/// Type containing group information.
-///
-/// It is returned by [`User::groups`].
-///
-/// ```no_run
-/// use sysinfo::Users;
-///
-/// let mut users = Users::new_with_refreshed_list();
-///
-/// for user in users.list() {
-///     println!(
-///         "user: (ID: {:?}, group ID: {:?}, name: {:?})",
-///         user.id(),
-///         user.group_id(),
-///         user.name(),
-///     );
-///     for group in user.groups() {
-///         println!("group: (ID: {:?}, name: {:?})", group.id(), group.name());
-///     }
-/// }
-/// ```
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
-pub struct Group {
-    pub(crate) id: Gid,
-    pub(crate) name: String,
-}
 
-impl Group {
-    /// Returns the ID of the group.
-    ///
-    /// ⚠️ This information is not set on Windows.
+    /// Returns the container with the given ID.
     ///
     /// ```no_run
-    /// use sysinfo::Users;
-    ///
-    /// let mut users = Users::new_with_refreshed_list();
+    /// use sysinfo::Containers;
     ///
-    /// for user in users.list() {
-    ///     for group in user.groups() {
-    ///         println!("{:?}", group.id());
-    ///     }
+    /// let containers = Containers::new_with_refreshed_list();
+    /// if let Some(container) = containers.get("3f2a9c0d4b7e") {
+    ///     println!("{}%", container.cpu_usage());
     /// }
     /// ```
-    pub fn id(&self) -> &Gid {
-        &self.id
+    pub fn get(&self, id: &str) -> Option<&Container> {
+        self.list().iter().find(|container| container.id() == id)
     }
 
-    /// Returns the name of the group.
+    /// Refreshes the CPU usage, the memory and the processes of the listed containers.
     ///
     /// ```no_run
-    /// use sysinfo::Users;
+    /// use sysinfo::Containers;
     ///
-    /// let mut users = Users::new_with_refreshed_list();
+    /// let mut containers = Containers::new_with_refreshed_list();
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// containers.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        self.inner.refresh();
+    }
+
+    /// The container list will be recomputed, adding the new containers and removing the ones
+    /// which stopped. The containers which were already listed keep the information needed to
+    /// compute their CPU usage.
     ///
-    /// for user in users.list() {
-    ///     for group in user.groups() {
-    ///         println!("{}", group.name());
-    ///     }
-    /// }
+    /// ⚠️ Only available on Linux, where the Docker, containerd (through its CRI plugin) and
+    /// Podman containers are found from the names of their control groups, with both the systemd
+    /// and cgroupfs drivers (except for containerd, which is only found with the systemd
+    /// driver). The processes of a container are the ones of its control group and of its
+    /// children.
+    ///
+    /// ```no_run
+    /// use sysinfo::Containers;
+    ///
+    /// let mut containers = Containers::new();
+    /// containers.refresh_list();
     /// ```
-    pub fn name(&self) -> &str {
-        &self.name
+    pub fn refresh_list(&mut self) {
+        self.inner.refresh_list();
     }
 }
 
-/// Type containing read and written bytes.
+/// A container running on the system.
 ///
-/// It is returned by [`Process::disk_usage`][crate::Process::disk_usage].
+/// It is returned by [`Containers::list`].
 ///
 /// ```no_run
-/// use sysinfo::System;
+/// use sysinfo::Containers;
 ///
-/// let s = System::new_all();
-/// for (pid, process) in s.processes() {
-///     let disk_usage = process.disk_usage();
-///     println!("[{}] read bytes   : new/total => {}/{} B",
-///         pid,
-///         disk_usage.read_bytes,
-///         disk_usage.total_read_bytes,
-///     );
-///     println!("[{}] written bytes: new/total => {}/{} B",
-///         pid,
-///         disk_usage.written_bytes,
-///         disk_usage.total_written_bytes,
-///     );
+/// let containers = Containers::new_with_refreshed_list();
+/// for container in containers.list() {
+///     println!("{}: {:?}", container.id(), container.pids());
 /// }
 /// ```
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd)]
-pub struct DiskUsage {
-    /// Total number of written bytes.
-    pub total_written_bytes: u64,
-    /// Number of written bytes since the last refresh.
-    pub written_bytes: u64,
-    /// Total number of read bytes.
-    pub total_read_bytes: u64,
-    /// Number of read bytes since the last refresh.
-    pub read_bytes: u64,
+pub struct Container {
+    pub(crate) inner: ContainerInner,
 }
 
-/// Enum describing the different status of a process.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum ProcessStatus {
-    /// ## Linux
-    ///
-    /// Idle kernel thread.
-    ///
-    /// ## macOs/FreeBSD
-    ///
-    /// Process being created by fork.
-    ///
-    /// ## Other OS
-    ///
-    /// Not available.
-    Idle,
-    /// Running.
-    Run,
-    /// ## Linux
-    ///
-    /// Sleeping in an interruptible waiting.
-    ///
-    /// ## macOS/FreeBSD
-    ///
-    /// Sleeping on an address.
-    ///
-    /// ## Other OS
-    ///
-    /// Not available.
-    Sleep,
-    /// ## Linux
+impl Container {
+    /// Returns the full ID of the container, which is 64 hexadecimal characters long.
     ///
-    /// Stopped (on a signal) or (before Linux 2.6.33) trace stopped.
-    ///
-    /// ## macOS/FreeBSD
-    ///
-    /// Process debugging or suspension.
-    ///
-    /// ## Other OS
-    ///
-    /// Not available.
-    Stop,
-    /// ## Linux/FreeBSD/macOS
+    /// ```no_run
+    /// use sysinfo::Containers;
     ///
-    /// Zombie process. Terminated but not reaped by its parent.
+    /// let containers = Containers::new_with_refreshed_list();
+    /// for container in containers.list() {
+    ///     println!("{}", container.id());
+    /// }
+    /// ```
+    pub fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    /// Returns the runtime running the container.
     ///
-    /// ## Other OS
+    /// containerd containers are returned as [`ContainerRuntime::Other`], with `containerd` as
+    /// name.
     ///
-    /// Not available.
-    Zombie,
-    /// ## Linux
+    /// ```no_run
+    /// use sysinfo::Containers;
     ///
-    /// Tracing stop (Linux 2.6.33 onward). Stopped by debugger during the tracing.
+    /// let containers = Containers::new_with_refreshed_list();
+    /// for container in containers.list() {
+    ///     println!("{}", container.runtime());
+    /// }
+    /// ```
+    pub fn runtime(&self) -> &ContainerRuntime {
+        self.inner.runtime()
+    }
+
+    /// Returns the CPU usage of the container (in %). It can be more than 100% if the container
+    /// uses several CPUs.
     ///
-    /// ## Other OS
+    /// Like [`Process::cpu_usage`], it is computed from the difference between two refreshes,
+    /// so it is `0.` after the container was first listed.
     ///
-    /// Not available.
-    Tracing,
-    /// ## Linux
+    /// ```no_run
+    /// use sysinfo::Containers;
     ///
-    /// Dead/uninterruptible sleep (usually IO).
+    /// let mut containers = Containers::new_with_refreshed_list();
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// containers.refresh();
+    /// for container in containers.list() {
+    ///     println!("{}%", container.cpu_usage());
+    /// }
+    /// ```
+    pub fn cpu_usage(&self) -> f32 {
+        self.inner.cpu_usage()
+    }
+
+    /// Returns the memory used by the container (in bytes), including the page cache.
     ///
-    /// ## FreeBSD
+    /// ```no_run
+    /// use sysinfo::Containers;
     ///
-    /// A process should never end up in this state.
+    /// let containers = Containers::new_with_refreshed_list();
+    /// for container in containers.list() {
+    ///     println!("{} bytes", container.memory());
+    /// }
+    /// ```
+    pub fn memory(&self) -> u64 {
+        self.inner.memory()
+    }
+
+    /// Returns the memory limit of the container (in bytes), or `None` if it isn't limited.
     ///
-    /// ## Other OS
+    /// ```no_run
+    /// use sysinfo::Containers;
     ///
-    /// Not available.
-    Dead,
-    /// ## Linux
+    /// let containers = Containers::new_with_refreshed_list();
+    /// for container in containers.list() {
+    ///     println!("{:?}", container.memory_limit());
+    /// }
+    /// ```
+    pub fn memory_limit(&self) -> Option<u64> {
+        self.inner.memory_limit()
+    }
+
+    /// Returns the PIDs of the processes of the container, sorted.
     ///
-    /// Wakekill (Linux 2.6.33 to 3.13 only).
+    /// ```no_run
+    /// use sysinfo::Containers;
     ///
-    /// ## Other OS
+    /// let containers = Containers::new_with_refreshed_list();
+    /// for container in containers.list() {
+    ///     println!("{:?}", container.pids());
+    /// }
+    /// ```
+    pub fn pids(&self) -> &[Pid] {
+        self.inner.pids()
+    }
+
+    /// Returns the processes of the container which are in the process list of `system`.
     ///
-    /// Not available.
-    Wakekill,
-    /// ## Linux
+    /// ```no_run
+    /// use sysinfo::{Containers, System};
     ///
-    /// Waking (Linux 2.6.33 to 3.13 only).
+    /// let s = System::new_all();
+    /// let containers = Containers::new_with_refreshed_list();
+    /// for container in containers.list() {
+    ///     for process in container.processes(&s) {
+    ///         println!("{}: {:?}", container.id(), process.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn processes<'a>(&'a self, system: &'a System) -> impl Iterator<Item = &'a Process> + 'a {
+        self.pids()
+            .iter()
+            .filter_map(move |pid| system.process(*pid))
+    }
+}
+
+/// Interacting with the PCI (and PCIe) devices of the system.
+///
+/// ```no_run
+/// use sysinfo::PciDevices;
+///
+/// let devices = PciDevices::new_with_refreshed_list();
+/// for device in devices.list() {
+///     println!(
+///         "{}: {:?} {:?}",
+///         device.class_name(),
+///         device.vendor_name,
+///         device.device_name,
+///     );
+/// }
+/// ```
+pub struct PciDevices {
+    devices: Vec<PciDevice>,
+}
+
+impl Default for PciDevices {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<PciDevices> for Vec<PciDevice> {
+    fn from(devices: PciDevices) -> Self {
+        devices.devices
+    }
+}
+
+impl From<Vec<PciDevice>> for PciDevices {
+    fn from(devices: Vec<PciDevice>) -> Self {
+        Self { devices }
+    }
+}
+
+impl std::ops::Deref for PciDevices {
+    type Target = [PciDevice];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for PciDevices {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a PciDevices {
+    type Item = &'a PciDevice;
+    type IntoIter = std::slice::Iter<'a, PciDevice>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut PciDevices {
+    type Item = &'a mut PciDevice;
+    type IntoIter = std::slice::IterMut<'a, PciDevice>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl PciDevices {
+    /// Creates a new empty [`PciDevices`][crate::PciDevices] type.
     ///
-    /// ## Other OS
+    /// If you want it to be filled directly, take a look at
+    /// [`PciDevices::new_with_refreshed_list`].
     ///
-    /// Not available.
-    Waking,
-    /// ## Linux
+    /// ```no_run
+    /// use sysinfo::PciDevices;
     ///
-    /// Parked (Linux 3.9 to 3.13 only).
+    /// let mut devices = PciDevices::new();
+    /// devices.refresh_list();
+    /// for device in devices.list() {
+    ///     eprintln!("{device:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            devices: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`PciDevices`][crate::PciDevices] type with the device list loaded. It is
+    /// a combination of [`PciDevices::new`] and [`PciDevices::refresh_list`].
     ///
-    /// ## macOS
+    /// ```no_run
+    /// use sysinfo::PciDevices;
     ///
-    /// Halted at a clean point.
+    /// let devices = PciDevices::new_with_refreshed_list();
+    /// for device in devices.list() {
+    ///     eprintln!("{device:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut devices = Self::new();
+        devices.refresh_list();
+        devices
+    }
+
+    /// Returns the device list.
     ///
-    /// ## Other OS
+    /// ```no_run
+    /// use sysinfo::PciDevices;
     ///
-    /// Not available.
-    Parked,
-    /// ## FreeBSD
+    /// let devices = PciDevices::new_with_refreshed_list();
+    /// for device in devices.list() {
+    ///     eprintln!("{device:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[PciDevice] {
+        &self.devices
+    }
+
+    /// Returns the device list.
     ///
-    /// Blocked on a lock.
+    /// ```no_run
+    /// use sysinfo::PciDevices;
     ///
-    /// ## Other OS
+    /// let mut devices = PciDevices::new_with_refreshed_list();
+    /// devices.list_mut().sort_by_key(|device| device.class);
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [PciDevice] {
+        &mut self.devices
+    }
+
+    /// The device list will be emptied then completely recomputed.
     ///
-    /// Not available.
-    LockBlocked,
-    /// ## Linux
+    /// The devices come from `/sys/bus/pci/devices` on Linux, from SetupAPI on Windows and from
+    /// the `IOPCIDevice` services on macOS.
     ///
-    /// Waiting in uninterruptible disk sleep.
+    /// ⚠️ On Linux, the vendor and device names are only available if the `pci.ids` database is
+    /// installed (it is usually provided by the `hwdata` or `pciutils` packages). On Windows,
+    /// the PCI domain is always `0000`. On macOS, the vendor names aren't available and the
+    /// device names are only available for some devices, like the GPUs. PCI devices aren't
+    /// available on FreeBSD and iOS.
     ///
-    /// ## Other OS
+    /// ```no_run
+    /// use sysinfo::PciDevices;
     ///
-    /// Not available.
-    UninterruptibleDiskSleep,
-    /// Unknown.
-    Unknown(u32),
+    /// let mut devices = PciDevices::new();
+    /// devices.refresh_list();
+    /// ```
+    pub fn refresh_list(&mut self) {
+        self.devices.clear();
+        crate::sys::get_pci_devices(&mut self.devices);
+    }
 }
 
-/// Returns the pid for the current process.
+/// A PCI (or PCIe) device.
 ///
-/// `Err` is returned in case the platform isn't supported.
+/// It is returned by [`PciDevices::list`].
 ///
 /// ```no_run
-/// use sysinfo::get_current_pid;
+/// use sysinfo::PciDevices;
 ///
-/// match get_current_pid() {
-///     Ok(pid) => {
-///         println!("current pid: {}", pid);
-///     }
-///     Err(e) => {
-///         eprintln!("failed to get current pid: {}", e);
-///     }
+/// let devices = PciDevices::new_with_refreshed_list();
+/// for device in devices.list() {
+///     println!(
+///         "{:04x}:{:04x} uses {:?}",
+///         device.vendor_id, device.device_id, device.driver,
+///     );
 /// }
 /// ```
-#[allow(clippy::unnecessary_wraps)]
-pub fn get_current_pid() -> Result<Pid, &'static str> {
-    cfg_if::cfg_if! {
-        if #[cfg(feature = "unknown-ci")] {
-            fn inner() -> Result<Pid, &'static str> {
-                Err("Unknown platform (CI)")
-            }
-        } else if #[cfg(any(
-            target_os = "freebsd",
-            target_os = "linux",
-            target_os = "android",
-            target_os = "macos",
-            target_os = "ios",
-        ))] {
-            fn inner() -> Result<Pid, &'static str> {
-                unsafe { Ok(Pid(libc::getpid())) }
-            }
-        } else if #[cfg(windows)] {
-            fn inner() -> Result<Pid, &'static str> {
-                use windows::Win32::System::Threading::GetCurrentProcessId;
+#[derive(Debug, Clone, PartialEq)]
+pub struct PciDevice {
+    /// Address of the device (domain, bus, device and function), like `0000:01:00.0`.
+    pub address: Option<String>,
+    /// Class code of the device: its base class, subclass and programming interface, like
+    /// `0x030000` for a VGA compatible controller.
+    pub class: u32,
+    /// Vendor ID, like `0x8086` for Intel.
+    pub vendor_id: u16,
+    /// Device ID.
+    pub device_id: u16,
+    /// Name of the vendor, like `Intel Corporation`.
+    pub vendor_name: Option<String>,
+    /// Name of the device, like `TigerLake-LP GT2 [Iris Xe Graphics]`.
+    pub device_name: Option<String>,
+    /// Name of the driver in use, like `i915` on Linux or `igfx` on Windows.
+    pub driver: Option<String>,
+    /// Number of lanes of the PCIe link, `None` if the device isn't a PCIe device.
+    pub link_width: Option<u8>,
+    /// Speed of the PCIe link (in GT/s), like `8.` for PCIe 3.0.
+    pub link_speed: Option<f32>,
+}
 
-                unsafe { Ok(Pid(GetCurrentProcessId() as _)) }
-            }
-        } else {
-            fn inner() -> Result<Pid, &'static str> {
-                Err("Unknown platform")
-            }
+impl PciDevice {
+    /// Returns the name of the base class of the device, like `Network controller` or
+    /// `Display controller`.
+    ///
+    /// ```no_run
+    /// use sysinfo::PciDevices;
+    ///
+    /// let devices = PciDevices::new_with_refreshed_list();
+    /// for device in devices.list() {
+    ///     println!("{}", device.class_name());
+    /// }
+    /// ```
+    pub fn class_name(&self) -> &'static str {
+        match self.class >> 16 {
+            0x00 => "Unclassified device",
+            0x01 => "Mass storage controller",
+            0x02 => "Network controller",
+            0x03 => "Display controller",
+            0x04 => "Multimedia controller",
+            0x05 => "Memory controller",
+            0x06 => "Bridge",
+            0x07 => "Communication controller",
+            0x08 => "Generic system peripheral",
+            0x09 => "Input device controller",
+            0x0a => "Docking station",
+            0x0b => "Processor",
+            0x0c => "Serial bus controller",
+            0x0d => "Wireless controller",
+            0x0e => "Intelligent controller",
+            0x0f => "Satellite communications controller",
+            0x10 => "Encryption controller",
+            0x11 => "Signal processing controller",
+            0x12 => "Processing accelerators",
+            0x13 => "Non-Essential Instrumentation",
+            0x40 => "Coprocessor",
+            _ => "Unassigned class",
         }
     }
-    inner()
 }
 
-/// MAC address for network interface.
+/// Querying arbitrary Windows performance counters, for the metrics which aren't provided by
+/// the other types of this crate.
 ///
-/// It is returned by [`NetworkData::mac_address`][crate::NetworkData::mac_address].
-#[derive(PartialEq, Eq, Copy, Clone, Debug)]
-pub struct MacAddr(pub [u8; 6]);
-
-impl MacAddr {
-    /// A `MacAddr` with all bytes set to `0`.
-    pub const UNSPECIFIED: Self = MacAddr([0; 6]);
-
-    /// Checks if this `MacAddr` has all bytes equal to `0`.
-    pub fn is_unspecified(&self) -> bool {
-        self == &MacAddr::UNSPECIFIED
-    }
-}
-
-impl fmt::Display for MacAddr {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let data = &self.0;
-        write!(
-            f,
-            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
-            data[0], data[1], data[2], data[3], data[4], data[5],
-        )
-    }
-}
-
-/// Interacting with components.
+/// The counters are identified by their English path, like
+/// `\Processor(_Total)\% Interrupt Time`, so it works whatever the language of the system is.
+/// Paths with a wildcard instance (like `\Process(*)\IO Read Bytes/sec`) are supported, their
+/// values are returned by [`PerformanceCounters::instances`].
+///
+/// ⚠️ Only available on Windows: [`PerformanceCounters::add`] always fails on the other
+/// systems.
 ///
 /// ```no_run
-/// use sysinfo::Components;
+/// use sysinfo::PerformanceCounters;
 ///
-/// let components = Components::new_with_refreshed_list();
-/// for component in &components {
-///     eprintln!("{component:?}");
-/// }
+/// let mut counters = PerformanceCounters::new();
+/// counters.add(r"\Processor(_Total)\% Interrupt Time");
+/// // Rate counters need two refreshes to have a value.
+/// counters.refresh();
+/// std::thread::sleep(std::time::Duration::from_secs(1));
+/// counters.refresh();
+/// println!("{:?}", counters.get(r"\Processor(_Total)\% Interrupt Time"));
 /// ```
-pub struct Components {
-    pub(crate) inner: ComponentsInner,
+pub struct PerformanceCounters {
+    pub(crate) inner: PerformanceCountersInner,
 }
 
-impl Default for Components {
+impl Default for PerformanceCounters {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl From<Components> for Vec<Component> {
-    fn from(components: Components) -> Self {
-        components.inner.into_vec()
-    }
-}
-
-impl From<Vec<Component>> for Components {
-    fn from(components: Vec<Component>) -> Self {
+impl PerformanceCounters {
+    /// Creates a new [`PerformanceCounters`][crate::PerformanceCounters] type without any
+    /// counter.
+    ///
+    /// ```no_run
+    /// use sysinfo::PerformanceCounters;
+    ///
+    /// let mut counters = PerformanceCounters::new();
+    /// ```
+    pub fn new() -> Self {
         Self {
-            inner: ComponentsInner::from_vec(components),
+            inner: PerformanceCountersInner::new(),
         }
     }
-}
 
-impl std::ops::Deref for Components {
-    type Target = [Component];
+    /// Adds the counter with the given English path. Returns `false` if the counter doesn't
+    /// exist or can't be added. Adding a counter which was already added doesn't do anything.
+    ///
+    /// ```no_run
+    /// use sysinfo::PerformanceCounters;
+    ///
+    /// let mut counters = PerformanceCounters::new();
+    /// if !counters.add(r"\Memory\Pool Nonpaged Bytes") {
+    ///     eprintln!("counter not available");
+    /// }
+    /// ```
+    pub fn add(&mut self, path: &str) -> bool {
+        self.inner.add(path)
+    }
 
-    fn deref(&self) -> &Self::Target {
-        self.list()
+    /// Removes the counter with the given path. Returns `false` if it wasn't added.
+    ///
+    /// ```no_run
+    /// use sysinfo::PerformanceCounters;
+    ///
+    /// let mut counters = PerformanceCounters::new();
+    /// counters.add(r"\Memory\Pool Nonpaged Bytes");
+    /// counters.remove(r"\Memory\Pool Nonpaged Bytes");
+    /// ```
+    pub fn remove(&mut self, path: &str) -> bool {
+        self.inner.remove(path)
     }
-}
 
-impl std::ops::DerefMut for Components {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        self.list_mut()
+    /// Collects the current values of all the added counters.
+    ///
+    /// The rate counters (like the `% ... Time` and `.../sec` ones) are computed from the
+    /// difference between two refreshes, so they only have a value after the second one.
+    ///
+    /// ```no_run
+    /// use sysinfo::PerformanceCounters;
+    ///
+    /// let mut counters = PerformanceCounters::new();
+    /// counters.add(r"\Memory\Pool Nonpaged Bytes");
+    /// counters.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        self.inner.refresh();
     }
-}
 
-impl<'a> IntoIterator for &'a Components {
-    type Item = &'a Component;
-    type IntoIter = std::slice::Iter<'a, Component>;
+    /// Returns the value of the counter with the given path, as collected by the last
+    /// [`PerformanceCounters::refresh`] call. Returns `None` if the counter wasn't added or if
+    /// it doesn't have a valid value.
+    ///
+    /// ```no_run
+    /// use sysinfo::PerformanceCounters;
+    ///
+    /// let mut counters = PerformanceCounters::new();
+    /// counters.add(r"\Memory\Pool Nonpaged Bytes");
+    /// counters.refresh();
+    /// println!("{:?}", counters.get(r"\Memory\Pool Nonpaged Bytes"));
+    /// ```
+    pub fn get(&self, path: &str) -> Option<f64> {
+        self.inner.get(path)
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.list().iter()
+    /// Returns the name and the value of each instance of the counter with the given path, as
+    /// collected by the last [`PerformanceCounters::refresh`] call. It is meant for the paths
+    /// with a wildcard instance.
+    ///
+    /// ```no_run
+    /// use sysinfo::PerformanceCounters;
+    ///
+    /// let path = r"\Process(*)\IO Read Bytes/sec";
+    /// let mut counters = PerformanceCounters::new();
+    /// counters.add(path);
+    /// counters.refresh();
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// counters.refresh();
+    /// for (process, value) in counters.instances(path) {
+    ///     println!("{process}: {value} B/s");
+    /// }
+    /// ```
+    pub fn instances(&self, path: &str) -> Vec<(String, f64)> {
+        self.inner.instances(path)
     }
 }
 
-impl<'a> IntoIterator for &'a mut Components {
-    type Item = &'a mut Component;
-    type IntoIter = std::slice::IterMut<'a, Component>;
+/// Contains all the methods of the [`Cpu`][crate::Cpu] struct.
+///
+/// ```no_run
+/// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+///
+/// let mut s = System::new_with_specifics(
+///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+/// );
+///
+/// // Wait a bit because CPU usage is based on diff.
+/// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+/// // Refresh CPUs again.
+/// s.refresh_cpu();
+///
+/// for cpu in s.cpus() {
+///     println!("{}%", cpu.cpu_usage());
+/// }
+/// ```
+pub struct Cpu {
+    pub(crate) inner: CpuInner,
+}
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.list_mut().iter_mut()
+impl Cpu {
+    /// Returns this CPU's usage.
+    ///
+    /// Note: You'll need to refresh it at least twice (diff between the first and the second is
+    /// how CPU usage is computed) at first if you want to have a non-zero value.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let mut s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    ///
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// // Refresh CPUs again.
+    /// s.refresh_cpu();
+    ///
+    /// for cpu in s.cpus() {
+    ///     println!("{}%", cpu.cpu_usage());
+    /// }
+    /// ```
+    pub fn cpu_usage(&self) -> f32 {
+        self.inner.cpu_usage()
     }
-}
 
-impl Components {
-    /// Creates a new empty [`Components`][crate::Components] type.
+    /// Returns the percentage of time this CPU was waiting for the hypervisor to run it
+    /// (also known as "steal time").
     ///
-    /// If you want it to be filled directly, take a look at
-    /// [`Components::new_with_refreshed_list`].
+    /// A high value means that the virtual machine is starved by its host: other guests are
+    /// using the physical CPUs. Like [`Cpu::cpu_usage`], it is computed from the difference
+    /// between two refreshes.
+    ///
+    /// ⚠️ Only reported on Linux. Always `0.` on other systems.
     ///
     /// ```no_run
-    /// use sysinfo::Components;
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// let mut components = Components::new();
-    /// components.refresh_list();
-    /// for component in &components {
-    ///     eprintln!("{component:?}");
+    /// let mut s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    ///
+    /// // Wait a bit because steal time is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// // Refresh CPUs again.
+    /// s.refresh_cpu();
+    ///
+    /// for cpu in s.cpus() {
+    ///     println!("{}: {}% stolen", cpu.name(), cpu.steal_percent());
     /// }
     /// ```
-    pub fn new() -> Self {
-        Self {
-            inner: ComponentsInner::new(),
-        }
+    pub fn steal_percent(&self) -> f32 {
+        self.inner.steal_percent()
     }
 
-    /// Creates a new [`Components`][crate::Components] type with the user list
-    /// loaded. It is a combination of [`Components::new`] and
-    /// [`Components::refresh_list`].
+    /// Returns this CPU's name.
     ///
     /// ```no_run
-    /// use sysinfo::Components;
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// let mut components = Components::new_with_refreshed_list();
-    /// for component in components.list() {
-    ///     eprintln!("{component:?}");
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}", cpu.name());
     /// }
     /// ```
-    pub fn new_with_refreshed_list() -> Self {
-        let mut components = Self::new();
-        components.refresh_list();
-        components
+    pub fn name(&self) -> &str {
+        self.inner.name()
     }
 
-    /// Returns the components list.
+    /// Returns the CPU's vendor id.
     ///
     /// ```no_run
-    /// use sysinfo::Components;
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// let components = Components::new_with_refreshed_list();
-    /// for component in components.list() {
-    ///     eprintln!("{component:?}");
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}", cpu.vendor_id());
     /// }
     /// ```
-    pub fn list(&self) -> &[Component] {
-        self.inner.list()
+    pub fn vendor_id(&self) -> &str {
+        self.inner.vendor_id()
     }
 
-    /// Returns the components list.
+    /// Returns the CPU's brand.
     ///
     /// ```no_run
-    /// use sysinfo::Components;
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// let mut components = Components::new_with_refreshed_list();
-    /// for component in components.list_mut() {
-    ///     component.refresh();
-    ///     eprintln!("{component:?}");
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}", cpu.brand());
     /// }
     /// ```
-    pub fn list_mut(&mut self) -> &mut [Component] {
-        self.inner.list_mut()
+    pub fn brand(&self) -> &str {
+        self.inner.brand()
     }
 
-    /// Refreshes the listed components' information.
+    /// Returns the CPU's family.
     ///
-    /// ⚠️ If a component is added or removed, this method won't take it into account. Use
-    /// [`Components::refresh_list`] instead.
+    /// On x86, it's the "display family" computed from `cpuid` (like `6` for most Intel CPUs
+    /// or `25` for AMD Zen 3 and 4). On ARM, it's the architecture version (like `8`).
     ///
-    /// ⚠️ If you didn't call [`Components::refresh_list`] beforehand, this method will do
-    /// nothing as the component list will be empty.
+    /// ⚠️ Not available on Apple Silicon and on Windows on ARM.
     ///
     /// ```no_run
-    /// use sysinfo::Components;
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// let mut components = Components::new_with_refreshed_list();
-    /// // We wait some time...?
-    /// components.refresh();
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{:?}", cpu.family());
+    /// }
     /// ```
-    pub fn refresh(&mut self) {
-        for component in self.list_mut() {
-            component.refresh();
-        }
+    pub fn family(&self) -> Option<u32> {
+        self.inner.family()
     }
 
-    /// The component list will be emptied then completely recomputed.
+    /// Returns the CPU's model number.
+    ///
+    /// On x86, it's the "display model" computed from `cpuid`. On ARM, it's the part number
+    /// (like `0xd0b` for a Cortex-A76).
+    ///
+    /// ⚠️ Not available on Apple Silicon and on Windows on ARM.
     ///
     /// ```no_run
-    /// use sysinfo::Components;
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// let mut components = Components::new();
-    /// components.refresh_list();
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{:?}", cpu.model());
+    /// }
     /// ```
-    pub fn refresh_list(&mut self) {
-        self.inner.refresh_list()
+    pub fn model(&self) -> Option<u32> {
+        self.inner.model()
     }
-}
-
-/// Getting a component temperature information.
-///
-/// ```no_run
-/// use sysinfo::Components;
-///
-/// let components = Components::new_with_refreshed_list();
-/// for component in &components {
-///     println!("{} {}°C", component.label(), component.temperature());
-/// }
-/// ```
-pub struct Component {
-    pub(crate) inner: ComponentInner,
-}
 
-impl Component {
-    /// Returns the temperature of the component (in celsius degree).
-    ///
-    /// ## Linux
+    /// Returns the CPU's stepping (the revision number on ARM).
     ///
-    /// Returns `f32::NAN` if it failed to retrieve it.
+    /// ⚠️ Not available on Apple Silicon and on Windows on ARM.
     ///
     /// ```no_run
-    /// use sysinfo::Components;
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// let components = Components::new_with_refreshed_list();
-    /// for component in &components {
-    ///     println!("{}°C", component.temperature());
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{:?}", cpu.stepping());
     /// }
     /// ```
-    pub fn temperature(&self) -> f32 {
-        self.inner.temperature()
+    pub fn stepping(&self) -> Option<u32> {
+        self.inner.stepping()
     }
 
-    /// Returns the maximum temperature of the component (in celsius degree).
+    /// Returns the revision of the microcode loaded in the CPU.
     ///
-    /// Note: if `temperature` is higher than the current `max`,
-    /// `max` value will be updated on refresh.
+    /// ⚠️ Only available on x86 CPUs, and not on FreeBSD.
     ///
-    /// ## Linux
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// May be computed by `sysinfo` from kernel.
-    /// Returns `f32::NAN` if it failed to retrieve it.
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     if let Some(revision) = cpu.microcode_revision() {
+    ///         println!("{}: {revision:#x}", cpu.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn microcode_revision(&self) -> Option<u64> {
+        self.inner.microcode_revision()
+    }
+
+    /// Returns the CPU's current frequency (in MHz).
+    ///
+    /// It is updated when calling [`System::refresh_cpu_frequency`] (or any refresh using
+    /// [`CpuRefreshKind::frequency`]).
     ///
     /// ```no_run
-    /// use sysinfo::Components;
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// let components = Components::new_with_refreshed_list();
-    /// for component in &components {
-    ///     println!("{}°C", component.max());
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}", cpu.frequency());
     /// }
     /// ```
-    pub fn max(&self) -> f32 {
-        self.inner.max()
+    pub fn frequency(&self) -> u64 {
+        self.inner.frequency()
     }
 
-    /// Returns the highest temperature before the component halts (in celsius degree).
+    /// Returns the name of the CPU frequency governor (like `performance` or `powersave`)
+    /// currently used by this CPU.
     ///
-    /// ## Linux
+    /// It is updated when calling [`System::refresh_cpu_frequency`] (or any refresh using
+    /// [`CpuRefreshKind::frequency`]).
     ///
-    /// Critical threshold defined by chip or kernel.
+    /// ⚠️ Only supported on Linux (with the `cpufreq` driver). Always `None` on other systems.
     ///
     /// ```no_run
-    /// use sysinfo::Components;
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// let components = Components::new_with_refreshed_list();
-    /// for component in &components {
-    ///     println!("{:?}°C", component.critical());
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}: {:?}", cpu.name(), cpu.governor());
     /// }
     /// ```
-    pub fn critical(&self) -> Option<f32> {
-        self.inner.critical()
+    pub fn governor(&self) -> Option<&str> {
+        self.inner.governor()
     }
 
-    /// Returns the label of the component.
-    ///
-    /// ## Linux
-    ///
-    /// Since components information is retrieved thanks to `hwmon`,
-    /// the labels are generated as follows.
-    /// Note: it may change and it was inspired by `sensors` own formatting.
+    /// Returns the CPU frequency governors which can be used by this CPU.
     ///
-    /// | name | label | device_model | id_sensor | Computed label by `sysinfo` |
-    /// |---------|--------|------------|----------|----------------------|
-    /// | ✓    | ✓    | ✓  | ✓ | `"{name} {label} {device_model} temp{id}"` |
-    /// | ✓    | ✓    | ✗  | ✓ | `"{name} {label} {id}"` |
-    /// | ✓    | ✗    | ✓  | ✓ | `"{name} {device_model}"` |
-    /// | ✓    | ✗    | ✗  | ✓ | `"{name} temp{id}"` |
+    /// ⚠️ Only supported on Linux (with the `cpufreq` driver). Always empty on other systems.
     ///
     /// ```no_run
-    /// use sysinfo::Components;
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// let components = Components::new_with_refreshed_list();
-    /// for component in &components {
-    ///     println!("{}", component.label());
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}: {:?}", cpu.name(), cpu.available_governors());
     /// }
     /// ```
-    pub fn label(&self) -> &str {
-        self.inner.label()
+    pub fn available_governors(&self) -> &[String] {
+        self.inner.available_governors()
     }
 
-    /// Refreshes component.
+    /// Returns the minimum frequency (in MHz) the governor is allowed to use for this CPU.
+    ///
+    /// It is updated when calling [`System::refresh_cpu_frequency`] (or any refresh using
+    /// [`CpuRefreshKind::frequency`]).
+    ///
+    /// ⚠️ Only supported on Linux (with the `cpufreq` driver). Always `None` on other systems.
     ///
     /// ```no_run
-    /// use sysinfo::Components;
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// let mut components = Components::new_with_refreshed_list();
-    /// for component in components.iter_mut() {
-    ///     component.refresh();
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}: {:?}", cpu.name(), cpu.scaling_min_frequency());
     /// }
     /// ```
-    pub fn refresh(&mut self) {
-        self.inner.refresh()
+    pub fn scaling_min_frequency(&self) -> Option<u64> {
+        self.inner.scaling_min_frequency()
     }
-}
-
-/// Contains all the methods of the [`Cpu`][crate::Cpu] struct.
-///
-/// ```no_run
-/// use sysinfo::{System, RefreshKind, CpuRefreshKind};
-///
-/// let mut s = System::new_with_specifics(
-///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
-/// );
-///
-/// // Wait a bit because CPU usage is based on diff.
-/// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-/// // Refresh CPUs again.
-/// s.refresh_cpu();
-///
-/// for cpu in s.cpus() {
-///     println!("{}%", cpu.cpu_usage());
-/// }
-/// ```
-pub struct Cpu {
-    pub(crate) inner: CpuInner,
-}
 
-impl Cpu {
-    /// Returns this CPU's usage.
+    /// Returns the maximum frequency (in MHz) the governor is allowed to use for this CPU.
     ///
-    /// Note: You'll need to refresh it at least twice (diff between the first and the second is
-    /// how CPU usage is computed) at first if you want to have a non-zero value.
+    /// It is updated when calling [`System::refresh_cpu_frequency`] (or any refresh using
+    /// [`CpuRefreshKind::frequency`]).
+    ///
+    /// ⚠️ Only supported on Linux (with the `cpufreq` driver). Always `None` on other systems.
     ///
     /// ```no_run
     /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// let mut s = System::new_with_specifics(
+    /// let s = System::new_with_specifics(
     ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
     /// );
-    ///
-    /// // Wait a bit because CPU usage is based on diff.
-    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-    /// // Refresh CPUs again.
-    /// s.refresh_cpu();
-    ///
     /// for cpu in s.cpus() {
-    ///     println!("{}%", cpu.cpu_usage());
+    ///     println!("{}: {:?}", cpu.name(), cpu.scaling_max_frequency());
     /// }
     /// ```
-    pub fn cpu_usage(&self) -> f32 {
-        self.inner.cpu_usage()
+    pub fn scaling_max_frequency(&self) -> Option<u64> {
+        self.inner.scaling_max_frequency()
     }
 
-    /// Returns this CPU's name.
+    /// Sets the CPU frequency governor used by this CPU. Returns `true` if it succeeded.
+    ///
+    /// The new governor is only visible in [`Cpu::governor`] after the next frequency refresh.
+    ///
+    /// ⚠️ It requires root privileges and is only available with the `cpufreq-write` feature.
+    /// Only supported on Linux, always returns `false` on other systems.
     ///
     /// ```no_run
     /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
@@ -3377,14 +11788,30 @@ impl Cpu {
     ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
     /// );
     /// for cpu in s.cpus() {
-    ///     println!("{}", cpu.name());
+    ///     if cpu.available_governors().iter().any(|g| g == "performance") {
+    ///         cpu.set_governor("performance");
+    ///     }
     /// }
     /// ```
-    pub fn name(&self) -> &str {
-        self.inner.name()
+    #[cfg(feature = "cpufreq-write")]
+    pub fn set_governor(&self, governor: &str) -> bool {
+        self.inner.set_governor(governor)
     }
 
-    /// Returns the CPU's vendor id.
+    /// Returns the CPU's temperature (in celsius degrees).
+    ///
+    /// Returns `None` if no temperature sensor could be associated with this CPU. It is
+    /// updated when calling [`System::refresh_cpu_temperature`] (or any refresh using
+    /// [`CpuRefreshKind::temperature`]).
+    ///
+    /// ⚠️ Sensors usually report one temperature per physical core, so logical CPUs sharing
+    /// the same core have the same value. If only a package sensor is available (like with AMD
+    /// `k10temp`), all CPUs of the package have the same value. On Apple Silicon, all cores of a
+    /// same cluster have the same value if there isn't one sensor per core.
+    ///
+    /// ⚠️ Not supported on Windows, where it always returns `None`: the per-core sensors can only
+    /// be read with a kernel driver, and the ACPI thermal zones (available with
+    /// [`Components`]) don't tell which of them is the CPU.
     ///
     /// ```no_run
     /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
@@ -3393,30 +11820,47 @@ impl Cpu {
     ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
     /// );
     /// for cpu in s.cpus() {
-    ///     println!("{}", cpu.vendor_id());
+    ///     if let Some(temperature) = cpu.temperature() {
+    ///         println!("{}: {temperature}°C", cpu.name());
+    ///     }
     /// }
     /// ```
-    pub fn vendor_id(&self) -> &str {
-        self.inner.vendor_id()
+    pub fn temperature(&self) -> Option<f32> {
+        self.inner.temperature()
     }
 
-    /// Returns the CPU's brand.
+    /// Returns the residency of the CPU in each of its idle states (C-states), from the
+    /// shallowest to the deepest one.
+    ///
+    /// It is updated when calling [`System::refresh_cpu_idle_states`] (or any refresh using
+    /// [`CpuRefreshKind::idle_states`]).
+    ///
+    /// ⚠️ Only supported on Linux (through `cpuidle`) and Windows (where only the `C1`, `C2`
+    /// and `C3` states are reported). Always empty for the global CPU.
     ///
     /// ```no_run
     /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
-    /// let s = System::new_with_specifics(
-    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// let mut s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::new().with_idle_states()),
     /// );
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_cpu_idle_states();
     /// for cpu in s.cpus() {
-    ///     println!("{}", cpu.brand());
+    ///     println!("{}: {:?}", cpu.name(), cpu.idle_states());
     /// }
     /// ```
-    pub fn brand(&self) -> &str {
-        self.inner.brand()
+    pub fn idle_states(&self) -> &[CpuIdleState] {
+        self.inner.idle_states()
     }
 
-    /// Returns the CPU's frequency.
+    /// Returns the time spent by the CPU in each state since the system boot.
+    ///
+    /// Unlike [`Cpu::cpu_usage`], these are raw counters: to compute a usage over an interval,
+    /// compute the difference between two refreshes.
+    ///
+    /// It is updated when calling [`System::refresh_cpu_usage`] (or any refresh using
+    /// [`CpuRefreshKind::cpu_usage`]).
     ///
     /// ```no_run
     /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
@@ -3425,17 +11869,363 @@ impl Cpu {
     ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
     /// );
     /// for cpu in s.cpus() {
-    ///     println!("{}", cpu.frequency());
+    ///     println!("{}: {:?}", cpu.name(), cpu.times());
     /// }
     /// ```
+    pub fn times(&self) -> CpuTimes {
+        self.inner.times()
+    }
+}
+
+/// Snapshot of the system's information, returned by
+/// [`System::to_snapshot`][crate::System::to_snapshot].
+///
+/// It holds the information retrieved by the last refreshes of the [`System`] and provides the
+/// same getters, so it can be used to analyze a captured state later on. With the `serde`
+/// feature, it can be serialized (to JSON for example) and deserialized again.
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// let snapshot = s.to_snapshot();
+/// println!("{} processes, {} bytes of used memory", snapshot.processes().len(), snapshot.used_memory());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SystemSnapshot {
+    pub(crate) version: u32,
+    pub(crate) timestamp: u64,
+    pub(crate) name: Option<String>,
+    pub(crate) kernel_version: Option<String>,
+    pub(crate) os_version: Option<String>,
+    pub(crate) long_os_version: Option<String>,
+    pub(crate) host_name: Option<String>,
+    pub(crate) uptime: u64,
+    pub(crate) boot_time: u64,
+    pub(crate) load_average: LoadAvg,
+    pub(crate) total_memory: u64,
+    pub(crate) free_memory: u64,
+    pub(crate) available_memory: u64,
+    pub(crate) used_memory: u64,
+    pub(crate) total_swap: u64,
+    pub(crate) free_swap: u64,
+    pub(crate) used_swap: u64,
+    pub(crate) physical_core_count: Option<usize>,
+    pub(crate) global_cpu_info: CpuSnapshot,
+    pub(crate) cpus: Vec<CpuSnapshot>,
+    pub(crate) processes: HashMap<Pid, ProcessSnapshot>,
+}
+
+impl SystemSnapshot {
+    /// Version of the format of the snapshots created by this version of `sysinfo`.
+    ///
+    /// Snapshots with a greater version can't be deserialized.
+    pub const VERSION: u32 = 1;
+
+    /// Returns the version of the format of the snapshot.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns when the snapshot was taken, in seconds since epoch.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Returns the processes, like [`System::processes`].
+    pub fn processes(&self) -> &HashMap<Pid, ProcessSnapshot> {
+        &self.processes
+    }
+
+    /// Returns the process corresponding to the given `pid` or `None` if it wasn't in the
+    /// snapshot, like [`System::process`].
+    pub fn process(&self, pid: Pid) -> Option<&ProcessSnapshot> {
+        self.processes.get(&pid)
+    }
+
+    /// Returns the information of the CPUs combined, like [`System::global_cpu_info`].
+    pub fn global_cpu_info(&self) -> &CpuSnapshot {
+        &self.global_cpu_info
+    }
+
+    /// Returns the CPUs, like [`System::cpus`].
+    pub fn cpus(&self) -> &[CpuSnapshot] {
+        &self.cpus
+    }
+
+    /// Returns the number of physical cores on the CPU or `None` if it couldn't be retrieved,
+    /// like [`System::physical_core_count`].
+    pub fn physical_core_count(&self) -> Option<usize> {
+        self.physical_core_count
+    }
+
+    /// Returns the RAM size in bytes, like [`System::total_memory`].
+    pub fn total_memory(&self) -> u64 {
+        self.total_memory
+    }
+
+    /// Returns the amount of free RAM in bytes, like [`System::free_memory`].
+    pub fn free_memory(&self) -> u64 {
+        self.free_memory
+    }
+
+    /// Returns the amount of available RAM in bytes, like [`System::available_memory`].
+    pub fn available_memory(&self) -> u64 {
+        self.available_memory
+    }
+
+    /// Returns the amount of used RAM in bytes, like [`System::used_memory`].
+    pub fn used_memory(&self) -> u64 {
+        self.used_memory
+    }
+
+    /// Returns the SWAP size in bytes, like [`System::total_swap`].
+    pub fn total_swap(&self) -> u64 {
+        self.total_swap
+    }
+
+    /// Returns the amount of free SWAP in bytes, like [`System::free_swap`].
+    pub fn free_swap(&self) -> u64 {
+        self.free_swap
+    }
+
+    /// Returns the amount of used SWAP in bytes, like [`System::used_swap`].
+    pub fn used_swap(&self) -> u64 {
+        self.used_swap
+    }
+
+    /// Returns the system uptime (in seconds), like [`System::uptime`].
+    pub fn uptime(&self) -> u64 {
+        self.uptime
+    }
+
+    /// Returns the time (in seconds) when the system booted since UNIX epoch, like
+    /// [`System::boot_time`].
+    pub fn boot_time(&self) -> u64 {
+        self.boot_time
+    }
+
+    /// Returns the system load average value, like [`System::load_average`].
+    pub fn load_average(&self) -> LoadAvg {
+        self.load_average.clone()
+    }
+
+    /// Returns the system name, like [`System::name`].
+    pub fn name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+    /// Returns the system's kernel version, like [`System::kernel_version`].
+    pub fn kernel_version(&self) -> Option<String> {
+        self.kernel_version.clone()
+    }
+
+    /// Returns the system version, like [`System::os_version`].
+    pub fn os_version(&self) -> Option<String> {
+        self.os_version.clone()
+    }
+
+    /// Returns the system long os version, like [`System::long_os_version`].
+    pub fn long_os_version(&self) -> Option<String> {
+        self.long_os_version.clone()
+    }
+
+    /// Returns the system hostname, like [`System::host_name`].
+    pub fn host_name(&self) -> Option<String> {
+        self.host_name.clone()
+    }
+}
+
+/// Snapshot of a process, part of a [`SystemSnapshot`].
+#[derive(Debug, Clone)]
+pub struct ProcessSnapshot {
+    pub(crate) name: String,
+    pub(crate) cmd: Vec<String>,
+    pub(crate) exe: PathBuf,
+    pub(crate) pid: Pid,
+    pub(crate) environ: Vec<String>,
+    pub(crate) cwd: PathBuf,
+    pub(crate) root: PathBuf,
+    pub(crate) memory: u64,
+    pub(crate) virtual_memory: u64,
+    pub(crate) parent: Option<Pid>,
+    pub(crate) status: ProcessStatus,
+    pub(crate) start_time: u64,
+    pub(crate) run_time: u64,
+    pub(crate) cpu_usage: f32,
+    pub(crate) disk_usage: DiskUsage,
+    pub(crate) user_id: Option<Uid>,
+    pub(crate) group_id: Option<Gid>,
+    pub(crate) session_id: Option<Pid>,
+}
+
+impl ProcessSnapshot {
+    fn new(process: &Process) -> Self {
+        Self {
+            name: process.name().to_owned(),
+            cmd: process.cmd().to_vec(),
+            exe: process.exe().to_path_buf(),
+            pid: process.pid(),
+            environ: process.environ().to_vec(),
+            cwd: process.cwd().to_path_buf(),
+            root: process.root().to_path_buf(),
+            memory: process.memory(),
+            virtual_memory: process.virtual_memory(),
+            parent: process.parent(),
+            status: process.status(),
+            start_time: process.start_time(),
+            run_time: process.run_time(),
+            cpu_usage: process.cpu_usage(),
+            disk_usage: process.disk_usage(),
+            user_id: process.user_id().cloned(),
+            group_id: process.group_id(),
+            session_id: process.session_id(),
+        }
+    }
+
+    /// Returns the name of the process, like [`Process::name`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the command line, like [`Process::cmd`].
+    pub fn cmd(&self) -> &[String] {
+        &self.cmd
+    }
+
+    /// Returns the path to the process, like [`Process::exe`].
+    pub fn exe(&self) -> &Path {
+        &self.exe
+    }
+
+    /// Returns the PID of the process, like [`Process::pid`].
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    /// Returns the environment variables of the process, like [`Process::environ`].
+    pub fn environ(&self) -> &[String] {
+        &self.environ
+    }
+
+    /// Returns the current working directory, like [`Process::cwd`].
+    pub fn cwd(&self) -> &Path {
+        &self.cwd
+    }
+
+    /// Returns the path of the root directory, like [`Process::root`].
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the memory usage (in bytes), like [`Process::memory`].
+    pub fn memory(&self) -> u64 {
+        self.memory
+    }
+
+    /// Returns the virtual memory usage (in bytes), like [`Process::virtual_memory`].
+    pub fn virtual_memory(&self) -> u64 {
+        self.virtual_memory
+    }
+
+    /// Returns the parent PID, like [`Process::parent`].
+    pub fn parent(&self) -> Option<Pid> {
+        self.parent
+    }
+
+    /// Returns the status of the process, like [`Process::status`].
+    pub fn status(&self) -> ProcessStatus {
+        self.status
+    }
+
+    /// Returns the time where the process was started (in seconds) from epoch, like
+    /// [`Process::start_time`].
+    pub fn start_time(&self) -> u64 {
+        self.start_time
+    }
+
+    /// Returns for how much time the process has been running (in seconds), like
+    /// [`Process::run_time`].
+    pub fn run_time(&self) -> u64 {
+        self.run_time
+    }
+
+    /// Returns the total CPU usage (in %), like [`Process::cpu_usage`].
+    pub fn cpu_usage(&self) -> f32 {
+        self.cpu_usage
+    }
+
+    /// Returns the number of bytes read and written to disk, like [`Process::disk_usage`].
+    pub fn disk_usage(&self) -> DiskUsage {
+        self.disk_usage
+    }
+
+    /// Returns the ID of the owner user of this process, like [`Process::user_id`].
+    pub fn user_id(&self) -> Option<&Uid> {
+        self.user_id.as_ref()
+    }
+
+    /// Returns the process group ID of the process, like [`Process::group_id`].
+    pub fn group_id(&self) -> Option<Gid> {
+        self.group_id
+    }
+
+    /// Returns the session ID of the process, like [`Process::session_id`].
+    pub fn session_id(&self) -> Option<Pid> {
+        self.session_id
+    }
+}
+
+/// Snapshot of a CPU, part of a [`SystemSnapshot`].
+#[derive(Debug, Clone)]
+pub struct CpuSnapshot {
+    pub(crate) cpu_usage: f32,
+    pub(crate) name: String,
+    pub(crate) vendor_id: String,
+    pub(crate) brand: String,
+    pub(crate) frequency: u64,
+}
+
+impl CpuSnapshot {
+    fn new(cpu: &Cpu) -> Self {
+        Self {
+            cpu_usage: cpu.cpu_usage(),
+            name: cpu.name().to_owned(),
+            vendor_id: cpu.vendor_id().to_owned(),
+            brand: cpu.brand().to_owned(),
+            frequency: cpu.frequency(),
+        }
+    }
+
+    /// Returns this CPU's usage, like [`Cpu::cpu_usage`].
+    pub fn cpu_usage(&self) -> f32 {
+        self.cpu_usage
+    }
+
+    /// Returns this CPU's name, like [`Cpu::name`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the CPU's vendor id, like [`Cpu::vendor_id`].
+    pub fn vendor_id(&self) -> &str {
+        &self.vendor_id
+    }
+
+    /// Returns the CPU's brand, like [`Cpu::brand`].
+    pub fn brand(&self) -> &str {
+        &self.brand
+    }
+
+    /// Returns the CPU's frequency, like [`Cpu::frequency`].
     pub fn frequency(&self) -> u64 {
-        self.inner.frequency()
+        self.frequency
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{MacAddr, ProcessStatus};
+    use super::{threshold_crossing, MacAddr, ProcessStatus};
 
     // This test only exists to ensure that the `Display` and `Debug` traits are implemented on the
     // `ProcessStatus` enum on all targets.
@@ -3454,6 +12244,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_threshold_crossing() {
+        assert_eq!(threshold_crossing(false, 80., 90., 5.), None);
+        assert_eq!(threshold_crossing(false, 91., 90., 5.), Some(true));
+        assert_eq!(threshold_crossing(true, 95., 90., 5.), None);
+        // Still within the hysteresis.
+        assert_eq!(threshold_crossing(true, 87., 90., 5.), None);
+        assert_eq!(threshold_crossing(true, 84., 90., 5.), Some(false));
+        assert_eq!(threshold_crossing(false, f32::NAN, 90., 5.), None);
+        assert_eq!(threshold_crossing(true, f32::NAN, 90., 5.), None);
+    }
+
+    #[test]
+    fn check_refresh_unknown_component() {
+        let mut components = crate::Components::new();
+        assert!(!components.refresh_component("not a sensor"));
+    }
+
     #[test]
     fn check_mac_address_is_unspecified_true() {
         assert!(MacAddr::UNSPECIFIED.is_unspecified());
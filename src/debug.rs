@@ -1,7 +1,8 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::{
-    Component, Components, Cpu, Disk, Disks, NetworkData, Networks, Process, System, User, Users,
+    Component, Components, Container, Containers, Cpu, Disk, Disks, Gpu, Gpus, NetworkData,
+    Networks, Process, System, SystemBuilder, User, Users,
 };
 
 use std::fmt;
@@ -11,7 +12,10 @@ impl fmt::Debug for Cpu {
         f.debug_struct("Cpu")
             .field("name", &self.name())
             .field("CPU usage", &self.cpu_usage())
+            .field("steal", &self.steal_percent())
             .field("frequency", &self.frequency())
+            .field("governor", &self.governor())
+            .field("temperature", &self.temperature())
             .field("vendor ID", &self.vendor_id())
             .field("brand", &self.brand())
             .finish()
@@ -33,6 +37,19 @@ impl fmt::Debug for System {
     }
 }
 
+impl fmt::Debug for SystemBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SystemBuilder")
+            .field("refreshes", &self.refreshes)
+            .field("process filter", &self.process_filter.is_some())
+            .field("string encoding policy", &self.string_encoding_policy)
+            .field("CPU usage smoothing", &self.cpu_usage_smoothing)
+            .field("use cgroup limits", &self.use_cgroup_limits)
+            .field("keep open files", &self.keep_open_files)
+            .finish()
+    }
+}
+
 impl fmt::Debug for Disk {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -152,6 +169,59 @@ impl fmt::Debug for Disks {
     }
 }
 
+impl fmt::Debug for Gpus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Gpus {{ {} }}",
+            self.iter()
+                .map(|x| format!("{x:?}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+impl fmt::Debug for Gpu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Gpu")
+            .field("name", &self.name())
+            .field("driver_version", &self.driver_version())
+            .field("usage", &self.usage())
+            .field("total_memory", &self.total_memory())
+            .field("used_memory", &self.used_memory())
+            .field("temperature", &self.temperature())
+            .field("power", &self.power())
+            .finish()
+    }
+}
+
+impl fmt::Debug for Containers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Containers {{ {} }}",
+            self.iter()
+                .map(|x| format!("{x:?}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+impl fmt::Debug for Container {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Container")
+            .field("id", &self.id())
+            .field("runtime", &self.runtime())
+            .field("cpu_usage", &self.cpu_usage())
+            .field("memory", &self.memory())
+            .field("memory_limit", &self.memory_limit())
+            .field("pids", &self.pids())
+            .finish()
+    }
+}
+
 impl fmt::Debug for Users {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
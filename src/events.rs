@@ -0,0 +1,509 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! A single subscription API for the events of the system: processes starting and stopping,
+//! disks being added or removed, network interfaces changing and components crossing their
+//! temperature threshold.
+//!
+//! Each kind of event is watched by its own thread, which uses the native notifications of the
+//! system when there are some (see [`DiskEvents`] and [`NetworkEvents`]) and polls otherwise.
+//! The events are delivered over an [`mpsc`] channel (with [`channel`]) or to a callback (with
+//! [`subscribe`]). With the `async` feature, they can also be consumed as a stream (with
+//! `stream`).
+//!
+//! ```no_run
+//! use sysinfo::events::{self, Event, EventKind};
+//!
+//! let (_subscription, receiver) = events::channel(EventKind::new().with_processes().with_disks());
+//! for event in receiver {
+//!     match event {
+//!         Event::ProcessStarted { pid, name } => println!("{name} ({pid}) started"),
+//!         Event::ProcessStopped { pid, name } => println!("{name} ({pid}) stopped"),
+//!         event => println!("{event:?}"),
+//!     }
+//! }
+//! ```
+
+use crate::{
+    ComponentEvent, ComponentEvents, ComponentThreshold, DiskEvent, DiskEvents, NetworkEvent,
+    NetworkEvents, Pid, ProcessRefreshKind, System,
+};
+
+use std::collections::HashMap;
+#[cfg(feature = "async")]
+use std::collections::VecDeque;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+#[cfg(feature = "async")]
+use std::sync::Mutex;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll, Waker};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+// How long the threads waiting for native notifications block before checking if the
+// subscription was dropped.
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Event delivered by a [`Subscription`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// A process started.
+    ProcessStarted {
+        /// PID of the process.
+        pid: Pid,
+        /// Name of the process.
+        name: String,
+    },
+    /// A process stopped.
+    ProcessStopped {
+        /// PID of the process.
+        pid: Pid,
+        /// Name of the process.
+        name: String,
+    },
+    /// A disk was added or removed.
+    Disk(DiskEvent),
+    /// A network interface appeared, disappeared or had its IP addresses changed.
+    Network(NetworkEvent),
+    /// The temperature of a component crossed its threshold.
+    Component(ComponentEvent),
+}
+
+/// Used to determine which events are watched.
+///
+/// ```
+/// use sysinfo::events::EventKind;
+/// use sysinfo::ComponentThreshold;
+///
+/// let kind = EventKind::new()
+///     .with_processes()
+///     .with_components(ComponentThreshold::Critical);
+/// assert!(kind.processes());
+/// assert!(!kind.disks());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventKind {
+    processes: bool,
+    disks: bool,
+    networks: bool,
+    components: Option<ComponentThreshold>,
+    interval: Duration,
+}
+
+impl Default for EventKind {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventKind {
+    /// Creates a new `EventKind` watching nothing, with a polling interval of one second.
+    ///
+    /// ```
+    /// use sysinfo::events::EventKind;
+    ///
+    /// let kind = EventKind::new();
+    /// assert!(!kind.processes());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            processes: false,
+            disks: false,
+            networks: false,
+            components: None,
+            interval: Duration::from_secs(1),
+        }
+    }
+
+    /// Creates a new `EventKind` watching everything, with the components crossing their
+    /// critical temperature.
+    ///
+    /// ```
+    /// use sysinfo::events::EventKind;
+    ///
+    /// let kind = EventKind::everything();
+    /// assert!(kind.processes() && kind.disks() && kind.networks());
+    /// ```
+    pub fn everything() -> Self {
+        Self::new()
+            .with_processes()
+            .with_disks()
+            .with_networks()
+            .with_components(ComponentThreshold::Critical)
+    }
+
+    /// Returns `true` if processes starting and stopping are watched.
+    pub fn processes(&self) -> bool {
+        self.processes
+    }
+
+    /// Watches processes starting and stopping.
+    ///
+    /// The processes are polled every [`interval`](EventKind::interval), so the ones living
+    /// less than that are missed.
+    #[must_use]
+    pub fn with_processes(mut self) -> Self {
+        self.processes = true;
+        self
+    }
+
+    /// Stops watching processes starting and stopping.
+    #[must_use]
+    pub fn without_processes(mut self) -> Self {
+        self.processes = false;
+        self
+    }
+
+    /// Returns `true` if disks being added or removed are watched.
+    pub fn disks(&self) -> bool {
+        self.disks
+    }
+
+    /// Watches disks being added or removed, with [`DiskEvents`].
+    #[must_use]
+    pub fn with_disks(mut self) -> Self {
+        self.disks = true;
+        self
+    }
+
+    /// Stops watching disks being added or removed.
+    #[must_use]
+    pub fn without_disks(mut self) -> Self {
+        self.disks = false;
+        self
+    }
+
+    /// Returns `true` if network interfaces changes are watched.
+    pub fn networks(&self) -> bool {
+        self.networks
+    }
+
+    /// Watches network interfaces changes, with [`NetworkEvents`].
+    #[must_use]
+    pub fn with_networks(mut self) -> Self {
+        self.networks = true;
+        self
+    }
+
+    /// Stops watching network interfaces changes.
+    #[must_use]
+    pub fn without_networks(mut self) -> Self {
+        self.networks = false;
+        self
+    }
+
+    /// Returns the threshold of the components if they are watched.
+    pub fn components(&self) -> Option<ComponentThreshold> {
+        self.components
+    }
+
+    /// Watches the components crossing `threshold`, with [`ComponentEvents`]. They are checked
+    /// every [`interval`](EventKind::interval).
+    #[must_use]
+    pub fn with_components(mut self, threshold: ComponentThreshold) -> Self {
+        self.components = Some(threshold);
+        self
+    }
+
+    /// Stops watching the components.
+    #[must_use]
+    pub fn without_components(mut self) -> Self {
+        self.components = None;
+        self
+    }
+
+    /// Returns how often the processes and the components are polled.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Sets how often the processes and the components are polled.
+    #[must_use]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+/// Keeps the watching threads running. They are stopped when it is dropped.
+///
+/// It is returned by [`channel`] and [`subscribe`].
+pub struct Subscription {
+    stop: Arc<AtomicBool>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Starts watching the events of `kind` and returns the receiving end of the channel they are
+/// sent to. The channel is closed when the [`Subscription`] is dropped.
+///
+/// ```no_run
+/// use sysinfo::events::{self, EventKind};
+/// use std::time::Duration;
+///
+/// let (subscription, receiver) = events::channel(EventKind::new().with_networks());
+/// while let Ok(event) = receiver.recv_timeout(Duration::from_secs(60)) {
+///     println!("{event:?}");
+/// }
+/// drop(subscription);
+/// ```
+pub fn channel(kind: EventKind) -> (Subscription, Receiver<Event>) {
+    let (sender, receiver) = mpsc::channel();
+    let stop = Arc::new(AtomicBool::new(false));
+    let mut threads = Vec::new();
+    let mut spawn = |watch: fn(EventKind, Sender<Event>, Arc<AtomicBool>)| {
+        let sender = sender.clone();
+        let stop = Arc::clone(&stop);
+        threads.push(std::thread::spawn(move || watch(kind, sender, stop)));
+    };
+    if kind.processes {
+        spawn(watch_processes);
+    }
+    if kind.disks {
+        spawn(watch_disks);
+    }
+    if kind.networks {
+        spawn(watch_networks);
+    }
+    if kind.components.is_some() {
+        spawn(watch_components);
+    }
+    (Subscription { stop, threads }, receiver)
+}
+
+/// Starts watching the events of `kind` and calls `callback` with each of them, from another
+/// thread, until the returned [`Subscription`] is dropped.
+///
+/// ```no_run
+/// use sysinfo::events::{self, EventKind};
+///
+/// let subscription = events::subscribe(EventKind::everything(), |event| {
+///     println!("{event:?}");
+/// });
+/// std::thread::sleep(std::time::Duration::from_secs(60));
+/// drop(subscription);
+/// ```
+pub fn subscribe<F>(kind: EventKind, mut callback: F) -> Subscription
+where
+    F: FnMut(Event) + Send + 'static,
+{
+    let (subscription, receiver) = channel(kind);
+    let stop = Arc::clone(&subscription.stop);
+    // This thread isn't joined, so the subscription can be dropped from the callback.
+    std::thread::spawn(move || {
+        for event in receiver {
+            if stop.load(Ordering::Relaxed) {
+                break;
+            }
+            callback(event);
+        }
+    });
+    subscription
+}
+
+/// Stream of the events, returned by [`stream`].
+///
+/// ⚠️ Only available with the `async` feature.
+#[cfg(feature = "async")]
+pub struct EventStream {
+    queue: Arc<Mutex<EventQueue>>,
+    _subscription: Subscription,
+}
+
+#[cfg(feature = "async")]
+#[derive(Default)]
+struct EventQueue {
+    events: VecDeque<Event>,
+    waker: Option<Waker>,
+    // Set once the thread forwarding the events ended: no event will be pushed anymore.
+    closed: bool,
+}
+
+/// Closes the queue when dropped, which happens when the forwarding thread ends as the callback
+/// owns it.
+#[cfg(feature = "async")]
+struct CloseQueue(Arc<Mutex<EventQueue>>);
+
+#[cfg(feature = "async")]
+impl Drop for CloseQueue {
+    fn drop(&mut self) {
+        let waker = {
+            let mut queue = self.0.lock().unwrap_or_else(|e| e.into_inner());
+            queue.closed = true;
+            queue.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl futures_core::Stream for EventStream {
+    type Item = Event;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Event>> {
+        let mut queue = self.queue.lock().unwrap_or_else(|e| e.into_inner());
+        match queue.events.pop_front() {
+            Some(event) => Poll::Ready(Some(event)),
+            None if queue.closed => Poll::Ready(None),
+            None => {
+                queue.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Starts watching the events of `kind` and returns a [`Stream`][futures_core::Stream] of
+/// them, for async runtimes. The watching threads are stopped when the stream is dropped, and the
+/// stream ends if they all stop before.
+///
+/// ⚠️ Only available with the `async` feature.
+///
+/// ```no_run
+/// use futures_core::Stream;
+/// use sysinfo::events::{self, EventKind};
+///
+/// fn process_events() -> impl Stream<Item = events::Event> {
+///     events::stream(EventKind::new().with_processes())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub fn stream(kind: EventKind) -> EventStream {
+    let queue = Arc::new(Mutex::new(EventQueue::default()));
+    let subscription = subscribe(kind, {
+        let close = CloseQueue(Arc::clone(&queue));
+        move |event| {
+            let waker = {
+                let mut queue = close.0.lock().unwrap_or_else(|e| e.into_inner());
+                queue.events.push_back(event);
+                queue.waker.take()
+            };
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    });
+    EventStream {
+        queue,
+        _subscription: subscription,
+    }
+}
+
+/// Sleeps for `duration`, returning `false` early if `stop` is set.
+fn sleep(stop: &AtomicBool, duration: Duration) -> bool {
+    let deadline = Instant::now() + duration;
+    while !stop.load(Ordering::Relaxed) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return true;
+        }
+        std::thread::sleep(remaining.min(STOP_CHECK_INTERVAL));
+    }
+    false
+}
+
+fn watch_processes(kind: EventKind, sender: Sender<Event>, stop: Arc<AtomicBool>) {
+    let mut system = System::new();
+    // A PID can be reused, so the start time is also compared.
+    let list = |system: &System| {
+        system
+            .processes()
+            .iter()
+            .map(|(pid, process)| (*pid, (process.start_time(), process.name().to_owned())))
+            .collect::<HashMap<_, _>>()
+    };
+    system.refresh_processes_specifics(ProcessRefreshKind::new());
+    let mut processes = list(&system);
+
+    while sleep(&stop, kind.interval) {
+        system.refresh_processes_specifics(ProcessRefreshKind::new());
+        let new_processes = list(&system);
+        let mut events = Vec::new();
+        for (pid, (start_time, name)) in &processes {
+            if new_processes.get(pid).map(|(start_time, _)| start_time) != Some(start_time) {
+                events.push(Event::ProcessStopped {
+                    pid: *pid,
+                    name: name.clone(),
+                });
+            }
+        }
+        for (pid, (start_time, name)) in &new_processes {
+            if processes.get(pid).map(|(start_time, _)| start_time) != Some(start_time) {
+                events.push(Event::ProcessStarted {
+                    pid: *pid,
+                    name: name.clone(),
+                });
+            }
+        }
+        processes = new_processes;
+        if !send(&sender, events) {
+            return;
+        }
+    }
+}
+
+fn watch_disks(_kind: EventKind, sender: Sender<Event>, stop: Arc<AtomicBool>) {
+    let mut events = match DiskEvents::new() {
+        Some(events) => events,
+        None => {
+            sysinfo_debug!("disk events aren't supported");
+            return;
+        }
+    };
+    while !stop.load(Ordering::Relaxed) {
+        let events = events.wait(Some(STOP_CHECK_INTERVAL));
+        if !send(&sender, events.into_iter().map(Event::Disk)) {
+            return;
+        }
+    }
+}
+
+fn watch_networks(_kind: EventKind, sender: Sender<Event>, stop: Arc<AtomicBool>) {
+    let mut events = match NetworkEvents::new() {
+        Some(events) => events,
+        None => {
+            sysinfo_debug!("network events aren't supported");
+            return;
+        }
+    };
+    while !stop.load(Ordering::Relaxed) {
+        let events = events.wait(Some(STOP_CHECK_INTERVAL));
+        if !send(&sender, events.into_iter().map(Event::Network)) {
+            return;
+        }
+    }
+}
+
+fn watch_components(kind: EventKind, sender: Sender<Event>, stop: Arc<AtomicBool>) {
+    let threshold = match kind.components {
+        Some(threshold) => threshold,
+        None => return,
+    };
+    let mut events = ComponentEvents::new(threshold);
+    loop {
+        if !send(&sender, events.check().into_iter().map(Event::Component)) {
+            return;
+        }
+        if !sleep(&stop, kind.interval) {
+            return;
+        }
+    }
+}
+
+/// Sends `events`, returning `false` if the receiver was dropped.
+fn send(sender: &Sender<Event>, events: impl IntoIterator<Item = Event>) -> bool {
+    events.into_iter().all(|event| sender.send(event).is_ok())
+}
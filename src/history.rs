@@ -0,0 +1,265 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Networks, Pid, System};
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+/// Values recorded by a [`History`], with the time at which they were recorded.
+///
+/// Only the last [`capacity`](History::capacity) values are kept, the oldest ones being dropped
+/// first.
+///
+/// ```no_run
+/// use sysinfo::{History, System};
+/// use std::time::Duration;
+///
+/// let mut s = System::new();
+/// let mut history = History::new(60);
+///
+/// s.refresh_cpu();
+/// history.record_global_cpu(&s);
+/// let series = history.global_cpu_usage();
+/// println!("max CPU usage over the last 10 seconds: {:?}", series.max(Duration::from_secs(10)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSeries {
+    samples: VecDeque<(Instant, f64)>,
+    capacity: usize,
+}
+
+impl TimeSeries {
+    fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    pub(crate) fn push(&mut self, instant: Instant, value: f64) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((instant, value));
+    }
+
+    /// Returns the number of recorded values.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns `true` if no value was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Returns the recorded values with their timestamps, from the oldest to the most recent.
+    ///
+    /// ```no_run
+    /// use sysinfo::{History, System};
+    ///
+    /// let s = System::new_all();
+    /// let mut history = History::new(60);
+    /// history.record_processes(&s);
+    ///
+    /// for (pid, _) in s.processes() {
+    ///     if let Some(series) = history.process_memory(*pid) {
+    ///         let values: Vec<f64> = series.iter().map(|(_, value)| value).collect();
+    ///         println!("{pid}: {values:?}");
+    ///     }
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Instant, f64)> + '_ {
+        self.samples.iter().copied()
+    }
+
+    /// Returns the most recent value with its timestamp.
+    pub fn last(&self) -> Option<(Instant, f64)> {
+        self.samples.back().copied()
+    }
+
+    /// Returns the values recorded during the last `window`.
+    fn window(&self, window: Duration) -> impl Iterator<Item = f64> + '_ {
+        let start = Instant::now().checked_sub(window);
+        self.samples
+            .iter()
+            .rev()
+            .take_while(move |(instant, _)| start.map_or(true, |start| *instant >= start))
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns the minimum of the values recorded during the last `window`, or `None` if there
+    /// are none.
+    pub fn min(&self, window: Duration) -> Option<f64> {
+        self.window(window).reduce(f64::min)
+    }
+
+    /// Returns the maximum of the values recorded during the last `window`, or `None` if there
+    /// are none.
+    pub fn max(&self, window: Duration) -> Option<f64> {
+        self.window(window).reduce(f64::max)
+    }
+
+    /// Returns the average of the values recorded during the last `window`, or `None` if there
+    /// are none.
+    pub fn average(&self, window: Duration) -> Option<f64> {
+        let (sum, count) = self
+            .window(window)
+            .fold((0., 0usize), |(sum, count), value| (sum + value, count + 1));
+        if count == 0 {
+            None
+        } else {
+            Some(sum / count as f64)
+        }
+    }
+}
+
+/// Keeps the last values of some metrics, to display their evolution (with sparklines for
+/// example) without storing them somewhere else.
+///
+/// The metrics are recorded from a [`System`] or a [`Networks`] which was just refreshed, by
+/// calling the `record_*` method matching each of them. Only the metrics recorded this way are
+/// kept, each of them in a [`TimeSeries`] holding at most [`capacity`](History::capacity)
+/// values.
+///
+/// ```no_run
+/// use sysinfo::{History, Networks, System};
+/// use std::time::Duration;
+///
+/// let mut s = System::new();
+/// let mut networks = Networks::new_with_refreshed_list();
+/// let mut history = History::new(60);
+///
+/// for _ in 0..10 {
+///     std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+///     s.refresh_cpu();
+///     networks.refresh();
+///     history.record_global_cpu(&s);
+///     history.record_networks(&networks);
+/// }
+/// println!("average CPU usage: {:?}", history.global_cpu_usage().average(Duration::MAX));
+/// ```
+#[derive(Debug, Clone)]
+pub struct History {
+    capacity: usize,
+    pub(crate) global_cpu_usage: TimeSeries,
+    processes: HashMap<Pid, (TimeSeries, TimeSeries)>,
+    networks: HashMap<String, (TimeSeries, TimeSeries)>,
+}
+
+impl History {
+    /// Creates a new `History` keeping the last `capacity` values of each metric.
+    ///
+    /// ```
+    /// use sysinfo::History;
+    ///
+    /// let history = History::new(60);
+    /// assert_eq!(history.capacity(), 60);
+    /// assert!(history.global_cpu_usage().is_empty());
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            global_cpu_usage: TimeSeries::new(capacity),
+            processes: HashMap::new(),
+            networks: HashMap::new(),
+        }
+    }
+
+    /// Returns the maximum number of values kept for each metric.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Removes all the recorded values.
+    pub fn clear(&mut self) {
+        *self = Self::new(self.capacity);
+    }
+
+    /// Records the global CPU usage (in %) of `system`.
+    ///
+    /// ⚠️ It should be called after [`System::refresh_cpu`] or a similar method.
+    pub fn record_global_cpu(&mut self, system: &System) {
+        self.global_cpu_usage
+            .push(Instant::now(), system.global_cpu_info().cpu_usage() as f64);
+    }
+
+    /// Records the CPU usage (in %) and the memory usage (in bytes) of the processes of
+    /// `system`.
+    ///
+    /// The history of the processes which aren't in `system` anymore is removed.
+    ///
+    /// ⚠️ It should be called after [`System::refresh_processes`] or a similar method.
+    pub fn record_processes(&mut self, system: &System) {
+        let now = Instant::now();
+        let capacity = self.capacity;
+        let processes = system.processes();
+        self.processes.retain(|pid, _| processes.contains_key(pid));
+        for (pid, process) in processes {
+            let (cpu_usage, memory) = self
+                .processes
+                .entry(*pid)
+                .or_insert_with(|| (TimeSeries::new(capacity), TimeSeries::new(capacity)));
+            cpu_usage.push(now, process.cpu_usage() as f64);
+            memory.push(now, process.memory() as f64);
+        }
+    }
+
+    /// Records the received and transmitted rates (in bytes per second) of the network
+    /// interfaces of `networks`, as returned by [`NetworkData::received_rate`] and
+    /// [`NetworkData::transmitted_rate`].
+    ///
+    /// The history of the interfaces which aren't in `networks` anymore is removed.
+    ///
+    /// ⚠️ It should be called after [`Networks::refresh`].
+    ///
+    /// [`NetworkData::received_rate`]: crate::NetworkData::received_rate
+    /// [`NetworkData::transmitted_rate`]: crate::NetworkData::transmitted_rate
+    pub fn record_networks(&mut self, networks: &Networks) {
+        let now = Instant::now();
+        let capacity = self.capacity;
+        self.networks.retain(|name, _| networks.contains_key(name));
+        for (name, data) in networks {
+            let (received, transmitted) = self
+                .networks
+                .entry(name.clone())
+                .or_insert_with(|| (TimeSeries::new(capacity), TimeSeries::new(capacity)));
+            received.push(now, data.received_rate());
+            transmitted.push(now, data.transmitted_rate());
+        }
+    }
+
+    /// Returns the recorded global CPU usage (in %).
+    pub fn global_cpu_usage(&self) -> &TimeSeries {
+        &self.global_cpu_usage
+    }
+
+    /// Returns the recorded CPU usage (in %) of the process matching `pid`.
+    pub fn process_cpu_usage(&self, pid: Pid) -> Option<&TimeSeries> {
+        self.processes.get(&pid).map(|(cpu_usage, _)| cpu_usage)
+    }
+
+    /// Returns the recorded memory usage (in bytes) of the process matching `pid`.
+    pub fn process_memory(&self, pid: Pid) -> Option<&TimeSeries> {
+        self.processes.get(&pid).map(|(_, memory)| memory)
+    }
+
+    /// Returns the recorded received rate (in bytes per second) of the network interface
+    /// named `interface_name`.
+    pub fn network_received(&self, interface_name: &str) -> Option<&TimeSeries> {
+        self.networks
+            .get(interface_name)
+            .map(|(received, _)| received)
+    }
+
+    /// Returns the recorded transmitted rate (in bytes per second) of the network interface
+    /// named `interface_name`.
+    pub fn network_transmitted(&self, interface_name: &str) -> Option<&TimeSeries> {
+        self.networks
+            .get(interface_name)
+            .map(|(_, transmitted)| transmitted)
+    }
+}
@@ -9,6 +9,7 @@
 #![allow(clippy::non_send_fields_in_send_ty)]
 #![allow(renamed_and_removed_lints)]
 #![allow(clippy::assertions_on_constants)]
+#![allow(clippy::iter_kv_map)]
 
 #[macro_use]
 mod macros;
@@ -36,6 +37,7 @@ cfg_if::cfg_if! {
     } else if #[cfg(windows)] {
         mod windows;
         use crate::windows as sys;
+        pub use crate::windows::Sid;
         use crate::windows::network_helper;
         mod network;
 
@@ -50,27 +52,63 @@ cfg_if::cfg_if! {
     }
 }
 
+#[cfg(feature = "async")]
+pub use crate::async_refresh::RefreshFuture;
+#[cfg(feature = "wireless")]
+pub use crate::common::WirelessInfo;
 pub use crate::common::{
-    get_current_pid, CGroupLimits, Component, Components, Cpu, CpuRefreshKind, Disk, DiskKind,
-    DiskUsage, Disks, Gid, Group, LoadAvg, MacAddr, NetworkData, Networks, Pid, Process,
-    ProcessRefreshKind, ProcessStatus, RefreshKind, Signal, System, Uid, User, Users,
+    get_current_pid, AvailableMemory, AvailableMemorySource, Batteries, Battery, BatteryState,
+    BlockDevice, BlockDeviceKind, CGroupLimits, ChassisKind, CommitCharge, Component,
+    ComponentEvent, ComponentEvents, ComponentKind, ComponentThreshold, ComponentUnit, Components,
+    CompressedMemory, Connection, ConnectionProtocol, ConnectionSummary, ConnectionTraffic,
+    Connections, Container, ContainerRuntime, Containers, CoolingDevice, Cpu, CpuActivity,
+    CpuIdleState, CpuRefreshKind, CpuSnapshot, CpuTimes, Disk, DiskEvent, DiskEvents, DiskKind,
+    DiskQuota, DiskRefreshKind, DiskUsage, Disks, DuplexMode, Entropy, FileHandles, Gid, Gpu,
+    GpuRefreshKind, Gpus, Group, GroupRefreshKind, HardwareInfo, Hypervisor, InterfaceKind,
+    IpNetwork, KernelParameter, Ksm, LoadAvg, MacAddr, MemoryBreakdown, MemoryModule,
+    MemoryModuleKind, MemoryPressureLevel, Neighbor, NeighborState, Neighbors, NetworkCounters,
+    NetworkData, NetworkEvent, NetworkEvents, Networks, OperationalState, OsRelease,
+    ParseRefreshKindError, PciDevice, PciDevices, PerformanceCounters, PhysicalDisk, Pid, Pressure,
+    PressureStats, PressureValues, Process, ProcessRefreshKind, ProcessSnapshot, ProcessStatus,
+    RefreshError, RefreshErrorKind, RefreshKind, RefreshReport, Service, ServiceStartKind,
+    ServiceState, Services, Session, Sessions, Signal, StoragePool, StoragePoolHealth,
+    StoragePoolKind, StringEncodingPolicy, System, SystemBuilder, SystemCounts, SystemSnapshot,
+    TcpState, ThermalZone, ThermalZones, TimeZone, TripPoint, TripPointKind, Uid, User,
+    UserRefreshKind, Users, Virtualization, WindowsVersion, ZramDevice,
 };
+#[cfg(feature = "energy")]
+pub use crate::common::{EnergyDomain, Power, PowerRail, PowerRailKind};
 
+pub use crate::history::{History, TimeSeries};
+pub use crate::sampler::Sampler;
+#[cfg(feature = "energy")]
+pub(crate) use crate::sys::PowerInner;
 pub(crate) use crate::sys::{
-    ComponentInner, ComponentsInner, CpuInner, DiskInner, DisksInner, NetworkDataInner,
-    NetworksInner, ProcessInner, SystemInner, UserInner,
+    ComponentInner, ComponentsInner, ContainerInner, ContainersInner, CpuInner, DiskEventsInner,
+    DiskInner, DisksInner, GpuInner, GpusInner, NetworkDataInner, NetworkEventsInner,
+    NetworksInner, PerformanceCountersInner, ProcessInner, SystemInner, UserInner,
 };
 pub use crate::sys::{IS_SUPPORTED, MINIMUM_CPU_UPDATE_INTERVAL, SUPPORTED_SIGNALS};
 
 #[cfg(feature = "c-interface")]
 pub use crate::c_interface::*;
 
+#[cfg(feature = "async")]
+mod async_refresh;
 #[cfg(feature = "c-interface")]
 mod c_interface;
 mod common;
 mod debug;
+pub mod events;
+mod history;
+mod sampler;
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(all(
+    any(target_os = "linux", target_os = "android", windows),
+    not(feature = "unknown-ci")
+))]
+mod smbios;
 mod system;
 mod utils;
 
@@ -108,17 +146,15 @@ pub fn set_open_files_limit(mut _new_limit: isize) -> bool {
             if _new_limit > max {
                 _new_limit = max;
             }
-            unsafe {
-                if let Ok(ref mut x) = sys::system::REMAINING_FILES.lock() {
-                    // If files are already open, to be sure that the number won't be bigger when those
-                    // files are closed, we subtract the current number of opened files to the new
-                    // limit.
-                    let diff = max.saturating_sub(**x);
-                    **x = _new_limit.saturating_sub(diff);
-                    true
-                } else {
-                    false
-                }
+            if let Ok(ref mut x) = sys::system::REMAINING_FILES.lock() {
+                // If files are already open, to be sure that the number won't be bigger when those
+                // files are closed, we subtract the current number of opened files to the new
+                // limit.
+                let diff = max.saturating_sub(**x);
+                **x = _new_limit.saturating_sub(diff);
+                true
+            } else {
+                false
             }
         } else {
             false
@@ -244,6 +280,112 @@ mod test {
         }
     }
 
+    #[test]
+    fn check_memory_breakdown() {
+        let mut s = System::new();
+
+        assert_eq!(s.memory_breakdown(), MemoryBreakdown::default());
+
+        s.refresh_memory();
+        let breakdown = s.memory_breakdown();
+        if IS_SUPPORTED {
+            for value in [
+                breakdown.buffers,
+                breakdown.cached,
+                breakdown.shared,
+                breakdown.slab_reclaimable,
+                breakdown.slab_unreclaimable,
+                breakdown.mapped,
+                breakdown.dirty,
+                breakdown.writeback,
+            ] {
+                assert!(value <= s.total_memory());
+            }
+        } else {
+            assert_eq!(breakdown, MemoryBreakdown::default());
+        }
+    }
+
+    #[test]
+    fn check_available_memory_breakdown() {
+        let mut s = System::new();
+
+        s.refresh_memory();
+        match s.available_memory_breakdown() {
+            Some(available) => {
+                assert!(IS_SUPPORTED);
+                assert_eq!(available.available, s.available_memory());
+                assert!(available.free + available.reclaimable >= available.available);
+            }
+            None => assert!(!IS_SUPPORTED),
+        }
+    }
+
+    #[test]
+    fn check_ksm_and_balloon() {
+        let mut s = System::new();
+
+        s.refresh_memory();
+        if !cfg!(any(target_os = "linux", target_os = "android")) || !IS_SUPPORTED {
+            assert_eq!(s.ksm(), None);
+            assert_eq!(s.balloon_memory(), None);
+        } else {
+            if let Some(ksm) = s.ksm() {
+                assert!(ksm.shared <= s.total_memory());
+            }
+            if let Some(balloon) = s.balloon_memory() {
+                assert!(balloon <= s.total_memory());
+            }
+        }
+    }
+
+    #[test]
+    fn check_oom_kills() {
+        let s = System::new();
+
+        if cfg!(any(target_os = "linux", target_os = "android")) && IS_SUPPORTED {
+            assert!(s.oom_kills().is_some());
+        } else {
+            assert_eq!(s.oom_kills(), None);
+        }
+    }
+
+    #[test]
+    fn check_commit_charge() {
+        let mut s = System::new();
+
+        assert_eq!(s.commit_charge(), CommitCharge::default());
+
+        s.refresh_memory();
+        let commit = s.commit_charge();
+        if cfg!(any(target_os = "linux", windows)) && IS_SUPPORTED {
+            assert!(commit.total > 0);
+            assert!(commit.limit > 0);
+            if cfg!(windows) {
+                assert!(commit.peak >= commit.total);
+            }
+        } else {
+            assert_eq!(commit, CommitCharge::default());
+        }
+    }
+
+    #[test]
+    fn check_memory_compressor() {
+        let mut s = System::new();
+
+        assert_eq!(s.compressed_memory(), CompressedMemory::default());
+
+        s.refresh_memory();
+        if cfg!(any(target_os = "macos", target_os = "ios")) && IS_SUPPORTED {
+            assert!(s.memory_pressure_level().is_some());
+        } else if cfg!(any(target_os = "linux", target_os = "android")) && IS_SUPPORTED {
+            assert_eq!(s.memory_pressure_level(), None);
+        } else {
+            assert_eq!(s.compressed_memory(), CompressedMemory::default());
+            assert_eq!(s.memory_pressure_level(), None);
+        }
+    }
+
     #[cfg(target_os = "linux")]
     #[test]
     fn check_processes_cpu_usage() {
@@ -267,6 +409,9 @@ mod test {
             .iter()
             .all(|(_, proc_)| proc_.cpu_usage() >= 0.0
                 && proc_.cpu_usage() <= (s.cpus().len() as f32) * 100.0));
+        assert!(s.processes().iter().all(|(_, proc_)| {
+            proc_.normalized_cpu_usage() >= 0.0 && proc_.normalized_cpu_usage() <= 100.0
+        }));
         assert!(s
             .processes()
             .iter()
@@ -299,6 +444,27 @@ mod test {
         assert!(users.list().len() >= MIN_USERS);
     }
 
+    #[test]
+    #[allow(clippy::absurd_extreme_comparisons)]
+    fn check_list_specifics() {
+        let users = Users::new_with_refreshed_list_specifics(UserRefreshKind::new());
+        assert!(users.list().len() >= MIN_USERS);
+        assert!(users
+            .iter()
+            .all(|u| u.home_dir().is_none() && u.shell().is_none()));
+
+        if IS_SUPPORTED {
+            let users =
+                Users::new_with_refreshed_list_specifics(UserRefreshKind::new().with_groups());
+            for user in users.list() {
+                assert_eq!(
+                    user.groups(),
+                    user.groups_specifics(GroupRefreshKind::new().with_supplementary_groups())
+                );
+            }
+        }
+    }
+
     #[test]
     fn check_uid_gid() {
         let mut users = Users::new();
@@ -316,6 +482,12 @@ mod test {
                     .expect("no root user");
                 assert_eq!(**user.id(), 0);
                 assert_eq!(*user.group_id(), 0);
+                assert!(user.home_dir().is_some());
+                assert!(user.domain().is_none() && !user.is_domain_account());
+                // Only the primary group.
+                assert_eq!(user.groups_specifics(GroupRefreshKind::new()).len(), 1);
+                assert_eq!(user.id().resolve_name().as_deref(), Some("root"));
+                assert!(user.group_id().resolve_name().is_some());
                 if let Some(user) = users.iter().find(|u| *u.group_id() > 0) {
                     assert!(**user.id() > 0);
                     assert!(*user.group_id() > 0);
@@ -392,6 +564,770 @@ mod test {
         }
     }
 
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "freebsd", target_os = "macos"))]
+    fn check_kernel_parameter() {
+        let s = System::new();
+        #[cfg(target_os = "linux")]
+        let (name, expected) = ("kernel.ostype", "Linux");
+        #[cfg(target_os = "freebsd")]
+        let (name, expected) = ("kern.ostype", "FreeBSD");
+        #[cfg(target_os = "macos")]
+        let (name, expected) = ("kern.ostype", "Darwin");
+        assert_eq!(
+            s.kernel_parameter(name).as_ref().and_then(|v| v.as_str()),
+            Some(expected)
+        );
+        assert_eq!(s.kernel_parameter("this.does.not.exist"), None);
+    }
+
+    #[test]
+    fn check_boot_time_sys() {
+        let s = System::new();
+        let boot_time = s
+            .boot_time_sys()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap();
+        if IS_SUPPORTED {
+            // Both are computed differently on Linux, so allow some difference.
+            assert!((boot_time.as_secs_f64() - s.boot_time() as f64).abs() < 2.);
+        } else {
+            assert_eq!(boot_time.as_secs(), 0);
+        }
+    }
+
+    #[test]
+    fn check_os_release() {
+        let s = System::new();
+        // The file is optional, and only exists since FreeBSD 13.
+        if let Some(release) = s.os_release() {
+            assert_eq!(
+                release.id.as_deref(),
+                release.fields.get("ID").map(|s| s.as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn check_arch() {
+        let s = System::new();
+        if IS_SUPPORTED {
+            assert!(s.kernel_arch().is_some());
+            assert!(s.userland_arch().is_some());
+        } else {
+            assert_eq!(s.kernel_arch(), None);
+            assert_eq!(s.userland_arch(), None);
+        }
+    }
+
+    #[test]
+    fn check_windows_version() {
+        let s = System::new();
+        let version = s.windows_version();
+        if cfg!(windows) {
+            let version = version.expect("failed to get Windows version");
+            assert!(version.major >= 6);
+            assert!(version.build != 0);
+        } else {
+            assert_eq!(version, None);
+        }
+    }
+
+    #[test]
+    fn check_uptime_duration() {
+        let s = System::new();
+        let uptime = s.uptime_duration();
+        if IS_SUPPORTED {
+            assert!(uptime > std::time::Duration::default());
+            assert!((uptime.as_secs_f64() - s.uptime() as f64).abs() < 2.);
+        } else {
+            assert_eq!(uptime, std::time::Duration::default());
+        }
+    }
+
+    #[test]
+    fn check_time_zone() {
+        let time_zone = System::time_zone();
+        // Offsets range from UTC-12 to UTC+14.
+        assert!(time_zone.utc_offset.abs() <= 14 * 3_600);
+        if !IS_SUPPORTED {
+            assert_eq!(time_zone, TimeZone::default());
+        }
+    }
+
+    #[test]
+    fn check_counts() {
+        let s = System::new();
+        let counts = s.counts();
+        if IS_SUPPORTED {
+            assert!(counts.processes.unwrap_or(0) > 0);
+            assert!(counts.handles.unwrap_or(0) > 0);
+            if let (Some(processes), Some(threads)) = (counts.processes, counts.threads) {
+                assert!(threads >= processes);
+            }
+        } else {
+            assert_eq!(counts, SystemCounts::default());
+        }
+    }
+
+    #[test]
+    fn check_file_handles() {
+        let s = System::new();
+        match s.file_handles() {
+            Some(handles) => {
+                assert!(handles.allocated > 0);
+                if let Some(usage) = handles.usage() {
+                    assert!(usage > 0.);
+                }
+            }
+            None => assert!(!IS_SUPPORTED),
+        }
+        let handles = FileHandles {
+            allocated: 25,
+            limit: Some(100),
+        };
+        assert_eq!(handles.usage(), Some(25.));
+        assert_eq!(FileHandles::default().usage(), None);
+    }
+
+    #[test]
+    fn check_entropy() {
+        let s = System::new();
+        match s.entropy() {
+            Some(entropy) => {
+                // The generator is initialized long before tests run.
+                assert!(entropy.ready);
+                if let (Some(available), Some(pool_size)) = (entropy.available, entropy.pool_size) {
+                    assert!(available <= pool_size);
+                }
+            }
+            None => assert!(!IS_SUPPORTED || cfg!(target_os = "freebsd")),
+        }
+    }
+
+    #[test]
+    fn check_services() {
+        let services = Services::new_with_refreshed_list();
+        for service in services.list() {
+            assert!(!service.name.is_empty());
+            if service.pid.is_some() {
+                assert_ne!(service.state, ServiceState::Stopped);
+            }
+            assert_eq!(
+                services.get(&service.name).map(|s| &s.name),
+                Some(&service.name)
+            );
+        }
+        if !IS_SUPPORTED {
+            assert!(services.is_empty());
+        }
+    }
+
+    #[test]
+    fn check_gpus() {
+        let mut gpus = Gpus::new_with_refreshed_list();
+        for gpu in gpus.list() {
+            assert!(!gpu.name().is_empty());
+            if let Some(usage) = gpu.usage() {
+                assert!((0. ..=100.).contains(&usage));
+            }
+            if let (Some(total), Some(used)) = (gpu.total_memory(), gpu.used_memory()) {
+                assert!(used <= total);
+            }
+        }
+        gpus.refresh_specifics(GpuRefreshKind::new());
+        if !IS_SUPPORTED {
+            assert!(gpus.is_empty());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "energy")]
+    fn check_power() {
+        let mut power = Power::new_with_refreshed();
+        power.refresh();
+        for rail in power.rails() {
+            assert!(!rail.name.is_empty());
+            assert!(rail.power >= 0.);
+        }
+        if power.rails().is_empty() {
+            assert_eq!(power.system_power(), None);
+        }
+    }
+
+    #[test]
+    fn check_cgroup_limits() {
+        let mut s = System::new();
+        s.refresh_memory();
+        let total_memory = s.total_memory();
+        let physical_core_count = s.physical_core_count();
+
+        s.set_use_cgroup_limits(true);
+        assert!(s.total_memory() <= total_memory);
+        assert!(s.free_memory() <= s.total_memory());
+        assert!(s.available_memory() <= s.total_memory());
+        assert!(s.used_memory() <= s.total_memory());
+        assert!(s.used_swap() <= s.total_swap());
+        assert!(s.physical_core_count() <= physical_core_count);
+        if let Some(limits) = s.cgroup_limits() {
+            assert!(limits.total_memory <= total_memory);
+            if let Some(cpus) = limits.cpu_count() {
+                assert!(cpus >= 1);
+                assert!(limits.cpus().map_or(true, |max| cpus <= max));
+            }
+        } else if cfg!(not(target_os = "linux")) {
+            assert_eq!(s.total_memory(), total_memory);
+        }
+    }
+
+    #[test]
+    fn check_containers() {
+        let mut containers = Containers::new_with_refreshed_list();
+        if !cfg!(target_os = "linux") {
+            assert!(containers.is_empty());
+            return;
+        }
+        containers.refresh();
+        for container in containers.list() {
+            assert_eq!(container.id().len(), 64);
+            assert!(container.pids().windows(2).all(|pids| pids[0] < pids[1]));
+        }
+    }
+
+    #[test]
+    fn check_pci_devices() {
+        let devices = PciDevices::new_with_refreshed_list();
+        if !cfg!(target_os = "linux") || !std::path::Path::new("/sys/bus/pci/devices").exists() {
+            return;
+        }
+        for device in devices.list() {
+            assert!(device.address.is_some());
+            assert!(!device.class_name().is_empty());
+            if let Some(width) = device.link_width {
+                assert!((1..=32).contains(&width));
+            }
+        }
+    }
+
+    #[test]
+    fn check_performance_counters() {
+        let path = r"\Processor(_Total)\% Processor Time";
+        let mut counters = PerformanceCounters::new();
+        if !cfg!(target_os = "windows") {
+            assert!(!counters.add(path));
+            counters.refresh();
+            assert_eq!(counters.get(path), None);
+            return;
+        }
+        assert!(counters.add(path));
+        assert!(!counters.add(r"\Does Not Exist\Nothing"));
+        counters.refresh();
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        counters.refresh();
+        assert!(counters.get(path).is_some());
+        assert!(counters.remove(path));
+        assert_eq!(counters.get(path), None);
+    }
+
+    #[test]
+    fn check_events() {
+        use crate::events::{self, Event, EventKind};
+        use std::time::{Duration, Instant};
+
+        let kind = EventKind::new()
+            .with_processes()
+            .with_interval(Duration::from_millis(100));
+        let (subscription, receiver) = events::channel(kind);
+        if !IS_SUPPORTED || cfg!(feature = "apple-sandbox") {
+            drop(subscription);
+            assert!(receiver.recv().is_err());
+            return;
+        }
+        // Lets the thread list the processes before starting a new one.
+        std::thread::sleep(Duration::from_millis(200));
+        let mut child = if cfg!(target_os = "windows") {
+            std::process::Command::new("waitfor")
+                .arg("/t")
+                .arg("1")
+                .arg("EventsSignal")
+                .stdout(std::process::Stdio::null())
+                .spawn()
+        } else {
+            std::process::Command::new("sleep").arg("0.5").spawn()
+        }
+        .expect("failed to start the process");
+        let pid = Pid::from_u32(child.id());
+        child.wait().expect("failed to wait for the process");
+
+        let (mut started, mut stopped) = (false, false);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !stopped {
+            let timeout = deadline.saturating_duration_since(Instant::now());
+            match receiver.recv_timeout(timeout) {
+                Ok(Event::ProcessStarted { pid: p, .. }) if p == pid => started = true,
+                Ok(Event::ProcessStopped { pid: p, .. }) if p == pid => stopped = true,
+                Ok(_) => {}
+                Err(_) => break,
+            }
+        }
+        assert!(started && stopped);
+        drop(subscription);
+        // The channel is closed once the threads are stopped.
+        while receiver.recv().is_ok() {}
+    }
+
+    #[test]
+    fn check_top_processes() {
+        let s = System::new_with_specifics(
+            RefreshKind::new().with_processes(ProcessRefreshKind::new().with_cpu()),
+        );
+        let pids = |processes: Vec<&Process>| {
+            processes
+                .into_iter()
+                .map(|process| process.pid())
+                .collect::<Vec<_>>()
+        };
+        let mut sorted = s.processes().values().collect::<Vec<_>>();
+        sorted.sort_by(|a, b| {
+            b.memory()
+                .cmp(&a.memory())
+                .then_with(|| a.pid().cmp(&b.pid()))
+        });
+        for n in [0, 1, 3, sorted.len() + 5] {
+            let expected = pids(sorted.iter().take(n).copied().collect());
+            assert_eq!(pids(s.top_processes_by_memory(n)), expected);
+            assert_eq!(s.top_processes_by_cpu(n).len(), n.min(s.processes().len()));
+        }
+        let top = s.top_processes_by_cpu(5);
+        assert!(top.windows(2).all(|w| w[0].cpu_usage() >= w[1].cpu_usage()));
+    }
+
+    #[test]
+    fn check_refresh_kind_parsing() {
+        let kinds = [
+            RefreshKind::new(),
+            RefreshKind::everything(),
+            RefreshKind::monitoring_agent(),
+            RefreshKind::resource_usage(),
+            RefreshKind::new().with_memory(),
+            RefreshKind::new().with_cpu(CpuRefreshKind::new()),
+            RefreshKind::everything().without_memory(),
+            RefreshKind::new()
+                .with_cpu(CpuRefreshKind::new().with_frequency().with_idle_states())
+                .with_processes(ProcessRefreshKind::new().with_user()),
+        ];
+        for kind in kinds {
+            assert_eq!(kind.to_string().parse::<RefreshKind>(), Ok(kind));
+        }
+        assert_eq!("".parse(), Ok(RefreshKind::new()));
+        assert_eq!(
+            " cpu , mem ".parse(),
+            Ok(RefreshKind::new()
+                .with_cpu(CpuRefreshKind::everything())
+                .with_memory())
+        );
+        assert_eq!(
+            "all,-procs,-cpu.usage".parse(),
+            Ok(RefreshKind::new()
+                .with_cpu(CpuRefreshKind::everything().without_cpu_usage())
+                .with_memory())
+        );
+        assert_eq!(
+            "processes.list,processes.cpu".parse(),
+            Ok(RefreshKind::new().with_processes(ProcessRefreshKind::new().with_cpu()))
+        );
+        assert_eq!(
+            "resource-usage,processes.user".parse(),
+            Ok(RefreshKind::resource_usage().with_processes(ProcessRefreshKind::new().with_user()))
+        );
+        assert_eq!("cpu,-monitoring-agent".parse(), Ok(RefreshKind::new()));
+        for invalid in ["disks", "cpu.foo", "-cpu.foo", "memory.free", "cpu,,x"] {
+            let err = invalid.parse::<RefreshKind>().unwrap_err();
+            assert!(invalid.contains(err.item()));
+        }
+    }
+
+    #[test]
+    fn check_system_builder() {
+        let pid = get_current_pid().expect("failed to get current pid");
+        let mut s = crate::SystemBuilder::new()
+            .with_refreshes(RefreshKind::new().with_processes(ProcessRefreshKind::new()))
+            .with_process_filter(move |process| process.pid() == pid)
+            .with_open_files(false)
+            .with_cpu_usage_smoothing(3)
+            .build();
+        if IS_SUPPORTED {
+            assert_eq!(s.processes().keys().collect::<Vec<_>>(), [&pid]);
+        }
+        s.refresh_processes();
+        assert!(s.processes().len() <= 1);
+        // A process which doesn't match the filter isn't added.
+        let others = System::new_with_specifics(
+            RefreshKind::new().with_processes(ProcessRefreshKind::new()),
+        );
+        if let Some(other) = others.processes().keys().find(|other| **other != pid) {
+            assert!(!s.refresh_process(*other));
+            assert!(s.process(*other).is_none());
+        }
+    }
+
+    #[test]
+    fn check_refresh_report() {
+        use crate::utils::{collect_errors, report_error, ErrorCollector};
+        use crate::{RefreshErrorKind, RefreshReport};
+
+        // Nothing is collected outside of `collect_errors`.
+        report_error(RefreshErrorKind::Other, "nothing", "ignored");
+        let report = collect_errors(|| {
+            report_error(RefreshErrorKind::Vanished, "process 1", "gone");
+            // The errors of the other threads are collected if they run in the collector.
+            let errors = ErrorCollector::current();
+            std::thread::spawn(move || {
+                errors.run(|| report_error(RefreshErrorKind::PermissionDenied, "/proc/1", "no"));
+                report_error(RefreshErrorKind::Other, "nothing", "ignored");
+            })
+            .join()
+            .unwrap();
+        })
+        .map_err(RefreshReport::into_errors)
+        .unwrap_err();
+        let errors = report
+            .iter()
+            .map(|error| (error.kind(), error.target()))
+            .collect::<Vec<_>>();
+        let mut expected = vec![
+            (RefreshErrorKind::Vanished, "process 1"),
+            (RefreshErrorKind::PermissionDenied, "/proc/1"),
+        ];
+        if !IS_SUPPORTED {
+            expected.push((RefreshErrorKind::Unavailable, std::env::consts::OS));
+        }
+        assert_eq!(errors, expected);
+        assert_eq!(report[0].to_string(), "process 1 vanished: gone");
+
+        // The collector isn't kept on a thread reused after the code it ran panicked.
+        let report = collect_errors(|| {
+            let errors = ErrorCollector::current();
+            std::thread::spawn(move || {
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    errors.run(|| {
+                        report_error(RefreshErrorKind::Other, "panic", "collected");
+                        panic!("refresh failed");
+                    })
+                }));
+                assert!(result.is_err());
+                report_error(RefreshErrorKind::Other, "nothing", "ignored");
+            })
+            .join()
+            .unwrap();
+        })
+        .map_err(RefreshReport::into_errors)
+        .unwrap_err();
+        assert_eq!(report.len(), if IS_SUPPORTED { 1 } else { 2 });
+        assert_eq!(report[0].target(), "panic");
+
+        let mut s = System::new();
+        match s.try_refresh_all() {
+            Ok(()) => assert!(IS_SUPPORTED),
+            // Processes can stop while they're being refreshed.
+            Err(report) => assert!(report.errors().iter().all(|error| {
+                error.kind()
+                    == if IS_SUPPORTED {
+                        RefreshErrorKind::Vanished
+                    } else {
+                        RefreshErrorKind::Unavailable
+                    }
+            })),
+        }
+    }
+
+    #[test]
+    fn check_history() {
+        use crate::{History, Networks};
+        use std::time::{Duration, Instant};
+
+        let mut history = History::new(3);
+        let now = Instant::now();
+        for value in [4., 1., 2., 3.] {
+            history.global_cpu_usage.push(now, value);
+        }
+        let series = history.global_cpu_usage();
+        assert_eq!(series.len(), 3);
+        assert_eq!(
+            series.iter().map(|(_, value)| value).collect::<Vec<_>>(),
+            [1., 2., 3.]
+        );
+        assert_eq!(series.last(), Some((now, 3.)));
+        assert_eq!(series.min(Duration::MAX), Some(1.));
+        assert_eq!(series.max(Duration::MAX), Some(3.));
+        assert_eq!(series.average(Duration::MAX), Some(2.));
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(series.max(Duration::from_millis(10)), None);
+        history.clear();
+        assert!(history.global_cpu_usage().is_empty());
+
+        let mut s = System::new();
+        s.refresh_processes();
+        history.record_processes(&s);
+        let mut networks = Networks::new_with_refreshed_list();
+        history.record_networks(&networks);
+        networks.refresh();
+        history.record_networks(&networks);
+        if IS_SUPPORTED {
+            let pid = get_current_pid().expect("failed to get current pid");
+            assert_eq!(
+                history.process_memory(pid).map(|series| series.len()),
+                Some(1)
+            );
+            assert!(history.process_cpu_usage(pid).is_some());
+            for (name, data) in &networks {
+                let received = history.network_received(name).expect("no received rates");
+                assert_eq!(received.len(), 2);
+                assert_eq!(
+                    received.last().map(|(_, rate)| rate),
+                    Some(data.received_rate())
+                );
+                let transmitted = history
+                    .network_transmitted(name)
+                    .expect("no transmitted rates");
+                assert_eq!(
+                    transmitted.last().map(|(_, rate)| rate),
+                    Some(data.transmitted_rate())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn check_sampler() {
+        use std::time::{Duration, Instant};
+
+        let sampler = crate::Sampler::new(
+            System::new(),
+            [
+                (RefreshKind::new().with_memory(), Duration::from_millis(10)),
+                (
+                    RefreshKind::new().with_processes(ProcessRefreshKind::new()),
+                    Duration::from_secs(3600),
+                ),
+            ],
+        );
+        if IS_SUPPORTED {
+            let deadline = Instant::now() + Duration::from_secs(10);
+            let snapshot = loop {
+                let snapshot = sampler.snapshot();
+                if snapshot.total_memory() > 0 || Instant::now() > deadline {
+                    break snapshot;
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            };
+            assert!(snapshot.total_memory() > 0);
+            assert!(!snapshot.processes().is_empty());
+        }
+        let s = sampler.stop();
+        if IS_SUPPORTED {
+            assert!(s.total_memory() > 0);
+        }
+    }
+
+    // The parallel `refresh_all` must give the same results as the serial refreshes.
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn check_parallel_refresh_all() {
+        let current = get_current_pid().ok();
+        let build = || {
+            System::builder()
+                .with_process_filter(move |process| Some(process.pid()) == current)
+                .with_cpu_usage_smoothing(3)
+                .with_cgroup_limits(true)
+                .build()
+        };
+        let mut parallel = build();
+        let mut serial = build();
+        parallel.refresh_all();
+        serial.refresh_system();
+        serial.refresh_processes();
+
+        assert_eq!(parallel.total_memory(), serial.total_memory());
+        assert_eq!(parallel.total_swap(), serial.total_swap());
+        assert_eq!(
+            parallel.cgroup_limits().map(|limits| limits.total_memory),
+            serial.cgroup_limits().map(|limits| limits.total_memory)
+        );
+        assert_eq!(parallel.cpus().len(), serial.cpus().len());
+        assert_eq!(parallel.physical_core_count(), serial.physical_core_count());
+        let pids = |s: &System| s.processes().keys().copied().collect::<Vec<_>>();
+        assert_eq!(pids(&parallel), pids(&serial));
+        if IS_SUPPORTED {
+            assert!(parallel.total_memory() > 0);
+            assert_eq!(pids(&parallel), current.into_iter().collect::<Vec<_>>());
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn check_async() {
+        use crate::events::{self, Event, EventKind};
+        use std::future::Future;
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake};
+        use std::time::{Duration, Instant};
+
+        struct ThreadWaker(std::thread::Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        // Polls `future` until it's ready or until `timeout` is reached.
+        fn block_on<F: Future>(future: F, timeout: Duration) -> Option<F::Output> {
+            let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+            let mut cx = Context::from_waker(&waker);
+            let mut future = Box::pin(future);
+            let deadline = Instant::now() + timeout;
+            loop {
+                if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                    return Some(output);
+                }
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    return None;
+                }
+                std::thread::park_timeout(remaining);
+            }
+        }
+
+        let mut s = System::new();
+        assert!(block_on(s.refresh_all_async(), Duration::from_secs(30)).is_some());
+        if IS_SUPPORTED {
+            assert!(!s.processes().is_empty());
+            assert!(s.total_memory() > 0);
+        }
+        // Dropping the future while it runs puts the refreshed `System` back.
+        let mut s = System::new();
+        {
+            let mut future = Box::pin(s.refresh_processes_async());
+            let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+            let _ = future.as_mut().poll(&mut Context::from_waker(&waker));
+        }
+        if IS_SUPPORTED {
+            assert!(!s.processes().is_empty());
+        }
+        // A panic during the refresh is resumed when polling and the `System` is put back.
+        let mut s = System::new();
+        s.refresh_memory();
+        let total_memory = s.total_memory();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let future = crate::RefreshFuture::new(&mut s, |_| panic!("refresh failed"));
+            block_on(future, Duration::from_secs(30))
+        }));
+        assert!(result.is_err());
+        assert_eq!(s.total_memory(), total_memory);
+        // Same when the future is dropped while the refresh is running.
+        {
+            let mut future = Box::pin(crate::RefreshFuture::new(&mut s, |_| {
+                panic!("refresh failed")
+            }));
+            let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+            let _ = future.as_mut().poll(&mut Context::from_waker(&waker));
+        }
+        assert_eq!(s.total_memory(), total_memory);
+
+        // Nothing is watched so the stream ends right away.
+        let mut stream = events::stream(EventKind::new());
+        let next = std::future::poll_fn(|cx| {
+            futures_core::Stream::poll_next(std::pin::Pin::new(&mut stream), cx)
+        });
+        assert!(matches!(block_on(next, Duration::from_secs(5)), Some(None)));
+
+        if !IS_SUPPORTED || cfg!(feature = "apple-sandbox") || cfg!(target_os = "windows") {
+            return;
+        }
+        let kind = EventKind::new()
+            .with_processes()
+            .with_interval(Duration::from_millis(100));
+        let mut stream = events::stream(kind);
+        // Lets the thread list the processes before starting a new one.
+        std::thread::sleep(Duration::from_millis(200));
+        let mut child = std::process::Command::new("sleep")
+            .arg("0.5")
+            .spawn()
+            .expect("failed to start the process");
+        let pid = Pid::from_u32(child.id());
+        let started = block_on(
+            std::future::poll_fn(|cx| loop {
+                match futures_core::Stream::poll_next(std::pin::Pin::new(&mut stream), cx) {
+                    Poll::Ready(Some(Event::ProcessStarted { pid: p, .. })) if p == pid => {
+                        return Poll::Ready(true);
+                    }
+                    Poll::Ready(Some(_)) => {}
+                    Poll::Ready(None) => return Poll::Ready(false),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }),
+            Duration::from_secs(5),
+        );
+        child.wait().expect("failed to wait for the process");
+        assert_eq!(started, Some(true));
+    }
+
+    #[cfg(feature = "c-interface")]
+    #[test]
+    fn check_c_interface() {
+        use std::ffi::CStr;
+
+        let system = crate::sysinfo_init();
+        crate::sysinfo_refresh_processes(system);
+        let process = crate::sysinfo_process_by_pid(system, crate::get_current_pid().unwrap().0);
+        assert!(!process.is_null());
+
+        let name = crate::sysinfo_process_name(process);
+        assert!(!name.is_null());
+        crate::sysinfo_rstring_free(name);
+
+        let cmd = crate::sysinfo_process_cmd(process);
+        let mut args = Vec::new();
+        unsafe {
+            while !(*cmd.add(args.len())).is_null() {
+                args.push(
+                    CStr::from_ptr(*cmd.add(args.len()))
+                        .to_str()
+                        .unwrap()
+                        .to_owned(),
+                );
+            }
+        }
+        crate::sysinfo_rstrings_free(cmd);
+        let expected = unsafe { &*(process as *const crate::Process) }.cmd();
+        assert_eq!(args, expected);
+
+        crate::sysinfo_destroy(system);
+
+        extern "C" fn count(_disk: crate::CDisk, data: *mut libc::c_void) -> bool {
+            unsafe { *(data as *mut usize) += 1 };
+            true
+        }
+        let disks = crate::sysinfo_disks_init();
+        crate::sysinfo_disks_refresh_list(disks);
+        let mut nb = 0usize;
+        let len = crate::sysinfo_disks(disks, Some(count), &mut nb as *mut usize as *mut _);
+        assert_eq!(nb, len);
+        crate::sysinfo_disks_destroy(disks);
+    }
+
+    #[test]
+    fn check_fqdn() {
+        let s = System::new();
+        if let (Some(fqdn), Some(domain)) = (s.fqdn(), s.domain_name()) {
+            assert!(fqdn.ends_with(&format!(".{domain}")));
+        }
+        if !cfg!(windows) {
+            assert_eq!(s.workgroup(), None);
+        }
+    }
+
     #[test]
     fn check_refresh_process_return_value() {
         // We don't want to test on unsupported systems.
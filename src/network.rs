@@ -1,9 +1,98 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use std::collections::HashMap;
+use std::time::{Duration, Instant, SystemTime};
 
-use crate::network_helper::get_interface_address;
-use crate::NetworkData;
+use crate::network_helper::{get_interface_address, get_interface_ip_networks};
+use crate::{NetworkData, OperationalState};
+
+/// State of an interface, kept between refreshes to know when it last changed.
+#[derive(PartialEq, Eq)]
+pub(crate) struct LinkState {
+    pub(crate) operational_state: OperationalState,
+    pub(crate) is_admin_up: bool,
+    pub(crate) has_carrier: Option<bool>,
+    /// Time (in seconds since UNIX epoch) at which a change was detected.
+    pub(crate) last_change: Option<u64>,
+}
+
+impl LinkState {
+    pub(crate) fn new(
+        operational_state: OperationalState,
+        is_admin_up: bool,
+        has_carrier: Option<bool>,
+    ) -> Self {
+        Self {
+            operational_state,
+            is_admin_up,
+            has_carrier,
+            last_change: None,
+        }
+    }
+
+    pub(crate) fn update(&mut self, new_state: Self) {
+        if self.operational_state != new_state.operational_state
+            || self.is_admin_up != new_state.is_admin_up
+            || self.has_carrier != new_state.has_carrier
+        {
+            *self = Self {
+                last_change: SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .ok(),
+                ..new_state
+            };
+        }
+    }
+}
+
+/// Times at which the counters of an interface were read, kept to compute the rates.
+#[derive(PartialEq, Eq)]
+pub(crate) struct RefreshTimes {
+    last: Instant,
+    /// Time elapsed between the two last reads.
+    elapsed: Option<Duration>,
+}
+
+impl RefreshTimes {
+    pub(crate) fn new() -> Self {
+        Self {
+            last: Instant::now(),
+            elapsed: None,
+        }
+    }
+
+    /// Must be called every time the counters are read.
+    pub(crate) fn update(&mut self) {
+        let now = Instant::now();
+        self.elapsed = Some(now.saturating_duration_since(self.last));
+        self.last = now;
+    }
+
+    /// Returns the per-second rate of `delta`, which is the difference between the two last
+    /// reads of a counter.
+    pub(crate) fn rate(&self, delta: u64) -> f64 {
+        match self.elapsed {
+            Some(elapsed) if !elapsed.is_zero() => delta as f64 / elapsed.as_secs_f64(),
+            _ => 0.,
+        }
+    }
+}
+
+/// Returns the difference between two reads of a counter.
+///
+/// If `handle_wrap` is `true` and the counter went backwards while its previous value fitted in
+/// 32 bits, it is considered to be a 32-bit counter which wrapped around between the reads.
+/// Otherwise the counter was reset (for example by reloading the driver) and `0` is returned.
+pub(crate) fn counter_delta(old: u64, new: u64, handle_wrap: bool) -> u64 {
+    if new >= old {
+        new - old
+    } else if handle_wrap && old <= u64::from(u32::MAX) {
+        u64::from(u32::MAX) - old + new + 1
+    } else {
+        0
+    }
+}
 
 /// Interface addresses are OS-independent
 pub(crate) fn refresh_networks_addresses(interfaces: &mut HashMap<String, NetworkData>) {
@@ -19,4 +108,50 @@ pub(crate) fn refresh_networks_addresses(interfaces: &mut HashMap<String, Networ
             sysinfo_debug!("refresh_networks_addresses failed: {:?}", _e);
         }
     }
+    match get_interface_ip_networks() {
+        Ok(mut ip_networks) => {
+            for (name, interface) in interfaces.iter_mut() {
+                let mut networks = ip_networks.remove(name).unwrap_or_default();
+                networks.sort_unstable();
+                networks.dedup();
+                interface.inner.ip_networks = networks;
+            }
+        }
+        Err(_e) => {
+            sysinfo_debug!("get_interface_ip_networks failed: {:?}", _e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{counter_delta, RefreshTimes};
+
+    use std::time::Duration;
+
+    #[test]
+    fn refresh_times_rate() {
+        let mut times = RefreshTimes::new();
+        // No rate until the counters have been read twice.
+        assert_eq!(times.rate(1_000), 0.);
+        times.update();
+        times.elapsed = Some(Duration::from_millis(500));
+        assert_eq!(times.rate(1_000), 2_000.);
+        times.elapsed = Some(Duration::from_secs(4));
+        assert_eq!(times.rate(1_000), 250.);
+        times.elapsed = Some(Duration::ZERO);
+        assert_eq!(times.rate(1_000), 0.);
+    }
+
+    #[test]
+    fn counter_delta_wrap() {
+        assert_eq!(counter_delta(10, 25, true), 15);
+        assert_eq!(counter_delta(10, 25, false), 15);
+        // A 32-bit counter which wrapped around.
+        let old = u64::from(u32::MAX) - 4;
+        assert_eq!(counter_delta(old, 10, true), 15);
+        assert_eq!(counter_delta(old, 10, false), 0);
+        // A 64-bit counter can only go backwards if it was reset.
+        assert_eq!(counter_delta(u64::from(u32::MAX) + 1, 10, true), 0);
+    }
 }
@@ -0,0 +1,200 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{RefreshKind, System, SystemSnapshot};
+
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+// Prevents a zero interval from making the sampling thread spin.
+const MIN_INTERVAL: Duration = Duration::from_millis(10);
+
+struct Shared {
+    snapshot: Mutex<Arc<SystemSnapshot>>,
+    stopped: Mutex<bool>,
+    stop: Condvar,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Refreshes a [`System`] on its own thread and hands out snapshots of it.
+///
+/// Each [`RefreshKind`] is refreshed at its own interval, so for example the CPU usage can be
+/// updated every second while the processes are only updated every five seconds. After each
+/// refresh, a new [`SystemSnapshot`] is published: getting it with [`Sampler::snapshot`] only
+/// clones an [`Arc`], so it can be done as often as needed (from a UI thread for example).
+///
+/// The sampling thread is stopped when the `Sampler` is dropped, or with [`Sampler::stop`]
+/// which gives the `System` back.
+///
+/// ```no_run
+/// use sysinfo::{CpuRefreshKind, ProcessRefreshKind, RefreshKind, Sampler, System};
+/// use std::time::Duration;
+///
+/// let sampler = Sampler::new(
+///     System::new(),
+///     [
+///         (
+///             RefreshKind::new()
+///                 .with_cpu(CpuRefreshKind::everything())
+///                 .with_memory(),
+///             Duration::from_secs(1),
+///         ),
+///         (
+///             RefreshKind::new().with_processes(ProcessRefreshKind::everything()),
+///             Duration::from_secs(5),
+///         ),
+///     ],
+/// );
+///
+/// loop {
+///     let snapshot = sampler.snapshot();
+///     println!(
+///         "{}% CPU, {} processes",
+///         snapshot.global_cpu_info().cpu_usage(),
+///         snapshot.processes().len(),
+///     );
+///     std::thread::sleep(Duration::from_secs(1));
+/// }
+/// ```
+pub struct Sampler {
+    shared: Arc<Shared>,
+    thread: Option<JoinHandle<System>>,
+}
+
+impl Sampler {
+    /// Starts refreshing `system` on a new thread, with each [`RefreshKind`] of `schedule`
+    /// refreshed at its interval. They are all refreshed a first time right away.
+    ///
+    /// ⚠️ The CPU usage needs two refreshes to be computed, so it is only available after the
+    /// second refresh, and intervals shorter than
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL] give inaccurate
+    /// values.
+    pub fn new<I>(system: System, schedule: I) -> Self
+    where
+        I: IntoIterator<Item = (RefreshKind, Duration)>,
+    {
+        let now = Instant::now();
+        let schedule = schedule
+            .into_iter()
+            .map(|(kind, interval)| (kind, interval.max(MIN_INTERVAL), now))
+            .collect::<Vec<_>>();
+        let shared = Arc::new(Shared {
+            snapshot: Mutex::new(Arc::new(system.to_snapshot())),
+            stopped: Mutex::new(false),
+            stop: Condvar::new(),
+        });
+        let thread = std::thread::spawn({
+            let shared = Arc::clone(&shared);
+            move || run(system, schedule, &shared)
+        });
+        Self {
+            shared,
+            thread: Some(thread),
+        }
+    }
+
+    /// Returns the snapshot taken after the last refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::{RefreshKind, Sampler, System};
+    /// use std::time::Duration;
+    ///
+    /// let sampler = Sampler::new(
+    ///     System::new(),
+    ///     [(RefreshKind::new().with_memory(), Duration::from_secs(1))],
+    /// );
+    /// std::thread::sleep(Duration::from_secs(1));
+    /// println!("used memory: {} bytes", sampler.snapshot().used_memory());
+    /// ```
+    pub fn snapshot(&self) -> Arc<SystemSnapshot> {
+        Arc::clone(&lock(&self.shared.snapshot))
+    }
+
+    /// Stops the sampling thread and returns the `System` it was refreshing.
+    ///
+    /// It waits for the current refresh to be done if there is one.
+    ///
+    /// ```no_run
+    /// use sysinfo::{RefreshKind, Sampler, System};
+    /// use std::time::Duration;
+    ///
+    /// let sampler = Sampler::new(
+    ///     System::new(),
+    ///     [(RefreshKind::new().with_memory(), Duration::from_secs(1))],
+    /// );
+    /// let system = sampler.stop();
+    /// println!("total memory: {} bytes", system.total_memory());
+    /// ```
+    pub fn stop(mut self) -> System {
+        self.stop_thread().unwrap_or_default()
+    }
+
+    fn stop_thread(&mut self) -> Option<System> {
+        *lock(&self.shared.stopped) = true;
+        self.shared.stop.notify_all();
+        match self.thread.take()?.join() {
+            Ok(system) => Some(system),
+            Err(_) => {
+                sysinfo_debug!("the sampling thread panicked");
+                None
+            }
+        }
+    }
+}
+
+impl Drop for Sampler {
+    fn drop(&mut self) {
+        self.stop_thread();
+    }
+}
+
+fn run(
+    mut system: System,
+    mut schedule: Vec<(RefreshKind, Duration, Instant)>,
+    shared: &Shared,
+) -> System {
+    loop {
+        let now = Instant::now();
+        let mut refreshed = false;
+        for (kind, interval, next) in schedule.iter_mut() {
+            if *next > now {
+                continue;
+            }
+            system.refresh_specifics(*kind);
+            refreshed = true;
+            *next += *interval;
+            // If a refresh took longer than the interval, the missed ones are skipped.
+            if *next <= now {
+                *next = now + *interval;
+            }
+        }
+        if refreshed {
+            *lock(&shared.snapshot) = Arc::new(system.to_snapshot());
+        }
+
+        let next = schedule.iter().map(|(_, _, next)| *next).min();
+        let mut stopped = lock(&shared.stopped);
+        loop {
+            if *stopped {
+                return system;
+            }
+            stopped = match next {
+                Some(next) => {
+                    let remaining = next.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        break;
+                    }
+                    shared
+                        .stop
+                        .wait_timeout(stopped, remaining)
+                        .map(|(stopped, _)| stopped)
+                        .unwrap_or_else(|e| e.into_inner().0)
+                }
+                None => shared.stop.wait(stopped).unwrap_or_else(|e| e.into_inner()),
+            };
+        }
+    }
+}
@@ -1,14 +1,19 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use serde::{ser::SerializeStruct, Serialize, Serializer};
+use serde::de::{self, DeserializeSeed, Deserializer, Unexpected};
+use serde::ser::{SerializeStruct, SerializeStructVariant};
+use serde::{Deserialize, Serialize, Serializer};
+
+use std::convert::TryFrom;
+use std::fmt;
 
 impl Serialize for crate::Disk {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // `7` corresponds to the (maximum) number of fields.
-        let mut state = serializer.serialize_struct("Disk", 7)?;
+        // `21` corresponds to the (maximum) number of fields.
+        let mut state = serializer.serialize_struct("Disk", 21)?;
 
         state.serialize_field("DiskKind", &self.kind())?;
         if let Some(s) = self.name().to_str() {
@@ -19,11 +24,148 @@ impl Serialize for crate::Disk {
         state.serialize_field("total_space", &self.total_space())?;
         state.serialize_field("available_space", &self.available_space())?;
         state.serialize_field("is_removable", &self.is_removable())?;
+        state.serialize_field("vendor", &self.vendor())?;
+        state.serialize_field("model", &self.model())?;
+        state.serialize_field("serial_number", &self.serial_number())?;
+        state.serialize_field("physical_disk", &self.physical_disk())?;
+        state.serialize_field("storage_pool", &self.storage_pool())?;
+        state.serialize_field("is_network", &self.is_network())?;
+        state.serialize_field("remote_host", &self.remote_host())?;
+        state.serialize_field("remote_path", &self.remote_path())?;
+        state.serialize_field("uuid", &self.uuid())?;
+        state.serialize_field("block_device", &self.block_device())?;
+        state.serialize_field("is_rotational", &self.is_rotational())?;
+        state.serialize_field("logical_sector_size", &self.logical_sector_size())?;
+        state.serialize_field("physical_sector_size", &self.physical_sector_size())?;
+        state.serialize_field("supports_trim", &self.supports_trim())?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::PhysicalDisk {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("PhysicalDisk", 4)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field("total_space", &self.total_space)?;
+        state.serialize_field("is_removable", &self.is_removable)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::StoragePool {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `8` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("StoragePool", 8)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field("health", &self.health)?;
+        state.serialize_field("devices", &self.devices)?;
+        state.serialize_field("total_space", &self.total_space)?;
+        state.serialize_field("allocated_space", &self.allocated_space)?;
+        state.serialize_field("usable_space", &self.usable_space)?;
+        state.serialize_field("used_space", &self.used_space)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::StoragePoolKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::ZFS => (0, "ZFS"),
+            Self::Btrfs => (1, "Btrfs"),
+        };
+
+        serializer.serialize_unit_variant("StoragePoolKind", index, variant)
+    }
+}
+
+impl Serialize for crate::StoragePoolHealth {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Online => (0, "Online"),
+            Self::Degraded => (1, "Degraded"),
+            Self::Faulted => (2, "Faulted"),
+            Self::Offline => (3, "Offline"),
+            Self::Unavailable => (4, "Unavailable"),
+            Self::Removed => (5, "Removed"),
+            Self::Suspended => (6, "Suspended"),
+            Self::Unknown => (7, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("StoragePoolHealth", index, variant)
+    }
+}
+
+impl Serialize for crate::DiskQuota {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `6` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("DiskQuota", 6)?;
+
+        state.serialize_field("used_space", &self.used_space)?;
+        state.serialize_field("soft_space_limit", &self.soft_space_limit)?;
+        state.serialize_field("hard_space_limit", &self.hard_space_limit)?;
+        state.serialize_field("used_inodes", &self.used_inodes)?;
+        state.serialize_field("soft_inode_limit", &self.soft_inode_limit)?;
+        state.serialize_field("hard_inode_limit", &self.hard_inode_limit)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::BlockDevice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("BlockDevice", 3)?;
 
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field("backing_devices", &self.backing_devices)?;
         state.end()
     }
 }
 
+impl Serialize for crate::BlockDeviceKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Disk => (0, "Disk"),
+            Self::Partition => (1, "Partition"),
+            Self::Crypt => (2, "Crypt"),
+            Self::Lvm => (3, "Lvm"),
+            Self::Raid => (4, "Raid"),
+            Self::DeviceMapper => (5, "DeviceMapper"),
+            Self::Loop => (6, "Loop"),
+            Self::Unknown => (7, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("BlockDeviceKind", index, variant)
+    }
+}
+
 impl Serialize for crate::Gid {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -56,8 +198,8 @@ impl Serialize for crate::Process {
     where
         S: Serializer,
     {
-        // `19` corresponds to the (maximum) number of fields.
-        let mut state = serializer.serialize_struct("Process", 19)?;
+        // `20` corresponds to the (maximum) number of fields.
+        let mut state = serializer.serialize_struct("Process", 20)?;
 
         state.serialize_field("name", &self.name())?;
         state.serialize_field("cmd", &self.cmd())?;
@@ -73,6 +215,7 @@ impl Serialize for crate::Process {
         state.serialize_field("start_time", &self.start_time())?;
         state.serialize_field("run_time", &self.run_time())?;
         state.serialize_field("cpu_usage", &self.cpu_usage())?;
+        state.serialize_field("normalized_cpu_usage", &self.normalized_cpu_usage())?;
         state.serialize_field("disk_usage", &self.disk_usage())?;
         state.serialize_field("user_id", &self.user_id())?;
         state.serialize_field("group_id", &self.group_id())?;
@@ -87,14 +230,26 @@ impl Serialize for crate::Cpu {
     where
         S: Serializer,
     {
-        // `5` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("Cpu", 5)?;
+        // `17` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Cpu", 17)?;
 
         state.serialize_field("cpu_usage", &self.cpu_usage())?;
+        state.serialize_field("steal_percent", &self.steal_percent())?;
         state.serialize_field("name", &self.name())?;
         state.serialize_field("vendor_id", &self.vendor_id())?;
         state.serialize_field("brand", &self.brand())?;
+        state.serialize_field("family", &self.family())?;
+        state.serialize_field("model", &self.model())?;
+        state.serialize_field("stepping", &self.stepping())?;
+        state.serialize_field("microcode_revision", &self.microcode_revision())?;
         state.serialize_field("frequency", &self.frequency())?;
+        state.serialize_field("governor", &self.governor())?;
+        state.serialize_field("available_governors", &self.available_governors())?;
+        state.serialize_field("scaling_min_frequency", &self.scaling_min_frequency())?;
+        state.serialize_field("scaling_max_frequency", &self.scaling_max_frequency())?;
+        state.serialize_field("temperature", &self.temperature())?;
+        state.serialize_field("idle_states", &self.idle_states())?;
+        state.serialize_field("times", &self.times())?;
 
         state.end()
     }
@@ -105,24 +260,39 @@ impl serde::Serialize for crate::System {
     where
         S: serde::Serializer,
     {
-        // `19` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("System", 19)?;
+        // `31` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("System", 31)?;
 
         state.serialize_field("global_cpu_info", &self.global_cpu_info())?;
         state.serialize_field("cpus", &self.cpus())?;
+        state.serialize_field("cpu_activity", &self.cpu_activity())?;
 
         state.serialize_field("physical_core_count", &self.physical_core_count())?;
+        state.serialize_field("socket_count", &self.socket_count())?;
         state.serialize_field("total_memory", &self.total_memory())?;
         state.serialize_field("free_memory", &self.free_memory())?;
         state.serialize_field("available_memory", &self.available_memory())?;
+        state.serialize_field(
+            "available_memory_breakdown",
+            &self.available_memory_breakdown(),
+        )?;
         state.serialize_field("used_memory", &self.used_memory())?;
         state.serialize_field("total_swap", &self.total_swap())?;
         state.serialize_field("free_swap", &self.free_swap())?;
         state.serialize_field("used_swap", &self.used_swap())?;
+        state.serialize_field("memory_breakdown", &self.memory_breakdown())?;
+        state.serialize_field("commit_charge", &self.commit_charge())?;
+        state.serialize_field("compressed_memory", &self.compressed_memory())?;
+        state.serialize_field("memory_pressure_level", &self.memory_pressure_level())?;
+        state.serialize_field("zram_devices", &self.zram_devices())?;
+        state.serialize_field("ksm", &self.ksm())?;
+        state.serialize_field("balloon_memory", &self.balloon_memory())?;
+        state.serialize_field("oom_kills", &self.oom_kills())?;
 
         state.serialize_field("uptime", &self.uptime())?;
         state.serialize_field("boot_time", &self.boot_time())?;
         state.serialize_field("load_average", &self.load_average())?;
+        state.serialize_field("pressure", &self.pressure())?;
         state.serialize_field("name", &self.name())?;
         state.serialize_field("kernel_version", &self.kernel_version())?;
         state.serialize_field("os_version", &self.os_version())?;
@@ -139,12 +309,14 @@ impl Serialize for crate::CGroupLimits {
     where
         S: Serializer,
     {
-        // `3` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("CGroupLimits", 3)?;
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CGroupLimits", 5)?;
 
         state.serialize_field("total_memory", &self.total_memory)?;
         state.serialize_field("free_memory", &self.free_memory)?;
         state.serialize_field("free_swap", &self.free_swap)?;
+        state.serialize_field("cpu_quota", &self.cpu_quota)?;
+        state.serialize_field("cpus", &self.cpus)?;
 
         state.end()
     }
@@ -186,216 +358,2512 @@ impl Serialize for crate::Users {
     }
 }
 
-impl Serialize for crate::Signal {
+impl Serialize for crate::Connections {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let (index, variant) = match *self {
-            Self::Hangup => (0, "Hangup"),
-            Self::Interrupt => (1, "Interrupt"),
-            Self::Quit => (2, "Quit"),
-            Self::Illegal => (3, "Illegal"),
-            Self::Trap => (4, "Trap"),
-            Self::Abort => (5, "Abort"),
-            Self::IOT => (6, "IOT"),
-            Self::Bus => (7, "Bus"),
-            Self::FloatingPointException => (8, "FloatingPointException"),
-            Self::Kill => (9, "Kill"),
-            Self::User1 => (10, "User1"),
-            Self::Segv => (11, "Segv"),
-            Self::User2 => (12, "User2"),
-            Self::Pipe => (13, "Pipe"),
-            Self::Alarm => (14, "Alarm"),
-            Self::Term => (15, "Term"),
-            Self::Child => (16, "Child"),
-            Self::Continue => (17, "Continue"),
-            Self::Stop => (18, "Stop"),
-            Self::TSTP => (19, "TSTP"),
-            Self::TTIN => (20, "TTIN"),
-            Self::TTOU => (21, "TTOU"),
-            Self::Urgent => (22, "Urgent"),
-            Self::XCPU => (23, "XCPU"),
-            Self::XFSZ => (24, "XFSZ"),
-            Self::VirtualAlarm => (25, "VirtualAlarm"),
-            Self::Profiling => (26, "Profiling"),
-            Self::Winch => (27, "Winch"),
-            Self::IO => (28, "IO"),
-            Self::Poll => (29, "Poll"),
-            Self::Power => (30, "Power"),
-            Self::Sys => (31, "Sys"),
-        };
+        serializer.collect_seq(self.iter())
+    }
+}
 
-        serializer.serialize_unit_variant("Signal", index, variant)
+impl Serialize for crate::Connection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `6` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Connection", 6)?;
+
+        state.serialize_field("protocol", &self.protocol)?;
+        state.serialize_field("local_address", &self.local_address)?;
+        state.serialize_field("remote_address", &self.remote_address)?;
+        state.serialize_field("state", &self.state)?;
+        state.serialize_field("pid", &self.pid)?;
+        state.serialize_field("traffic", &self.traffic)?;
+
+        state.end()
     }
 }
 
-impl Serialize for crate::LoadAvg {
+impl Serialize for crate::ConnectionTraffic {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         // `3` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("LoadAvg", 3)?;
+        let mut state = serializer.serialize_struct("ConnectionTraffic", 3)?;
+
+        state.serialize_field("bytes_sent", &self.bytes_sent)?;
+        state.serialize_field("bytes_received", &self.bytes_received)?;
+        state.serialize_field("retransmitted_segments", &self.retransmitted_segments)?;
 
-        state.serialize_field("one", &self.one)?;
-        state.serialize_field("five", &self.five)?;
-        state.serialize_field("fifteen", &self.fifteen)?;
         state.end()
     }
 }
 
-impl Serialize for crate::NetworkData {
+impl Serialize for crate::ConnectionSummary {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // `13` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("NetworkData", 13)?;
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ConnectionSummary", 5)?;
 
-        state.serialize_field("received", &self.received())?;
-        state.serialize_field("total_received", &self.total_received())?;
-        state.serialize_field("transmitted", &self.transmitted())?;
-        state.serialize_field("total_transmitted", &self.total_transmitted())?;
-        state.serialize_field("packets_received", &self.packets_received())?;
-        state.serialize_field("total_packets_received", &self.total_packets_received())?;
-        state.serialize_field("packets_transmitted", &self.packets_transmitted())?;
-        state.serialize_field(
-            "total_packets_transmitted",
-            &self.total_packets_transmitted(),
-        )?;
-        state.serialize_field("errors_on_received", &self.errors_on_received())?;
-        state.serialize_field("total_errors_on_received", &self.total_errors_on_received())?;
-        state.serialize_field("errors_on_transmitted", &self.errors_on_transmitted())?;
-        state.serialize_field(
-            "total_errors_on_transmitted",
-            &self.total_errors_on_transmitted(),
-        )?;
-        state.serialize_field("mac_address", &self.mac_address())?;
+        state.serialize_field("tcp_established", &self.tcp_established)?;
+        state.serialize_field("tcp_listening", &self.tcp_listening)?;
+        state.serialize_field("tcp_time_wait", &self.tcp_time_wait)?;
+        state.serialize_field("tcp_total", &self.tcp_total)?;
+        state.serialize_field("udp", &self.udp)?;
 
         state.end()
     }
 }
 
-impl Serialize for crate::Component {
+impl Serialize for crate::ConnectionProtocol {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // `4` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("Component", 4)?;
+        let (index, variant) = match *self {
+            Self::Tcp => (0, "Tcp"),
+            Self::Udp => (1, "Udp"),
+        };
 
-        state.serialize_field("temperature", &self.temperature())?;
-        state.serialize_field("max", &self.max())?;
-        state.serialize_field("critical", &self.critical())?;
-        state.serialize_field("label", &self.label())?;
+        serializer.serialize_unit_variant("ConnectionProtocol", index, variant)
+    }
+}
 
-        state.end()
+impl Serialize for crate::TcpState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Listen => (0, "Listen"),
+            Self::SynSent => (1, "SynSent"),
+            Self::SynReceived => (2, "SynReceived"),
+            Self::Established => (3, "Established"),
+            Self::FinWait1 => (4, "FinWait1"),
+            Self::FinWait2 => (5, "FinWait2"),
+            Self::CloseWait => (6, "CloseWait"),
+            Self::Closing => (7, "Closing"),
+            Self::LastAck => (8, "LastAck"),
+            Self::TimeWait => (9, "TimeWait"),
+            Self::Closed => (10, "Closed"),
+        };
+
+        serializer.serialize_unit_variant("TcpState", index, variant)
     }
 }
 
-impl Serialize for crate::User {
+impl Serialize for crate::Batteries {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // `4` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("User", 4)?;
+        serializer.collect_seq(self.iter())
+    }
+}
 
-        state.serialize_field("id", &self.id())?;
-        state.serialize_field("group_id", &self.group_id())?;
-        state.serialize_field("name", &self.name())?;
-        state.serialize_field("groups", &self.groups())?;
+impl Serialize for crate::Battery {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `9` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Battery", 9)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("state", &self.state)?;
+        state.serialize_field("charge", &self.charge)?;
+        state.serialize_field("energy", &self.energy)?;
+        state.serialize_field("full_capacity", &self.full_capacity)?;
+        state.serialize_field("design_capacity", &self.design_capacity)?;
+        state.serialize_field("cycle_count", &self.cycle_count)?;
+        state.serialize_field("time_to_empty", &self.time_to_empty)?;
+        state.serialize_field("time_to_full", &self.time_to_full)?;
 
         state.end()
     }
 }
 
-impl Serialize for crate::Group {
+impl Serialize for crate::BatteryState {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // `2` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("Group", 2)?;
+        let (index, variant) = match *self {
+            Self::Charging => (0, "Charging"),
+            Self::Discharging => (1, "Discharging"),
+            Self::Full => (2, "Full"),
+            Self::NotCharging => (3, "NotCharging"),
+            Self::Unknown => (4, "Unknown"),
+        };
 
-        state.serialize_field("id", &self.id())?;
-        state.serialize_field("name", &self.name())?;
+        serializer.serialize_unit_variant("BatteryState", index, variant)
+    }
+}
+
+impl Serialize for crate::ThermalZones {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl Serialize for crate::ThermalZone {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ThermalZone", 4)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field("temperature", &self.temperature)?;
+        state.serialize_field("trip_points", &self.trip_points)?;
 
         state.end()
     }
 }
 
-impl Serialize for crate::DiskKind {
+impl Serialize for crate::TripPoint {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let (index, variant, maybe_value) = match *self {
-            Self::HDD => (0, "HDD", None),
-            Self::SSD => (1, "SSD", None),
-            Self::Unknown(ref s) => (2, "Unknown", Some(s)),
-        };
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("TripPoint", 4)?;
 
-        if let Some(ref value) = maybe_value {
-            serializer.serialize_newtype_variant("DiskKind", index, variant, value)
-        } else {
-            serializer.serialize_unit_variant("DiskKind", index, variant)
-        }
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field("temperature", &self.temperature)?;
+        state.serialize_field("hysteresis", &self.hysteresis)?;
+        state.serialize_field("cooling_devices", &self.cooling_devices)?;
+
+        state.end()
     }
 }
 
-impl Serialize for crate::ProcessStatus {
+impl Serialize for crate::TripPointKind {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let (index, variant, maybe_value) = match *self {
-            Self::Idle => (0, "Idle", None),
-            Self::Run => (1, "Run", None),
-            Self::Sleep => (2, "Sleep", None),
-            Self::Stop => (3, "Stop", None),
-            Self::Zombie => (4, "Zombie", None),
-            Self::Tracing => (5, "Tracing", None),
-            Self::Dead => (6, "Dead", None),
-            Self::Wakekill => (7, "Wakekill", None),
-            Self::Waking => (8, "Waking", None),
-            Self::Parked => (9, "Parked", None),
-            Self::LockBlocked => (10, "LockBlocked", None),
-            Self::UninterruptibleDiskSleep => (11, "UninterruptibleDiskSleep", None),
-            Self::Unknown(n) => (12, "Unknown", Some(n)),
+        let (index, variant) = match *self {
+            Self::Active => (0, "Active"),
+            Self::Passive => (1, "Passive"),
+            Self::Hot => (2, "Hot"),
+            Self::Critical => (3, "Critical"),
         };
 
-        if let Some(ref value) = maybe_value {
-            serializer.serialize_newtype_variant("ProcessStatus", index, variant, value)
-        } else {
-            serializer.serialize_unit_variant("ProcessStatus", index, variant)
-        }
+        serializer.serialize_unit_variant("TripPointKind", index, variant)
     }
 }
 
-impl Serialize for crate::DiskUsage {
+impl Serialize for crate::CoolingDevice {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // `4` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("DiskUsage", 4)?;
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CoolingDevice", 3)?;
 
-        state.serialize_field("total_written_bytes", &self.total_written_bytes)?;
-        state.serialize_field("written_bytes", &self.written_bytes)?;
-        state.serialize_field("total_read_bytes", &self.total_read_bytes)?;
-        state.serialize_field("read_bytes", &self.read_bytes)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("state", &self.state)?;
+        state.serialize_field("max_state", &self.max_state)?;
 
         state.end()
     }
 }
 
-impl Serialize for crate::MacAddr {
+impl Serialize for crate::Gpus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_newtype_struct("MacAddr", &self.0)
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl Serialize for crate::Gpu {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `7` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Gpu", 7)?;
+
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("driver_version", &self.driver_version())?;
+        state.serialize_field("usage", &self.usage())?;
+        state.serialize_field("total_memory", &self.total_memory())?;
+        state.serialize_field("used_memory", &self.used_memory())?;
+        state.serialize_field("temperature", &self.temperature())?;
+        state.serialize_field("power", &self.power())?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::Containers {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl Serialize for crate::Container {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `6` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Container", 6)?;
+
+        state.serialize_field("id", &self.id())?;
+        state.serialize_field("runtime", &self.runtime())?;
+        state.serialize_field("cpu_usage", &self.cpu_usage())?;
+        state.serialize_field("memory", &self.memory())?;
+        state.serialize_field("memory_limit", &self.memory_limit())?;
+        state.serialize_field("pids", &self.pids())?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::PciDevices {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl Serialize for crate::PciDevice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `9` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("PciDevice", 9)?;
+
+        state.serialize_field("address", &self.address)?;
+        state.serialize_field("class", &self.class)?;
+        state.serialize_field("vendor_id", &self.vendor_id)?;
+        state.serialize_field("device_id", &self.device_id)?;
+        state.serialize_field("vendor_name", &self.vendor_name)?;
+        state.serialize_field("device_name", &self.device_name)?;
+        state.serialize_field("driver", &self.driver)?;
+        state.serialize_field("link_width", &self.link_width)?;
+        state.serialize_field("link_speed", &self.link_speed)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::Services {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl Serialize for crate::Service {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `6` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Service", 6)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("description", &self.description)?;
+        state.serialize_field("state", &self.state)?;
+        state.serialize_field("start_kind", &self.start_kind)?;
+        state.serialize_field("command", &self.command)?;
+        state.serialize_field("pid", &self.pid)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::ServiceState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Running => (0, "Running"),
+            Self::Stopped => (1, "Stopped"),
+            Self::Starting => (2, "Starting"),
+            Self::Stopping => (3, "Stopping"),
+            Self::Paused => (4, "Paused"),
+            Self::Unknown => (5, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("ServiceState", index, variant)
+    }
+}
+
+impl Serialize for crate::ServiceStartKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Automatic => (0, "Automatic"),
+            Self::Manual => (1, "Manual"),
+            Self::Disabled => (2, "Disabled"),
+            Self::Unknown => (3, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("ServiceStartKind", index, variant)
+    }
+}
+
+impl Serialize for crate::Neighbors {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl Serialize for crate::Neighbor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Neighbor", 4)?;
+
+        state.serialize_field("ip_address", &self.ip_address)?;
+        state.serialize_field("mac_address", &self.mac_address)?;
+        state.serialize_field("state", &self.state)?;
+        state.serialize_field("interface", &self.interface)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::NeighborState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Incomplete => (0, "Incomplete"),
+            Self::Reachable => (1, "Reachable"),
+            Self::Stale => (2, "Stale"),
+            Self::Delay => (3, "Delay"),
+            Self::Probe => (4, "Probe"),
+            Self::Failed => (5, "Failed"),
+            Self::Permanent => (6, "Permanent"),
+            Self::Unknown => (7, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("NeighborState", index, variant)
+    }
+}
+
+impl Serialize for crate::Signal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Hangup => (0, "Hangup"),
+            Self::Interrupt => (1, "Interrupt"),
+            Self::Quit => (2, "Quit"),
+            Self::Illegal => (3, "Illegal"),
+            Self::Trap => (4, "Trap"),
+            Self::Abort => (5, "Abort"),
+            Self::IOT => (6, "IOT"),
+            Self::Bus => (7, "Bus"),
+            Self::FloatingPointException => (8, "FloatingPointException"),
+            Self::Kill => (9, "Kill"),
+            Self::User1 => (10, "User1"),
+            Self::Segv => (11, "Segv"),
+            Self::User2 => (12, "User2"),
+            Self::Pipe => (13, "Pipe"),
+            Self::Alarm => (14, "Alarm"),
+            Self::Term => (15, "Term"),
+            Self::Child => (16, "Child"),
+            Self::Continue => (17, "Continue"),
+            Self::Stop => (18, "Stop"),
+            Self::TSTP => (19, "TSTP"),
+            Self::TTIN => (20, "TTIN"),
+            Self::TTOU => (21, "TTOU"),
+            Self::Urgent => (22, "Urgent"),
+            Self::XCPU => (23, "XCPU"),
+            Self::XFSZ => (24, "XFSZ"),
+            Self::VirtualAlarm => (25, "VirtualAlarm"),
+            Self::Profiling => (26, "Profiling"),
+            Self::Winch => (27, "Winch"),
+            Self::IO => (28, "IO"),
+            Self::Poll => (29, "Poll"),
+            Self::Power => (30, "Power"),
+            Self::Sys => (31, "Sys"),
+        };
+
+        serializer.serialize_unit_variant("Signal", index, variant)
+    }
+}
+
+impl Serialize for crate::LoadAvg {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("LoadAvg", 3)?;
+
+        state.serialize_field("one", &self.one)?;
+        state.serialize_field("five", &self.five)?;
+        state.serialize_field("fifteen", &self.fifteen)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::Pressure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Pressure", 3)?;
+
+        state.serialize_field("cpu", &self.cpu)?;
+        state.serialize_field("memory", &self.memory)?;
+        state.serialize_field("io", &self.io)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::PressureStats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("PressureStats", 2)?;
+
+        state.serialize_field("some", &self.some)?;
+        state.serialize_field("full", &self.full)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::PressureValues {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("PressureValues", 4)?;
+
+        state.serialize_field("avg10", &self.avg10)?;
+        state.serialize_field("avg60", &self.avg60)?;
+        state.serialize_field("avg300", &self.avg300)?;
+        state.serialize_field("total", &self.total)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::MemoryBreakdown {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `8` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("MemoryBreakdown", 8)?;
+
+        state.serialize_field("buffers", &self.buffers)?;
+        state.serialize_field("cached", &self.cached)?;
+        state.serialize_field("shared", &self.shared)?;
+        state.serialize_field("slab_reclaimable", &self.slab_reclaimable)?;
+        state.serialize_field("slab_unreclaimable", &self.slab_unreclaimable)?;
+        state.serialize_field("mapped", &self.mapped)?;
+        state.serialize_field("dirty", &self.dirty)?;
+        state.serialize_field("writeback", &self.writeback)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::AvailableMemory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("AvailableMemory", 4)?;
+
+        state.serialize_field("free", &self.free)?;
+        state.serialize_field("reclaimable", &self.reclaimable)?;
+        state.serialize_field("available", &self.available)?;
+        state.serialize_field("source", &self.source)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::AvailableMemorySource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Kernel => (0, "Kernel"),
+            Self::Computed => (1, "Computed"),
+        };
+
+        serializer.serialize_unit_variant("AvailableMemorySource", index, variant)
+    }
+}
+
+impl Serialize for crate::Ksm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Ksm", 4)?;
+
+        state.serialize_field("running", &self.running)?;
+        state.serialize_field("shared", &self.shared)?;
+        state.serialize_field("saved", &self.saved)?;
+        state.serialize_field("unshared", &self.unshared)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::CommitCharge {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CommitCharge", 3)?;
+
+        state.serialize_field("total", &self.total)?;
+        state.serialize_field("limit", &self.limit)?;
+        state.serialize_field("peak", &self.peak)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::CompressedMemory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CompressedMemory", 2)?;
+
+        state.serialize_field("uncompressed", &self.uncompressed)?;
+        state.serialize_field("compressed", &self.compressed)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::ZramDevice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `6` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ZramDevice", 6)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("algorithm", &self.algorithm)?;
+        state.serialize_field("disk_size", &self.disk_size)?;
+        state.serialize_field("uncompressed", &self.uncompressed)?;
+        state.serialize_field("compressed", &self.compressed)?;
+        state.serialize_field("memory_used", &self.memory_used)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::MemoryModule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `8` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("MemoryModule", 8)?;
+
+        state.serialize_field("locator", &self.locator)?;
+        state.serialize_field("bank_locator", &self.bank_locator)?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field("size", &self.size)?;
+        state.serialize_field("speed", &self.speed)?;
+        state.serialize_field("manufacturer", &self.manufacturer)?;
+        state.serialize_field("serial_number", &self.serial_number)?;
+        state.serialize_field("part_number", &self.part_number)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::MemoryModuleKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::DRAM => (0, "DRAM"),
+            Self::SDRAM => (1, "SDRAM"),
+            Self::DDR => (2, "DDR"),
+            Self::DDR2 => (3, "DDR2"),
+            Self::DDR3 => (4, "DDR3"),
+            Self::DDR4 => (5, "DDR4"),
+            Self::DDR5 => (6, "DDR5"),
+            Self::LPDDR => (7, "LPDDR"),
+            Self::LPDDR2 => (8, "LPDDR2"),
+            Self::LPDDR3 => (9, "LPDDR3"),
+            Self::LPDDR4 => (10, "LPDDR4"),
+            Self::LPDDR5 => (11, "LPDDR5"),
+            Self::Unknown(ref value) => {
+                return serializer.serialize_newtype_variant(
+                    "MemoryModuleKind",
+                    12,
+                    "Unknown",
+                    value,
+                )
+            }
+        };
+
+        serializer.serialize_unit_variant("MemoryModuleKind", index, variant)
+    }
+}
+
+impl Serialize for crate::HardwareInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `11` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("HardwareInfo", 11)?;
+
+        state.serialize_field("manufacturer", &self.manufacturer)?;
+        state.serialize_field("product_name", &self.product_name)?;
+        state.serialize_field("product_version", &self.product_version)?;
+        state.serialize_field("serial_number", &self.serial_number)?;
+        state.serialize_field("board_vendor", &self.board_vendor)?;
+        state.serialize_field("board_name", &self.board_name)?;
+        state.serialize_field("board_serial_number", &self.board_serial_number)?;
+        state.serialize_field("bios_vendor", &self.bios_vendor)?;
+        state.serialize_field("bios_version", &self.bios_version)?;
+        state.serialize_field("bios_release_date", &self.bios_release_date)?;
+        state.serialize_field("chassis", &self.chassis)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::ChassisKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Desktop => (0, "Desktop"),
+            Self::Laptop => (1, "Laptop"),
+            Self::Tablet => (2, "Tablet"),
+            Self::AllInOne => (3, "AllInOne"),
+            Self::Server => (4, "Server"),
+            Self::VirtualMachine => (5, "VirtualMachine"),
+            Self::Unknown => (6, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("ChassisKind", index, variant)
+    }
+}
+
+impl Serialize for crate::Virtualization {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Virtualization", 2)?;
+
+        state.serialize_field("hypervisor", &self.hypervisor)?;
+        state.serialize_field("container", &self.container)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::Hypervisor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Kvm => (0, "Kvm"),
+            Self::Qemu => (1, "Qemu"),
+            Self::HyperV => (2, "HyperV"),
+            Self::VMware => (3, "VMware"),
+            Self::VirtualBox => (4, "VirtualBox"),
+            Self::Xen => (5, "Xen"),
+            Self::Parallels => (6, "Parallels"),
+            Self::Bhyve => (7, "Bhyve"),
+            Self::Apple => (8, "Apple"),
+            Self::Other(ref vendor) => {
+                return serializer.serialize_newtype_variant("Hypervisor", 9, "Other", vendor)
+            }
+        };
+
+        serializer.serialize_unit_variant("Hypervisor", index, variant)
+    }
+}
+
+impl Serialize for crate::ContainerRuntime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Docker => (0, "Docker"),
+            Self::Podman => (1, "Podman"),
+            Self::Lxc => (2, "Lxc"),
+            Self::SystemdNspawn => (3, "SystemdNspawn"),
+            Self::Wsl => (4, "Wsl"),
+            Self::Jail => (5, "Jail"),
+            Self::Other(ref name) => {
+                return serializer.serialize_newtype_variant("ContainerRuntime", 6, "Other", name)
+            }
+        };
+
+        serializer.serialize_unit_variant("ContainerRuntime", index, variant)
+    }
+}
+
+impl Serialize for crate::Entropy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Entropy", 3)?;
+
+        state.serialize_field("ready", &self.ready)?;
+        state.serialize_field("available", &self.available)?;
+        state.serialize_field("pool_size", &self.pool_size)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::FileHandles {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("FileHandles", 2)?;
+
+        state.serialize_field("allocated", &self.allocated)?;
+        state.serialize_field("limit", &self.limit)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::SystemCounts {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("SystemCounts", 3)?;
+
+        state.serialize_field("processes", &self.processes)?;
+        state.serialize_field("threads", &self.threads)?;
+        state.serialize_field("handles", &self.handles)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::OsRelease {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `11` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("OsRelease", 11)?;
+
+        state.serialize_field("id", &self.id)?;
+        state.serialize_field("id_like", &self.id_like)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("pretty_name", &self.pretty_name)?;
+        state.serialize_field("version", &self.version)?;
+        state.serialize_field("version_id", &self.version_id)?;
+        state.serialize_field("version_codename", &self.version_codename)?;
+        state.serialize_field("variant", &self.variant)?;
+        state.serialize_field("variant_id", &self.variant_id)?;
+        state.serialize_field("build_id", &self.build_id)?;
+        state.serialize_field("fields", &self.fields)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::WindowsVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `11` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("WindowsVersion", 11)?;
+
+        state.serialize_field("major", &self.major)?;
+        state.serialize_field("minor", &self.minor)?;
+        state.serialize_field("build", &self.build)?;
+        state.serialize_field("revision", &self.revision)?;
+        state.serialize_field("display_version", &self.display_version)?;
+        state.serialize_field("edition", &self.edition)?;
+        state.serialize_field("service_pack", &self.service_pack)?;
+        state.serialize_field("service_pack_major", &self.service_pack_major)?;
+        state.serialize_field("service_pack_minor", &self.service_pack_minor)?;
+        state.serialize_field("suite_mask", &self.suite_mask)?;
+        state.serialize_field("product_type", &self.product_type)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::TimeZone {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("TimeZone", 2)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("utc_offset", &self.utc_offset)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::KernelParameter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Self::Integer(ref value) => {
+                serializer.serialize_newtype_variant("KernelParameter", 0, "Integer", value)
+            }
+            Self::Integers(ref values) => {
+                serializer.serialize_newtype_variant("KernelParameter", 1, "Integers", values)
+            }
+            Self::String(ref value) => {
+                serializer.serialize_newtype_variant("KernelParameter", 2, "String", value)
+            }
+        }
+    }
+}
+
+impl Serialize for crate::MemoryPressureLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Normal => (0, "Normal"),
+            Self::Warning => (1, "Warning"),
+            Self::Critical => (2, "Critical"),
+        };
+
+        serializer.serialize_unit_variant("MemoryPressureLevel", index, variant)
+    }
+}
+
+impl Serialize for crate::CpuTimes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `10` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CpuTimes", 10)?;
+
+        state.serialize_field("user", &self.user)?;
+        state.serialize_field("nice", &self.nice)?;
+        state.serialize_field("system", &self.system)?;
+        state.serialize_field("idle", &self.idle)?;
+        state.serialize_field("iowait", &self.iowait)?;
+        state.serialize_field("irq", &self.irq)?;
+        state.serialize_field("softirq", &self.softirq)?;
+        state.serialize_field("steal", &self.steal)?;
+        state.serialize_field("guest", &self.guest)?;
+        state.serialize_field("guest_nice", &self.guest_nice)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::CpuIdleState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CpuIdleState", 2)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("residency", &self.residency)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::CpuActivity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `6` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CpuActivity", 6)?;
+
+        state.serialize_field("interrupts", &self.interrupts)?;
+        state.serialize_field("soft_interrupts", &self.soft_interrupts)?;
+        state.serialize_field("context_switches", &self.context_switches)?;
+        state.serialize_field("interrupts_per_second", &self.interrupts_per_second)?;
+        state.serialize_field(
+            "soft_interrupts_per_second",
+            &self.soft_interrupts_per_second,
+        )?;
+        state.serialize_field(
+            "context_switches_per_second",
+            &self.context_switches_per_second,
+        )?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::NetworkData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `29` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("NetworkData", 29)?;
+
+        state.serialize_field("received", &self.received())?;
+        state.serialize_field("total_received", &self.total_received())?;
+        state.serialize_field("received_rate", &self.received_rate())?;
+        state.serialize_field("transmitted", &self.transmitted())?;
+        state.serialize_field("total_transmitted", &self.total_transmitted())?;
+        state.serialize_field("transmitted_rate", &self.transmitted_rate())?;
+        state.serialize_field("packets_received", &self.packets_received())?;
+        state.serialize_field("total_packets_received", &self.total_packets_received())?;
+        state.serialize_field("packets_transmitted", &self.packets_transmitted())?;
+        state.serialize_field(
+            "total_packets_transmitted",
+            &self.total_packets_transmitted(),
+        )?;
+        state.serialize_field("errors_on_received", &self.errors_on_received())?;
+        state.serialize_field("total_errors_on_received", &self.total_errors_on_received())?;
+        state.serialize_field("errors_on_transmitted", &self.errors_on_transmitted())?;
+        state.serialize_field(
+            "total_errors_on_transmitted",
+            &self.total_errors_on_transmitted(),
+        )?;
+        state.serialize_field("detailed_counters", &self.detailed_counters())?;
+        state.serialize_field("mac_address", &self.mac_address())?;
+        state.serialize_field("ip_networks", &self.ip_networks())?;
+        state.serialize_field("mtu", &self.mtu())?;
+        state.serialize_field("link_speed", &self.link_speed())?;
+        state.serialize_field("duplex", &self.duplex())?;
+        state.serialize_field("operational_state", &self.operational_state())?;
+        state.serialize_field("is_admin_up", &self.is_admin_up())?;
+        state.serialize_field("has_carrier", &self.has_carrier())?;
+        state.serialize_field("carrier_changes", &self.carrier_changes())?;
+        state.serialize_field("last_state_change", &self.last_state_change())?;
+        state.serialize_field("kind", &self.kind())?;
+        state.serialize_field("description", &self.description())?;
+        state.serialize_field("master", &self.master())?;
+        state.serialize_field("lower_interfaces", &self.lower_interfaces())?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::Component {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `10` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Component", 10)?;
+
+        state.serialize_field("temperature", &self.temperature())?;
+        state.serialize_field("max", &self.max())?;
+        state.serialize_field("critical", &self.critical())?;
+        state.serialize_field("label", &self.label())?;
+        state.serialize_field("id", &self.id())?;
+        state.serialize_field("kind", &self.kind())?;
+        state.serialize_field("unit", &self.unit())?;
+        state.serialize_field("lower_limit", &self.lower_limit())?;
+        state.serialize_field("upper_limit", &self.upper_limit())?;
+        state.serialize_field("raw_value", &self.raw_value())?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::ComponentKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Cpu => (0, "Cpu"),
+            Self::Gpu => (1, "Gpu"),
+            Self::Storage => (2, "Storage"),
+            Self::Battery => (3, "Battery"),
+            Self::Other => (4, "Other"),
+        };
+
+        serializer.serialize_unit_variant("ComponentKind", index, variant)
+    }
+}
+
+impl Serialize for crate::ComponentUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Celsius => (0, "Celsius"),
+            Self::Volt => (1, "Volt"),
+            Self::Ampere => (2, "Ampere"),
+            Self::Watt => (3, "Watt"),
+            Self::Rpm => (4, "Rpm"),
+        };
+
+        serializer.serialize_unit_variant("ComponentUnit", index, variant)
+    }
+}
+
+impl Serialize for crate::User {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `8` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("User", 8)?;
+
+        state.serialize_field("id", &self.id())?;
+        state.serialize_field("group_id", &self.group_id())?;
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("groups", &self.groups())?;
+        state.serialize_field("home_dir", &self.home_dir())?;
+        state.serialize_field("shell", &self.shell())?;
+        state.serialize_field("domain", &self.domain())?;
+        state.serialize_field("is_domain_account", &self.is_domain_account())?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::Group {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Group", 2)?;
+
+        state.serialize_field("id", &self.id())?;
+        state.serialize_field("name", &self.name())?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::Sessions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl Serialize for crate::Session {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Session", 5)?;
+
+        state.serialize_field("user_name", &self.user_name)?;
+        state.serialize_field("login_time", &self.login_time)?;
+        state.serialize_field("terminal", &self.terminal)?;
+        state.serialize_field("host", &self.host)?;
+        state.serialize_field("remote", &self.remote)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::DiskKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant, maybe_value) = match *self {
+            Self::HDD => (0, "HDD", None),
+            Self::SSD => (1, "SSD", None),
+            Self::Unknown(ref s) => (2, "Unknown", Some(s)),
+        };
+
+        if let Some(ref value) = maybe_value {
+            serializer.serialize_newtype_variant("DiskKind", index, variant, value)
+        } else {
+            serializer.serialize_unit_variant("DiskKind", index, variant)
+        }
+    }
+}
+
+impl Serialize for crate::ProcessStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant, maybe_value) = match *self {
+            Self::Idle => (0, "Idle", None),
+            Self::Run => (1, "Run", None),
+            Self::Sleep => (2, "Sleep", None),
+            Self::Stop => (3, "Stop", None),
+            Self::Zombie => (4, "Zombie", None),
+            Self::Tracing => (5, "Tracing", None),
+            Self::Dead => (6, "Dead", None),
+            Self::Wakekill => (7, "Wakekill", None),
+            Self::Waking => (8, "Waking", None),
+            Self::Parked => (9, "Parked", None),
+            Self::LockBlocked => (10, "LockBlocked", None),
+            Self::UninterruptibleDiskSleep => (11, "UninterruptibleDiskSleep", None),
+            Self::Unknown(n) => (12, "Unknown", Some(n)),
+        };
+
+        if let Some(ref value) = maybe_value {
+            serializer.serialize_newtype_variant("ProcessStatus", index, variant, value)
+        } else {
+            serializer.serialize_unit_variant("ProcessStatus", index, variant)
+        }
+    }
+}
+
+impl Serialize for crate::DiskUsage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("DiskUsage", 4)?;
+
+        state.serialize_field("total_written_bytes", &self.total_written_bytes)?;
+        state.serialize_field("written_bytes", &self.written_bytes)?;
+        state.serialize_field("total_read_bytes", &self.total_read_bytes)?;
+        state.serialize_field("read_bytes", &self.read_bytes)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::MacAddr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct("MacAddr", &self.0)
+    }
+}
+
+impl Serialize for crate::DuplexMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Full => (0, "Full"),
+            Self::Half => (1, "Half"),
+            Self::Unknown => (2, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("DuplexMode", index, variant)
+    }
+}
+
+impl Serialize for crate::NetworkCounters {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `9` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("NetworkCounters", 9)?;
+
+        state.serialize_field("multicast_received", &self.multicast_received)?;
+        state.serialize_field("multicast_transmitted", &self.multicast_transmitted)?;
+        state.serialize_field("collisions", &self.collisions)?;
+        state.serialize_field("dropped_on_received", &self.dropped_on_received)?;
+        state.serialize_field("dropped_on_transmitted", &self.dropped_on_transmitted)?;
+        state.serialize_field("fifo_errors_on_received", &self.fifo_errors_on_received)?;
+        state.serialize_field(
+            "fifo_errors_on_transmitted",
+            &self.fifo_errors_on_transmitted,
+        )?;
+        state.serialize_field("frame_errors_on_received", &self.frame_errors_on_received)?;
+        state.serialize_field(
+            "carrier_errors_on_transmitted",
+            &self.carrier_errors_on_transmitted,
+        )?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::InterfaceKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Vlan => (0, "Vlan"),
+            Self::Bridge => (1, "Bridge"),
+            Self::Bond => (2, "Bond"),
+            Self::Other => (3, "Other"),
+        };
+
+        serializer.serialize_unit_variant("InterfaceKind", index, variant)
+    }
+}
+
+impl Serialize for crate::OperationalState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Up => (0, "Up"),
+            Self::Down => (1, "Down"),
+            Self::Testing => (2, "Testing"),
+            Self::Dormant => (3, "Dormant"),
+            Self::NotPresent => (4, "NotPresent"),
+            Self::LowerLayerDown => (5, "LowerLayerDown"),
+            Self::Unknown => (6, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("OperationalState", index, variant)
+    }
+}
+
+impl Serialize for crate::IpNetwork {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("IpNetwork", 2)?;
+
+        state.serialize_field("addr", &self.addr)?;
+        state.serialize_field("prefix", &self.prefix)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::DiskEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Self::Added(ref mount_point) => {
+                serializer.serialize_newtype_variant("DiskEvent", 0, "Added", mount_point)
+            }
+            Self::Removed(ref mount_point) => {
+                serializer.serialize_newtype_variant("DiskEvent", 1, "Removed", mount_point)
+            }
+        }
+    }
+}
+
+impl Serialize for crate::NetworkEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant, name) = match *self {
+            Self::Added(ref name) => (0, "Added", name),
+            Self::Removed(ref name) => (1, "Removed", name),
+            Self::AddressChanged(ref name) => (2, "AddressChanged", name),
+        };
+
+        serializer.serialize_newtype_variant("NetworkEvent", index, variant, name)
+    }
+}
+
+impl Serialize for crate::ComponentThreshold {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Self::Critical => {
+                serializer.serialize_unit_variant("ComponentThreshold", 0, "Critical")
+            }
+            Self::Temperature(ref temperature) => serializer.serialize_newtype_variant(
+                "ComponentThreshold",
+                1,
+                "Temperature",
+                temperature,
+            ),
+        }
+    }
+}
+
+impl Serialize for crate::ComponentEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant, label, temperature, threshold) = match *self {
+            Self::Exceeded {
+                ref label,
+                temperature,
+                threshold,
+            } => (0, "Exceeded", label, temperature, threshold),
+            Self::Recovered {
+                ref label,
+                temperature,
+                threshold,
+            } => (1, "Recovered", label, temperature, threshold),
+        };
+
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct_variant("ComponentEvent", index, variant, 3)?;
+
+        state.serialize_field("label", label)?;
+        state.serialize_field("temperature", &temperature)?;
+        state.serialize_field("threshold", &threshold)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::events::Event {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Self::ProcessStarted { pid, ref name } | Self::ProcessStopped { pid, ref name } => {
+                let (index, variant) = match *self {
+                    Self::ProcessStarted { .. } => (0, "ProcessStarted"),
+                    _ => (1, "ProcessStopped"),
+                };
+                // `2` corresponds to the number of fields.
+                let mut state = serializer.serialize_struct_variant("Event", index, variant, 2)?;
+
+                state.serialize_field("pid", &pid)?;
+                state.serialize_field("name", name)?;
+
+                state.end()
+            }
+            Self::Disk(ref event) => {
+                serializer.serialize_newtype_variant("Event", 2, "Disk", event)
+            }
+            Self::Network(ref event) => {
+                serializer.serialize_newtype_variant("Event", 3, "Network", event)
+            }
+            Self::Component(ref event) => {
+                serializer.serialize_newtype_variant("Event", 4, "Component", event)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "energy")]
+impl Serialize for crate::EnergyDomain {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("EnergyDomain", 4)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("package", &self.package)?;
+        state.serialize_field("energy", &self.energy)?;
+        state.serialize_field("power", &self.power)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "energy")]
+impl Serialize for crate::PowerRail {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("PowerRail", 3)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field("power", &self.power)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "energy")]
+impl Serialize for crate::PowerRailKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::System => (0, "System"),
+            Self::Cpu => (1, "Cpu"),
+            Self::Gpu => (2, "Gpu"),
+            Self::Memory => (3, "Memory"),
+            Self::Battery => (4, "Battery"),
+            Self::Other => (5, "Other"),
+        };
+
+        serializer.serialize_unit_variant("PowerRailKind", index, variant)
+    }
+}
+
+#[cfg(feature = "wireless")]
+impl Serialize for crate::WirelessInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("WirelessInfo", 5)?;
+
+        state.serialize_field("ssid", &self.ssid)?;
+        state.serialize_field("bssid", &self.bssid)?;
+        state.serialize_field("signal", &self.signal)?;
+        state.serialize_field("channel", &self.channel)?;
+        state.serialize_field("bitrate", &self.bitrate)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::SystemSnapshot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `21` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("SystemSnapshot", 21)?;
+
+        state.serialize_field("version", &self.version)?;
+        state.serialize_field("timestamp", &self.timestamp)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("kernel_version", &self.kernel_version)?;
+        state.serialize_field("os_version", &self.os_version)?;
+        state.serialize_field("long_os_version", &self.long_os_version)?;
+        state.serialize_field("host_name", &self.host_name)?;
+        state.serialize_field("uptime", &self.uptime)?;
+        state.serialize_field("boot_time", &self.boot_time)?;
+        state.serialize_field("load_average", &self.load_average)?;
+        state.serialize_field("total_memory", &self.total_memory)?;
+        state.serialize_field("free_memory", &self.free_memory)?;
+        state.serialize_field("available_memory", &self.available_memory)?;
+        state.serialize_field("used_memory", &self.used_memory)?;
+        state.serialize_field("total_swap", &self.total_swap)?;
+        state.serialize_field("free_swap", &self.free_swap)?;
+        state.serialize_field("used_swap", &self.used_swap)?;
+        state.serialize_field("physical_core_count", &self.physical_core_count)?;
+        state.serialize_field("global_cpu_info", &self.global_cpu_info)?;
+        state.serialize_field("cpus", &self.cpus)?;
+        state.serialize_field("processes", &self.processes)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::ProcessSnapshot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `18` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ProcessSnapshot", 18)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("cmd", &self.cmd)?;
+        state.serialize_field("exe", &self.exe)?;
+        state.serialize_field("pid", &self.pid)?;
+        state.serialize_field("environ", &self.environ)?;
+        state.serialize_field("cwd", &self.cwd)?;
+        state.serialize_field("root", &self.root)?;
+        state.serialize_field("memory", &self.memory)?;
+        state.serialize_field("virtual_memory", &self.virtual_memory)?;
+        state.serialize_field("parent", &self.parent)?;
+        state.serialize_field("status", &self.status)?;
+        state.serialize_field("start_time", &self.start_time)?;
+        state.serialize_field("run_time", &self.run_time)?;
+        state.serialize_field("cpu_usage", &self.cpu_usage)?;
+        state.serialize_field("disk_usage", &self.disk_usage)?;
+        state.serialize_field("user_id", &self.user_id)?;
+        state.serialize_field("group_id", &self.group_id)?;
+        state.serialize_field("session_id", &self.session_id)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::CpuSnapshot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CpuSnapshot", 5)?;
+
+        state.serialize_field("cpu_usage", &self.cpu_usage)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("vendor_id", &self.vendor_id)?;
+        state.serialize_field("brand", &self.brand)?;
+        state.serialize_field("frequency", &self.frequency)?;
+
+        state.end()
+    }
+}
+
+// The types wrapping the system's handles (like `System`, `Process` or `Disk`) can't be
+// deserialized, but the types only holding data can, from what their `Serialize` implementation
+// returns.
+
+/// Implements `Deserialize` for a struct whose fields are all public, from a map (for
+/// self-describing formats) or from a sequence of its fields in the given order. The fields
+/// given after a `;` were added later: they are set to their default value when they are
+/// missing, so values serialized by older versions can still be read. If a function is given
+/// after the fields, it is called to validate the deserialized value.
+macro_rules! impl_deserialize_struct {
+    (
+        $ty:ident { $($field:ident),+ $(; $($added:ident),+)? $(,)? } $(, $check:path)?
+    ) => {
+        impl<'de> Deserialize<'de> for crate::$ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &[$(stringify!($field),)+ $($(stringify!($added),)+)?];
+
+                struct Visitor;
+
+                impl<'de> de::Visitor<'de> for Visitor {
+                    type Value = crate::$ty;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str(concat!("struct ", stringify!($ty)))
+                    }
+
+                    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: de::SeqAccess<'de>,
+                    {
+                        let value = crate::$ty {
+                            $($field: seq.next_element()?.ok_or_else(|| {
+                                de::Error::missing_field(stringify!($field))
+                            })?,)+
+                            $($($added: seq.next_element()?.unwrap_or_default(),)+)?
+                        };
+                        $($check(&value)?;)?
+                        Ok(value)
+                    }
+
+                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: de::MapAccess<'de>,
+                    {
+                        $(let mut $field = None;)+
+                        $($(let mut $added = None;)+)?
+                        while let Some(key) = map.next_key::<Field>()? {
+                            match key.0.as_str() {
+                                $(stringify!($field) => $field = Some(map.next_value()?),)+
+                                $($(stringify!($added) => $added = Some(map.next_value()?),)+)?
+                                _ => {
+                                    map.next_value::<de::IgnoredAny>()?;
+                                }
+                            }
+                        }
+                        let value = crate::$ty {
+                            $($field: $field.ok_or_else(|| {
+                                de::Error::missing_field(stringify!($field))
+                            })?,)+
+                            $($($added: $added.unwrap_or_default(),)+)?
+                        };
+                        $($check(&value)?;)?
+                        Ok(value)
+                    }
+                }
+
+                deserializer.deserialize_struct(stringify!($ty), FIELDS, Visitor)
+            }
+        }
+    };
+}
+
+/// Implements `Deserialize` for an enum whose variants are given in the order of their index,
+/// with the type of their value for the newtype variants.
+macro_rules! impl_deserialize_enum {
+    ($ty:ident { $($variant:ident $(($inner:ty))?),+ $(,)? }) => {
+        impl<'de> Deserialize<'de> for crate::$ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                const VARIANTS: &[&str] = &[$(stringify!($variant)),+];
+
+                struct Visitor;
+
+                impl<'de> de::Visitor<'de> for Visitor {
+                    type Value = crate::$ty;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str(concat!("enum ", stringify!($ty)))
+                    }
+
+                    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: de::EnumAccess<'de>,
+                    {
+                        let (index, variant) = data.variant_seed(VariantSeed(VARIANTS))?;
+                        $(
+                            if VARIANTS[index] == stringify!($variant) {
+                                return impl_deserialize_enum!(
+                                    @variant $ty, variant, $variant $(($inner))?
+                                );
+                            }
+                        )+
+                        unreachable!()
+                    }
+                }
+
+                deserializer.deserialize_enum(stringify!($ty), VARIANTS, Visitor)
+            }
+        }
+    };
+    (@variant $ty:ident, $access:ident, $variant:ident) => {
+        de::VariantAccess::unit_variant($access).map(|()| crate::$ty::$variant)
+    };
+    (@variant $ty:ident, $access:ident, $variant:ident ($inner:ty)) => {
+        de::VariantAccess::newtype_variant::<$inner>($access).map(crate::$ty::$variant)
+    };
+}
+
+/// Name of a field of a struct.
+struct Field(String);
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a field name")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(Field(value.to_owned()))
+            }
+        }
+
+        deserializer.deserialize_identifier(Visitor)
+    }
+}
+
+/// Returns the index of a variant of an enum, from its name or its index.
+struct VariantSeed(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for VariantSeed {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> de::Visitor<'de> for VariantSeed {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a variant name or index")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        match usize::try_from(value) {
+            Ok(index) if index < self.0.len() => Ok(index),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(value), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        self.0
+            .iter()
+            .position(|variant| *variant == value)
+            .ok_or_else(|| E::unknown_variant(value, self.0))
+    }
+}
+
+/// Deserializes the IDs ([`Pid`][crate::Pid], [`Uid`][crate::Uid] and [`Gid`][crate::Gid]) from
+/// the strings they are serialized as, like `"42"`, parsing them with [`FromStr`][std::str::FromStr].
+struct IdVisitor<T>(std::marker::PhantomData<T>);
+
+impl<'de, T: std::str::FromStr> de::Visitor<'de> for IdVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an ID")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value
+            .parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for crate::Pid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct("Pid", IdVisitor(std::marker::PhantomData))
+    }
+}
+
+impl<'de> Deserialize<'de> for crate::Gid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct("Gid", IdVisitor(std::marker::PhantomData))
+    }
+}
+
+impl<'de> Deserialize<'de> for crate::Uid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct("Uid", IdVisitor(std::marker::PhantomData))
+    }
+}
+
+impl<'de> Deserialize<'de> for crate::MacAddr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <[u8; 6]>::deserialize(deserializer).map(crate::MacAddr)
+    }
+}
+
+impl_deserialize_struct!(PhysicalDisk {
+    name,
+    kind,
+    total_space,
+    is_removable
+});
+impl_deserialize_struct!(StoragePool {
+    name,
+    kind,
+    health,
+    devices,
+    total_space,
+    allocated_space,
+    usable_space,
+    used_space
+});
+impl_deserialize_enum!(StoragePoolKind { ZFS, Btrfs });
+impl_deserialize_enum!(StoragePoolHealth {
+    Online,
+    Degraded,
+    Faulted,
+    Offline,
+    Unavailable,
+    Removed,
+    Suspended,
+    Unknown
+});
+impl_deserialize_struct!(DiskQuota {
+    used_space,
+    soft_space_limit,
+    hard_space_limit,
+    used_inodes,
+    soft_inode_limit,
+    hard_inode_limit
+});
+impl_deserialize_struct!(BlockDevice {
+    name,
+    kind,
+    backing_devices
+});
+impl_deserialize_enum!(BlockDeviceKind {
+    Disk,
+    Partition,
+    Crypt,
+    Lvm,
+    Raid,
+    DeviceMapper,
+    Loop,
+    Unknown
+});
+impl_deserialize_struct!(CGroupLimits {
+    total_memory,
+    free_memory,
+    free_swap;
+    cpu_quota,
+    cpus
+});
+impl_deserialize_struct!(Connection {
+    protocol,
+    local_address,
+    remote_address,
+    state,
+    pid,
+    traffic
+});
+impl_deserialize_struct!(ConnectionTraffic {
+    bytes_sent,
+    bytes_received,
+    retransmitted_segments
+});
+impl_deserialize_struct!(ConnectionSummary {
+    tcp_established,
+    tcp_listening,
+    tcp_time_wait,
+    tcp_total,
+    udp
+});
+impl_deserialize_enum!(ConnectionProtocol { Tcp, Udp });
+impl_deserialize_enum!(TcpState {
+    Listen,
+    SynSent,
+    SynReceived,
+    Established,
+    FinWait1,
+    FinWait2,
+    CloseWait,
+    Closing,
+    LastAck,
+    TimeWait,
+    Closed
+});
+impl_deserialize_struct!(Battery {
+    name,
+    state,
+    charge,
+    energy,
+    full_capacity,
+    design_capacity,
+    cycle_count,
+    time_to_empty,
+    time_to_full
+});
+impl_deserialize_enum!(BatteryState {
+    Charging,
+    Discharging,
+    Full,
+    NotCharging,
+    Unknown
+});
+impl_deserialize_struct!(ThermalZone {
+    name,
+    kind,
+    temperature,
+    trip_points
+});
+impl_deserialize_struct!(TripPoint {
+    kind,
+    temperature,
+    hysteresis,
+    cooling_devices
+});
+impl_deserialize_enum!(TripPointKind {
+    Active,
+    Passive,
+    Hot,
+    Critical
+});
+impl_deserialize_struct!(CoolingDevice {
+    name,
+    state,
+    max_state
+});
+impl_deserialize_struct!(PciDevice {
+    address,
+    class,
+    vendor_id,
+    device_id,
+    vendor_name,
+    device_name,
+    driver,
+    link_width,
+    link_speed
+});
+impl_deserialize_struct!(Service {
+    name,
+    description,
+    state,
+    start_kind,
+    command,
+    pid
+});
+impl_deserialize_enum!(ServiceState {
+    Running,
+    Stopped,
+    Starting,
+    Stopping,
+    Paused,
+    Unknown
+});
+impl_deserialize_enum!(ServiceStartKind {
+    Automatic,
+    Manual,
+    Disabled,
+    Unknown
+});
+impl_deserialize_struct!(Neighbor {
+    ip_address,
+    mac_address,
+    state,
+    interface
+});
+impl_deserialize_enum!(NeighborState {
+    Incomplete,
+    Reachable,
+    Stale,
+    Delay,
+    Probe,
+    Failed,
+    Permanent,
+    Unknown
+});
+impl_deserialize_enum!(Signal {
+    Hangup,
+    Interrupt,
+    Quit,
+    Illegal,
+    Trap,
+    Abort,
+    IOT,
+    Bus,
+    FloatingPointException,
+    Kill,
+    User1,
+    Segv,
+    User2,
+    Pipe,
+    Alarm,
+    Term,
+    Child,
+    Continue,
+    Stop,
+    TSTP,
+    TTIN,
+    TTOU,
+    Urgent,
+    XCPU,
+    XFSZ,
+    VirtualAlarm,
+    Profiling,
+    Winch,
+    IO,
+    Poll,
+    Power,
+    Sys
+});
+impl_deserialize_struct!(LoadAvg { one, five, fifteen });
+impl_deserialize_struct!(Pressure { cpu, memory, io });
+impl_deserialize_struct!(PressureStats { some, full });
+impl_deserialize_struct!(PressureValues {
+    avg10,
+    avg60,
+    avg300,
+    total
+});
+impl_deserialize_struct!(MemoryBreakdown {
+    buffers,
+    cached,
+    shared,
+    slab_reclaimable,
+    slab_unreclaimable,
+    mapped,
+    dirty,
+    writeback
+});
+impl_deserialize_struct!(AvailableMemory {
+    free,
+    reclaimable,
+    available,
+    source
+});
+impl_deserialize_enum!(AvailableMemorySource { Kernel, Computed });
+impl_deserialize_struct!(Ksm {
+    running,
+    shared,
+    saved,
+    unshared
+});
+impl_deserialize_struct!(CommitCharge { total, limit, peak });
+impl_deserialize_struct!(CompressedMemory {
+    uncompressed,
+    compressed
+});
+impl_deserialize_struct!(ZramDevice {
+    name,
+    algorithm,
+    disk_size,
+    uncompressed,
+    compressed,
+    memory_used
+});
+impl_deserialize_struct!(MemoryModule {
+    locator,
+    bank_locator,
+    kind,
+    size,
+    speed,
+    manufacturer,
+    serial_number,
+    part_number
+});
+impl_deserialize_enum!(MemoryModuleKind { DRAM, SDRAM, DDR, DDR2, DDR3, DDR4, DDR5, LPDDR, LPDDR2, LPDDR3, LPDDR4, LPDDR5, Unknown(u8) });
+impl_deserialize_struct!(HardwareInfo {
+    manufacturer,
+    product_name,
+    product_version,
+    serial_number,
+    board_vendor,
+    board_name,
+    board_serial_number,
+    bios_vendor,
+    bios_version,
+    bios_release_date,
+    chassis
+});
+impl_deserialize_enum!(ChassisKind {
+    Desktop,
+    Laptop,
+    Tablet,
+    AllInOne,
+    Server,
+    VirtualMachine,
+    Unknown
+});
+impl_deserialize_struct!(Virtualization {
+    hypervisor,
+    container
+});
+impl_deserialize_enum!(Hypervisor { Kvm, Qemu, HyperV, VMware, VirtualBox, Xen, Parallels, Bhyve, Apple, Other(String) });
+impl_deserialize_enum!(ContainerRuntime { Docker, Podman, Lxc, SystemdNspawn, Wsl, Jail, Other(String) });
+impl_deserialize_struct!(Entropy {
+    ready,
+    available,
+    pool_size
+});
+impl_deserialize_struct!(FileHandles { allocated, limit });
+impl_deserialize_struct!(SystemCounts {
+    processes,
+    threads,
+    handles
+});
+impl_deserialize_struct!(OsRelease {
+    id,
+    id_like,
+    name,
+    pretty_name,
+    version,
+    version_id,
+    version_codename,
+    variant,
+    variant_id,
+    build_id,
+    fields
+});
+impl_deserialize_struct!(WindowsVersion {
+    major,
+    minor,
+    build,
+    revision,
+    display_version,
+    edition,
+    service_pack,
+    service_pack_major,
+    service_pack_minor,
+    suite_mask,
+    product_type
+});
+impl_deserialize_struct!(TimeZone { name, utc_offset });
+impl_deserialize_enum!(KernelParameter { Integer(i64), Integers(Vec<i64>), String(String) });
+impl_deserialize_enum!(MemoryPressureLevel {
+    Normal,
+    Warning,
+    Critical
+});
+impl_deserialize_struct!(CpuTimes {
+    user,
+    nice,
+    system,
+    idle,
+    iowait,
+    irq,
+    softirq,
+    steal,
+    guest,
+    guest_nice
+});
+impl_deserialize_struct!(CpuIdleState { name, residency });
+impl_deserialize_struct!(CpuActivity {
+    interrupts,
+    soft_interrupts,
+    context_switches,
+    interrupts_per_second,
+    soft_interrupts_per_second,
+    context_switches_per_second
+});
+impl_deserialize_enum!(ComponentKind {
+    Cpu,
+    Gpu,
+    Storage,
+    Battery,
+    Other
+});
+impl_deserialize_enum!(ComponentUnit {
+    Celsius,
+    Volt,
+    Ampere,
+    Watt,
+    Rpm
+});
+impl_deserialize_struct!(Session {
+    user_name,
+    login_time,
+    terminal,
+    host,
+    remote
+});
+impl_deserialize_enum!(DiskKind { HDD, SSD, Unknown(isize) });
+impl_deserialize_enum!(ProcessStatus { Idle, Run, Sleep, Stop, Zombie, Tracing, Dead, Wakekill, Waking, Parked, LockBlocked, UninterruptibleDiskSleep, Unknown(u32) });
+impl_deserialize_struct!(DiskUsage {
+    total_written_bytes,
+    written_bytes,
+    total_read_bytes,
+    read_bytes
+});
+impl_deserialize_enum!(DuplexMode {
+    Full,
+    Half,
+    Unknown
+});
+impl_deserialize_struct!(NetworkCounters {
+    multicast_received,
+    multicast_transmitted,
+    collisions,
+    dropped_on_received,
+    dropped_on_transmitted,
+    fifo_errors_on_received,
+    fifo_errors_on_transmitted,
+    frame_errors_on_received,
+    carrier_errors_on_transmitted
+});
+impl_deserialize_enum!(InterfaceKind {
+    Vlan,
+    Bridge,
+    Bond,
+    Other
+});
+impl_deserialize_enum!(OperationalState {
+    Up,
+    Down,
+    Testing,
+    Dormant,
+    NotPresent,
+    LowerLayerDown,
+    Unknown
+});
+impl_deserialize_struct!(IpNetwork { addr, prefix });
+impl_deserialize_enum!(DiskEvent { Added(std::path::PathBuf), Removed(std::path::PathBuf) });
+impl_deserialize_enum!(NetworkEvent { Added(String), Removed(String), AddressChanged(String) });
+impl_deserialize_enum!(ComponentThreshold { Critical, Temperature(f32) });
+impl_deserialize_struct!(
+    SystemSnapshot {
+        version,
+        timestamp,
+        name,
+        kernel_version,
+        os_version,
+        long_os_version,
+        host_name,
+        uptime,
+        boot_time,
+        load_average,
+        total_memory,
+        free_memory,
+        available_memory,
+        used_memory,
+        total_swap,
+        free_swap,
+        used_swap,
+        physical_core_count,
+        global_cpu_info,
+        cpus,
+        processes
+    },
+    check_snapshot_version
+);
+impl_deserialize_struct!(ProcessSnapshot {
+    name,
+    cmd,
+    exe,
+    pid,
+    environ,
+    cwd,
+    root,
+    memory,
+    virtual_memory,
+    parent,
+    status,
+    start_time,
+    run_time,
+    cpu_usage,
+    disk_usage,
+    user_id,
+    group_id,
+    session_id
+});
+impl_deserialize_struct!(CpuSnapshot {
+    cpu_usage,
+    name,
+    vendor_id,
+    brand,
+    frequency
+});
+
+/// Snapshots written by a newer version of `sysinfo` might not be understood correctly.
+fn check_snapshot_version<E: de::Error>(snapshot: &crate::SystemSnapshot) -> Result<(), E> {
+    if snapshot.version > crate::SystemSnapshot::VERSION {
+        return Err(E::custom(format_args!(
+            "unsupported snapshot version {}, expected at most {}",
+            snapshot.version,
+            crate::SystemSnapshot::VERSION
+        )));
+    }
+    Ok(())
+}
+#[cfg(feature = "energy")]
+impl_deserialize_struct!(EnergyDomain {
+    name,
+    package,
+    energy,
+    power
+});
+#[cfg(feature = "energy")]
+impl_deserialize_struct!(PowerRail { name, kind, power });
+#[cfg(feature = "energy")]
+impl_deserialize_enum!(PowerRailKind {
+    System,
+    Cpu,
+    Gpu,
+    Memory,
+    Battery,
+    Other
+});
+#[cfg(feature = "wireless")]
+impl_deserialize_struct!(WirelessInfo {
+    ssid,
+    bssid,
+    signal,
+    channel,
+    bitrate
+});
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        CGroupLimits, Connection, ConnectionProtocol, ConnectionTraffic, DiskUsage, Hypervisor,
+        LoadAvg, Pid, ProcessStatus, System, TcpState,
+    };
+
+    fn round_trip<T>(value: &T) -> T
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let json = serde_json::to_string(value).unwrap();
+        let deserialized: T = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+        deserialized
+    }
+
+    #[test]
+    fn check_ids() {
+        use std::convert::TryFrom;
+
+        assert_eq!(serde_json::to_string(&Pid::from_u32(42)).unwrap(), "\"42\"");
+        assert_eq!(round_trip(&Pid::from_u32(42)), Pid::from_u32(42));
+        assert!(serde_json::from_str::<Pid>("\"a\"").is_err());
+        assert!(serde_json::from_str::<Pid>("42").is_err());
+        let gid = crate::Gid::try_from(100).unwrap();
+        assert_eq!(serde_json::to_string(&gid).unwrap(), "\"100\"");
+        assert_eq!(round_trip(&gid), gid);
+        // On Windows, the user IDs are SIDs, which can't be created from an integer.
+        #[cfg(not(windows))]
+        {
+            let uid = crate::Uid::try_from(1000).unwrap();
+            assert_eq!(serde_json::to_string(&uid).unwrap(), "\"1000\"");
+            assert_eq!(round_trip(&uid), uid);
+        }
+
+        let s = System::new_with_specifics(
+            crate::RefreshKind::new().with_processes(crate::ProcessRefreshKind::new().with_user()),
+        );
+        if let Some(process) = crate::get_current_pid().ok().and_then(|pid| s.process(pid)) {
+            if let Some(uid) = process.user_id() {
+                assert_eq!(&round_trip(uid), uid);
+            }
+            if let Some(gid) = process.group_id() {
+                assert_eq!(round_trip(&gid), gid);
+            }
+        }
+    }
+
+    #[test]
+    fn check_round_trip() {
+        let usage = DiskUsage {
+            total_written_bytes: 4,
+            written_bytes: 3,
+            total_read_bytes: 2,
+            read_bytes: 1,
+        };
+        assert_eq!(round_trip(&usage), usage);
+        assert_eq!(round_trip(&ProcessStatus::Run), ProcessStatus::Run);
+        assert_eq!(
+            round_trip(&ProcessStatus::Unknown(12)),
+            ProcessStatus::Unknown(12)
+        );
+        assert_eq!(
+            round_trip(&Hypervisor::Other("bochs".to_owned())),
+            Hypervisor::Other("bochs".to_owned())
+        );
+        let connection = Connection {
+            protocol: ConnectionProtocol::Tcp,
+            local_address: "127.0.0.1:8080".parse().unwrap(),
+            remote_address: Some("[::1]:41000".parse().unwrap()),
+            state: Some(TcpState::Established),
+            pid: Some(Pid::from_u32(1)),
+            traffic: Some(ConnectionTraffic {
+                bytes_sent: 10,
+                bytes_received: 20,
+                retransmitted_segments: 0,
+            }),
+        };
+        assert_eq!(round_trip(&connection), connection);
+        round_trip(&System::new().load_average());
+    }
+
+    #[test]
+    fn check_deserialize_errors() {
+        // The fields can also be given as a sequence, in the order they are serialized.
+        let load: LoadAvg = serde_json::from_str("[1.0, 5.0, 15.0]").unwrap();
+        assert_eq!((load.one, load.five, load.fifteen), (1., 5., 15.));
+        // Unknown fields are ignored.
+        assert!(
+            serde_json::from_str::<LoadAvg>(r#"{"one":1,"five":2,"fifteen":3,"other":4}"#).is_ok()
+        );
+        assert!(serde_json::from_str::<LoadAvg>(r#"{"one":1,"five":2}"#).is_err());
+        assert!(serde_json::from_str::<ProcessStatus>("\"Running\"").is_err());
+    }
+
+    #[test]
+    fn check_cgroup_limits() {
+        // Serialized before the CPU limits were added.
+        let limits: CGroupLimits =
+            serde_json::from_str(r#"{"total_memory":4096,"free_memory":1024,"free_swap":512}"#)
+                .unwrap();
+        assert_eq!(
+            (limits.total_memory, limits.free_memory, limits.free_swap),
+            (4096, 1024, 512),
+        );
+        assert_eq!(limits.cpu_quota(), None);
+        assert_eq!(limits.cpu_count(), None);
+        let limits: CGroupLimits = serde_json::from_str("[4096, 1024, 512]").unwrap();
+        assert_eq!(limits.total_memory, 4096);
+        assert_eq!(limits.cpu_quota(), None);
+
+        let limits: CGroupLimits = serde_json::from_str(
+            r#"{"total_memory":4096,"free_memory":1024,"free_swap":512,"cpu_quota":1.5,"cpus":2}"#,
+        )
+        .unwrap();
+        assert_eq!(limits.cpu_quota(), Some(1.5));
+        assert_eq!(limits.cpu_count(), Some(2));
+        assert_eq!(round_trip(&limits).cpu_count(), Some(2));
+    }
+
+    #[test]
+    fn check_snapshot() {
+        let s = System::new_all();
+        let snapshot = s.to_snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let loaded: crate::SystemSnapshot = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.version(), crate::SystemSnapshot::VERSION);
+        assert_eq!(loaded.timestamp(), snapshot.timestamp());
+        assert_eq!(loaded.total_memory(), snapshot.total_memory());
+        assert_eq!(loaded.host_name(), snapshot.host_name());
+        assert_eq!(loaded.cpus().len(), snapshot.cpus().len());
+        assert_eq!(loaded.processes().len(), snapshot.processes().len());
+        for (pid, process) in snapshot.processes() {
+            let loaded = loaded.process(*pid).unwrap();
+            assert_eq!(loaded.name(), process.name());
+            assert_eq!(loaded.cmd(), process.cmd());
+            assert_eq!(loaded.status(), process.status());
+            assert_eq!(loaded.cpu_usage(), process.cpu_usage());
+            assert_eq!(loaded.user_id(), process.user_id());
+        }
+
+        // Snapshots from newer versions are rejected.
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["version"] = (crate::SystemSnapshot::VERSION + 1).into();
+        assert!(serde_json::from_value::<crate::SystemSnapshot>(value).is_err());
     }
 }
@@ -0,0 +1,299 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::utils::chassis_from_smbios;
+use crate::{ChassisKind, HardwareInfo, MemoryModule, MemoryModuleKind};
+
+// Type of the "BIOS Information" structure.
+const BIOS_INFORMATION: u8 = 0;
+// Type of the "System Information" structure.
+const SYSTEM_INFORMATION: u8 = 1;
+// Type of the "Baseboard Information" structures.
+const BASEBOARD_INFORMATION: u8 = 2;
+// Type of the "System Enclosure" structures.
+const SYSTEM_ENCLOSURE: u8 = 3;
+// Type of the "Memory Device" structures.
+const MEMORY_DEVICE: u8 = 17;
+// Type of the "End-of-Table" structure.
+const END_OF_TABLE: u8 = 127;
+
+struct Structure<'a> {
+    kind: u8,
+    // Formatted area, including the header.
+    data: &'a [u8],
+    strings: Vec<&'a [u8]>,
+}
+
+impl<'a> Structure<'a> {
+    fn u8(&self, offset: usize) -> Option<u8> {
+        self.data.get(offset).copied()
+    }
+
+    fn u16(&self, offset: usize) -> Option<u16> {
+        let bytes = self.data.get(offset..offset + 2)?;
+        Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&self, offset: usize) -> Option<u32> {
+        let bytes = self.data.get(offset..offset + 4)?;
+        Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    // Strings are referenced by their (1-based) index, `0` meaning "no string".
+    fn string(&self, offset: usize) -> String {
+        self.u8(offset)
+            .and_then(|index| self.strings.get(usize::from(index).checked_sub(1)?))
+            .map(|s| String::from_utf8_lossy(s).trim().to_owned())
+            .unwrap_or_default()
+    }
+}
+
+fn parse_structures(mut table: &[u8]) -> Vec<Structure<'_>> {
+    let mut structures = Vec::new();
+
+    while table.len() >= 4 {
+        let kind = table[0];
+        let len = usize::from(table[1]);
+        if kind == END_OF_TABLE || len < 4 || len > table.len() {
+            break;
+        }
+        let (data, rest) = table.split_at(len);
+        // The string-set ends with two null bytes.
+        let end = match rest.windows(2).position(|w| w == [0, 0]) {
+            Some(end) => end,
+            None => break,
+        };
+        let strings = rest[..end]
+            .split(|c| *c == 0)
+            .filter(|s| !s.is_empty())
+            .collect();
+        structures.push(Structure {
+            kind,
+            data,
+            strings,
+        });
+        table = &rest[end + 2..];
+    }
+    structures
+}
+
+/// Returns the memory modules described in the given raw SMBIOS table.
+pub(crate) fn parse_memory_modules(table: &[u8]) -> Vec<MemoryModule> {
+    parse_structures(table)
+        .iter()
+        .filter(|s| s.kind == MEMORY_DEVICE)
+        .filter_map(|s| {
+            let size = match s.u16(0x0C)? {
+                // The slot is empty.
+                0 => return None,
+                0xFFFF => 0,
+                // The size is in the "Extended Size" field (in MiB).
+                0x7FFF => u64::from(s.u32(0x1C)? & 0x7FFF_FFFF) * 1024 * 1024,
+                // Bit 15 tells whether the size is in KiB or in MiB.
+                size if size & 0x8000 != 0 => u64::from(size & 0x7FFF) * 1024,
+                size => u64::from(size) * 1024 * 1024,
+            };
+            // The configured speed is only available since SMBIOS 2.7.
+            let speed = match s.u16(0x20).filter(|speed| *speed != 0) {
+                Some(0xFFFF) => s.u32(0x58),
+                Some(speed) => Some(u32::from(speed)),
+                None => match s.u16(0x15) {
+                    Some(0xFFFF) => s.u32(0x54),
+                    speed => speed.map(u32::from),
+                },
+            }
+            .unwrap_or(0);
+            Some(MemoryModule {
+                locator: s.string(0x10),
+                bank_locator: s.string(0x11),
+                kind: memory_module_kind(s.u8(0x12).unwrap_or(0)),
+                size,
+                speed,
+                manufacturer: s.string(0x17),
+                serial_number: s.string(0x18),
+                part_number: s.string(0x1A),
+            })
+        })
+        .collect()
+}
+
+/// Returns the system information described in the given raw SMBIOS table.
+pub(crate) fn parse_hardware_info(table: &[u8]) -> HardwareInfo {
+    let mut info = HardwareInfo::default();
+    // There can be more than one baseboard, the first one being the motherboard.
+    let mut found_baseboard = false;
+
+    for s in parse_structures(table) {
+        match s.kind {
+            BIOS_INFORMATION => {
+                info.bios_vendor = s.string(0x04);
+                info.bios_version = s.string(0x05);
+                info.bios_release_date = s.string(0x08);
+            }
+            SYSTEM_INFORMATION => {
+                info.manufacturer = s.string(0x04);
+                info.product_name = s.string(0x05);
+                info.product_version = s.string(0x06);
+                info.serial_number = s.string(0x07);
+            }
+            BASEBOARD_INFORMATION if !found_baseboard => {
+                found_baseboard = true;
+                info.board_vendor = s.string(0x04);
+                info.board_name = s.string(0x05);
+                info.board_serial_number = s.string(0x07);
+            }
+            // Docking stations add their own enclosure, the first one being the system's.
+            SYSTEM_ENCLOSURE if info.chassis == ChassisKind::Unknown => {
+                info.chassis = chassis_from_smbios(s.u8(0x05).unwrap_or(0));
+            }
+            _ => {}
+        }
+    }
+    info
+}
+
+fn memory_module_kind(kind: u8) -> MemoryModuleKind {
+    match kind {
+        0x03 => MemoryModuleKind::DRAM,
+        0x0F => MemoryModuleKind::SDRAM,
+        0x12 => MemoryModuleKind::DDR,
+        0x13 | 0x14 => MemoryModuleKind::DDR2,
+        0x18 => MemoryModuleKind::DDR3,
+        0x1A => MemoryModuleKind::DDR4,
+        0x22 => MemoryModuleKind::DDR5,
+        0x1B => MemoryModuleKind::LPDDR,
+        0x1C => MemoryModuleKind::LPDDR2,
+        0x1D => MemoryModuleKind::LPDDR3,
+        0x1E => MemoryModuleKind::LPDDR4,
+        0x23 => MemoryModuleKind::LPDDR5,
+        kind => MemoryModuleKind::Unknown(kind),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_hardware_info, parse_memory_modules};
+    use crate::{ChassisKind, MemoryModuleKind};
+
+    fn structure(kind: u8, len: usize, strings_at: &[usize], strings: &[&str]) -> Vec<u8> {
+        let mut data = vec![0u8; len];
+        data[0] = kind;
+        data[1] = len as u8;
+        for (index, offset) in strings_at.iter().enumerate() {
+            data[*offset] = index as u8 + 1;
+        }
+        for s in strings {
+            data.extend_from_slice(s.as_bytes());
+            data.push(0);
+        }
+        data.push(0);
+        data
+    }
+
+    fn memory_device(size: u16, extended_size: u32, kind: u8, strings: &[&str]) -> Vec<u8> {
+        let mut data = vec![0u8; 0x28];
+        data[0] = 17;
+        data[1] = data.len() as u8;
+        data[0x0C..0x0E].copy_from_slice(&size.to_le_bytes());
+        data[0x10] = 1; // Device locator.
+        data[0x11] = 2; // Bank locator.
+        data[0x12] = kind;
+        data[0x15..0x17].copy_from_slice(&4800u16.to_le_bytes());
+        data[0x17] = 3; // Manufacturer.
+        data[0x1A] = 4; // Part number.
+        data[0x1C..0x20].copy_from_slice(&extended_size.to_le_bytes());
+        data[0x20..0x22].copy_from_slice(&4400u16.to_le_bytes());
+        for s in strings {
+            data.extend_from_slice(s.as_bytes());
+            data.push(0);
+        }
+        if strings.is_empty() {
+            data.push(0);
+        }
+        data.push(0);
+        data
+    }
+
+    #[test]
+    fn memory_modules() {
+        let mut table = Vec::new();
+        // BIOS information, which must be skipped.
+        table.extend_from_slice(&[0, 4, 0, 0, b'B', b'I', b'O', b'S', 0, 0]);
+        table.extend(memory_device(
+            16384,
+            0,
+            0x22,
+            &["DIMM_A1", "BANK 0", "Samsung", "M323R2GA3BB0-CQKOL"],
+        ));
+        // Empty slot.
+        table.extend(memory_device(0, 0, 0x02, &[]));
+        table.extend(memory_device(0x7FFF, 65536, 0x1A, &["DIMM_B1"]));
+        table.extend_from_slice(&[127, 4, 0, 0, 0, 0]);
+
+        let modules = parse_memory_modules(&table);
+        assert_eq!(modules.len(), 2);
+
+        assert_eq!(modules[0].locator, "DIMM_A1");
+        assert_eq!(modules[0].bank_locator, "BANK 0");
+        assert_eq!(modules[0].kind, MemoryModuleKind::DDR5);
+        assert_eq!(modules[0].size, 16 * 1024 * 1024 * 1024);
+        assert_eq!(modules[0].speed, 4400);
+        assert_eq!(modules[0].manufacturer, "Samsung");
+        assert_eq!(modules[0].serial_number, "");
+        assert_eq!(modules[0].part_number, "M323R2GA3BB0-CQKOL");
+
+        assert_eq!(modules[1].locator, "DIMM_B1");
+        assert_eq!(modules[1].bank_locator, "");
+        assert_eq!(modules[1].kind, MemoryModuleKind::DDR4);
+        assert_eq!(modules[1].size, 64 * 1024 * 1024 * 1024);
+    }
+
+    #[test]
+    fn hardware_info() {
+        let mut table = Vec::new();
+        table.extend(structure(
+            0,
+            0x18,
+            &[0x04, 0x05, 0x08],
+            &["LENOVO", "N32ET86W (1.62 )", "02/14/2023"],
+        ));
+        table.extend(structure(
+            1,
+            0x1B,
+            &[0x04, 0x05, 0x06, 0x07],
+            &[
+                "LENOVO",
+                "20XWCTO1WW",
+                "ThinkPad X1 Carbon Gen 9",
+                "PF2ABCDE",
+            ],
+        ));
+        table.extend(structure(2, 0x0F, &[0x04, 0x05], &["LENOVO", "20XWCTO1WW"]));
+        // Second baseboard, which must be ignored.
+        table.extend(structure(2, 0x0F, &[0x04], &["Other"]));
+        let mut enclosure = structure(3, 0x0D, &[0x04], &["LENOVO"]);
+        // Notebook.
+        enclosure[0x05] = 0x0A;
+        table.extend(enclosure);
+        table.extend_from_slice(&[127, 4, 0, 0, 0, 0]);
+
+        let info = parse_hardware_info(&table);
+        assert_eq!(info.bios_vendor, "LENOVO");
+        assert_eq!(info.bios_version, "N32ET86W (1.62 )");
+        assert_eq!(info.bios_release_date, "02/14/2023");
+        assert_eq!(info.manufacturer, "LENOVO");
+        assert_eq!(info.product_name, "20XWCTO1WW");
+        assert_eq!(info.product_version, "ThinkPad X1 Carbon Gen 9");
+        assert_eq!(info.serial_number, "PF2ABCDE");
+        assert_eq!(info.board_vendor, "LENOVO");
+        assert_eq!(info.board_name, "20XWCTO1WW");
+        assert_eq!(info.board_serial_number, "");
+        assert_eq!(info.chassis, ChassisKind::Laptop);
+    }
+
+    #[test]
+    fn truncated_table() {
+        let table = memory_device(8192, 0, 0x1A, &["DIMM_A1"]);
+        assert!(parse_memory_modules(&table[..table.len() - 1]).is_empty());
+        assert!(parse_memory_modules(&table[..10]).is_empty());
+    }
+}
@@ -1,81 +1,372 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+// This file is generated by `cbindgen` (run `make header`), don't edit it manually.
+//
+// Ownership rules:
+//
+//  * The `CSystem`, `CDisks`, `CNetworks` and `CComponents` objects returned by the `*_init`
+//    functions must be freed with the matching `*_destroy` function.
+//  * The `RString` values returned by the functions must be freed with `sysinfo_rstring_free`,
+//    and the `RString` arrays (ended by a `NULL` pointer) with `sysinfo_rstrings_free`.
+//  * The `CProcess`, `CDisk`, `CNetworkData` and `CComponent` values are borrowed from the object
+//    they come from: they must not be freed and are only valid until this object is refreshed or
+//    destroyed.
+//  * The `RString` values given to the callbacks are only valid during the call.
+
 #pragma once
 
 #include <sys/types.h>
 #include <stdbool.h>
 #include <stdint.h>
 
-typedef void* CSystem;
-typedef const void* CProcess;
-typedef const char* RString;
-typedef void* CNetworks;
-typedef void* CDisks;
+// Equivalent of [`System`][crate::System] struct.
+typedef void *CSystem;
 
-#ifdef WIN32
+#if defined(WIN32)
+// on windows, libc has not include pid_t.
 typedef size_t PID;
-#else
+#endif
+
+#if !defined(WIN32)
+// other platforms, use libc::pid_t
 typedef pid_t PID;
 #endif
 
+// Equivalent of [`Disks`][crate::Disks] struct.
+typedef void *CDisks;
+
+// Equivalent of [`Disk`][crate::Disk] struct.
+typedef const void *CDisk;
+
+// Callback used by [`sysinfo_disks`].
+typedef bool (*DiskLoop)(CDisk disk, void *data);
+
+// C string returned from `CString::into_raw`.
+typedef const char *RString;
+
+// Equivalent of [`Networks`][crate::Networks] struct.
+typedef void *CNetworks;
+
+// Equivalent of [`NetworkData`][crate::NetworkData] struct.
+typedef const void *CNetworkData;
+
+// Callback used by [`sysinfo_networks`]. `interface_name` is only valid during the call.
+typedef bool (*NetworkLoop)(RString interface_name, CNetworkData network, void *data);
+
+// Equivalent of [`Components`][crate::Components] struct.
+typedef void *CComponents;
+
+// Equivalent of [`Component`][crate::Component] struct.
+typedef const void *CComponent;
+
+// Callback used by [`sysinfo_components`].
+typedef bool (*ComponentLoop)(CComponent component, void *data);
 
-CSystem     sysinfo_init(void);
-void        sysinfo_destroy(CSystem system);
-CNetworks   sysinfo_networks_init(void);
-void        sysinfo_networks_destroy(CNetworks networks);
+// Equivalent of [`Process`][crate::Process] struct.
+typedef const void *CProcess;
 
-void        sysinfo_refresh_system(CSystem system);
-void        sysinfo_refresh_memory(CSystem system);
-void        sysinfo_refresh_cpu(CSystem system);
-void        sysinfo_refresh_all(CSystem system);
-void        sysinfo_refresh_processes(CSystem system);
-#ifdef __linux__
-void        sysinfo_refresh_process(CSystem system, PID pid);
+// Callback used by [`processes`][crate::System#method.processes].
+typedef bool (*ProcessLoop)(PID pid, CProcess process, void *data);
+
+// Equivalent of [`DiskUsage`][crate::DiskUsage] struct.
+typedef struct CDiskUsage {
+  // Total number of written bytes.
+  uint64_t total_written_bytes;
+  // Number of written bytes since the last refresh.
+  uint64_t written_bytes;
+  // Total number of read bytes.
+  uint64_t total_read_bytes;
+  // Number of read bytes since the last refresh.
+  uint64_t read_bytes;
+} CDiskUsage;
+
+// Equivalent of [`System::new()`][crate::System#method.new].
+CSystem sysinfo_init(void);
+
+// Equivalent of `System::drop()`. Important in C to cleanup memory.
+void sysinfo_destroy(CSystem system);
+
+// Equivalent of [`System::refresh_system()`][crate::System#method.refresh_system].
+void sysinfo_refresh_system(CSystem system);
+
+// Equivalent of [`System::refresh_memory()`][crate::System#method.refresh_memory].
+void sysinfo_refresh_memory(CSystem system);
+
+// Equivalent of [`System::refresh_cpu_usage()`][crate::System#method.refresh_cpu_usage].
+void sysinfo_refresh_cpu(CSystem system);
+
+// Equivalent of [`System::refresh_all()`][crate::System#method.refresh_all].
+void sysinfo_refresh_all(CSystem system);
+
+// Equivalent of [`System::refresh_processes()`][crate::System#method.refresh_processes].
+void sysinfo_refresh_processes(CSystem system);
+
+#if defined(__linux__)
+// Equivalent of [`System::refresh_process()`][crate::System#method.refresh_process].
+void sysinfo_refresh_process(CSystem system, PID pid);
 #endif
 
-CDisks      sysinfo_disks_init(void);
-void        sysinfo_disks_destroy(CDisks disks);
-void        sysinfo_disks_refresh(CDisks disks);
-void        sysinfo_disks_refresh_list(CDisks disks);
-
-size_t      sysinfo_total_memory(CSystem system);
-size_t      sysinfo_free_memory(CSystem system);
-size_t      sysinfo_used_memory(CSystem system);
-size_t      sysinfo_total_swap(CSystem system);
-size_t      sysinfo_free_swap(CSystem system);
-size_t      sysinfo_used_swap(CSystem system);
-
-void        sysinfo_cpus_usage(CSystem system, unsigned int *length, float **cpus);
-
-size_t      sysinfo_processes(CSystem system, bool (*fn_pointer)(PID, CProcess, void*),
-                              void *data);
-#ifdef __linux__
-size_t      sysinfo_process_tasks(CProcess process, bool (*fn_pointer)(PID, CProcess, void*),
-                                  void *data);
+// Equivalent of [`Disks::new()`][crate::Disks#method.new].
+CDisks sysinfo_disks_init(void);
+
+// Equivalent of `Disks::drop()`. Important in C to cleanup memory.
+void sysinfo_disks_destroy(CDisks disks);
+
+// Equivalent of [`Disks::refresh()`][crate::Disks#method.refresh].
+void sysinfo_disks_refresh(CDisks disks);
+
+// Equivalent of [`Disks::refresh_list()`][crate::Disks#method.refresh_list].
+void sysinfo_disks_refresh_list(CDisks disks);
+
+// Equivalent of iterating over [`Disks::list()`][crate::Disks#method.list]. Returns the number
+// of disks.
+//
+// # ⚠️ WARNING ⚠️
+//
+// The `CDisk` values are only valid until the next refresh of `disks`!
+size_t sysinfo_disks(CDisks disks, DiskLoop fn_pointer, void *data);
+
+// Equivalent of [`Disk::name()`][crate::Disk#method.name].
+RString sysinfo_disk_name(CDisk disk);
+
+// Equivalent of [`Disk::file_system()`][crate::Disk#method.file_system].
+RString sysinfo_disk_file_system(CDisk disk);
+
+// Equivalent of [`Disk::mount_point()`][crate::Disk#method.mount_point].
+RString sysinfo_disk_mount_point(CDisk disk);
+
+// Equivalent of [`Disk::total_space()`][crate::Disk#method.total_space].
+uint64_t sysinfo_disk_total_space(CDisk disk);
+
+// Equivalent of [`Disk::available_space()`][crate::Disk#method.available_space].
+uint64_t sysinfo_disk_available_space(CDisk disk);
+
+// Equivalent of [`Disk::is_removable()`][crate::Disk#method.is_removable].
+bool sysinfo_disk_is_removable(CDisk disk);
+
+// Equivalent of [`System::total_memory()`][crate::System#method.total_memory].
+size_t sysinfo_total_memory(CSystem system);
+
+// Equivalent of [`System::free_memory()`][crate::System#method.free_memory].
+size_t sysinfo_free_memory(CSystem system);
+
+// Equivalent of [`System::used_memory()`][crate::System#method.used_memory].
+size_t sysinfo_used_memory(CSystem system);
+
+// Equivalent of [`System::total_swap()`][crate::System#method.total_swap].
+size_t sysinfo_total_swap(CSystem system);
+
+// Equivalent of [`System::free_swap()`][crate::System#method.free_swap].
+size_t sysinfo_free_swap(CSystem system);
+
+// Equivalent of [`System::used_swap()`][crate::System#method.used_swap].
+size_t sysinfo_used_swap(CSystem system);
+
+// Equivalent of [`Networks::new()`][crate::Networks#method.new].
+CNetworks sysinfo_networks_init(void);
+
+// Equivalent of `Networks::drop()`. Important in C to cleanup memory.
+void sysinfo_networks_destroy(CNetworks networks);
+
+// Equivalent of [`Networks::refresh_list()`][crate::Networks#method.refresh_list].
+void sysinfo_networks_refresh_list(CNetworks networks);
+
+// Equivalent of [`Networks::refresh()`][crate::Networks#method.refresh].
+void sysinfo_networks_refresh(CNetworks networks);
+
+// Equivalent of
+// `system::networks().iter().fold(0, |acc, (_, data)| acc + data.received() as size_t)`.
+size_t sysinfo_networks_received(CNetworks networks);
+
+// Equivalent of
+// `system::networks().iter().fold(0, |acc, (_, data)| acc + data.transmitted() as size_t)`.
+size_t sysinfo_networks_transmitted(CNetworks networks);
+
+// Equivalent of iterating over [`Networks::list()`][crate::Networks#method.list]. Returns the
+// number of network interfaces.
+//
+// # ⚠️ WARNING ⚠️
+//
+// The `CNetworkData` values are only valid until the next refresh of `networks`!
+size_t sysinfo_networks(CNetworks networks, NetworkLoop fn_pointer, void *data);
+
+// Equivalent of [`NetworkData::received()`][crate::NetworkData#method.received].
+uint64_t sysinfo_network_received(CNetworkData network);
+
+// Equivalent of [`NetworkData::total_received()`][crate::NetworkData#method.total_received].
+uint64_t sysinfo_network_total_received(CNetworkData network);
+
+// Equivalent of [`NetworkData::transmitted()`][crate::NetworkData#method.transmitted].
+uint64_t sysinfo_network_transmitted(CNetworkData network);
+
+// Equivalent of [`NetworkData::total_transmitted()`][crate::NetworkData#method.total_transmitted].
+uint64_t sysinfo_network_total_transmitted(CNetworkData network);
+
+// Equivalent of [`NetworkData::packets_received()`][crate::NetworkData#method.packets_received].
+uint64_t sysinfo_network_packets_received(CNetworkData network);
+
+// Equivalent of [`NetworkData::total_packets_received()`][crate::NetworkData#method.total_packets_received].
+uint64_t sysinfo_network_total_packets_received(CNetworkData network);
+
+// Equivalent of [`NetworkData::packets_transmitted()`][crate::NetworkData#method.packets_transmitted].
+uint64_t sysinfo_network_packets_transmitted(CNetworkData network);
+
+// Equivalent of [`NetworkData::total_packets_transmitted()`][crate::NetworkData#method.total_packets_transmitted].
+uint64_t sysinfo_network_total_packets_transmitted(CNetworkData network);
+
+// Equivalent of [`Components::new()`][crate::Components#method.new].
+CComponents sysinfo_components_init(void);
+
+// Equivalent of `Components::drop()`. Important in C to cleanup memory.
+void sysinfo_components_destroy(CComponents components);
+
+// Equivalent of [`Components::refresh()`][crate::Components#method.refresh].
+void sysinfo_components_refresh(CComponents components);
+
+// Equivalent of [`Components::refresh_list()`][crate::Components#method.refresh_list].
+void sysinfo_components_refresh_list(CComponents components);
+
+// Equivalent of iterating over [`Components::list()`][crate::Components#method.list]. Returns
+// the number of components.
+//
+// # ⚠️ WARNING ⚠️
+//
+// The `CComponent` values are only valid until the next refresh of `components`!
+size_t sysinfo_components(CComponents components, ComponentLoop fn_pointer, void *data);
+
+// Equivalent of [`Component::label()`][crate::Component#method.label].
+RString sysinfo_component_label(CComponent component);
+
+// Equivalent of [`Component::temperature()`][crate::Component#method.temperature].
+float sysinfo_component_temperature(CComponent component);
+
+// Equivalent of [`Component::max()`][crate::Component#method.max].
+float sysinfo_component_max(CComponent component);
+
+// Equivalent of [`Component::critical()`][crate::Component#method.critical].
+//
+// In case there is no known critical temperature, it returns `NaN`.
+float sysinfo_component_critical(CComponent component);
+
+// Equivalent of [`System::cpus_usage()`][crate::System#method.cpus_usage].
+//
+// * `length` will contain the number of CPU usage added into `procs`.
+// * `procs` will be allocated if it's null and will contain of CPU usage.
+void sysinfo_cpus_usage(CSystem system, unsigned int *length, float **procs);
+
+// Equivalent of [`System::processes()`][crate::System#method.processes]. Returns an
+// array ended by a null pointer. Must be freed.
+//
+// # ⚠️ WARNING ⚠️
+//
+// While having this method returned processes, you should *never* call any refresh method!
+size_t sysinfo_processes(CSystem system, ProcessLoop fn_pointer, void *data);
+
+// Equivalent of [`System::process()`][crate::System#method.process].
+//
+// # ⚠️ WARNING ⚠️
+//
+// While having this method returned process, you should *never* call any
+// refresh method!
+CProcess sysinfo_process_by_pid(CSystem system, PID pid);
+
+#if defined(__linux__)
+// Equivalent of iterating over [`Process::tasks()`][crate::Process#method.tasks].
+//
+// # ⚠️ WARNING ⚠️
+//
+// While having this method processes, you should *never* call any refresh method!
+size_t sysinfo_process_tasks(CProcess process, ProcessLoop fn_pointer, void *data);
 #endif
-CProcess    sysinfo_process_by_pid(CSystem system, PID pid);
-PID         sysinfo_process_pid(CProcess process);
-PID         sysinfo_process_parent_pid(CProcess process);
-float       sysinfo_process_cpu_usage(CProcess process);
-size_t      sysinfo_process_memory(CProcess process);
-size_t      sysinfo_process_virtual_memory(CProcess process);
-RString     sysinfo_process_executable_path(CProcess process);
-RString     sysinfo_process_root_directory(CProcess process);
-RString     sysinfo_process_current_directory(CProcess process);
-void        sysinfo_networks_refresh_list(CNetworks networks);
-void        sysinfo_networks_refresh(CNetworks networks);
-size_t      sysinfo_networks_received(CNetworks networks);
-size_t      sysinfo_networks_transmitted(CNetworks networks);
-
-RString     sysinfo_cpu_vendor_id(CSystem system);
-RString     sysinfo_cpu_brand(CSystem system);
-uint64_t    sysinfo_cpu_frequency(CSystem system);
-uint32_t    sysinfo_cpu_physical_cores(CSystem system);
-
-RString     sysinfo_system_name(CSystem system);
-RString     sysinfo_system_kernel_version(CSystem system);
-RString     sysinfo_system_version(CSystem system);
-RString     sysinfo_system_host_name(CSystem system);
-RString     sysinfo_system_long_version(CSystem system);
-
-void        sysinfo_rstring_free(RString str);
\ No newline at end of file
+
+// Equivalent of [`Process::pid()`][crate::Process#method.pid].
+PID sysinfo_process_pid(CProcess process);
+
+// Equivalent of [`Process::parent()`][crate::Process#method.parent].
+//
+// In case there is no known parent, it returns `0`.
+PID sysinfo_process_parent_pid(CProcess process);
+
+// Equivalent of [`Process::cpu_usage()`][crate::Process#method.cpu_usage].
+float sysinfo_process_cpu_usage(CProcess process);
+
+// Equivalent of [`Process::memory()`][crate::Process#method.memory].
+size_t sysinfo_process_memory(CProcess process);
+
+// Equivalent of [`Process::virtual_memory()`][crate::Process#method.virtual_memory].
+size_t sysinfo_process_virtual_memory(CProcess process);
+
+// Equivalent of [`Process::name()`][crate::Process#method.name].
+RString sysinfo_process_name(CProcess process);
+
+// Equivalent of [`Process::cmd()`][crate::Process#method.cmd]. Returns an array ended by a
+// null pointer, which must be freed with [`sysinfo_rstrings_free`].
+RString *sysinfo_process_cmd(CProcess process);
+
+// Equivalent of [`Process::environ()`][crate::Process#method.environ]. Returns an array ended
+// by a null pointer, which must be freed with [`sysinfo_rstrings_free`].
+RString *sysinfo_process_environ(CProcess process);
+
+// Equivalent of [`Process::start_time()`][crate::Process#method.start_time].
+uint64_t sysinfo_process_start_time(CProcess process);
+
+// Equivalent of [`Process::run_time()`][crate::Process#method.run_time].
+uint64_t sysinfo_process_run_time(CProcess process);
+
+// Equivalent of [`Process::disk_usage()`][crate::Process#method.disk_usage].
+struct CDiskUsage sysinfo_process_disk_usage(CProcess process);
+
+// Equivalent of [`Process::user_id()`][crate::Process#method.user_id], formatted as a string
+// (like `1000`, or `S-1-5-18` on Windows).
+//
+// In case there is no known user, it returns `NULL`.
+RString sysinfo_process_user_id(CProcess process);
+
+// Equivalent of [`Uid::resolve_name()`][crate::Uid#method.resolve_name] on the user of the
+// process.
+//
+// In case there is no known user, it returns `NULL`.
+RString sysinfo_process_user_name(CProcess process);
+
+// Equivalent of [`Process::exe()`][crate::Process#method.exe].
+RString sysinfo_process_executable_path(CProcess process);
+
+// Equivalent of [`Process::root()`][crate::Process#method.root].
+RString sysinfo_process_root_directory(CProcess process);
+
+// Equivalent of [`Process::cwd()`][crate::Process#method.cwd].
+RString sysinfo_process_current_directory(CProcess process);
+
+// Frees a C string created with `CString::into_raw()`.
+void sysinfo_rstring_free(RString s);
+
+// Frees an array of C strings ended by a null pointer, like the ones returned by
+// [`sysinfo_process_cmd`] and [`sysinfo_process_environ`].
+void sysinfo_rstrings_free(RString *strings);
+
+// Equivalent of [`cpu::vendor_id()`].
+RString sysinfo_cpu_vendor_id(CSystem system);
+
+// Equivalent of [`cpu::brand()`].
+RString sysinfo_cpu_brand(CSystem system);
+
+// Equivalent of [`system::physical_core_count()`].
+uint32_t sysinfo_cpu_physical_cores(CSystem system);
+
+// Equivalent of [`cpu::frequency()`].
+uint64_t sysinfo_cpu_frequency(CSystem system);
+
+// Equivalent of [`System::name()`][crate::System#method.name].
+RString sysinfo_system_name(CSystem system);
+
+// Equivalent of [`System::version()`][crate::System#method.version].
+RString sysinfo_system_version(CSystem system);
+
+// Equivalent of [`System::kernel_version()`][crate::System#method.kernel_version].
+RString sysinfo_system_kernel_version(CSystem system);
+
+// Equivalent of [`System::host_name()`][crate::System#method.host_name].
+RString sysinfo_system_host_name(CSystem system);
+
+// Equivalent of [`System::long_os_version()`][crate::System#method.long_os_version].
+RString sysinfo_system_long_version(CSystem system);
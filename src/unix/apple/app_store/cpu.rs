@@ -0,0 +1,13 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+pub(crate) struct CpuFrequencySampler;
+
+impl CpuFrequencySampler {
+    pub(crate) fn new() -> Self {
+        Self
+    }
+
+    pub(crate) fn sample(&mut self) -> Option<Vec<u64>> {
+        None
+    }
+}
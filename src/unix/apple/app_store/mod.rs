@@ -1,4 +1,11 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 pub mod component;
+pub(crate) mod connections;
+pub(crate) mod cpu;
+pub(crate) mod gpu;
+pub(crate) mod pci;
+#[cfg(feature = "energy")]
+pub(crate) mod power;
 pub mod process;
+pub(crate) mod services;
@@ -0,0 +1,17 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::PowerRail;
+
+pub(crate) struct PowerInner;
+
+impl PowerInner {
+    pub(crate) fn new() -> Self {
+        Self
+    }
+
+    pub(crate) fn rails(&self) -> &[PowerRail] {
+        &[]
+    }
+
+    pub(crate) fn refresh(&mut self) {}
+}
@@ -1,6 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use std::path::Path;
+use std::time::Duration;
 
 use crate::{DiskUsage, Gid, Pid, ProcessStatus, Signal, Uid};
 
@@ -67,6 +68,20 @@ impl ProcessInner {
         0.0
     }
 
+    pub(crate) fn normalized_cpu_usage(&self) -> f32 {
+        0.0
+    }
+
+    pub(crate) fn set_cpu_usage(&mut self, _cpu_usage: f32, _normalized_cpu_usage: f32) {}
+
+    pub(crate) fn user_time(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    pub(crate) fn kernel_time(&self) -> Duration {
+        Duration::ZERO
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage::default()
     }
@@ -92,4 +107,12 @@ impl ProcessInner {
     pub(crate) fn session_id(&self) -> Option<Pid> {
         None
     }
+
+    pub(crate) fn is_translated(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_remote_session(&self) -> bool {
+        false
+    }
 }
@@ -1,17 +1,23 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::sys::utils::{get_sys_value, get_sys_value_by_name};
-use crate::{Cpu, CpuRefreshKind};
+use crate::sys::component::CpuTemperatures;
+use crate::sys::inner::cpu::CpuFrequencySampler;
+use crate::sys::utils::{get_sys_value, get_sys_value_by_name, get_sysctl_u32};
+use crate::{Cpu, CpuRefreshKind, CpuTimes};
 
 use libc::{c_char, c_void, host_processor_info, mach_port_t, mach_task_self};
 use std::mem;
 use std::ops::Deref;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub(crate) struct CpusWrapper {
     pub(crate) global_cpu: Cpu,
     pub(crate) cpus: Vec<Cpu>,
-    pub(crate) got_cpu_frequency: bool,
+    frequency_sampler: CpuFrequencySampler,
+    temperatures: CpuTemperatures,
+    // Ticks of the previous `refresh_global_cpu_usage` call.
+    global_ticks: Option<[u32; libc::CPU_STATE_MAX as usize]>,
 }
 
 impl CpusWrapper {
@@ -27,24 +33,31 @@ impl CpusWrapper {
                 ),
             },
             cpus: Vec::new(),
-            got_cpu_frequency: false,
+            frequency_sampler: CpuFrequencySampler::new(),
+            temperatures: CpuTemperatures::new(),
+            global_ticks: None,
         }
     }
 
     pub(crate) fn refresh(&mut self, refresh_kind: CpuRefreshKind, port: mach_port_t) {
-        let cpus = &mut self.cpus;
-        if cpus.is_empty() {
-            init_cpus(port, cpus, &mut self.global_cpu, refresh_kind);
-            self.got_cpu_frequency = refresh_kind.frequency();
+        if self.cpus.is_empty() {
+            init_cpus(port, &mut self.cpus, &mut self.global_cpu, refresh_kind);
+            if refresh_kind.frequency() {
+                // The first sample is used as reference for the next refreshes.
+                self.frequency_sampler.sample();
+            }
+            if refresh_kind.temperature() {
+                self.refresh_temperature();
+            }
             return;
         }
-        if refresh_kind.frequency() && !self.got_cpu_frequency {
-            let frequency = unsafe { get_cpu_frequency() };
-            for proc_ in cpus.iter_mut() {
-                proc_.inner.set_frequency(frequency);
-            }
-            self.got_cpu_frequency = true;
+        if refresh_kind.frequency() {
+            self.refresh_frequency();
+        }
+        if refresh_kind.temperature() {
+            self.refresh_temperature();
         }
+        let cpus = &mut self.cpus;
         if refresh_kind.cpu_usage() {
             update_cpu_usage(port, &mut self.global_cpu, |proc_data, cpu_info| {
                 let mut percentage = 0f32;
@@ -52,6 +65,7 @@ impl CpusWrapper {
                 for proc_ in cpus.iter_mut() {
                     let cpu_usage = compute_usage_of_cpu(proc_, cpu_info, offset);
                     proc_.inner.update(cpu_usage, Arc::clone(&proc_data));
+                    proc_.inner.times = get_cpu_times(cpu_info, offset);
                     percentage += proc_.inner.cpu_usage();
 
                     offset += libc::CPU_STATE_MAX as isize;
@@ -60,6 +74,61 @@ impl CpusWrapper {
             });
         }
     }
+
+    pub(crate) fn refresh_global_cpu_usage(&mut self, port: mach_port_t) {
+        let mut info = mem::MaybeUninit::<libc::host_cpu_load_info>::uninit();
+        let mut count = libc::HOST_CPU_LOAD_INFO_COUNT;
+        let ticks = unsafe {
+            if libc::host_statistics(
+                port,
+                libc::HOST_CPU_LOAD_INFO,
+                info.as_mut_ptr() as *mut _,
+                &mut count,
+            ) != libc::KERN_SUCCESS
+            {
+                sysinfo_debug!("host_statistics failed to get CPU load info");
+                return;
+            }
+            info.assume_init().cpu_ticks
+        };
+        if let Some(old_ticks) = self.global_ticks.replace(ticks) {
+            let diff = |state: libc::c_int| {
+                ticks[state as usize].wrapping_sub(old_ticks[state as usize]) as u64
+            };
+            let in_use = diff(libc::CPU_STATE_USER)
+                + diff(libc::CPU_STATE_SYSTEM)
+                + diff(libc::CPU_STATE_NICE);
+            let total = in_use + diff(libc::CPU_STATE_IDLE);
+            if total > 0 {
+                self.global_cpu
+                    .inner
+                    .set_cpu_usage(in_use as f32 / total as f32 * 100.);
+            }
+        }
+    }
+
+    fn refresh_temperature(&mut self) {
+        let temperatures = self.temperatures.temperatures(self.cpus.len());
+        for (cpu, temperature) in self.cpus.iter_mut().zip(temperatures) {
+            cpu.inner.temperature = temperature;
+        }
+    }
+
+    fn refresh_frequency(&mut self) {
+        match self.frequency_sampler.sample() {
+            Some(frequencies) if frequencies.len() == self.cpus.len() => {
+                for (cpu, frequency) in self.cpus.iter_mut().zip(frequencies) {
+                    cpu.inner.set_frequency(frequency);
+                }
+            }
+            _ => {
+                let frequency = unsafe { get_cpu_frequency() };
+                for cpu in self.cpus.iter_mut() {
+                    cpu.inner.set_frequency(frequency);
+                }
+            }
+        }
+    }
 }
 
 pub(crate) struct UnsafePtr<T>(*mut T);
@@ -110,8 +179,14 @@ pub(crate) struct CpuInner {
     cpu_usage: f32,
     cpu_data: Arc<CpuData>,
     frequency: u64,
+    temperature: Option<f32>,
+    times: CpuTimes,
     vendor_id: String,
     brand: String,
+    family: Option<u32>,
+    model: Option<u32>,
+    stepping: Option<u32>,
+    microcode_revision: Option<u64>,
 }
 
 impl CpuInner {
@@ -127,8 +202,14 @@ impl CpuInner {
             cpu_usage: 0f32,
             cpu_data,
             frequency,
+            temperature: None,
+            times: CpuTimes::default(),
             vendor_id,
             brand,
+            family: None,
+            model: None,
+            stepping: None,
+            microcode_revision: None,
         }
     }
 
@@ -153,6 +234,11 @@ impl CpuInner {
         self.cpu_usage
     }
 
+    pub(crate) fn steal_percent(&self) -> f32 {
+        // Steal time is only reported by Linux guests.
+        0.0
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
@@ -161,6 +247,39 @@ impl CpuInner {
         self.frequency
     }
 
+    pub(crate) fn temperature(&self) -> Option<f32> {
+        self.temperature
+    }
+
+    pub(crate) fn times(&self) -> CpuTimes {
+        self.times
+    }
+
+    pub(crate) fn idle_states(&self) -> &[crate::CpuIdleState] {
+        &[]
+    }
+
+    pub(crate) fn governor(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn available_governors(&self) -> &[String] {
+        &[]
+    }
+
+    pub(crate) fn scaling_min_frequency(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn scaling_max_frequency(&self) -> Option<u64> {
+        None
+    }
+
+    #[cfg(feature = "cpufreq-write")]
+    pub(crate) fn set_governor(&self, _governor: &str) -> bool {
+        false
+    }
+
     pub(crate) fn vendor_id(&self) -> &str {
         &self.vendor_id
     }
@@ -168,6 +287,22 @@ impl CpuInner {
     pub(crate) fn brand(&self) -> &str {
         &self.brand
     }
+
+    pub(crate) fn family(&self) -> Option<u32> {
+        self.family
+    }
+
+    pub(crate) fn model(&self) -> Option<u32> {
+        self.model
+    }
+
+    pub(crate) fn stepping(&self) -> Option<u32> {
+        self.stepping
+    }
+
+    pub(crate) fn microcode_revision(&self) -> Option<u64> {
+        self.microcode_revision
+    }
 }
 
 pub(crate) unsafe fn get_cpu_frequency() -> u64 {
@@ -194,6 +329,16 @@ pub(crate) unsafe fn get_cpu_frequency() -> u64 {
     }
 }
 
+#[cfg(target_os = "macos")]
+pub(crate) fn socket_count() -> Option<usize> {
+    get_sysctl_u32(b"hw.packages\0").map(|packages| packages as _)
+}
+
+#[cfg(target_os = "ios")]
+pub(crate) fn socket_count() -> Option<usize> {
+    None
+}
+
 pub(crate) fn physical_core_count() -> Option<usize> {
     let mut physical_core_count = 0;
 
@@ -226,6 +371,26 @@ fn get_idle(cpu_info: *mut i32, offset: isize) -> i32 {
     unsafe { *cpu_info.offset(offset + libc::CPU_STATE_IDLE as isize) }
 }
 
+/// Returns the time spent in each state by the CPU at `offset`.
+fn get_cpu_times(cpu_info: *mut i32, offset: isize) -> CpuTimes {
+    let clock_ticks = match unsafe { libc::sysconf(libc::_SC_CLK_TCK) } {
+        ticks if ticks > 0 => ticks as u64,
+        _ => 100,
+    };
+    // Values are `natural_t` ticks.
+    let get = |state: libc::c_int| unsafe {
+        let ticks = *cpu_info.offset(offset + state as isize) as u32 as u64;
+        Duration::from_millis(ticks * 1_000 / clock_ticks)
+    };
+    CpuTimes {
+        user: get(libc::CPU_STATE_USER),
+        nice: get(libc::CPU_STATE_NICE),
+        system: get(libc::CPU_STATE_SYSTEM),
+        idle: get(libc::CPU_STATE_IDLE),
+        ..Default::default()
+    }
+}
+
 pub(crate) fn compute_usage_of_cpu(proc_: &Cpu, cpu_info: *mut i32, offset: isize) -> f32 {
     let old_cpu_info = proc_.inner.data().cpu_info.0;
     let in_use;
@@ -267,36 +432,58 @@ pub(crate) fn update_cpu_usage<F: FnOnce(Arc<CpuData>, *mut i32) -> (f32, usize)
             let (total_percentage, len) =
                 f(Arc::new(CpuData::new(cpu_info, num_cpu_info)), cpu_info);
             total_cpu_usage = total_percentage / len as f32;
+
+            let mut times = CpuTimes::default();
+            for pos in 0..len {
+                let cpu_times = get_cpu_times(cpu_info, (pos * libc::CPU_STATE_MAX as usize) as _);
+                times.user += cpu_times.user;
+                times.nice += cpu_times.nice;
+                times.system += cpu_times.system;
+                times.idle += cpu_times.idle;
+            }
+            global_cpu.inner.times = times;
         }
         global_cpu.inner.set_cpu_usage(total_cpu_usage);
     }
 }
 
+pub(crate) fn get_nb_cpus() -> usize {
+    let mut num_cpu: u32 = 0;
+    let mut mib = [libc::CTL_HW as _, libc::HW_NCPU as _];
+
+    unsafe {
+        if !get_sys_value(
+            mem::size_of::<u32>(),
+            &mut num_cpu as *mut _ as *mut _,
+            &mut mib,
+        ) || num_cpu < 1
+        {
+            num_cpu = 1;
+        }
+    }
+    num_cpu as usize
+}
+
 pub(crate) fn init_cpus(
     port: libc::mach_port_t,
     cpus: &mut Vec<Cpu>,
     global_cpu: &mut Cpu,
     refresh_kind: CpuRefreshKind,
 ) {
-    let mut num_cpu = 0;
-    let mut mib = [libc::CTL_HW as _, libc::HW_NCPU as _];
+    let num_cpu = get_nb_cpus();
 
     let (vendor_id, brand) = get_vendor_id_and_brand();
+    // These entries only exist on Intel CPUs.
+    let family = get_sysctl_u32(b"machdep.cpu.family\0");
+    let model = get_sysctl_u32(b"machdep.cpu.model\0");
+    let stepping = get_sysctl_u32(b"machdep.cpu.stepping\0");
+    let microcode_revision = get_sysctl_u32(b"machdep.cpu.microcode_version\0").map(u64::from);
     let frequency = if refresh_kind.frequency() {
         unsafe { get_cpu_frequency() }
     } else {
         global_cpu.frequency()
     };
 
-    unsafe {
-        if !get_sys_value(
-            mem::size_of::<u32>(),
-            &mut num_cpu as *mut _ as *mut _,
-            &mut mib,
-        ) {
-            num_cpu = 1;
-        }
-    }
     update_cpu_usage(port, global_cpu, |proc_data, cpu_info| {
         let mut percentage = 0f32;
         let mut offset = 0;
@@ -315,6 +502,11 @@ pub(crate) fn init_cpus(
                 cpu.inner.set_cpu_usage(cpu_usage);
                 percentage += cpu.cpu_usage();
             }
+            cpu.inner.times = get_cpu_times(cpu_info, offset);
+            cpu.inner.family = family;
+            cpu.inner.model = model;
+            cpu.inner.stepping = stepping;
+            cpu.inner.microcode_revision = microcode_revision;
             cpus.push(cpu);
 
             offset += libc::CPU_STATE_MAX as isize;
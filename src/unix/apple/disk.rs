@@ -4,7 +4,8 @@ use crate::sys::{
     ffi,
     utils::{self, CFReleaser},
 };
-use crate::{Disk, DiskKind};
+use crate::utils::parse_remote_location;
+use crate::{BlockDevice, Disk, DiskKind, DiskQuota, DiskRefreshKind, Gid, PhysicalDisk, Uid};
 
 use core_foundation_sys::array::CFArrayCreate;
 use core_foundation_sys::base::kCFAllocatorDefault;
@@ -28,6 +29,49 @@ pub(crate) struct DiskInner {
     pub(crate) total_space: u64,
     pub(crate) available_space: u64,
     pub(crate) is_removable: bool,
+    uuid: Option<String>,
+    vendor: Option<String>,
+    model: Option<String>,
+    serial_number: Option<String>,
+    physical_disk: Option<String>,
+    is_rotational: Option<bool>,
+    logical_sector_size: Option<u64>,
+    physical_sector_size: Option<u64>,
+    supports_trim: Option<bool>,
+    is_network: bool,
+    remote_host: Option<String>,
+    remote_path: Option<String>,
+    // `false` if the volume properties must not be queried for this disk.
+    refresh_space: bool,
+}
+
+/// Information about the device containing a disk.
+pub(crate) struct DiskInfo {
+    pub(crate) kind: DiskKind,
+    pub(crate) vendor: Option<String>,
+    pub(crate) model: Option<String>,
+    pub(crate) serial_number: Option<String>,
+    pub(crate) physical_disk: Option<PhysicalDisk>,
+    pub(crate) is_rotational: Option<bool>,
+    pub(crate) logical_sector_size: Option<u64>,
+    pub(crate) physical_sector_size: Option<u64>,
+    pub(crate) supports_trim: Option<bool>,
+}
+
+impl DiskInfo {
+    fn new(kind: DiskKind) -> Self {
+        Self {
+            kind,
+            vendor: None,
+            model: None,
+            serial_number: None,
+            physical_disk: None,
+            is_rotational: None,
+            logical_sector_size: None,
+            physical_sector_size: None,
+            supports_trim: None,
+        }
+    }
 }
 
 impl DiskInner {
@@ -59,7 +103,74 @@ impl DiskInner {
         self.is_removable
     }
 
+    pub(crate) fn uuid(&self) -> Option<&str> {
+        self.uuid.as_deref()
+    }
+
+    pub(crate) fn vendor(&self) -> Option<&str> {
+        self.vendor.as_deref()
+    }
+
+    pub(crate) fn model(&self) -> Option<&str> {
+        self.model.as_deref()
+    }
+
+    pub(crate) fn serial_number(&self) -> Option<&str> {
+        self.serial_number.as_deref()
+    }
+
+    pub(crate) fn physical_disk(&self) -> Option<&str> {
+        self.physical_disk.as_deref()
+    }
+
+    pub(crate) fn storage_pool(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn block_device(&self) -> Option<&BlockDevice> {
+        None
+    }
+
+    pub(crate) fn is_rotational(&self) -> Option<bool> {
+        self.is_rotational
+    }
+
+    pub(crate) fn logical_sector_size(&self) -> Option<u64> {
+        self.logical_sector_size
+    }
+
+    pub(crate) fn physical_sector_size(&self) -> Option<u64> {
+        self.physical_sector_size
+    }
+
+    pub(crate) fn supports_trim(&self) -> Option<bool> {
+        self.supports_trim
+    }
+
+    pub(crate) fn user_quota(&self, _user_id: &Uid) -> Option<DiskQuota> {
+        None
+    }
+
+    pub(crate) fn group_quota(&self, _group_id: Gid) -> Option<DiskQuota> {
+        None
+    }
+
+    pub(crate) fn is_network(&self) -> bool {
+        self.is_network
+    }
+
+    pub(crate) fn remote_host(&self) -> Option<&str> {
+        self.remote_host.as_deref()
+    }
+
+    pub(crate) fn remote_path(&self) -> Option<&str> {
+        self.remote_path.as_deref()
+    }
+
     pub(crate) fn refresh(&mut self) -> bool {
+        if !self.refresh_space {
+            return false;
+        }
         unsafe {
             if let Some(requested_properties) = build_requested_properties(&[
                 ffi::kCFURLVolumeAvailableCapacityKey,
@@ -87,12 +198,14 @@ impl crate::DisksInner {
     pub(crate) fn new() -> Self {
         Self {
             disks: Vec::with_capacity(2),
+            physical_disks: Vec::new(),
+            storage_pools: Vec::new(),
         }
     }
 
-    pub(crate) fn refresh_list(&mut self) {
+    pub(crate) fn refresh_list(&mut self, refresh_kind: DiskRefreshKind) {
         unsafe {
-            get_list(&mut self.disks);
+            get_list(&mut self.disks, &mut self.physical_disks, refresh_kind);
         }
     }
 
@@ -105,8 +218,13 @@ impl crate::DisksInner {
     }
 }
 
-unsafe fn get_list(container: &mut Vec<Disk>) {
+unsafe fn get_list(
+    container: &mut Vec<Disk>,
+    physical_disks: &mut Vec<PhysicalDisk>,
+    refresh_kind: DiskRefreshKind,
+) {
     container.clear();
+    physical_disks.clear();
 
     let raw_disks = {
         let count = libc::getfsstat(ptr::null_mut(), 0, libc::MNT_NOWAIT);
@@ -135,6 +253,7 @@ unsafe fn get_list(container: &mut Vec<Disk>) {
         ffi::kCFURLVolumeAvailableCapacityForImportantUsageKey,
         ffi::kCFURLVolumeAvailableCapacityKey,
         ffi::kCFURLVolumeNameKey,
+        ffi::kCFURLVolumeUUIDStringKey,
         ffi::kCFURLVolumeIsBrowsableKey,
         ffi::kCFURLVolumeIsLocalKey,
     ]) {
@@ -161,6 +280,20 @@ unsafe fn get_list(container: &mut Vec<Disk>) {
             }
         };
 
+        let mount_point = PathBuf::from(OsStr::from_bytes(
+            CStr::from_ptr(c_disk.f_mntonname.as_ptr()).to_bytes(),
+        ));
+
+        let is_network = (c_disk.f_flags & libc::MNT_LOCAL as u32) == 0;
+        if is_network && !refresh_kind.network_disks() {
+            continue;
+        }
+        if is_network && !refresh_kind.network_disks_space() {
+            // Getting the volume properties requires to contact the server, which may hang.
+            container.push(new_network_disk(mount_point, volume_url, &c_disk));
+            continue;
+        }
+
         let prop_dict = match get_disk_properties(&volume_url, &requested_properties) {
             Some(props) => props,
             None => continue,
@@ -192,19 +325,22 @@ unsafe fn get_list(container: &mut Vec<Disk>) {
         )
         .unwrap_or(true);
 
-        // Skip any drive that is not locally attached to the system.
+        // Skip any drive that is not locally attached to the system, unless the network disks
+        // were requested.
         //
         // This includes items like SMB mounts, and matches the other platform's behavior.
-        if !local_only {
+        if !local_only && !refresh_kind.network_disks() {
             continue;
         }
 
-        let mount_point = PathBuf::from(OsStr::from_bytes(
-            CStr::from_ptr(c_disk.f_mntonname.as_ptr()).to_bytes(),
-        ));
-
-        if let Some(disk) = new_disk(mount_point, volume_url, c_disk, &prop_dict) {
+        if let Some((disk, physical_disk)) = new_disk(mount_point, volume_url, c_disk, &prop_dict) {
             container.push(disk);
+            // Only the physical disks containing a listed disk are known.
+            if let Some(physical_disk) = physical_disk {
+                if !physical_disks.iter().any(|d| d.name == physical_disk.name) {
+                    physical_disks.push(physical_disk);
+                }
+            }
         }
     }
 }
@@ -262,7 +398,7 @@ pub(super) enum DictKey {
     Defined(&'static str),
 }
 
-unsafe fn get_dict_value<T, F: FnOnce(*const c_void) -> Option<T>>(
+pub(super) unsafe fn get_dict_value<T, F: FnOnce(*const c_void) -> Option<T>>(
     dict: CFDictionaryRef,
     key: DictKey,
     callback: F,
@@ -295,39 +431,39 @@ unsafe fn get_dict_value<T, F: FnOnce(*const c_void) -> Option<T>>(
 }
 
 pub(super) unsafe fn get_str_value(dict: CFDictionaryRef, key: DictKey) -> Option<String> {
-    get_dict_value(dict, key, |v| {
-        let v = v as cfs::CFStringRef;
-
-        let len_utf16 = cfs::CFStringGetLength(v) as usize;
-        let len_bytes = len_utf16 * 2; // Two bytes per UTF-16 codepoint.
-
-        let v_ptr = cfs::CFStringGetCStringPtr(v, cfs::kCFStringEncodingUTF8);
-        if v_ptr.is_null() {
-            // Fallback on CFStringGetString to read the underlying bytes from the CFString.
-            let mut buf = vec![0; len_bytes];
-            let success = cfs::CFStringGetCString(
-                v,
-                buf.as_mut_ptr(),
-                len_bytes as _,
-                cfs::kCFStringEncodingUTF8,
-            );
+    get_dict_value(dict, key, |v| cfstring_to_string(v as cfs::CFStringRef))
+}
 
-            if success != 0 {
-                utils::vec_to_rust(buf)
-            } else {
-                None
-            }
+pub(super) unsafe fn cfstring_to_string(v: cfs::CFStringRef) -> Option<String> {
+    let len_utf16 = cfs::CFStringGetLength(v) as usize;
+    let len_bytes = len_utf16 * 2; // Two bytes per UTF-16 codepoint.
+
+    let v_ptr = cfs::CFStringGetCStringPtr(v, cfs::kCFStringEncodingUTF8);
+    if v_ptr.is_null() {
+        // Fallback on CFStringGetString to read the underlying bytes from the CFString.
+        let mut buf = vec![0; len_bytes];
+        let success = cfs::CFStringGetCString(
+            v,
+            buf.as_mut_ptr(),
+            len_bytes as _,
+            cfs::kCFStringEncodingUTF8,
+        );
+
+        if success != 0 {
+            utils::vec_to_rust(buf)
         } else {
-            crate::unix::utils::cstr_to_rust_with_size(v_ptr, Some(len_bytes))
+            None
         }
-    })
+    } else {
+        crate::unix::utils::cstr_to_rust_with_size(v_ptr, Some(len_bytes))
+    }
 }
 
-unsafe fn get_bool_value(dict: CFDictionaryRef, key: DictKey) -> Option<bool> {
+pub(super) unsafe fn get_bool_value(dict: CFDictionaryRef, key: DictKey) -> Option<bool> {
     get_dict_value(dict, key, |v| Some(v as CFBooleanRef == kCFBooleanTrue))
 }
 
-unsafe fn get_int_value(dict: CFDictionaryRef, key: DictKey) -> Option<i64> {
+pub(super) unsafe fn get_int_value(dict: CFDictionaryRef, key: DictKey) -> Option<i64> {
     get_dict_value(dict, key, |v| {
         let mut val: i64 = 0;
         if CFNumberGetValue(
@@ -347,15 +483,19 @@ unsafe fn new_disk(
     volume_url: RetainedCFURL,
     c_disk: libc::statfs,
     disk_props: &RetainedCFDictionary,
-) -> Option<Disk> {
+) -> Option<(Disk, Option<PhysicalDisk>)> {
     // IOKit is not available on any but the most recent (16+) iOS and iPadOS versions.
     // Due to this, we can't query the medium type. All iOS devices use flash-based storage
     // so we just assume the disk type is an SSD until Rust has a way to conditionally link to
     // IOKit in more recent deployment versions.
     #[cfg(target_os = "macos")]
-    let type_ = crate::sys::inner::disk::get_disk_type(&c_disk).unwrap_or(DiskKind::Unknown(-1));
+    let info = crate::sys::inner::disk::get_disk_info(&c_disk)
+        .unwrap_or_else(|| DiskInfo::new(DiskKind::Unknown(-1)));
     #[cfg(not(target_os = "macos"))]
-    let type_ = DiskKind::SSD;
+    let info = DiskInfo {
+        is_rotational: Some(false),
+        ..DiskInfo::new(DiskKind::SSD)
+    };
 
     // Note: Since we requested these properties from the system, we don't expect
     // these property retrievals to fail.
@@ -403,23 +543,18 @@ unsafe fn new_disk(
 
     let available_space = get_available_volume_space(disk_props);
 
-    let file_system = {
-        let len = c_disk
-            .f_fstypename
-            .iter()
-            .position(|&b| b == 0)
-            .unwrap_or(c_disk.f_fstypename.len());
-        OsString::from_vec(
-            c_disk.f_fstypename[..len]
-                .iter()
-                .map(|c| *c as u8)
-                .collect(),
-        )
-    };
+    let uuid = get_str_value(
+        disk_props.inner(),
+        DictKey::Extern(ffi::kCFURLVolumeUUIDStringKey),
+    );
+
+    let file_system = get_file_system(&c_disk);
+    let is_network = (c_disk.f_flags & libc::MNT_LOCAL as u32) == 0;
+    let (remote_host, remote_path) = get_remote_location(&c_disk, is_network);
 
-    Some(Disk {
+    let disk = Disk {
         inner: DiskInner {
-            type_,
+            type_: info.kind,
             name,
             file_system,
             mount_point,
@@ -427,6 +562,89 @@ unsafe fn new_disk(
             total_space,
             available_space,
             is_removable,
+            uuid,
+            vendor: info.vendor,
+            model: info.model,
+            serial_number: info.serial_number,
+            physical_disk: info.physical_disk.as_ref().map(|d| d.name.clone()),
+            is_rotational: info.is_rotational,
+            logical_sector_size: info.logical_sector_size,
+            physical_sector_size: info.physical_sector_size,
+            supports_trim: info.supports_trim,
+            is_network,
+            remote_host,
+            remote_path,
+            refresh_space: true,
         },
-    })
+    };
+    Some((disk, info.physical_disk))
+}
+
+/// Creates a network disk without querying its volume properties.
+unsafe fn new_network_disk(
+    mount_point: PathBuf,
+    volume_url: RetainedCFURL,
+    c_disk: &libc::statfs,
+) -> Disk {
+    let name = mount_point
+        .file_name()
+        .map(OsString::from)
+        .unwrap_or_else(|| mount_point.clone().into_os_string());
+    let (remote_host, remote_path) = get_remote_location(c_disk, true);
+    Disk {
+        inner: DiskInner {
+            type_: DiskKind::Unknown(-1),
+            name,
+            file_system: get_file_system(c_disk),
+            mount_point,
+            volume_url,
+            total_space: 0,
+            available_space: 0,
+            is_removable: false,
+            uuid: None,
+            vendor: None,
+            model: None,
+            serial_number: None,
+            physical_disk: None,
+            is_rotational: None,
+            logical_sector_size: None,
+            physical_sector_size: None,
+            supports_trim: None,
+            is_network: true,
+            remote_host,
+            remote_path,
+            refresh_space: false,
+        },
+    }
+}
+
+fn get_file_system(c_disk: &libc::statfs) -> OsString {
+    let len = c_disk
+        .f_fstypename
+        .iter()
+        .position(|&b| b == 0)
+        .unwrap_or(c_disk.f_fstypename.len());
+    OsString::from_vec(
+        c_disk.f_fstypename[..len]
+            .iter()
+            .map(|c| *c as u8)
+            .collect(),
+    )
+}
+
+unsafe fn get_remote_location(
+    c_disk: &libc::statfs,
+    is_network: bool,
+) -> (Option<String>, Option<String>) {
+    if !is_network {
+        return (None, None);
+    }
+    match CStr::from_ptr(c_disk.f_mntfromname.as_ptr())
+        .to_str()
+        .ok()
+        .and_then(parse_remote_location)
+    {
+        Some((host, path)) => (Some(host), Some(path)),
+        None => (None, None),
+    }
 }
@@ -2,4 +2,29 @@
 
 pub mod ffi {}
 pub use crate::sys::app_store::component;
+pub(crate) use crate::sys::app_store::connections;
+pub(crate) use crate::sys::app_store::cpu;
+pub(crate) use crate::sys::app_store::gpu;
+pub(crate) use crate::sys::app_store::pci;
+#[cfg(feature = "energy")]
+pub(crate) use crate::sys::app_store::power;
 pub use crate::sys::app_store::process;
+pub(crate) use crate::sys::app_store::services;
+
+pub(crate) mod battery {
+    use crate::Battery;
+
+    pub(crate) fn get_batteries(_: &mut Vec<Battery>) {}
+}
+
+pub(crate) mod hardware {
+    use crate::HardwareInfo;
+
+    pub(crate) fn get_hardware_info() -> HardwareInfo {
+        HardwareInfo::default()
+    }
+
+    pub(crate) fn get_machine_id() -> Option<String> {
+        None
+    }
+}
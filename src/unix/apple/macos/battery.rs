@@ -0,0 +1,139 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::ffi;
+use crate::sys::{
+    disk::{get_bool_value, get_int_value, DictKey},
+    macos::utils::IOReleaser,
+    utils::CFReleaser,
+};
+use crate::{Battery, BatteryState};
+
+use core_foundation_sys::base::kCFAllocatorDefault;
+use core_foundation_sys::dictionary::{CFDictionaryRef, CFMutableDictionaryRef};
+
+use std::ptr;
+use std::time::Duration;
+
+// Value of `AvgTimeToEmpty` and `AvgTimeToFull` while they are computed.
+const UNKNOWN_TIME: i64 = 0xffff;
+
+pub(crate) fn get_batteries(batteries: &mut Vec<Battery>) {
+    unsafe {
+        for_each_battery(|properties| {
+            if let Some(battery) = get_battery(properties, batteries.len()) {
+                batteries.push(battery);
+            }
+        });
+    }
+}
+
+/// Returns the power (in watts) drawn from each discharging battery.
+#[cfg(feature = "energy")]
+pub(crate) fn get_discharge_rails(rails: &mut Vec<crate::PowerRail>) {
+    unsafe {
+        let mut index = 0;
+        for_each_battery(|properties| {
+            let int = |key: &'static str| get_int_value(properties, DictKey::Defined(key));
+            // The amperage (in mA) is negative when discharging and the voltage is in mV.
+            if let (Some(amperage), Some(voltage)) = (int("InstantAmperage"), int("Voltage")) {
+                if amperage < 0
+                    && get_bool_value(properties, DictKey::Defined("IsCharging")) != Some(true)
+                {
+                    rails.push(crate::PowerRail {
+                        name: format!("InternalBattery-{index}"),
+                        kind: crate::PowerRailKind::Battery,
+                        power: (-amperage * voltage) as f32 / 1_000_000.,
+                    });
+                }
+            }
+            index += 1;
+        });
+    }
+}
+
+/// Calls `f` with the properties of each battery.
+unsafe fn for_each_battery<F: FnMut(CFDictionaryRef)>(mut f: F) {
+    // `IOServiceGetMatchingServices` takes ownership of the dictionary.
+    let matching = ffi::IOServiceMatching(b"AppleSmartBattery\0".as_ptr() as *const _);
+    if matching.is_null() {
+        return;
+    }
+    let mut iterator: ffi::io_iterator_t = 0;
+    if ffi::IOServiceGetMatchingServices(ffi::kIOMasterPortDefault, matching, &mut iterator)
+        != libc::KERN_SUCCESS
+    {
+        sysinfo_debug!("IOServiceGetMatchingServices call failed, `AppleSmartBattery` not found");
+        return;
+    }
+    let iterator = IOReleaser::new_unchecked(iterator);
+    while let Some(service) = IOReleaser::new(ffi::IOIteratorNext(iterator.inner())) {
+        let mut properties: CFMutableDictionaryRef = ptr::null_mut();
+        if ffi::IORegistryEntryCreateCFProperties(
+            service.inner(),
+            &mut properties,
+            kCFAllocatorDefault,
+            0,
+        ) != libc::KERN_SUCCESS
+        {
+            continue;
+        }
+        if let Some(properties) = CFReleaser::new(properties) {
+            f(properties.inner());
+        }
+    }
+}
+
+unsafe fn get_battery(properties: CFDictionaryRef, index: usize) -> Option<Battery> {
+    let int = |key: &'static str| get_int_value(properties, DictKey::Defined(key));
+    let boolean =
+        |key: &'static str| get_bool_value(properties, DictKey::Defined(key)).unwrap_or(false);
+    let time = |key: &'static str| {
+        int(key)
+            .filter(|minutes| *minutes >= 0 && *minutes != UNKNOWN_TIME)
+            .map(|minutes| Duration::from_secs(minutes as u64 * 60))
+    };
+
+    if !boolean("BatteryInstalled") {
+        return None;
+    }
+    // On Apple Silicon, `CurrentCapacity` and `MaxCapacity` are percentages and the capacities
+    // in mAh are in the `AppleRaw*` properties.
+    let current = int("AppleRawCurrentCapacity").or_else(|| int("CurrentCapacity"))?;
+    let max = int("AppleRawMaxCapacity").or_else(|| int("MaxCapacity"))?;
+    // In mV, used to convert the capacities from mAh to mWh.
+    let voltage = int("Voltage");
+    let to_mwh = |capacity: Option<i64>| -> Option<u64> {
+        let capacity = capacity.filter(|capacity| *capacity > 0)?;
+        Some((capacity * voltage? / 1_000) as u64)
+    };
+    let state = if boolean("IsCharging") {
+        BatteryState::Charging
+    } else if boolean("FullyCharged") {
+        BatteryState::Full
+    } else if boolean("ExternalConnected") {
+        BatteryState::NotCharging
+    } else {
+        BatteryState::Discharging
+    };
+    Some(Battery {
+        name: format!("InternalBattery-{index}"),
+        state,
+        charge: if max > 0 {
+            current as f32 * 100. / max as f32
+        } else {
+            0.
+        },
+        energy: to_mwh(Some(current)),
+        full_capacity: to_mwh(Some(max)),
+        design_capacity: to_mwh(int("DesignCapacity")),
+        cycle_count: int("CycleCount").map(|count| count as u32),
+        time_to_empty: match state {
+            BatteryState::Discharging => time("AvgTimeToEmpty"),
+            _ => None,
+        },
+        time_to_full: match state {
+            BatteryState::Charging => time("AvgTimeToFull"),
+            _ => None,
+        },
+    })
+}
@@ -9,14 +9,14 @@ use core_foundation_sys::string::{
 };
 
 use crate::sys::inner::ffi::{
-    kHIDPage_AppleVendor, kHIDUsage_AppleVendor_TemperatureSensor, kIOHIDEventTypeTemperature,
-    matching, IOHIDEventFieldBase, IOHIDEventGetFloatValue, IOHIDEventSystemClientCopyServices,
+    __IOHIDEventSystemClient, __IOHIDServiceClient, kHIDPage_AppleVendor,
+    kHIDUsage_AppleVendor_TemperatureSensor, kIOHIDEventTypeTemperature, matching,
+    IOHIDEventFieldBase, IOHIDEventGetFloatValue, IOHIDEventSystemClientCopyServices,
     IOHIDEventSystemClientCreate, IOHIDEventSystemClientSetMatching, IOHIDServiceClientCopyEvent,
-    IOHIDServiceClientCopyProperty, __IOHIDEventSystemClient, __IOHIDServiceClient,
-    HID_DEVICE_PROPERTY_PRODUCT,
+    IOHIDServiceClientCopyProperty, HID_DEVICE_PROPERTY_PRODUCT,
 };
 use crate::sys::utils::CFReleaser;
-use crate::Component;
+use crate::{Component, ComponentKind};
 
 pub(crate) struct ComponentsInner {
     components: Vec<Component>,
@@ -54,81 +54,223 @@ impl ComponentsInner {
         self.components.clear();
 
         unsafe {
-            let matches = match CFReleaser::new(matching(
-                kHIDPage_AppleVendor,
-                kHIDUsage_AppleVendor_TemperatureSensor,
-            )) {
-                Some(m) => m,
+            if self.client.is_none() {
+                self.client = create_client();
+            }
+            let client = match self.client {
+                Some(ref client) => client,
                 None => return,
             };
 
-            if self.client.is_none() {
-                let client =
-                    match CFReleaser::new(IOHIDEventSystemClientCreate(kCFAllocatorDefault)) {
-                        Some(c) => c,
-                        None => return,
-                    };
-                // Without this call, client is freed during the execution of the program. It must be kept!
-                CFRetain(client.inner() as _);
-                self.client = Some(client);
+            for (name, service) in get_temperature_services(client) {
+                let kind = get_component_kind(&name);
+                let mut component = ComponentInner::new(name, kind, None, None, service);
+                component.refresh();
+
+                self.components.push(Component { inner: component });
             }
+        }
+    }
+}
 
-            let client = self.client.as_ref().unwrap();
+unsafe fn create_client() -> Option<CFReleaser<__IOHIDEventSystemClient>> {
+    let client = CFReleaser::new(IOHIDEventSystemClientCreate(kCFAllocatorDefault))?;
+    // Without this call, client is freed during the execution of the program. It must be kept!
+    CFRetain(client.inner() as _);
+    Some(client)
+}
 
-            let _ = IOHIDEventSystemClientSetMatching(client.inner(), matches.inner());
+/// Returns the temperature sensors alongside their name.
+unsafe fn get_temperature_services(
+    client: &CFReleaser<__IOHIDEventSystemClient>,
+) -> Vec<(String, CFReleaser<__IOHIDServiceClient>)> {
+    let mut services_list = Vec::new();
 
-            let services = match CFReleaser::new(IOHIDEventSystemClientCopyServices(client.inner()))
-            {
-                Some(s) => s,
-                None => return,
-            };
+    let matches = match CFReleaser::new(matching(
+        kHIDPage_AppleVendor,
+        kHIDUsage_AppleVendor_TemperatureSensor,
+    )) {
+        Some(m) => m,
+        None => return services_list,
+    };
 
-            let key_ref = match CFReleaser::new(CFStringCreateWithBytes(
-                kCFAllocatorDefault,
-                HID_DEVICE_PROPERTY_PRODUCT.as_ptr(),
-                HID_DEVICE_PROPERTY_PRODUCT.len() as _,
-                kCFStringEncodingUTF8,
-                false as _,
-            )) {
-                Some(r) => r,
-                None => return,
-            };
+    let _ = IOHIDEventSystemClientSetMatching(client.inner(), matches.inner());
 
-            let count = CFArrayGetCount(services.inner());
-
-            for i in 0..count {
-                let service = match CFReleaser::new(
-                    CFArrayGetValueAtIndex(services.inner(), i) as *const _
-                ) {
-                    Some(s) => s,
-                    None => continue,
-                };
-
-                let name = match CFReleaser::new(IOHIDServiceClientCopyProperty(
-                    service.inner(),
-                    key_ref.inner(),
-                )) {
-                    Some(n) => n,
-                    None => continue,
-                };
-
-                let name_ptr =
-                    CFStringGetCStringPtr(name.inner() as *const _, kCFStringEncodingUTF8);
-                let name_str = CStr::from_ptr(name_ptr).to_string_lossy().to_string();
-
-                let mut component = ComponentInner::new(name_str, None, None, service);
-                component.refresh();
+    let services = match CFReleaser::new(IOHIDEventSystemClientCopyServices(client.inner())) {
+        Some(s) => s,
+        None => return services_list,
+    };
 
-                self.components.push(Component { inner: component });
+    let key_ref = match CFReleaser::new(CFStringCreateWithBytes(
+        kCFAllocatorDefault,
+        HID_DEVICE_PROPERTY_PRODUCT.as_ptr(),
+        HID_DEVICE_PROPERTY_PRODUCT.len() as _,
+        kCFStringEncodingUTF8,
+        false as _,
+    )) {
+        Some(r) => r,
+        None => return services_list,
+    };
+
+    let count = CFArrayGetCount(services.inner());
+
+    for i in 0..count {
+        let service = match CFReleaser::new(CFArrayGetValueAtIndex(services.inner(), i) as *const _)
+        {
+            Some(s) => s,
+            None => continue,
+        };
+
+        let name = match CFReleaser::new(IOHIDServiceClientCopyProperty(
+            service.inner(),
+            key_ref.inner(),
+        )) {
+            Some(n) => n,
+            None => continue,
+        };
+
+        let name_ptr = CFStringGetCStringPtr(name.inner() as *const _, kCFStringEncodingUTF8);
+        if name_ptr.is_null() {
+            continue;
+        }
+        let name_str = CStr::from_ptr(name_ptr).to_string_lossy().to_string();
+
+        services_list.push((name_str, service));
+    }
+    services_list
+}
+
+/// Returns the kind of the sensor from its name (like `GPU MTR Temp Sensor1`).
+///
+/// The GPU sensors are provided by the HID temperature services alongside the others, so there
+/// is no need to go through `IOReport`.
+fn get_component_kind(name: &str) -> ComponentKind {
+    if name.starts_with("GPU") {
+        ComponentKind::Gpu
+    } else if name.starts_with("eACC") || name.starts_with("pACC") || name.starts_with("PMU tdie") {
+        ComponentKind::Cpu
+    } else if name.starts_with("NAND") {
+        ComponentKind::Storage
+    } else if name.contains("battery") || name.contains("Battery") {
+        ComponentKind::Battery
+    } else {
+        ComponentKind::Other
+    }
+}
+
+unsafe fn get_service_temperature(service: &CFReleaser<__IOHIDServiceClient>) -> Option<f32> {
+    let event = CFReleaser::new(IOHIDServiceClientCopyEvent(
+        service.inner() as *const _,
+        kIOHIDEventTypeTemperature,
+        0,
+        0,
+    ))?;
+
+    Some(IOHIDEventGetFloatValue(
+        event.inner(),
+        IOHIDEventFieldBase(kIOHIDEventTypeTemperature),
+    ) as _)
+}
+
+/// Reads the temperature of each CPU from the `eACC` (efficiency cores) and `pACC`
+/// (performance cores) sensors.
+pub(crate) struct CpuTemperatures {
+    client: Option<CFReleaser<__IOHIDEventSystemClient>>,
+    efficiency_sensors: Vec<CFReleaser<__IOHIDServiceClient>>,
+    performance_sensors: Vec<CFReleaser<__IOHIDServiceClient>>,
+}
+
+impl CpuTemperatures {
+    pub(crate) fn new() -> Self {
+        Self {
+            client: None,
+            efficiency_sensors: Vec::new(),
+            performance_sensors: Vec::new(),
+        }
+    }
+
+    pub(crate) fn temperatures(&mut self, nb_cpus: usize) -> Vec<Option<f32>> {
+        unsafe {
+            if self.client.is_none() {
+                self.client = create_client();
+                if let Some(ref client) = self.client {
+                    let mut efficiency = Vec::new();
+                    let mut performance = Vec::new();
+                    for (name, service) in get_temperature_services(client) {
+                        if name.starts_with("eACC") {
+                            efficiency.push((sensor_index(&name), service));
+                        } else if name.starts_with("pACC") {
+                            performance.push((sensor_index(&name), service));
+                        }
+                    }
+                    efficiency.sort_unstable_by_key(|(index, _)| *index);
+                    performance.sort_unstable_by_key(|(index, _)| *index);
+                    self.efficiency_sensors = efficiency.into_iter().map(|(_, s)| s).collect();
+                    self.performance_sensors = performance.into_iter().map(|(_, s)| s).collect();
+                }
             }
+
+            // Efficiency cores come first.
+            let nb_efficiency_cpus = get_efficiency_cpus_count().min(nb_cpus);
+            let mut temperatures =
+                cluster_temperatures(&self.efficiency_sensors, nb_efficiency_cpus);
+            temperatures.extend(cluster_temperatures(
+                &self.performance_sensors,
+                nb_cpus - nb_efficiency_cpus,
+            ));
+            temperatures
+        }
+    }
+}
+
+/// Returns the number at the end of the sensor name (`pACC MTR Temp Sensor2` -> `2`).
+fn sensor_index(name: &str) -> u32 {
+    let digits = name.len() - name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    name[name.len() - digits..].parse().unwrap_or(0)
+}
+
+fn get_efficiency_cpus_count() -> usize {
+    let mut count: u32 = 0;
+    unsafe {
+        if crate::sys::utils::get_sys_value_by_name(
+            b"hw.perflevel1.logicalcpu\0",
+            &mut std::mem::size_of::<u32>(),
+            &mut count as *mut u32 as *mut _,
+        ) {
+            count as usize
+        } else {
+            0
         }
     }
 }
 
+/// If there is one sensor per core, each core gets its own temperature. Otherwise, all the
+/// cores of the cluster get the highest temperature of the cluster.
+unsafe fn cluster_temperatures(
+    sensors: &[CFReleaser<__IOHIDServiceClient>],
+    nb_cpus: usize,
+) -> Vec<Option<f32>> {
+    let temperatures = sensors
+        .iter()
+        .map(|sensor| get_service_temperature(sensor))
+        .collect::<Vec<_>>();
+    if temperatures.len() == nb_cpus {
+        return temperatures;
+    }
+    let max = temperatures
+        .into_iter()
+        .flatten()
+        .fold(None, |max: Option<f32>, t| {
+            Some(max.map_or(t, |max| max.max(t)))
+        });
+    vec![max; nb_cpus]
+}
+
 pub(crate) struct ComponentInner {
     service: CFReleaser<__IOHIDServiceClient>,
     temperature: f32,
     label: String,
+    kind: ComponentKind,
     max: f32,
     critical: Option<f32>,
 }
@@ -136,6 +278,7 @@ pub(crate) struct ComponentInner {
 impl ComponentInner {
     pub(crate) fn new(
         label: String,
+        kind: ComponentKind,
         max: Option<f32>,
         critical: Option<f32>,
         service: CFReleaser<__IOHIDServiceClient>,
@@ -143,6 +286,7 @@ impl ComponentInner {
         Self {
             service,
             label,
+            kind,
             max: max.unwrap_or(0.),
             critical,
             temperature: 0.,
@@ -161,28 +305,38 @@ impl ComponentInner {
         self.critical
     }
 
+    pub(crate) fn lower_limit(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn upper_limit(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn raw_value(&self) -> Option<i64> {
+        None
+    }
+
     pub(crate) fn label(&self) -> &str {
         &self.label
     }
 
+    // The HID sensor names are unique and don't depend on the system language.
+    pub(crate) fn id(&self) -> &str {
+        &self.label
+    }
+
+    pub(crate) fn kind(&self) -> ComponentKind {
+        self.kind
+    }
+
     pub(crate) fn refresh(&mut self) {
         unsafe {
-            let event = match CFReleaser::new(IOHIDServiceClientCopyEvent(
-                self.service.inner() as *const _,
-                kIOHIDEventTypeTemperature,
-                0,
-                0,
-            )) {
-                Some(e) => e,
-                None => return,
-            };
-
-            self.temperature = IOHIDEventGetFloatValue(
-                event.inner(),
-                IOHIDEventFieldBase(kIOHIDEventTypeTemperature),
-            ) as _;
-            if self.temperature > self.max {
-                self.max = self.temperature;
+            if let Some(temperature) = get_service_temperature(&self.service) {
+                self.temperature = temperature;
+                if self.temperature > self.max {
+                    self.max = self.temperature;
+                }
             }
         }
     }
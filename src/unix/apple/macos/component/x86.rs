@@ -1,21 +1,43 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::sys::{ffi, macos::utils::IOReleaser};
-use crate::Component;
+use crate::{Component, ComponentKind};
 
 use libc::{c_char, c_int, c_void};
 
 use std::mem;
 
-const COMPONENTS_TEMPERATURE_IDS: &[(&str, &[i8])] = &[
-    ("PECI CPU", &['T' as i8, 'C' as i8, 'X' as i8, 'C' as i8]), // PECI CPU "TCXC"
-    ("PECI CPU", &['T' as i8, 'C' as i8, 'X' as i8, 'c' as i8]), // PECI CPU "TCXc"
+const COMPONENTS_TEMPERATURE_IDS: &[(&str, ComponentKind, &[i8])] = &[
+    (
+        "PECI CPU",
+        ComponentKind::Cpu,
+        &['T' as i8, 'C' as i8, 'X' as i8, 'C' as i8],
+    ), // PECI CPU "TCXC"
+    (
+        "PECI CPU",
+        ComponentKind::Cpu,
+        &['T' as i8, 'C' as i8, 'X' as i8, 'c' as i8],
+    ), // PECI CPU "TCXc"
     (
         "CPU Proximity",
+        ComponentKind::Cpu,
         &['T' as i8, 'C' as i8, '0' as i8, 'P' as i8],
     ), // CPU Proximity (heat spreader) "TC0P"
-    ("GPU", &['T' as i8, 'G' as i8, '0' as i8, 'P' as i8]),      // GPU "TG0P"
-    ("Battery", &['T' as i8, 'B' as i8, '0' as i8, 'T' as i8]),  // Battery "TB0T"
+    (
+        "GPU",
+        ComponentKind::Gpu,
+        &['T' as i8, 'G' as i8, '0' as i8, 'P' as i8],
+    ), // GPU "TG0P"
+    (
+        "GPU Die",
+        ComponentKind::Gpu,
+        &['T' as i8, 'G' as i8, '0' as i8, 'D' as i8],
+    ), // GPU die "TG0D"
+    (
+        "Battery",
+        ComponentKind::Battery,
+        &['T' as i8, 'B' as i8, '0' as i8, 'T' as i8],
+    ), // Battery "TB0T"
 ];
 
 pub(crate) struct ComponentFFI {
@@ -85,9 +107,9 @@ impl ComponentsInner {
             let critical_temp =
                 get_temperature(connection, &['T' as i8, 'C' as i8, '0' as i8, 'D' as i8, 0]);
 
-            for (id, v) in COMPONENTS_TEMPERATURE_IDS.iter() {
+            for (id, kind, v) in COMPONENTS_TEMPERATURE_IDS.iter() {
                 if let Some(c) =
-                    ComponentInner::new((*id).to_owned(), None, critical_temp, v, connection)
+                    ComponentInner::new((*id).to_owned(), *kind, None, critical_temp, v, connection)
                 {
                     self.components.push(Component { inner: c });
                 }
@@ -96,11 +118,58 @@ impl ComponentsInner {
     }
 }
 
+/// Reads the temperature of each CPU from the SMC `TCxC` keys (one per physical core).
+pub(crate) struct CpuTemperatures {
+    cores: Option<Vec<ComponentFFI>>,
+    connection: Option<IoService>,
+}
+
+impl CpuTemperatures {
+    pub(crate) fn new() -> Self {
+        Self {
+            cores: None,
+            connection: None,
+        }
+    }
+
+    pub(crate) fn temperatures(&mut self, nb_cpus: usize) -> Vec<Option<f32>> {
+        if self.cores.is_none() {
+            self.connection = IoService::new_connection();
+            let mut cores = Vec::new();
+            if let Some(ref connection) = self.connection {
+                // Core keys are numbered with a single hexadecimal digit.
+                for id in b"0123456789ABCDEF" {
+                    match ComponentFFI::new(
+                        &['T' as i8, 'C' as i8, *id as i8, 'C' as i8],
+                        connection.inner(),
+                    ) {
+                        Some(core) => cores.push(core),
+                        None => break,
+                    }
+                }
+            }
+            self.cores = Some(cores);
+        }
+        let cores = match self.cores {
+            Some(ref cores) if !cores.is_empty() => cores,
+            _ => return vec![None; nb_cpus],
+        };
+        // With hyper-threading, logical CPUs of a same physical core are next to each other.
+        let cpus_per_core = (nb_cpus / cores.len()).max(1);
+        (0..nb_cpus)
+            .map(|cpu| cores.get(cpu / cpus_per_core)?.temperature())
+            .collect()
+    }
+}
+
 pub(crate) struct ComponentInner {
     temperature: f32,
     max: f32,
     critical: Option<f32>,
     label: String,
+    /// The SMC key, like `TC0P`.
+    key: String,
+    kind: ComponentKind,
     ffi_part: ComponentFFI,
 }
 
@@ -108,6 +177,7 @@ impl ComponentInner {
     /// Creates a new `ComponentInner` with the given information.
     pub(crate) fn new(
         label: String,
+        kind: ComponentKind,
         max: Option<f32>,
         critical: Option<f32>,
         key: &[i8],
@@ -117,6 +187,8 @@ impl ComponentInner {
         ffi_part.temperature().map(|temperature| Self {
             temperature,
             label,
+            key: key.iter().map(|c| *c as u8 as char).collect(),
+            kind,
             max: max.unwrap_or(temperature),
             critical,
             ffi_part,
@@ -135,10 +207,30 @@ impl ComponentInner {
         self.critical
     }
 
+    pub(crate) fn lower_limit(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn upper_limit(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn raw_value(&self) -> Option<i64> {
+        None
+    }
+
     pub(crate) fn label(&self) -> &str {
         &self.label
     }
 
+    pub(crate) fn id(&self) -> &str {
+        &self.key
+    }
+
+    pub(crate) fn kind(&self) -> ComponentKind {
+        self.kind
+    }
+
     pub(crate) fn refresh(&mut self) {
         if let Some(temp) = self.ffi_part.temperature() {
             self.temperature = temp;
@@ -269,6 +361,27 @@ fn get_temperature(con: ffi::io_connect_t, key: &[i8]) -> Option<f32> {
     }
 }
 
+/// Reads a power (in watts) from the SMC `key`, stored as a `flt ` value.
+#[cfg(feature = "energy")]
+pub(crate) fn get_power(con: ffi::io_connect_t, key: &[i8]) -> Option<f32> {
+    unsafe {
+        let (input_structure, val) = get_key_size(con, key).ok()?;
+        let val = read_key(con, &input_structure, val).ok()?;
+        if val.data_size == 4
+            && libc::strcmp(val.data_type.as_ptr(), b"flt \0".as_ptr() as *const i8) == 0
+        {
+            let bytes = [
+                val.bytes[0] as u8,
+                val.bytes[1] as u8,
+                val.bytes[2] as u8,
+                val.bytes[3] as u8,
+            ];
+            return Some(f32::from_le_bytes(bytes));
+        }
+    }
+    None
+}
+
 pub(crate) struct IoService(ffi::io_connect_t);
 
 impl IoService {
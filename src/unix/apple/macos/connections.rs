@@ -0,0 +1,205 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::inner::process::get_proc_list;
+use crate::{Connection, ConnectionProtocol, ConnectionSummary, Pid, TcpState};
+
+use libc::{c_int, c_void, proc_fdinfo};
+
+use std::mem::{self, MaybeUninit};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+// Not provided by `libc`, values from `sys/proc_info.h`.
+const PROC_PIDFDSOCKETINFO: c_int = 3;
+const SOCKINFO_IN: c_int = 1;
+const SOCKINFO_TCP: c_int = 2;
+const INI_IPV4: u8 = 0x1;
+const INI_IPV6: u8 = 0x2;
+
+/// `struct in_sockinfo`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+#[allow(dead_code)]
+struct InSockInfo {
+    insi_fport: c_int,
+    insi_lport: c_int,
+    insi_gencnt: u64,
+    insi_flags: u32,
+    insi_flow: u32,
+    insi_vflag: u8,
+    insi_ip_ttl: u8,
+    rfu_1: u32,
+    // IPv4 addresses are stored in the last 4 bytes.
+    insi_faddr: [u8; 16],
+    insi_laddr: [u8; 16],
+    insi_v4: u8,
+    insi_v6: [u8; 12],
+}
+
+/// Beginning of `struct tcp_sockinfo`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+#[allow(dead_code)]
+struct TcpSockInfo {
+    tcpsi_ini: InSockInfo,
+    tcpsi_state: c_int,
+}
+
+#[repr(C)]
+#[allow(dead_code)]
+union SocketInfoProto {
+    pri_in: InSockInfo,
+    pri_tcp: TcpSockInfo,
+    // The union contains bigger types for the other kinds of sockets.
+    _padding: [u64; 80],
+}
+
+/// `struct socket_info`.
+#[repr(C)]
+#[allow(dead_code)]
+struct SocketInfo {
+    // `struct vinfo_stat`.
+    soi_stat: [u64; 17],
+    soi_so: u64,
+    soi_pcb: u64,
+    soi_type: c_int,
+    soi_protocol: c_int,
+    soi_family: c_int,
+    // From `soi_options` to `soi_error`.
+    soi_options: [i16; 8],
+    soi_oobmark: u32,
+    // `struct sockbuf_info`.
+    soi_rcv: [u32; 6],
+    soi_snd: [u32; 6],
+    soi_kind: c_int,
+    rfu_1: u32,
+    soi_proto: SocketInfoProto,
+}
+
+/// `struct socket_fdinfo`.
+#[repr(C)]
+#[allow(dead_code)]
+struct SocketFdInfo {
+    // `struct proc_fileinfo`.
+    pfi: [u64; 3],
+    psi: SocketInfo,
+}
+
+// Values from `TSI_S_*`.
+fn tcp_state(state: c_int) -> Option<TcpState> {
+    Some(match state {
+        0 => TcpState::Closed,
+        1 => TcpState::Listen,
+        2 => TcpState::SynSent,
+        3 => TcpState::SynReceived,
+        4 => TcpState::Established,
+        5 => TcpState::CloseWait,
+        6 => TcpState::FinWait1,
+        7 => TcpState::Closing,
+        8 => TcpState::LastAck,
+        9 => TcpState::FinWait2,
+        10 => TcpState::TimeWait,
+        _ => return None,
+    })
+}
+
+fn socket_address(info: &InSockInfo, addr: [u8; 16], port: c_int) -> Option<SocketAddr> {
+    let ip = if info.insi_vflag & INI_IPV4 != 0 {
+        IpAddr::V4(Ipv4Addr::new(addr[12], addr[13], addr[14], addr[15]))
+    } else if info.insi_vflag & INI_IPV6 != 0 {
+        IpAddr::V6(Ipv6Addr::from(addr))
+    } else {
+        return None;
+    };
+    // Ports are stored in network byte order.
+    Some(SocketAddr::new(ip, u16::from_be(port as u16)))
+}
+
+unsafe fn get_fds(pid: Pid) -> Vec<proc_fdinfo> {
+    let size = libc::proc_pidinfo(pid.0, libc::PROC_PIDLISTFDS, 0, std::ptr::null_mut(), 0);
+    if size <= 0 {
+        return Vec::new();
+    }
+    let mut fds: Vec<proc_fdinfo> =
+        Vec::with_capacity(size as usize / mem::size_of::<proc_fdinfo>());
+    let size = libc::proc_pidinfo(
+        pid.0,
+        libc::PROC_PIDLISTFDS,
+        0,
+        fds.as_mut_ptr() as *mut c_void,
+        (fds.capacity() * mem::size_of::<proc_fdinfo>()) as _,
+    );
+    if size <= 0 {
+        return Vec::new();
+    }
+    fds.set_len(size as usize / mem::size_of::<proc_fdinfo>());
+    fds
+}
+
+unsafe fn get_connection(pid: Pid, fd: c_int) -> Option<Connection> {
+    let mut info: MaybeUninit<SocketFdInfo> = MaybeUninit::uninit();
+    let size = libc::proc_pidfdinfo(
+        pid.0,
+        fd,
+        PROC_PIDFDSOCKETINFO,
+        info.as_mut_ptr() as *mut c_void,
+        mem::size_of::<SocketFdInfo>() as _,
+    );
+    if size <= 0 {
+        return None;
+    }
+    let info = info.assume_init().psi;
+    let (protocol, in_info, state) = match info.soi_kind {
+        SOCKINFO_IN if info.soi_protocol == libc::IPPROTO_UDP => {
+            (ConnectionProtocol::Udp, info.soi_proto.pri_in, None)
+        }
+        SOCKINFO_TCP => {
+            let tcp_info = info.soi_proto.pri_tcp;
+            (
+                ConnectionProtocol::Tcp,
+                tcp_info.tcpsi_ini,
+                tcp_state(tcp_info.tcpsi_state),
+            )
+        }
+        _ => return None,
+    };
+    let remote_address = socket_address(&in_info, in_info.insi_faddr, in_info.insi_fport)
+        .filter(|addr| !addr.ip().is_unspecified() || addr.port() != 0);
+    Some(Connection {
+        protocol,
+        local_address: socket_address(&in_info, in_info.insi_laddr, in_info.insi_lport)?,
+        remote_address,
+        state,
+        pid: Some(pid),
+        traffic: None,
+    })
+}
+
+pub(crate) fn get_connections(connections: &mut Vec<Connection>) {
+    let pids = match get_proc_list() {
+        Some(pids) => pids,
+        None => return,
+    };
+    for pid in pids {
+        unsafe {
+            for fd in get_fds(pid) {
+                if fd.proc_fdtype != libc::PROX_FDTYPE_SOCKET as u32 {
+                    continue;
+                }
+                if let Some(connection) = get_connection(pid, fd.proc_fd) {
+                    connections.push(connection);
+                }
+            }
+        }
+    }
+}
+
+// There is no cheaper way to count the sockets of all processes.
+pub(crate) fn get_connection_summary() -> ConnectionSummary {
+    let mut connections = Vec::new();
+    get_connections(&mut connections);
+    let mut summary = ConnectionSummary::default();
+    for connection in connections {
+        summary.add(connection.protocol, connection.state);
+    }
+    summary
+}
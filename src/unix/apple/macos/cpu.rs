@@ -7,6 +7,17 @@ pub(crate) unsafe fn get_cpu_frequency() -> u64 {
 
 #[cfg(not(feature = "apple-sandbox"))]
 pub(crate) unsafe fn get_cpu_frequency() -> u64 {
+    // The last entry of the performance cores frequency table is the maximum frequency.
+    get_frequency_table(b"voltage-states5-sram\0")
+        .and_then(|table| table.last().copied())
+        .unwrap_or(0)
+}
+
+/// Returns the frequencies (in MHz) supported by a cores cluster. `property` is the name of
+/// the `pmgr` property containing them (`voltage-states1-sram` for efficiency cores and
+/// `voltage-states5-sram` for performance cores).
+#[cfg(not(feature = "apple-sandbox"))]
+unsafe fn get_frequency_table(property: &[u8]) -> Option<Vec<u64>> {
     use crate::sys::ffi;
     use crate::sys::macos::utils::IOReleaser;
     use crate::sys::utils::CFReleaser;
@@ -14,7 +25,7 @@ pub(crate) unsafe fn get_cpu_frequency() -> u64 {
     let matching = ffi::IOServiceMatching(b"AppleARMIODevice\0".as_ptr() as *const _);
     if matching.is_null() {
         sysinfo_debug!("IOServiceMatching call failed, `AppleARMIODevice` not found");
-        return 0;
+        return None;
     }
 
     // Starting from mac M1, the above call returns nothing for the CPU frequency
@@ -25,7 +36,7 @@ pub(crate) unsafe fn get_cpu_frequency() -> u64 {
         ffi::IOServiceGetMatchingServices(ffi::kIOMasterPortDefault, matching, &mut iterator);
     if result != ffi::KIO_RETURN_SUCCESS {
         sysinfo_debug!("Error: IOServiceGetMatchingServices() = {}", result);
-        return 0;
+        return None;
     }
     let iterator = match IOReleaser::new(iterator) {
         Some(i) => i,
@@ -33,7 +44,7 @@ pub(crate) unsafe fn get_cpu_frequency() -> u64 {
             sysinfo_debug!(
                 "Error: IOServiceGetMatchingServices() succeeded but returned invalid descriptor"
             );
-            return 0;
+            return None;
         }
     };
 
@@ -43,7 +54,7 @@ pub(crate) unsafe fn get_cpu_frequency() -> u64 {
             Some(d) => d,
             None => {
                 sysinfo_debug!("`pmgr` entry was not found in AppleARMIODevice service");
-                return 0;
+                return None;
             }
         };
         let status = ffi::IORegistryEntryGetName(entry.inner(), name.as_mut_ptr());
@@ -56,14 +67,14 @@ pub(crate) unsafe fn get_cpu_frequency() -> u64 {
 
     let node_name = match CFReleaser::new(ffi::CFStringCreateWithCStringNoCopy(
         std::ptr::null(),
-        b"voltage-states5-sram\0".as_ptr() as *const _,
+        property.as_ptr() as *const _,
         core_foundation_sys::string::kCFStringEncodingUTF8,
         core_foundation_sys::base::kCFAllocatorNull as *mut _,
     )) {
         Some(n) => n,
         None => {
             sysinfo_debug!("CFStringCreateWithCStringNoCopy failed");
-            return 0;
+            return None;
         }
     };
 
@@ -75,21 +86,209 @@ pub(crate) unsafe fn get_cpu_frequency() -> u64 {
     )) {
         Some(c) => c,
         None => {
-            sysinfo_debug!("`voltage-states5-sram` property not found");
-            return 0;
+            sysinfo_debug!("`{:?}` property not found", property);
+            return None;
         }
     };
 
     let core_length = core_foundation_sys::data::CFDataGetLength(core_ref.inner() as *const _);
     if core_length < 8 {
-        sysinfo_debug!("expected `voltage-states5-sram` buffer to have at least size 8");
-        return 0;
+        sysinfo_debug!("expected `{:?}` buffer to have at least size 8", property);
+        return None;
     }
-    let mut max: u64 = 0;
+    let mut data = vec![0u8; core_length as usize];
     core_foundation_sys::data::CFDataGetBytes(
         core_ref.inner() as *const _,
-        core_foundation_sys::base::CFRange::init(core_length - 8, 4),
-        &mut max as *mut _ as *mut _,
+        core_foundation_sys::base::CFRange::init(0, core_length),
+        data.as_mut_ptr(),
     );
-    max / 1_000_000
+    // The data is a list of `(frequency, voltage)` pairs of `u32`.
+    Some(
+        data.chunks_exact(8)
+            .map(|pair| u32::from_ne_bytes([pair[0], pair[1], pair[2], pair[3]]) as u64)
+            .map(|frequency| frequency / 1_000_000)
+            .filter(|frequency| *frequency != 0)
+            .collect(),
+    )
+}
+
+/// Computes the current frequency of each core by using the residency in each performance
+/// state between two `IOReport` samples.
+#[cfg(not(feature = "apple-sandbox"))]
+pub(crate) struct CpuFrequencySampler {
+    state: Option<IOReportState>,
+    initialized: bool,
+}
+
+#[cfg(not(feature = "apple-sandbox"))]
+struct IOReportState {
+    subscription: crate::sys::utils::CFReleaser<libc::c_void>,
+    channels: crate::sys::utils::CFReleaser<core_foundation_sys::dictionary::__CFDictionary>,
+    previous:
+        Option<crate::sys::utils::CFReleaser<core_foundation_sys::dictionary::__CFDictionary>>,
+    efficiency_frequencies: Vec<u64>,
+    performance_frequencies: Vec<u64>,
+}
+
+#[cfg(not(feature = "apple-sandbox"))]
+impl CpuFrequencySampler {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: None,
+            initialized: false,
+        }
+    }
+
+    /// Returns the average frequency of each core since the previous call. The first call
+    /// always returns `None`.
+    pub(crate) fn sample(&mut self) -> Option<Vec<u64>> {
+        if !self.initialized {
+            self.initialized = true;
+            self.state = unsafe { IOReportState::new() };
+        }
+        unsafe { self.state.as_mut()?.sample() }
+    }
+}
+
+#[cfg(not(feature = "apple-sandbox"))]
+impl IOReportState {
+    unsafe fn new() -> Option<Self> {
+        use crate::sys::ffi;
+        use crate::sys::utils::CFReleaser;
+
+        let efficiency_frequencies = get_frequency_table(b"voltage-states1-sram\0")?;
+        let performance_frequencies = get_frequency_table(b"voltage-states5-sram\0")?;
+
+        let group = create_cfstring(b"CPU Stats\0")?;
+        let subgroup = create_cfstring(b"CPU Core Performance States\0")?;
+        let channels = CFReleaser::new(ffi::IOReportCopyChannelsInGroup(
+            group.inner(),
+            subgroup.inner(),
+            0,
+            0,
+            0,
+        ))?;
+        let mut subbed_channels = std::ptr::null_mut();
+        let subscription = CFReleaser::new(ffi::IOReportCreateSubscription(
+            std::ptr::null(),
+            channels.inner() as *mut _,
+            &mut subbed_channels,
+            0,
+            std::ptr::null(),
+        ))?;
+        let channels = CFReleaser::new(subbed_channels)?;
+        Some(Self {
+            subscription,
+            channels,
+            previous: None,
+            efficiency_frequencies,
+            performance_frequencies,
+        })
+    }
+
+    unsafe fn sample(&mut self) -> Option<Vec<u64>> {
+        use crate::sys::ffi;
+        use crate::sys::utils::CFReleaser;
+        use core_foundation_sys::array::{CFArrayGetCount, CFArrayGetValueAtIndex};
+        use core_foundation_sys::dictionary::CFDictionaryGetValue;
+
+        let current = CFReleaser::new(ffi::IOReportCreateSamples(
+            self.subscription.inner(),
+            self.channels.inner() as *mut _,
+            std::ptr::null(),
+        ))?;
+        let previous = self.previous.replace(current)?;
+        let current = self.previous.as_ref()?;
+        let delta = CFReleaser::new(ffi::IOReportCreateSamplesDelta(
+            previous.inner(),
+            current.inner(),
+            std::ptr::null(),
+        ))?;
+
+        let key = create_cfstring(b"IOReportChannels\0")?;
+        let channels = CFDictionaryGetValue(delta.inner(), key.inner() as *const _);
+        if channels.is_null() {
+            return None;
+        }
+        let channels = channels as core_foundation_sys::array::CFArrayRef;
+        let mut frequencies = Vec::new();
+        for i in 0..CFArrayGetCount(channels) {
+            let channel = CFArrayGetValueAtIndex(channels, i) as _;
+            let name = match cfstring_to_string(ffi::IOReportChannelGetChannelName(channel)) {
+                Some(name) => name,
+                None => continue,
+            };
+            let table = if name.starts_with("ECPU") {
+                &self.efficiency_frequencies
+            } else if name.starts_with("PCPU") {
+                &self.performance_frequencies
+            } else {
+                continue;
+            };
+            frequencies.push(average_frequency(channel, table));
+        }
+        Some(frequencies)
+    }
+}
+
+#[cfg(not(feature = "apple-sandbox"))]
+unsafe fn average_frequency(
+    channel: core_foundation_sys::dictionary::CFDictionaryRef,
+    table: &[u64],
+) -> u64 {
+    use crate::sys::ffi;
+
+    let mut total_residency = 0f64;
+    let mut weighted = 0f64;
+    let mut active_state = 0;
+    for state in 0..ffi::IOReportStateGetCount(channel) {
+        let name = cfstring_to_string(ffi::IOReportStateGetNameForIndex(channel, state))
+            .unwrap_or_default();
+        // Inactive states are not part of the frequency table.
+        if name == "IDLE" || name == "DOWN" || name == "OFF" {
+            continue;
+        }
+        let residency = ffi::IOReportStateGetResidency(channel, state).max(0) as f64;
+        if let Some(frequency) = table.get(active_state) {
+            total_residency += residency;
+            weighted += residency * *frequency as f64;
+        }
+        active_state += 1;
+    }
+    if total_residency > 0. {
+        (weighted / total_residency) as u64
+    } else {
+        // The core was idle during the whole interval.
+        table.first().copied().unwrap_or(0)
+    }
+}
+
+#[cfg(not(feature = "apple-sandbox"))]
+unsafe fn create_cfstring(
+    s: &'static [u8],
+) -> Option<crate::sys::utils::CFReleaser<core_foundation_sys::string::__CFString>> {
+    crate::sys::utils::CFReleaser::new(crate::sys::ffi::CFStringCreateWithCStringNoCopy(
+        std::ptr::null(),
+        s.as_ptr() as *const _,
+        core_foundation_sys::string::kCFStringEncodingUTF8,
+        core_foundation_sys::base::kCFAllocatorNull as *mut _,
+    ))
+}
+
+#[cfg(not(feature = "apple-sandbox"))]
+unsafe fn cfstring_to_string(s: core_foundation_sys::string::CFStringRef) -> Option<String> {
+    if s.is_null() {
+        return None;
+    }
+    let mut buf = [0 as libc::c_char; 64];
+    if core_foundation_sys::string::CFStringGetCString(
+        s,
+        buf.as_mut_ptr(),
+        buf.len() as _,
+        core_foundation_sys::string::kCFStringEncodingUTF8,
+    ) == 0
+    {
+        return None;
+    }
+    crate::unix::utils::cstr_to_rust(buf.as_ptr())
 }
@@ -2,28 +2,75 @@
 
 use crate::sys::ffi;
 use crate::sys::{
-    disk::{get_str_value, DictKey},
+    disk::{get_bool_value, get_int_value, get_str_value, DictKey, DiskInfo},
     macos::utils::IOReleaser,
     utils::CFReleaser,
 };
-use crate::DiskKind;
+use crate::{DiskKind, PhysicalDisk};
 
 use core_foundation_sys::base::{kCFAllocatorDefault, kCFAllocatorNull};
+use core_foundation_sys::dictionary::CFMutableDictionaryRef;
 use core_foundation_sys::string as cfs;
 
 use std::ffi::CStr;
+use std::ptr;
 
-pub(crate) fn get_disk_type(disk: &libc::statfs) -> Option<DiskKind> {
-    let characteristics_string = unsafe {
+fn create_cf_string(s: &'static str) -> Option<CFReleaser<cfs::__CFString>> {
+    unsafe {
         CFReleaser::new(cfs::CFStringCreateWithBytesNoCopy(
             kCFAllocatorDefault,
-            ffi::kIOPropertyDeviceCharacteristicsKey.as_ptr(),
-            ffi::kIOPropertyDeviceCharacteristicsKey.len() as _,
+            s.as_ptr(),
+            s.len() as _,
             cfs::kCFStringEncodingUTF8,
             false as _,
             kCFAllocatorNull,
-        ))?
-    };
+        ))
+    }
+}
+
+/// Returns `true` if the storage device supports unmapping blocks (also known as TRIM).
+unsafe fn supports_unmap(entry: ffi::io_registry_entry_t) -> Option<bool> {
+    let features_string = create_cf_string(ffi::kIOStorageFeaturesKey)?;
+    let features = CFReleaser::new(ffi::IORegistryEntryCreateCFProperty(
+        entry,
+        features_string.inner(),
+        kCFAllocatorDefault,
+        0,
+    ))?;
+    Some(
+        get_bool_value(
+            features.inner(),
+            DictKey::Defined(ffi::kIOStorageFeatureUnmap),
+        )
+        .unwrap_or(false),
+    )
+}
+
+/// Returns the physical disk if `entry` is a whole `IOMedia` (like `disk0`).
+unsafe fn get_whole_media(entry: ffi::io_registry_entry_t) -> Option<PhysicalDisk> {
+    let mut properties: CFMutableDictionaryRef = ptr::null_mut();
+    if ffi::IORegistryEntryCreateCFProperties(entry, &mut properties, kCFAllocatorDefault, 0)
+        != libc::KERN_SUCCESS
+    {
+        return None;
+    }
+    let properties = CFReleaser::new(properties)?;
+    if !get_bool_value(properties.inner(), DictKey::Defined(ffi::kIOMediaWholeKey))? {
+        return None;
+    }
+    let get_bool = |key| get_bool_value(properties.inner(), DictKey::Defined(key)) == Some(true);
+    Some(PhysicalDisk {
+        name: get_str_value(properties.inner(), DictKey::Defined(ffi::kIOBSDNameKey))?,
+        // The kind is only known once the device characteristics are found.
+        kind: DiskKind::Unknown(-1),
+        total_space: get_int_value(properties.inner(), DictKey::Defined(ffi::kIOMediaSizeKey))
+            .unwrap_or(0) as u64,
+        is_removable: get_bool(ffi::kIOMediaRemovableKey) || get_bool(ffi::kIOMediaEjectableKey),
+    })
+}
+
+pub(crate) fn get_disk_info(disk: &libc::statfs) -> Option<DiskInfo> {
+    let characteristics_string = create_cf_string(ffi::kIOPropertyDeviceCharacteristicsKey)?;
 
     // Removes `/dev/` from the value.
     let bsd_name = unsafe {
@@ -66,6 +113,11 @@ pub(crate) fn get_disk_type(disk: &libc::statfs) -> Option<DiskKind> {
     while let Some(mut current_service_entry) =
         IOReleaser::new(unsafe { ffi::IOIteratorNext(service_iterator.inner()) })
     {
+        // The physical disk is the last whole media found before reaching the device. For
+        // example, an APFS volume is on a synthesized whole media (the APFS container), which is
+        // itself on a partition of the physical disk.
+        let mut physical_disk = unsafe { get_whole_media(current_service_entry.inner()) };
+
         // Note: This loop is required in a non-obvious way. Due to device properties existing as a tree
         // in IOKit, we may need an arbitrary number of calls to `IORegistryEntryCreateCFProperty` in order to find
         // the values we are looking for. The function may return nothing if we aren't deep enough into the registry
@@ -87,6 +139,9 @@ pub(crate) fn get_disk_type(disk: &libc::statfs) -> Option<DiskKind> {
                 // There were no more parents left
                 None => break,
             };
+            if let Some(whole_media) = unsafe { get_whole_media(current_service_entry.inner()) } {
+                physical_disk = Some(whole_media);
+            }
 
             let properties_result = unsafe {
                 CFReleaser::new(ffi::IORegistryEntryCreateCFProperty(
@@ -98,26 +153,46 @@ pub(crate) fn get_disk_type(disk: &libc::statfs) -> Option<DiskKind> {
             };
 
             if let Some(device_properties) = properties_result {
-                let disk_type = unsafe {
-                    super::disk::get_str_value(
-                        device_properties.inner(),
-                        DictKey::Defined(ffi::kIOPropertyMediumTypeKey),
-                    )
+                let get_value = |key: &'static str| {
+                    unsafe { get_str_value(device_properties.inner(), DictKey::Defined(key)) }
+                        .map(|value| value.trim().to_owned())
+                        .filter(|value| !value.is_empty())
                 };
-
-                if let Some(disk_type) = disk_type.and_then(|medium| match medium.as_str() {
-                    _ if medium == ffi::kIOPropertyMediumTypeSolidStateKey => Some(DiskKind::SSD),
-                    _ if medium == ffi::kIOPropertyMediumTypeRotationalKey => Some(DiskKind::HDD),
+                let get_size = |key: &'static str| {
+                    unsafe { get_int_value(device_properties.inner(), DictKey::Defined(key)) }
+                        .filter(|size| *size > 0)
+                        .map(|size| size as u64)
+                };
+                let disk_type = get_value(ffi::kIOPropertyMediumTypeKey);
+                let is_rotational = disk_type.and_then(|medium| match medium.as_str() {
+                    _ if medium == ffi::kIOPropertyMediumTypeSolidStateKey => Some(false),
+                    _ if medium == ffi::kIOPropertyMediumTypeRotationalKey => Some(true),
                     _ => None,
-                }) {
-                    return Some(disk_type);
-                } else {
-                    // Many external drive vendors do not advertise their device's storage medium.
-                    //
-                    // In these cases, assuming that there were _any_ properties about them registered, we fallback
-                    // to `HDD` when no storage medium is provided by the device instead of `Unknown`.
-                    return Some(DiskKind::HDD);
-                }
+                });
+
+                // Many external drive vendors do not advertise their device's storage medium.
+                //
+                // In these cases, assuming that there were _any_ properties about them registered, we fallback
+                // to `HDD` when no storage medium is provided by the device instead of `Unknown`.
+                let kind = match is_rotational {
+                    Some(false) => DiskKind::SSD,
+                    _ => DiskKind::HDD,
+                };
+
+                return Some(DiskInfo {
+                    kind,
+                    vendor: get_value(ffi::kIOPropertyVendorNameKey),
+                    model: get_value(ffi::kIOPropertyProductNameKey),
+                    serial_number: get_value(ffi::kIOPropertyProductSerialNumberKey),
+                    physical_disk: physical_disk.map(|physical_disk| PhysicalDisk {
+                        kind,
+                        ..physical_disk
+                    }),
+                    is_rotational,
+                    logical_sector_size: get_size(ffi::kIOPropertyLogicalBlockSizeKey),
+                    physical_sector_size: get_size(ffi::kIOPropertyPhysicalBlockSizeKey),
+                    supports_trim: unsafe { supports_unmap(current_service_entry.inner()) },
+                });
             }
         }
     }
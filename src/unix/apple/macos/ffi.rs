@@ -34,6 +34,30 @@ pub const kIOPropertyMediumTypeKey: &str = "Medium Type";
 pub const kIOPropertyMediumTypeSolidStateKey: &str = "Solid State";
 #[allow(non_upper_case_globals)]
 pub const kIOPropertyMediumTypeRotationalKey: &str = "Rotational";
+#[allow(non_upper_case_globals)]
+pub const kIOPropertyLogicalBlockSizeKey: &str = "Logical Block Size";
+#[allow(non_upper_case_globals)]
+pub const kIOPropertyPhysicalBlockSizeKey: &str = "Physical Block Size";
+#[allow(non_upper_case_globals)]
+pub const kIOStorageFeaturesKey: &str = "IOStorageFeatures";
+#[allow(non_upper_case_globals)]
+pub const kIOStorageFeatureUnmap: &str = "Unmap";
+#[allow(non_upper_case_globals)]
+pub const kIOPropertyVendorNameKey: &str = "Vendor Name";
+#[allow(non_upper_case_globals)]
+pub const kIOPropertyProductNameKey: &str = "Product Name";
+#[allow(non_upper_case_globals)]
+pub const kIOPropertyProductSerialNumberKey: &str = "Serial Number";
+#[allow(non_upper_case_globals)]
+pub const kIOBSDNameKey: &str = "BSD Name";
+#[allow(non_upper_case_globals)]
+pub const kIOMediaWholeKey: &str = "Whole";
+#[allow(non_upper_case_globals)]
+pub const kIOMediaSizeKey: &str = "Size";
+#[allow(non_upper_case_globals)]
+pub const kIOMediaRemovableKey: &str = "Removable";
+#[allow(non_upper_case_globals)]
+pub const kIOMediaEjectableKey: &str = "Ejectable";
 
 // Based on https://github.com/libusb/libusb/blob/bed8d3034eac74a6e1ba123b5c270ea63cb6cf1a/libusb/os/darwin_usb.c#L54-L55,
 // we can simply set it to 0 (and is the same value as its replacement `kIOMainPortDefault`).
@@ -48,8 +72,11 @@ extern "C" {
         matching: CFMutableDictionaryRef,
         existing: *mut io_iterator_t,
     ) -> kern_return_t;
-    #[allow(dead_code)]
     pub fn IOServiceMatching(a: *const c_char) -> CFMutableDictionaryRef;
+    pub fn IOServiceGetMatchingService(
+        mainPort: mach_port_t,
+        matching: CFMutableDictionaryRef,
+    ) -> io_object_t;
 
     pub fn IOIteratorNext(iterator: io_iterator_t) -> io_object_t;
 
@@ -61,13 +88,29 @@ extern "C" {
         allocator: CFAllocatorRef,
         options: IOOptionBits,
     ) -> CFDictionaryRef;
+    pub fn IORegistryEntryCreateCFProperties(
+        entry: io_registry_entry_t,
+        properties: *mut CFMutableDictionaryRef,
+        allocator: CFAllocatorRef,
+        options: IOOptionBits,
+    ) -> kern_return_t;
     pub fn IORegistryEntryGetParentEntry(
         entry: io_registry_entry_t,
         plane: io_name_t,
         parent: *mut io_registry_entry_t,
     ) -> kern_return_t;
+    pub fn IORegistryEntryGetChildEntry(
+        entry: io_registry_entry_t,
+        plane: io_name_t,
+        child: *mut io_registry_entry_t,
+    ) -> kern_return_t;
+    pub fn IOObjectGetClass(object: io_object_t, class_name: *mut c_char) -> kern_return_t;
     #[allow(dead_code)]
     pub fn IORegistryEntryGetName(entry: io_registry_entry_t, name: io_name_t) -> kern_return_t;
+    pub fn IORegistryEntryFromPath(
+        mainPort: mach_port_t,
+        path: *const c_char,
+    ) -> io_registry_entry_t;
 
     pub fn IOBSDNameMatching(
         mainPort: mach_port_t,
@@ -310,3 +353,58 @@ mod io_service {
 
 #[cfg(all(not(feature = "apple-sandbox"), target_arch = "aarch64"))]
 pub use io_service::*;
+
+// IOReport is a private framework used to sample kernel performance counters. It's used to
+// compute the current CPU cores frequency.
+#[cfg(not(feature = "apple-sandbox"))]
+mod io_report {
+    use core_foundation_sys::base::CFTypeRef;
+    use core_foundation_sys::dictionary::{CFDictionaryRef, CFMutableDictionaryRef};
+    use core_foundation_sys::string::CFStringRef;
+
+    pub type IOReportSubscriptionRef = CFTypeRef;
+
+    #[link(name = "IOReport", kind = "dylib")]
+    extern "C" {
+        pub fn IOReportCopyChannelsInGroup(
+            group: CFStringRef,
+            subgroup: CFStringRef,
+            a: u64,
+            b: u64,
+            c: u64,
+        ) -> CFMutableDictionaryRef;
+        pub fn IOReportCreateSubscription(
+            a: CFTypeRef,
+            desired_channels: CFMutableDictionaryRef,
+            subbed_channels: *mut CFMutableDictionaryRef,
+            channel_id: u64,
+            b: CFTypeRef,
+        ) -> IOReportSubscriptionRef;
+        pub fn IOReportCreateSamples(
+            subscription: IOReportSubscriptionRef,
+            subbed_channels: CFMutableDictionaryRef,
+            a: CFTypeRef,
+        ) -> CFDictionaryRef;
+        pub fn IOReportCreateSamplesDelta(
+            previous: CFDictionaryRef,
+            current: CFDictionaryRef,
+            a: CFTypeRef,
+        ) -> CFDictionaryRef;
+        pub fn IOReportChannelGetChannelName(channel: CFDictionaryRef) -> CFStringRef;
+        pub fn IOReportStateGetCount(channel: CFDictionaryRef) -> i32;
+        pub fn IOReportStateGetNameForIndex(channel: CFDictionaryRef, index: i32) -> CFStringRef;
+        pub fn IOReportStateGetResidency(channel: CFDictionaryRef, index: i32) -> i64;
+    }
+}
+
+#[cfg(not(feature = "apple-sandbox"))]
+pub use io_report::*;
+
+#[cfg(not(feature = "apple-sandbox"))]
+#[link(name = "ServiceManagement", kind = "framework")]
+extern "C" {
+    pub static kSMDomainSystemLaunchd: CFStringRef;
+
+    // Deprecated since macOS 10.10 but it is still the only API listing the launchd jobs.
+    pub fn SMCopyAllJobDictionaries(domain: CFStringRef) -> core_foundation_sys::array::CFArrayRef;
+}
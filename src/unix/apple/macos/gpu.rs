@@ -0,0 +1,155 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::disk::{get_int_value, DictKey};
+use crate::sys::ffi;
+use crate::sys::macos::hardware::get_property;
+use crate::sys::macos::utils::IOReleaser;
+use crate::sys::utils::CFReleaser;
+use crate::{Gpu, GpuRefreshKind};
+
+use core_foundation_sys::base::kCFAllocatorDefault;
+use core_foundation_sys::dictionary::__CFDictionary;
+use core_foundation_sys::string::{kCFStringEncodingUTF8, CFStringCreateWithBytes};
+
+pub(crate) struct GpuInner {
+    name: String,
+    usage: Option<f32>,
+    used_memory: Option<u64>,
+    accelerator: IOReleaser,
+}
+
+impl GpuInner {
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn driver_version(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn usage(&self) -> Option<f32> {
+        self.usage
+    }
+
+    pub(crate) fn total_memory(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn used_memory(&self) -> Option<u64> {
+        self.used_memory
+    }
+
+    pub(crate) fn temperature(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn power(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn refresh(&mut self, refresh_kind: GpuRefreshKind) {
+        if !refresh_kind.usage() && !refresh_kind.memory() {
+            return;
+        }
+        unsafe {
+            let statistics = get_statistics(&self.accelerator);
+            let value = |key: &'static str| {
+                statistics
+                    .as_ref()
+                    .and_then(|statistics| get_int_value(statistics.inner(), DictKey::Defined(key)))
+            };
+            if refresh_kind.usage() {
+                self.usage = value("Device Utilization %").map(|usage| usage as f32);
+            }
+            // Apple Silicon GPUs use the system memory.
+            if refresh_kind.memory() {
+                self.used_memory = value("In use system memory")
+                    .or_else(|| value("vramUsedBytes"))
+                    .map(|memory| memory as u64);
+            }
+        }
+    }
+}
+
+/// Returns the `PerformanceStatistics` dictionary of the accelerator, which is updated by the
+/// driver.
+unsafe fn get_statistics(accelerator: &IOReleaser) -> Option<CFReleaser<__CFDictionary>> {
+    let key = "PerformanceStatistics";
+    let key = CFReleaser::new(CFStringCreateWithBytes(
+        kCFAllocatorDefault,
+        key.as_ptr(),
+        key.len() as _,
+        kCFStringEncodingUTF8,
+        false as _,
+    ))?;
+    CFReleaser::new(ffi::IORegistryEntryCreateCFProperty(
+        accelerator.inner(),
+        key.inner(),
+        kCFAllocatorDefault,
+        0,
+    ))
+}
+
+pub(crate) struct GpusInner {
+    gpus: Vec<Gpu>,
+}
+
+impl GpusInner {
+    pub(crate) fn new() -> Self {
+        Self { gpus: Vec::new() }
+    }
+
+    pub(crate) fn list(&self) -> &[Gpu] {
+        &self.gpus
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Gpu] {
+        &mut self.gpus
+    }
+
+    pub(crate) fn refresh_list(&mut self, refresh_kind: GpuRefreshKind) {
+        self.gpus.clear();
+        unsafe {
+            let mut iterator: ffi::io_iterator_t = 0;
+            // `IOServiceGetMatchingServices` takes ownership of the matching dictionary.
+            if ffi::IOServiceGetMatchingServices(
+                ffi::kIOMasterPortDefault,
+                ffi::IOServiceMatching(b"IOAccelerator\0".as_ptr() as *const _),
+                &mut iterator,
+            ) != libc::KERN_SUCCESS
+            {
+                sysinfo_debug!("`IOAccelerator` services not found");
+                return;
+            }
+            let iterator = match IOReleaser::new(iterator) {
+                Some(iterator) => iterator,
+                None => return,
+            };
+            while let Some(accelerator) = IOReleaser::new(ffi::IOIteratorNext(iterator.inner())) {
+                // On Intel Macs, the model is set on the PCI device of the GPU.
+                let name = get_property(&accelerator, "model")
+                    .or_else(|| {
+                        let mut parent = 0;
+                        if ffi::IORegistryEntryGetParentEntry(
+                            accelerator.inner(),
+                            ffi::kIOServicePlane.as_ptr().cast(),
+                            &mut parent,
+                        ) != libc::KERN_SUCCESS
+                        {
+                            return None;
+                        }
+                        get_property(&IOReleaser::new(parent)?, "model")
+                    })
+                    .unwrap_or_else(|| format!("GPU {}", self.gpus.len()));
+                let mut inner = GpuInner {
+                    name,
+                    usage: None,
+                    used_memory: None,
+                    accelerator,
+                };
+                inner.refresh(refresh_kind);
+                self.gpus.push(Gpu { inner });
+            }
+        }
+    }
+}
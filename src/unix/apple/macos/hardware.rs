@@ -0,0 +1,157 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::ffi;
+use crate::sys::macos::utils::IOReleaser;
+use crate::sys::utils::CFReleaser;
+use crate::{ChassisKind, HardwareInfo};
+
+use core_foundation_sys::base::{kCFAllocatorDefault, CFGetTypeID};
+use core_foundation_sys::data::{CFDataGetBytePtr, CFDataGetLength, CFDataGetTypeID, CFDataRef};
+use core_foundation_sys::string::{
+    kCFStringEncodingUTF8, CFStringCreateWithBytes, CFStringGetCString, CFStringGetTypeID,
+    CFStringRef,
+};
+use libc::c_char;
+
+use std::ffi::CStr;
+
+// It is the root of the device tree.
+unsafe fn get_platform_expert() -> Option<IOReleaser> {
+    let platform = IOReleaser::new(ffi::IOServiceGetMatchingService(
+        ffi::kIOMasterPortDefault,
+        ffi::IOServiceMatching(b"IOPlatformExpertDevice\0".as_ptr() as *const _),
+    ));
+    if platform.is_none() {
+        sysinfo_debug!("`IOPlatformExpertDevice` service not found");
+    }
+    platform
+}
+
+pub(crate) fn get_machine_id() -> Option<String> {
+    unsafe { get_property(&get_platform_expert()?, "IOPlatformUUID") }
+}
+
+pub(crate) fn get_hardware_info() -> HardwareInfo {
+    unsafe {
+        let platform = match get_platform_expert() {
+            Some(platform) => platform,
+            None => return HardwareInfo::default(),
+        };
+        // Only available on Intel Macs.
+        let rom = IOReleaser::new(ffi::IORegistryEntryFromPath(
+            ffi::kIOMasterPortDefault,
+            b"IODeviceTree:/rom\0".as_ptr() as *const _,
+        ));
+        let chosen = IOReleaser::new(ffi::IORegistryEntryFromPath(
+            ffi::kIOMasterPortDefault,
+            b"IODeviceTree:/chosen\0".as_ptr() as *const _,
+        ));
+        let rom_property =
+            |key: &str| -> Option<String> { rom.as_ref().and_then(|rom| get_property(rom, key)) };
+
+        // Like `MacBookPro18,3`.
+        let model = get_property(&platform, "model").unwrap_or_default();
+        let manufacturer = get_property(&platform, "manufacturer").unwrap_or_default();
+        // Only set on Apple Silicon, like `MacBook Pro (14-inch, 2021)`.
+        let product_name = get_property(&platform, "product-name").unwrap_or_else(|| model.clone());
+        HardwareInfo {
+            // Apple Silicon models are like `Mac14,2`, so the product name is checked first.
+            chassis: chassis_from_model(&product_name)
+                .or_else(|| chassis_from_model(&model))
+                .unwrap_or_default(),
+            product_name,
+            product_version: model,
+            serial_number: get_property(&platform, "IOPlatformSerialNumber").unwrap_or_default(),
+            // Apple makes its own motherboards.
+            board_vendor: manufacturer.clone(),
+            // Like `Mac-827FB448E656EC26` on Intel or `J314sAP` on Apple Silicon.
+            board_name: get_property(&platform, "board-id")
+                .or_else(|| get_property(&platform, "target-type"))
+                .unwrap_or_default(),
+            // There is no separate serial number for the motherboard.
+            board_serial_number: String::new(),
+            bios_vendor: rom_property("vendor").unwrap_or_else(|| manufacturer.clone()),
+            bios_version: rom_property("version")
+                .or_else(|| {
+                    chosen
+                        .as_ref()
+                        .and_then(|chosen| get_property(chosen, "system-firmware-version"))
+                })
+                .unwrap_or_default(),
+            bios_release_date: rom_property("release-date").unwrap_or_default(),
+            manufacturer,
+        }
+    }
+}
+
+/// Returns the chassis from a model name (like `MacBook Air (M2, 2022)`) or identifier (like
+/// `MacBookPro18,3`).
+fn chassis_from_model(model: &str) -> Option<ChassisKind> {
+    let model = model.replace(' ', "").to_ascii_lowercase();
+    Some(if model.starts_with("macbook") {
+        ChassisKind::Laptop
+    } else if model.starts_with("imac") {
+        ChassisKind::AllInOne
+    } else if ["macmini", "macpro", "macstudio"]
+        .iter()
+        .any(|prefix| model.starts_with(prefix))
+    {
+        ChassisKind::Desktop
+    } else if model.starts_with("xserve") {
+        ChassisKind::Server
+    } else if model.starts_with("virtualmac") {
+        ChassisKind::VirtualMachine
+    } else {
+        return None;
+    })
+}
+
+/// Returns the given property of the registry entry. It is either a string or a
+/// null-terminated `CFData`.
+pub(crate) unsafe fn get_property(entry: &IOReleaser, key: &str) -> Option<String> {
+    let key = CFReleaser::new(CFStringCreateWithBytes(
+        kCFAllocatorDefault,
+        key.as_ptr(),
+        key.len() as _,
+        kCFStringEncodingUTF8,
+        false as _,
+    ))?;
+    let value = CFReleaser::new(ffi::IORegistryEntryCreateCFProperty(
+        entry.inner(),
+        key.inner(),
+        kCFAllocatorDefault,
+        0,
+    ))?;
+    let type_id = CFGetTypeID(value.inner() as *const _);
+
+    let value = if type_id == CFStringGetTypeID() {
+        let mut buf = [0 as c_char; 256];
+        if CFStringGetCString(
+            value.inner() as CFStringRef,
+            buf.as_mut_ptr(),
+            buf.len() as _,
+            kCFStringEncodingUTF8,
+        ) == 0
+        {
+            return None;
+        }
+        CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned()
+    } else if type_id == CFDataGetTypeID() {
+        let data = value.inner() as CFDataRef;
+        let len = CFDataGetLength(data);
+        if len <= 0 {
+            return None;
+        }
+        let bytes = std::slice::from_raw_parts(CFDataGetBytePtr(data), len as _);
+        let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+        String::from_utf8_lossy(&bytes[..end]).into_owned()
+    } else {
+        return None;
+    };
+    let value = value.trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_owned())
+    }
+}
@@ -1,12 +1,28 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+pub(crate) mod battery;
 pub mod disk;
 pub mod ffi;
+pub(crate) mod hardware;
 pub(crate) mod utils;
+#[cfg(feature = "wireless")]
+pub(crate) mod wireless;
+
+#[cfg(not(feature = "apple-sandbox"))]
+pub(crate) mod connections;
 
 #[cfg(not(feature = "apple-sandbox"))]
 pub(crate) mod cpu;
 
+#[cfg(not(feature = "apple-sandbox"))]
+pub(crate) mod gpu;
+
+#[cfg(not(feature = "apple-sandbox"))]
+pub(crate) mod pci;
+
+#[cfg(all(feature = "energy", not(feature = "apple-sandbox")))]
+pub(crate) mod power;
+
 #[cfg(not(feature = "apple-sandbox"))]
 pub mod system;
 
@@ -16,8 +32,29 @@ pub mod component;
 #[cfg(not(feature = "apple-sandbox"))]
 pub mod process;
 
+#[cfg(not(feature = "apple-sandbox"))]
+pub(crate) mod services;
+
 #[cfg(feature = "apple-sandbox")]
 pub use crate::sys::app_store::component;
 
+#[cfg(feature = "apple-sandbox")]
+pub(crate) use crate::sys::app_store::connections;
+
+#[cfg(feature = "apple-sandbox")]
+pub(crate) use crate::sys::app_store::cpu;
+
+#[cfg(feature = "apple-sandbox")]
+pub(crate) use crate::sys::app_store::gpu;
+
+#[cfg(feature = "apple-sandbox")]
+pub(crate) use crate::sys::app_store::pci;
+
+#[cfg(all(feature = "energy", feature = "apple-sandbox"))]
+pub(crate) use crate::sys::app_store::power;
+
 #[cfg(feature = "apple-sandbox")]
 pub use crate::sys::app_store::process;
+
+#[cfg(feature = "apple-sandbox")]
+pub(crate) use crate::sys::app_store::services;
@@ -0,0 +1,150 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::ffi;
+use crate::sys::macos::hardware::get_property;
+use crate::sys::macos::utils::IOReleaser;
+use crate::sys::utils::CFReleaser;
+use crate::PciDevice;
+
+use core_foundation_sys::base::{kCFAllocatorDefault, CFGetTypeID};
+use core_foundation_sys::data::{CFDataGetBytePtr, CFDataGetLength, CFDataGetTypeID, CFDataRef};
+use core_foundation_sys::number::{
+    kCFNumberSInt64Type, CFNumberGetTypeID, CFNumberGetValue, CFNumberRef,
+};
+use core_foundation_sys::string::{kCFStringEncodingUTF8, CFStringCreateWithBytes};
+use libc::c_char;
+
+use std::ffi::{c_void, CStr};
+
+pub(crate) fn get_pci_devices(devices: &mut Vec<PciDevice>) {
+    unsafe {
+        let mut iterator: ffi::io_iterator_t = 0;
+        // `IOServiceGetMatchingServices` takes ownership of the matching dictionary.
+        if ffi::IOServiceGetMatchingServices(
+            ffi::kIOMasterPortDefault,
+            ffi::IOServiceMatching(b"IOPCIDevice\0".as_ptr() as *const _),
+            &mut iterator,
+        ) != libc::KERN_SUCCESS
+        {
+            sysinfo_debug!("`IOPCIDevice` services not found");
+            return;
+        }
+        let iterator = match IOReleaser::new(iterator) {
+            Some(iterator) => iterator,
+            None => return,
+        };
+        while let Some(device) = IOReleaser::new(ffi::IOIteratorNext(iterator.inner())) {
+            // The IDs are stored as 32-bit little-endian values.
+            let id = |key: &str| {
+                let bytes = get_data(&device, key)?;
+                Some(u32::from_le_bytes([
+                    *bytes.first()?,
+                    *bytes.get(1)?,
+                    *bytes.get(2)?,
+                    *bytes.get(3)?,
+                ]))
+            };
+            let (vendor_id, device_id) = match (id("vendor-id"), id("device-id")) {
+                (Some(vendor_id), Some(device_id)) => (vendor_id as u16, device_id as u16),
+                _ => continue,
+            };
+            // The speed (as the PCIe generation) and the width are the first bits of the link
+            // status register.
+            let link_status = get_number(&device, "IOPCIExpressLinkStatus");
+            devices.push(PciDevice {
+                // Like `0:2:0(0:0)`.
+                address: get_property(&device, "pcidebug").and_then(|address| {
+                    let address = address.split('(').next()?;
+                    let mut parts = address.split(':').map(|part| part.parse::<u32>().ok());
+                    let (bus, device, function) = (parts.next()??, parts.next()??, parts.next()??);
+                    Some(format!("0000:{bus:02x}:{device:02x}.{function:x}"))
+                }),
+                class: id("class-code").unwrap_or(0),
+                vendor_id,
+                device_id,
+                vendor_name: None,
+                device_name: get_property(&device, "model"),
+                driver: get_driver(&device),
+                link_width: link_status
+                    .map(|status| ((status >> 4) & 0x3f) as u8)
+                    .filter(|width| *width != 0),
+                link_speed: link_status.and_then(|status| {
+                    Some(match status & 0xf {
+                        1 => 2.5,
+                        2 => 5.,
+                        3 => 8.,
+                        4 => 16.,
+                        5 => 32.,
+                        6 => 64.,
+                        _ => return None,
+                    })
+                }),
+            });
+        }
+    }
+}
+
+/// Returns the class of the driver attached to the device, like `AppleBCM5701Ethernet`.
+unsafe fn get_driver(device: &IOReleaser) -> Option<String> {
+    let mut child = 0;
+    if ffi::IORegistryEntryGetChildEntry(
+        device.inner(),
+        ffi::kIOServicePlane.as_ptr().cast(),
+        &mut child,
+    ) != libc::KERN_SUCCESS
+    {
+        return None;
+    }
+    let child = IOReleaser::new(child)?;
+    let mut name = [0 as c_char; 128];
+    if ffi::IOObjectGetClass(child.inner(), name.as_mut_ptr()) != libc::KERN_SUCCESS {
+        return None;
+    }
+    Some(CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned())
+}
+
+unsafe fn get_cf_property(entry: &IOReleaser, key: &str) -> Option<CFReleaser<c_void>> {
+    let key = CFReleaser::new(CFStringCreateWithBytes(
+        kCFAllocatorDefault,
+        key.as_ptr(),
+        key.len() as _,
+        kCFStringEncodingUTF8,
+        false as _,
+    ))?;
+    CFReleaser::new(ffi::IORegistryEntryCreateCFProperty(
+        entry.inner(),
+        key.inner(),
+        kCFAllocatorDefault,
+        0,
+    ) as *const c_void)
+}
+
+unsafe fn get_data(entry: &IOReleaser, key: &str) -> Option<Vec<u8>> {
+    let value = get_cf_property(entry, key)?;
+    if CFGetTypeID(value.inner()) != CFDataGetTypeID() {
+        return None;
+    }
+    let data = value.inner() as CFDataRef;
+    let len = CFDataGetLength(data);
+    if len <= 0 {
+        return None;
+    }
+    Some(std::slice::from_raw_parts(CFDataGetBytePtr(data), len as _).to_vec())
+}
+
+unsafe fn get_number(entry: &IOReleaser, key: &str) -> Option<u64> {
+    let value = get_cf_property(entry, key)?;
+    if CFGetTypeID(value.inner()) != CFNumberGetTypeID() {
+        return None;
+    }
+    let mut number = 0i64;
+    if CFNumberGetValue(
+        value.inner() as CFNumberRef,
+        kCFNumberSInt64Type,
+        &mut number as *mut i64 as *mut c_void,
+    ) {
+        Some(number as u64)
+    } else {
+        None
+    }
+}
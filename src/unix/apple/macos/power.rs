@@ -0,0 +1,65 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::sys::inner::component::{get_power, IoService};
+use crate::sys::macos::battery::get_discharge_rails;
+use crate::PowerRail;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::PowerRailKind;
+
+// The power keys of the SMC, only available on Intel Macs.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const SMC_POWER_KEYS: &[(&str, PowerRailKind, &[i8])] = &[
+    (
+        "system",
+        PowerRailKind::System,
+        &['P' as i8, 'S' as i8, 'T' as i8, 'R' as i8],
+    ), // System total "PSTR"
+    (
+        "cpu",
+        PowerRailKind::Cpu,
+        &['P' as i8, 'C' as i8, 'P' as i8, 'C' as i8],
+    ), // CPU package cores "PCPC"
+    (
+        "gpu",
+        PowerRailKind::Gpu,
+        &['P' as i8, 'C' as i8, 'P' as i8, 'G' as i8],
+    ), // CPU package graphics "PCPG"
+];
+
+pub(crate) struct PowerInner {
+    rails: Vec<PowerRail>,
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    connection: Option<IoService>,
+}
+
+impl PowerInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            rails: Vec::new(),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            connection: IoService::new_connection(),
+        }
+    }
+
+    pub(crate) fn rails(&self) -> &[PowerRail] {
+        &self.rails
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        self.rails.clear();
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if let Some(ref connection) = self.connection {
+            for (name, kind, key) in SMC_POWER_KEYS {
+                if let Some(power) = get_power(connection.inner(), key) {
+                    self.rails.push(PowerRail {
+                        name: (*name).to_owned(),
+                        kind: *kind,
+                        power,
+                    });
+                }
+            }
+        }
+        get_discharge_rails(&mut self.rails);
+    }
+}
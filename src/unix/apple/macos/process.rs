@@ -3,6 +3,7 @@
 use std::mem::{self, MaybeUninit};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use std::borrow::Borrow;
 
@@ -27,10 +28,15 @@ pub(crate) struct ProcessInner {
     pub(crate) virtual_memory: u64,
     old_utime: u64,
     old_stime: u64,
+    /// Total user time, in mach absolute time units.
+    total_user_time: u64,
+    /// Total system time, in mach absolute time units.
+    total_system_time: u64,
     start_time: u64,
     run_time: u64,
     pub(crate) updated: bool,
     cpu_usage: f32,
+    normalized_cpu_usage: f32,
     user_id: Option<Uid>,
     effective_user_id: Option<Uid>,
     group_id: Option<Gid>,
@@ -67,8 +73,11 @@ impl ProcessInner {
             memory: 0,
             virtual_memory: 0,
             cpu_usage: 0.,
+            normalized_cpu_usage: 0.,
             old_utime: 0,
             old_stime: 0,
+            total_user_time: 0,
+            total_system_time: 0,
             updated: true,
             start_time: 0,
             run_time: 0,
@@ -105,8 +114,11 @@ impl ProcessInner {
             memory: 0,
             virtual_memory: 0,
             cpu_usage: 0.,
+            normalized_cpu_usage: 0.,
             old_utime: 0,
             old_stime: 0,
+            total_user_time: 0,
+            total_system_time: 0,
             updated: true,
             start_time,
             run_time,
@@ -191,6 +203,23 @@ impl ProcessInner {
         self.cpu_usage
     }
 
+    pub(crate) fn normalized_cpu_usage(&self) -> f32 {
+        self.normalized_cpu_usage
+    }
+
+    pub(crate) fn set_cpu_usage(&mut self, cpu_usage: f32, normalized_cpu_usage: f32) {
+        self.cpu_usage = cpu_usage;
+        self.normalized_cpu_usage = normalized_cpu_usage;
+    }
+
+    pub(crate) fn user_time(&self) -> Duration {
+        mach_time_to_duration(self.total_user_time)
+    }
+
+    pub(crate) fn kernel_time(&self) -> Duration {
+        mach_time_to_duration(self.total_system_time)
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             read_bytes: self.read_bytes.saturating_sub(self.old_read_bytes),
@@ -240,6 +269,76 @@ impl ProcessInner {
             }
         }
     }
+
+    pub(crate) fn is_translated(&self) -> bool {
+        unsafe { is_process_translated(self.pid) }
+    }
+
+    pub(crate) fn is_remote_session(&self) -> bool {
+        crate::unix::sessions::is_remote_environ(&self.environ)
+    }
+}
+
+#[allow(deprecated)] // Because of libc::mach_timebase_info.
+fn mach_time_to_duration(time: u64) -> Duration {
+    let mut info = libc::mach_timebase_info_data_t { numer: 0, denom: 0 };
+    unsafe {
+        if libc::mach_timebase_info(&mut info) != libc::KERN_SUCCESS || info.denom == 0 {
+            return Duration::from_nanos(time);
+        }
+    }
+    Duration::from_nanos((time as u128 * info.numer as u128 / info.denom as u128) as u64)
+}
+
+// `P_TRANSLATED` from `<sys/proc.h>`: the process is running under Rosetta.
+const P_TRANSLATED: c_int = 0x0002_0000;
+
+// Beginning of the `extern_proc` struct (`kinfo_proc.kp_proc`), which is all we need to read
+// `p_flag`.
+#[repr(C)]
+struct ExternProcHead {
+    p_un: [*mut c_void; 2],
+    p_vmspace: *mut c_void,
+    p_sigacts: *mut c_void,
+    p_flag: c_int,
+}
+
+unsafe fn is_process_translated(pid: Pid) -> bool {
+    let mut mib = [
+        libc::CTL_KERN,
+        libc::KERN_PROC,
+        libc::KERN_PROC_PID,
+        pid.0 as _,
+    ];
+    let mut size = 0;
+    if libc::sysctl(
+        mib.as_mut_ptr(),
+        mib.len() as _,
+        std::ptr::null_mut(),
+        &mut size,
+        std::ptr::null_mut(),
+        0,
+    ) != 0
+        || size < mem::size_of::<ExternProcHead>()
+    {
+        return false;
+    }
+    let mut buffer: Vec<u8> = Vec::with_capacity(size);
+    if libc::sysctl(
+        mib.as_mut_ptr(),
+        mib.len() as _,
+        buffer.as_mut_ptr() as *mut _,
+        &mut size,
+        std::ptr::null_mut(),
+        0,
+    ) != 0
+        || size < mem::size_of::<ExternProcHead>()
+    {
+        sysinfo_debug!("couldn't get `kinfo_proc` for PID {}", pid.0);
+        return false;
+    }
+    let head = std::ptr::read_unaligned(buffer.as_ptr() as *const ExternProcHead);
+    head.p_flag & P_TRANSLATED != 0
 }
 
 #[allow(deprecated)] // Because of libc::mach_absolute_time.
@@ -521,6 +620,8 @@ unsafe fn create_new_process(
 
     p.memory = task_info.pti_resident_size;
     p.virtual_memory = task_info.pti_virtual_size;
+    p.total_user_time = task_info.pti_total_user;
+    p.total_system_time = task_info.pti_total_system;
 
     p.user_id = Some(Uid(info.pbi_ruid));
     p.effective_user_id = Some(Uid(info.pbi_uid));
@@ -595,6 +696,7 @@ pub(crate) fn update_process(
     wrap: &Wrap,
     pid: Pid,
     time_interval: Option<f64>,
+    nb_cpus: usize,
     now: u64,
     refresh_kind: ProcessRefreshKind,
     check_if_alive: bool,
@@ -646,10 +748,13 @@ pub(crate) fn update_process(
 
             if refresh_kind.cpu() {
                 compute_cpu_usage(p, task_info, system_time, user_time, time_interval);
+                p.normalized_cpu_usage = p.cpu_usage / nb_cpus.max(1) as f32;
             }
 
             p.memory = task_info.pti_resident_size;
             p.virtual_memory = task_info.pti_virtual_size;
+            p.total_user_time = task_info.pti_total_user;
+            p.total_system_time = task_info.pti_total_system;
             if refresh_kind.disk_usage() {
                 update_proc_disk_activity(p);
             }
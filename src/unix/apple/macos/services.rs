@@ -0,0 +1,62 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::disk::{
+    cfstring_to_string, get_bool_value, get_dict_value, get_int_value, get_str_value, DictKey,
+};
+use crate::sys::ffi;
+use crate::sys::utils::CFReleaser;
+use crate::{Pid, Service, ServiceStartKind, ServiceState};
+
+use core_foundation_sys::array::{CFArrayGetCount, CFArrayGetValueAtIndex, CFArrayRef};
+use core_foundation_sys::dictionary::CFDictionaryRef;
+
+pub(crate) fn get_services(services: &mut Vec<Service>) {
+    unsafe {
+        let jobs = match CFReleaser::new(ffi::SMCopyAllJobDictionaries(ffi::kSMDomainSystemLaunchd))
+        {
+            Some(jobs) => jobs,
+            None => {
+                sysinfo_debug!("SMCopyAllJobDictionaries failed");
+                return;
+            }
+        };
+        for index in 0..CFArrayGetCount(jobs.inner()) {
+            let job = CFArrayGetValueAtIndex(jobs.inner(), index) as CFDictionaryRef;
+            if let Some(service) = get_service(job) {
+                services.push(service);
+            }
+        }
+    }
+}
+
+unsafe fn get_service(job: CFDictionaryRef) -> Option<Service> {
+    let pid = get_int_value(job, DictKey::Defined("PID"))
+        .filter(|pid| *pid > 0)
+        .map(|pid| Pid::from(pid as usize));
+    // Jobs which aren't on demand are started at load and restarted when they exit.
+    let start_kind = match get_bool_value(job, DictKey::Defined("OnDemand")) {
+        Some(false) => ServiceStartKind::Automatic,
+        _ => ServiceStartKind::Manual,
+    };
+    let command = get_dict_value(job, DictKey::Defined("ProgramArguments"), |arguments| {
+        let arguments = arguments as CFArrayRef;
+        let arguments = (0..CFArrayGetCount(arguments))
+            .filter_map(|index| cfstring_to_string(CFArrayGetValueAtIndex(arguments, index) as _))
+            .collect::<Vec<_>>();
+        Some(arguments.join(" ")).filter(|command| !command.is_empty())
+    })
+    .or_else(|| get_str_value(job, DictKey::Defined("Program")));
+
+    Some(Service {
+        name: get_str_value(job, DictKey::Defined("Label"))?,
+        description: None,
+        state: if pid.is_some() {
+            ServiceState::Running
+        } else {
+            ServiceState::Stopped
+        },
+        start_kind,
+        command,
+        pid,
+    })
+}
@@ -5,7 +5,7 @@ use libc::{mach_timebase_info, mach_timebase_info_data_t};
 
 use libc::{
     host_processor_info, mach_port_t, munmap, natural_t, processor_cpu_load_info,
-    processor_cpu_load_info_t, sysconf, vm_page_size, PROCESSOR_CPU_LOAD_INFO, _SC_CLK_TCK,
+    processor_cpu_load_info_t, sysconf, vm_page_size, _SC_CLK_TCK, PROCESSOR_CPU_LOAD_INFO,
 };
 use std::ptr::null_mut;
 
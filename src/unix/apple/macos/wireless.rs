@@ -0,0 +1,119 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::CFReleaser;
+use crate::{MacAddr, WirelessInfo};
+
+use core_foundation_sys::base::kCFAllocatorDefault;
+use core_foundation_sys::string::{
+    kCFStringEncodingUTF8, CFStringCreateWithBytes, CFStringGetCString, CFStringRef,
+};
+use libc::{c_char, c_void};
+
+use std::ffi::CStr;
+
+type Id = *mut c_void;
+type Sel = *mut c_void;
+
+#[link(name = "objc")]
+extern "C" {
+    fn objc_getClass(name: *const c_char) -> Id;
+    fn sel_registerName(name: *const c_char) -> Sel;
+    fn objc_msgSend();
+    fn objc_autoreleasePoolPush() -> *mut c_void;
+    fn objc_autoreleasePoolPop(pool: *mut c_void);
+}
+
+#[link(name = "CoreWLAN", kind = "framework")]
+extern "C" {}
+
+// `objc_msgSend` must be cast to the signature of the called method.
+unsafe fn send<R>(receiver: Id, selector: &'static [u8]) -> R {
+    let send: unsafe extern "C" fn(Id, Sel) -> R = std::mem::transmute(objc_msgSend as *const ());
+    send(receiver, sel_registerName(selector.as_ptr() as _))
+}
+
+unsafe fn send_with_arg<R>(receiver: Id, selector: &'static [u8], arg: Id) -> R {
+    let send: unsafe extern "C" fn(Id, Sel, Id) -> R =
+        std::mem::transmute(objc_msgSend as *const ());
+    send(receiver, sel_registerName(selector.as_ptr() as _), arg)
+}
+
+// `NSString` is toll-free bridged with `CFString`.
+unsafe fn ns_string_to_string(s: Id) -> Option<String> {
+    if s.is_null() {
+        return None;
+    }
+    let mut buf = [0 as c_char; 128];
+    if CFStringGetCString(
+        s as CFStringRef,
+        buf.as_mut_ptr(),
+        buf.len() as _,
+        kCFStringEncodingUTF8,
+    ) == 0
+    {
+        return None;
+    }
+    Some(CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned())
+}
+
+fn parse_bssid(bssid: &str) -> Option<MacAddr> {
+    let mut mac = [0; 6];
+    let mut parts = bssid.split(':');
+    for byte in mac.iter_mut() {
+        *byte = u8::from_str_radix(parts.next()?, 16).ok()?;
+    }
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(MacAddr(mac))
+}
+
+/// Returns the details of the Wi-Fi connection of `interface`, or `None` if it is not a connected
+/// wireless interface.
+pub(crate) fn get_wireless_info(interface: &str) -> Option<WirelessInfo> {
+    unsafe {
+        let class = objc_getClass(b"CWWiFiClient\0".as_ptr() as _);
+        if class.is_null() {
+            return None;
+        }
+        let name = CFReleaser::new(CFStringCreateWithBytes(
+            kCFAllocatorDefault,
+            interface.as_ptr(),
+            interface.len() as _,
+            kCFStringEncodingUTF8,
+            false as _,
+        ))?;
+
+        let pool = objc_autoreleasePoolPush();
+        let info = (|| {
+            let client: Id = send(class, b"sharedWiFiClient\0");
+            if client.is_null() {
+                return None;
+            }
+            let wifi: Id = send_with_arg(client, b"interfaceWithName:\0", name.inner() as Id);
+            if wifi.is_null() {
+                return None;
+            }
+            let ssid = ns_string_to_string(send(wifi, b"ssid\0"));
+            let bssid = ns_string_to_string(send(wifi, b"bssid\0")).and_then(|b| parse_bssid(&b));
+            // The channel is `nil` when the interface is not associated to a network.
+            let channel: Id = send(wifi, b"wlanChannel\0");
+            if channel.is_null() {
+                return None;
+            }
+            let channel_number: isize = send(channel, b"channelNumber\0");
+            let rssi: isize = send(wifi, b"rssiValue\0");
+            // In Mbit/s.
+            let transmit_rate: f64 = send(wifi, b"transmitRate\0");
+            Some(WirelessInfo {
+                ssid,
+                bssid,
+                signal: Some(rssi as i32).filter(|rssi| *rssi != 0),
+                channel: Some(channel_number as u32).filter(|channel| *channel != 0),
+                bitrate: Some((transmit_rate * 1_000_000.) as u64).filter(|rate| *rate != 0),
+            })
+        })();
+        objc_autoreleasePoolPop(pool);
+        info
+    }
+}
@@ -5,9 +5,44 @@ use libc::{self, c_char, if_msghdr2, CTL_NET, NET_RT_IFLIST2, PF_ROUTE, RTM_IFIN
 use std::collections::{hash_map, HashMap};
 use std::ptr::null_mut;
 
-use crate::common::MacAddr;
-use crate::network::refresh_networks_addresses;
+use crate::common::{
+    DuplexMode, InterfaceKind, IpNetwork, MacAddr, NetworkCounters, OperationalState,
+};
+use crate::network::{counter_delta, refresh_networks_addresses, LinkState, RefreshTimes};
+use crate::network_helper::{
+    get_duplex_mode, get_interface_index, get_interface_kind, get_vlan_parent,
+};
 use crate::NetworkData;
+#[cfg(feature = "wireless")]
+use crate::WirelessInfo;
+
+// The baudrate is `0` for interfaces which don't have a link speed, like loopback.
+fn get_link_speed(baudrate: u64) -> Option<u64> {
+    Some(baudrate).filter(|speed| *speed != 0)
+}
+
+fn get_counters(data: &libc::if_data64) -> NetworkCounters {
+    NetworkCounters {
+        multicast_received: Some(data.ifi_imcasts),
+        multicast_transmitted: Some(data.ifi_omcasts),
+        collisions: Some(data.ifi_collisions),
+        dropped_on_received: Some(data.ifi_iqdrops),
+        ..NetworkCounters::default()
+    }
+}
+
+// The link state is not available in `if_data64`, `IFF_RUNNING` is set when the interface has a
+// carrier.
+fn get_link_state(flags: libc::c_int) -> LinkState {
+    let is_admin_up = flags & libc::IFF_UP != 0;
+    let is_running = flags & libc::IFF_RUNNING != 0;
+    let operational_state = if is_admin_up && is_running {
+        OperationalState::Up
+    } else {
+        OperationalState::Down
+    };
+    LinkState::new(operational_state, is_admin_up, Some(is_running))
+}
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident, $new_val:expr) => {{
@@ -18,12 +53,21 @@ macro_rules! old_and_new {
 
 pub(crate) struct NetworksInner {
     pub(crate) interfaces: HashMap<String, NetworkData>,
+    handle_counter_wrap: bool,
 }
 
 impl NetworksInner {
     pub(crate) fn new() -> Self {
         Self {
             interfaces: HashMap::new(),
+            handle_counter_wrap: true,
+        }
+    }
+
+    pub(crate) fn set_counter_wrap_handling(&mut self, enabled: bool) {
+        self.handle_counter_wrap = enabled;
+        for data in self.interfaces.values_mut() {
+            data.inner.handle_counter_wrap = enabled;
         }
     }
 
@@ -35,19 +79,55 @@ impl NetworksInner {
         for (_, data) in self.interfaces.iter_mut() {
             data.inner.updated = false;
         }
-        self.update_networks(true);
+        self.update_networks(true, 0);
         self.interfaces.retain(|_, data| data.inner.updated);
+        for (name, data) in self.interfaces.iter_mut() {
+            data.inner.duplex = get_duplex_mode(name);
+            data.inner.lower_interfaces = if data.inner.kind == InterfaceKind::Vlan {
+                get_vlan_parent(name).into_iter().collect()
+            } else {
+                Vec::new()
+            };
+        }
         refresh_networks_addresses(&mut self.interfaces);
+        #[cfg(all(feature = "wireless", target_os = "macos"))]
+        self.refresh_wireless();
     }
 
     pub(crate) fn refresh(&mut self) {
-        self.update_networks(false);
+        self.update_networks(false, 0);
+        #[cfg(all(feature = "wireless", target_os = "macos"))]
+        self.refresh_wireless();
+    }
+
+    pub(crate) fn refresh_interfaces(&mut self, interface_names: &[&str]) {
+        for interface_name in interface_names {
+            if !self.interfaces.contains_key(*interface_name) {
+                continue;
+            }
+            if let Some(index) = get_interface_index(interface_name) {
+                self.update_networks(false, index);
+            }
+            #[cfg(all(feature = "wireless", target_os = "macos"))]
+            if let Some(data) = self.interfaces.get_mut(*interface_name) {
+                data.inner.wireless =
+                    crate::sys::inner::wireless::get_wireless_info(interface_name);
+            }
+        }
+    }
+
+    #[cfg(all(feature = "wireless", target_os = "macos"))]
+    fn refresh_wireless(&mut self) {
+        for (name, data) in self.interfaces.iter_mut() {
+            data.inner.wireless = crate::sys::inner::wireless::get_wireless_info(name);
+        }
     }
 
     #[allow(clippy::cast_ptr_alignment)]
     #[allow(clippy::uninit_vec)]
-    fn update_networks(&mut self, insert: bool) {
-        let mib = &mut [CTL_NET, PF_ROUTE, 0, 0, NET_RT_IFLIST2, 0];
+    /// Updates the interface whose index is `index`, or all of them if it is `0`.
+    fn update_networks(&mut self, insert: bool, index: u32) {
+        let mib = &mut [CTL_NET, PF_ROUTE, 0, 0, NET_RT_IFLIST2, index as _];
         let mut len = 0;
         unsafe {
             if libc::sysctl(
@@ -138,6 +218,14 @@ impl NetworksInner {
                                 old_errors_out,
                                 (*if2m).ifm_data.ifi_oerrors
                             );
+                            interface.refresh_times.update();
+                            interface.counters = get_counters(&(*if2m).ifm_data);
+                            interface.mtu = (*if2m).ifm_data.ifi_mtu as _;
+                            interface.link_speed = get_link_speed((*if2m).ifm_data.ifi_baudrate);
+                            interface
+                                .link_state
+                                .update(get_link_state((*if2m).ifm_flags));
+                            interface.kind = get_interface_kind((*if2m).ifm_data.ifi_type);
                             interface.updated = true;
                         }
                         hash_map::Entry::Vacant(e) => {
@@ -165,8 +253,21 @@ impl NetworksInner {
                                     old_errors_in: errors_in,
                                     errors_out,
                                     old_errors_out: errors_out,
+                                    refresh_times: RefreshTimes::new(),
+                                    handle_counter_wrap: self.handle_counter_wrap,
+                                    counters: get_counters(&(*if2m).ifm_data),
                                     updated: true,
                                     mac_addr: MacAddr::UNSPECIFIED,
+                                    ip_networks: Vec::new(),
+                                    mtu: (*if2m).ifm_data.ifi_mtu as _,
+                                    link_speed: get_link_speed((*if2m).ifm_data.ifi_baudrate),
+                                    duplex: DuplexMode::Unknown,
+                                    link_state: get_link_state((*if2m).ifm_flags),
+                                    kind: get_interface_kind((*if2m).ifm_data.ifi_type),
+                                    master: None,
+                                    lower_interfaces: Vec::new(),
+                                    #[cfg(feature = "wireless")]
+                                    wireless: None,
                                 },
                             });
                         }
@@ -191,30 +292,61 @@ pub(crate) struct NetworkDataInner {
     old_errors_in: u64,
     errors_out: u64,
     old_errors_out: u64,
+    refresh_times: RefreshTimes,
+    /// Whether the deltas of the counters take 32-bit wraps into account
+    handle_counter_wrap: bool,
+    counters: NetworkCounters,
     updated: bool,
     /// MAC address
     pub(crate) mac_addr: MacAddr,
+    /// IP networks
+    pub(crate) ip_networks: Vec<IpNetwork>,
+    /// Maximum Transmission Unit
+    mtu: u64,
+    /// Link speed, in bits per second
+    link_speed: Option<u64>,
+    duplex: DuplexMode,
+    link_state: LinkState,
+    kind: InterfaceKind,
+    /// Bond or bridge this interface is a member of
+    master: Option<String>,
+    /// Interfaces this interface sits on
+    lower_interfaces: Vec<String>,
+    #[cfg(feature = "wireless")]
+    wireless: Option<WirelessInfo>,
 }
 
 impl NetworkDataInner {
     pub(crate) fn received(&self) -> u64 {
-        self.current_in.saturating_sub(self.old_in)
+        counter_delta(self.old_in, self.current_in, self.handle_counter_wrap)
     }
 
     pub(crate) fn total_received(&self) -> u64 {
         self.current_in
     }
 
+    pub(crate) fn received_rate(&self) -> f64 {
+        self.refresh_times.rate(self.received())
+    }
+
     pub(crate) fn transmitted(&self) -> u64 {
-        self.current_out.saturating_sub(self.old_out)
+        counter_delta(self.old_out, self.current_out, self.handle_counter_wrap)
     }
 
     pub(crate) fn total_transmitted(&self) -> u64 {
         self.current_out
     }
 
+    pub(crate) fn transmitted_rate(&self) -> f64 {
+        self.refresh_times.rate(self.transmitted())
+    }
+
     pub(crate) fn packets_received(&self) -> u64 {
-        self.packets_in.saturating_sub(self.old_packets_in)
+        counter_delta(
+            self.old_packets_in,
+            self.packets_in,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_packets_received(&self) -> u64 {
@@ -222,7 +354,11 @@ impl NetworkDataInner {
     }
 
     pub(crate) fn packets_transmitted(&self) -> u64 {
-        self.packets_out.saturating_sub(self.old_packets_out)
+        counter_delta(
+            self.old_packets_out,
+            self.packets_out,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_packets_transmitted(&self) -> u64 {
@@ -230,7 +366,7 @@ impl NetworkDataInner {
     }
 
     pub(crate) fn errors_on_received(&self) -> u64 {
-        self.errors_in.saturating_sub(self.old_errors_in)
+        counter_delta(self.old_errors_in, self.errors_in, self.handle_counter_wrap)
     }
 
     pub(crate) fn total_errors_on_received(&self) -> u64 {
@@ -238,14 +374,79 @@ impl NetworkDataInner {
     }
 
     pub(crate) fn errors_on_transmitted(&self) -> u64 {
-        self.errors_out.saturating_sub(self.old_errors_out)
+        counter_delta(
+            self.old_errors_out,
+            self.errors_out,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_errors_on_transmitted(&self) -> u64 {
         self.errors_out
     }
 
+    pub(crate) fn detailed_counters(&self) -> NetworkCounters {
+        self.counters
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
+
+    pub(crate) fn ip_networks(&self) -> &[IpNetwork] {
+        &self.ip_networks
+    }
+
+    pub(crate) fn mtu(&self) -> u64 {
+        self.mtu
+    }
+
+    pub(crate) fn link_speed(&self) -> Option<u64> {
+        self.link_speed
+    }
+
+    pub(crate) fn duplex(&self) -> DuplexMode {
+        self.duplex
+    }
+
+    pub(crate) fn operational_state(&self) -> OperationalState {
+        self.link_state.operational_state
+    }
+
+    pub(crate) fn is_admin_up(&self) -> bool {
+        self.link_state.is_admin_up
+    }
+
+    pub(crate) fn has_carrier(&self) -> Option<bool> {
+        self.link_state.has_carrier
+    }
+
+    pub(crate) fn carrier_changes(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn last_state_change(&self) -> Option<u64> {
+        self.link_state.last_change
+    }
+
+    pub(crate) fn kind(&self) -> InterfaceKind {
+        self.kind
+    }
+
+    pub(crate) fn description(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn master(&self) -> Option<&str> {
+        self.master.as_deref()
+    }
+
+    pub(crate) fn lower_interfaces(&self) -> &[String] {
+        &self.lower_interfaces
+    }
+
+    #[cfg(feature = "wireless")]
+    pub(crate) fn wireless(&self) -> Option<&WirelessInfo> {
+        self.wireless.as_ref()
+    }
 }
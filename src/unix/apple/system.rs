@@ -3,15 +3,18 @@
 use crate::sys::cpu::*;
 #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
 use crate::sys::process::*;
-use crate::sys::utils::{get_sys_value, get_sys_value_by_name};
+use crate::sys::utils::{get_sys_value, get_sys_value_by_name, get_sysctl_u32};
 
-use crate::{Cpu, CpuRefreshKind, LoadAvg, Pid, Process, ProcessRefreshKind};
+use crate::{
+    CompressedMemory, Cpu, CpuActivity, CpuRefreshKind, Entropy, FileHandles, HardwareInfo,
+    KernelParameter, LoadAvg, MemoryBreakdown, MemoryPressureLevel, OsRelease, Pid, Process,
+    ProcessRefreshKind, SystemCounts, Virtualization, WindowsVersion,
+};
 
 use std::cell::UnsafeCell;
 use std::collections::HashMap;
 use std::mem;
-#[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use libc::{
     c_int, c_void, host_statistics64, mach_port_t, sysconf, sysctl, timeval, vm_statistics64,
@@ -24,6 +27,8 @@ pub(crate) struct SystemInner {
     mem_free: u64,
     mem_used: u64,
     mem_available: u64,
+    mem_breakdown: MemoryBreakdown,
+    compressed_memory: CompressedMemory,
     swap_total: u64,
     swap_free: u64,
     page_size_b: u64,
@@ -34,12 +39,22 @@ pub(crate) struct SystemInner {
     cpus: CpusWrapper,
 }
 
+// From `mach/machine.h`.
+const CPU_ARCH_MASK: u32 = 0xff00_0000;
+const CPU_ARCH_ABI64: u32 = 0x0100_0000;
+const CPU_TYPE_X86: u32 = 7;
+const CPU_TYPE_ARM: u32 = 12;
+
 pub(crate) struct Wrap<'a>(pub UnsafeCell<&'a mut HashMap<Pid, Process>>);
 
 unsafe impl<'a> Send for Wrap<'a> {}
 unsafe impl<'a> Sync for Wrap<'a> {}
 
 fn boot_time() -> u64 {
+    boot_timeval().map(|tv| tv.tv_sec as _).unwrap_or(0)
+}
+
+fn boot_timeval() -> Option<timeval> {
     let mut boot_time = timeval {
         tv_sec: 0,
         tv_usec: 0,
@@ -57,9 +72,9 @@ fn boot_time() -> u64 {
             0,
         ) < 0
         {
-            0
+            None
         } else {
-            boot_time.tv_sec as _
+            Some(boot_time)
         }
     }
 }
@@ -72,34 +87,21 @@ fn get_now() -> u64 {
         .unwrap_or(0)
 }
 
-impl SystemInner {
-    pub(crate) fn new() -> Self {
-        unsafe {
-            let port = libc::mach_host_self();
-
-            Self {
-                process_list: HashMap::with_capacity(200),
-                mem_total: 0,
-                mem_free: 0,
-                mem_available: 0,
-                mem_used: 0,
-                swap_total: 0,
-                swap_free: 0,
-                page_size_b: sysconf(_SC_PAGESIZE) as _,
-                port,
-                boot_time: boot_time(),
-                #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
-                clock_info: crate::sys::macos::system::SystemTimeInfo::new(port),
-                cpus: CpusWrapper::new(),
-            }
-        }
-    }
+/// Reads the memory information without needing the `SystemInner`, so it can be done on another
+/// thread. The result is applied with `SystemInner::apply_memory`.
+pub(crate) struct MemoryReader {
+    port: mach_port_t,
+    read_total: bool,
+    swap: Option<libc::xsw_usage>,
+    mem_total: Option<u64>,
+    stat: Option<vm_statistics64>,
+}
 
-    pub(crate) fn refresh_memory(&mut self) {
+impl MemoryReader {
+    pub(crate) fn read(&mut self) {
         let mut mib = [libc::CTL_VM as _, libc::VM_SWAPUSAGE as _];
 
         unsafe {
-            // get system values
             // get swap info
             let mut xs: libc::xsw_usage = mem::zeroed::<libc::xsw_usage>();
             if get_sys_value(
@@ -107,53 +109,129 @@ impl SystemInner {
                 &mut xs as *mut _ as *mut c_void,
                 &mut mib,
             ) {
-                self.swap_total = xs.xsu_total;
-                self.swap_free = xs.xsu_avail;
+                self.swap = Some(xs);
             }
             mib[0] = libc::CTL_HW as _;
             mib[1] = libc::HW_MEMSIZE as _;
             // get ram info
-            if self.mem_total < 1 {
-                get_sys_value(
+            if self.read_total {
+                let mut mem_total = 0u64;
+                if get_sys_value(
                     mem::size_of::<u64>(),
-                    &mut self.mem_total as *mut u64 as *mut c_void,
+                    &mut mem_total as *mut u64 as *mut c_void,
                     &mut mib,
-                );
+                ) {
+                    self.mem_total = Some(mem_total);
+                }
             }
             let mut count: u32 = libc::HOST_VM_INFO64_COUNT as _;
             let mut stat = mem::zeroed::<vm_statistics64>();
-            if host_statistics64(
+            let ret = host_statistics64(
                 self.port,
                 libc::HOST_VM_INFO64,
                 &mut stat as *mut vm_statistics64 as *mut _,
                 &mut count,
-            ) == libc::KERN_SUCCESS
-            {
-                // From the apple documentation:
-                //
-                // /*
-                //  * NB: speculative pages are already accounted for in "free_count",
-                //  * so "speculative_count" is the number of "free" pages that are
-                //  * used to hold data that was read speculatively from disk but
-                //  * haven't actually been used by anyone so far.
-                //  */
-                self.mem_available = u64::from(stat.free_count)
-                    .saturating_add(u64::from(stat.inactive_count))
-                    .saturating_add(u64::from(stat.purgeable_count))
-                    .saturating_sub(u64::from(stat.compressor_page_count))
-                    .saturating_mul(self.page_size_b);
-                self.mem_used = u64::from(stat.active_count)
-                    .saturating_add(u64::from(stat.wire_count))
-                    .saturating_add(u64::from(stat.compressor_page_count))
-                    .saturating_add(u64::from(stat.speculative_count))
-                    .saturating_mul(self.page_size_b);
-                self.mem_free = u64::from(stat.free_count)
-                    .saturating_sub(u64::from(stat.speculative_count))
-                    .saturating_mul(self.page_size_b);
+            );
+            if ret == libc::KERN_SUCCESS {
+                self.stat = Some(stat);
+            } else {
+                crate::utils::report_error(
+                    crate::RefreshErrorKind::Unavailable,
+                    "memory",
+                    format_args!("host_statistics64 failed: {ret}"),
+                );
+            }
+        }
+    }
+}
+
+impl SystemInner {
+    pub(crate) fn new() -> Self {
+        unsafe {
+            let port = libc::mach_host_self();
+
+            Self {
+                process_list: HashMap::with_capacity(200),
+                mem_total: 0,
+                mem_free: 0,
+                mem_available: 0,
+                mem_used: 0,
+                mem_breakdown: MemoryBreakdown::default(),
+                compressed_memory: CompressedMemory::default(),
+                swap_total: 0,
+                swap_free: 0,
+                page_size_b: sysconf(_SC_PAGESIZE) as _,
+                port,
+                boot_time: boot_time(),
+                #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+                clock_info: crate::sys::macos::system::SystemTimeInfo::new(port),
+                cpus: CpusWrapper::new(),
             }
         }
     }
 
+    pub(crate) fn set_string_encoding_policy(&mut self, _policy: crate::StringEncodingPolicy) {}
+
+    pub(crate) fn set_keep_open_files(&mut self, _enabled: bool) {}
+
+    pub(crate) fn refresh_memory(&mut self) {
+        let mut reader = self.memory_reader();
+        reader.read();
+        self.apply_memory(reader);
+    }
+
+    pub(crate) fn memory_reader(&mut self) -> MemoryReader {
+        MemoryReader {
+            port: self.port,
+            read_total: self.mem_total < 1,
+            swap: None,
+            mem_total: None,
+            stat: None,
+        }
+    }
+
+    pub(crate) fn apply_memory(&mut self, reader: MemoryReader) {
+        if let Some(xs) = reader.swap {
+            self.swap_total = xs.xsu_total;
+            self.swap_free = xs.xsu_avail;
+        }
+        if let Some(mem_total) = reader.mem_total {
+            self.mem_total = mem_total;
+        }
+        if let Some(stat) = reader.stat {
+            // From the apple documentation:
+            //
+            // /*
+            //  * NB: speculative pages are already accounted for in "free_count",
+            //  * so "speculative_count" is the number of "free" pages that are
+            //  * used to hold data that was read speculatively from disk but
+            //  * haven't actually been used by anyone so far.
+            //  */
+            self.mem_available = u64::from(stat.free_count)
+                .saturating_add(u64::from(stat.inactive_count))
+                .saturating_add(u64::from(stat.purgeable_count))
+                .saturating_sub(u64::from(stat.compressor_page_count))
+                .saturating_mul(self.page_size_b);
+            self.mem_used = u64::from(stat.active_count)
+                .saturating_add(u64::from(stat.wire_count))
+                .saturating_add(u64::from(stat.compressor_page_count))
+                .saturating_add(u64::from(stat.speculative_count))
+                .saturating_mul(self.page_size_b);
+            self.mem_free = u64::from(stat.free_count)
+                .saturating_sub(u64::from(stat.speculative_count))
+                .saturating_mul(self.page_size_b);
+            // File-backed pages are the closest thing to a page cache.
+            self.mem_breakdown.cached =
+                u64::from(stat.external_page_count).saturating_mul(self.page_size_b);
+            self.compressed_memory = CompressedMemory {
+                uncompressed: stat
+                    .total_uncompressed_pages_in_compressor
+                    .saturating_mul(self.page_size_b),
+                compressed: u64::from(stat.compressor_page_count).saturating_mul(self.page_size_b),
+            };
+        }
+    }
+
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
         None
     }
@@ -162,6 +240,10 @@ impl SystemInner {
         self.cpus.refresh(refresh_kind, self.port);
     }
 
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        self.cpus.refresh_global_cpu_usage(self.port);
+    }
+
     #[cfg(any(target_os = "ios", feature = "apple-sandbox"))]
     pub(crate) fn refresh_processes_specifics(&mut self, _refresh_kind: ProcessRefreshKind) {}
 
@@ -172,6 +254,11 @@ impl SystemInner {
         unsafe {
             let count = libc::proc_listallpids(::std::ptr::null_mut(), 0);
             if count < 1 {
+                crate::utils::report_error(
+                    crate::RefreshErrorKind::Unavailable,
+                    "processes",
+                    std::io::Error::last_os_error(),
+                );
                 return;
             }
         }
@@ -179,6 +266,7 @@ impl SystemInner {
             let now = get_now();
             let port = self.port;
             let time_interval = self.clock_info.as_mut().map(|c| c.get_time_interval(port));
+            let nb_cpus = get_nb_cpus();
             let entries: Vec<Process> = {
                 let wrap = &Wrap(UnsafeCell::new(&mut self.process_list));
 
@@ -187,7 +275,15 @@ impl SystemInner {
 
                 into_iter(pids)
                     .flat_map(|pid| {
-                        match update_process(wrap, pid, time_interval, now, refresh_kind, false) {
+                        match update_process(
+                            wrap,
+                            pid,
+                            time_interval,
+                            nb_cpus,
+                            now,
+                            refresh_kind,
+                            false,
+                        ) {
                             Ok(x) => x,
                             _ => None,
                         }
@@ -199,6 +295,12 @@ impl SystemInner {
             });
             self.process_list
                 .retain(|_, proc_| std::mem::replace(&mut proc_.inner.updated, false));
+        } else {
+            crate::utils::report_error(
+                crate::RefreshErrorKind::Other,
+                "processes",
+                "proc_listallpids failed",
+            );
         }
     }
 
@@ -226,7 +328,15 @@ impl SystemInner {
         }
         match {
             let wrap = Wrap(UnsafeCell::new(&mut self.process_list));
-            update_process(&wrap, pid, time_interval, now, refresh_kind, true)
+            update_process(
+                &wrap,
+                pid,
+                time_interval,
+                get_nb_cpus(),
+                now,
+                refresh_kind,
+                true,
+            )
         } {
             Ok(Some(p)) => {
                 self.process_list.insert(p.pid(), p);
@@ -253,14 +363,47 @@ impl SystemInner {
         &self.cpus.global_cpu
     }
 
+    #[cfg(feature = "energy")]
+    pub(crate) fn refresh_energy(&mut self) {}
+
+    #[cfg(feature = "energy")]
+    pub(crate) fn energy_domains(&self) -> &[crate::EnergyDomain] {
+        &[]
+    }
+
+    pub(crate) fn cpu_activity(&self) -> CpuActivity {
+        // There is no public API to get these values.
+        CpuActivity::default()
+    }
+
     pub(crate) fn cpus(&self) -> &[Cpu] {
         &self.cpus.cpus
     }
 
+    pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
+        &mut self.process_list
+    }
+
+    pub(crate) fn global_cpu_info_mut(&mut self) -> &mut Cpu {
+        &mut self.cpus.global_cpu
+    }
+
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus.cpus
+    }
+
     pub(crate) fn physical_core_count(&self) -> Option<usize> {
         physical_core_count()
     }
 
+    pub(crate) fn socket_count(&self) -> Option<usize> {
+        socket_count()
+    }
+
+    pub(crate) fn cpu_features(&self) -> Vec<String> {
+        crate::utils::get_cpu_features()
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -273,10 +416,61 @@ impl SystemInner {
         self.mem_available
     }
 
+    pub(crate) fn available_memory_breakdown(&self) -> Option<crate::AvailableMemory> {
+        Some(crate::AvailableMemory {
+            free: self.mem_free,
+            reclaimable: self.mem_available.saturating_sub(self.mem_free),
+            available: self.mem_available,
+            source: crate::AvailableMemorySource::Computed,
+        })
+    }
+
     pub(crate) fn used_memory(&self) -> u64 {
         self.mem_used
     }
 
+    pub(crate) fn memory_breakdown(&self) -> MemoryBreakdown {
+        self.mem_breakdown
+    }
+
+    pub(crate) fn commit_charge(&self) -> crate::CommitCharge {
+        crate::CommitCharge::default()
+    }
+
+    pub(crate) fn compressed_memory(&self) -> CompressedMemory {
+        self.compressed_memory
+    }
+
+    pub(crate) fn memory_pressure_level(&self) -> Option<MemoryPressureLevel> {
+        // Values of the `kVMPressure*` constants from `sys/event.h`.
+        match get_sysctl_u32(b"kern.memorystatus_vm_pressure_level\0")? {
+            1 => Some(MemoryPressureLevel::Normal),
+            2 => Some(MemoryPressureLevel::Warning),
+            4 => Some(MemoryPressureLevel::Critical),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn zram_devices(&self) -> &[crate::ZramDevice] {
+        &[]
+    }
+
+    pub(crate) fn ksm(&self) -> Option<crate::Ksm> {
+        None
+    }
+
+    pub(crate) fn balloon_memory(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn oom_kills(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn memory_modules(&self) -> Vec<crate::MemoryModule> {
+        Vec::new()
+    }
+
     pub(crate) fn total_swap(&self) -> u64 {
         self.swap_total
     }
@@ -298,6 +492,10 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn pressure(&self) -> Option<crate::Pressure> {
+        None
+    }
+
     pub(crate) fn load_average(&self) -> LoadAvg {
         let mut loads = vec![0f64; 3];
 
@@ -315,6 +513,26 @@ impl SystemInner {
         self.boot_time
     }
 
+    pub(crate) fn uptime_duration(&self) -> Duration {
+        // Unlike `CLOCK_UPTIME_RAW`, it is based on `mach_continuous_time` which keeps running
+        // while the system is asleep.
+        unsafe {
+            let mut up: libc::timespec = mem::zeroed();
+            if libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut up) != 0 {
+                sysinfo_debug!("clock_gettime failed: uptime cannot be retrieved...");
+                return Duration::default();
+            }
+            Duration::new(up.tv_sec as _, up.tv_nsec as _)
+        }
+    }
+
+    pub(crate) fn boot_time_sys(&self) -> SystemTime {
+        // The kernel adjusts it when the clock is set, and it includes the time spent asleep.
+        boot_timeval()
+            .map(|tv| UNIX_EPOCH + Duration::new(tv.tv_sec as _, tv.tv_usec as u32 * 1_000))
+            .unwrap_or(UNIX_EPOCH)
+    }
+
     pub(crate) fn name(&self) -> Option<String> {
         get_system_info(libc::KERN_OSTYPE, Some("Darwin"))
     }
@@ -366,6 +584,104 @@ impl SystemInner {
         get_system_info(libc::KERN_HOSTNAME, None)
     }
 
+    pub(crate) fn counts(&self) -> SystemCounts {
+        #[cfg(target_os = "macos")]
+        let processes = match unsafe { libc::proc_listallpids(std::ptr::null_mut(), 0) } {
+            count if count > 0 => Some(count as u64),
+            _ => None,
+        };
+        #[cfg(not(target_os = "macos"))]
+        let processes = None;
+
+        SystemCounts {
+            processes,
+            // The kernel only exposes the maximum number of threads.
+            threads: None,
+            handles: get_sysctl_u32(b"kern.num_files\0").map(u64::from),
+        }
+    }
+
+    pub(crate) fn file_handles(&self) -> Option<FileHandles> {
+        Some(FileHandles {
+            allocated: u64::from(get_sysctl_u32(b"kern.num_files\0")?),
+            limit: get_sysctl_u32(b"kern.maxfiles\0").map(u64::from),
+        })
+    }
+
+    pub(crate) fn entropy(&self) -> Option<Entropy> {
+        // The generator is seeded by the boot loader, before any process runs.
+        Some(Entropy {
+            ready: true,
+            available: None,
+            pool_size: None,
+        })
+    }
+
+    pub(crate) fn fqdn(&self) -> Option<String> {
+        crate::unix::utils::get_fqdn(&self.host_name()?)
+    }
+
+    pub(crate) fn domain_name(&self) -> Option<String> {
+        let fqdn = self.fqdn()?;
+        let (_, domain) = fqdn.split_once('.')?;
+        Some(domain.to_owned()).filter(|domain| !domain.is_empty())
+    }
+
+    pub(crate) fn workgroup(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn hardware_info(&self) -> HardwareInfo {
+        let mut info = crate::sys::inner::hardware::get_hardware_info();
+        crate::utils::set_virtual_chassis(&mut info);
+        info
+    }
+
+    pub(crate) fn virtualization(&self) -> Virtualization {
+        Virtualization {
+            hypervisor: crate::utils::get_hypervisor(&self.hardware_info()),
+            container: None,
+        }
+    }
+
+    pub(crate) fn machine_id(&self) -> Option<String> {
+        crate::sys::inner::hardware::get_machine_id()
+    }
+
+    pub(crate) fn kernel_arch(&self) -> Option<String> {
+        // Processes translated by Rosetta see the emulated architecture.
+        if get_sysctl_u32(b"sysctl.proc_translated\0") == Some(1) {
+            return Some("aarch64".to_owned());
+        }
+        let cpu_type = get_sysctl_u32(b"hw.cputype\0")?;
+        let is_64 = cpu_type & CPU_ARCH_ABI64 != 0;
+        let arch = match (cpu_type & !CPU_ARCH_MASK, is_64) {
+            (CPU_TYPE_X86, false) => "x86",
+            (CPU_TYPE_X86, true) => "x86_64",
+            (CPU_TYPE_ARM, false) => "arm",
+            (CPU_TYPE_ARM, true) => "aarch64",
+            _ => return crate::unix::utils::get_uname_machine(),
+        };
+        Some(arch.to_owned())
+    }
+
+    pub(crate) fn userland_arch(&self) -> Option<String> {
+        // There is no 32-bit userland anymore.
+        self.kernel_arch()
+    }
+
+    pub(crate) fn os_release(&self) -> Option<OsRelease> {
+        None
+    }
+
+    pub(crate) fn windows_version(&self) -> Option<WindowsVersion> {
+        None
+    }
+
+    pub(crate) fn kernel_parameter(&self, name: &str) -> Option<KernelParameter> {
+        crate::sys::get_kernel_parameter(name)
+    }
+
     pub(crate) fn kernel_version(&self) -> Option<String> {
         get_system_info(libc::KERN_OSRELEASE, None)
     }
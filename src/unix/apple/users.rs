@@ -2,7 +2,7 @@
 
 use crate::{
     common::{Gid, Uid},
-    User, UserInner,
+    User, UserInner, UserRefreshKind,
 };
 
 use libc::{c_char, endpwent, getpwent, setpwent, strlen};
@@ -23,7 +23,7 @@ fn endswith(s1: *const c_char, s2: &[u8]) -> bool {
     }
 }
 
-pub(crate) fn get_users(users: &mut Vec<User>) {
+pub(crate) fn get_users(users: &mut Vec<User>, refresh_kind: UserRefreshKind) {
     fn filter(shell: *const c_char, uid: u32) -> bool {
         !endswith(shell, b"/false") && !endswith(shell, b"/uucico") && uid < 65536
     }
@@ -55,14 +55,23 @@ pub(crate) fn get_users(users: &mut Vec<User>) {
 
                 let uid = (*pw).pw_uid;
                 let gid = (*pw).pw_gid;
-                users_map.insert(name, (Uid(uid), Gid(gid)));
+                let (home_dir, shell) = if refresh_kind.home_dir() {
+                    (
+                        crate::unix::utils::cstr_to_rust((*pw).pw_dir).unwrap_or_default(),
+                        crate::unix::utils::cstr_to_rust((*pw).pw_shell).unwrap_or_default(),
+                    )
+                } else {
+                    (String::new(), String::new())
+                };
+                users_map.insert(name, (Uid(uid), Gid(gid), home_dir, shell));
             }
         }
         endpwent();
     }
-    for (name, (uid, gid)) in users_map {
+    for (name, (uid, gid, home_dir, shell)) in users_map {
         users.push(User {
-            inner: UserInner::new(uid, gid, name),
+            inner: UserInner::new(uid, gid, name, &home_dir, &shell),
+            groups: None,
         });
     }
 }
@@ -68,3 +68,19 @@ pub(crate) unsafe fn get_sys_value_by_name(
         0,
     ) == 0
 }
+
+pub(crate) fn get_sysctl_u32(name: &[u8]) -> Option<u32> {
+    let mut value: u32 = 0;
+
+    unsafe {
+        if get_sys_value_by_name(
+            name,
+            &mut std::mem::size_of::<u32>(),
+            &mut value as *mut u32 as *mut c_void,
+        ) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
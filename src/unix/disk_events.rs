@@ -0,0 +1,58 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::ptr;
+use std::time::Duration;
+
+pub(crate) struct DiskEventsInner {
+    // Receives the `EVFILT_FS` events, sent when a file system is mounted or unmounted.
+    kq: libc::c_int,
+}
+
+impl DiskEventsInner {
+    pub(crate) fn new() -> Option<Self> {
+        unsafe {
+            let kq = libc::kqueue();
+            if kq < 0 {
+                sysinfo_debug!("kqueue failed");
+                return None;
+            }
+            let events = Self { kq };
+            let mut change: libc::kevent = std::mem::zeroed();
+            change.filter = libc::EVFILT_FS;
+            change.flags = libc::EV_ADD | libc::EV_CLEAR;
+            if libc::kevent(kq, &change, 1, ptr::null_mut(), 0, ptr::null()) < 0 {
+                sysinfo_debug!("failed to register the EVFILT_FS filter");
+                return None;
+            }
+            Some(events)
+        }
+    }
+
+    pub(crate) fn wait(&mut self, timeout: Option<Duration>) -> bool {
+        let timeout = timeout.map(|timeout| libc::timespec {
+            tv_sec: timeout.as_secs() as _,
+            tv_nsec: timeout.subsec_nanos() as _,
+        });
+        unsafe {
+            let mut event: libc::kevent = std::mem::zeroed();
+            libc::kevent(
+                self.kq,
+                ptr::null(),
+                0,
+                &mut event,
+                1,
+                timeout
+                    .as_ref()
+                    .map_or(ptr::null(), |timeout| timeout as *const _),
+            ) > 0
+        }
+    }
+}
+
+impl Drop for DiskEventsInner {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.kq);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use super::utils::get_sys_value_by_name;
+use crate::{Battery, BatteryState};
+
+use libc::c_int;
+
+use std::time::Duration;
+
+// Values from `dev/acpica/acpiio.h`.
+const ACPI_BATT_STAT_DISCHARG: c_int = 0x0001;
+const ACPI_BATT_STAT_CHARGING: c_int = 0x0002;
+const ACPI_BATT_STAT_NOT_PRESENT: c_int = 0x0007;
+
+// The `hw.acpi.battery` sysctls combine all the batteries. The capacities are only available
+// per battery through the `ACPIIO_BATT_GET_BIX` ioctl on `/dev/acpi`, which requires to be root.
+pub(crate) fn get_batteries(batteries: &mut Vec<Battery>) {
+    let mut units: c_int = 0;
+    let mut life: c_int = 0;
+    let mut state: c_int = 0;
+    let mut time: c_int = -1;
+    unsafe {
+        if !get_sys_value_by_name(b"hw.acpi.battery.units\0", &mut units)
+            || units == 0
+            || !get_sys_value_by_name(b"hw.acpi.battery.life\0", &mut life)
+            || !get_sys_value_by_name(b"hw.acpi.battery.state\0", &mut state)
+            || state == ACPI_BATT_STAT_NOT_PRESENT
+        {
+            return;
+        }
+        get_sys_value_by_name(b"hw.acpi.battery.time\0", &mut time);
+    }
+    let state = if state & ACPI_BATT_STAT_CHARGING != 0 {
+        BatteryState::Charging
+    } else if state & ACPI_BATT_STAT_DISCHARG != 0 {
+        BatteryState::Discharging
+    } else if life >= 100 {
+        BatteryState::Full
+    } else {
+        BatteryState::NotCharging
+    };
+    batteries.push(Battery {
+        name: "Battery".to_owned(),
+        state,
+        charge: life as f32,
+        energy: None,
+        full_capacity: None,
+        design_capacity: None,
+        cycle_count: None,
+        // In minutes, `-1` if unknown.
+        time_to_empty: if state == BatteryState::Discharging && time >= 0 {
+            Some(Duration::from_secs(time as u64 * 60))
+        } else {
+            None
+        },
+        time_to_full: None,
+    });
+}
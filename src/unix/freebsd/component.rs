@@ -1,12 +1,14 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use super::utils::get_sys_value_by_name;
-use crate::Component;
+use crate::{Component, ComponentKind};
 
 pub(crate) struct ComponentInner {
     id: Vec<u8>,
     label: String,
     temperature: f32,
+    /// The temperature as read from the sysctl, in tenth of degree Kelvin.
+    raw_value: i64,
     max: f32,
 }
 
@@ -23,14 +25,37 @@ impl ComponentInner {
         None
     }
 
+    pub(crate) fn lower_limit(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn upper_limit(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn raw_value(&self) -> Option<i64> {
+        Some(self.raw_value)
+    }
+
     pub(crate) fn label(&self) -> &str {
         &self.label
     }
 
+    pub(crate) fn id(&self) -> &str {
+        // The sysctl name, without its trailing NUL byte.
+        std::str::from_utf8(&self.id[..self.id.len() - 1]).unwrap_or("")
+    }
+
+    // Only the CPU temperatures are listed.
+    pub(crate) fn kind(&self) -> ComponentKind {
+        ComponentKind::Cpu
+    }
+
     pub(crate) fn refresh(&mut self) {
         unsafe {
-            if let Some(temperature) = refresh_component(&self.id) {
-                self.temperature = temperature;
+            if let Some(raw_value) = read_component(&self.id) {
+                self.raw_value = i64::from(raw_value);
+                self.temperature = to_celsius(raw_value);
                 if self.temperature > self.max {
                     self.max = self.temperature;
                 }
@@ -39,16 +64,26 @@ impl ComponentInner {
     }
 }
 
-unsafe fn refresh_component(id: &[u8]) -> Option<f32> {
+/// Returns the temperature of the sysctl `id`, in tenth of degree Kelvin.
+unsafe fn read_component(id: &[u8]) -> Option<libc::c_int> {
     let mut temperature: libc::c_int = 0;
     if !get_sys_value_by_name(id, &mut temperature) {
         None
     } else {
-        // convert from Kelvin (x 10 -> 273.2 x 10) to Celsius
-        Some((temperature - 2732) as f32 / 10.)
+        Some(temperature)
     }
 }
 
+fn to_celsius(temperature: libc::c_int) -> f32 {
+    // convert from Kelvin (x 10 -> 273.2 x 10) to Celsius
+    (temperature - 2732) as f32 / 10.
+}
+
+/// Returns the temperature of the given core, provided by the `coretemp` or `amdtemp` driver.
+pub(crate) fn get_cpu_temperature(core: usize) -> Option<f32> {
+    unsafe { read_component(format!("dev.cpu.{core}.temperature\0").as_bytes()).map(to_celsius) }
+}
+
 pub(crate) struct ComponentsInner {
     nb_cpus: usize,
     components: Vec<Component>,
@@ -87,12 +122,14 @@ impl ComponentsInner {
         for core in 0..self.nb_cpus {
             unsafe {
                 let id = format!("dev.cpu.{core}.temperature\0").as_bytes().to_vec();
-                if let Some(temperature) = refresh_component(&id) {
+                if let Some(raw_value) = read_component(&id) {
+                    let temperature = to_celsius(raw_value);
                     self.components.push(Component {
                         inner: ComponentInner {
                             id,
                             label: format!("CPU {}", core + 1),
                             temperature,
+                            raw_value: i64::from(raw_value),
                             max: temperature,
                         },
                     });
@@ -101,3 +138,15 @@ impl ComponentsInner {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::to_celsius;
+
+    #[test]
+    fn check_to_celsius() {
+        assert_eq!(to_celsius(2732), 0.);
+        assert_eq!(to_celsius(3182), 45.);
+        assert_eq!(to_celsius(2632), -10.);
+    }
+}
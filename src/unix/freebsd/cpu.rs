@@ -1,11 +1,14 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::sys::component::get_cpu_temperature;
 use crate::sys::utils::{
     get_sys_value_array, get_sys_value_by_name, get_sys_value_str_by_name, init_mib, VecSwitcher,
 };
-use crate::{Cpu, CpuRefreshKind};
+use crate::{Cpu, CpuActivity, CpuRefreshKind, CpuTimes};
 
-use libc::{c_int, c_ulong};
+use libc::{c_int, c_uint, c_ulong};
+
+use std::time::{Duration, Instant};
 
 pub(crate) unsafe fn get_nb_cpus() -> usize {
     let mut smp: c_int = 0;
@@ -26,7 +29,6 @@ pub(crate) unsafe fn get_nb_cpus() -> usize {
 pub(crate) struct CpusWrapper {
     pub(crate) global_cpu: Cpu,
     pub(crate) cpus: Vec<Cpu>,
-    got_cpu_frequency: bool,
     mib_cp_time: [c_int; 2],
     mib_cp_times: [c_int; 2],
     // For the global CPU usage.
@@ -34,6 +36,11 @@ pub(crate) struct CpusWrapper {
     // For each CPU usage.
     cp_times: VecSwitcher<c_ulong>,
     nb_cpus: usize,
+    // Frequency of the statistics clock, used to convert the ticks into durations.
+    stathz: u64,
+    activity: CpuActivity,
+    // Last time `activity` was updated, used to compute the rates.
+    last_activity_update: Option<Instant>,
 }
 
 impl CpusWrapper {
@@ -50,12 +57,14 @@ impl CpusWrapper {
                     inner: CpuInner::new(String::new(), String::new(), 0),
                 },
                 cpus: Vec::with_capacity(nb_cpus),
-                got_cpu_frequency: false,
                 mib_cp_time,
                 mib_cp_times,
                 cp_time: VecSwitcher::new(vec![0; libc::CPUSTATES as usize]),
                 cp_times: VecSwitcher::new(vec![0; nb_cpus * libc::CPUSTATES as usize]),
                 nb_cpus,
+                stathz: get_stathz(),
+                activity: CpuActivity::default(),
+                last_activity_update: None,
             }
         }
     }
@@ -67,79 +76,143 @@ impl CpusWrapper {
             // We get the CPU vendor ID in here.
             let vendor_id =
                 get_sys_value_str_by_name(b"hw.model\0").unwrap_or_else(|| "<unknown>".to_owned());
+            let signature = get_cpu_signature();
             for pos in 0..self.nb_cpus {
                 if refresh_kind.frequency() {
                     unsafe {
                         frequency = get_frequency_for_cpu(pos);
                     }
                 }
-                self.cpus.push(Cpu {
-                    inner: CpuInner::new(format!("cpu {pos}"), vendor_id.clone(), frequency),
-                });
+                let mut inner = CpuInner::new(format!("cpu {pos}"), vendor_id.clone(), frequency);
+                inner.signature = signature;
+                self.cpus.push(Cpu { inner });
             }
-            self.got_cpu_frequency = refresh_kind.frequency();
-        } else if refresh_kind.frequency() && !self.got_cpu_frequency {
+        } else if refresh_kind.frequency() {
             for (pos, proc_) in self.cpus.iter_mut().enumerate() {
                 unsafe {
                     proc_.inner.frequency = get_frequency_for_cpu(pos);
                 }
             }
-            self.got_cpu_frequency = true;
+        }
+        if refresh_kind.temperature() {
+            for (pos, proc_) in self.cpus.iter_mut().enumerate() {
+                proc_.inner.temperature = get_cpu_temperature(pos);
+            }
         }
         if refresh_kind.cpu_usage() {
             self.get_cpu_usage();
+            self.get_activity();
         }
     }
 
-    fn get_cpu_usage(&mut self) {
+    fn get_activity(&mut self) {
+        let get_counter = |name: &[u8]| {
+            let mut value: c_uint = 0;
+            unsafe {
+                if !get_sys_value_by_name(name, &mut value) {
+                    sysinfo_debug!("failed to get {:?}", name);
+                }
+            }
+            value as u64
+        };
+        let now = Instant::now();
+        self.activity.update(
+            get_counter(b"vm.stats.sys.v_intr\0"),
+            get_counter(b"vm.stats.sys.v_soft\0"),
+            get_counter(b"vm.stats.sys.v_swtch\0"),
+            self.last_activity_update
+                .map(|last_update| now.duration_since(last_update)),
+        );
+        self.last_activity_update = Some(now);
+    }
+
+    pub(crate) fn activity(&self) -> CpuActivity {
+        self.activity
+    }
+
+    /// Only reads `kern.cp_time` to update the global CPU usage.
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
         unsafe {
             get_sys_value_array(&self.mib_cp_time, self.cp_time.get_mut());
-            get_sys_value_array(&self.mib_cp_times, self.cp_times.get_mut());
         }
+        fill_cpu(
+            &mut self.global_cpu,
+            self.cp_time.get_new(),
+            self.cp_time.get_old(),
+            self.stathz,
+        );
+    }
 
-        fn fill_cpu(proc_: &mut Cpu, new_cp_time: &[c_ulong], old_cp_time: &[c_ulong]) {
-            let mut total_new: u64 = 0;
-            let mut total_old: u64 = 0;
-            let mut cp_diff: c_ulong = 0;
-
-            for i in 0..(libc::CPUSTATES as usize) {
-                // We obviously don't want to get the idle part of the CPU usage, otherwise
-                // we would always be at 100%...
-                if i != libc::CP_IDLE as usize {
-                    cp_diff = cp_diff.saturating_add(new_cp_time[i].saturating_sub(old_cp_time[i]));
-                }
-                total_new = total_new.saturating_add(new_cp_time[i] as _);
-                total_old = total_old.saturating_add(old_cp_time[i] as _);
-            }
-
-            let total_diff = total_new.saturating_sub(total_old);
-            if total_diff < 1 {
-                proc_.inner.cpu_usage = 0.;
-            } else {
-                proc_.inner.cpu_usage = cp_diff as f32 / total_diff as f32 * 100.;
-            }
+    fn get_cpu_usage(&mut self) {
+        unsafe {
+            get_sys_value_array(&self.mib_cp_time, self.cp_time.get_mut());
+            get_sys_value_array(&self.mib_cp_times, self.cp_times.get_mut());
         }
 
         fill_cpu(
             &mut self.global_cpu,
             self.cp_time.get_new(),
             self.cp_time.get_old(),
+            self.stathz,
         );
         let old_cp_times = self.cp_times.get_old();
         let new_cp_times = self.cp_times.get_new();
         for (pos, proc_) in self.cpus.iter_mut().enumerate() {
             let index = pos * libc::CPUSTATES as usize;
 
-            fill_cpu(proc_, &new_cp_times[index..], &old_cp_times[index..]);
+            fill_cpu(
+                proc_,
+                &new_cp_times[index..],
+                &old_cp_times[index..],
+                self.stathz,
+            );
         }
     }
 }
 
+fn fill_cpu(proc_: &mut Cpu, new_cp_time: &[c_ulong], old_cp_time: &[c_ulong], stathz: u64) {
+    let to_duration =
+        |state: c_int| Duration::from_millis(new_cp_time[state as usize] as u64 * 1_000 / stathz);
+    proc_.inner.times = CpuTimes {
+        user: to_duration(libc::CP_USER),
+        nice: to_duration(libc::CP_NICE),
+        system: to_duration(libc::CP_SYS),
+        irq: to_duration(libc::CP_INTR),
+        idle: to_duration(libc::CP_IDLE),
+        ..Default::default()
+    };
+
+    let mut total_new: u64 = 0;
+    let mut total_old: u64 = 0;
+    let mut cp_diff: c_ulong = 0;
+
+    for i in 0..(libc::CPUSTATES as usize) {
+        // We obviously don't want to get the idle part of the CPU usage, otherwise
+        // we would always be at 100%...
+        if i != libc::CP_IDLE as usize {
+            cp_diff = cp_diff.saturating_add(new_cp_time[i].saturating_sub(old_cp_time[i]));
+        }
+        total_new = total_new.saturating_add(new_cp_time[i] as _);
+        total_old = total_old.saturating_add(old_cp_time[i] as _);
+    }
+
+    let total_diff = total_new.saturating_sub(total_old);
+    if total_diff < 1 {
+        proc_.inner.cpu_usage = 0.;
+    } else {
+        proc_.inner.cpu_usage = cp_diff as f32 / total_diff as f32 * 100.;
+    }
+}
+
 pub(crate) struct CpuInner {
     pub(crate) cpu_usage: f32,
     name: String,
     pub(crate) vendor_id: String,
     pub(crate) frequency: u64,
+    pub(crate) temperature: Option<f32>,
+    pub(crate) times: CpuTimes,
+    // Family, model and stepping.
+    signature: Option<(u32, u32, u32)>,
 }
 
 impl CpuInner {
@@ -149,6 +222,9 @@ impl CpuInner {
             name,
             vendor_id,
             frequency,
+            temperature: None,
+            times: CpuTimes::default(),
+            signature: None,
         }
     }
 
@@ -156,6 +232,15 @@ impl CpuInner {
         self.cpu_usage
     }
 
+    pub(crate) fn set_cpu_usage(&mut self, cpu_usage: f32) {
+        self.cpu_usage = cpu_usage;
+    }
+
+    pub(crate) fn steal_percent(&self) -> f32 {
+        // Steal time is only reported by Linux guests.
+        0.0
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
@@ -164,6 +249,39 @@ impl CpuInner {
         self.frequency
     }
 
+    pub(crate) fn temperature(&self) -> Option<f32> {
+        self.temperature
+    }
+
+    pub(crate) fn times(&self) -> CpuTimes {
+        self.times
+    }
+
+    pub(crate) fn idle_states(&self) -> &[crate::CpuIdleState] {
+        &[]
+    }
+
+    pub(crate) fn governor(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn available_governors(&self) -> &[String] {
+        &[]
+    }
+
+    pub(crate) fn scaling_min_frequency(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn scaling_max_frequency(&self) -> Option<u64> {
+        None
+    }
+
+    #[cfg(feature = "cpufreq-write")]
+    pub(crate) fn set_governor(&self, _governor: &str) -> bool {
+        false
+    }
+
     pub(crate) fn vendor_id(&self) -> &str {
         &self.vendor_id
     }
@@ -171,6 +289,32 @@ impl CpuInner {
     pub(crate) fn brand(&self) -> &str {
         ""
     }
+
+    pub(crate) fn family(&self) -> Option<u32> {
+        self.signature.map(|(family, _, _)| family)
+    }
+
+    pub(crate) fn model(&self) -> Option<u32> {
+        self.signature.map(|(_, model, _)| model)
+    }
+
+    pub(crate) fn stepping(&self) -> Option<u32> {
+        self.signature.map(|(_, _, stepping)| stepping)
+    }
+
+    pub(crate) fn microcode_revision(&self) -> Option<u64> {
+        None
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn get_cpu_signature() -> Option<(u32, u32, u32)> {
+    Some(crate::utils::get_cpu_signature())
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn get_cpu_signature() -> Option<(u32, u32, u32)> {
+    None
 }
 
 pub(crate) fn physical_core_count() -> Option<usize> {
@@ -185,6 +329,18 @@ pub(crate) fn physical_core_count() -> Option<usize> {
     }
 }
 
+fn get_stathz() -> u64 {
+    // `kern.clockrate` is a `struct clockinfo`: `hz`, `tick`, `spare`, `stathz` and `profhz`.
+    let mut clockinfo: [c_int; 5] = [0; 5];
+    unsafe {
+        if get_sys_value_by_name(b"kern.clockrate\0", &mut clockinfo) && clockinfo[3] > 0 {
+            return clockinfo[3] as _;
+        }
+    }
+    // Default value of `stathz`.
+    128
+}
+
 unsafe fn get_frequency_for_cpu(cpu_nb: usize) -> u64 {
     let mut frequency = 0;
 
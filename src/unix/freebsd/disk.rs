@@ -1,6 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Disk, DiskKind};
+use crate::utils::parse_remote_location;
+use crate::{BlockDevice, Disk, DiskKind, DiskQuota, DiskRefreshKind, Gid, Uid};
 
 use std::ffi::{OsStr, OsString};
 use std::os::unix::ffi::OsStringExt;
@@ -16,6 +17,11 @@ pub(crate) struct DiskInner {
     available_space: u64,
     file_system: OsString,
     is_removable: bool,
+    is_network: bool,
+    remote_host: Option<String>,
+    remote_path: Option<String>,
+    // `false` if `statvfs` must not be called on this disk.
+    refresh_space: bool,
 }
 
 impl DiskInner {
@@ -47,7 +53,74 @@ impl DiskInner {
         self.is_removable
     }
 
+    pub(crate) fn uuid(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn vendor(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn model(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn serial_number(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn physical_disk(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn storage_pool(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn block_device(&self) -> Option<&BlockDevice> {
+        None
+    }
+
+    pub(crate) fn is_rotational(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn logical_sector_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn physical_sector_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn supports_trim(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn user_quota(&self, _user_id: &Uid) -> Option<DiskQuota> {
+        None
+    }
+
+    pub(crate) fn group_quota(&self, _group_id: Gid) -> Option<DiskQuota> {
+        None
+    }
+
+    pub(crate) fn is_network(&self) -> bool {
+        self.is_network
+    }
+
+    pub(crate) fn remote_host(&self) -> Option<&str> {
+        self.remote_host.as_deref()
+    }
+
+    pub(crate) fn remote_path(&self) -> Option<&str> {
+        self.remote_path.as_deref()
+    }
+
     pub(crate) fn refresh(&mut self) -> bool {
+        if !self.refresh_space {
+            return false;
+        }
         unsafe {
             let mut vfs: libc::statvfs = std::mem::zeroed();
             refresh_disk(self, &mut vfs)
@@ -59,11 +132,13 @@ impl crate::DisksInner {
     pub(crate) fn new() -> Self {
         Self {
             disks: Vec::with_capacity(2),
+            physical_disks: Vec::new(),
+            storage_pools: Vec::new(),
         }
     }
 
-    pub(crate) fn refresh_list(&mut self) {
-        unsafe { get_all_list(&mut self.disks) }
+    pub(crate) fn refresh_list(&mut self, refresh_kind: DiskRefreshKind) {
+        unsafe { get_all_list(&mut self.disks, refresh_kind) }
     }
 
     pub(crate) fn list(&self) -> &[Disk] {
@@ -89,12 +164,13 @@ unsafe fn refresh_disk(disk: &mut DiskInner, vfs: &mut libc::statvfs) -> bool {
     true
 }
 
-pub unsafe fn get_all_list(container: &mut Vec<Disk>) {
+pub unsafe fn get_all_list(container: &mut Vec<Disk>, refresh_kind: DiskRefreshKind) {
     container.clear();
 
     let mut fs_infos: *mut libc::statfs = std::ptr::null_mut();
 
-    let count = libc::getmntinfo(&mut fs_infos, libc::MNT_WAIT);
+    // `MNT_WAIT` would make the kernel query every file system, including the network ones.
+    let count = libc::getmntinfo(&mut fs_infos, libc::MNT_NOWAIT);
 
     if count < 1 {
         return;
@@ -130,9 +206,18 @@ pub unsafe fn get_all_list(container: &mut Vec<Disk>) {
             _ => {}
         }
 
-        if libc::statvfs(fs_info.f_mntonname.as_ptr(), &mut vfs) != 0 {
+        let is_network = (fs_info.f_flags as u64 & libc::MNT_LOCAL as u64) == 0;
+        if is_network && !refresh_kind.network_disks() {
             continue;
         }
+        let refresh_space = !is_network || refresh_kind.network_disks_space();
+        if refresh_space {
+            if libc::statvfs(fs_info.f_mntonname.as_ptr(), &mut vfs) != 0 {
+                continue;
+            }
+        } else {
+            vfs = std::mem::zeroed();
+        }
 
         let mount_point = match c_buf_to_str(&fs_info.f_mntonname) {
             Some(m) => m,
@@ -153,6 +238,13 @@ pub unsafe fn get_all_list(container: &mut Vec<Disk>) {
             [b"USB", b"usb"].iter().any(|b| *b == &fs_type[..]) || fs_type.starts_with(b"/dev/cd");
 
         let f_frsize: u64 = vfs.f_frsize as _;
+        let (remote_host, remote_path) = match c_buf_to_str(&fs_info.f_mntfromname)
+            .filter(|_| is_network)
+            .and_then(parse_remote_location)
+        {
+            Some((host, path)) => (Some(host), Some(path)),
+            None => (None, None),
+        };
 
         container.push(Disk {
             inner: DiskInner {
@@ -163,6 +255,10 @@ pub unsafe fn get_all_list(container: &mut Vec<Disk>) {
                 available_space: vfs.f_favail.saturating_mul(f_frsize),
                 file_system: OsString::from_vec(fs_type),
                 is_removable,
+                is_network,
+                remote_host,
+                remote_path,
+                refresh_space,
             },
         });
     }
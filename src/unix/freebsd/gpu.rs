@@ -0,0 +1,59 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Gpu, GpuRefreshKind};
+
+pub(crate) struct GpuInner;
+
+impl GpuInner {
+    pub(crate) fn name(&self) -> &str {
+        ""
+    }
+
+    pub(crate) fn driver_version(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn usage(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn total_memory(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn used_memory(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn temperature(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn power(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn refresh(&mut self, _refresh_kind: GpuRefreshKind) {}
+}
+
+pub(crate) struct GpusInner {
+    gpus: Vec<Gpu>,
+}
+
+impl GpusInner {
+    pub(crate) fn new() -> Self {
+        Self { gpus: Vec::new() }
+    }
+
+    pub(crate) fn list(&self) -> &[Gpu] {
+        &self.gpus
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Gpu] {
+        &mut self.gpus
+    }
+
+    pub(crate) fn refresh_list(&mut self, _refresh_kind: GpuRefreshKind) {
+        // Doesn't do anything.
+    }
+}
@@ -1,20 +1,46 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+pub(crate) mod battery;
 pub mod component;
+pub(crate) mod container;
 pub mod cpu;
 pub mod disk;
+pub(crate) mod gpu;
 pub mod network;
+pub(crate) mod pci;
+#[cfg(feature = "energy")]
+pub(crate) mod power;
 pub mod process;
+pub(crate) mod services;
 pub mod system;
+pub(crate) mod thermal;
 mod utils;
 
+pub(crate) use self::battery::get_batteries;
 pub(crate) use self::component::{ComponentInner, ComponentsInner};
+pub(crate) use self::container::{ContainerInner, ContainersInner};
 pub(crate) use self::cpu::CpuInner;
 pub(crate) use self::disk::DiskInner;
-pub(crate) use self::network::{NetworkDataInner, NetworksInner};
+pub(crate) use self::gpu::{GpuInner, GpusInner};
+pub(crate) use self::network::{
+    get_connection_summary, get_connections, NetworkDataInner, NetworksInner,
+};
+pub(crate) use self::pci::get_pci_devices;
+#[cfg(feature = "energy")]
+pub(crate) use self::power::PowerInner;
 pub(crate) use self::process::ProcessInner;
+pub(crate) use self::services::get_services;
 pub(crate) use self::system::SystemInner;
-pub(crate) use crate::unix::users::{get_users, UserInner};
+pub(crate) use self::thermal::get_thermal_zones;
+pub(crate) use crate::unix::disk_events::DiskEventsInner;
+pub(crate) use crate::unix::kernel_parameters::get_kernel_parameter;
+pub(crate) use crate::unix::locale::{get_locale, get_time_zone};
+pub(crate) use crate::unix::neighbors::get_neighbors;
+pub(crate) use crate::unix::network_events::NetworkEventsInner;
+pub(crate) use crate::unix::os_release::get_os_release;
+pub(crate) use crate::unix::performance_counters::PerformanceCountersInner;
+pub(crate) use crate::unix::sessions::get_sessions;
+pub(crate) use crate::unix::users::{get_users, resolve_group_name, resolve_user_name, UserInner};
 pub(crate) use crate::unix::DisksInner;
 
 use libc::c_int;
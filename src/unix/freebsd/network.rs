@@ -4,9 +4,47 @@ use std::collections::{hash_map, HashMap};
 use std::mem::MaybeUninit;
 
 use super::utils;
-use crate::common::MacAddr;
-use crate::network::refresh_networks_addresses;
-use crate::NetworkData;
+use crate::common::{
+    DuplexMode, InterfaceKind, IpNetwork, MacAddr, NetworkCounters, OperationalState,
+};
+use crate::network::{counter_delta, refresh_networks_addresses, LinkState, RefreshTimes};
+use crate::network_helper::{
+    get_duplex_mode, get_interface_index, get_interface_kind, get_vlan_parent,
+};
+use crate::{Connection, ConnectionSummary, NetworkData};
+
+// The baudrate is `0` for interfaces which don't have a link speed, like loopback.
+fn get_link_speed(data: &libc::if_data) -> Option<u64> {
+    Some(data.ifi_baudrate).filter(|speed| *speed != 0)
+}
+
+fn get_counters(data: &libc::if_data) -> NetworkCounters {
+    NetworkCounters {
+        multicast_received: Some(data.ifi_imcasts),
+        multicast_transmitted: Some(data.ifi_omcasts),
+        collisions: Some(data.ifi_collisions),
+        dropped_on_received: Some(data.ifi_iqdrops),
+        dropped_on_transmitted: Some(data.ifi_oqdrops),
+        ..NetworkCounters::default()
+    }
+}
+
+fn get_link_state(flags: libc::c_int, data: &libc::if_data) -> LinkState {
+    let is_admin_up = flags & libc::IFF_UP != 0;
+    let has_carrier = match data.ifi_link_state as libc::c_int {
+        libc::LINK_STATE_UP => Some(true),
+        libc::LINK_STATE_DOWN => Some(false),
+        _ => None,
+    };
+    let operational_state = match (is_admin_up, has_carrier) {
+        (false, _) | (true, Some(false)) => OperationalState::Down,
+        (true, Some(true)) => OperationalState::Up,
+        // Interfaces without a link state, like loopback.
+        (true, None) if flags & libc::IFF_RUNNING != 0 => OperationalState::Up,
+        (true, None) => OperationalState::Unknown,
+    };
+    LinkState::new(operational_state, is_admin_up, has_carrier)
+}
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident, $data:expr) => {{
@@ -17,12 +55,21 @@ macro_rules! old_and_new {
 
 pub(crate) struct NetworksInner {
     pub(crate) interfaces: HashMap<String, NetworkData>,
+    handle_counter_wrap: bool,
 }
 
 impl NetworksInner {
     pub(crate) fn new() -> Self {
         Self {
             interfaces: HashMap::new(),
+            handle_counter_wrap: true,
+        }
+    }
+
+    pub(crate) fn set_counter_wrap_handling(&mut self, enabled: bool) {
+        self.handle_counter_wrap = enabled;
+        for data in self.interfaces.values_mut() {
+            data.inner.handle_counter_wrap = enabled;
         }
     }
 
@@ -32,20 +79,41 @@ impl NetworksInner {
 
     pub(crate) fn refresh_list(&mut self) {
         unsafe {
-            self.refresh_interfaces(true);
+            self.update_interfaces(true);
         }
         // Remove interfaces which are gone.
         self.interfaces.retain(|_, n| n.inner.updated);
+        for (name, data) in self.interfaces.iter_mut() {
+            data.inner.duplex = get_duplex_mode(name);
+            data.inner.lower_interfaces = if data.inner.kind == InterfaceKind::Vlan {
+                get_vlan_parent(name).into_iter().collect()
+            } else {
+                Vec::new()
+            };
+        }
         refresh_networks_addresses(&mut self.interfaces);
     }
 
     pub(crate) fn refresh(&mut self) {
         unsafe {
-            self.refresh_interfaces(false);
+            self.update_interfaces(false);
+        }
+    }
+
+    pub(crate) fn refresh_interfaces(&mut self, interface_names: &[&str]) {
+        for interface_name in interface_names {
+            if !self.interfaces.contains_key(*interface_name) {
+                continue;
+            }
+            if let Some(index) = get_interface_index(interface_name) {
+                unsafe {
+                    self.update_interface(index as _, false);
+                }
+            }
         }
     }
 
-    unsafe fn refresh_interfaces(&mut self, refresh_all: bool) {
+    unsafe fn update_interfaces(&mut self, refresh_all: bool) {
         let mut nb_interfaces: libc::c_int = 0;
         if !utils::get_sys_value(
             &[
@@ -65,59 +133,81 @@ impl NetworksInner {
                 interface.inner.updated = false;
             }
         }
-        let mut data: libc::ifmibdata = MaybeUninit::zeroed().assume_init();
         for row in 1..nb_interfaces {
-            let mib = [
-                libc::CTL_NET,
-                libc::PF_LINK,
-                libc::NETLINK_GENERIC,
-                libc::IFMIB_IFDATA,
-                row,
-                libc::IFDATA_GENERAL,
-            ];
+            self.update_interface(row, refresh_all);
+        }
+    }
 
-            if !utils::get_sys_value(&mib, &mut data) {
-                continue;
-            }
-            if let Some(name) = utils::c_buf_to_string(&data.ifmd_name) {
-                let data = &data.ifmd_data;
-                match self.interfaces.entry(name) {
-                    hash_map::Entry::Occupied(mut e) => {
-                        let interface = e.get_mut();
-                        let interface = &mut interface.inner;
-
-                        old_and_new!(interface, ifi_ibytes, old_ifi_ibytes, data);
-                        old_and_new!(interface, ifi_obytes, old_ifi_obytes, data);
-                        old_and_new!(interface, ifi_ipackets, old_ifi_ipackets, data);
-                        old_and_new!(interface, ifi_opackets, old_ifi_opackets, data);
-                        old_and_new!(interface, ifi_ierrors, old_ifi_ierrors, data);
-                        old_and_new!(interface, ifi_oerrors, old_ifi_oerrors, data);
-                        interface.updated = true;
-                    }
-                    hash_map::Entry::Vacant(e) => {
-                        if !refresh_all {
-                            // This is simply a refresh, we don't want to add new interfaces!
-                            continue;
-                        }
-                        e.insert(NetworkData {
-                            inner: NetworkDataInner {
-                                ifi_ibytes: data.ifi_ibytes,
-                                old_ifi_ibytes: 0,
-                                ifi_obytes: data.ifi_obytes,
-                                old_ifi_obytes: 0,
-                                ifi_ipackets: data.ifi_ipackets,
-                                old_ifi_ipackets: 0,
-                                ifi_opackets: data.ifi_opackets,
-                                old_ifi_opackets: 0,
-                                ifi_ierrors: data.ifi_ierrors,
-                                old_ifi_ierrors: 0,
-                                ifi_oerrors: data.ifi_oerrors,
-                                old_ifi_oerrors: 0,
-                                updated: true,
-                                mac_addr: MacAddr::UNSPECIFIED,
-                            },
-                        });
+    unsafe fn update_interface(&mut self, row: libc::c_int, refresh_all: bool) {
+        let mut data: libc::ifmibdata = MaybeUninit::zeroed().assume_init();
+        let mib = [
+            libc::CTL_NET,
+            libc::PF_LINK,
+            libc::NETLINK_GENERIC,
+            libc::IFMIB_IFDATA,
+            row,
+            libc::IFDATA_GENERAL,
+        ];
+
+        if !utils::get_sys_value(&mib, &mut data) {
+            return;
+        }
+        if let Some(name) = utils::c_buf_to_string(&data.ifmd_name) {
+            let flags = data.ifmd_flags;
+            let data = &data.ifmd_data;
+            match self.interfaces.entry(name) {
+                hash_map::Entry::Occupied(mut e) => {
+                    let interface = e.get_mut();
+                    let interface = &mut interface.inner;
+
+                    old_and_new!(interface, ifi_ibytes, old_ifi_ibytes, data);
+                    old_and_new!(interface, ifi_obytes, old_ifi_obytes, data);
+                    old_and_new!(interface, ifi_ipackets, old_ifi_ipackets, data);
+                    old_and_new!(interface, ifi_opackets, old_ifi_opackets, data);
+                    old_and_new!(interface, ifi_ierrors, old_ifi_ierrors, data);
+                    old_and_new!(interface, ifi_oerrors, old_ifi_oerrors, data);
+                    interface.refresh_times.update();
+                    interface.counters = get_counters(data);
+                    interface.mtu = data.ifi_mtu as _;
+                    interface.link_speed = get_link_speed(data);
+                    interface.link_state.update(get_link_state(flags, data));
+                    interface.kind = get_interface_kind(data.ifi_type);
+                    interface.updated = true;
+                }
+                hash_map::Entry::Vacant(e) => {
+                    if !refresh_all {
+                        // This is simply a refresh, we don't want to add new interfaces!
+                        return;
                     }
+                    e.insert(NetworkData {
+                        inner: NetworkDataInner {
+                            ifi_ibytes: data.ifi_ibytes,
+                            old_ifi_ibytes: 0,
+                            ifi_obytes: data.ifi_obytes,
+                            old_ifi_obytes: 0,
+                            ifi_ipackets: data.ifi_ipackets,
+                            old_ifi_ipackets: 0,
+                            ifi_opackets: data.ifi_opackets,
+                            old_ifi_opackets: 0,
+                            ifi_ierrors: data.ifi_ierrors,
+                            old_ifi_ierrors: 0,
+                            ifi_oerrors: data.ifi_oerrors,
+                            old_ifi_oerrors: 0,
+                            refresh_times: RefreshTimes::new(),
+                            handle_counter_wrap: self.handle_counter_wrap,
+                            counters: get_counters(data),
+                            updated: true,
+                            mac_addr: MacAddr::UNSPECIFIED,
+                            ip_networks: Vec::new(),
+                            mtu: data.ifi_mtu as _,
+                            link_speed: get_link_speed(data),
+                            duplex: DuplexMode::Unknown,
+                            link_state: get_link_state(flags, data),
+                            kind: get_interface_kind(data.ifi_type),
+                            master: None,
+                            lower_interfaces: Vec::new(),
+                        },
+                    });
                 }
             }
         }
@@ -145,31 +235,68 @@ pub(crate) struct NetworkDataInner {
     /// similar to `ifi_ierrors`
     ifi_oerrors: u64,
     old_ifi_oerrors: u64,
+    refresh_times: RefreshTimes,
+    /// Whether the deltas of the counters take 32-bit wraps into account
+    handle_counter_wrap: bool,
+    counters: NetworkCounters,
     /// Whether or not the above data has been updated during refresh
     updated: bool,
     /// MAC address
     pub(crate) mac_addr: MacAddr,
+    /// IP networks
+    pub(crate) ip_networks: Vec<IpNetwork>,
+    /// Maximum Transmission Unit
+    mtu: u64,
+    /// Link speed, in bits per second
+    link_speed: Option<u64>,
+    duplex: DuplexMode,
+    link_state: LinkState,
+    kind: InterfaceKind,
+    /// Bond or bridge this interface is a member of
+    master: Option<String>,
+    /// Interfaces this interface sits on
+    lower_interfaces: Vec<String>,
 }
 
 impl NetworkDataInner {
     pub(crate) fn received(&self) -> u64 {
-        self.ifi_ibytes.saturating_sub(self.old_ifi_ibytes)
+        counter_delta(
+            self.old_ifi_ibytes,
+            self.ifi_ibytes,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_received(&self) -> u64 {
         self.ifi_ibytes
     }
 
+    pub(crate) fn received_rate(&self) -> f64 {
+        self.refresh_times.rate(self.received())
+    }
+
     pub(crate) fn transmitted(&self) -> u64 {
-        self.ifi_obytes.saturating_sub(self.old_ifi_obytes)
+        counter_delta(
+            self.old_ifi_obytes,
+            self.ifi_obytes,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_transmitted(&self) -> u64 {
         self.ifi_obytes
     }
 
+    pub(crate) fn transmitted_rate(&self) -> f64 {
+        self.refresh_times.rate(self.transmitted())
+    }
+
     pub(crate) fn packets_received(&self) -> u64 {
-        self.ifi_ipackets.saturating_sub(self.old_ifi_ipackets)
+        counter_delta(
+            self.old_ifi_ipackets,
+            self.ifi_ipackets,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_packets_received(&self) -> u64 {
@@ -177,7 +304,11 @@ impl NetworkDataInner {
     }
 
     pub(crate) fn packets_transmitted(&self) -> u64 {
-        self.ifi_opackets.saturating_sub(self.old_ifi_opackets)
+        counter_delta(
+            self.old_ifi_opackets,
+            self.ifi_opackets,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_packets_transmitted(&self) -> u64 {
@@ -185,7 +316,11 @@ impl NetworkDataInner {
     }
 
     pub(crate) fn errors_on_received(&self) -> u64 {
-        self.ifi_ierrors.saturating_sub(self.old_ifi_ierrors)
+        counter_delta(
+            self.old_ifi_ierrors,
+            self.ifi_ierrors,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_errors_on_received(&self) -> u64 {
@@ -193,14 +328,85 @@ impl NetworkDataInner {
     }
 
     pub(crate) fn errors_on_transmitted(&self) -> u64 {
-        self.ifi_oerrors.saturating_sub(self.old_ifi_oerrors)
+        counter_delta(
+            self.old_ifi_oerrors,
+            self.ifi_oerrors,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_errors_on_transmitted(&self) -> u64 {
         self.ifi_oerrors
     }
 
+    pub(crate) fn detailed_counters(&self) -> NetworkCounters {
+        self.counters
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
+
+    pub(crate) fn ip_networks(&self) -> &[IpNetwork] {
+        &self.ip_networks
+    }
+
+    pub(crate) fn mtu(&self) -> u64 {
+        self.mtu
+    }
+
+    pub(crate) fn link_speed(&self) -> Option<u64> {
+        self.link_speed
+    }
+
+    pub(crate) fn duplex(&self) -> DuplexMode {
+        self.duplex
+    }
+
+    pub(crate) fn operational_state(&self) -> OperationalState {
+        self.link_state.operational_state
+    }
+
+    pub(crate) fn is_admin_up(&self) -> bool {
+        self.link_state.is_admin_up
+    }
+
+    pub(crate) fn has_carrier(&self) -> Option<bool> {
+        self.link_state.has_carrier
+    }
+
+    pub(crate) fn carrier_changes(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn last_state_change(&self) -> Option<u64> {
+        self.link_state.last_change
+    }
+
+    pub(crate) fn kind(&self) -> InterfaceKind {
+        self.kind
+    }
+
+    pub(crate) fn description(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn master(&self) -> Option<&str> {
+        self.master.as_deref()
+    }
+
+    pub(crate) fn lower_interfaces(&self) -> &[String] {
+        &self.lower_interfaces
+    }
+
+    #[cfg(feature = "wireless")]
+    pub(crate) fn wireless(&self) -> Option<&crate::WirelessInfo> {
+        None
+    }
+}
+
+pub(crate) fn get_connections(_: &mut Vec<Connection>) {}
+
+pub(crate) fn get_connection_summary() -> ConnectionSummary {
+    ConnectionSummary::default()
 }
@@ -0,0 +1,45 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use super::utils::get_sys_value_by_name;
+use crate::{PowerRail, PowerRailKind};
+
+use libc::c_int;
+
+// Value from `dev/acpica/acpiio.h`.
+const ACPI_BATT_STAT_DISCHARG: c_int = 0x0001;
+
+pub(crate) struct PowerInner {
+    rails: Vec<PowerRail>,
+}
+
+impl PowerInner {
+    pub(crate) fn new() -> Self {
+        Self { rails: Vec::new() }
+    }
+
+    pub(crate) fn rails(&self) -> &[PowerRail] {
+        &self.rails
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        self.rails.clear();
+        let mut state: c_int = 0;
+        // In mW, `-1` if unknown. Like the other `hw.acpi.battery` sysctls, it combines all the
+        // batteries.
+        let mut rate: c_int = -1;
+        unsafe {
+            if !get_sys_value_by_name(b"hw.acpi.battery.state\0", &mut state)
+                || state & ACPI_BATT_STAT_DISCHARG == 0
+                || !get_sys_value_by_name(b"hw.acpi.battery.rate\0", &mut rate)
+                || rate < 0
+            {
+                return;
+            }
+        }
+        self.rails.push(PowerRail {
+            name: "Battery".to_owned(),
+            kind: PowerRailKind::Battery,
+            power: rate as f32 / 1_000.,
+        });
+    }
+}
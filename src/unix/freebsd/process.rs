@@ -4,6 +4,7 @@ use crate::{DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Sig
 
 use std::fmt;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use libc::kill;
 
@@ -53,6 +54,9 @@ pub(crate) struct ProcessInner {
     pub(crate) virtual_memory: u64,
     pub(crate) updated: bool,
     cpu_usage: f32,
+    normalized_cpu_usage: f32,
+    user_time: Duration,
+    kernel_time: Duration,
     start_time: u64,
     run_time: u64,
     pub(crate) status: ProcessStatus,
@@ -128,6 +132,23 @@ impl ProcessInner {
         self.cpu_usage
     }
 
+    pub(crate) fn normalized_cpu_usage(&self) -> f32 {
+        self.normalized_cpu_usage
+    }
+
+    pub(crate) fn set_cpu_usage(&mut self, cpu_usage: f32, normalized_cpu_usage: f32) {
+        self.cpu_usage = cpu_usage;
+        self.normalized_cpu_usage = normalized_cpu_usage;
+    }
+
+    pub(crate) fn user_time(&self) -> Duration {
+        self.user_time
+    }
+
+    pub(crate) fn kernel_time(&self) -> Duration {
+        self.kernel_time
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
@@ -177,6 +198,14 @@ impl ProcessInner {
             }
         }
     }
+
+    pub(crate) fn is_translated(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_remote_session(&self) -> bool {
+        crate::unix::sessions::is_remote_environ(&self.environ)
+    }
 }
 
 pub(crate) unsafe fn get_process_data(
@@ -184,6 +213,7 @@ pub(crate) unsafe fn get_process_data(
     wrap: &WrapMap,
     page_size: isize,
     fscale: f32,
+    nb_cpus: usize,
     now: u64,
     refresh_kind: ProcessRefreshKind,
 ) -> Result<Option<Process>, ()> {
@@ -198,6 +228,7 @@ pub(crate) unsafe fn get_process_data(
     } else {
         0.
     };
+    let normalized_cpu_usage = cpu_usage / nb_cpus.max(1) as f32;
     // Processes can be reparented apparently?
     let parent = if kproc.ki_ppid != 0 {
         Some(Pid(kproc.ki_ppid))
@@ -213,6 +244,8 @@ pub(crate) unsafe fn get_process_data(
     // let run_time = (kproc.ki_runtime + 5_000) / 10_000;
 
     let start_time = kproc.ki_start.tv_sec as u64;
+    let user_time = timeval_to_duration(&kproc.ki_rusage.ru_utime);
+    let kernel_time = timeval_to_duration(&kproc.ki_rusage.ru_stime);
 
     if let Some(proc_) = (*wrap.0.get()).get_mut(&Pid(kproc.ki_pid)) {
         let proc_ = &mut proc_.inner;
@@ -221,6 +254,9 @@ pub(crate) unsafe fn get_process_data(
         // same process.
         if proc_.start_time == start_time {
             proc_.cpu_usage = cpu_usage;
+            proc_.normalized_cpu_usage = normalized_cpu_usage;
+            proc_.user_time = user_time;
+            proc_.kernel_time = kernel_time;
             proc_.parent = parent;
             proc_.status = status;
             proc_.virtual_memory = virtual_memory;
@@ -276,6 +312,9 @@ pub(crate) unsafe fn get_process_data(
             start_time,
             run_time: now.saturating_sub(start_time),
             cpu_usage,
+            normalized_cpu_usage,
+            user_time,
+            kernel_time,
             virtual_memory,
             memory,
             // procstat_getfiles
@@ -298,3 +337,7 @@ pub(crate) unsafe fn get_process_data(
         },
     }))
 }
+
+fn timeval_to_duration(tv: &libc::timeval) -> Duration {
+    Duration::from_secs(tv.tv_sec as _) + Duration::from_micros(tv.tv_usec as _)
+}
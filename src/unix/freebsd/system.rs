@@ -1,6 +1,10 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Cpu, CpuRefreshKind, LoadAvg, Pid, Process, ProcessInner, ProcessRefreshKind};
+use crate::{
+    ContainerRuntime, Cpu, CpuActivity, CpuRefreshKind, Entropy, FileHandles, HardwareInfo,
+    Hypervisor, KernelParameter, LoadAvg, MemoryBreakdown, OsRelease, Pid, Process, ProcessInner,
+    ProcessRefreshKind, SystemCounts, Virtualization, WindowsVersion,
+};
 
 use std::cell::UnsafeCell;
 use std::collections::HashMap;
@@ -8,20 +12,36 @@ use std::ffi::CStr;
 use std::mem::MaybeUninit;
 use std::path::{Path, PathBuf};
 use std::ptr::NonNull;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use crate::sys::cpu::{physical_core_count, CpusWrapper};
 use crate::sys::utils::{
-    self, boot_time, c_buf_to_string, from_cstr_array, get_sys_value, get_sys_value_by_name,
-    get_system_info, init_mib,
+    self, boot_time, boot_timeval, c_buf_to_string, from_cstr_array, get_kenv, get_sys_value,
+    get_sys_value_by_name, get_sys_value_str_by_name, get_system_info, init_mib,
 };
 
+use crate::unix::utils::{get_uname_machine, get_userland_arch};
+use crate::utils::normalize_arch;
+
 use libc::c_int;
 
+// The swap is read with the same `kvm` handle as the processes, which isn't thread-safe, so the
+// memory is only read when applying the reader, on the thread owning the `SystemInner`.
+#[cfg(feature = "parallel")]
+pub(crate) struct MemoryReader;
+
+#[cfg(feature = "parallel")]
+impl MemoryReader {
+    pub(crate) fn read(&mut self) {}
+}
+
 pub(crate) struct SystemInner {
     process_list: HashMap<Pid, Process>,
     mem_total: u64,
     mem_free: u64,
+    mem_free_pages: u64,
     mem_used: u64,
+    mem_breakdown: MemoryBreakdown,
     swap_total: u64,
     swap_used: u64,
     boot_time: u64,
@@ -35,7 +55,9 @@ impl SystemInner {
             process_list: HashMap::with_capacity(200),
             mem_total: 0,
             mem_free: 0,
+            mem_free_pages: 0,
             mem_used: 0,
+            mem_breakdown: MemoryBreakdown::default(),
             swap_total: 0,
             swap_used: 0,
             boot_time: boot_time(),
@@ -44,12 +66,28 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn set_string_encoding_policy(&mut self, _policy: crate::StringEncodingPolicy) {}
+
+    pub(crate) fn set_keep_open_files(&mut self, _enabled: bool) {}
+
+    #[cfg(feature = "parallel")]
+    pub(crate) fn memory_reader(&mut self) -> MemoryReader {
+        MemoryReader
+    }
+
+    #[cfg(feature = "parallel")]
+    pub(crate) fn apply_memory(&mut self, _reader: MemoryReader) {
+        self.refresh_memory();
+    }
+
     pub(crate) fn refresh_memory(&mut self) {
         if self.mem_total == 0 {
             self.mem_total = self.system_info.get_total_memory();
         }
         self.mem_used = self.system_info.get_used_memory();
         self.mem_free = self.system_info.get_free_memory();
+        self.mem_free_pages = self.system_info.get_free_pages_memory();
+        self.mem_breakdown = self.system_info.get_memory_breakdown();
         let (swap_used, swap_total) = self.system_info.get_swap_info();
         self.swap_total = swap_total;
         self.swap_used = swap_used;
@@ -63,6 +101,10 @@ impl SystemInner {
         self.cpus.refresh(refresh_kind)
     }
 
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        self.cpus.refresh_global_cpu_usage()
+    }
+
     pub(crate) fn refresh_processes_specifics(&mut self, refresh_kind: ProcessRefreshKind) {
         unsafe { self.refresh_procs(refresh_kind) }
     }
@@ -83,6 +125,7 @@ impl SystemInner {
             let now = super::utils::get_now();
 
             let fscale = self.system_info.fscale;
+            let nb_cpus = self.system_info.nb_cpus;
             let page_size = self.system_info.page_size as isize;
             let proc_list = utils::WrapMap(UnsafeCell::new(&mut self.process_list));
             let procs: &mut [utils::KInfoProc] =
@@ -113,6 +156,7 @@ impl SystemInner {
                 &proc_list,
                 page_size,
                 fscale,
+                nb_cpus,
                 now,
                 refresh_kind,
             ) {
@@ -142,14 +186,46 @@ impl SystemInner {
         &self.cpus.global_cpu
     }
 
+    #[cfg(feature = "energy")]
+    pub(crate) fn refresh_energy(&mut self) {}
+
+    #[cfg(feature = "energy")]
+    pub(crate) fn energy_domains(&self) -> &[crate::EnergyDomain] {
+        &[]
+    }
+
+    pub(crate) fn cpu_activity(&self) -> CpuActivity {
+        self.cpus.activity()
+    }
+
     pub(crate) fn cpus(&self) -> &[Cpu] {
         &self.cpus.cpus
     }
 
+    pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
+        &mut self.process_list
+    }
+
+    pub(crate) fn global_cpu_info_mut(&mut self) -> &mut Cpu {
+        &mut self.cpus.global_cpu
+    }
+
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus.cpus
+    }
+
     pub(crate) fn physical_core_count(&self) -> Option<usize> {
         physical_core_count()
     }
 
+    pub(crate) fn socket_count(&self) -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn cpu_features(&self) -> Vec<String> {
+        crate::utils::get_cpu_features()
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -162,10 +238,55 @@ impl SystemInner {
         self.mem_free
     }
 
+    pub(crate) fn available_memory_breakdown(&self) -> Option<crate::AvailableMemory> {
+        Some(crate::AvailableMemory {
+            free: self.mem_free_pages,
+            reclaimable: self.mem_free.saturating_sub(self.mem_free_pages),
+            available: self.mem_free,
+            source: crate::AvailableMemorySource::Computed,
+        })
+    }
+
     pub(crate) fn used_memory(&self) -> u64 {
         self.mem_used
     }
 
+    pub(crate) fn memory_breakdown(&self) -> MemoryBreakdown {
+        self.mem_breakdown
+    }
+
+    pub(crate) fn commit_charge(&self) -> crate::CommitCharge {
+        crate::CommitCharge::default()
+    }
+
+    pub(crate) fn compressed_memory(&self) -> crate::CompressedMemory {
+        crate::CompressedMemory::default()
+    }
+
+    pub(crate) fn memory_pressure_level(&self) -> Option<crate::MemoryPressureLevel> {
+        None
+    }
+
+    pub(crate) fn zram_devices(&self) -> &[crate::ZramDevice] {
+        &[]
+    }
+
+    pub(crate) fn ksm(&self) -> Option<crate::Ksm> {
+        None
+    }
+
+    pub(crate) fn balloon_memory(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn oom_kills(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn memory_modules(&self) -> Vec<crate::MemoryModule> {
+        Vec::new()
+    }
+
     pub(crate) fn total_swap(&self) -> u64 {
         self.swap_total
     }
@@ -191,6 +312,25 @@ impl SystemInner {
         self.boot_time
     }
 
+    pub(crate) fn uptime_duration(&self) -> Duration {
+        // `CLOCK_UPTIME` stops while the system is suspended, but the boot time is adjusted on
+        // resume.
+        SystemTime::now()
+            .duration_since(self.boot_time_sys())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn boot_time_sys(&self) -> SystemTime {
+        // The kernel adjusts it when the clock is set.
+        boot_timeval()
+            .map(|tv| UNIX_EPOCH + Duration::new(tv.tv_sec as _, tv.tv_usec as u32 * 1_000))
+            .unwrap_or(UNIX_EPOCH)
+    }
+
+    pub(crate) fn pressure(&self) -> Option<crate::Pressure> {
+        None
+    }
+
     pub(crate) fn load_average(&self) -> LoadAvg {
         let mut loads = vec![0f64; 3];
         unsafe {
@@ -215,6 +355,147 @@ impl SystemInner {
         self.system_info.get_hostname()
     }
 
+    pub(crate) fn virtualization(&self) -> Virtualization {
+        // Set by the kernel from the `cpuid` leaves and the SMBIOS information.
+        let hypervisor = match get_sys_value_str_by_name(b"kern.vm_guest\0").as_deref() {
+            Some("none") => None,
+            Some("kvm") => Some(Hypervisor::Kvm),
+            Some("hv") => Some(Hypervisor::HyperV),
+            Some("vmware") => Some(Hypervisor::VMware),
+            Some("vbox") => Some(Hypervisor::VirtualBox),
+            Some("xen") => Some(Hypervisor::Xen),
+            Some("parallels") => Some(Hypervisor::Parallels),
+            Some("bhyve") => Some(Hypervisor::Bhyve),
+            _ => crate::utils::get_hypervisor(&self.hardware_info()),
+        };
+        let mut jailed: c_int = 0;
+        let container = unsafe {
+            if get_sys_value_by_name(b"security.jail.jailed\0", &mut jailed) && jailed != 0 {
+                Some(ContainerRuntime::Jail)
+            } else {
+                None
+            }
+        };
+        Virtualization {
+            hypervisor,
+            container,
+        }
+    }
+
+    pub(crate) fn machine_id(&self) -> Option<String> {
+        // Set by `hostid` from the SMBIOS UUID, or randomly generated if there is none.
+        get_sys_value_str_by_name(b"kern.hostuuid\0")
+            .filter(|uuid| !uuid.is_empty() && uuid != "00000000-0000-0000-0000-000000000000")
+            .or_else(|| get_kenv(b"smbios.system.uuid\0"))
+    }
+
+    pub(crate) fn kernel_arch(&self) -> Option<String> {
+        get_uname_machine().map(|machine| normalize_arch(&machine))
+    }
+
+    pub(crate) fn userland_arch(&self) -> Option<String> {
+        get_userland_arch()
+    }
+
+    pub(crate) fn os_release(&self) -> Option<OsRelease> {
+        crate::sys::get_os_release()
+    }
+
+    pub(crate) fn windows_version(&self) -> Option<WindowsVersion> {
+        None
+    }
+
+    pub(crate) fn kernel_parameter(&self, name: &str) -> Option<KernelParameter> {
+        crate::sys::get_kernel_parameter(name)
+    }
+
+    pub(crate) fn counts(&self) -> SystemCounts {
+        let count = |what: c_int| unsafe {
+            let mut count = 0;
+            let procs = libc::kvm_getprocs(self.system_info.kd.as_ptr(), what, 0, &mut count);
+            if procs.is_null() {
+                None
+            } else {
+                Some(count as u64)
+            }
+        };
+        let mut open_files: c_int = 0;
+        SystemCounts {
+            processes: count(libc::KERN_PROC_PROC),
+            // There is one entry per thread with this flag.
+            threads: count(libc::KERN_PROC_ALL | libc::KERN_PROC_INC_THREAD),
+            handles: unsafe { get_sys_value_by_name(b"kern.openfiles\0", &mut open_files) }
+                .then(|| open_files as u64),
+        }
+    }
+
+    pub(crate) fn file_handles(&self) -> Option<FileHandles> {
+        let mut allocated: c_int = 0;
+        let mut limit: c_int = 0;
+        unsafe {
+            if !get_sys_value_by_name(b"kern.openfiles\0", &mut allocated) {
+                return None;
+            }
+            Some(FileHandles {
+                allocated: allocated as _,
+                limit: get_sys_value_by_name(b"kern.maxfiles\0", &mut limit).then(|| limit as _),
+            })
+        }
+    }
+
+    pub(crate) fn entropy(&self) -> Option<Entropy> {
+        let mut byte = 0u8;
+        let ret =
+            unsafe { libc::getrandom(&mut byte as *mut u8 as *mut _, 1, libc::GRND_NONBLOCK) };
+        let ready = match ret {
+            1 => true,
+            _ if std::io::Error::last_os_error().raw_os_error() == Some(libc::EAGAIN) => false,
+            _ => return None,
+        };
+        Some(Entropy {
+            ready,
+            available: None,
+            pool_size: None,
+        })
+    }
+
+    pub(crate) fn fqdn(&self) -> Option<String> {
+        crate::unix::utils::get_fqdn(&self.host_name()?)
+    }
+
+    pub(crate) fn domain_name(&self) -> Option<String> {
+        let fqdn = self.fqdn()?;
+        let (_, domain) = fqdn.split_once('.')?;
+        Some(domain.to_owned()).filter(|domain| !domain.is_empty())
+    }
+
+    pub(crate) fn workgroup(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn hardware_info(&self) -> HardwareInfo {
+        // The loader exports the SMBIOS information into the kernel environment.
+        let read = |name: &[u8]| get_kenv(name).unwrap_or_default();
+        let mut info = HardwareInfo {
+            manufacturer: read(b"smbios.system.maker\0"),
+            product_name: read(b"smbios.system.product\0"),
+            product_version: read(b"smbios.system.version\0"),
+            serial_number: read(b"smbios.system.serial\0"),
+            board_vendor: read(b"smbios.planar.maker\0"),
+            board_name: read(b"smbios.planar.product\0"),
+            board_serial_number: read(b"smbios.planar.serial\0"),
+            bios_vendor: read(b"smbios.bios.vendor\0"),
+            bios_version: read(b"smbios.bios.version\0"),
+            bios_release_date: read(b"smbios.bios.reldate\0"),
+            chassis: read(b"smbios.chassis.type\0")
+                .parse()
+                .map(crate::utils::chassis_from_smbios)
+                .unwrap_or_default(),
+        };
+        crate::utils::set_virtual_chassis(&mut info);
+        info
+    }
+
     pub(crate) fn kernel_version(&self) -> Option<String> {
         self.system_info.get_kernel_version()
     }
@@ -236,6 +517,11 @@ impl SystemInner {
             let procs = libc::kvm_getprocs(kd, libc::KERN_PROC_PROC, 0, &mut count);
             if count < 1 {
                 sysinfo_debug!("kvm_getprocs returned nothing...");
+                crate::utils::report_error(
+                    crate::RefreshErrorKind::Unavailable,
+                    "processes",
+                    "kvm_getprocs returned nothing",
+                );
                 return;
             }
             #[cfg(feature = "multithread")]
@@ -244,6 +530,7 @@ impl SystemInner {
             use std::iter::Iterator as IterTrait;
 
             let fscale = self.system_info.fscale;
+            let nb_cpus = self.system_info.nb_cpus;
             let page_size = self.system_info.page_size as isize;
             let now = super::utils::get_now();
             let proc_list = utils::WrapMap(UnsafeCell::new(&mut self.process_list));
@@ -256,6 +543,7 @@ impl SystemInner {
                     &proc_list,
                     page_size,
                     fscale,
+                    nb_cpus,
                     now,
                     refresh_kind,
                 )
@@ -366,6 +654,8 @@ struct SystemInfo {
     /// From FreeBSD manual: "The kernel fixed-point scale factor". It's used when computing
     /// processes' CPU usage.
     fscale: f32,
+    /// Number of CPUs, used to compute the normalized CPU usage of processes.
+    nb_cpus: usize,
     procstat: *mut libc::procstat,
     zfs: Zfs,
 }
@@ -404,6 +694,7 @@ impl SystemInfo {
                 hostname: Default::default(),
                 kd,
                 fscale: 0.,
+                nb_cpus: super::cpu::get_nb_cpus(),
                 procstat: std::ptr::null_mut(),
                 zfs: Zfs::new(),
             };
@@ -502,7 +793,13 @@ impl SystemInfo {
             // This is a fallback. It includes all the available memory, not just the one available
             // for the users.
             let mut total_memory: u64 = 0;
-            get_sys_value(&self.hw_physical_memory, &mut total_memory);
+            if !get_sys_value(&self.hw_physical_memory, &mut total_memory) {
+                crate::utils::report_error(
+                    crate::RefreshErrorKind::Unavailable,
+                    "memory",
+                    std::io::Error::last_os_error(),
+                );
+            }
             total_memory
         }
     }
@@ -546,6 +843,34 @@ impl SystemInfo {
         }
     }
 
+    // Unlike `get_free_memory`, it only returns the pages which aren't used at all.
+    fn get_free_pages_memory(&self) -> u64 {
+        let mut free_mem: u64 = 0;
+
+        unsafe {
+            get_sys_value(&self.virtual_free_count, &mut free_mem);
+        }
+        free_mem.saturating_mul(self.page_size as _)
+    }
+
+    fn get_memory_breakdown(&self) -> MemoryBreakdown {
+        let mut buffers_mem: u64 = 0;
+        let mut cached_mem: u64 = 0;
+
+        unsafe {
+            get_sys_value(&self.buf_space, &mut buffers_mem);
+            get_sys_value(&self.virtual_cache_count, &mut cached_mem);
+        }
+        MemoryBreakdown {
+            buffers: buffers_mem,
+            // The ZFS ARC doesn't go through the page cache.
+            cached: cached_mem
+                .saturating_mul(self.page_size as _)
+                .saturating_add(self.zfs.arc_size().unwrap_or(0)),
+            ..MemoryBreakdown::default()
+        }
+    }
+
     #[allow(clippy::collapsible_if)] // I keep as is for readability reasons.
     unsafe fn get_proc_missing_info(&mut self, kproc: &libc::kinfo_proc, proc_: &mut ProcessInner) {
         if self.procstat.is_null() {
@@ -0,0 +1,52 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use super::utils::get_sys_value_by_name;
+use crate::{ThermalZone, TripPoint, TripPointKind};
+
+use libc::c_int;
+
+/// Reads a `hw.acpi.thermal` temperature, given in tenth of degrees Kelvin (`-1` if the trip
+/// point isn't defined).
+fn get_temperature(name: &str) -> Option<f32> {
+    let mut value: c_int = -1;
+    unsafe {
+        if !get_sys_value_by_name(format!("{name}\0").as_bytes(), &mut value) || value < 0 {
+            return None;
+        }
+    }
+    Some((value - 2732) as f32 / 10.)
+}
+
+pub(crate) fn get_thermal_zones(zones: &mut Vec<ThermalZone>) {
+    for index in 0.. {
+        let prefix = format!("hw.acpi.thermal.tz{index}");
+        let temperature = match get_temperature(&format!("{prefix}.temperature")) {
+            Some(temperature) => temperature,
+            None => break,
+        };
+        let mut trip_points = Vec::new();
+        let mut add_trip_point = |kind, name: &str| {
+            if let Some(temperature) = get_temperature(&format!("{prefix}.{name}")) {
+                trip_points.push(TripPoint {
+                    kind,
+                    temperature,
+                    hysteresis: None,
+                    cooling_devices: Vec::new(),
+                });
+            }
+        };
+        add_trip_point(TripPointKind::Critical, "_CRT");
+        add_trip_point(TripPointKind::Hot, "_HOT");
+        add_trip_point(TripPointKind::Passive, "_PSV");
+        // ACPI defines up to 10 active cooling levels.
+        for level in 0..10 {
+            add_trip_point(TripPointKind::Active, &format!("_AC{level}"));
+        }
+        zones.push(ThermalZone {
+            name: format!("tz{index}"),
+            kind: None,
+            temperature: Some(temperature),
+            trip_points,
+        });
+    }
+}
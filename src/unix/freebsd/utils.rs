@@ -62,6 +62,10 @@ pub unsafe fn init_mib(name: &[u8], mib: &mut [c_int]) {
 }
 
 pub(crate) fn boot_time() -> u64 {
+    boot_timeval().map(|tv| tv.tv_sec as _).unwrap_or(0)
+}
+
+pub(crate) fn boot_timeval() -> Option<timeval> {
     let mut boot_time = timeval {
         tv_sec: 0,
         tv_usec: 0,
@@ -78,9 +82,9 @@ pub(crate) fn boot_time() -> u64 {
             0,
         ) < 0
         {
-            0
+            None
         } else {
-            boot_time.tv_sec as _
+            Some(boot_time)
         }
     }
 }
@@ -191,6 +195,26 @@ pub(crate) fn get_sys_value_str_by_name(name: &[u8]) -> Option<String> {
     }
 }
 
+/// Returns the value of the given kernel environment variable, like the `smbios.*` ones set by
+/// the loader.
+pub(crate) fn get_kenv(name: &[u8]) -> Option<String> {
+    // Values are at most `KENV_MVALLEN` (128) bytes long.
+    let mut buf = [0 as libc::c_char; 129];
+
+    unsafe {
+        if libc::kenv(
+            libc::KENV_GET,
+            name.as_ptr() as *const c_char,
+            buf.as_mut_ptr(),
+            buf.len() as _,
+        ) < 0
+        {
+            return None;
+        }
+    }
+    c_buf_to_string(&buf)
+}
+
 pub(crate) fn get_system_info(mib: &[c_int], default: Option<&str>) -> Option<String> {
     let mut size = 0;
 
@@ -0,0 +1,159 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::KernelParameter;
+
+use libc::{c_int, c_void};
+use std::ffi::CString;
+use std::mem::size_of;
+
+// `CTL_MAXNAME` is `12` on Apple targets and `24` on FreeBSD.
+const MAX_MIB_LEN: usize = 24;
+
+pub(crate) fn get_kernel_parameter(name: &str) -> Option<KernelParameter> {
+    let c_name = CString::new(name).ok()?;
+    let mut mib = [0 as c_int; MAX_MIB_LEN];
+    let mut mib_len = MAX_MIB_LEN as libc::size_t;
+
+    unsafe {
+        if libc::sysctlnametomib(c_name.as_ptr(), mib.as_mut_ptr(), &mut mib_len) != 0 {
+            sysinfo_debug!("unknown kernel parameter `{}`", name);
+            return None;
+        }
+        let mib = &mib[..mib_len as usize];
+        let (kind, format) = get_format(mib)?;
+        let data = get_data(mib)?;
+        decode(kind, &format, &data)
+    }
+}
+
+/// Returns the kind and the format of the parameter, using the undocumented `{0, 4}` "oidfmt"
+/// node (which is what `sysctl(8)` uses too).
+unsafe fn get_format(mib: &[c_int]) -> Option<(c_int, String)> {
+    let mut query = Vec::with_capacity(mib.len() + 2);
+    query.extend_from_slice(&[0, 4]);
+    query.extend_from_slice(mib);
+    let mut buf = [0u8; 1_024];
+    let mut len = buf.len() as libc::size_t;
+    if libc::sysctl(
+        query.as_mut_ptr(),
+        query.len() as _,
+        buf.as_mut_ptr() as *mut c_void,
+        &mut len,
+        std::ptr::null_mut(),
+        0,
+    ) != 0
+        || (len as usize) < size_of::<u32>()
+    {
+        return None;
+    }
+    let kind = u32::from_ne_bytes([buf[0], buf[1], buf[2], buf[3]]) as c_int & libc::CTLTYPE;
+    let format = &buf[size_of::<u32>()..len as usize];
+    let end = format.iter().position(|c| *c == 0).unwrap_or(format.len());
+    Some((kind, String::from_utf8_lossy(&format[..end]).into_owned()))
+}
+
+unsafe fn get_data(mib: &[c_int]) -> Option<Vec<u8>> {
+    let mut len = 0;
+    if libc::sysctl(
+        mib.as_ptr() as *mut _,
+        mib.len() as _,
+        std::ptr::null_mut(),
+        &mut len,
+        std::ptr::null_mut(),
+        0,
+    ) != 0
+    {
+        return None;
+    }
+    // The value might grow between both calls.
+    let mut data = vec![0u8; len as usize + 64];
+    let mut len = data.len() as libc::size_t;
+    if libc::sysctl(
+        mib.as_ptr() as *mut _,
+        mib.len() as _,
+        data.as_mut_ptr() as *mut c_void,
+        &mut len,
+        std::ptr::null_mut(),
+        0,
+    ) != 0
+    {
+        return None;
+    }
+    data.truncate(len as usize);
+    Some(data)
+}
+
+/// Returns the size in bytes of the integers and whether they are signed.
+#[cfg(target_os = "freebsd")]
+fn integer_layout(kind: c_int, _format: &str) -> Option<(usize, bool)> {
+    Some(match kind {
+        libc::CTLTYPE_INT | libc::CTLTYPE_S32 => (4, true),
+        libc::CTLTYPE_UINT | libc::CTLTYPE_U32 => (4, false),
+        libc::CTLTYPE_LONG => (size_of::<libc::c_long>(), true),
+        libc::CTLTYPE_ULONG => (size_of::<libc::c_ulong>(), false),
+        libc::CTLTYPE_S64 => (8, true),
+        libc::CTLTYPE_U64 => (8, false),
+        libc::CTLTYPE_S8 => (1, true),
+        libc::CTLTYPE_U8 => (1, false),
+        libc::CTLTYPE_S16 => (2, true),
+        libc::CTLTYPE_U16 => (2, false),
+        _ => return None,
+    })
+}
+
+/// Returns the size in bytes of the integers and whether they are signed.
+///
+/// Apple only has one kind for all integers smaller than 64 bits, the format tells them apart.
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+fn integer_layout(kind: c_int, format: &str) -> Option<(usize, bool)> {
+    let unsigned = format.ends_with('U');
+    Some(match kind {
+        libc::CTLTYPE_INT if format.starts_with('L') => (size_of::<libc::c_long>(), !unsigned),
+        libc::CTLTYPE_INT => (4, !unsigned),
+        libc::CTLTYPE_QUAD => (8, !unsigned),
+        _ => return None,
+    })
+}
+
+fn decode(kind: c_int, format: &str, data: &[u8]) -> Option<KernelParameter> {
+    if kind == libc::CTLTYPE_STRING {
+        let end = data.iter().position(|c| *c == 0).unwrap_or(data.len());
+        return Some(KernelParameter::String(
+            String::from_utf8_lossy(&data[..end]).trim().to_owned(),
+        ));
+    }
+    // Nodes and opaque values (structs) can't be represented.
+    let (size, signed) = integer_layout(kind, format)?;
+    if data.is_empty() || data.len() % size != 0 {
+        return None;
+    }
+    let mut integers = data
+        .chunks_exact(size)
+        .map(|chunk| {
+            let mut bytes = [0u8; 8];
+            if cfg!(target_endian = "little") {
+                bytes[..size].copy_from_slice(chunk);
+                // Sign extension.
+                if signed && chunk[size - 1] & 0x80 != 0 {
+                    bytes[size..].fill(0xff);
+                }
+            } else {
+                bytes[8 - size..].copy_from_slice(chunk);
+                if signed && chunk[0] & 0x80 != 0 {
+                    bytes[..8 - size].fill(0xff);
+                }
+            }
+            let value = u64::from_ne_bytes(bytes);
+            if signed {
+                Some(value as i64)
+            } else {
+                i64::try_from(value).ok()
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if integers.len() == 1 {
+        Some(KernelParameter::Integer(integers.remove(0)))
+    } else {
+        Some(KernelParameter::Integers(integers))
+    }
+}
@@ -0,0 +1,177 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::get_all_data;
+use crate::{Battery, BatteryState};
+
+use std::fs::read_dir;
+use std::path::Path;
+use std::time::Duration;
+
+pub(crate) fn get_batteries(batteries: &mut Vec<Battery>) {
+    let dir = match read_dir("/sys/class/power_supply") {
+        Ok(dir) => dir,
+        Err(_) => return,
+    };
+    for entry in dir.flatten() {
+        let path = entry.path();
+        let read = |file: &str| {
+            get_all_data(path.join(file), 64)
+                .ok()
+                .map(|data| data.trim().to_owned())
+        };
+        if let Some(battery) = parse_battery(&path, read) {
+            batteries.push(battery);
+        }
+    }
+}
+
+/// Builds a battery from the `power_supply` attributes returned by `read`.
+///
+/// Energies are either given in µWh (`energy_*`) or, depending on the driver, as charges in µAh
+/// (`charge_*`), in which case they are converted using the design voltage.
+fn parse_battery<F: Fn(&str) -> Option<String>>(path: &Path, read: F) -> Option<Battery> {
+    if read("type").as_deref() != Some("Battery") {
+        return None;
+    }
+    // Batteries of peripherals (like a mouse) don't power the system.
+    if read("scope").as_deref() == Some("Device") {
+        return None;
+    }
+    let number = |file: &str| read(file).and_then(|value| value.parse::<u64>().ok());
+    let voltage = number("voltage_min_design").or_else(|| number("voltage_now"));
+    // Returns the value in mWh.
+    let energy = |name: &str| {
+        number(&format!("energy_{name}"))
+            .map(|value| value / 1_000)
+            .or_else(|| {
+                let charge = number(&format!("charge_{name}"))?;
+                Some(charge * voltage? / 1_000_000_000)
+            })
+    };
+    let energy_now = energy("now");
+    let full_capacity = energy("full");
+    let state = match read("status").as_deref() {
+        Some("Charging") => BatteryState::Charging,
+        Some("Discharging") => BatteryState::Discharging,
+        Some("Full") => BatteryState::Full,
+        Some("Not charging") => BatteryState::NotCharging,
+        _ => BatteryState::Unknown,
+    };
+    let charge = match (number("capacity"), energy_now, full_capacity) {
+        (Some(capacity), _, _) => capacity as f32,
+        (None, Some(now), Some(full)) if full != 0 => now as f32 * 100. / full as f32,
+        _ => return None,
+    };
+    // In mW.
+    let power = number("power_now").map(|power| power / 1_000).or_else(|| {
+        let current = number("current_now")?;
+        Some(current * voltage? / 1_000_000_000)
+    });
+    let hours = |energy: Option<u64>| -> Option<Duration> {
+        match (energy, power) {
+            (Some(energy), Some(power)) if power != 0 => {
+                Some(Duration::from_secs(energy * 3_600 / power))
+            }
+            _ => None,
+        }
+    };
+    let time_to_empty = match state {
+        BatteryState::Discharging => number("time_to_empty_now")
+            .map(Duration::from_secs)
+            .or_else(|| hours(energy_now)),
+        _ => None,
+    };
+    let missing = full_capacity
+        .zip(energy_now)
+        .map(|(full, now)| full.saturating_sub(now));
+    let time_to_full = match state {
+        BatteryState::Charging => number("time_to_full_now")
+            .map(Duration::from_secs)
+            .or_else(|| hours(missing)),
+        _ => None,
+    };
+    Some(Battery {
+        name: path.file_name()?.to_string_lossy().into_owned(),
+        state,
+        charge,
+        energy: energy_now,
+        full_capacity,
+        design_capacity: energy("full_design"),
+        // Drivers which don't know it report `0`.
+        cycle_count: number("cycle_count")
+            .filter(|count| *count != 0)
+            .map(|count| count as u32),
+        time_to_empty,
+        time_to_full,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_battery;
+    use crate::BatteryState;
+
+    use std::collections::HashMap;
+    use std::path::Path;
+    use std::time::Duration;
+
+    fn parse(attributes: &[(&str, &str)]) -> Option<crate::Battery> {
+        let attributes: HashMap<_, _> = attributes.iter().copied().collect();
+        parse_battery(Path::new("/sys/class/power_supply/BAT0"), |file| {
+            attributes.get(file).map(|value| value.to_string())
+        })
+    }
+
+    #[test]
+    fn check_parse_battery_energy() {
+        let battery = parse(&[
+            ("type", "Battery"),
+            ("status", "Discharging"),
+            ("capacity", "50"),
+            ("energy_now", "25000000"),
+            ("energy_full", "50000000"),
+            ("energy_full_design", "60000000"),
+            ("power_now", "10000000"),
+            ("cycle_count", "0"),
+        ])
+        .unwrap();
+        assert_eq!(battery.name, "BAT0");
+        assert_eq!(battery.state, BatteryState::Discharging);
+        assert_eq!(battery.charge, 50.);
+        assert_eq!(battery.energy, Some(25_000));
+        assert_eq!(battery.full_capacity, Some(50_000));
+        assert_eq!(battery.design_capacity, Some(60_000));
+        assert_eq!(battery.cycle_count, None);
+        // 25 Wh at 10 W.
+        assert_eq!(battery.time_to_empty, Some(Duration::from_secs(9_000)));
+        assert_eq!(battery.time_to_full, None);
+    }
+
+    #[test]
+    fn check_parse_battery_charge() {
+        let battery = parse(&[
+            ("type", "Battery"),
+            ("status", "Charging"),
+            ("charge_now", "2000000"),
+            ("charge_full", "4000000"),
+            ("voltage_min_design", "10000000"),
+            ("current_now", "1000000"),
+            ("cycle_count", "12"),
+        ])
+        .unwrap();
+        assert_eq!(battery.state, BatteryState::Charging);
+        assert_eq!(battery.charge, 50.);
+        assert_eq!(battery.energy, Some(20_000));
+        assert_eq!(battery.full_capacity, Some(40_000));
+        assert_eq!(battery.design_capacity, None);
+        assert_eq!(battery.cycle_count, Some(12));
+        // 20 Wh left to charge at 10 W.
+        assert_eq!(battery.time_to_full, Some(Duration::from_secs(7_200)));
+    }
+
+    #[test]
+    fn check_parse_not_battery() {
+        assert!(parse(&[("type", "Mains"), ("online", "1")]).is_none());
+        assert!(parse(&[("type", "Battery"), ("scope", "Device"), ("capacity", "5")]).is_none());
+    }
+}
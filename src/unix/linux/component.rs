@@ -4,12 +4,14 @@
 //
 // Values in /sys/class/hwmonN are `c_long` or `c_ulong`
 // transposed to rust we only read `u32` or `i32` values.
-use crate::Component;
+use crate::sys::nvml::Nvml;
+use crate::{Component, ComponentKind};
 
 use std::collections::HashMap;
 use std::fs::{read_dir, File};
 use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 #[derive(Default)]
 pub(crate) struct ComponentInner {
@@ -31,6 +33,9 @@ pub(crate) struct ComponentInner {
     /// - Read in: `temp[1-*]_input`.
     /// - Unit: read as millidegree Celsius converted to Celsius.
     temperature: Option<f32>,
+    /// Temperature current value, as read in `temp[1-*]_input` (in millidegree Celsius), or as
+    /// returned by NVML (in degree Celsius).
+    raw_value: Option<i64>,
     /// Maximum value computed by `sysinfo`.
     max: Option<f32>,
     /// Max threshold provided by the chip/kernel
@@ -56,6 +61,7 @@ pub(crate) struct ComponentInner {
     /// - 4: thermistor
     /// - 5: AMD AMDSI
     /// - 6: Intel PECI
+    ///
     /// Not all types are supported by all chips
     sensor_type: Option<TermalSensorType>,
     /// Component Label
@@ -71,6 +77,9 @@ pub(crate) struct ComponentInner {
     /// this temperature channel is being used for, and user-space
     /// doesn't. In all other cases, the label is provided by user-space.
     label: String,
+    /// Identifier of the sensor, built from the path of the `hwmon` device (which, unlike
+    /// `/sys/class/hwmon/hwmonN`, doesn't change across reboots) and the channel.
+    id: String,
     // TODO: not used now.
     // Historical minimum temperature
     // - Read in:`temp[1-*]_lowest
@@ -91,6 +100,8 @@ pub(crate) struct ComponentInner {
     input_file: Option<PathBuf>,
     /// `temp[1-*]_highest file` to read if available highest value.
     highest_file: Option<PathBuf>,
+    /// NVIDIA GPU (and its index) read through NVML instead of `hwmon`.
+    nvml: Option<(Arc<Nvml>, u32)>,
 }
 
 // Read arbitrary data from sysfs.
@@ -155,6 +166,7 @@ enum TermalSensorType {
     IntelPECI,
     /// Not all types are supported by all chips so we keep space for
     /// unknown sensors.
+    #[allow(dead_code)]
     Unknown(u8),
 }
 
@@ -182,7 +194,9 @@ fn fill_component(component: &mut ComponentInner, item: &str, folder: &Path, fil
                 read_number_from_file::<u8>(&hwmon_file).map(TermalSensorType::from)
         }
         "input" => {
-            let temperature = get_temperature_from_file(&hwmon_file);
+            let raw_value = read_number_from_file::<i32>(&hwmon_file);
+            let temperature = convert_temp_celsius(raw_value);
+            component.raw_value = raw_value.map(i64::from);
             component.input_file = Some(hwmon_file);
             component.temperature = temperature;
             // Maximum know try to get it from `highest` if not available
@@ -208,7 +222,45 @@ fn fill_component(component: &mut ComponentInner, item: &str, folder: &Path, fil
     }
 }
 
+/// Returns the kind of the component from the name of its `hwmon` chip.
+fn get_component_kind(name: &str) -> ComponentKind {
+    match name {
+        "coretemp" | "k8temp" | "k10temp" | "zenpower" | "via_cputemp" => ComponentKind::Cpu,
+        "amdgpu" | "radeon" | "nouveau" | "i915" | "xe" => ComponentKind::Gpu,
+        "nvme" | "drivetemp" => ComponentKind::Storage,
+        // Thermal zones of SoCs, like `cpu_thermal` or `gpu0_thermal`.
+        _ if name.starts_with("cpu") => ComponentKind::Cpu,
+        _ if name.starts_with("gpu") => ComponentKind::Gpu,
+        // Batteries are named after their `power_supply` device, like `BAT0`.
+        _ if name.starts_with("BAT") || name.contains("battery") => ComponentKind::Battery,
+        _ => ComponentKind::Other,
+    }
+}
+
 impl ComponentInner {
+    /// Returns the NVIDIA GPUs, which are only available through NVML with the proprietary
+    /// driver.
+    fn from_nvml(components: &mut Vec<Component>, nvml: &Arc<Nvml>) {
+        for (index, name) in nvml.gpus() {
+            let temperature = nvml.temperature(index);
+            components.push(Component {
+                inner: ComponentInner {
+                    id: nvml
+                        .uuid(index)
+                        .map(|uuid| format!("nvml/{uuid}"))
+                        .unwrap_or_else(|| format!("nvml/{index}")),
+                    label: name,
+                    temperature,
+                    raw_value: temperature.map(|temperature| temperature as i64),
+                    max: temperature,
+                    threshold_critical: nvml.critical(index),
+                    nvml: Some((Arc::clone(nvml), index)),
+                    ..Default::default()
+                },
+            });
+        }
+    }
+
     /// Read out `hwmon` info (hardware monitor) from `folder`
     /// to get values' path to be used on refresh as well as files containing `max`,
     /// `critical value` and `label`. Then we store everything into `components`.
@@ -235,9 +287,21 @@ impl ComponentInner {
     /// Kernel hwmon API: https://www.kernel.org/doc/html/latest/hwmon/hwmon-kernel-api.html
     /// DriveTemp kernel API: https://docs.kernel.org/gpu/amdgpu/thermal.html#hwmon-interfaces
     /// Amdgpu hwmon interface: https://www.kernel.org/doc/html/latest/hwmon/drivetemp.html
-    fn from_hwmon(components: &mut Vec<Component>, folder: &Path) -> Option<()> {
+    ///
+    /// `sys_devices` is the folder containing the devices, normally `/sys/devices`.
+    fn from_hwmon(
+        components: &mut Vec<Component>,
+        folder: &Path,
+        sys_devices: &Path,
+    ) -> Option<()> {
         let dir = read_dir(folder).ok()?;
         let mut matchings: HashMap<u32, Component> = HashMap::with_capacity(10);
+        // For example `pci0000:00/0000:00:18.3` for `k10temp`.
+        let device = folder.join("device").canonicalize().ok().and_then(|path| {
+            path.strip_prefix(sys_devices)
+                .ok()
+                .map(|path| path.display().to_string())
+        });
         for entry in dir.flatten() {
             let entry = entry.path();
             let filename = entry.file_name().and_then(|x| x.to_str()).unwrap_or("");
@@ -266,6 +330,10 @@ impl ComponentInner {
                 // So let's pretend we have a unique label!
                 // See the table in `Component::label` documentation for the table detail.
                 c.inner.label = c.inner.format_label("temp", id);
+                c.inner.id = format!(
+                    "{}/temp{id}",
+                    device.as_deref().unwrap_or(c.inner.name.as_str())
+                );
                 c
             })
             // Remove components without `tempN_input` file termal. `Component` doesn't support this kind of sensors yet
@@ -311,11 +379,43 @@ impl ComponentInner {
         &self.label
     }
 
+    pub(crate) fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub(crate) fn lower_limit(&self) -> Option<f32> {
+        self.threshold_min
+    }
+
+    pub(crate) fn upper_limit(&self) -> Option<f32> {
+        self.threshold_max
+    }
+
+    pub(crate) fn raw_value(&self) -> Option<i64> {
+        self.raw_value
+    }
+
+    pub(crate) fn kind(&self) -> ComponentKind {
+        if self.nvml.is_some() {
+            return ComponentKind::Gpu;
+        }
+        get_component_kind(&self.name)
+    }
+
     pub(crate) fn refresh(&mut self) {
-        let current = self
+        if let Some((ref nvml, index)) = self.nvml {
+            if let Some(temperature) = nvml.temperature(index) {
+                self.temperature = Some(temperature);
+                self.raw_value = Some(temperature as i64);
+                self.max = Some(self.max.map_or(temperature, |max| max.max(temperature)));
+            }
+            return;
+        }
+        let raw_value = self
             .input_file
             .as_ref()
-            .and_then(|file| get_temperature_from_file(file.as_path()));
+            .and_then(|file| read_number_from_file::<i32>(file.as_path()));
+        let current = convert_temp_celsius(raw_value);
         // tries to read out kernel highest if not compute something from temperature.
         let max = self
             .highest_file
@@ -328,22 +428,28 @@ impl ComponentInner {
             });
         self.max = max;
         self.temperature = current;
+        self.raw_value = raw_value.map(i64::from);
     }
 }
 
 pub(crate) struct ComponentsInner {
     components: Vec<Component>,
+    nvml: Option<Arc<Nvml>>,
 }
 
 impl ComponentsInner {
     pub(crate) fn new() -> Self {
         Self {
             components: Vec::with_capacity(4),
+            nvml: None,
         }
     }
 
     pub(crate) fn from_vec(components: Vec<Component>) -> Self {
-        Self { components }
+        Self {
+            components,
+            nvml: None,
+        }
     }
 
     pub(crate) fn into_vec(self) -> Vec<Component> {
@@ -360,7 +466,16 @@ impl ComponentsInner {
 
     pub(crate) fn refresh_list(&mut self) {
         self.components.clear();
-        if let Ok(dir) = read_dir(Path::new("/sys/class/hwmon/")) {
+        let dir = read_dir(Path::new("/sys/class/hwmon/"));
+        if let Err(ref _e) = dir {
+            sysinfo_debug!("failed to list the components: {:?}", _e);
+            crate::utils::report_io_error(
+                "/sys/class/hwmon/",
+                _e,
+                crate::RefreshErrorKind::Unavailable,
+            );
+        }
+        if let Ok(dir) = dir {
             for entry in dir.flatten() {
                 let entry = entry.path();
                 if !entry.is_dir()
@@ -372,8 +487,109 @@ impl ComponentsInner {
                 {
                     continue;
                 }
-                ComponentInner::from_hwmon(&mut self.components, &entry);
+                ComponentInner::from_hwmon(&mut self.components, &entry, Path::new("/sys/devices"));
             }
         }
+        if self.nvml.is_none() {
+            self.nvml = Nvml::load();
+        }
+        if let Some(ref nvml) = self.nvml {
+            ComponentInner::from_nvml(&mut self.components, nvml);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{get_component_kind, ComponentInner};
+    use crate::ComponentKind;
+
+    use std::fs;
+    use std::os::unix::fs::symlink;
+    use std::path::Path;
+
+    fn add_hwmon(folder: &Path, files: &[(&str, &str)]) {
+        fs::create_dir_all(folder).expect("failed to create hwmon");
+        for (file, content) in files {
+            fs::write(folder.join(file), content).expect("failed to write file");
+        }
+    }
+
+    #[test]
+    fn check_component_kind() {
+        assert_eq!(get_component_kind("k10temp"), ComponentKind::Cpu);
+        assert_eq!(get_component_kind("cpu_thermal"), ComponentKind::Cpu);
+        assert_eq!(get_component_kind("amdgpu"), ComponentKind::Gpu);
+        assert_eq!(get_component_kind("gpu0_thermal"), ComponentKind::Gpu);
+        assert_eq!(get_component_kind("nvme"), ComponentKind::Storage);
+        assert_eq!(get_component_kind("BAT0"), ComponentKind::Battery);
+        assert_eq!(get_component_kind("acpitz"), ComponentKind::Other);
+    }
+
+    #[test]
+    fn check_hwmon_ids() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let sys_devices = dir.path().canonicalize().unwrap().join("devices");
+
+        // A PCI device, whose id doesn't depend on the `hwmonN` numbering.
+        let k10temp = sys_devices.join("pci0000:00/0000:00:18.3/hwmon/hwmon3");
+        add_hwmon(
+            &k10temp,
+            &[
+                ("name", "k10temp\n"),
+                ("temp1_input", "45125\n"),
+                ("temp1_label", "Tctl\n"),
+                ("temp3_input", "40000\n"),
+                // Without `tempN_input`, it isn't listed.
+                ("temp4_label", "Tccd2\n"),
+            ],
+        );
+        symlink("../..", k10temp.join("device")).expect("failed to create link");
+        // A virtual device, without `device` link.
+        let acpitz = sys_devices.join("virtual/thermal/thermal_zone0/hwmon0");
+        add_hwmon(&acpitz, &[("name", "acpitz\n"), ("temp1_input", "27800\n")]);
+
+        let mut components = Vec::new();
+        ComponentInner::from_hwmon(&mut components, &k10temp, &sys_devices);
+        ComponentInner::from_hwmon(&mut components, &acpitz, &sys_devices);
+        let mut ids = components
+            .iter()
+            .map(|c| (c.inner.id(), c.inner.label(), c.inner.temperature()))
+            .collect::<Vec<_>>();
+        ids.sort_by(|a, b| a.0.cmp(b.0));
+        assert_eq!(
+            ids,
+            [
+                ("acpitz/temp1", "acpitz temp1", 27.8),
+                ("pci0000:00/0000:00:18.3/temp1", "k10temp Tctl", 45.125),
+                ("pci0000:00/0000:00:18.3/temp3", "k10temp temp3", 40.),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_hwmon_limits() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let nvme = dir.path().join("hwmon1");
+        add_hwmon(
+            &nvme,
+            &[
+                ("name", "nvme\n"),
+                ("temp1_input", "38850\n"),
+                ("temp1_min", "-273150\n"),
+                ("temp1_max", "81850\n"),
+                ("temp1_crit", "84850\n"),
+            ],
+        );
+
+        let mut components = Vec::new();
+        ComponentInner::from_hwmon(&mut components, &nvme, dir.path());
+        assert_eq!(components.len(), 1);
+        let nvme = &components[0].inner;
+        assert_eq!(nvme.raw_value(), Some(38_850));
+        assert_eq!(nvme.temperature(), 38.85);
+        assert_eq!(nvme.lower_limit(), Some(-273.15));
+        assert_eq!(nvme.upper_limit(), Some(81.85));
+        assert_eq!(nvme.critical(), Some(84.85));
     }
 }
@@ -0,0 +1,350 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::netlink::{find_attribute, parse_attributes, read_u32, read_u64, NetlinkSocket};
+use crate::{Connection, ConnectionProtocol, ConnectionSummary, ConnectionTraffic, Pid, TcpState};
+
+use std::collections::HashMap;
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::Path;
+
+// From `linux/sock_diag.h` and `linux/inet_diag.h`.
+const NETLINK_SOCK_DIAG: libc::c_int = 4;
+const SOCK_DIAG_BY_FAMILY: u16 = 20;
+const INET_DIAG_INFO: u16 = 2;
+// Size of `struct inet_diag_msg`.
+const INET_DIAG_MSG_LEN: usize = 72;
+// Offset of `idiag_inode` in `struct inet_diag_msg`.
+const INET_DIAG_MSG_INODE: usize = 68;
+const TCP_ESTABLISHED: u32 = 1;
+
+const SOCKET_TABLES: [(&str, ConnectionProtocol); 4] = [
+    ("tcp", ConnectionProtocol::Tcp),
+    ("tcp6", ConnectionProtocol::Tcp),
+    ("udp", ConnectionProtocol::Udp),
+    ("udp6", ConnectionProtocol::Udp),
+];
+
+pub(crate) fn get_connections(connections: &mut Vec<Connection>) {
+    let mut sockets = Vec::new();
+    for (file, protocol) in SOCKET_TABLES {
+        if let Ok(content) = fs::read_to_string(Path::new("/proc/net").join(file)) {
+            sockets.extend(parse_socket_table(&content, protocol));
+        }
+    }
+    let owners = get_socket_owners(Path::new("/proc"));
+    let traffic = get_tcp_traffic();
+    connections.extend(sockets.into_iter().map(|(mut connection, inode)| {
+        connection.pid = owners.get(&inode).copied();
+        if connection.protocol == ConnectionProtocol::Tcp {
+            connection.traffic = traffic.get(&inode).copied();
+        }
+        connection
+    }));
+}
+
+pub(crate) fn get_connection_summary() -> ConnectionSummary {
+    let mut summary = ConnectionSummary::default();
+    for (file, protocol) in SOCKET_TABLES {
+        if let Ok(content) = fs::read_to_string(Path::new("/proc/net").join(file)) {
+            count_sockets(&content, protocol, &mut summary);
+        }
+    }
+    summary
+}
+
+/// Returns the traffic statistics of the established TCP connections, associated to the inode
+/// of the sockets.
+///
+/// They are retrieved with `sock_diag` which returns the `tcp_info` of every socket, and not
+/// only of the ones owned by the current process like the `TCP_INFO` socket option.
+fn get_tcp_traffic() -> HashMap<u64, ConnectionTraffic> {
+    let mut traffic = HashMap::new();
+    let mut socket = match NetlinkSocket::new(NETLINK_SOCK_DIAG) {
+        Some(socket) => socket,
+        None => return traffic,
+    };
+    for family in [libc::AF_INET, libc::AF_INET6] {
+        // `struct inet_diag_req_v2`, the socket ID is left empty to get all sockets.
+        let mut request = [0; 56];
+        request[0] = family as u8;
+        request[1] = libc::IPPROTO_TCP as u8;
+        request[2] = 1 << (INET_DIAG_INFO - 1);
+        request[4..8].copy_from_slice(&(1u32 << TCP_ESTABLISHED).to_ne_bytes());
+        let answers = match socket.request(SOCK_DIAG_BY_FAMILY, true, &request) {
+            Some(answers) => answers,
+            None => {
+                sysinfo_debug!("sock_diag request failed");
+                continue;
+            }
+        };
+        traffic.extend(
+            answers
+                .iter()
+                .filter_map(|answer| parse_diag_message(answer)),
+        );
+    }
+    traffic
+}
+
+/// Parses a `struct inet_diag_msg` followed by its attributes.
+fn parse_diag_message(message: &[u8]) -> Option<(u64, ConnectionTraffic)> {
+    let inode = read_u32(message.get(INET_DIAG_MSG_INODE..)?)?;
+    let attributes = parse_attributes(message.get(INET_DIAG_MSG_LEN..)?);
+    let info = find_attribute(&attributes, INET_DIAG_INFO)?;
+    Some((u64::from(inode), parse_tcp_info(info)?))
+}
+
+/// Parses a `struct tcp_info`, whose size depends on the kernel version.
+fn parse_tcp_info(info: &[u8]) -> Option<ConnectionTraffic> {
+    let retransmitted_segments = read_u32(info.get(100..)?)?;
+    let bytes_received = read_u64(info.get(128..)?)?;
+    // `tcpi_bytes_sent` was added in Linux 4.19, before that only the number of acknowledged
+    // bytes is available.
+    let bytes_sent = info
+        .get(200..)
+        .and_then(read_u64)
+        .or_else(|| read_u64(info.get(120..)?))?;
+    Some(ConnectionTraffic {
+        bytes_sent,
+        bytes_received,
+        retransmitted_segments: u64::from(retransmitted_segments),
+    })
+}
+
+/// Parses an address like `0100007F:0035`. The IP address is made of 32-bit words in host byte
+/// order, and the port is in host byte order.
+fn parse_address(address: &str) -> Option<SocketAddr> {
+    let (ip, port) = address.split_once(':')?;
+    let port = u16::from_str_radix(port, 16).ok()?;
+    let ip = match ip.len() {
+        8 => IpAddr::V4(Ipv4Addr::from(
+            u32::from_str_radix(ip, 16).ok()?.to_ne_bytes(),
+        )),
+        32 => {
+            let mut bytes = [0; 16];
+            for (i, chunk) in bytes.chunks_mut(4).enumerate() {
+                let word = u32::from_str_radix(ip.get(i * 8..i * 8 + 8)?, 16).ok()?;
+                chunk.copy_from_slice(&word.to_ne_bytes());
+            }
+            IpAddr::V6(Ipv6Addr::from(bytes))
+        }
+        _ => return None,
+    };
+    Some(SocketAddr::new(ip, port))
+}
+
+// Values from `include/net/tcp_states.h`.
+fn parse_tcp_state(state: &str) -> Option<TcpState> {
+    Some(match u8::from_str_radix(state, 16).ok()? {
+        1 => TcpState::Established,
+        2 => TcpState::SynSent,
+        // `TCP_NEW_SYN_RECV` is used for the requests which are not yet full sockets.
+        3 | 12 => TcpState::SynReceived,
+        4 => TcpState::FinWait1,
+        5 => TcpState::FinWait2,
+        6 => TcpState::TimeWait,
+        7 => TcpState::Closed,
+        8 => TcpState::CloseWait,
+        9 => TcpState::LastAck,
+        10 => TcpState::Listen,
+        11 => TcpState::Closing,
+        _ => return None,
+    })
+}
+
+/// Parses the content of `/proc/net/{tcp,tcp6,udp,udp6}` and returns the sockets with their
+/// inode.
+fn parse_socket_table(content: &str, protocol: ConnectionProtocol) -> Vec<(Connection, u64)> {
+    content
+        .lines()
+        // The first line contains the column names.
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let local_address = parse_address(fields.nth(1)?)?;
+            let remote_address = parse_address(fields.next()?)?;
+            let state = fields.next()?;
+            let inode = fields.nth(5)?.parse().ok()?;
+            Some((
+                Connection {
+                    protocol,
+                    local_address,
+                    remote_address: Some(remote_address)
+                        .filter(|addr| !addr.ip().is_unspecified() || addr.port() != 0),
+                    state: match protocol {
+                        ConnectionProtocol::Tcp => parse_tcp_state(state),
+                        ConnectionProtocol::Udp => None,
+                    },
+                    pid: None,
+                    traffic: None,
+                },
+                inode,
+            ))
+        })
+        .collect()
+}
+
+/// Counts the sockets in the content of `/proc/net/{tcp,tcp6,udp,udp6}` without parsing their
+/// addresses.
+fn count_sockets(content: &str, protocol: ConnectionProtocol, summary: &mut ConnectionSummary) {
+    // The first line contains the column names.
+    for line in content.lines().skip(1) {
+        if let Some(state) = line.split_whitespace().nth(3) {
+            summary.add(protocol, parse_tcp_state(state));
+        }
+    }
+}
+
+/// Returns the processes owning the sockets, associated to the inode of the sockets.
+fn get_socket_owners(proc_path: &Path) -> HashMap<u64, Pid> {
+    let mut owners = HashMap::new();
+    let entries = match fs::read_dir(proc_path) {
+        Ok(entries) => entries,
+        Err(_) => return owners,
+    };
+    for entry in entries.flatten() {
+        let pid = match entry.file_name().to_str().and_then(|pid| pid.parse().ok()) {
+            Some(pid) => Pid(pid),
+            None => continue,
+        };
+        // It fails for the processes of other users if we're not root.
+        let fds = match fs::read_dir(entry.path().join("fd")) {
+            Ok(fds) => fds,
+            Err(_) => continue,
+        };
+        for fd in fds.flatten() {
+            let inode = fs::read_link(fd.path()).ok().and_then(|link| {
+                link.to_str()?
+                    .strip_prefix("socket:[")?
+                    .strip_suffix(']')?
+                    .parse()
+                    .ok()
+            });
+            if let Some(inode) = inode {
+                owners.entry(inode).or_insert(pid);
+            }
+        }
+    }
+    owners
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        count_sockets, get_socket_owners, parse_diag_message, parse_socket_table, parse_tcp_info,
+    };
+    use crate::{ConnectionProtocol, ConnectionSummary, ConnectionTraffic, Pid, TcpState};
+
+    use std::fs;
+    use std::os::unix::fs::symlink;
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn socket_table() {
+        let tcp = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:0035 00000000:0000 0A 00000000:00000000 00:00000000 00000000   101        0 21420 1 0000000000000000 100 0 0 10 5
+   1: 0F02000A:9A3C 5DB8D822:01BB 01 00000000:00000000 02:00000F73 00000000  1000        0 78906 2 0000000000000000 20 4 30 10 -1";
+        let sockets = parse_socket_table(tcp, ConnectionProtocol::Tcp);
+        assert_eq!(sockets.len(), 2);
+
+        let (listen, inode) = &sockets[0];
+        assert_eq!(*inode, 21420);
+        assert_eq!(listen.local_address, "127.0.0.1:53".parse().unwrap());
+        assert_eq!(listen.remote_address, None);
+        assert_eq!(listen.state, Some(TcpState::Listen));
+
+        let (established, inode) = &sockets[1];
+        assert_eq!(*inode, 78906);
+        assert_eq!(
+            established.local_address,
+            "10.0.2.15:39484".parse().unwrap()
+        );
+        assert_eq!(
+            established.remote_address,
+            Some("34.216.184.93:443".parse().unwrap())
+        );
+        assert_eq!(established.state, Some(TcpState::Established));
+
+        let udp6 = "  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops
+  123: 00000000000000000000000001000000:0222 00000000000000000000000000000000:0000 07 00000000:00000000 00:00000000 00000000     0        0 16598 2 0000000000000000 0";
+        let sockets = parse_socket_table(udp6, ConnectionProtocol::Udp);
+        assert_eq!(sockets.len(), 1);
+        let (udp, inode) = &sockets[0];
+        assert_eq!(*inode, 16598);
+        assert_eq!(udp.local_address, "[::1]:546".parse().unwrap());
+        assert_eq!(udp.remote_address, None);
+        assert_eq!(udp.state, None);
+    }
+
+    #[test]
+    fn socket_summary() {
+        let tcp = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:0035 00000000:0000 0A 00000000:00000000 00:00000000 00000000   101        0 21420 1 0000000000000000 100 0 0 10 5
+   1: 0F02000A:9A3C 5DB8D822:01BB 01 00000000:00000000 02:00000F73 00000000  1000        0 78906 2 0000000000000000 20 4 30 10 -1
+   2: 0F02000A:9A3E 5DB8D822:01BB 06 00000000:00000000 03:00001750 00000000     0        0 0 3 0000000000000000
+   3: 0F02000A:9A40 5DB8D822:01BB 08 00000000:00000000 00:00000000 00000000  1000        0 78910 1 0000000000000000 20 4 30 10 -1";
+        let udp = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops
+  123: 00000000:0044 00000000:0000 07 00000000:00000000 00:00000000 00000000     0        0 16598 2 0000000000000000 0";
+        let mut summary = ConnectionSummary::default();
+        count_sockets(tcp, ConnectionProtocol::Tcp, &mut summary);
+        count_sockets(udp, ConnectionProtocol::Udp, &mut summary);
+        assert_eq!(
+            summary,
+            ConnectionSummary {
+                tcp_established: 1,
+                tcp_listening: 1,
+                tcp_time_wait: 1,
+                tcp_total: 4,
+                udp: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn socket_owners() {
+        let proc_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let fd_dir = proc_dir.path().join("42/fd");
+        fs::create_dir_all(&fd_dir).expect("failed to create directory");
+        symlink("socket:[21420]", fd_dir.join("3")).expect("failed to create symlink");
+        symlink("/dev/null", fd_dir.join("4")).expect("failed to create symlink");
+        fs::create_dir_all(proc_dir.path().join("net")).expect("failed to create directory");
+
+        let owners = get_socket_owners(proc_dir.path());
+        assert_eq!(owners.len(), 1);
+        assert_eq!(owners.get(&21420), Some(&Pid(42)));
+    }
+
+    #[test]
+    fn tcp_info() {
+        let mut info = vec![0; 232];
+        info[100..104].copy_from_slice(&3u32.to_ne_bytes());
+        info[120..128].copy_from_slice(&1_000u64.to_ne_bytes());
+        info[128..136].copy_from_slice(&2_000u64.to_ne_bytes());
+        info[200..208].copy_from_slice(&1_500u64.to_ne_bytes());
+        assert_eq!(
+            parse_tcp_info(&info),
+            Some(ConnectionTraffic {
+                bytes_sent: 1_500,
+                bytes_received: 2_000,
+                retransmitted_segments: 3,
+            })
+        );
+        // Before Linux 4.19, only the acknowledged bytes are available.
+        assert_eq!(
+            parse_tcp_info(&info[..160]).map(|traffic| traffic.bytes_sent),
+            Some(1_000)
+        );
+        assert_eq!(parse_tcp_info(&info[..104]), None);
+
+        let mut message = vec![0; 72];
+        message[68..72].copy_from_slice(&78906u32.to_ne_bytes());
+        message.extend_from_slice(&(4 + info.len() as u16).to_ne_bytes());
+        message.extend_from_slice(&2u16.to_ne_bytes());
+        message.extend_from_slice(&info);
+        assert_eq!(
+            parse_diag_message(&message).map(|(inode, _)| inode),
+            Some(78906)
+        );
+    }
+}
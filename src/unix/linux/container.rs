@@ -0,0 +1,297 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::services::get_cgroup_pids;
+use crate::sys::utils::get_all_data;
+use crate::{Container, ContainerRuntime, Pid};
+
+use std::fs::read_dir;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+// The directories of the container in the cgroup hierarchies.
+enum CGroup {
+    Unified(PathBuf),
+    V1 { cpuacct: PathBuf, memory: PathBuf },
+}
+
+pub(crate) struct ContainerInner {
+    id: String,
+    runtime: ContainerRuntime,
+    cpu_usage: f32,
+    memory: u64,
+    memory_limit: Option<u64>,
+    pids: Vec<Pid>,
+    // The CPU time used by the container (in nanoseconds) and when it was read.
+    cpu_time: Option<(u64, Instant)>,
+    cgroup: CGroup,
+}
+
+impl ContainerInner {
+    fn new(id: String, runtime: ContainerRuntime, cgroup: CGroup) -> Self {
+        Self {
+            id,
+            runtime,
+            cpu_usage: 0.,
+            memory: 0,
+            memory_limit: None,
+            pids: Vec::new(),
+            cpu_time: None,
+            cgroup,
+        }
+    }
+
+    pub(crate) fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub(crate) fn runtime(&self) -> &ContainerRuntime {
+        &self.runtime
+    }
+
+    pub(crate) fn cpu_usage(&self) -> f32 {
+        self.cpu_usage
+    }
+
+    pub(crate) fn memory(&self) -> u64 {
+        self.memory
+    }
+
+    pub(crate) fn memory_limit(&self) -> Option<u64> {
+        self.memory_limit
+    }
+
+    pub(crate) fn pids(&self) -> &[Pid] {
+        &self.pids
+    }
+
+    fn refresh(&mut self) {
+        let read = |file: PathBuf| {
+            get_all_data(file, 64)
+                .ok()
+                .and_then(|data| data.trim().parse::<u64>().ok())
+        };
+        let (cpu_time, procs_dir) = match self.cgroup {
+            CGroup::Unified(ref dir) => {
+                self.memory = read(dir.join("memory.current")).unwrap_or(0);
+                // It is `max` if there is no limit.
+                self.memory_limit = read(dir.join("memory.max"));
+                let cpu_time = get_all_data(dir.join("cpu.stat"), 1_024)
+                    .ok()
+                    .and_then(|stat| parse_cpu_stat(&stat));
+                (cpu_time, dir)
+            }
+            CGroup::V1 {
+                ref cpuacct,
+                ref memory,
+            } => {
+                self.memory = read(memory.join("memory.usage_in_bytes")).unwrap_or(0);
+                // Without a limit, it is the highest page-aligned value of an `i64`.
+                self.memory_limit =
+                    read(memory.join("memory.limit_in_bytes")).filter(|limit| *limit < 1 << 62);
+                (read(cpuacct.join("cpuacct.usage")), memory)
+            }
+        };
+
+        let mut pids = Vec::new();
+        get_cgroup_pids(procs_dir, &mut pids);
+        pids.sort_unstable();
+        pids.dedup();
+        self.pids = pids.into_iter().map(Pid::from).collect();
+
+        let now = Instant::now();
+        self.cpu_usage = match (self.cpu_time, cpu_time) {
+            (Some((old_cpu_time, old_now)), Some(cpu_time)) => {
+                let elapsed = now.duration_since(old_now).as_nanos() as f64;
+                if elapsed > 0. {
+                    (cpu_time.saturating_sub(old_cpu_time) as f64 / elapsed * 100.) as f32
+                } else {
+                    0.
+                }
+            }
+            _ => 0.,
+        };
+        self.cpu_time = cpu_time.map(|cpu_time| (cpu_time, now));
+    }
+}
+
+pub(crate) struct ContainersInner {
+    containers: Vec<Container>,
+}
+
+impl ContainersInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            containers: Vec::new(),
+        }
+    }
+
+    pub(crate) fn list(&self) -> &[Container] {
+        &self.containers
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Container] {
+        &mut self.containers
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        for container in &mut self.containers {
+            container.inner.refresh();
+        }
+    }
+
+    pub(crate) fn refresh_list(&mut self) {
+        let root = Path::new(CGROUP_ROOT);
+        let unified = root.join("cgroup.controllers").exists();
+        let mut found = Vec::new();
+        if unified {
+            find_containers(root, root, &mut found);
+        } else {
+            find_containers(&root.join("memory"), &root.join("memory"), &mut found);
+        }
+
+        // The containers which were already listed are kept to compute their CPU usage.
+        let mut old = std::mem::take(&mut self.containers);
+        for (runtime, id, path) in found {
+            let cgroup = if unified {
+                CGroup::Unified(root.join(&path))
+            } else {
+                CGroup::V1 {
+                    cpuacct: root.join("cpuacct").join(&path),
+                    memory: root.join("memory").join(&path),
+                }
+            };
+            let mut inner = match old.iter().position(|container| container.inner.id == id) {
+                Some(pos) => {
+                    let mut inner = old.swap_remove(pos).inner;
+                    inner.cgroup = cgroup;
+                    inner
+                }
+                None => ContainerInner::new(id, runtime, cgroup),
+            };
+            inner.refresh();
+            self.containers.push(Container { inner });
+        }
+    }
+}
+
+/// Walks the cgroup hierarchy from `dir`, collecting the runtime, the ID and the path (relative
+/// to `root`) of the containers' cgroups.
+fn find_containers(root: &Path, dir: &Path, found: &mut Vec<(ContainerRuntime, String, PathBuf)>) {
+    let entries = match read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    let parent = dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    for entry in entries.flatten() {
+        if !entry.file_type().map_or(false, |kind| kind.is_dir()) {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let path = entry.path();
+        match parse_container_cgroup(&name, &parent) {
+            // The processes of a container can be in sub-cgroups, so they aren't walked.
+            Some((runtime, id)) => {
+                if let Ok(path) = path.strip_prefix(root) {
+                    found.push((runtime, id.to_owned(), path.to_path_buf()));
+                }
+            }
+            None => find_containers(root, &path, found),
+        }
+    }
+}
+
+/// Returns the runtime and the ID of the container from the name of its cgroup and the name of
+/// its parent, like `docker-<id>.scope` with the systemd cgroup driver or `docker/<id>` with
+/// the cgroupfs driver.
+fn parse_container_cgroup<'a>(name: &'a str, parent: &str) -> Option<(ContainerRuntime, &'a str)> {
+    let (runtime, id) = match name.strip_suffix(".scope") {
+        Some(scope) => {
+            if let Some(id) = scope.strip_prefix("docker-") {
+                (ContainerRuntime::Docker, id)
+            } else if let Some(id) = scope.strip_prefix("cri-containerd-") {
+                (ContainerRuntime::Other("containerd".to_owned()), id)
+            } else {
+                (ContainerRuntime::Podman, scope.strip_prefix("libpod-")?)
+            }
+        }
+        None => match parent {
+            "docker" => (ContainerRuntime::Docker, name),
+            "libpod_parent" => (
+                ContainerRuntime::Podman,
+                name.strip_prefix("libpod-").unwrap_or(name),
+            ),
+            _ => return None,
+        },
+    };
+    // It also excludes the scopes of the monitors of the containers, like `libpod-conmon-<id>`.
+    if id.len() == 64 && id.bytes().all(|c| c.is_ascii_hexdigit()) {
+        Some((runtime, id))
+    } else {
+        None
+    }
+}
+
+/// Returns the CPU time (in nanoseconds) from the content of the `cpu.stat` file.
+fn parse_cpu_stat(stat: &str) -> Option<u64> {
+    stat.lines().find_map(|line| {
+        let usage = line.strip_prefix("usage_usec ")?;
+        usage.trim().parse::<u64>().ok().map(|usage| usage * 1_000)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_container_cgroup, parse_cpu_stat};
+    use crate::ContainerRuntime;
+
+    #[test]
+    fn check_parse_container_cgroup() {
+        let id = "3f2a9c0d4b7e8f1a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192";
+        assert_eq!(
+            parse_container_cgroup(&format!("docker-{id}.scope"), "system.slice"),
+            Some((ContainerRuntime::Docker, id))
+        );
+        assert_eq!(
+            parse_container_cgroup(id, "docker"),
+            Some((ContainerRuntime::Docker, id))
+        );
+        assert_eq!(
+            parse_container_cgroup(
+                &format!("cri-containerd-{id}.scope"),
+                "kubepods-besteffort.slice"
+            ),
+            Some((ContainerRuntime::Other("containerd".to_owned()), id))
+        );
+        assert_eq!(
+            parse_container_cgroup(&format!("libpod-{id}.scope"), "user.slice"),
+            Some((ContainerRuntime::Podman, id))
+        );
+        assert_eq!(
+            parse_container_cgroup(&format!("libpod-{id}"), "libpod_parent"),
+            Some((ContainerRuntime::Podman, id))
+        );
+        assert_eq!(
+            parse_container_cgroup(&format!("libpod-conmon-{id}.scope"), "user.slice"),
+            None
+        );
+        assert_eq!(
+            parse_container_cgroup("docker.service", "system.slice"),
+            None
+        );
+        assert_eq!(parse_container_cgroup(id, "system.slice"), None);
+    }
+
+    #[test]
+    fn check_parse_cpu_stat() {
+        assert_eq!(
+            parse_cpu_stat("usage_usec 1234\nuser_usec 1000\nsystem_usec 234\n"),
+            Some(1_234_000)
+        );
+        assert_eq!(parse_cpu_stat("user_usec 1000\n"), None);
+    }
+}
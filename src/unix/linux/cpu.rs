@@ -3,12 +3,13 @@
 #![allow(clippy::too_many_arguments)]
 
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{BufRead, BufReader, Read};
-use std::time::Instant;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
-use crate::sys::utils::to_u64;
-use crate::{Cpu, CpuRefreshKind};
+use crate::sys::utils::{get_all_data, to_u64};
+use crate::{Cpu, CpuActivity, CpuIdleState, CpuRefreshKind, CpuTimes};
 
 macro_rules! to_str {
     ($e:expr) => {
@@ -24,7 +25,14 @@ pub(crate) struct CpusWrapper {
     /// The reason behind this is to avoid calling the `update_cpus` more than necessary.
     /// For example when running `refresh_all` or `refresh_specifics`.
     need_cpus_update: bool,
-    got_cpu_frequency: bool,
+    /// `temp*_input` hwmon file of each CPU, computed the first time the temperature is
+    /// refreshed.
+    temperature_files: Option<Vec<Option<PathBuf>>>,
+    activity: CpuActivity,
+    /// Last time `activity` was updated, used to compute the rates.
+    last_activity_update: Option<Instant>,
+    /// Last time the idle states were refreshed, used to compute their residency.
+    last_idle_states_update: Option<Instant>,
     /// This field is needed to prevent updating when not enough time passed since last update.
     last_update: Option<Instant>,
 }
@@ -46,13 +54,15 @@ impl CpusWrapper {
                     0,
                     0,
                     0,
-                    String::new(),
-                    String::new(),
+                    CpuIdentification::default(),
                 ),
             },
             cpus: Vec::with_capacity(4),
             need_cpus_update: true,
-            got_cpu_frequency: false,
+            temperature_files: None,
+            activity: CpuActivity::default(),
+            last_activity_update: None,
+            last_idle_states_update: None,
             last_update: None,
         }
     }
@@ -74,8 +84,8 @@ impl CpusWrapper {
             .unwrap_or(true);
 
         let first = self.cpus.is_empty();
-        let mut vendors_brands = if first {
-            get_vendor_id_and_brand()
+        let mut identifications = if first {
+            get_cpu_identifications()
         } else {
             HashMap::new()
         };
@@ -88,6 +98,11 @@ impl CpusWrapper {
                 Ok(f) => f,
                 Err(_e) => {
                     sysinfo_debug!("failed to retrieve CPU information: {:?}", _e);
+                    crate::utils::report_io_error(
+                        "/proc/stat",
+                        &_e,
+                        crate::RefreshErrorKind::Unavailable,
+                    );
                     return;
                 }
             };
@@ -96,6 +111,8 @@ impl CpusWrapper {
             self.need_cpus_update = false;
             let mut i: usize = 0;
             let mut it = buf.split(b'\n');
+            // Number of interrupts, soft interrupts and context switches.
+            let mut activity = [0; 3];
 
             if first || refresh_kind.cpu_usage() {
                 if let Some(Ok(line)) = it.next() {
@@ -125,15 +142,13 @@ impl CpusWrapper {
                 if first || !only_update_global_cpu {
                     while let Some(Ok(line)) = it.next() {
                         if &line[..3] != b"cpu" {
+                            read_activity_line(&line, &mut activity);
                             break;
                         }
 
                         let mut parts = line.split(|x| *x == b' ').filter(|s| !s.is_empty());
                         if first {
-                            let (vendor_id, brand) = match vendors_brands.remove(&i) {
-                                Some((vendor_id, brand)) => (vendor_id, brand),
-                                None => (String::new(), String::new()),
-                            };
+                            let identification = identifications.remove(&i).unwrap_or_default();
                             self.cpus.push(Cpu {
                                 inner: CpuInner::new_with_values(
                                     to_str!(parts.next().unwrap_or(&[])),
@@ -148,8 +163,7 @@ impl CpusWrapper {
                                     parts.next().map(to_u64).unwrap_or(0),
                                     parts.next().map(to_u64).unwrap_or(0),
                                     0,
-                                    vendor_id,
-                                    brand,
+                                    identification,
                                 ),
                             });
                         } else {
@@ -171,6 +185,19 @@ impl CpusWrapper {
                         i += 1;
                     }
                 }
+                // The interrupts and context switches are after the CPUs lines.
+                for line in it.flatten() {
+                    read_activity_line(&line, &mut activity);
+                }
+                let now = Instant::now();
+                self.activity.update(
+                    activity[0],
+                    activity[1],
+                    activity[2],
+                    self.last_activity_update
+                        .map(|last_update| now.duration_since(last_update)),
+                );
+                self.last_activity_update = Some(now);
             }
         }
 
@@ -192,6 +219,7 @@ impl CpusWrapper {
             }
 
             #[cfg(not(feature = "multithread"))]
+            #[allow(clippy::ptr_arg)]
             fn iter_mut<'a>(val: &'a mut Vec<Cpu>) -> std::slice::IterMut<'a, Cpu> {
                 val.iter_mut()
             }
@@ -199,10 +227,66 @@ impl CpusWrapper {
             // `get_cpu_frequency` is very slow, so better run it in parallel.
             iter_mut(&mut self.cpus)
                 .enumerate()
-                .for_each(|(pos, proc_)| proc_.inner.frequency = get_cpu_frequency(pos));
+                .for_each(|(pos, proc_)| {
+                    proc_.inner.frequency = get_cpu_frequency(pos);
+                    proc_.inner.refresh_scaling_info(pos);
+                });
+        }
+
+        if refresh_kind.temperature() {
+            let nb_cpus = self.cpus.len();
+            let files = self
+                .temperature_files
+                .get_or_insert_with(|| get_cpu_temperature_files(nb_cpus));
+            for (cpu, file) in self.cpus.iter_mut().zip(files.iter()) {
+                cpu.inner.temperature = file.as_deref().and_then(read_temperature);
+            }
+        }
+
+        if refresh_kind.idle_states() {
+            let now = Instant::now();
+            let elapsed = self
+                .last_idle_states_update
+                .map(|last_update| now.duration_since(last_update));
+            for (pos, cpu) in self.cpus.iter_mut().enumerate() {
+                cpu.inner.refresh_idle_states(pos, elapsed);
+            }
+            self.last_idle_states_update = Some(now);
+        }
+    }
 
-            self.got_cpu_frequency = true;
+    /// Only reads the first line of `/proc/stat` to update the global CPU usage.
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        let mut line = Vec::new();
+        if let Err(_e) =
+            File::open("/proc/stat").and_then(|f| BufReader::new(f).read_until(b'\n', &mut line))
+        {
+            sysinfo_debug!("failed to retrieve CPU information: {:?}", _e);
+            return;
+        }
+        if !line.starts_with(b"cpu ") {
+            return;
+        }
+        if line.last() == Some(&b'\n') {
+            line.pop();
         }
+        let mut parts = line.split(|x| *x == b' ').filter(|s| !s.is_empty());
+        let name = parts.next().unwrap_or(&[]);
+        if self.global_cpu.inner.name.is_empty() {
+            self.global_cpu.inner.name = to_str!(name).to_owned();
+        }
+        self.global_cpu.inner.set(
+            parts.next().map(to_u64).unwrap_or(0),
+            parts.next().map(to_u64).unwrap_or(0),
+            parts.next().map(to_u64).unwrap_or(0),
+            parts.next().map(to_u64).unwrap_or(0),
+            parts.next().map(to_u64).unwrap_or(0),
+            parts.next().map(to_u64).unwrap_or(0),
+            parts.next().map(to_u64).unwrap_or(0),
+            parts.next().map(to_u64).unwrap_or(0),
+            parts.next().map(to_u64).unwrap_or(0),
+            parts.next().map(to_u64).unwrap_or(0),
+        );
     }
 
     pub(crate) fn get_global_raw_times(&self) -> (u64, u64) {
@@ -212,6 +296,10 @@ impl CpusWrapper {
         )
     }
 
+    pub(crate) fn activity(&self) -> CpuActivity {
+        self.activity
+    }
+
     pub(crate) fn len(&self) -> usize {
         self.cpus.len()
     }
@@ -225,6 +313,19 @@ impl CpusWrapper {
     }
 }
 
+/// Reads the `intr`, `softirq` and `ctxt` lines of `/proc/stat`.
+fn read_activity_line(line: &[u8], activity: &mut [u64; 3]) {
+    let mut parts = line.split(|x| *x == b' ').filter(|s| !s.is_empty());
+    let index = match parts.next() {
+        Some(b"intr") => 0,
+        Some(b"softirq") => 1,
+        Some(b"ctxt") => 2,
+        _ => return,
+    };
+    // The first value is the total, the next ones are the values for each interrupt.
+    activity[index] = parts.next().map(to_u64).unwrap_or(0);
+}
+
 /// Struct containing values to compute a CPU usage.
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct CpuValues {
@@ -294,6 +395,28 @@ impl CpuValues {
             .saturating_add(self.softirq)
     }
 
+    /// Converts the values (in clock ticks) into a `CpuTimes`.
+    pub fn times(&self) -> CpuTimes {
+        let clock_ticks = match unsafe { libc::sysconf(libc::_SC_CLK_TCK) } {
+            ticks if ticks > 0 => ticks as u64,
+            _ => 100,
+        };
+        let to_duration =
+            |ticks: u64| Duration::from_millis(ticks.saturating_mul(1_000) / clock_ticks);
+        CpuTimes {
+            user: to_duration(self.user),
+            nice: to_duration(self.nice),
+            system: to_duration(self.system),
+            idle: to_duration(self.idle),
+            iowait: to_duration(self.iowait),
+            irq: to_duration(self.irq),
+            softirq: to_duration(self.softirq),
+            steal: to_duration(self.steal),
+            guest: to_duration(self.guest),
+            guest_nice: to_duration(self.guest_nice),
+        }
+    }
+
     /// Returns total time.
     pub fn total_time(&self) -> u64 {
         self.work_time()
@@ -312,11 +435,24 @@ pub(crate) struct CpuInner {
     new_values: CpuValues,
     pub(crate) name: String,
     cpu_usage: f32,
+    steal_percent: f32,
     total_time: u64,
     old_total_time: u64,
     pub(crate) frequency: u64,
+    governor: Option<String>,
+    available_governors: Vec<String>,
+    scaling_min_frequency: Option<u64>,
+    scaling_max_frequency: Option<u64>,
+    pub(crate) temperature: Option<f32>,
+    idle_states: Vec<CpuIdleState>,
+    /// Time (in microseconds) spent in each idle state at the last refresh.
+    idle_states_times: Vec<u64>,
     pub(crate) vendor_id: String,
     pub(crate) brand: String,
+    family: Option<u32>,
+    model: Option<u32>,
+    stepping: Option<u32>,
+    microcode_revision: Option<u64>,
 }
 
 impl CpuInner {
@@ -333,8 +469,7 @@ impl CpuInner {
         guest: u64,
         guest_nice: u64,
         frequency: u64,
-        vendor_id: String,
-        brand: String,
+        identification: CpuIdentification,
     ) -> Self {
         let mut new_values = CpuValues::new();
         new_values.set(
@@ -345,11 +480,23 @@ impl CpuInner {
             old_values: CpuValues::new(),
             new_values,
             cpu_usage: 0f32,
+            steal_percent: 0f32,
             total_time: 0,
             old_total_time: 0,
             frequency,
-            vendor_id,
-            brand,
+            governor: None,
+            available_governors: Vec::new(),
+            scaling_min_frequency: None,
+            scaling_max_frequency: None,
+            temperature: None,
+            idle_states: Vec::new(),
+            idle_states_times: Vec::new(),
+            vendor_id: identification.vendor_id,
+            brand: identification.brand,
+            family: identification.family,
+            model: identification.model,
+            stepping: identification.stepping,
+            microcode_revision: identification.microcode_revision,
         }
     }
 
@@ -387,12 +534,26 @@ impl CpuInner {
         if self.cpu_usage > 100. {
             self.cpu_usage = 100.; // to prevent the percentage to go above 100%
         }
+        self.steal_percent = min!(self.new_values.steal, self.old_values.steal, 0.)
+            / min!(self.total_time, self.old_total_time, 1.)
+            * 100.;
+        if self.steal_percent > 100. {
+            self.steal_percent = 100.;
+        }
     }
 
     pub(crate) fn cpu_usage(&self) -> f32 {
         self.cpu_usage
     }
 
+    pub(crate) fn set_cpu_usage(&mut self, cpu_usage: f32) {
+        self.cpu_usage = cpu_usage;
+    }
+
+    pub(crate) fn steal_percent(&self) -> f32 {
+        self.steal_percent
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
@@ -402,6 +563,108 @@ impl CpuInner {
         self.frequency
     }
 
+    pub(crate) fn times(&self) -> CpuTimes {
+        self.new_values.times()
+    }
+
+    /// Reads the cpufreq governor and scaling limits of the CPU.
+    fn refresh_scaling_info(&mut self, cpu_core_index: usize) {
+        self.governor = read_cpufreq_value(cpu_core_index, "scaling_governor");
+        // Frequencies are in kHz.
+        self.scaling_min_frequency = read_cpufreq_value(cpu_core_index, "scaling_min_freq")
+            .and_then(|freq| freq.parse::<u64>().ok())
+            .map(|freq| freq / 1000);
+        self.scaling_max_frequency = read_cpufreq_value(cpu_core_index, "scaling_max_freq")
+            .and_then(|freq| freq.parse::<u64>().ok())
+            .map(|freq| freq / 1000);
+        if self.available_governors.is_empty() {
+            if let Some(governors) =
+                read_cpufreq_value(cpu_core_index, "scaling_available_governors")
+            {
+                self.available_governors =
+                    governors.split_whitespace().map(str::to_owned).collect();
+            }
+        }
+    }
+
+    /// Reads the time spent in each `cpuidle` state of the CPU.
+    fn refresh_idle_states(&mut self, cpu_core_index: usize, elapsed: Option<Duration>) {
+        let elapsed = elapsed
+            .map(|elapsed| elapsed.as_micros() as f32)
+            .unwrap_or(0.);
+        for index in 0.. {
+            let state = format!("/sys/devices/system/cpu/cpu{cpu_core_index}/cpuidle/state{index}");
+            let time = match get_all_data(format!("{state}/time"), 32)
+                .ok()
+                .and_then(|time| time.trim().parse::<u64>().ok())
+            {
+                Some(time) => time,
+                None => break,
+            };
+            if index >= self.idle_states.len() {
+                let name = get_all_data(format!("{state}/name"), 32)
+                    .map(|name| name.trim().to_owned())
+                    .unwrap_or_else(|_| format!("state{index}"));
+                self.idle_states.push(CpuIdleState {
+                    name,
+                    residency: 0.,
+                });
+                self.idle_states_times.push(time);
+                continue;
+            }
+            let spent = time.saturating_sub(self.idle_states_times[index]) as f32;
+            self.idle_states[index].residency = if elapsed > 0. {
+                (spent * 100. / elapsed).min(100.)
+            } else {
+                0.
+            };
+            self.idle_states_times[index] = time;
+        }
+    }
+
+    pub(crate) fn idle_states(&self) -> &[CpuIdleState] {
+        &self.idle_states
+    }
+
+    pub(crate) fn governor(&self) -> Option<&str> {
+        self.governor.as_deref()
+    }
+
+    pub(crate) fn available_governors(&self) -> &[String] {
+        &self.available_governors
+    }
+
+    pub(crate) fn scaling_min_frequency(&self) -> Option<u64> {
+        self.scaling_min_frequency
+    }
+
+    pub(crate) fn scaling_max_frequency(&self) -> Option<u64> {
+        self.scaling_max_frequency
+    }
+
+    #[cfg(feature = "cpufreq-write")]
+    pub(crate) fn set_governor(&self, governor: &str) -> bool {
+        // The CPU name (like `cpu0`) is also the name of its sysfs folder.
+        match fs::write(
+            format!(
+                "/sys/devices/system/cpu/{}/cpufreq/scaling_governor",
+                self.name
+            ),
+            governor,
+        ) {
+            Ok(()) => true,
+            Err(_e) => {
+                sysinfo_debug!("failed to set `{}` governor: {:?}", governor, _e);
+                false
+            }
+        }
+    }
+
+    /// Returns the CPU temperature in celsius.
+    pub(crate) fn temperature(&self) -> Option<f32> {
+        self.temperature
+    }
+
     pub(crate) fn vendor_id(&self) -> &str {
         &self.vendor_id
     }
@@ -409,6 +672,22 @@ impl CpuInner {
     pub(crate) fn brand(&self) -> &str {
         &self.brand
     }
+
+    pub(crate) fn family(&self) -> Option<u32> {
+        self.family
+    }
+
+    pub(crate) fn model(&self) -> Option<u32> {
+        self.model
+    }
+
+    pub(crate) fn stepping(&self) -> Option<u32> {
+        self.stepping
+    }
+
+    pub(crate) fn microcode_revision(&self) -> Option<u64> {
+        self.microcode_revision
+    }
 }
 
 pub(crate) fn get_cpu_frequency(cpu_core_index: usize) -> u64 {
@@ -433,19 +712,161 @@ pub(crate) fn get_cpu_frequency(cpu_core_index: usize) -> u64 {
     {
         return 0;
     }
-    let find_cpu_mhz = s.split('\n').find(|line| {
+    get_cpu_frequency_from_cpuinfo(&s, cpu_core_index)
+}
+
+fn get_cpu_frequency_from_cpuinfo(cpuinfo: &str, cpu_core_index: usize) -> u64 {
+    let is_frequency_line = |line: &&str| {
         line.starts_with("cpu MHz\t")
             || line.starts_with("BogoMIPS")
             || line.starts_with("clock\t")
             || line.starts_with("bogomips per cpu")
-    });
+    };
+    // Each core has its own block (separated by an empty line) starting with its
+    // `processor` index. If there is no such block, we use the first frequency we find.
+    let find_cpu_mhz = cpuinfo
+        .split("\n\n")
+        .find(|block| {
+            block
+                .lines()
+                .find(|line| line.starts_with("processor"))
+                .and_then(|line| line.split(':').next_back())
+                .and_then(|val| val.trim().parse::<usize>().ok())
+                == Some(cpu_core_index)
+        })
+        .and_then(|block| block.lines().find(is_frequency_line))
+        .or_else(|| cpuinfo.lines().find(is_frequency_line));
     find_cpu_mhz
-        .and_then(|line| line.split(':').last())
+        .and_then(|line| line.split(':').next_back())
         .and_then(|val| val.replace("MHz", "").trim().parse::<f64>().ok())
         .map(|speed| speed as u64)
         .unwrap_or_default()
 }
 
+fn read_cpufreq_value(cpu_core_index: usize, name: &str) -> Option<String> {
+    let value = get_all_data(
+        format!("/sys/devices/system/cpu/cpu{cpu_core_index}/cpufreq/{name}"),
+        64,
+    )
+    .ok()?;
+    let value = value.trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_owned())
+    }
+}
+
+fn read_temperature(file: &Path) -> Option<f32> {
+    get_all_data(file, 16)
+        .ok()?
+        .trim()
+        .parse::<i32>()
+        .ok()
+        // The value is in millidegree Celsius.
+        .map(|temperature| temperature as f32 / 1000.)
+}
+
+fn read_cpu_topology_value(cpu: usize, name: &str) -> u32 {
+    get_all_data(
+        format!("/sys/devices/system/cpu/cpu{cpu}/topology/{name}"),
+        16,
+    )
+    .ok()
+    .and_then(|s| s.trim().parse().ok())
+    .unwrap_or(0)
+}
+
+/// Returns the `temp*_input` file to read for each CPU.
+///
+/// On Intel, `coretemp` provides a `Core N` sensor for each physical core (and a
+/// `Package id N` sensor for each package). On AMD, `k10temp` (or `zenpower`) only provides
+/// a sensor for the whole package, so all the CPUs of a package use the same sensor.
+fn get_cpu_temperature_files(nb_cpus: usize) -> Vec<Option<PathBuf>> {
+    let mut hwmons = match fs::read_dir("/sys/class/hwmon") {
+        Ok(dir) => dir
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                let name = get_all_data(path.join("name"), 32).ok()?;
+                let name = name.trim();
+                if name == "coretemp" || name == "k10temp" || name == "zenpower" {
+                    // Sorting on the device path gives us the packages order.
+                    let device = fs::canonicalize(path.join("device")).unwrap_or_default();
+                    Some((device, name.to_owned(), path))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>(),
+        Err(_e) => {
+            sysinfo_debug!("failed to read `/sys/class/hwmon`: {:?}", _e);
+            return vec![None; nb_cpus];
+        }
+    };
+    hwmons.sort_unstable();
+
+    // `(package id, core id)` -> sensor.
+    let mut core_files = HashMap::new();
+    // package id -> sensor.
+    let mut package_files = HashMap::new();
+    for (index, (_, name, path)) in hwmons.into_iter().enumerate() {
+        let mut package_id = index as u32;
+        let mut package_file = None;
+        let mut cores = Vec::new();
+        let entries = match fs::read_dir(&path) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries.flatten() {
+            let file_name = entry.file_name();
+            let file_name = match file_name.to_str() {
+                Some(f) => f,
+                None => continue,
+            };
+            let sensor = match file_name.strip_suffix("_label") {
+                Some(sensor) if sensor.starts_with("temp") => sensor,
+                _ => continue,
+            };
+            let label = match get_all_data(entry.path(), 32) {
+                Ok(label) => label,
+                Err(_) => continue,
+            };
+            let label = label.trim();
+            let input = path.join(format!("{sensor}_input"));
+            if name == "coretemp" {
+                if let Some(id) = label.strip_prefix("Package id ") {
+                    if let Ok(id) = id.parse() {
+                        package_id = id;
+                    }
+                    package_file = Some(input);
+                } else if let Some(Ok(core_id)) = label.strip_prefix("Core ").map(str::parse) {
+                    cores.push((core_id, input));
+                }
+            } else if label == "Tdie" || (label == "Tctl" && package_file.is_none()) {
+                // `Tctl` may have an offset, so `Tdie` is preferred.
+                package_file = Some(input);
+            }
+        }
+        for (core_id, input) in cores {
+            core_files.insert((package_id, core_id), input);
+        }
+        if let Some(package_file) = package_file {
+            package_files.insert(package_id, package_file);
+        }
+    }
+
+    (0..nb_cpus)
+        .map(|cpu| {
+            let package_id = read_cpu_topology_value(cpu, "physical_package_id");
+            let core_id: u32 = read_cpu_topology_value(cpu, "core_id");
+            core_files
+                .get(&(package_id, core_id))
+                .or_else(|| package_files.get(&package_id))
+                .cloned()
+        })
+        .collect()
+}
+
 #[allow(unused_assignments)]
 pub(crate) fn get_physical_core_count() -> Option<usize> {
     let mut s = String::new();
@@ -503,6 +924,38 @@ pub(crate) fn get_physical_core_count() -> Option<usize> {
     Some(core_ids_and_physical_ids.len())
 }
 
+pub(crate) fn get_socket_count() -> Option<usize> {
+    let entries = match fs::read_dir("/sys/devices/system/cpu") {
+        Ok(entries) => entries,
+        Err(_e) => {
+            sysinfo_debug!("failed to read `/sys/devices/system/cpu`: {:?}", _e);
+            return None;
+        }
+    };
+    let packages = entries
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.file_name();
+            let cpu = file_name
+                .to_str()?
+                .strip_prefix("cpu")?
+                .parse::<usize>()
+                .ok()?;
+            get_all_data(
+                format!("/sys/devices/system/cpu/cpu{cpu}/topology/physical_package_id"),
+                16,
+            )
+            .ok()
+            .and_then(|s| s.trim().parse::<i32>().ok())
+        })
+        .collect::<HashSet<_>>();
+    if packages.is_empty() {
+        None
+    } else {
+        Some(packages.len())
+    }
+}
+
 /// Obtain the implementer of this CPU core.
 ///
 /// This has been obtained from util-linux's lscpu implementation, see
@@ -714,7 +1167,18 @@ fn get_arm_part(implementer: u32, part: u32) -> Option<&'static str> {
 }
 
 /// Returns the brand/vendor string for the first CPU (which should be the same for all CPUs).
-pub(crate) fn get_vendor_id_and_brand() -> HashMap<usize, (String, String)> {
+/// Identification of a CPU, read from `/proc/cpuinfo`.
+#[derive(Default)]
+pub(crate) struct CpuIdentification {
+    vendor_id: String,
+    brand: String,
+    family: Option<u32>,
+    model: Option<u32>,
+    stepping: Option<u32>,
+    microcode_revision: Option<u64>,
+}
+
+pub(crate) fn get_cpu_identifications() -> HashMap<usize, CpuIdentification> {
     let mut s = String::new();
     if File::open("/proc/cpuinfo")
         .and_then(|mut f| f.read_to_string(&mut s))
@@ -725,20 +1189,32 @@ pub(crate) fn get_vendor_id_and_brand() -> HashMap<usize, (String, String)> {
 
     fn get_value(s: &str) -> String {
         s.split(':')
-            .last()
+            .next_back()
             .map(|x| x.trim().to_owned())
             .unwrap_or_default()
     }
 
     fn get_hex_value(s: &str) -> u32 {
         s.split(':')
-            .last()
+            .next_back()
             .map(|x| x.trim())
             .filter(|x| x.starts_with("0x"))
             .map(|x| u32::from_str_radix(&x[2..], 16).unwrap())
             .unwrap_or_default()
     }
 
+    fn get_u64_hex_value(s: &str) -> Option<u64> {
+        s.split(':')
+            .next_back()
+            .map(|x| x.trim())
+            .and_then(|x| x.strip_prefix("0x"))
+            .and_then(|x| u64::from_str_radix(x, 16).ok())
+    }
+
+    fn get_u32_value(s: &str) -> Option<u32> {
+        s.split(':').next_back().and_then(|x| x.trim().parse().ok())
+    }
+
     #[inline]
     fn is_new_processor(line: &str) -> bool {
         line.starts_with("processor\t")
@@ -749,48 +1225,55 @@ pub(crate) fn get_vendor_id_and_brand() -> HashMap<usize, (String, String)> {
         index: usize,
         vendor_id: Option<String>,
         brand: Option<String>,
+        family: Option<u32>,
+        model: Option<u32>,
+        stepping: Option<u32>,
+        microcode_revision: Option<u64>,
         implementer: Option<u32>,
+        architecture: Option<u32>,
         part: Option<u32>,
+        revision: Option<u32>,
     }
 
     impl CpuInfo {
-        fn has_all_info(&self) -> bool {
-            (self.brand.is_some() && self.vendor_id.is_some())
-                || (self.implementer.is_some() && self.part.is_some())
-        }
-
-        fn convert(mut self) -> (usize, String, String) {
-            let (vendor_id, brand) = if let (Some(implementer), Some(part)) =
-                (self.implementer.take(), self.part.take())
-            {
-                let vendor_id = get_arm_implementer(implementer).map(String::from);
-                // It's possible to "model name" even with an ARM CPU, so just in case we can't retrieve
-                // the brand from "CPU part", we will then use the value from "model name".
-                //
-                // Example from raspberry pi 3B+:
-                //
-                // ```
-                // model name      : ARMv7 Processor rev 4 (v7l)
-                // CPU implementer : 0x41
-                // CPU part        : 0xd03
-                // ```
-                let brand = get_arm_part(implementer, part)
-                    .map(String::from)
-                    .or_else(|| self.brand.take());
-                (vendor_id, brand)
-            } else {
-                (self.vendor_id.take(), self.brand.take())
-            };
+        fn convert(mut self) -> (usize, CpuIdentification) {
+            let (vendor_id, brand) =
+                if let (Some(implementer), Some(part)) = (self.implementer.take(), self.part) {
+                    let vendor_id = get_arm_implementer(implementer).map(String::from);
+                    // It's possible to "model name" even with an ARM CPU, so just in case we can't retrieve
+                    // the brand from "CPU part", we will then use the value from "model name".
+                    //
+                    // Example from raspberry pi 3B+:
+                    //
+                    // ```
+                    // model name      : ARMv7 Processor rev 4 (v7l)
+                    // CPU implementer : 0x41
+                    // CPU part        : 0xd03
+                    // ```
+                    let brand = get_arm_part(implementer, part)
+                        .map(String::from)
+                        .or_else(|| self.brand.take());
+                    (vendor_id, brand)
+                } else {
+                    (self.vendor_id.take(), self.brand.take())
+                };
             (
                 self.index,
-                vendor_id.unwrap_or_default(),
-                brand.unwrap_or_default(),
+                CpuIdentification {
+                    vendor_id: vendor_id.unwrap_or_default(),
+                    brand: brand.unwrap_or_default(),
+                    // On ARM, there is no family/model/stepping so we use the closest values.
+                    family: self.family.or(self.architecture),
+                    model: self.model.or(self.part),
+                    stepping: self.stepping.or(self.revision),
+                    microcode_revision: self.microcode_revision,
+                },
             )
         }
     }
 
-    let mut cpus: HashMap<usize, (String, String)> = HashMap::new();
-    let mut lines = s.split('\n');
+    let mut cpus: HashMap<usize, CpuIdentification> = HashMap::new();
+    let mut lines = s.split('\n').peekable();
     while let Some(line) = lines.next() {
         if is_new_processor(line) {
             let index = match line
@@ -810,23 +1293,65 @@ pub(crate) fn get_vendor_id_and_brand() -> HashMap<usize, (String, String)> {
                 ..Default::default()
             };
 
-            #[allow(clippy::while_let_on_iterator)]
-            while let Some(line) = lines.next() {
+            while let Some(line) = lines.next_if(|line| !is_new_processor(line)) {
                 if line.starts_with("vendor_id\t") {
                     info.vendor_id = Some(get_value(line));
                 } else if line.starts_with("model name\t") {
                     info.brand = Some(get_value(line));
+                } else if line.starts_with("cpu family\t") {
+                    info.family = get_u32_value(line);
+                } else if line.starts_with("model\t") {
+                    info.model = get_u32_value(line);
+                } else if line.starts_with("stepping\t") {
+                    info.stepping = get_u32_value(line);
+                } else if line.starts_with("microcode\t") {
+                    info.microcode_revision = get_u64_hex_value(line);
                 } else if line.starts_with("CPU implementer\t") {
                     info.implementer = Some(get_hex_value(line));
+                } else if line.starts_with("CPU architecture") {
+                    info.architecture = get_u32_value(line);
                 } else if line.starts_with("CPU part\t") {
                     info.part = Some(get_hex_value(line));
-                } else if info.has_all_info() || is_new_processor(line) {
-                    break;
+                } else if line.starts_with("CPU revision\t") {
+                    info.revision = get_u32_value(line);
                 }
             }
-            let (index, vendor_id, brand) = info.convert();
-            cpus.insert(index, (vendor_id, brand));
+            let (index, identification) = info.convert();
+            cpus.insert(index, identification);
         }
     }
     cpus
 }
+
+#[cfg(test)]
+mod test {
+    use super::get_cpu_frequency_from_cpuinfo;
+
+    #[test]
+    fn check_cpu_frequency_from_cpuinfo() {
+        let cpuinfo = "\
+processor\t: 0
+vendor_id\t: GenuineIntel
+cpu MHz\t\t: 800.012
+
+processor\t: 1
+vendor_id\t: GenuineIntel
+cpu MHz\t\t: 3400.456
+";
+        assert_eq!(get_cpu_frequency_from_cpuinfo(cpuinfo, 0), 800);
+        assert_eq!(get_cpu_frequency_from_cpuinfo(cpuinfo, 1), 3400);
+        // Unknown cores use the first frequency.
+        assert_eq!(get_cpu_frequency_from_cpuinfo(cpuinfo, 4), 800);
+
+        // On some ARM systems, the frequency isn't listed per core.
+        let cpuinfo = "\
+processor\t: 0
+BogoMIPS\t: 108.00
+
+processor\t: 1
+Features\t: fp asimd
+";
+        assert_eq!(get_cpu_frequency_from_cpuinfo(cpuinfo, 1), 108);
+        assert_eq!(get_cpu_frequency_from_cpuinfo("", 0), 0);
+    }
+}
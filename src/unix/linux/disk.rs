@@ -1,14 +1,21 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::sys::storage_pool::get_storage_pools;
 use crate::sys::utils::{get_all_data, to_cpath};
-use crate::{Disk, DiskKind};
+use crate::utils::parse_remote_location;
+use crate::{
+    BlockDevice, BlockDeviceKind, Disk, DiskKind, DiskQuota, DiskRefreshKind, Gid, PhysicalDisk,
+    StoragePool, StoragePoolKind, Uid,
+};
 
 use libc::statvfs;
 use std::ffi::{OsStr, OsString};
-use std::fs;
+use std::fs::{self, File};
 use std::mem;
 use std::os::unix::ffi::OsStrExt;
+use std::os::unix::io::AsRawFd;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 macro_rules! cast {
     ($x:expr) => {
@@ -24,6 +31,19 @@ pub(crate) struct DiskInner {
     total_space: u64,
     available_space: u64,
     is_removable: bool,
+    uuid: Option<String>,
+    vendor: Option<String>,
+    model: Option<String>,
+    serial_number: Option<String>,
+    physical_disk: Option<String>,
+    block_device: Option<BlockDevice>,
+    storage_pool: Option<String>,
+    queue: QueueInfo,
+    is_network: bool,
+    remote_host: Option<String>,
+    remote_path: Option<String>,
+    // `false` if `statvfs` must not be called on this disk.
+    refresh_space: bool,
 }
 
 impl DiskInner {
@@ -55,7 +75,80 @@ impl DiskInner {
         self.is_removable
     }
 
+    pub(crate) fn uuid(&self) -> Option<&str> {
+        self.uuid.as_deref()
+    }
+
+    pub(crate) fn vendor(&self) -> Option<&str> {
+        self.vendor.as_deref()
+    }
+
+    pub(crate) fn model(&self) -> Option<&str> {
+        self.model.as_deref()
+    }
+
+    pub(crate) fn serial_number(&self) -> Option<&str> {
+        self.serial_number.as_deref()
+    }
+
+    pub(crate) fn physical_disk(&self) -> Option<&str> {
+        self.physical_disk.as_deref()
+    }
+
+    pub(crate) fn storage_pool(&self) -> Option<&str> {
+        self.storage_pool.as_deref()
+    }
+
+    pub(crate) fn block_device(&self) -> Option<&BlockDevice> {
+        self.block_device.as_ref()
+    }
+
+    pub(crate) fn is_rotational(&self) -> Option<bool> {
+        self.queue.is_rotational
+    }
+
+    pub(crate) fn logical_sector_size(&self) -> Option<u64> {
+        self.queue.logical_sector_size
+    }
+
+    pub(crate) fn physical_sector_size(&self) -> Option<u64> {
+        self.queue.physical_sector_size
+    }
+
+    pub(crate) fn supports_trim(&self) -> Option<bool> {
+        self.queue.supports_trim
+    }
+
+    pub(crate) fn user_quota(&self, user_id: &Uid) -> Option<DiskQuota> {
+        if self.is_network {
+            return None;
+        }
+        get_quota(&self.device_name, USRQUOTA, user_id.0)
+    }
+
+    pub(crate) fn group_quota(&self, group_id: Gid) -> Option<DiskQuota> {
+        if self.is_network {
+            return None;
+        }
+        get_quota(&self.device_name, GRPQUOTA, group_id.0)
+    }
+
+    pub(crate) fn is_network(&self) -> bool {
+        self.is_network
+    }
+
+    pub(crate) fn remote_host(&self) -> Option<&str> {
+        self.remote_host.as_deref()
+    }
+
+    pub(crate) fn remote_path(&self) -> Option<&str> {
+        self.remote_path.as_deref()
+    }
+
     pub(crate) fn refresh(&mut self) -> bool {
+        if !self.refresh_space {
+            return false;
+        }
         unsafe {
             let mut stat: statvfs = mem::zeroed();
             let mount_point_cpath = to_cpath(&self.mount_point);
@@ -64,6 +157,7 @@ impl DiskInner {
                 self.available_space = cast!(tmp);
                 true
             } else {
+                report_statvfs_error(&self.mount_point);
                 false
             }
         }
@@ -74,14 +168,27 @@ impl crate::DisksInner {
     pub(crate) fn new() -> Self {
         Self {
             disks: Vec::with_capacity(2),
+            physical_disks: Vec::new(),
+            storage_pools: Vec::new(),
         }
     }
 
-    pub(crate) fn refresh_list(&mut self) {
-        get_all_list(
-            &mut self.disks,
-            &get_all_data("/proc/mounts", 16_385).unwrap_or_default(),
-        )
+    pub(crate) fn refresh_list(&mut self, refresh_kind: DiskRefreshKind) {
+        let mounts = get_all_data("/proc/mounts", 16_385).unwrap_or_else(|_e| {
+            sysinfo_debug!("failed to read `/proc/mounts`: {:?}", _e);
+            crate::utils::report_io_error(
+                "/proc/mounts",
+                &_e,
+                crate::RefreshErrorKind::Unavailable,
+            );
+            String::new()
+        });
+        get_all_list(&mut self.disks, &mounts, refresh_kind);
+        self.physical_disks = get_physical_disks();
+        self.storage_pools = get_storage_pools();
+        for disk in self.disks.iter_mut() {
+            disk.inner.storage_pool = find_storage_pool(&disk.inner, &self.storage_pools);
+        }
     }
 
     pub(crate) fn list(&self) -> &[Disk] {
@@ -93,48 +200,180 @@ impl crate::DisksInner {
     }
 }
 
+pub(crate) struct DiskEventsInner {
+    // Polling the mount table returns `POLLPRI` when it changed since the last poll.
+    mounts: File,
+}
+
+impl DiskEventsInner {
+    pub(crate) fn new() -> Option<Self> {
+        match File::open("/proc/self/mounts") {
+            Ok(mounts) => Some(Self { mounts }),
+            Err(_e) => {
+                sysinfo_debug!("failed to open `/proc/self/mounts`: {:?}", _e);
+                None
+            }
+        }
+    }
+
+    pub(crate) fn wait(&mut self, timeout: Option<Duration>) -> bool {
+        let mut fd = libc::pollfd {
+            fd: self.mounts.as_raw_fd(),
+            events: libc::POLLPRI,
+            revents: 0,
+        };
+        let timeout = timeout.map_or(-1, |timeout| {
+            timeout.as_millis().min(libc::c_int::MAX as u128) as libc::c_int
+        });
+        unsafe {
+            retry_eintr!(libc::poll(&mut fd, 1, timeout)) > 0 && fd.revents & libc::POLLPRI != 0
+        }
+    }
+}
+
+fn report_statvfs_error(mount_point: &Path) {
+    let _e = std::io::Error::last_os_error();
+    sysinfo_debug!("`statvfs` failed on {:?}: {:?}", mount_point, _e);
+    crate::utils::report_io_error(
+        mount_point.display(),
+        &_e,
+        crate::RefreshErrorKind::Vanished,
+    );
+}
+
 fn new_disk(
     device_name: &OsStr,
     mount_point: &Path,
     file_system: &OsStr,
     removable_entries: &[PathBuf],
+    uuid_entries: &[(PathBuf, String)],
+    refresh_kind: DiskRefreshKind,
 ) -> Option<Disk> {
+    let is_network = file_system.to_str().map_or(false, is_network_file_system);
+    let refresh_space = !is_network || refresh_kind.network_disks_space();
     let mount_point_cpath = to_cpath(mount_point);
-    let type_ = find_type_for_device_name(device_name);
     let mut total = 0;
     let mut available = 0;
     unsafe {
-        let mut stat: statvfs = mem::zeroed();
-        if retry_eintr!(statvfs(mount_point_cpath.as_ptr() as *const _, &mut stat)) == 0 {
-            let bsize = cast!(stat.f_bsize);
-            let blocks = cast!(stat.f_blocks);
-            let bavail = cast!(stat.f_bavail);
-            total = bsize.saturating_mul(blocks);
-            available = bsize.saturating_mul(bavail);
-        }
-        if total == 0 {
-            return None;
+        if refresh_space {
+            let mut stat: statvfs = mem::zeroed();
+            if retry_eintr!(statvfs(mount_point_cpath.as_ptr() as *const _, &mut stat)) == 0 {
+                let bsize = cast!(stat.f_bsize);
+                let blocks = cast!(stat.f_blocks);
+                let bavail = cast!(stat.f_bavail);
+                total = bsize.saturating_mul(blocks);
+                available = bsize.saturating_mul(bavail);
+            } else {
+                report_statvfs_error(mount_point);
+            }
+            if total == 0 {
+                return None;
+            }
         }
-        let mount_point = mount_point.to_owned();
-        let is_removable = removable_entries
-            .iter()
-            .any(|e| e.as_os_str() == device_name);
-        Some(Disk {
-            inner: DiskInner {
-                type_,
-                device_name: device_name.to_owned(),
-                file_system: file_system.to_owned(),
-                mount_point,
-                total_space: cast!(total),
-                available_space: cast!(available),
-                is_removable,
-            },
-        })
     }
+    let is_removable = removable_entries
+        .iter()
+        .any(|e| e.as_os_str() == device_name);
+    let uuid = if is_network {
+        None
+    } else {
+        find_uuid(device_name, uuid_entries)
+    };
+    // Network disks aren't backed by a local block device.
+    let (type_, vendor, model, serial_number, physical_disk, block_device, queue, remote_location) =
+        if is_network {
+            let remote_location = device_name.to_str().and_then(parse_remote_location);
+            (
+                DiskKind::Unknown(-1),
+                None,
+                None,
+                None,
+                None,
+                None,
+                QueueInfo::default(),
+                remote_location,
+            )
+        } else {
+            let block_device_name = get_block_device_name(device_name);
+            let block_device_path = Path::new("/sys/block/").join(&block_device_name);
+            let (vendor, model, serial_number) =
+                get_device_identity(&block_device_path, Path::new("/run/udev/data"));
+            let block_device = get_block_device(Path::new("/sys/class/block/"), device_name);
+            let physical_disk = if is_physical_block_device(&block_device_path) {
+                block_device_name.to_str().map(|name| name.to_owned())
+            } else {
+                // Stacked devices (like a LUKS container on a partition) can still be backed by
+                // only one physical disk.
+                block_device.as_ref().and_then(|block_device| {
+                    match block_device.physical_disks().as_slice() {
+                        [physical_disk] => Some((*physical_disk).to_owned()),
+                        _ => None,
+                    }
+                })
+            };
+            (
+                find_type_for_block_device(&block_device_path),
+                vendor,
+                model,
+                serial_number,
+                physical_disk,
+                block_device,
+                get_queue_info(&block_device_path),
+                None,
+            )
+        };
+    let (remote_host, remote_path) = match remote_location {
+        Some((host, path)) => (Some(host), Some(path)),
+        None => (None, None),
+    };
+    Some(Disk {
+        inner: DiskInner {
+            type_,
+            device_name: device_name.to_owned(),
+            file_system: file_system.to_owned(),
+            mount_point: mount_point.to_owned(),
+            total_space: cast!(total),
+            available_space: cast!(available),
+            is_removable,
+            uuid,
+            vendor,
+            model,
+            serial_number,
+            physical_disk,
+            block_device,
+            storage_pool: None,
+            queue,
+            is_network,
+            remote_host,
+            remote_path,
+            refresh_space,
+        },
+    })
+}
+
+fn is_network_file_system(file_system: &str) -> bool {
+    matches!(
+        file_system,
+        "nfs" | // nfs2 or nfs3
+        "nfs4" |
+        "cifs" |
+        "smb3" |
+        "smbfs" |
+        "ncpfs" |
+        "afs" |
+        "ceph" |
+        "glusterfs" |
+        "lustre" |
+        "fuse.sshfs" |
+        "fuse.rclone" |
+        "fuse.s3fs" |
+        "fuse.glusterfs"
+    )
 }
 
+/// Returns the name of the device under `/sys/block/` containing the given device.
 #[allow(clippy::manual_range_contains)]
-fn find_type_for_device_name(device_name: &OsStr) -> DiskKind {
+fn get_block_device_name(device_name: &OsStr) -> OsString {
     // The format of devices are as follows:
     //  - device_name is symbolic link in the case of /dev/mapper/
     //     and /dev/root, and the target is corresponding device under
@@ -151,7 +390,7 @@ fn find_type_for_device_name(device_name: &OsStr) -> DiskKind {
     if device_name_path.starts_with("/dev/mapper/") {
         // Recursively solve, for example /dev/dm-0
         if real_path != device_name_path {
-            return find_type_for_device_name(OsStr::new(&real_path));
+            return get_block_device_name(OsStr::new(&real_path));
         }
     } else if device_name_path.starts_with("/dev/sd") || device_name_path.starts_with("/dev/vd") {
         // Turn "sda1" into "sda" or "vda1" into "vda"
@@ -166,7 +405,7 @@ fn find_type_for_device_name(device_name: &OsStr) -> DiskKind {
     } else if device_name_path.starts_with("/dev/root") {
         // Recursively solve, for example /dev/mmcblk0p1
         if real_path != device_name_path {
-            return find_type_for_device_name(OsStr::new(&real_path));
+            return get_block_device_name(OsStr::new(&real_path));
         }
     } else if device_name_path.starts_with("/dev/mmcblk") {
         // Turn "mmcblk0p1" into "mmcblk0"
@@ -180,12 +419,11 @@ fn find_type_for_device_name(device_name: &OsStr) -> DiskKind {
         real_path = real_path.trim_start_matches("/dev/");
     }
 
-    let trimmed: &OsStr = OsStrExt::from_bytes(real_path.as_bytes());
+    OsStr::from_bytes(real_path.as_bytes()).to_owned()
+}
 
-    let path = Path::new("/sys/block/")
-        .to_owned()
-        .join(trimmed)
-        .join("queue/rotational");
+fn find_type_for_block_device(block_device: &Path) -> DiskKind {
+    let path = block_device.join("queue/rotational");
     // Normally, this file only contains '0' or '1' but just in case, we get 8 bytes...
     match get_all_data(path, 8)
         .unwrap_or_default()
@@ -204,7 +442,277 @@ fn find_type_for_device_name(device_name: &OsStr) -> DiskKind {
     }
 }
 
-fn get_all_list(container: &mut Vec<Disk>, content: &str) {
+// Quota types, from `linux/quota.h`.
+const USRQUOTA: u32 = 0;
+const GRPQUOTA: u32 = 1;
+
+#[cfg(not(target_os = "android"))]
+fn get_quota(device_name: &OsStr, quota_type: u32, id: u32) -> Option<DiskQuota> {
+    const Q_GETQUOTA: u32 = 0x80_0007;
+
+    let device = to_cpath(Path::new(device_name));
+    let mut dqblk: libc::dqblk = unsafe { mem::zeroed() };
+    // Equivalent of the `QCMD` macro.
+    let cmd = ((Q_GETQUOTA << 8) | quota_type) as libc::c_int;
+    if unsafe {
+        libc::quotactl(
+            cmd,
+            device.as_ptr() as *const _,
+            id as libc::c_int,
+            &mut dqblk as *mut libc::dqblk as *mut libc::c_char,
+        )
+    } != 0
+    {
+        // It fails with `ESRCH` if quotas aren't enabled on the file system.
+        return None;
+    }
+    Some(quota_from_dqblk(&dqblk))
+}
+
+#[cfg(not(target_os = "android"))]
+fn quota_from_dqblk(dqblk: &libc::dqblk) -> DiskQuota {
+    // Size (in bytes) of the blocks used for space limits.
+    const QIF_DQBLKSIZE: u64 = 1024;
+
+    // `0` means that there is no limit.
+    let limit = |value: u64| if value == 0 { None } else { Some(value) };
+    DiskQuota {
+        used_space: dqblk.dqb_curspace,
+        soft_space_limit: limit(dqblk.dqb_bsoftlimit).map(|l| l.saturating_mul(QIF_DQBLKSIZE)),
+        hard_space_limit: limit(dqblk.dqb_bhardlimit).map(|l| l.saturating_mul(QIF_DQBLKSIZE)),
+        used_inodes: Some(dqblk.dqb_curinodes),
+        soft_inode_limit: limit(dqblk.dqb_isoftlimit),
+        hard_inode_limit: limit(dqblk.dqb_ihardlimit),
+    }
+}
+
+#[cfg(target_os = "android")]
+fn get_quota(_device_name: &OsStr, _quota_type: u32, _id: u32) -> Option<DiskQuota> {
+    None
+}
+
+#[derive(Default)]
+struct QueueInfo {
+    is_rotational: Option<bool>,
+    logical_sector_size: Option<u64>,
+    physical_sector_size: Option<u64>,
+    supports_trim: Option<bool>,
+}
+
+fn get_queue_info(block_device: &Path) -> QueueInfo {
+    let queue = block_device.join("queue");
+    let read = |file: &str| -> Option<u64> {
+        get_all_data(queue.join(file), 32).ok()?.trim().parse().ok()
+    };
+    QueueInfo {
+        is_rotational: read("rotational").map(|rotational| rotational != 0),
+        logical_sector_size: read("logical_block_size").filter(|size| *size != 0),
+        physical_sector_size: read("physical_block_size").filter(|size| *size != 0),
+        // Devices which don't support discarding blocks have a `0` limit.
+        supports_trim: read("discard_max_bytes").map(|max_bytes| max_bytes != 0),
+    }
+}
+
+/// Returns the vendor, the model and the serial number of the given block device.
+///
+/// `udev_data` is the udev database directory, normally `/run/udev/data`.
+fn get_device_identity(
+    block_device: &Path,
+    udev_data: &Path,
+) -> (Option<String>, Option<String>, Option<String>) {
+    let read = |path: PathBuf| -> Option<String> {
+        let value = get_all_data(path, 256).ok()?;
+        let value = value.trim();
+        if value.is_empty() {
+            None
+        } else {
+            Some(value.to_owned())
+        }
+    };
+    // Not all drivers expose these information in sysfs, so we fallback to the udev database.
+    let udev_data = read(block_device.join("dev"))
+        .and_then(|dev| get_all_data(udev_data.join(format!("b{dev}")), 16_384).ok())
+        .unwrap_or_default();
+    let udev_property = |key: &str| -> Option<String> {
+        udev_data.lines().find_map(|line| {
+            let value = line
+                .strip_prefix("E:")?
+                .strip_prefix(key)?
+                .strip_prefix('=')?;
+            Some(value.trim().to_owned()).filter(|value| !value.is_empty())
+        })
+    };
+
+    let device = block_device.join("device");
+    // Some devices (like virtio ones) use this file for their PCI vendor ID, which isn't useful.
+    let vendor = read(device.join("vendor"))
+        .filter(|vendor| !vendor.starts_with("0x"))
+        .or_else(|| udev_property("ID_VENDOR"));
+    let model = read(device.join("model")).or_else(|| udev_property("ID_MODEL"));
+    let serial_number = read(device.join("serial"))
+        .or_else(|| read(block_device.join("serial")))
+        .or_else(|| udev_property("ID_SERIAL_SHORT"));
+    (vendor, model, serial_number)
+}
+
+/// Returns the devices with their file system UUID, which are the names of the links in `by_uuid`
+/// (normally `/dev/disk/by-uuid/`).
+fn get_uuid_entries(by_uuid: &Path) -> Vec<(PathBuf, String)> {
+    match fs::read_dir(by_uuid) {
+        Ok(r) => r
+            .filter_map(|res| {
+                let entry = res.ok()?;
+                let uuid = entry.file_name().to_str()?.to_owned();
+                Some((entry.path().canonicalize().ok()?, uuid))
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn find_uuid(device_name: &OsStr, uuid_entries: &[(PathBuf, String)]) -> Option<String> {
+    let device = fs::canonicalize(device_name).ok()?;
+    uuid_entries
+        .iter()
+        .find(|(path, _)| *path == device)
+        .map(|(_, uuid)| uuid.clone())
+}
+
+fn find_storage_pool(disk: &DiskInner, storage_pools: &[StoragePool]) -> Option<String> {
+    let pool = match disk.file_system.to_str()? {
+        // ZFS datasets are named after their pool (like `tank/home`).
+        "zfs" => {
+            let pool_name = disk.device_name.to_str()?.split('/').next()?;
+            storage_pools
+                .iter()
+                .find(|pool| pool.kind == StoragePoolKind::ZFS && pool.name == pool_name)
+        }
+        "btrfs" => {
+            let device = fs::canonicalize(&disk.device_name).ok()?;
+            let device = device.file_name()?.to_str()?;
+            storage_pools.iter().find(|pool| {
+                pool.kind == StoragePoolKind::Btrfs && pool.devices.iter().any(|d| d == device)
+            })
+        }
+        _ => None,
+    };
+    pool.map(|pool| pool.name.clone())
+}
+
+// Virtual block devices (like loop, zram or device-mapper ones) don't have a `device` entry.
+fn is_physical_block_device(block_device: &Path) -> bool {
+    block_device.join("device").exists()
+}
+
+// Device-mapper and RAID devices can be stacked on each other, but never that deep.
+const MAX_BLOCK_DEVICE_DEPTH: usize = 16;
+
+/// Returns the block device `device_name` refers to, along with the devices it is built on.
+fn get_block_device(sys_class_block: &Path, device_name: &OsStr) -> Option<BlockDevice> {
+    // Devices like `/dev/mapper/*` or `/dev/root` are symbolic links.
+    let real_path = fs::canonicalize(device_name).ok()?;
+    get_block_device_layer(sys_class_block, real_path.file_name()?.to_str()?, 0)
+}
+
+fn get_block_device_layer(sys_class_block: &Path, name: &str, depth: usize) -> Option<BlockDevice> {
+    let path = sys_class_block.join(name);
+    if depth > MAX_BLOCK_DEVICE_DEPTH || !path.exists() {
+        return None;
+    }
+    let mut backing_devices = if path.join("partition").exists() {
+        // A partition is built on the block device containing it, which is its parent in sysfs.
+        fs::canonicalize(&path)
+            .ok()
+            .and_then(|path| Some(path.parent()?.file_name()?.to_str()?.to_owned()))
+            .and_then(|parent| get_block_device_layer(sys_class_block, &parent, depth + 1))
+            .into_iter()
+            .collect()
+    } else {
+        // Device-mapper and RAID devices list the devices they are built on in `slaves`.
+        fs::read_dir(path.join("slaves"))
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| {
+                        let entry = entry.ok()?;
+                        get_block_device_layer(
+                            sys_class_block,
+                            entry.file_name().to_str()?,
+                            depth + 1,
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    };
+    backing_devices.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    Some(BlockDevice {
+        name: name.to_owned(),
+        kind: get_block_device_kind(&path, name),
+        backing_devices,
+    })
+}
+
+fn get_block_device_kind(path: &Path, name: &str) -> BlockDeviceKind {
+    if path.join("partition").exists() {
+        return BlockDeviceKind::Partition;
+    }
+    // The prefix of a device-mapper UUID tells which subsystem created the device.
+    if let Ok(uuid) = get_all_data(path.join("dm/uuid"), 256) {
+        let uuid = uuid.trim_start();
+        return if uuid.starts_with("CRYPT-") {
+            BlockDeviceKind::Crypt
+        } else if uuid.starts_with("LVM-") {
+            BlockDeviceKind::Lvm
+        } else {
+            BlockDeviceKind::DeviceMapper
+        };
+    }
+    if path.join("md").exists() {
+        BlockDeviceKind::Raid
+    } else if name.starts_with("loop") {
+        BlockDeviceKind::Loop
+    } else if is_physical_block_device(path) {
+        BlockDeviceKind::Disk
+    } else {
+        BlockDeviceKind::Unknown
+    }
+}
+
+fn get_physical_disks() -> Vec<PhysicalDisk> {
+    let entries = match fs::read_dir("/sys/block/") {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+    let mut physical_disks = entries
+        .filter_map(|entry| {
+            let block_device = entry.ok()?.path();
+            if !is_physical_block_device(&block_device) {
+                return None;
+            }
+            let read = |file: &str| -> Option<u64> {
+                get_all_data(block_device.join(file), 32)
+                    .ok()?
+                    .trim()
+                    .parse()
+                    .ok()
+            };
+            Some(PhysicalDisk {
+                name: block_device.file_name()?.to_str()?.to_owned(),
+                kind: find_type_for_block_device(&block_device),
+                // The size is always in 512-byte sectors, whatever the actual sector size is.
+                total_space: read("size")?.saturating_mul(512),
+                is_removable: read("removable") == Some(1),
+            })
+        })
+        .collect::<Vec<_>>();
+    physical_disks.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    physical_disks
+}
+
+fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefreshKind) {
+    #[cfg(feature = "parallel")]
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
     container.clear();
     // The goal of this array is to list all removable devices (the ones whose name starts with
     // "usb-").
@@ -224,8 +732,9 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str) {
             .collect::<Vec<PathBuf>>(),
         _ => Vec::new(),
     };
+    let uuid_entries = get_uuid_entries(Path::new("/dev/disk/by-uuid/"));
 
-    for disk in content
+    let mounts = content
         .lines()
         .map(|line| {
             let line = line.trim_start();
@@ -258,10 +767,11 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str) {
                 "pstore" | // https://www.kernel.org/doc/Documentation/ABI/testing/pstore
                 "squashfs" | // squashfs is a compressed read-only file system (for snaps)
                 "rpc_pipefs" | // The pipefs pseudo file system service
-                "iso9660" | // optical media
-                "nfs4" | // calling statvfs on a mounted NFS may hang
-                "nfs" // nfs2 or nfs3
+                "iso9660" // optical media
             );
+            // Calling statvfs on a network file system may hang so they're only listed if asked.
+            let filtered =
+                filtered || (!refresh_kind.network_disks() && is_network_file_system(fs_vfstype));
 
             !(filtered ||
                fs_file.starts_with("/sys") || // check if fs_file is an 'ignored' mount point
@@ -269,17 +779,31 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str) {
                (fs_file.starts_with("/run") && !fs_file.starts_with("/run/media")) ||
                fs_spec.starts_with("sunrpc"))
         })
-        .filter_map(|(fs_spec, fs_file, fs_vfstype)| {
-            new_disk(
-                fs_spec.as_ref(),
-                Path::new(&fs_file),
-                fs_vfstype.as_ref(),
-                &removable_entries,
-            )
-        })
-    {
-        container.push(disk);
-    }
+        .collect::<Vec<_>>();
+
+    // Each disk needs a `statvfs` call, which can be slow, so they're retrieved at the same time.
+    #[cfg(feature = "parallel")]
+    let mounts = mounts.into_par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let mounts = mounts.into_iter();
+
+    let errors = crate::utils::ErrorCollector::current();
+    container.extend(
+        mounts
+            .filter_map(|(fs_spec, fs_file, fs_vfstype)| {
+                errors.run(|| {
+                    new_disk(
+                        fs_spec.as_ref(),
+                        Path::new(&fs_file),
+                        fs_vfstype.as_ref(),
+                        &removable_entries,
+                        &uuid_entries,
+                        refresh_kind,
+                    )
+                })
+            })
+            .collect::<Vec<_>>(),
+    );
 }
 
 // #[test]
@@ -316,3 +840,186 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str) {
 //         }
 //     );
 // }
+
+#[cfg(test)]
+mod test {
+    use super::{find_uuid, get_block_device_layer, get_device_identity, get_uuid_entries};
+    use crate::{BlockDevice, BlockDeviceKind};
+
+    use std::fs;
+    use std::os::unix::fs::symlink;
+    use std::path::Path;
+
+    fn add_device(sys: &Path, device: &str, files: &[(&str, &str)]) {
+        let path = sys.join("devices").join(device);
+        fs::create_dir_all(&path).expect("failed to create device");
+        for (file, content) in files {
+            let file = path.join(file);
+            fs::create_dir_all(file.parent().unwrap()).expect("failed to create directory");
+            fs::write(file, content).expect("failed to write file");
+        }
+        let name = Path::new(device).file_name().unwrap();
+        symlink(&path, sys.join("class").join(name)).expect("failed to create link");
+    }
+
+    fn add_slave(sys: &Path, device: &str, slave: &str) {
+        let slaves = sys.join("class").join(device).join("slaves");
+        fs::create_dir_all(&slaves).expect("failed to create slaves");
+        symlink(sys.join("class").join(slave), slaves.join(slave)).expect("failed to create link");
+    }
+
+    fn physical_disk(name: &str) -> BlockDevice {
+        BlockDevice {
+            name: name.to_owned(),
+            kind: BlockDeviceKind::Disk,
+            backing_devices: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn block_device_stack() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let sys = dir.path();
+        fs::create_dir_all(sys.join("class")).expect("failed to create class");
+
+        // A LUKS container on a LVM volume on a RAID1 array of two partitions.
+        add_device(sys, "sda", &[("device/model", "disk")]);
+        add_device(sys, "sda/sda2", &[("partition", "2")]);
+        add_device(sys, "sdb", &[("device/model", "disk")]);
+        add_device(sys, "sdb/sdb2", &[("partition", "2")]);
+        add_device(sys, "md0", &[("md/level", "raid1")]);
+        add_slave(sys, "md0", "sdb2");
+        add_slave(sys, "md0", "sda2");
+        add_device(sys, "dm-0", &[("dm/uuid", "LVM-abcdef\n")]);
+        add_slave(sys, "dm-0", "md0");
+        add_device(sys, "dm-1", &[("dm/uuid", "CRYPT-LUKS2-abcdef-root\n")]);
+        add_slave(sys, "dm-1", "dm-0");
+        add_device(sys, "loop0", &[]);
+
+        let class = sys.join("class");
+        let partition = |name: &str, disk: &str| BlockDevice {
+            name: name.to_owned(),
+            kind: BlockDeviceKind::Partition,
+            backing_devices: vec![physical_disk(disk)],
+        };
+        let block_device = get_block_device_layer(&class, "dm-1", 0).expect("no block device");
+        assert_eq!(
+            block_device,
+            BlockDevice {
+                name: "dm-1".to_owned(),
+                kind: BlockDeviceKind::Crypt,
+                backing_devices: vec![BlockDevice {
+                    name: "dm-0".to_owned(),
+                    kind: BlockDeviceKind::Lvm,
+                    backing_devices: vec![BlockDevice {
+                        name: "md0".to_owned(),
+                        kind: BlockDeviceKind::Raid,
+                        backing_devices: vec![partition("sda2", "sda"), partition("sdb2", "sdb")],
+                    }],
+                }],
+            }
+        );
+        assert_eq!(block_device.physical_disks(), ["sda", "sdb"]);
+
+        let block_device = get_block_device_layer(&class, "sda2", 0).expect("no block device");
+        assert_eq!(block_device.physical_disks(), ["sda"]);
+
+        let block_device = get_block_device_layer(&class, "loop0", 0).expect("no block device");
+        assert_eq!(block_device.kind, BlockDeviceKind::Loop);
+        assert!(block_device.physical_disks().is_empty());
+
+        assert!(get_block_device_layer(&class, "sdc", 0).is_none());
+    }
+
+    #[test]
+    fn device_identity() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let udev_data = dir.path().join("udev");
+        fs::create_dir_all(&udev_data).expect("failed to create udev data");
+        let write = |path: &Path, content: &str| {
+            fs::create_dir_all(path.parent().unwrap()).expect("failed to create directory");
+            fs::write(path, content).expect("failed to write file");
+        };
+
+        // Everything is in sysfs, except the serial number.
+        let sda = dir.path().join("sda");
+        write(&sda.join("dev"), "8:0\n");
+        write(&sda.join("device/vendor"), "ATA     \n");
+        write(&sda.join("device/model"), "Samsung SSD 860\n");
+        write(
+            &udev_data.join("b8:0"),
+            "S:disk/by-id/ata-Samsung\nE:ID_MODEL=Samsung_SSD\nE:ID_SERIAL_SHORT=S3Z9NB0K\n",
+        );
+        assert_eq!(
+            get_device_identity(&sda, &udev_data),
+            (
+                Some("ATA".to_owned()),
+                Some("Samsung SSD 860".to_owned()),
+                Some("S3Z9NB0K".to_owned()),
+            )
+        );
+
+        // The PCI vendor ID of virtio devices is skipped.
+        let vda = dir.path().join("vda");
+        write(&vda.join("dev"), "252:0\n");
+        write(&vda.join("device/vendor"), "0x1af4\n");
+        write(&vda.join("serial"), "disk0\n");
+        write(&udev_data.join("b252:0"), "E:ID_VENDOR=\n");
+        assert_eq!(
+            get_device_identity(&vda, &udev_data),
+            (None, None, Some("disk0".to_owned()))
+        );
+
+        assert_eq!(
+            get_device_identity(&dir.path().join("sdz"), &udev_data),
+            (None, None, None)
+        );
+    }
+
+    #[cfg(not(target_os = "android"))]
+    #[test]
+    fn quota_from_dqblk() {
+        let mut dqblk: libc::dqblk = unsafe { std::mem::zeroed() };
+        dqblk.dqb_curspace = 5_000;
+        dqblk.dqb_bsoftlimit = 10;
+        dqblk.dqb_curinodes = 42;
+        dqblk.dqb_ihardlimit = 100;
+        assert_eq!(
+            super::quota_from_dqblk(&dqblk),
+            crate::DiskQuota {
+                used_space: 5_000,
+                soft_space_limit: Some(10 * 1_024),
+                hard_space_limit: None,
+                used_inodes: Some(42),
+                soft_inode_limit: None,
+                hard_inode_limit: Some(100),
+            }
+        );
+    }
+
+    #[test]
+    fn disk_uuid() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let by_uuid = dir.path().join("by-uuid");
+        fs::create_dir_all(&by_uuid).expect("failed to create by-uuid");
+        for device in ["sda1", "sda2", "dm-0"] {
+            fs::write(dir.path().join(device), "").expect("failed to create device");
+        }
+        symlink("../sda1", by_uuid.join("2f5b-1c3d")).expect("failed to create link");
+        symlink("../dm-0", by_uuid.join("a1b2c3d4-e5f6")).expect("failed to create link");
+        // Links to devices which don't exist are skipped.
+        symlink("../sdb1", by_uuid.join("0000-0000")).expect("failed to create link");
+        // Like the links of `/dev/mapper/`.
+        fs::create_dir_all(dir.path().join("mapper")).expect("failed to create mapper");
+        symlink("../dm-0", dir.path().join("mapper/root")).expect("failed to create link");
+
+        let entries = get_uuid_entries(&by_uuid);
+        assert_eq!(entries.len(), 2);
+        let uuid = |device: &str| find_uuid(dir.path().join(device).as_os_str(), &entries);
+        assert_eq!(uuid("sda1").as_deref(), Some("2f5b-1c3d"));
+        assert_eq!(uuid("mapper/root").as_deref(), Some("a1b2c3d4-e5f6"));
+        assert_eq!(uuid("sda2"), None);
+        assert_eq!(uuid("sdc1"), None);
+        assert!(get_uuid_entries(&dir.path().join("missing")).is_empty());
+    }
+}
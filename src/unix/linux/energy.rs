@@ -0,0 +1,135 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::get_all_data;
+use crate::EnergyDomain;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+const POWERCAP_PATH: &str = "/sys/class/powercap";
+
+struct RaplZone {
+    // Path to the `energy_uj` file.
+    energy_file: PathBuf,
+    // Value (in microjoules) at which the counter wraps around.
+    max_energy_range: u64,
+    last_energy: u64,
+}
+
+pub(crate) struct EnergyWrapper {
+    domains: Vec<EnergyDomain>,
+    // `zones[i]` is the source of `domains[i]`.
+    zones: Vec<RaplZone>,
+    last_update: Option<Instant>,
+}
+
+impl EnergyWrapper {
+    pub(crate) fn new() -> Self {
+        Self {
+            domains: Vec::new(),
+            zones: Vec::new(),
+            last_update: None,
+        }
+    }
+
+    pub(crate) fn domains(&self) -> &[EnergyDomain] {
+        &self.domains
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        let now = Instant::now();
+        if self.last_update.is_none() {
+            self.init();
+            self.last_update = Some(now);
+            return;
+        }
+        let elapsed = self.last_update.map(|last| now.duration_since(last));
+        for (domain, zone) in self.domains.iter_mut().zip(self.zones.iter_mut()) {
+            let energy = match read_u64(&zone.energy_file) {
+                Some(energy) => energy,
+                None => continue,
+            };
+            let consumed = if energy >= zone.last_energy {
+                energy - zone.last_energy
+            } else {
+                // The counter wrapped around.
+                zone.max_energy_range.saturating_sub(zone.last_energy) + energy
+            };
+            zone.last_energy = energy;
+            // The counters are in microjoules.
+            domain.update(consumed as f64 / 1_000_000., elapsed);
+        }
+        self.last_update = Some(now);
+    }
+
+    fn init(&mut self) {
+        let entries = match fs::read_dir(POWERCAP_PATH) {
+            Ok(entries) => entries,
+            Err(_e) => {
+                sysinfo_debug!("failed to read `{}`: {:?}", POWERCAP_PATH, _e);
+                return;
+            }
+        };
+        // AMD CPUs are also handled by the `intel-rapl` driver.
+        let mut zones = entries
+            .flatten()
+            .filter_map(|entry| {
+                let file_name = entry.file_name().into_string().ok()?;
+                let ids = file_name
+                    .strip_prefix("intel-rapl:")?
+                    .split(':')
+                    .map(|id| id.parse::<usize>().ok())
+                    .collect::<Option<Vec<_>>>()?;
+                Some((ids, entry.path()))
+            })
+            .collect::<Vec<_>>();
+        zones.sort_unstable();
+
+        for (ids, path) in zones {
+            let name = match read_name(&path) {
+                Some(name) => name,
+                None => continue,
+            };
+            let energy_file = path.join("energy_uj");
+            let last_energy = match read_u64(&energy_file) {
+                Some(energy) => energy,
+                None => {
+                    sysinfo_debug!("cannot read `{}`", energy_file.display());
+                    continue;
+                }
+            };
+            let max_energy_range = read_u64(&path.join("max_energy_range_uj")).unwrap_or(u64::MAX);
+            // Sub-zones (like `intel-rapl:0:1`) belong to the package of their parent zone.
+            let package = if ids.len() > 1 {
+                read_name(&Path::new(POWERCAP_PATH).join(format!("intel-rapl:{}", ids[0])))
+            } else {
+                Some(name.clone())
+            }
+            .and_then(|name| name.strip_prefix("package-")?.parse().ok())
+            .unwrap_or(0);
+            let name = if name.starts_with("package-") {
+                "package".to_owned()
+            } else {
+                name
+            };
+
+            self.domains.push(EnergyDomain::new(name, package));
+            self.zones.push(RaplZone {
+                energy_file,
+                max_energy_range,
+                last_energy,
+            });
+        }
+    }
+}
+
+fn read_name(zone: &Path) -> Option<String> {
+    get_all_data(zone.join("name"), 32)
+        .ok()
+        .map(|name| name.trim().to_owned())
+}
+
+fn read_u64(file: &Path) -> Option<u64> {
+    get_all_data(file, 32).ok()?.trim().parse().ok()
+}
@@ -0,0 +1,229 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::nvml::Nvml;
+use crate::sys::pci::{pci_names, read_pci_ids};
+use crate::sys::utils::get_all_data;
+use crate::{Gpu, GpuRefreshKind};
+
+use std::fs::read_dir;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+const NVIDIA_VENDOR_ID: u16 = 0x10de;
+
+enum Source {
+    Nvml(Arc<Nvml>, u32),
+    // The `device` directory of the DRM card.
+    Drm(PathBuf),
+}
+
+pub(crate) struct GpuInner {
+    name: String,
+    driver_version: Option<String>,
+    usage: Option<f32>,
+    total_memory: Option<u64>,
+    used_memory: Option<u64>,
+    temperature: Option<f32>,
+    power: Option<f32>,
+    source: Source,
+}
+
+impl GpuInner {
+    fn new(name: String, driver_version: Option<String>, source: Source) -> Self {
+        Self {
+            name,
+            driver_version,
+            usage: None,
+            total_memory: None,
+            used_memory: None,
+            temperature: None,
+            power: None,
+            source,
+        }
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn driver_version(&self) -> Option<&str> {
+        self.driver_version.as_deref()
+    }
+
+    pub(crate) fn usage(&self) -> Option<f32> {
+        self.usage
+    }
+
+    pub(crate) fn total_memory(&self) -> Option<u64> {
+        self.total_memory
+    }
+
+    pub(crate) fn used_memory(&self) -> Option<u64> {
+        self.used_memory
+    }
+
+    pub(crate) fn temperature(&self) -> Option<f32> {
+        self.temperature
+    }
+
+    pub(crate) fn power(&self) -> Option<f32> {
+        self.power
+    }
+
+    pub(crate) fn refresh(&mut self, refresh_kind: GpuRefreshKind) {
+        match self.source {
+            Source::Nvml(ref nvml, index) => {
+                if refresh_kind.usage() {
+                    self.usage = nvml.usage(index);
+                }
+                if refresh_kind.memory() {
+                    let memory = nvml.memory(index);
+                    self.total_memory = memory.map(|(total, _)| total);
+                    self.used_memory = memory.map(|(_, used)| used);
+                }
+                if refresh_kind.temperature() {
+                    self.temperature = nvml.temperature(index);
+                }
+                if refresh_kind.power() {
+                    self.power = nvml.power(index);
+                }
+            }
+            Source::Drm(ref device) => {
+                let read = |file: &Path| {
+                    get_all_data(file, 64)
+                        .ok()
+                        .and_then(|data| data.trim().parse::<u64>().ok())
+                };
+                if refresh_kind.usage() {
+                    self.usage = read(&device.join("gpu_busy_percent")).map(|usage| usage as f32);
+                }
+                if refresh_kind.memory() {
+                    self.total_memory = read(&device.join("mem_info_vram_total"));
+                    self.used_memory = read(&device.join("mem_info_vram_used"));
+                }
+                if !refresh_kind.temperature() && !refresh_kind.power() {
+                    return;
+                }
+                let hwmon = match get_hwmon(device) {
+                    Some(hwmon) => hwmon,
+                    None => return,
+                };
+                // The temperature is given in millidegree Celsius.
+                if refresh_kind.temperature() {
+                    self.temperature =
+                        read(&hwmon.join("temp1_input")).map(|value| value as f32 / 1_000.);
+                }
+                // The power is given in microwatts. Older GPUs only provide an average.
+                if refresh_kind.power() {
+                    self.power = read(&hwmon.join("power1_input"))
+                        .or_else(|| read(&hwmon.join("power1_average")))
+                        .map(|value| value as f32 / 1_000_000.);
+                }
+            }
+        }
+    }
+}
+
+fn get_hwmon(device: &Path) -> Option<PathBuf> {
+    read_dir(device.join("hwmon"))
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| path.is_dir())
+}
+
+pub(crate) struct GpusInner {
+    gpus: Vec<Gpu>,
+    nvml: Option<Arc<Nvml>>,
+}
+
+impl GpusInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            gpus: Vec::new(),
+            nvml: None,
+        }
+    }
+
+    pub(crate) fn list(&self) -> &[Gpu] {
+        &self.gpus
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Gpu] {
+        &mut self.gpus
+    }
+
+    pub(crate) fn refresh_list(&mut self, refresh_kind: GpuRefreshKind) {
+        self.gpus.clear();
+        if self.nvml.is_none() {
+            self.nvml = Nvml::load();
+        }
+        if let Some(ref nvml) = self.nvml {
+            let driver_version = nvml.driver_version();
+            for (index, name) in nvml.gpus() {
+                self.gpus.push(Gpu {
+                    inner: GpuInner::new(
+                        name,
+                        driver_version.clone(),
+                        Source::Nvml(Arc::clone(nvml), index),
+                    ),
+                });
+            }
+        }
+        // NVIDIA GPUs also have a DRM card with the proprietary driver, but without any of the
+        // attributes.
+        let skip_nvidia = !self.gpus.is_empty();
+        let mut pci_ids = None;
+
+        let mut cards = match read_dir("/sys/class/drm") {
+            Ok(dir) => dir
+                .flatten()
+                .filter_map(|entry| {
+                    let name = entry.file_name().to_string_lossy().into_owned();
+                    // The connectors are named like `card0-HDMI-A-1`.
+                    if name.starts_with("card") && !name.contains('-') {
+                        Some((name, entry.path().join("device")))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>(),
+            Err(_) => Vec::new(),
+        };
+        cards.sort_unstable();
+
+        for (card, device) in cards {
+            let id = |file: &str| {
+                get_all_data(device.join(file), 16)
+                    .ok()
+                    .and_then(|id| u16::from_str_radix(id.trim().trim_start_matches("0x"), 16).ok())
+            };
+            let (vendor_id, device_id) = match (id("vendor"), id("device")) {
+                (Some(vendor_id), Some(device_id)) => (vendor_id, device_id),
+                _ => continue,
+            };
+            if skip_nvidia && vendor_id == NVIDIA_VENDOR_ID {
+                continue;
+            }
+            let pci_ids = pci_ids.get_or_insert_with(read_pci_ids);
+            let name = pci_names(pci_ids, vendor_id, device_id)
+                .1
+                .unwrap_or_else(|| format!("{card} ({vendor_id:04x}:{device_id:04x})"));
+            // In-tree drivers don't have a version, they are versioned with the kernel.
+            let driver_version = std::fs::read_link(device.join("driver"))
+                .ok()
+                .and_then(|driver| {
+                    let driver = driver.file_name()?.to_string_lossy().into_owned();
+                    get_all_data(format!("/sys/module/{driver}/version"), 32).ok()
+                })
+                .map(|version| version.trim().to_owned());
+            self.gpus.push(Gpu {
+                inner: GpuInner::new(name, driver_version, Source::Drm(device)),
+            });
+        }
+
+        for gpu in &mut self.gpus {
+            gpu.inner.refresh(refresh_kind);
+        }
+    }
+}
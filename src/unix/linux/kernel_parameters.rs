@@ -0,0 +1,94 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::get_all_data;
+use crate::KernelParameter;
+
+use std::path::{Path, PathBuf};
+
+pub(crate) fn get_kernel_parameter(name: &str) -> Option<KernelParameter> {
+    let path = kernel_parameter_path(name)?;
+    // Some parameters (like `kernel.random.uuid`) are generated on every read, and others are
+    // only readable by root.
+    match get_all_data(path, 4_096) {
+        Ok(content) => Some(parse_kernel_parameter(&content)),
+        Err(_e) => {
+            sysinfo_debug!("failed to read kernel parameter `{}`: {:?}", name, _e);
+            None
+        }
+    }
+}
+
+/// Converts a sysctl name (`vm.swappiness` or `vm/swappiness`) into its `/proc/sys` path.
+fn kernel_parameter_path(name: &str) -> Option<PathBuf> {
+    // If the name already contains a `/`, dots are part of the components (like network
+    // interfaces named `eth0.100`).
+    let relative = if name.contains('/') {
+        name.to_owned()
+    } else {
+        name.replace('.', "/")
+    };
+    if relative
+        .split('/')
+        .any(|component| component.is_empty() || component == "." || component == "..")
+    {
+        return None;
+    }
+    Some(Path::new("/proc/sys").join(relative))
+}
+
+/// Parses the content of a `/proc/sys` file. Integers are separated by whitespace (like
+/// `net.ipv4.ip_local_port_range`), everything else is returned as text.
+fn parse_kernel_parameter(content: &str) -> KernelParameter {
+    let content = content.trim();
+    let integers = content
+        .split_whitespace()
+        .map(|value| value.parse::<i64>().ok())
+        .collect::<Option<Vec<_>>>();
+    match integers {
+        Some(mut integers) if integers.len() == 1 => KernelParameter::Integer(integers.remove(0)),
+        Some(integers) if !integers.is_empty() => KernelParameter::Integers(integers),
+        _ => KernelParameter::String(content.to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{kernel_parameter_path, parse_kernel_parameter};
+    use crate::KernelParameter;
+
+    use std::path::Path;
+
+    #[test]
+    fn check_kernel_parameter_path() {
+        assert_eq!(
+            kernel_parameter_path("vm.swappiness").as_deref(),
+            Some(Path::new("/proc/sys/vm/swappiness"))
+        );
+        assert_eq!(
+            kernel_parameter_path("net/ipv4/conf/eth0.100/forwarding").as_deref(),
+            Some(Path::new("/proc/sys/net/ipv4/conf/eth0.100/forwarding"))
+        );
+        assert_eq!(kernel_parameter_path(""), None);
+        assert_eq!(kernel_parameter_path("vm/../../etc/passwd"), None);
+        assert_eq!(kernel_parameter_path("/etc/passwd"), None);
+        assert_eq!(kernel_parameter_path("vm..swappiness"), None);
+    }
+
+    #[test]
+    fn check_parse_kernel_parameter() {
+        assert_eq!(parse_kernel_parameter("60\n"), KernelParameter::Integer(60));
+        assert_eq!(
+            parse_kernel_parameter("32768\t60999\n"),
+            KernelParameter::Integers(vec![32768, 60999])
+        );
+        assert_eq!(parse_kernel_parameter("-1\n"), KernelParameter::Integer(-1));
+        assert_eq!(
+            parse_kernel_parameter("cubic reno\n"),
+            KernelParameter::String("cubic reno".to_owned())
+        );
+        assert_eq!(
+            parse_kernel_parameter("\n"),
+            KernelParameter::String(String::new())
+        );
+    }
+}
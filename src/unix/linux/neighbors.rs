@@ -0,0 +1,159 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::netlink::{find_attribute, parse_attributes, read_u16, read_u32, NetlinkSocket};
+use crate::{MacAddr, Neighbor, NeighborState};
+
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
+use std::ffi::CStr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+// Size of `struct ndmsg`.
+const NDMSG_LEN: usize = 12;
+
+pub(crate) fn get_neighbors(neighbors: &mut Vec<Neighbor>) {
+    let mut socket = match NetlinkSocket::new(libc::NETLINK_ROUTE) {
+        Some(socket) => socket,
+        None => return,
+    };
+    // `struct ndmsg`, the family is left to `AF_UNSPEC` to get both IPv4 and IPv6 entries.
+    let messages = match socket.request(libc::RTM_GETNEIGH, true, &[0; NDMSG_LEN]) {
+        Some(messages) => messages,
+        None => {
+            sysinfo_debug!("failed to retrieve the neighbor table");
+            return;
+        }
+    };
+    let mut interfaces = HashMap::new();
+    for message in messages {
+        let (index, ip_address, mac_address, state) = match parse_neighbor(&message) {
+            Some(neighbor) => neighbor,
+            None => continue,
+        };
+        let interface = interfaces
+            .entry(index)
+            .or_insert_with(|| get_interface_name(index));
+        if let Some(interface) = interface {
+            neighbors.push(Neighbor {
+                ip_address,
+                mac_address,
+                state,
+                interface: interface.clone(),
+            });
+        }
+    }
+}
+
+fn get_interface_name(index: u32) -> Option<String> {
+    let mut name = [0; libc::IF_NAMESIZE];
+    unsafe {
+        if libc::if_indextoname(index, name.as_mut_ptr()).is_null() {
+            return None;
+        }
+        Some(CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned())
+    }
+}
+
+// Values from `NUD_*`.
+fn neighbor_state(state: u16) -> NeighborState {
+    match state {
+        libc::NUD_INCOMPLETE => NeighborState::Incomplete,
+        libc::NUD_REACHABLE => NeighborState::Reachable,
+        libc::NUD_STALE => NeighborState::Stale,
+        libc::NUD_DELAY => NeighborState::Delay,
+        libc::NUD_PROBE => NeighborState::Probe,
+        libc::NUD_FAILED => NeighborState::Failed,
+        libc::NUD_PERMANENT => NeighborState::Permanent,
+        _ => NeighborState::Unknown,
+    }
+}
+
+/// Parses a `RTM_NEWNEIGH` message and returns the interface index, IP address, MAC address and
+/// state of the entry.
+fn parse_neighbor(message: &[u8]) -> Option<(u32, IpAddr, MacAddr, NeighborState)> {
+    let index = read_u32(message.get(4..)?)?;
+    let state = read_u16(message.get(8..)?)?;
+    // Like `ip neigh`, entries which don't need address resolution (like loopback) are skipped.
+    if state == libc::NUD_NONE || state == libc::NUD_NOARP {
+        return None;
+    }
+    let attributes = parse_attributes(message.get(NDMSG_LEN..)?);
+    let ip_address = match find_attribute(&attributes, libc::NDA_DST)? {
+        address if address.len() == 4 => {
+            IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(address).ok()?))
+        }
+        address if address.len() == 16 => {
+            IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(address).ok()?))
+        }
+        _ => return None,
+    };
+    // Only Ethernet-like link layer addresses are supported.
+    let mac_address = find_attribute(&attributes, libc::NDA_LLADDR)
+        .and_then(|address| address.try_into().ok())
+        .map_or(MacAddr::UNSPECIFIED, MacAddr);
+    Some((index, ip_address, mac_address, neighbor_state(state)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_neighbor;
+    use crate::{MacAddr, NeighborState};
+
+    fn attribute(message: &mut Vec<u8>, kind: u16, payload: &[u8]) {
+        message.extend_from_slice(&(4 + payload.len() as u16).to_ne_bytes());
+        message.extend_from_slice(&kind.to_ne_bytes());
+        message.extend_from_slice(payload);
+        while message.len() % 4 != 0 {
+            message.push(0);
+        }
+    }
+
+    fn ndmsg(family: u8, index: u32, state: u16) -> Vec<u8> {
+        let mut message = vec![family, 0, 0, 0];
+        message.extend_from_slice(&index.to_ne_bytes());
+        message.extend_from_slice(&state.to_ne_bytes());
+        message.extend_from_slice(&[0, 1]);
+        message
+    }
+
+    #[test]
+    fn neighbor() {
+        let mut message = ndmsg(libc::AF_INET as _, 2, libc::NUD_REACHABLE);
+        attribute(&mut message, libc::NDA_DST, &[192, 168, 1, 1]);
+        attribute(
+            &mut message,
+            libc::NDA_LLADDR,
+            &[0x52, 0x54, 0, 0x12, 0x35, 2],
+        );
+        assert_eq!(
+            parse_neighbor(&message),
+            Some((
+                2,
+                "192.168.1.1".parse().unwrap(),
+                MacAddr([0x52, 0x54, 0, 0x12, 0x35, 2]),
+                NeighborState::Reachable,
+            ))
+        );
+
+        // The address resolution is in progress, so there's no MAC address yet.
+        let mut message = ndmsg(libc::AF_INET6 as _, 3, libc::NUD_INCOMPLETE);
+        attribute(
+            &mut message,
+            libc::NDA_DST,
+            &[0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+        );
+        assert_eq!(
+            parse_neighbor(&message),
+            Some((
+                3,
+                "fe80::1".parse().unwrap(),
+                MacAddr::UNSPECIFIED,
+                NeighborState::Incomplete,
+            ))
+        );
+
+        let mut message = ndmsg(libc::AF_INET as _, 1, libc::NUD_NOARP);
+        attribute(&mut message, libc::NDA_DST, &[127, 0, 0, 1]);
+        assert_eq!(parse_neighbor(&message), None);
+    }
+}
@@ -0,0 +1,209 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::convert::TryInto;
+use std::time::Duration;
+
+// From `linux/netlink.h`.
+const NLM_F_REQUEST: u16 = 0x1;
+const NLM_F_DUMP: u16 = 0x300;
+const NLMSG_ERROR: u16 = 0x2;
+const NLMSG_DONE: u16 = 0x3;
+// Size of `struct nlmsghdr`.
+const NLMSG_HDRLEN: usize = 16;
+// Attributes which are themselves nested have the `NLA_F_NESTED` flag set in their type.
+const NLA_TYPE_MASK: u16 = 0x3fff;
+
+pub(crate) fn align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+/// Returns the `(type, payload)` of the netlink attributes in `data`.
+pub(crate) fn parse_attributes(mut data: &[u8]) -> Vec<(u16, &[u8])> {
+    let mut attributes = Vec::new();
+    while data.len() >= 4 {
+        let len = usize::from(u16::from_ne_bytes([data[0], data[1]]));
+        let kind = u16::from_ne_bytes([data[2], data[3]]) & NLA_TYPE_MASK;
+        if len < 4 || len > data.len() {
+            break;
+        }
+        attributes.push((kind, &data[4..len]));
+        data = &data[align(len).min(data.len())..];
+    }
+    attributes
+}
+
+pub(crate) fn find_attribute<'a>(attributes: &[(u16, &'a [u8])], kind: u16) -> Option<&'a [u8]> {
+    attributes
+        .iter()
+        .find(|(attribute, _)| *attribute == kind)
+        .map(|(_, payload)| *payload)
+}
+
+pub(crate) fn read_u16(payload: &[u8]) -> Option<u16> {
+    Some(u16::from_ne_bytes(payload.get(..2)?.try_into().ok()?))
+}
+
+pub(crate) fn read_u32(payload: &[u8]) -> Option<u32> {
+    Some(u32::from_ne_bytes(payload.get(..4)?.try_into().ok()?))
+}
+
+pub(crate) fn read_u64(payload: &[u8]) -> Option<u64> {
+    Some(u64::from_ne_bytes(payload.get(..8)?.try_into().ok()?))
+}
+
+pub(crate) struct NetlinkSocket {
+    fd: libc::c_int,
+    sequence: u32,
+}
+
+impl NetlinkSocket {
+    pub(crate) fn new(protocol: libc::c_int) -> Option<Self> {
+        let fd = unsafe {
+            libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+                protocol,
+            )
+        };
+        if fd < 0 {
+            sysinfo_debug!("failed to create netlink socket");
+            return None;
+        }
+        Some(Self { fd, sequence: 0 })
+    }
+
+    /// Creates a socket receiving the notifications sent to the multicast `groups`.
+    pub(crate) fn subscribe(protocol: libc::c_int, groups: u32) -> Option<Self> {
+        let socket = Self::new(protocol)?;
+        unsafe {
+            let mut addr: libc::sockaddr_nl = std::mem::zeroed();
+            addr.nl_family = libc::AF_NETLINK as _;
+            addr.nl_groups = groups;
+            if libc::bind(
+                socket.fd,
+                &addr as *const libc::sockaddr_nl as *const libc::sockaddr,
+                std::mem::size_of::<libc::sockaddr_nl>() as _,
+            ) < 0
+            {
+                sysinfo_debug!("failed to bind netlink socket");
+                return None;
+            }
+        }
+        Some(socket)
+    }
+
+    /// Waits until notifications are received and discards them. Returns `false` if `timeout`
+    /// elapsed before.
+    pub(crate) fn wait(&mut self, timeout: Option<Duration>) -> bool {
+        let mut fd = libc::pollfd {
+            fd: self.fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout = timeout.map_or(-1, |timeout| {
+            timeout.as_millis().min(libc::c_int::MAX as u128) as libc::c_int
+        });
+        unsafe {
+            if retry_eintr!(libc::poll(&mut fd, 1, timeout)) <= 0 || fd.revents & libc::POLLIN == 0
+            {
+                return false;
+            }
+            let mut buf = [0u8; 4096];
+            // If the queue overflowed, `recv` fails with `ENOBUFS`, which is fine since the
+            // content of the notifications isn't used.
+            while libc::recv(
+                self.fd,
+                buf.as_mut_ptr() as *mut _,
+                buf.len(),
+                libc::MSG_DONTWAIT,
+            ) > 0
+            {}
+        }
+        true
+    }
+
+    /// Sends a message of type `kind` and returns the payloads of the answers.
+    pub(crate) fn request(
+        &mut self,
+        kind: u16,
+        dump: bool,
+        payload: &[u8],
+    ) -> Option<Vec<Vec<u8>>> {
+        self.sequence += 1;
+        let mut flags = NLM_F_REQUEST;
+        if dump {
+            flags |= NLM_F_DUMP;
+        }
+
+        let mut buf = Vec::with_capacity(NLMSG_HDRLEN + payload.len());
+        buf.extend_from_slice(&((NLMSG_HDRLEN + payload.len()) as u32).to_ne_bytes());
+        buf.extend_from_slice(&kind.to_ne_bytes());
+        buf.extend_from_slice(&flags.to_ne_bytes());
+        buf.extend_from_slice(&self.sequence.to_ne_bytes());
+        buf.extend_from_slice(&0u32.to_ne_bytes()); // Port ID, `0` is the kernel.
+        buf.extend_from_slice(payload);
+
+        unsafe {
+            if libc::send(self.fd, buf.as_ptr() as *const _, buf.len(), 0) < 0 {
+                return None;
+            }
+        }
+
+        let mut answers = Vec::new();
+        let mut buf = vec![0u8; 16 * 1024];
+        loop {
+            let received = unsafe {
+                retry_eintr!(libc::recv(
+                    self.fd,
+                    buf.as_mut_ptr() as *mut _,
+                    buf.len(),
+                    0
+                ))
+            };
+            if received < 0 {
+                return None;
+            }
+            let mut data = &buf[..received as usize];
+            while data.len() >= NLMSG_HDRLEN {
+                let len = read_u32(data)? as usize;
+                let kind = read_u16(&data[4..])?;
+                if len < NLMSG_HDRLEN || len > data.len() {
+                    return None;
+                }
+                match kind {
+                    NLMSG_DONE => return Some(answers),
+                    // The request failed, the error code is right after the header.
+                    NLMSG_ERROR => return None,
+                    _ => answers.push(data[NLMSG_HDRLEN..len].to_vec()),
+                }
+                data = &data[align(len).min(data.len())..];
+            }
+            if !dump {
+                return Some(answers);
+            }
+        }
+    }
+}
+
+impl Drop for NetlinkSocket {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.fd);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_attributes;
+
+    #[test]
+    fn truncated_attributes() {
+        let mut attributes = Vec::new();
+        attributes.extend_from_slice(&8u16.to_ne_bytes());
+        attributes.extend_from_slice(&52u16.to_ne_bytes());
+        attributes.extend_from_slice(b"home");
+        assert_eq!(parse_attributes(&attributes), [(52, &b"home"[..])]);
+        assert!(parse_attributes(&attributes[..6]).is_empty());
+    }
+}
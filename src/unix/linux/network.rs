@@ -1,13 +1,22 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use std::collections::{hash_map, HashMap};
+use std::fs::File;
 use std::io::Read;
 use std::path::Path;
-use std::{fs::File, u8};
-
-use crate::common::MacAddr;
-use crate::network::refresh_networks_addresses;
+use std::time::Duration;
+
+use crate::common::{
+    DuplexMode, InterfaceKind, IpNetwork, MacAddr, NetworkCounters, OperationalState,
+};
+use crate::network::{counter_delta, refresh_networks_addresses, LinkState, RefreshTimes};
+use crate::sys::netlink::NetlinkSocket;
+use crate::sys::utils::get_all_data;
+#[cfg(feature = "wireless")]
+use crate::sys::wireless::get_wireless_info;
 use crate::NetworkData;
+#[cfg(feature = "wireless")]
+use crate::WirelessInfo;
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident) => {{
@@ -39,86 +48,270 @@ fn read<P: AsRef<Path>>(parent: P, path: &str, data: &mut Vec<u8>) -> u64 {
     0
 }
 
+fn get_counters(statistics: &Path, data: &mut Vec<u8>) -> NetworkCounters {
+    let mut read_counter = |file: &str| Some(read(statistics, file, data));
+    NetworkCounters {
+        multicast_received: read_counter("multicast"),
+        multicast_transmitted: None,
+        collisions: read_counter("collisions"),
+        dropped_on_received: read_counter("rx_dropped"),
+        dropped_on_transmitted: read_counter("tx_dropped"),
+        fifo_errors_on_received: read_counter("rx_fifo_errors"),
+        fifo_errors_on_transmitted: read_counter("tx_fifo_errors"),
+        frame_errors_on_received: read_counter("rx_frame_errors"),
+        carrier_errors_on_transmitted: read_counter("tx_carrier_errors"),
+    }
+}
+
+/// Link information of an interface, exposed by the kernel with the ethtool data.
+struct LinkInfo {
+    mtu: u64,
+    link_speed: Option<u64>,
+    duplex: DuplexMode,
+}
+
+fn get_link_info(interface: &Path) -> LinkInfo {
+    let read_str = |file: &str| get_all_data(interface.join(file), 16).ok();
+    LinkInfo {
+        mtu: read_str("mtu")
+            .and_then(|mtu| mtu.trim().parse().ok())
+            .unwrap_or(0),
+        // The speed is in Mb/s and is `-1` if unknown. Reading it fails if the link is down.
+        link_speed: read_str("speed")
+            .and_then(|speed| speed.trim().parse::<i64>().ok())
+            .filter(|speed| *speed > 0)
+            .map(|speed| speed as u64 * 1_000_000),
+        duplex: match read_str("duplex").as_deref().map(str::trim) {
+            Some("full") => DuplexMode::Full,
+            Some("half") => DuplexMode::Half,
+            _ => DuplexMode::Unknown,
+        },
+    }
+}
+
+fn get_link_state(interface: &Path) -> LinkState {
+    let read_str = |file: &str| get_all_data(interface.join(file), 32).ok();
+    let operational_state = match read_str("operstate").as_deref().map(str::trim) {
+        Some("up") => OperationalState::Up,
+        Some("down") => OperationalState::Down,
+        Some("testing") => OperationalState::Testing,
+        Some("dormant") => OperationalState::Dormant,
+        Some("notpresent") => OperationalState::NotPresent,
+        Some("lowerlayerdown") => OperationalState::LowerLayerDown,
+        _ => OperationalState::Unknown,
+    };
+    let is_admin_up = read_str("flags")
+        .and_then(|flags| u32::from_str_radix(flags.trim().trim_start_matches("0x"), 16).ok())
+        .map(|flags| flags & libc::IFF_UP as u32 != 0)
+        .unwrap_or(false);
+    // Reading the carrier fails if the interface is disabled.
+    let has_carrier = match read_str("carrier").as_deref().map(str::trim) {
+        Some("1") => Some(true),
+        Some("0") => Some(false),
+        _ => None,
+    };
+    LinkState::new(operational_state, is_admin_up, has_carrier)
+}
+
+fn get_carrier_changes(interface: &Path) -> Option<u64> {
+    get_all_data(interface.join("carrier_changes"), 32)
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+// The alias set with `ip link set <interface> alias <description>`.
+fn get_description(interface: &Path) -> Option<String> {
+    let alias = get_all_data(interface.join("ifalias"), 256).ok()?;
+    Some(alias.trim().to_owned()).filter(|alias| !alias.is_empty())
+}
+
+/// Relations of an interface with the other interfaces.
+struct LinkRelations {
+    kind: InterfaceKind,
+    master: Option<String>,
+    lower_interfaces: Vec<String>,
+}
+
+fn get_link_relations(interface: &Path) -> LinkRelations {
+    let kind = match get_all_data(interface.join("uevent"), 1024) {
+        Ok(uevent) => match uevent
+            .lines()
+            .find_map(|line| line.strip_prefix("DEVTYPE="))
+        {
+            Some("vlan") => InterfaceKind::Vlan,
+            Some("bridge") => InterfaceKind::Bridge,
+            Some("bond") => InterfaceKind::Bond,
+            _ => InterfaceKind::Other,
+        },
+        Err(_) => InterfaceKind::Other,
+    };
+    // Both are symlinks to the other interfaces' directories.
+    let master = std::fs::read_link(interface.join("master"))
+        .ok()
+        .and_then(|master| Some(master.file_name()?.to_str()?.to_owned()));
+    let mut lower_interfaces: Vec<String> = match std::fs::read_dir(interface) {
+        Ok(dir) => dir
+            .flatten()
+            .filter_map(|entry| {
+                Some(
+                    entry
+                        .file_name()
+                        .to_str()?
+                        .strip_prefix("lower_")?
+                        .to_owned(),
+                )
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    lower_interfaces.sort_unstable();
+    LinkRelations {
+        kind,
+        master,
+        lower_interfaces,
+    }
+}
+
 fn refresh_networks_list_from_sysfs(
     interfaces: &mut HashMap<String, NetworkData>,
     sysfs_net: &Path,
+    handle_counter_wrap: bool,
 ) {
-    if let Ok(dir) = std::fs::read_dir(sysfs_net) {
-        let mut data = vec![0; 30];
-
-        for stats in interfaces.values_mut() {
-            stats.inner.updated = false;
+    let dir = match std::fs::read_dir(sysfs_net) {
+        Ok(dir) => dir,
+        Err(_e) => {
+            sysinfo_debug!("failed to list the network interfaces: {:?}", _e);
+            crate::utils::report_io_error(
+                sysfs_net.display(),
+                &_e,
+                crate::RefreshErrorKind::Unavailable,
+            );
+            return;
         }
+    };
+    let mut data = vec![0; 30];
 
-        for entry in dir.flatten() {
-            let parent = &entry.path().join("statistics");
-            let entry = match entry.file_name().into_string() {
-                Ok(entry) => entry,
-                Err(_) => continue,
-            };
-            let rx_bytes = read(parent, "rx_bytes", &mut data);
-            let tx_bytes = read(parent, "tx_bytes", &mut data);
-            let rx_packets = read(parent, "rx_packets", &mut data);
-            let tx_packets = read(parent, "tx_packets", &mut data);
-            let rx_errors = read(parent, "rx_errors", &mut data);
-            let tx_errors = read(parent, "tx_errors", &mut data);
-            // let rx_compressed = read(parent, "rx_compressed", &mut data);
-            // let tx_compressed = read(parent, "tx_compressed", &mut data);
-            match interfaces.entry(entry) {
-                hash_map::Entry::Occupied(mut e) => {
-                    let interface = e.get_mut();
-                    let interface = &mut interface.inner;
-
-                    old_and_new!(interface, rx_bytes, old_rx_bytes);
-                    old_and_new!(interface, tx_bytes, old_tx_bytes);
-                    old_and_new!(interface, rx_packets, old_rx_packets);
-                    old_and_new!(interface, tx_packets, old_tx_packets);
-                    old_and_new!(interface, rx_errors, old_rx_errors);
-                    old_and_new!(interface, tx_errors, old_tx_errors);
-                    // old_and_new!(e, rx_compressed, old_rx_compressed);
-                    // old_and_new!(e, tx_compressed, old_tx_compressed);
-                    interface.updated = true;
-                }
-                hash_map::Entry::Vacant(e) => {
-                    e.insert(NetworkData {
-                        inner: NetworkDataInner {
-                            rx_bytes,
-                            old_rx_bytes: rx_bytes,
-                            tx_bytes,
-                            old_tx_bytes: tx_bytes,
-                            rx_packets,
-                            old_rx_packets: rx_packets,
-                            tx_packets,
-                            old_tx_packets: tx_packets,
-                            rx_errors,
-                            old_rx_errors: rx_errors,
-                            tx_errors,
-                            old_tx_errors: tx_errors,
-                            mac_addr: MacAddr::UNSPECIFIED,
-                            // rx_compressed,
-                            // old_rx_compressed: rx_compressed,
-                            // tx_compressed,
-                            // old_tx_compressed: tx_compressed,
-                            updated: true,
-                        },
-                    });
-                }
-            };
-        }
+    for stats in interfaces.values_mut() {
+        stats.inner.updated = false;
+    }
 
-        // Remove interfaces which are gone.
-        interfaces.retain(|_, d| d.inner.updated);
+    for entry in dir.flatten() {
+        let link_info = get_link_info(&entry.path());
+        let link_state = get_link_state(&entry.path());
+        let relations = get_link_relations(&entry.path());
+        let carrier_changes = get_carrier_changes(&entry.path());
+        let description = get_description(&entry.path());
+        let parent = &entry.path().join("statistics");
+        let entry = match entry.file_name().into_string() {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+        let rx_bytes = read(parent, "rx_bytes", &mut data);
+        let tx_bytes = read(parent, "tx_bytes", &mut data);
+        let rx_packets = read(parent, "rx_packets", &mut data);
+        let tx_packets = read(parent, "tx_packets", &mut data);
+        let rx_errors = read(parent, "rx_errors", &mut data);
+        let tx_errors = read(parent, "tx_errors", &mut data);
+        let counters = get_counters(parent, &mut data);
+        // let rx_compressed = read(parent, "rx_compressed", &mut data);
+        // let tx_compressed = read(parent, "tx_compressed", &mut data);
+        #[cfg(feature = "wireless")]
+        let wireless = get_wireless_info(&entry);
+        match interfaces.entry(entry) {
+            hash_map::Entry::Occupied(mut e) => {
+                let interface = e.get_mut();
+                let interface = &mut interface.inner;
+
+                old_and_new!(interface, rx_bytes, old_rx_bytes);
+                old_and_new!(interface, tx_bytes, old_tx_bytes);
+                old_and_new!(interface, rx_packets, old_rx_packets);
+                old_and_new!(interface, tx_packets, old_tx_packets);
+                old_and_new!(interface, rx_errors, old_rx_errors);
+                old_and_new!(interface, tx_errors, old_tx_errors);
+                interface.refresh_times.update();
+                interface.counters = counters;
+                // old_and_new!(e, rx_compressed, old_rx_compressed);
+                // old_and_new!(e, tx_compressed, old_tx_compressed);
+                interface.mtu = link_info.mtu;
+                interface.link_speed = link_info.link_speed;
+                interface.duplex = link_info.duplex;
+                interface.link_state.update(link_state);
+                interface.carrier_changes = carrier_changes;
+                interface.kind = relations.kind;
+                interface.description = description;
+                interface.master = relations.master;
+                interface.lower_interfaces = relations.lower_interfaces;
+                #[cfg(feature = "wireless")]
+                {
+                    interface.wireless = wireless;
+                }
+                interface.updated = true;
+            }
+            hash_map::Entry::Vacant(e) => {
+                e.insert(NetworkData {
+                    inner: NetworkDataInner {
+                        rx_bytes,
+                        old_rx_bytes: rx_bytes,
+                        tx_bytes,
+                        old_tx_bytes: tx_bytes,
+                        rx_packets,
+                        old_rx_packets: rx_packets,
+                        tx_packets,
+                        old_tx_packets: tx_packets,
+                        rx_errors,
+                        old_rx_errors: rx_errors,
+                        tx_errors,
+                        old_tx_errors: tx_errors,
+                        refresh_times: RefreshTimes::new(),
+                        handle_counter_wrap,
+                        counters,
+                        mac_addr: MacAddr::UNSPECIFIED,
+                        ip_networks: Vec::new(),
+                        mtu: link_info.mtu,
+                        link_speed: link_info.link_speed,
+                        duplex: link_info.duplex,
+                        link_state,
+                        carrier_changes,
+                        kind: relations.kind,
+                        description,
+                        master: relations.master,
+                        lower_interfaces: relations.lower_interfaces,
+                        #[cfg(feature = "wireless")]
+                        wireless,
+                        // rx_compressed,
+                        // old_rx_compressed: rx_compressed,
+                        // tx_compressed,
+                        // old_tx_compressed: tx_compressed,
+                        updated: true,
+                    },
+                });
+            }
+        };
     }
+
+    // Remove interfaces which are gone.
+    interfaces.retain(|_, d| d.inner.updated);
 }
 
 pub(crate) struct NetworksInner {
     pub(crate) interfaces: HashMap<String, NetworkData>,
+    handle_counter_wrap: bool,
 }
 
 impl NetworksInner {
     pub(crate) fn new() -> Self {
         Self {
             interfaces: HashMap::new(),
+            handle_counter_wrap: true,
+        }
+    }
+
+    pub(crate) fn set_counter_wrap_handling(&mut self, enabled: bool) {
+        self.handle_counter_wrap = enabled;
+        for data in self.interfaces.values_mut() {
+            data.inner.handle_counter_wrap = enabled;
         }
     }
 
@@ -134,8 +327,22 @@ impl NetworksInner {
         }
     }
 
+    pub(crate) fn refresh_interfaces(&mut self, interface_names: &[&str]) {
+        let mut v = vec![0; 30];
+
+        for interface_name in interface_names {
+            if let Some(data) = self.interfaces.get_mut(*interface_name) {
+                data.inner.update(interface_name, &mut v);
+            }
+        }
+    }
+
     pub(crate) fn refresh_list(&mut self) {
-        refresh_networks_list_from_sysfs(&mut self.interfaces, Path::new("/sys/class/net/"));
+        refresh_networks_list_from_sysfs(
+            &mut self.interfaces,
+            Path::new("/sys/class/net/"),
+            self.handle_counter_wrap,
+        );
         refresh_networks_addresses(&mut self.interfaces);
     }
 }
@@ -161,8 +368,31 @@ pub(crate) struct NetworkDataInner {
     /// similar to `rx_errors`
     tx_errors: u64,
     old_tx_errors: u64,
+    refresh_times: RefreshTimes,
+    /// Whether the deltas of the counters take 32-bit wraps into account
+    handle_counter_wrap: bool,
+    counters: NetworkCounters,
     /// MAC address
     pub(crate) mac_addr: MacAddr,
+    /// IP networks
+    pub(crate) ip_networks: Vec<IpNetwork>,
+    /// Maximum Transmission Unit
+    mtu: u64,
+    /// Negotiated link speed, in bits per second
+    link_speed: Option<u64>,
+    duplex: DuplexMode,
+    link_state: LinkState,
+    /// Number of times the carrier was lost or detected
+    carrier_changes: Option<u64>,
+    kind: InterfaceKind,
+    /// Alias of the interface
+    description: Option<String>,
+    /// Bond or bridge this interface is a member of
+    master: Option<String>,
+    /// Interfaces this interface sits on
+    lower_interfaces: Vec<String>,
+    #[cfg(feature = "wireless")]
+    wireless: Option<WirelessInfo>,
     // /// Indicates the number of compressed packets received by this
     // /// network device. This value might only be relevant for interfaces
     // /// that support packet compression (e.g: PPP).
@@ -179,7 +409,14 @@ pub(crate) struct NetworkDataInner {
 
 impl NetworkDataInner {
     fn update(&mut self, path: &str, data: &mut Vec<u8>) {
-        let path = &Path::new("/sys/class/net/").join(path).join("statistics");
+        let interface = Path::new("/sys/class/net/").join(path);
+        self.link_state.update(get_link_state(&interface));
+        self.carrier_changes = get_carrier_changes(&interface);
+        #[cfg(feature = "wireless")]
+        {
+            self.wireless = get_wireless_info(path);
+        }
+        let path = &interface.join("statistics");
         old_and_new!(self, rx_bytes, old_rx_bytes, read(path, "rx_bytes", data));
         old_and_new!(self, tx_bytes, old_tx_bytes, read(path, "tx_bytes", data));
         old_and_new!(
@@ -206,6 +443,8 @@ impl NetworkDataInner {
             old_tx_errors,
             read(path, "tx_errors", data)
         );
+        self.refresh_times.update();
+        self.counters = get_counters(path, data);
         // old_and_new!(
         //     self,
         //     rx_compressed,
@@ -221,23 +460,35 @@ impl NetworkDataInner {
     }
 
     pub(crate) fn received(&self) -> u64 {
-        self.rx_bytes.saturating_sub(self.old_rx_bytes)
+        counter_delta(self.old_rx_bytes, self.rx_bytes, self.handle_counter_wrap)
     }
 
     pub(crate) fn total_received(&self) -> u64 {
         self.rx_bytes
     }
 
+    pub(crate) fn received_rate(&self) -> f64 {
+        self.refresh_times.rate(self.received())
+    }
+
     pub(crate) fn transmitted(&self) -> u64 {
-        self.tx_bytes.saturating_sub(self.old_tx_bytes)
+        counter_delta(self.old_tx_bytes, self.tx_bytes, self.handle_counter_wrap)
     }
 
     pub(crate) fn total_transmitted(&self) -> u64 {
         self.tx_bytes
     }
 
+    pub(crate) fn transmitted_rate(&self) -> f64 {
+        self.refresh_times.rate(self.transmitted())
+    }
+
     pub(crate) fn packets_received(&self) -> u64 {
-        self.rx_packets.saturating_sub(self.old_rx_packets)
+        counter_delta(
+            self.old_rx_packets,
+            self.rx_packets,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_packets_received(&self) -> u64 {
@@ -245,7 +496,11 @@ impl NetworkDataInner {
     }
 
     pub(crate) fn packets_transmitted(&self) -> u64 {
-        self.tx_packets.saturating_sub(self.old_tx_packets)
+        counter_delta(
+            self.old_tx_packets,
+            self.tx_packets,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_packets_transmitted(&self) -> u64 {
@@ -253,7 +508,7 @@ impl NetworkDataInner {
     }
 
     pub(crate) fn errors_on_received(&self) -> u64 {
-        self.rx_errors.saturating_sub(self.old_rx_errors)
+        counter_delta(self.old_rx_errors, self.rx_errors, self.handle_counter_wrap)
     }
 
     pub(crate) fn total_errors_on_received(&self) -> u64 {
@@ -261,23 +516,104 @@ impl NetworkDataInner {
     }
 
     pub(crate) fn errors_on_transmitted(&self) -> u64 {
-        self.tx_errors.saturating_sub(self.old_tx_errors)
+        counter_delta(self.old_tx_errors, self.tx_errors, self.handle_counter_wrap)
     }
 
     pub(crate) fn total_errors_on_transmitted(&self) -> u64 {
         self.tx_errors
     }
 
+    pub(crate) fn detailed_counters(&self) -> NetworkCounters {
+        self.counters
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
+
+    pub(crate) fn ip_networks(&self) -> &[IpNetwork] {
+        &self.ip_networks
+    }
+
+    pub(crate) fn mtu(&self) -> u64 {
+        self.mtu
+    }
+
+    pub(crate) fn link_speed(&self) -> Option<u64> {
+        self.link_speed
+    }
+
+    pub(crate) fn duplex(&self) -> DuplexMode {
+        self.duplex
+    }
+
+    pub(crate) fn operational_state(&self) -> OperationalState {
+        self.link_state.operational_state
+    }
+
+    pub(crate) fn is_admin_up(&self) -> bool {
+        self.link_state.is_admin_up
+    }
+
+    pub(crate) fn has_carrier(&self) -> Option<bool> {
+        self.link_state.has_carrier
+    }
+
+    pub(crate) fn carrier_changes(&self) -> Option<u64> {
+        self.carrier_changes
+    }
+
+    pub(crate) fn last_state_change(&self) -> Option<u64> {
+        self.link_state.last_change
+    }
+
+    pub(crate) fn kind(&self) -> InterfaceKind {
+        self.kind
+    }
+
+    pub(crate) fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub(crate) fn master(&self) -> Option<&str> {
+        self.master.as_deref()
+    }
+
+    pub(crate) fn lower_interfaces(&self) -> &[String] {
+        &self.lower_interfaces
+    }
+
+    #[cfg(feature = "wireless")]
+    pub(crate) fn wireless(&self) -> Option<&WirelessInfo> {
+        self.wireless.as_ref()
+    }
+}
+
+pub(crate) struct NetworkEventsInner {
+    // Receives the rtnetlink notifications about links and addresses.
+    socket: NetlinkSocket,
+}
+
+impl NetworkEventsInner {
+    pub(crate) fn new() -> Option<Self> {
+        let groups = libc::RTMGRP_LINK | libc::RTMGRP_IPV4_IFADDR | libc::RTMGRP_IPV6_IFADDR;
+        Some(Self {
+            socket: NetlinkSocket::subscribe(libc::NETLINK_ROUTE, groups as _)?,
+        })
+    }
+
+    pub(crate) fn wait(&mut self, timeout: Option<Duration>) -> bool {
+        self.socket.wait(timeout)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::refresh_networks_list_from_sysfs;
+    use crate::{DuplexMode, InterfaceKind, OperationalState};
     use std::collections::HashMap;
     use std::fs;
+    use std::os::unix::fs::symlink;
 
     #[test]
     fn refresh_networks_list_add_interface() {
@@ -287,12 +623,12 @@ mod test {
 
         let mut interfaces = HashMap::new();
 
-        refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path(), true);
         assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["itf1"]);
 
         fs::create_dir(sys_net_dir.path().join("itf2")).expect("failed to create subdirectory");
 
-        refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path(), true);
         let mut itf_names: Vec<String> = interfaces.keys().map(|n| n.to_owned()).collect();
         itf_names.sort();
         assert_eq!(itf_names, ["itf1", "itf2"]);
@@ -309,14 +645,154 @@ mod test {
 
         let mut interfaces = HashMap::new();
 
-        refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path(), true);
         let mut itf_names: Vec<String> = interfaces.keys().map(|n| n.to_owned()).collect();
         itf_names.sort();
         assert_eq!(itf_names, ["itf1", "itf2"]);
 
         fs::remove_dir(&itf1_dir).expect("failed to remove subdirectory");
 
-        refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path(), true);
         assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["itf2"]);
     }
+
+    #[test]
+    fn refresh_networks_list_link_info() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let eth0 = sys_net_dir.path().join("eth0");
+        fs::create_dir(&eth0).expect("failed to create subdirectory");
+        fs::write(eth0.join("mtu"), "1500\n").expect("failed to write file");
+        fs::write(eth0.join("speed"), "1000\n").expect("failed to write file");
+        fs::write(eth0.join("duplex"), "full\n").expect("failed to write file");
+        fs::write(eth0.join("ifalias"), "uplink\n").expect("failed to write file");
+        let lo = sys_net_dir.path().join("lo");
+        fs::create_dir(&lo).expect("failed to create subdirectory");
+        fs::write(lo.join("mtu"), "65536\n").expect("failed to write file");
+        fs::write(lo.join("speed"), "-1\n").expect("failed to write file");
+        fs::write(lo.join("ifalias"), "\n").expect("failed to write file");
+
+        let mut interfaces = HashMap::new();
+        refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path(), true);
+
+        let eth0 = &interfaces["eth0"];
+        assert_eq!(eth0.mtu(), 1500);
+        assert_eq!(eth0.link_speed(), Some(1_000_000_000));
+        assert_eq!(eth0.duplex(), DuplexMode::Full);
+        assert_eq!(eth0.description(), Some("uplink"));
+        let lo = &interfaces["lo"];
+        assert_eq!(lo.mtu(), 65536);
+        assert_eq!(lo.link_speed(), None);
+        assert_eq!(lo.duplex(), DuplexMode::Unknown);
+        assert_eq!(lo.description(), None);
+    }
+
+    #[test]
+    fn refresh_networks_list_counters() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let statistics = sys_net_dir.path().join("eth0/statistics");
+        fs::create_dir_all(&statistics).expect("failed to create subdirectory");
+        for (file, value) in [
+            ("multicast", "12\n"),
+            ("collisions", "3\n"),
+            ("rx_dropped", "7\n"),
+            ("rx_frame_errors", "1\n"),
+        ] {
+            fs::write(statistics.join(file), value).expect("failed to write file");
+        }
+
+        let mut interfaces = HashMap::new();
+        refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path(), true);
+
+        let counters = interfaces["eth0"].detailed_counters();
+        assert_eq!(counters.multicast_received, Some(12));
+        assert_eq!(counters.multicast_transmitted, None);
+        assert_eq!(counters.collisions, Some(3));
+        assert_eq!(counters.dropped_on_received, Some(7));
+        assert_eq!(counters.dropped_on_transmitted, Some(0));
+        assert_eq!(counters.frame_errors_on_received, Some(1));
+    }
+
+    #[test]
+    fn refresh_networks_list_link_state() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let eth0 = sys_net_dir.path().join("eth0");
+        fs::create_dir(&eth0).expect("failed to create subdirectory");
+        let write = |file: &str, content: &str| {
+            fs::write(eth0.join(file), content).expect("failed to write file");
+        };
+        write("operstate", "up\n");
+        write("flags", "0x1003\n");
+        write("carrier", "1\n");
+        write("carrier_changes", "2\n");
+
+        let mut interfaces = HashMap::new();
+        refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path(), true);
+
+        let eth0 = &interfaces["eth0"];
+        assert_eq!(eth0.operational_state(), OperationalState::Up);
+        assert!(eth0.is_admin_up());
+        assert_eq!(eth0.has_carrier(), Some(true));
+        assert_eq!(eth0.carrier_changes(), Some(2));
+        assert_eq!(eth0.last_state_change(), None);
+
+        // The cable was unplugged.
+        write("operstate", "down\n");
+        write("carrier", "0\n");
+        write("carrier_changes", "3\n");
+        refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path(), true);
+
+        let eth0 = &interfaces["eth0"];
+        assert_eq!(eth0.operational_state(), OperationalState::Down);
+        assert!(eth0.is_admin_up());
+        assert_eq!(eth0.has_carrier(), Some(false));
+        assert_eq!(eth0.carrier_changes(), Some(3));
+        assert!(eth0.last_state_change().is_some());
+    }
+
+    #[test]
+    fn refresh_networks_list_relations() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let create = |name: &str, devtype: Option<&str>| {
+            let dir = sys_net_dir.path().join(name);
+            fs::create_dir(&dir).expect("failed to create subdirectory");
+            if let Some(devtype) = devtype {
+                fs::write(
+                    dir.join("uevent"),
+                    format!("DEVTYPE={devtype}\nINTERFACE={name}\n"),
+                )
+                .expect("failed to write file");
+            }
+            dir
+        };
+        let eth0 = create("eth0", None);
+        let eth1 = create("eth1", None);
+        let bond0 = create("bond0", Some("bond"));
+        let vlan = create("bond0.10", Some("vlan"));
+        for (from, to) in [(&eth0, "bond0"), (&eth1, "bond0")] {
+            symlink(format!("../{to}"), from.join("master")).expect("failed to create symlink");
+        }
+        for (from, to) in [(&bond0, "eth0"), (&bond0, "eth1"), (&vlan, "bond0")] {
+            symlink(format!("../{to}"), from.join(format!("lower_{to}")))
+                .expect("failed to create symlink");
+        }
+
+        let mut interfaces = HashMap::new();
+        refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path(), true);
+
+        let eth0 = &interfaces["eth0"];
+        assert_eq!(eth0.kind(), InterfaceKind::Other);
+        assert_eq!(eth0.master(), Some("bond0"));
+        assert!(eth0.lower_interfaces().is_empty());
+        let bond0 = &interfaces["bond0"];
+        assert_eq!(bond0.kind(), InterfaceKind::Bond);
+        assert_eq!(bond0.master(), None);
+        assert_eq!(bond0.lower_interfaces(), ["eth0", "eth1"]);
+        let vlan = &interfaces["bond0.10"];
+        assert_eq!(vlan.kind(), InterfaceKind::Vlan);
+        assert_eq!(vlan.lower_interfaces(), ["bond0"]);
+    }
 }
@@ -0,0 +1,253 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// The proprietary NVIDIA driver doesn't register a `hwmon` device, the GPU temperatures are
+// only available through NVML. The library is loaded at runtime since it is only installed
+// alongside the driver.
+
+use libc::{c_char, c_int, c_uint, c_void};
+
+use std::ffi::CStr;
+use std::sync::Arc;
+
+type NvmlDevice = *mut c_void;
+
+// From `nvml.h`.
+const NVML_SUCCESS: c_int = 0;
+const NVML_TEMPERATURE_GPU: c_int = 0;
+const NVML_TEMPERATURE_THRESHOLD_SHUTDOWN: c_int = 0;
+const NVML_DEVICE_NAME_BUFFER_SIZE: usize = 96;
+const NVML_DEVICE_UUID_V2_BUFFER_SIZE: usize = 96;
+const NVML_SYSTEM_DRIVER_VERSION_BUFFER_SIZE: usize = 80;
+
+#[repr(C)]
+struct NvmlUtilization {
+    gpu: c_uint,
+    memory: c_uint,
+}
+
+#[repr(C)]
+struct NvmlMemory {
+    total: u64,
+    free: u64,
+    used: u64,
+}
+
+type InitFn = unsafe extern "C" fn() -> c_int;
+type GetCountFn = unsafe extern "C" fn(*mut c_uint) -> c_int;
+type GetHandleByIndexFn = unsafe extern "C" fn(c_uint, *mut NvmlDevice) -> c_int;
+type GetNameFn = unsafe extern "C" fn(NvmlDevice, *mut c_char, c_uint) -> c_int;
+type GetTemperatureFn = unsafe extern "C" fn(NvmlDevice, c_int, *mut c_uint) -> c_int;
+type GetUtilizationFn = unsafe extern "C" fn(NvmlDevice, *mut NvmlUtilization) -> c_int;
+type GetMemoryInfoFn = unsafe extern "C" fn(NvmlDevice, *mut NvmlMemory) -> c_int;
+type GetPowerUsageFn = unsafe extern "C" fn(NvmlDevice, *mut c_uint) -> c_int;
+type GetDriverVersionFn = unsafe extern "C" fn(*mut c_char, c_uint) -> c_int;
+
+pub(crate) struct Nvml {
+    library: *mut c_void,
+    shutdown: InitFn,
+    get_count: GetCountFn,
+    get_handle_by_index: GetHandleByIndexFn,
+    get_name: GetNameFn,
+    get_uuid: GetNameFn,
+    get_temperature: GetTemperatureFn,
+    get_temperature_threshold: GetTemperatureFn,
+    get_utilization: GetUtilizationFn,
+    get_memory_info: GetMemoryInfoFn,
+    get_power_usage: GetPowerUsageFn,
+    get_driver_version: GetDriverVersionFn,
+}
+
+// NVML functions are thread-safe.
+unsafe impl Send for Nvml {}
+unsafe impl Sync for Nvml {}
+
+unsafe fn symbol<T: Copy>(library: *mut c_void, name: &[u8]) -> Option<T> {
+    let symbol = libc::dlsym(library, name.as_ptr() as *const c_char);
+    if symbol.is_null() {
+        sysinfo_debug!("`{:?}` not found in NVML", CStr::from_bytes_with_nul(name));
+        None
+    } else {
+        Some(std::mem::transmute_copy(&symbol))
+    }
+}
+
+impl Nvml {
+    /// Loads and initializes NVML. Returns `None` if it isn't installed.
+    pub(crate) fn load() -> Option<Arc<Self>> {
+        unsafe {
+            let library = libc::dlopen(
+                b"libnvidia-ml.so.1\0".as_ptr() as *const c_char,
+                libc::RTLD_NOW | libc::RTLD_LOCAL,
+            );
+            if library.is_null() {
+                return None;
+            }
+            let nvml = Self::init(library);
+            if nvml.is_none() {
+                libc::dlclose(library);
+            }
+            nvml.map(Arc::new)
+        }
+    }
+
+    unsafe fn init(library: *mut c_void) -> Option<Self> {
+        let init: InitFn = symbol(library, b"nvmlInit_v2\0")?;
+        let shutdown = symbol(library, b"nvmlShutdown\0")?;
+        let get_count = symbol(library, b"nvmlDeviceGetCount_v2\0")?;
+        let get_handle_by_index = symbol(library, b"nvmlDeviceGetHandleByIndex_v2\0")?;
+        let get_name = symbol(library, b"nvmlDeviceGetName\0")?;
+        let get_uuid = symbol(library, b"nvmlDeviceGetUUID\0")?;
+        let get_temperature = symbol(library, b"nvmlDeviceGetTemperature\0")?;
+        let get_temperature_threshold = symbol(library, b"nvmlDeviceGetTemperatureThreshold\0")?;
+        let get_utilization = symbol(library, b"nvmlDeviceGetUtilizationRates\0")?;
+        let get_memory_info = symbol(library, b"nvmlDeviceGetMemoryInfo\0")?;
+        let get_power_usage = symbol(library, b"nvmlDeviceGetPowerUsage\0")?;
+        let get_driver_version = symbol(library, b"nvmlSystemGetDriverVersion\0")?;
+        if init() != NVML_SUCCESS {
+            sysinfo_debug!("failed to initialize NVML");
+            return None;
+        }
+        Some(Self {
+            library,
+            shutdown,
+            get_count,
+            get_handle_by_index,
+            get_name,
+            get_uuid,
+            get_temperature,
+            get_temperature_threshold,
+            get_utilization,
+            get_memory_info,
+            get_power_usage,
+            get_driver_version,
+        })
+    }
+
+    fn device(&self, index: u32) -> Option<NvmlDevice> {
+        let mut device = std::ptr::null_mut();
+        if unsafe { (self.get_handle_by_index)(index, &mut device) } == NVML_SUCCESS {
+            Some(device)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index and name of the GPUs.
+    pub(crate) fn gpus(&self) -> Vec<(u32, String)> {
+        let mut count = 0;
+        if unsafe { (self.get_count)(&mut count) } != NVML_SUCCESS {
+            return Vec::new();
+        }
+        (0..count)
+            .filter_map(|index| {
+                let name = self.string(index, self.get_name, NVML_DEVICE_NAME_BUFFER_SIZE)?;
+                Some((index, name))
+            })
+            .collect()
+    }
+
+    /// Returns the UUID of the GPU `index`, which doesn't change across reboots.
+    pub(crate) fn uuid(&self, index: u32) -> Option<String> {
+        self.string(index, self.get_uuid, NVML_DEVICE_UUID_V2_BUFFER_SIZE)
+    }
+
+    fn string(&self, index: u32, getter: GetNameFn, size: usize) -> Option<String> {
+        let device = self.device(index)?;
+        let mut buf = vec![0 as c_char; size];
+        unsafe {
+            if getter(device, buf.as_mut_ptr(), buf.len() as _) != NVML_SUCCESS {
+                return None;
+            }
+            Some(CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned())
+        }
+    }
+
+    /// Returns the temperature of the GPU `index`, in celsius degree.
+    pub(crate) fn temperature(&self, index: u32) -> Option<f32> {
+        let device = self.device(index)?;
+        let mut temperature = 0;
+        if unsafe { (self.get_temperature)(device, NVML_TEMPERATURE_GPU, &mut temperature) }
+            == NVML_SUCCESS
+        {
+            Some(temperature as f32)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the temperature at which the GPU `index` shuts down, in celsius degree.
+    pub(crate) fn critical(&self, index: u32) -> Option<f32> {
+        let device = self.device(index)?;
+        let mut temperature = 0;
+        if unsafe {
+            (self.get_temperature_threshold)(
+                device,
+                NVML_TEMPERATURE_THRESHOLD_SHUTDOWN,
+                &mut temperature,
+            )
+        } == NVML_SUCCESS
+        {
+            Some(temperature as f32)
+        } else {
+            None
+        }
+    }
+
+    /// Returns how busy the GPU `index` is, in %.
+    pub(crate) fn usage(&self, index: u32) -> Option<f32> {
+        let device = self.device(index)?;
+        let mut utilization = NvmlUtilization { gpu: 0, memory: 0 };
+        if unsafe { (self.get_utilization)(device, &mut utilization) } == NVML_SUCCESS {
+            Some(utilization.gpu as f32)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the total and used memory of the GPU `index`, in bytes.
+    pub(crate) fn memory(&self, index: u32) -> Option<(u64, u64)> {
+        let device = self.device(index)?;
+        let mut memory = NvmlMemory {
+            total: 0,
+            free: 0,
+            used: 0,
+        };
+        if unsafe { (self.get_memory_info)(device, &mut memory) } == NVML_SUCCESS {
+            Some((memory.total, memory.used))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the power drawn by the GPU `index`, in watts.
+    pub(crate) fn power(&self, index: u32) -> Option<f32> {
+        let device = self.device(index)?;
+        let mut power = 0;
+        // The power is given in milliwatts.
+        if unsafe { (self.get_power_usage)(device, &mut power) } == NVML_SUCCESS {
+            Some(power as f32 / 1_000.)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the version of the driver, which is the same for all the GPUs.
+    pub(crate) fn driver_version(&self) -> Option<String> {
+        let mut buf = vec![0 as c_char; NVML_SYSTEM_DRIVER_VERSION_BUFFER_SIZE];
+        unsafe {
+            if (self.get_driver_version)(buf.as_mut_ptr(), buf.len() as _) != NVML_SUCCESS {
+                return None;
+            }
+            Some(CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned())
+        }
+    }
+}
+
+impl Drop for Nvml {
+    fn drop(&mut self) {
+        unsafe {
+            (self.shutdown)();
+            libc::dlclose(self.library);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::get_all_data;
+use crate::PciDevice;
+
+use std::fs::read_dir;
+use std::path::Path;
+
+const PCI_IDS_FILES: &[&str] = &["/usr/share/hwdata/pci.ids", "/usr/share/misc/pci.ids"];
+
+pub(crate) fn get_pci_devices(devices: &mut Vec<PciDevice>) {
+    let mut entries = match read_dir("/sys/bus/pci/devices") {
+        Ok(entries) => entries
+            .flatten()
+            .map(|entry| {
+                (
+                    entry.file_name().to_string_lossy().into_owned(),
+                    entry.path(),
+                )
+            })
+            .collect::<Vec<_>>(),
+        Err(_) => return,
+    };
+    entries.sort_unstable();
+    let pci_ids = read_pci_ids();
+
+    for (address, device) in entries {
+        let hex = |file: &str| {
+            get_all_data(device.join(file), 16)
+                .ok()
+                .and_then(|id| u32::from_str_radix(id.trim().trim_start_matches("0x"), 16).ok())
+        };
+        let (vendor_id, device_id) = match (hex("vendor"), hex("device")) {
+            (Some(vendor_id), Some(device_id)) => (vendor_id as u16, device_id as u16),
+            _ => continue,
+        };
+        let (vendor_name, device_name) = pci_names(&pci_ids, vendor_id, device_id);
+        devices.push(PciDevice {
+            address: Some(address),
+            class: hex("class").unwrap_or(0),
+            vendor_id,
+            device_id,
+            vendor_name,
+            device_name,
+            driver: get_driver(&device),
+            link_width: get_all_data(device.join("current_link_width"), 16)
+                .ok()
+                .and_then(|width| width.trim().parse::<u8>().ok())
+                // Devices which aren't PCIe can report a width of `0` or `255`.
+                .filter(|width| (1..=32).contains(width)),
+            link_speed: get_all_data(device.join("current_link_speed"), 32)
+                .ok()
+                .and_then(|speed| parse_link_speed(&speed)),
+        });
+    }
+}
+
+fn get_driver(device: &Path) -> Option<String> {
+    let driver = std::fs::read_link(device.join("driver")).ok()?;
+    Some(driver.file_name()?.to_string_lossy().into_owned())
+}
+
+/// Returns the content of the `pci.ids` database, or an empty string if it isn't installed.
+pub(crate) fn read_pci_ids() -> String {
+    PCI_IDS_FILES
+        .iter()
+        .find_map(|file| std::fs::read_to_string(file).ok())
+        .unwrap_or_default()
+}
+
+/// Returns the names of the vendor and of the device from the content of the `pci.ids`
+/// database.
+pub(crate) fn pci_names(
+    pci_ids: &str,
+    vendor_id: u16,
+    device_id: u16,
+) -> (Option<String>, Option<String>) {
+    let vendor = format!("{vendor_id:04x} ");
+    let device = format!("\t{device_id:04x} ");
+    let mut vendor_name = None;
+
+    for line in pci_ids.lines() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if !line.starts_with('\t') {
+            // The devices of a vendor are listed right after it.
+            if vendor_name.is_some() {
+                break;
+            }
+            if line.starts_with(&vendor) {
+                vendor_name = Some(line[vendor.len()..].trim().to_owned());
+            }
+        } else if vendor_name.is_some() && line.starts_with(&device) {
+            return (vendor_name, Some(line[device.len()..].trim().to_owned()));
+        }
+    }
+    (vendor_name, None)
+}
+
+/// Returns the link speed (in GT/s) from a value like `8.0 GT/s PCIe`, or `None` if it is
+/// `Unknown`.
+fn parse_link_speed(speed: &str) -> Option<f32> {
+    speed
+        .trim()
+        .split(' ')
+        .next()?
+        .parse::<f32>()
+        .ok()
+        .filter(|speed| *speed > 0.)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_link_speed, pci_names};
+
+    #[test]
+    fn check_pci_names() {
+        let pci_ids = "\
+# Vendors, devices and subsystems.
+1002  Advanced Micro Devices, Inc. [AMD/ATI]
+\t73bf  Navi 21 [Radeon RX 6800/6800 XT / 6900 XT]
+\t\t1002 0e3a  Radeon RX 6900 XT
+\t73df  Navi 22 [Radeon RX 6700/6700 XT/6750 XT / 6800M/6850M XT]
+8086  Intel Corporation
+\t73bf  Not a GPU
+\t9a49  TigerLake-LP GT2 [Iris Xe Graphics]
+";
+        assert_eq!(
+            pci_names(pci_ids, 0x1002, 0x73bf),
+            (
+                Some("Advanced Micro Devices, Inc. [AMD/ATI]".to_owned()),
+                Some("Navi 21 [Radeon RX 6800/6800 XT / 6900 XT]".to_owned())
+            )
+        );
+        assert_eq!(
+            pci_names(pci_ids, 0x8086, 0x9a49),
+            (
+                Some("Intel Corporation".to_owned()),
+                Some("TigerLake-LP GT2 [Iris Xe Graphics]".to_owned())
+            )
+        );
+        assert_eq!(
+            pci_names(pci_ids, 0x1002, 0x9a49),
+            (
+                Some("Advanced Micro Devices, Inc. [AMD/ATI]".to_owned()),
+                None
+            )
+        );
+        assert_eq!(pci_names(pci_ids, 0x10de, 0x2206), (None, None));
+    }
+
+    #[test]
+    fn check_parse_link_speed() {
+        assert_eq!(parse_link_speed("8.0 GT/s PCIe\n"), Some(8.));
+        assert_eq!(parse_link_speed("2.5 GT/s"), Some(2.5));
+        assert_eq!(parse_link_speed("Unknown"), None);
+    }
+}
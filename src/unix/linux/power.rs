@@ -0,0 +1,134 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::energy::EnergyWrapper;
+use crate::sys::utils::get_all_data;
+use crate::{EnergyDomain, PowerRail, PowerRailKind};
+
+use std::fs::read_dir;
+
+pub(crate) struct PowerInner {
+    energy: EnergyWrapper,
+    rails: Vec<PowerRail>,
+}
+
+impl PowerInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            energy: EnergyWrapper::new(),
+            rails: Vec::new(),
+        }
+    }
+
+    pub(crate) fn rails(&self) -> &[PowerRail] {
+        &self.rails
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        self.energy.refresh();
+        self.rails.clear();
+        self.rails.extend(
+            self.energy
+                .domains()
+                .iter()
+                .map(EnergyDomain::to_power_rail),
+        );
+
+        let dir = match read_dir("/sys/class/power_supply") {
+            Ok(dir) => dir,
+            Err(_) => return,
+        };
+        let mut batteries = Vec::new();
+        for entry in dir.flatten() {
+            let path = entry.path();
+            let read = |file: &str| {
+                get_all_data(path.join(file), 64)
+                    .ok()
+                    .map(|data| data.trim().to_owned())
+            };
+            if let Some(power) = parse_discharge_power(read) {
+                batteries.push(PowerRail {
+                    name: entry.file_name().to_string_lossy().into_owned(),
+                    kind: PowerRailKind::Battery,
+                    power,
+                });
+            }
+        }
+        batteries.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        self.rails.extend(batteries);
+    }
+}
+
+/// Returns the power (in watts) drawn from a discharging battery, from the `power_supply`
+/// attributes returned by `read`.
+///
+/// Depending on the driver, the power is either given in µW (`power_now`) or computed from the
+/// current in µA and the voltage in µV. Some drivers report a negative current when
+/// discharging.
+fn parse_discharge_power<F: Fn(&str) -> Option<String>>(read: F) -> Option<f32> {
+    if read("type").as_deref() != Some("Battery")
+        || read("scope").as_deref() == Some("Device")
+        || read("status").as_deref() != Some("Discharging")
+    {
+        return None;
+    }
+    let number = |file: &str| {
+        read(file)
+            .and_then(|value| value.parse::<i64>().ok())
+            .map(|value| value.unsigned_abs() as f64)
+    };
+    let power = number("power_now")
+        .map(|power| power / 1_000_000.)
+        .or_else(|| Some(number("current_now")? * number("voltage_now")? / 1e12))?;
+    Some(power as f32)
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_discharge_power;
+
+    use std::collections::HashMap;
+
+    fn parse(attributes: &[(&str, &str)]) -> Option<f32> {
+        let attributes: HashMap<_, _> = attributes.iter().copied().collect();
+        parse_discharge_power(|file| attributes.get(file).map(|value| value.to_string()))
+    }
+
+    #[test]
+    fn check_parse_discharge_power() {
+        assert_eq!(
+            parse(&[
+                ("type", "Battery"),
+                ("status", "Discharging"),
+                ("power_now", "12500000"),
+            ]),
+            Some(12.5)
+        );
+        assert_eq!(
+            parse(&[
+                ("type", "Battery"),
+                ("status", "Discharging"),
+                ("current_now", "-1000000"),
+                ("voltage_now", "11000000"),
+            ]),
+            Some(11.)
+        );
+        assert_eq!(
+            parse(&[
+                ("type", "Battery"),
+                ("status", "Charging"),
+                ("power_now", "12500000"),
+            ]),
+            None
+        );
+        assert_eq!(
+            parse(&[
+                ("type", "Battery"),
+                ("scope", "Device"),
+                ("status", "Discharging"),
+                ("power_now", "100000"),
+            ]),
+            None
+        );
+        assert_eq!(parse(&[("type", "Mains"), ("online", "1")]), None);
+    }
+}
@@ -1,12 +1,14 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use std::borrow::Cow;
 use std::cell::UnsafeCell;
 use std::collections::HashMap;
 use std::fmt;
 use std::fs::{self, File};
-use std::io::Read;
+use std::io::{self, Read};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 use libc::{gid_t, kill, uid_t};
 
@@ -15,7 +17,10 @@ use crate::sys::utils::{
     get_all_data, get_all_data_from_file, realpath, FileCounter, PathHandler, PathPush,
 };
 use crate::utils::into_iter;
-use crate::{DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::{
+    DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, StringEncodingPolicy,
+    Uid,
+};
 
 #[doc(hidden)]
 impl From<char> for ProcessStatus {
@@ -71,11 +76,14 @@ pub(crate) struct ProcessInner {
     stime: u64,
     old_utime: u64,
     old_stime: u64,
+    /// Number of clock ticks per second, used to convert `utime` and `stime`.
+    clock_cycle: u64,
     start_time_without_boot_time: u64,
     start_time: u64,
     run_time: u64,
     pub(crate) updated: bool,
     cpu_usage: f32,
+    normalized_cpu_usage: f32,
     user_id: Option<Uid>,
     effective_user_id: Option<Uid>,
     group_id: Option<Gid>,
@@ -103,10 +111,12 @@ impl ProcessInner {
             memory: 0,
             virtual_memory: 0,
             cpu_usage: 0.,
+            normalized_cpu_usage: 0.,
             utime: 0,
             stime: 0,
             old_utime: 0,
             old_stime: 0,
+            clock_cycle: 0,
             updated: true,
             start_time_without_boot_time: 0,
             start_time: 0,
@@ -190,6 +200,23 @@ impl ProcessInner {
         self.cpu_usage
     }
 
+    pub(crate) fn normalized_cpu_usage(&self) -> f32 {
+        self.normalized_cpu_usage
+    }
+
+    pub(crate) fn set_cpu_usage(&mut self, cpu_usage: f32, normalized_cpu_usage: f32) {
+        self.cpu_usage = cpu_usage;
+        self.normalized_cpu_usage = normalized_cpu_usage;
+    }
+
+    pub(crate) fn user_time(&self) -> Duration {
+        ticks_to_duration(self.utime, self.clock_cycle)
+    }
+
+    pub(crate) fn kernel_time(&self) -> Duration {
+        ticks_to_duration(self.stime, self.clock_cycle)
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
@@ -239,6 +266,14 @@ impl ProcessInner {
             }
         }
     }
+
+    pub(crate) fn is_translated(&self) -> bool {
+        is_binfmt_qemu_interpreter(&self.exe, Path::new("/proc/sys/fs/binfmt_misc"))
+    }
+
+    pub(crate) fn is_remote_session(&self) -> bool {
+        crate::unix::sessions::is_remote_environ(&self.environ)
+    }
 }
 
 pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, total_time: f32, max_value: f32) {
@@ -256,12 +291,22 @@ pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, total_time: f32, max_value
         / total_time
         * 100.)
         .min(max_value);
+    // `max_value` is `"number of CPUs" * 100.`.
+    p.normalized_cpu_usage = p.cpu_usage / max_value * 100.;
 
     for task in p.tasks.values_mut() {
         compute_cpu_usage(&mut task.inner, total_time, max_value);
     }
 }
 
+fn ticks_to_duration(ticks: u64, clock_cycle: u64) -> Duration {
+    if clock_cycle == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_secs(ticks / clock_cycle)
+        + Duration::from_nanos((ticks % clock_cycle) * 1_000_000_000 / clock_cycle)
+}
+
 pub(crate) fn unset_updated(p: &mut ProcessInner) {
     p.updated = false;
     for task in p.tasks.values_mut() {
@@ -329,10 +374,20 @@ fn compute_start_time_without_boot_time(parts: &[&str], info: &SystemInfo) -> u6
     u64::from_str(parts[21]).unwrap_or(0) / info.clock_cycle
 }
 
-fn _get_stat_data(path: &Path, stat_file: &mut Option<FileCounter>) -> Result<String, ()> {
-    let mut file = File::open(path.join("stat")).map_err(|_| ())?;
-    let data = get_all_data_from_file(&mut file, 1024).map_err(|_| ())?;
-    *stat_file = FileCounter::new(file);
+fn _get_stat_data(
+    path: &Path,
+    stat_file: &mut Option<FileCounter>,
+    info: &SystemInfo,
+) -> Result<String, ()> {
+    let stat_path = path.join("stat");
+    let report = |e: io::Error| {
+        crate::utils::report_io_error(stat_path.display(), &e, crate::RefreshErrorKind::Vanished)
+    };
+    let mut file = File::open(&stat_path).map_err(&report)?;
+    let data = get_all_data_from_file(&mut file, 1024).map_err(report)?;
+    if info.keep_open_files {
+        *stat_file = FileCounter::new(file);
+    }
     Ok(data)
 }
 
@@ -402,8 +457,8 @@ fn retrieve_all_new_process_info(
         }
     }
 
-    p.cmd = copy_from_file(tmp.join("cmdline"));
-    p.environ = copy_from_file(tmp.join("environ"));
+    p.cmd = copy_from_file(tmp.join("cmdline"), info.string_encoding_policy);
+    p.environ = copy_from_file(tmp.join("environ"), info.string_encoding_policy);
     p.cwd = realpath(tmp.join("cwd"));
     p.root = realpath(tmp.join("root"));
 
@@ -457,11 +512,11 @@ pub(crate) fn _get_process_data(
                 Err(_) => {
                     // It's possible that the file descriptor is no longer valid in case the
                     // original process was terminated and another one took its place.
-                    _get_stat_data(path, &mut entry.stat_file)?
+                    _get_stat_data(path, &mut entry.stat_file, info)?
                 }
             }
         } else {
-            _get_stat_data(path, &mut entry.stat_file)?
+            _get_stat_data(path, &mut entry.stat_file, info)?
         };
         let parts = parse_stat_file(&data).ok_or(())?;
         let start_time_without_boot_time = compute_start_time_without_boot_time(&parts, info);
@@ -492,7 +547,7 @@ pub(crate) fn _get_process_data(
         parts
     } else {
         let mut stat_file = None;
-        let data = _get_stat_data(path, &mut stat_file)?;
+        let data = _get_stat_data(path, &mut stat_file, info)?;
         let parts = parse_stat_file(&data).ok_or(())?;
 
         let mut p =
@@ -538,6 +593,7 @@ fn update_time_and_memory(
         if entry.virtual_memory >= parent_virtual_memory {
             entry.virtual_memory -= parent_virtual_memory;
         }
+        entry.clock_cycle = info.clock_cycle;
         set_time(
             entry,
             u64::from_str(parts[13]).unwrap_or(0),
@@ -565,7 +621,17 @@ pub(crate) fn refresh_procs(
 ) -> bool {
     let d = match fs::read_dir(path) {
         Ok(d) => d,
-        Err(_) => return false,
+        Err(_e) => {
+            if pid.0 == 0 {
+                sysinfo_debug!("failed to list the processes: {:?}", _e);
+                crate::utils::report_io_error(
+                    path.display(),
+                    &_e,
+                    crate::RefreshErrorKind::Unavailable,
+                );
+            }
+            return false;
+        }
     };
     let folders = d
         .filter_map(|entry| {
@@ -585,17 +651,21 @@ pub(crate) fn refresh_procs(
         #[cfg(feature = "multithread")]
         use rayon::iter::ParallelIterator;
 
+        let errors = crate::utils::ErrorCollector::current();
         into_iter(folders)
             .filter_map(|e| {
-                let (p, _) = _get_process_data(
-                    e.as_path(),
-                    proc_list.get(),
-                    pid,
-                    uptime,
-                    info,
-                    refresh_kind,
-                )
-                .ok()?;
+                let (p, _) = errors
+                    .run(|| {
+                        _get_process_data(
+                            e.as_path(),
+                            proc_list.get(),
+                            pid,
+                            uptime,
+                            info,
+                            refresh_kind,
+                        )
+                    })
+                    .ok()?;
                 p
             })
             .collect::<Vec<_>>()
@@ -614,7 +684,7 @@ pub(crate) fn refresh_procs(
         // Sub-tasks are not cleaned up outside so we do it here directly.
         proc_list
             .tasks
-            .retain(|&pid, _| updated_pids.iter().any(|&x| x == pid));
+            .retain(|&pid, _| updated_pids.contains(&pid));
         new_tasks
     }
     .into_iter()
@@ -624,7 +694,7 @@ pub(crate) fn refresh_procs(
     true
 }
 
-fn copy_from_file(entry: &Path) -> Vec<String> {
+fn copy_from_file(entry: &Path, policy: StringEncodingPolicy) -> Vec<String> {
     match File::open(entry) {
         Ok(mut f) => {
             let mut data = Vec::with_capacity(16_384);
@@ -638,10 +708,16 @@ fn copy_from_file(entry: &Path) -> Vec<String> {
                 for (pos, x) in data.iter().enumerate() {
                     if *x == 0 {
                         if pos - start >= 1 {
-                            if let Ok(s) =
-                                std::str::from_utf8(&data[start..pos]).map(|x| x.trim().to_owned())
-                            {
-                                out.push(s);
+                            let s = match policy {
+                                StringEncodingPolicy::Skip => {
+                                    std::str::from_utf8(&data[start..pos]).ok().map(Cow::from)
+                                }
+                                StringEncodingPolicy::Lossy => {
+                                    Some(String::from_utf8_lossy(&data[start..pos]))
+                                }
+                            };
+                            if let Some(s) = s {
+                                out.push(s.trim().to_owned());
                             }
                         }
                         start = pos + 1; // to keeping prevent '\0'
@@ -727,3 +803,144 @@ fn parse_stat_file(data: &str) -> Option<Vec<&str>> {
     }
     Some(parts)
 }
+
+// When a foreign binary is run through `binfmt_misc`, the kernel starts the registered
+// interpreter instead, so `/proc/[pid]/exe` points to the `qemu-*` binary. `binfmt_misc` is
+// where the `binfmt_misc` filesystem is mounted.
+fn is_binfmt_qemu_interpreter(exe: &Path, binfmt_misc: &Path) -> bool {
+    if !exe
+        .file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| name.starts_with("qemu-"))
+        .unwrap_or(false)
+    {
+        return false;
+    }
+    let entries = match fs::read_dir(binfmt_misc) {
+        Ok(entries) => entries,
+        Err(_) => return false,
+    };
+    entries.flatten().any(|entry| {
+        let data = match get_all_data(entry.path(), 1024) {
+            Ok(data) => data,
+            Err(_) => return false,
+        };
+        let mut enabled = false;
+        let mut interpreter = None;
+        for line in data.lines() {
+            if line == "enabled" {
+                enabled = true;
+            } else if let Some(path) = line.strip_prefix("interpreter ") {
+                interpreter = Some(path);
+            }
+        }
+        enabled && interpreter.map(Path::new) == Some(exe)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{compute_cpu_usage, copy_from_file, is_binfmt_qemu_interpreter, ProcessInner};
+    use crate::{Pid, StringEncodingPolicy};
+
+    #[test]
+    fn copy_from_file_encoding() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = dir.path().join("cmdline");
+        std::fs::write(&path, b"ls\0-l\xff\0/tmp\0").expect("failed to write cmdline");
+
+        assert_eq!(
+            copy_from_file(&path, StringEncodingPolicy::Skip),
+            ["ls", "/tmp"]
+        );
+        assert_eq!(
+            copy_from_file(&path, StringEncodingPolicy::Lossy),
+            ["ls", "-l\u{fffd}", "/tmp"]
+        );
+    }
+
+    #[test]
+    fn check_normalized_cpu_usage() {
+        let mut p = ProcessInner::new(Pid(1));
+        // The first refresh doesn't compute anything.
+        compute_cpu_usage(&mut p, 100., 400.);
+        assert_eq!(p.cpu_usage(), 0.);
+        assert_eq!(p.normalized_cpu_usage(), 0.);
+
+        // 150 ticks out of 100 on a machine with 4 CPUs.
+        p.old_utime = 100;
+        p.old_stime = 50;
+        p.utime = 200;
+        p.stime = 100;
+        compute_cpu_usage(&mut p, 100., 400.);
+        assert_eq!(p.cpu_usage(), 150.);
+        assert_eq!(p.normalized_cpu_usage(), 37.5);
+
+        // The CPU usage is capped to the capacity of the machine.
+        p.utime = 1_000;
+        compute_cpu_usage(&mut p, 100., 400.);
+        assert_eq!(p.cpu_usage(), 400.);
+        assert_eq!(p.normalized_cpu_usage(), 100.);
+    }
+
+    #[test]
+    fn check_binfmt_qemu_interpreter() {
+        use std::fs;
+        use std::path::Path;
+
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let binfmt_misc = dir.path();
+        fs::write(binfmt_misc.join("register"), "").expect("failed to write file");
+        fs::write(binfmt_misc.join("status"), "enabled\n").expect("failed to write file");
+        fs::write(
+            binfmt_misc.join("qemu-aarch64"),
+            "enabled\n\
+             interpreter /usr/bin/qemu-aarch64-static\n\
+             flags: F\n\
+             offset 0\n\
+             magic 7f454c460201010000000000000000000200b700\n",
+        )
+        .expect("failed to write file");
+        fs::write(
+            binfmt_misc.join("qemu-riscv64"),
+            "disabled\n\
+             interpreter /usr/bin/qemu-riscv64-static\n\
+             flags: F\n",
+        )
+        .expect("failed to write file");
+        // Not an emulator.
+        fs::write(
+            binfmt_misc.join("python3.11"),
+            "enabled\n\
+             interpreter /usr/bin/python3.11\n\
+             flags: \n\
+             offset 0\n\
+             magic a70d0d0a\n",
+        )
+        .expect("failed to write file");
+
+        assert!(is_binfmt_qemu_interpreter(
+            Path::new("/usr/bin/qemu-aarch64-static"),
+            binfmt_misc,
+        ));
+        // The entry is disabled.
+        assert!(!is_binfmt_qemu_interpreter(
+            Path::new("/usr/bin/qemu-riscv64-static"),
+            binfmt_misc,
+        ));
+        // Not registered.
+        assert!(!is_binfmt_qemu_interpreter(
+            Path::new("/usr/bin/qemu-arm-static"),
+            binfmt_misc,
+        ));
+        // Registered, but it isn't `qemu`.
+        assert!(!is_binfmt_qemu_interpreter(
+            Path::new("/usr/bin/python3.11"),
+            binfmt_misc,
+        ));
+        assert!(!is_binfmt_qemu_interpreter(
+            Path::new("/usr/bin/qemu-aarch64-static"),
+            &binfmt_misc.join("missing"),
+        ));
+    }
+}
@@ -0,0 +1,249 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::get_all_data;
+use crate::{Pid, Service, ServiceStartKind, ServiceState};
+
+use std::collections::{HashMap, HashSet};
+use std::fs::read_dir;
+use std::path::{Path, PathBuf};
+
+// Directories containing the unit files, from the highest priority to the lowest.
+const UNIT_DIRS: &[&str] = &[
+    "/etc/systemd/system",
+    "/run/systemd/system",
+    "/usr/local/lib/systemd/system",
+    "/usr/lib/systemd/system",
+    "/lib/systemd/system",
+];
+
+#[derive(Default)]
+struct UnitFile {
+    description: Option<String>,
+    command: Option<String>,
+}
+
+pub(crate) fn get_services(services: &mut Vec<Service>) {
+    // This directory only exists when systemd is the init system.
+    if !Path::new("/run/systemd/system").is_dir() {
+        return;
+    }
+    let mut files: HashMap<String, PathBuf> = HashMap::new();
+    let mut enabled = HashSet::new();
+    for dir in UNIT_DIRS {
+        let entries = match read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name.ends_with(".service") {
+                files.entry(name).or_insert_with(|| entry.path());
+            } else if name.ends_with(".wants") || name.ends_with(".requires") {
+                // Enabling a unit links it into the `.wants` directory of its target.
+                if let Ok(links) = read_dir(entry.path()) {
+                    enabled.extend(
+                        links
+                            .flatten()
+                            .map(|link| link.file_name().to_string_lossy().into_owned()),
+                    );
+                }
+            }
+        }
+    }
+    let mut pids = HashMap::new();
+    for root in [
+        "/sys/fs/cgroup/system.slice",
+        "/sys/fs/cgroup/systemd/system.slice",
+    ] {
+        let root = Path::new(root);
+        if root.is_dir() {
+            get_service_pids(root, &mut pids);
+            break;
+        }
+    }
+
+    // Instances of template units (like `getty@tty1.service`) don't have their own unit file, so
+    // they are only listed when they are enabled or running.
+    let mut names = files
+        .keys()
+        .chain(enabled.iter())
+        .chain(pids.keys())
+        .filter(|name| name.ends_with(".service") && !name.contains("@."))
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names.dedup();
+
+    for name in names {
+        let file = files.get(name).or_else(|| files.get(&template_name(name)?));
+        // Masked units are links to `/dev/null`.
+        let masked = file.map_or(false, |file| {
+            file.canonicalize()
+                .map_or(false, |path| path == Path::new("/dev/null"))
+        });
+        let unit = file
+            .filter(|_| !masked)
+            .and_then(|file| get_all_data(file, 4_096).ok())
+            .map(|content| parse_unit_file(&content))
+            .unwrap_or_default();
+        let pid = pids.get(name).copied();
+        services.push(Service {
+            name: name.clone(),
+            description: unit.description,
+            state: if pid.is_some() {
+                ServiceState::Running
+            } else {
+                ServiceState::Stopped
+            },
+            start_kind: if masked {
+                ServiceStartKind::Disabled
+            } else if enabled.contains(name) {
+                ServiceStartKind::Automatic
+            } else {
+                ServiceStartKind::Manual
+            },
+            command: unit.command,
+            pid,
+        });
+    }
+}
+
+/// Returns the name of the template unit of an instance, like `getty@.service` for
+/// `getty@tty1.service`.
+fn template_name(name: &str) -> Option<String> {
+    let (prefix, _) = name.split_once('@')?;
+    Some(format!("{prefix}@.service"))
+}
+
+/// Walks the control groups of the system slice, keeping the lowest PID of each service, which
+/// is its main process most of the time.
+fn get_service_pids(dir: &Path, pids: &mut HashMap<String, Pid>) {
+    let entries = match read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        if !entry.file_type().map_or(false, |kind| kind.is_dir()) {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.ends_with(".service") {
+            let mut group = Vec::new();
+            get_cgroup_pids(&entry.path(), &mut group);
+            if let Some(pid) = group.into_iter().min() {
+                pids.insert(name, Pid::from(pid));
+            }
+        } else if name.ends_with(".slice") {
+            get_service_pids(&entry.path(), pids);
+        }
+    }
+}
+
+/// Collects the PIDs of the control group and of its children, since services can delegate the
+/// management of their control group.
+pub(crate) fn get_cgroup_pids(dir: &Path, pids: &mut Vec<usize>) {
+    if let Ok(procs) = get_all_data(dir.join("cgroup.procs"), 1_024) {
+        pids.extend(
+            procs
+                .lines()
+                .filter_map(|pid| pid.trim().parse::<usize>().ok()),
+        );
+    }
+    if let Ok(entries) = read_dir(dir) {
+        for entry in entries.flatten() {
+            if entry.file_type().map_or(false, |kind| kind.is_dir()) {
+                get_cgroup_pids(&entry.path(), pids);
+            }
+        }
+    }
+}
+
+/// Reads the `Description` of the `[Unit]` section and the first `ExecStart` of the `[Service]`
+/// section of a unit file.
+fn parse_unit_file(content: &str) -> UnitFile {
+    let mut unit = UnitFile::default();
+    let mut section = String::new();
+    let mut lines = content.lines();
+
+    while let Some(line) = lines.next() {
+        let mut line = line.trim().to_owned();
+        // Lines ending with a backslash continue on the next one.
+        while line.ends_with('\\') {
+            line.pop();
+            match lines.next() {
+                Some(next) => {
+                    line = format!("{} {}", line.trim_end(), next.trim());
+                }
+                None => break,
+            }
+        }
+        if line.starts_with('[') {
+            section = line;
+            continue;
+        }
+        let (key, value) = match line.split_once('=') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => continue,
+        };
+        match (section.as_str(), key) {
+            ("[Unit]", "Description") if !value.is_empty() => {
+                unit.description = Some(value.to_owned());
+            }
+            ("[Service]", "ExecStart") if unit.command.is_none() => {
+                // The prefixes change how the command is run (like ignoring its failure).
+                let command = value.trim_start_matches(['-', '@', ':', '+', '!']);
+                if !command.is_empty() {
+                    unit.command = Some(command.to_owned());
+                }
+            }
+            _ => {}
+        }
+    }
+    unit
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_unit_file, template_name};
+
+    #[test]
+    fn check_parse_unit_file() {
+        let unit = parse_unit_file(
+            "[Unit]
+Description=OpenBSD Secure Shell server
+# Description=Commented out
+After=network.target auditd.service
+
+[Service]
+EnvironmentFile=-/etc/default/ssh
+ExecStartPre=/usr/sbin/sshd -t
+ExecStart=-/usr/sbin/sshd -D \\
+    $SSHD_OPTS
+ExecReload=/bin/kill -HUP $MAINPID
+
+[Install]
+WantedBy=multi-user.target
+",
+        );
+        assert_eq!(
+            unit.description.as_deref(),
+            Some("OpenBSD Secure Shell server")
+        );
+        assert_eq!(
+            unit.command.as_deref(),
+            Some("/usr/sbin/sshd -D $SSHD_OPTS")
+        );
+
+        let unit = parse_unit_file("[Service]\nType=oneshot\n");
+        assert_eq!(unit.description, None);
+        assert_eq!(unit.command, None);
+    }
+
+    #[test]
+    fn check_template_name() {
+        assert_eq!(
+            template_name("getty@tty1.service").as_deref(),
+            Some("getty@.service")
+        );
+        assert_eq!(template_name("sshd.service"), None);
+    }
+}
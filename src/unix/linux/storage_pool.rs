@@ -0,0 +1,218 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::get_all_data;
+use crate::{StoragePool, StoragePoolHealth, StoragePoolKind};
+
+use std::fs;
+use std::path::Path;
+
+pub(crate) fn get_storage_pools() -> Vec<StoragePool> {
+    let mut pools = get_zfs_pools(Path::new("/proc/spl/kstat/zfs/"));
+    pools.extend(get_btrfs_pools(Path::new("/sys/fs/btrfs/")));
+    pools.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    pools
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    get_all_data(path, 32).ok()?.trim().parse().ok()
+}
+
+/// ZFS only exposes the state of its pools in procfs, the other information requires `libzfs`.
+fn get_zfs_pools(kstat: &Path) -> Vec<StoragePool> {
+    let entries = match fs::read_dir(kstat) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+    entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            // Only pools have a `state` file, the other entries are global statistics.
+            let state = get_all_data(path.join("state"), 32).ok()?;
+            Some(StoragePool {
+                name: path.file_name()?.to_str()?.to_owned(),
+                kind: StoragePoolKind::ZFS,
+                health: parse_zfs_state(state.trim()),
+                devices: Vec::new(),
+                total_space: None,
+                allocated_space: None,
+                usable_space: None,
+                used_space: None,
+            })
+        })
+        .collect()
+}
+
+fn parse_zfs_state(state: &str) -> StoragePoolHealth {
+    match state {
+        "ONLINE" => StoragePoolHealth::Online,
+        "DEGRADED" => StoragePoolHealth::Degraded,
+        "FAULTED" => StoragePoolHealth::Faulted,
+        "OFFLINE" => StoragePoolHealth::Offline,
+        "UNAVAIL" => StoragePoolHealth::Unavailable,
+        "REMOVED" => StoragePoolHealth::Removed,
+        "SUSPENDED" => StoragePoolHealth::Suspended,
+        _ => StoragePoolHealth::Unknown,
+    }
+}
+
+/// Space (in bytes) of a btrfs block group type (`data`, `metadata` or `system`).
+struct BtrfsAllocation {
+    // Logical space allocated to this type.
+    total_bytes: u64,
+    // Logical space used.
+    bytes_used: u64,
+    // Raw space allocated on the devices, which includes redundancy.
+    disk_total: u64,
+}
+
+fn get_btrfs_allocation(allocation: &Path) -> BtrfsAllocation {
+    BtrfsAllocation {
+        total_bytes: read_u64(&allocation.join("total_bytes")).unwrap_or(0),
+        bytes_used: read_u64(&allocation.join("bytes_used")).unwrap_or(0),
+        disk_total: read_u64(&allocation.join("disk_total")).unwrap_or(0),
+    }
+}
+
+fn get_btrfs_pools(sys_fs_btrfs: &Path) -> Vec<StoragePool> {
+    let entries = match fs::read_dir(sys_fs_btrfs) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+    entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            // Only mounted file systems have a directory named after their UUID, with a `devices`
+            // sub-directory.
+            let devices_entries = fs::read_dir(path.join("devices")).ok()?;
+            let uuid = path.file_name()?.to_str()?.to_owned();
+            let name = get_all_data(path.join("label"), 256)
+                .ok()
+                .map(|label| label.trim().to_owned())
+                .filter(|label| !label.is_empty())
+                .unwrap_or(uuid);
+
+            let mut devices = Vec::new();
+            let mut total_space = 0u64;
+            for device in devices_entries.flatten() {
+                // The size is always in 512-byte sectors, whatever the actual sector size is.
+                let size = read_u64(&device.path().join("size")).unwrap_or(0);
+                total_space = total_space.saturating_add(size.saturating_mul(512));
+                if let Some(name) = device.file_name().to_str() {
+                    devices.push(name.to_owned());
+                }
+            }
+            devices.sort_unstable();
+
+            let allocation = path.join("allocation");
+            let data = get_btrfs_allocation(&allocation.join("data"));
+            let metadata = get_btrfs_allocation(&allocation.join("metadata"));
+            let system = get_btrfs_allocation(&allocation.join("system"));
+            let allocated_space = data.disk_total + metadata.disk_total + system.disk_total;
+            // The data profile (like RAID1) tells how much raw space is needed to store data.
+            let data_ratio = if data.total_bytes != 0 && data.disk_total > data.total_bytes {
+                data.disk_total as f64 / data.total_bytes as f64
+            } else {
+                1.
+            };
+            let unallocated = total_space.saturating_sub(allocated_space);
+            let usable_space = data.total_bytes + (unallocated as f64 / data_ratio) as u64;
+
+            Some(StoragePool {
+                name,
+                kind: StoragePoolKind::Btrfs,
+                health: get_btrfs_health(&path),
+                devices,
+                total_space: Some(total_space),
+                allocated_space: Some(allocated_space),
+                usable_space: Some(usable_space),
+                used_space: Some(data.bytes_used),
+            })
+        })
+        .collect()
+}
+
+fn get_btrfs_health(path: &Path) -> StoragePoolHealth {
+    let entries = match fs::read_dir(path.join("devinfo")) {
+        Ok(entries) => entries,
+        // Kernels older than 5.9 don't have this information.
+        Err(_) => return StoragePoolHealth::Unknown,
+    };
+    let is_missing = |device: &Path| read_u64(&device.join("missing")).unwrap_or(0) != 0;
+    if entries.flatten().any(|device| is_missing(&device.path())) {
+        StoragePoolHealth::Degraded
+    } else {
+        StoragePoolHealth::Online
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{get_btrfs_pools, get_zfs_pools};
+    use crate::{StoragePoolHealth, StoragePoolKind};
+
+    use std::fs;
+    use std::path::Path;
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).expect("failed to create directory");
+        fs::write(path, content).expect("failed to write file");
+    }
+
+    #[test]
+    fn zfs_pools() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let kstat = dir.path();
+        write(&kstat.join("arcstats"), "");
+        write(&kstat.join("tank/state"), "DEGRADED\n");
+        write(&kstat.join("tank/txgs"), "");
+
+        let pools = get_zfs_pools(kstat);
+        assert_eq!(pools.len(), 1);
+        assert_eq!(pools[0].name, "tank");
+        assert_eq!(pools[0].kind, StoragePoolKind::ZFS);
+        assert_eq!(pools[0].health, StoragePoolHealth::Degraded);
+        assert_eq!(pools[0].total_space, None);
+    }
+
+    #[test]
+    fn btrfs_pools() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let uuid = dir.path().join("0a1b2c3d-1234-5678-9abc-def012345678");
+        write(&uuid.join("label"), "data\n");
+        // Two 1 GiB devices.
+        write(&uuid.join("devices/sda1/size"), "2097152\n");
+        write(&uuid.join("devices/sdb1/size"), "2097152\n");
+        write(&uuid.join("devinfo/1/missing"), "0\n");
+        write(&uuid.join("devinfo/2/missing"), "0\n");
+        // RAID1 data and metadata: the raw space is twice the logical one.
+        let mib = 1024 * 1024;
+        for (kind, total, used) in [("data", 512, 300), ("metadata", 128, 10), ("system", 8, 1)] {
+            let allocation = uuid.join("allocation").join(kind);
+            write(&allocation.join("total_bytes"), &format!("{}", total * mib));
+            write(&allocation.join("bytes_used"), &format!("{}", used * mib));
+            write(
+                &allocation.join("disk_total"),
+                &format!("{}", total * mib * 2),
+            );
+        }
+        // Not mounted file systems don't have a `devices` directory.
+        write(&dir.path().join("features/raid1c34"), "0\n");
+
+        let pools = get_btrfs_pools(dir.path());
+        assert_eq!(pools.len(), 1);
+        let pool = &pools[0];
+        assert_eq!(pool.name, "data");
+        assert_eq!(pool.kind, StoragePoolKind::Btrfs);
+        assert_eq!(pool.health, StoragePoolHealth::Online);
+        assert_eq!(pool.devices, ["sda1", "sdb1"]);
+        assert_eq!(pool.total_space, Some(2048 * mib));
+        assert_eq!(pool.allocated_space, Some(1296 * mib));
+        // The 752 MiB left can store 376 MiB of RAID1 data.
+        assert_eq!(pool.usable_space, Some((512 + 376) * mib));
+        assert_eq!(pool.used_space, Some(300 * mib));
+
+        write(&uuid.join("devinfo/2/missing"), "1\n");
+        let pools = get_btrfs_pools(dir.path());
+        assert_eq!(pools[0].health, StoragePoolHealth::Degraded);
+    }
+}
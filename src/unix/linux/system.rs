@@ -1,23 +1,34 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::sys::cpu::{get_physical_core_count, CpusWrapper};
+use crate::smbios::{parse_hardware_info, parse_memory_modules};
+use crate::sys::cpu::{get_physical_core_count, get_socket_count, CpusWrapper};
 use crate::sys::process::{_get_process_data, compute_cpu_usage, refresh_procs, unset_updated};
 use crate::sys::utils::{get_all_data, to_u64};
-use crate::{Cpu, CpuRefreshKind, LoadAvg, Pid, Process, ProcessInner, ProcessRefreshKind};
+use crate::unix::utils::{get_uname_machine, get_userland_arch};
+use crate::utils::{chassis_from_smbios, get_hypervisor, normalize_arch, set_virtual_chassis};
+use crate::{
+    AvailableMemory, AvailableMemorySource, CommitCharge, CompressedMemory, ContainerRuntime, Cpu,
+    CpuActivity, CpuRefreshKind, Entropy, FileHandles, HardwareInfo, KernelParameter, Ksm, LoadAvg,
+    MemoryBreakdown, MemoryModule, OsRelease, Pid, Pressure, PressureStats, Process, ProcessInner,
+    ProcessRefreshKind, StringEncodingPolicy, SystemCounts, Virtualization, WindowsVersion,
+    ZramDevice,
+};
 
 use libc::{self, c_char, sysconf, _SC_CLK_TCK, _SC_HOST_NAME_MAX, _SC_PAGESIZE};
 use std::cmp::min;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Read};
-use std::path::Path;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 // This whole thing is to prevent having too many files open at once. It could be problematic
 // for processes using a lot of files and using sysinfo at the same time.
 #[allow(clippy::mutex_atomic)]
-pub(crate) static mut REMAINING_FILES: once_cell::sync::Lazy<Arc<Mutex<isize>>> =
+pub(crate) static REMAINING_FILES: once_cell::sync::Lazy<Arc<Mutex<isize>>> =
     once_cell::sync::Lazy::new(|| {
         unsafe {
             let mut limits = libc::rlimit {
@@ -89,10 +100,34 @@ fn boot_time() -> u64 {
     }
 }
 
+fn uptime_duration() -> Option<Duration> {
+    // `CLOCK_BOOTTIME` keeps running while the system is suspended, unlike `CLOCK_MONOTONIC`.
+    unsafe {
+        let mut up: libc::timespec = std::mem::zeroed();
+        if libc::clock_gettime(libc::CLOCK_BOOTTIME, &mut up) != 0 {
+            sysinfo_debug!("clock_gettime failed: uptime cannot be retrieved...");
+            return None;
+        }
+        Some(Duration::new(up.tv_sec as _, up.tv_nsec as _))
+    }
+}
+
+fn boot_time_sys() -> SystemTime {
+    uptime_duration()
+        .and_then(|uptime| SystemTime::now().checked_sub(uptime))
+        .unwrap_or(UNIX_EPOCH)
+}
+
+const KSM_PATH: &str = "/sys/kernel/mm/ksm";
+// Mount point of the cgroup hierarchies.
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
 pub(crate) struct SystemInfo {
     pub(crate) page_size_b: u64,
     pub(crate) clock_cycle: u64,
     pub(crate) boot_time: u64,
+    pub(crate) string_encoding_policy: StringEncodingPolicy,
+    pub(crate) keep_open_files: bool,
 }
 
 impl SystemInfo {
@@ -102,6 +137,8 @@ impl SystemInfo {
                 page_size_b: sysconf(_SC_PAGESIZE) as _,
                 clock_cycle: sysconf(_SC_CLK_TCK) as _,
                 boot_time: boot_time(),
+                string_encoding_policy: StringEncodingPolicy::Skip,
+                keep_open_files: true,
             }
         }
     }
@@ -112,14 +149,26 @@ pub(crate) struct SystemInner {
     mem_total: u64,
     mem_free: u64,
     mem_available: u64,
+    mem_available_found: bool,
     mem_buffers: u64,
     mem_page_cache: u64,
     mem_shmem: u64,
     mem_slab_reclaimable: u64,
+    mem_slab_unreclaimable: u64,
+    mem_mapped: u64,
+    mem_dirty: u64,
+    mem_writeback: u64,
+    mem_committed: u64,
+    mem_commit_limit: u64,
+    mem_zswap: u64,
+    mem_zswapped: u64,
+    zram_devices: Vec<ZramDevice>,
     swap_total: u64,
     swap_free: u64,
     info: SystemInfo,
     cpus: CpusWrapper,
+    #[cfg(feature = "energy")]
+    energy: crate::sys::energy::EnergyWrapper,
 }
 
 impl SystemInner {
@@ -171,6 +220,20 @@ impl SystemInner {
     }
 }
 
+/// Reads the memory information without needing the `SystemInner`, so it can be done on another
+/// thread. The result is applied with `SystemInner::apply_memory`.
+pub(crate) struct MemoryReader {
+    meminfo: Option<std::io::Result<String>>,
+    zram_devices: Vec<ZramDevice>,
+}
+
+impl MemoryReader {
+    pub(crate) fn read(&mut self) {
+        self.meminfo = Some(get_all_data("/proc/meminfo", 16_635));
+        refresh_zram_devices(&mut self.zram_devices);
+    }
+}
+
 impl SystemInner {
     pub(crate) fn new() -> Self {
         Self {
@@ -180,31 +243,80 @@ impl SystemInner {
             mem_total: 0,
             mem_free: 0,
             mem_available: 0,
+            mem_available_found: false,
             mem_buffers: 0,
             mem_page_cache: 0,
             mem_shmem: 0,
             mem_slab_reclaimable: 0,
+            mem_slab_unreclaimable: 0,
+            mem_mapped: 0,
+            mem_dirty: 0,
+            mem_writeback: 0,
+            mem_committed: 0,
+            mem_commit_limit: 0,
+            mem_zswap: 0,
+            mem_zswapped: 0,
+            zram_devices: Vec::new(),
             swap_total: 0,
             swap_free: 0,
             cpus: CpusWrapper::new(),
             info: SystemInfo::new(),
+            #[cfg(feature = "energy")]
+            energy: crate::sys::energy::EnergyWrapper::new(),
         }
     }
 
+    pub(crate) fn set_string_encoding_policy(&mut self, policy: StringEncodingPolicy) {
+        self.info.string_encoding_policy = policy;
+    }
+
+    pub(crate) fn set_keep_open_files(&mut self, enabled: bool) {
+        self.info.keep_open_files = enabled;
+    }
+
     pub(crate) fn refresh_memory(&mut self) {
-        let mut mem_available_found = false;
-        read_table("/proc/meminfo", ':', |key, value_kib| {
+        let mut reader = self.memory_reader();
+        reader.read();
+        self.apply_memory(reader);
+    }
+
+    pub(crate) fn memory_reader(&mut self) -> MemoryReader {
+        MemoryReader {
+            meminfo: None,
+            zram_devices: std::mem::take(&mut self.zram_devices),
+        }
+    }
+
+    pub(crate) fn apply_memory(&mut self, reader: MemoryReader) {
+        if let Some(meminfo) = reader.meminfo {
+            self.update_memory(&read_meminfo(meminfo));
+        }
+        self.zram_devices = reader.zram_devices;
+    }
+
+    /// Updates the memory information from the content of `/proc/meminfo`.
+    fn update_memory(&mut self, meminfo: &str) {
+        self.mem_available_found = false;
+        parse_table(meminfo, ':', |key, value_kib| {
             let field = match key {
                 "MemTotal" => &mut self.mem_total,
                 "MemFree" => &mut self.mem_free,
                 "MemAvailable" => {
-                    mem_available_found = true;
+                    self.mem_available_found = true;
                     &mut self.mem_available
                 }
                 "Buffers" => &mut self.mem_buffers,
                 "Cached" => &mut self.mem_page_cache,
                 "Shmem" => &mut self.mem_shmem,
                 "SReclaimable" => &mut self.mem_slab_reclaimable,
+                "SUnreclaim" => &mut self.mem_slab_unreclaimable,
+                "Mapped" => &mut self.mem_mapped,
+                "Dirty" => &mut self.mem_dirty,
+                "Writeback" => &mut self.mem_writeback,
+                "Committed_AS" => &mut self.mem_committed,
+                "CommitLimit" => &mut self.mem_commit_limit,
+                "Zswap" => &mut self.mem_zswap,
+                "Zswapped" => &mut self.mem_zswapped,
                 "SwapTotal" => &mut self.swap_total,
                 "SwapFree" => &mut self.swap_free,
                 _ => return,
@@ -216,7 +328,7 @@ impl SystemInner {
         // Linux < 3.14 may not have MemAvailable in /proc/meminfo
         // So it should fallback to the old way of estimating available memory
         // https://github.com/KittyKatt/screenFetch/issues/386#issuecomment-249312716
-        if !mem_available_found {
+        if !self.mem_available_found {
             self.mem_available = self
                 .mem_free
                 .saturating_add(self.mem_buffers)
@@ -234,6 +346,10 @@ impl SystemInner {
         self.refresh_cpus(false, refresh_kind);
     }
 
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        self.cpus.refresh_global_cpu_usage();
+    }
+
     pub(crate) fn refresh_processes_specifics(&mut self, refresh_kind: ProcessRefreshKind) {
         let uptime = self.uptime();
         refresh_procs(
@@ -310,14 +426,48 @@ impl SystemInner {
         &self.cpus.global_cpu
     }
 
+    pub(crate) fn cpu_activity(&self) -> CpuActivity {
+        self.cpus.activity()
+    }
+
+    #[cfg(feature = "energy")]
+    pub(crate) fn refresh_energy(&mut self) {
+        self.energy.refresh();
+    }
+
+    #[cfg(feature = "energy")]
+    pub(crate) fn energy_domains(&self) -> &[crate::EnergyDomain] {
+        self.energy.domains()
+    }
+
     pub(crate) fn cpus(&self) -> &[Cpu] {
         &self.cpus.cpus
     }
 
+    pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
+        &mut self.process_list.inner.tasks
+    }
+
+    pub(crate) fn global_cpu_info_mut(&mut self) -> &mut Cpu {
+        &mut self.cpus.global_cpu
+    }
+
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus.cpus
+    }
+
     pub(crate) fn physical_core_count(&self) -> Option<usize> {
         get_physical_core_count()
     }
 
+    pub(crate) fn socket_count(&self) -> Option<usize> {
+        get_socket_count()
+    }
+
+    pub(crate) fn cpu_features(&self) -> Vec<String> {
+        crate::utils::get_cpu_features()
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -330,10 +480,119 @@ impl SystemInner {
         self.mem_available
     }
 
+    pub(crate) fn available_memory_breakdown(&self) -> Option<AvailableMemory> {
+        Some(AvailableMemory {
+            free: self.mem_free,
+            reclaimable: self.mem_available.saturating_sub(self.mem_free),
+            available: self.mem_available,
+            source: if self.mem_available_found {
+                AvailableMemorySource::Kernel
+            } else {
+                AvailableMemorySource::Computed
+            },
+        })
+    }
+
     pub(crate) fn used_memory(&self) -> u64 {
         self.mem_total - self.mem_available
     }
 
+    pub(crate) fn memory_breakdown(&self) -> MemoryBreakdown {
+        MemoryBreakdown {
+            buffers: self.mem_buffers,
+            cached: self.mem_page_cache,
+            shared: self.mem_shmem,
+            slab_reclaimable: self.mem_slab_reclaimable,
+            slab_unreclaimable: self.mem_slab_unreclaimable,
+            mapped: self.mem_mapped,
+            dirty: self.mem_dirty,
+            writeback: self.mem_writeback,
+        }
+    }
+
+    pub(crate) fn commit_charge(&self) -> CommitCharge {
+        CommitCharge {
+            total: self.mem_committed,
+            limit: self.mem_commit_limit,
+            peak: 0,
+        }
+    }
+
+    pub(crate) fn compressed_memory(&self) -> CompressedMemory {
+        CompressedMemory {
+            uncompressed: self.mem_zswapped,
+            compressed: self.mem_zswap,
+        }
+    }
+
+    pub(crate) fn zram_devices(&self) -> &[ZramDevice] {
+        &self.zram_devices
+    }
+
+    pub(crate) fn ksm(&self) -> Option<Ksm> {
+        let read = |name: &str| -> Option<u64> {
+            get_all_data(Path::new(KSM_PATH).join(name), 32)
+                .ok()?
+                .trim()
+                .parse()
+                .ok()
+        };
+        let to_bytes = |pages: u64| pages.saturating_mul(self.info.page_size_b);
+
+        Some(Ksm {
+            // `run` is `1` when KSM is running, `0` when it's stopped and `2` when it's stopped
+            // and all merged pages were unmerged.
+            running: read("run")? == 1,
+            shared: to_bytes(read("pages_shared")?),
+            saved: to_bytes(read("pages_sharing")?),
+            unshared: to_bytes(read("pages_unshared")?),
+        })
+    }
+
+    pub(crate) fn balloon_memory(&self) -> Option<u64> {
+        let mut balloon_pages = None;
+        let mut inflated = None;
+        let mut deflated = None;
+        read_table("/proc/vmstat", ' ', |key, value| match key {
+            "nr_balloon_pages" => balloon_pages = Some(value),
+            "balloon_inflate" => inflated = Some(value),
+            "balloon_deflate" => deflated = Some(value),
+            _ => {}
+        });
+        // `nr_balloon_pages` was added in Linux 6.15. Before that, only the events of the balloon
+        // drivers supporting page migration are available.
+        let pages = match (balloon_pages, inflated, deflated) {
+            (Some(pages), _, _) => pages,
+            (None, Some(inflated), Some(deflated)) => inflated.saturating_sub(deflated),
+            _ => return None,
+        };
+        Some(pages.saturating_mul(self.info.page_size_b))
+    }
+
+    pub(crate) fn oom_kills(&self) -> Option<u64> {
+        let mut oom_kills = None;
+        read_table("/proc/vmstat", ' ', |key, value| {
+            if key == "oom_kill" {
+                oom_kills = Some(value);
+            }
+        });
+        oom_kills
+    }
+
+    pub(crate) fn memory_modules(&self) -> Vec<MemoryModule> {
+        match std::fs::read("/sys/firmware/dmi/tables/DMI") {
+            Ok(table) => parse_memory_modules(&table),
+            Err(_e) => {
+                sysinfo_debug!("failed to read SMBIOS table: {:?}", _e);
+                Vec::new()
+            }
+        }
+    }
+
+    pub(crate) fn memory_pressure_level(&self) -> Option<crate::MemoryPressureLevel> {
+        None
+    }
+
     pub(crate) fn total_swap(&self) -> u64 {
         self.swap_total
     }
@@ -360,6 +619,14 @@ impl SystemInner {
         self.info.boot_time
     }
 
+    pub(crate) fn boot_time_sys(&self) -> SystemTime {
+        boot_time_sys()
+    }
+
+    pub(crate) fn uptime_duration(&self) -> Duration {
+        uptime_duration().unwrap_or_default()
+    }
+
     pub(crate) fn load_average(&self) -> LoadAvg {
         let mut s = String::new();
         if File::open("/proc/loadavg")
@@ -381,6 +648,52 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn counts(&self) -> SystemCounts {
+        let processes = std::fs::read_dir("/proc").ok().map(|dir| {
+            dir.flatten()
+                .filter(|entry| {
+                    let name = entry.file_name();
+                    !name.is_empty() && name.as_bytes().iter().all(u8::is_ascii_digit)
+                })
+                .count() as u64
+        });
+        SystemCounts {
+            processes,
+            threads: get_all_data("/proc/loadavg", 128)
+                .ok()
+                .and_then(|loadavg| parse_loadavg_threads(&loadavg)),
+            handles: get_all_data("/proc/sys/fs/file-nr", 128)
+                .ok()
+                .and_then(|file_nr| parse_file_nr(&file_nr).map(|(allocated, _)| allocated)),
+        }
+    }
+
+    pub(crate) fn file_handles(&self) -> Option<FileHandles> {
+        let (allocated, max) = parse_file_nr(&get_all_data("/proc/sys/fs/file-nr", 128).ok()?)?;
+        Some(FileHandles {
+            allocated,
+            // systemd sets it to the maximum value, which means there is no limit.
+            limit: Some(max).filter(|max| *max < i64::MAX as u64),
+        })
+    }
+
+    pub(crate) fn entropy(&self) -> Option<Entropy> {
+        let available = read_u64("/proc/sys/kernel/random/entropy_avail").map(|bits| bits as u32);
+        Some(Entropy {
+            ready: is_random_ready().or_else(|| available.map(|bits| bits >= 128))?,
+            available,
+            pool_size: read_u64("/proc/sys/kernel/random/poolsize").map(|bits| bits as u32),
+        })
+    }
+
+    pub(crate) fn pressure(&self) -> Option<Pressure> {
+        Some(Pressure {
+            cpu: read_pressure_stats("/proc/pressure/cpu")?,
+            memory: read_pressure_stats("/proc/pressure/memory")?,
+            io: read_pressure_stats("/proc/pressure/io")?,
+        })
+    }
+
     #[cfg(not(target_os = "android"))]
     pub(crate) fn name(&self) -> Option<String> {
         get_system_info_linux(
@@ -427,6 +740,90 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn fqdn(&self) -> Option<String> {
+        crate::unix::utils::get_fqdn(&self.host_name()?)
+    }
+
+    pub(crate) fn domain_name(&self) -> Option<String> {
+        let fqdn = self.fqdn()?;
+        let (_, domain) = fqdn.split_once('.')?;
+        Some(domain.to_owned()).filter(|domain| !domain.is_empty())
+    }
+
+    pub(crate) fn workgroup(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn hardware_info(&self) -> HardwareInfo {
+        // Only root can read the raw table, whereas most of the `/sys/class/dmi/id` files (but
+        // the serial numbers) can be read by anyone.
+        let mut info = match std::fs::read("/sys/firmware/dmi/tables/DMI") {
+            Ok(table) => parse_hardware_info(&table),
+            Err(_) => {
+                let read = |file: &str| {
+                    get_all_data(Path::new("/sys/class/dmi/id").join(file), 256)
+                        .map(|data| data.trim().to_owned())
+                        .unwrap_or_default()
+                };
+                HardwareInfo {
+                    manufacturer: read("sys_vendor"),
+                    product_name: read("product_name"),
+                    product_version: read("product_version"),
+                    serial_number: read("product_serial"),
+                    board_vendor: read("board_vendor"),
+                    board_name: read("board_name"),
+                    board_serial_number: read("board_serial"),
+                    bios_vendor: read("bios_vendor"),
+                    bios_version: read("bios_version"),
+                    bios_release_date: read("bios_date"),
+                    chassis: read("chassis_type")
+                        .parse()
+                        .map(chassis_from_smbios)
+                        .unwrap_or_default(),
+                }
+            }
+        };
+        set_virtual_chassis(&mut info);
+        info
+    }
+
+    pub(crate) fn machine_id(&self) -> Option<String> {
+        get_machine_id(Path::new("/"))
+    }
+
+    pub(crate) fn virtualization(&self) -> Virtualization {
+        Virtualization {
+            hypervisor: get_hypervisor(&self.hardware_info()).or_else(|| {
+                // Xen PV guests have neither the `cpuid` leaves nor the SMBIOS information.
+                get_all_data("/sys/hypervisor/type", 16)
+                    .ok()
+                    .filter(|kind| kind.trim() == "xen")
+                    .map(|_| crate::Hypervisor::Xen)
+            }),
+            container: get_container(),
+        }
+    }
+
+    pub(crate) fn kernel_arch(&self) -> Option<String> {
+        get_uname_machine().map(|machine| normalize_arch(&machine))
+    }
+
+    pub(crate) fn userland_arch(&self) -> Option<String> {
+        get_userland_arch()
+    }
+
+    pub(crate) fn os_release(&self) -> Option<OsRelease> {
+        crate::sys::get_os_release()
+    }
+
+    pub(crate) fn windows_version(&self) -> Option<WindowsVersion> {
+        None
+    }
+
+    pub(crate) fn kernel_parameter(&self, name: &str) -> Option<KernelParameter> {
+        crate::sys::get_kernel_parameter(name)
+    }
+
     pub(crate) fn kernel_version(&self) -> Option<String> {
         let mut raw = std::mem::MaybeUninit::<libc::utsname>::zeroed();
 
@@ -482,79 +879,285 @@ impl SystemInner {
     }
 }
 
-fn read_u64(filename: &str) -> Option<u64> {
+/// Returns the number of scheduling entities (threads) from `/proc/loadavg`, where it is the
+/// fourth field (`running/total`).
+fn parse_loadavg_threads(loadavg: &str) -> Option<u64> {
+    let (_, total) = loadavg.split_whitespace().nth(3)?.split_once('/')?;
+    total.parse().ok()
+}
+
+/// Returns the number of file handles in use and the maximum from `/proc/sys/fs/file-nr`, which
+/// contains the allocated, free (always `0` since Linux 2.6) and maximum number of handles.
+fn parse_file_nr(file_nr: &str) -> Option<(u64, u64)> {
+    let mut values = file_nr
+        .split_whitespace()
+        .map(|value| value.parse::<u64>().ok());
+    let allocated = values.next()??;
+    let free = values.next()??;
+    let max = values.next()??;
+    Some((allocated.saturating_sub(free), max))
+}
+
+/// Returns whether the random number generator is initialized, by asking `getrandom` not to block
+/// until it is. Returns `None` if the syscall isn't available (before Linux 3.17).
+fn is_random_ready() -> Option<bool> {
+    let mut byte = 0u8;
+    let ret = unsafe {
+        libc::syscall(
+            libc::SYS_getrandom,
+            &mut byte as *mut u8,
+            1usize,
+            libc::GRND_NONBLOCK,
+        )
+    };
+    if ret == 1 {
+        return Some(true);
+    }
+    match std::io::Error::last_os_error().raw_os_error() {
+        Some(libc::EAGAIN) => Some(false),
+        _ => None,
+    }
+}
+
+fn get_container() -> Option<ContainerRuntime> {
+    if Path::new("/.dockerenv").exists() {
+        return Some(ContainerRuntime::Docker);
+    }
+    if Path::new("/run/.containerenv").exists() {
+        return Some(ContainerRuntime::Podman);
+    }
+    let read = |path: &str| get_all_data(path, 4096).ok();
+    // systemd writes the `container` environment variable of the init process (which can only
+    // be read by root) into this file.
+    let name = read("/run/systemd/container").or_else(|| {
+        read("/proc/1/environ")?
+            .split('\0')
+            .find_map(|var| var.strip_prefix("container=").map(str::to_owned))
+    });
+    let cgroup = read("/proc/1/cgroup").unwrap_or_default();
+    parse_container(name.as_deref(), &cgroup).or_else(|| {
+        // The WSL kernels are named like `5.15.153.1-microsoft-standard-WSL2`.
+        read("/proc/sys/kernel/osrelease")
+            .filter(|release| release.to_lowercase().contains("microsoft"))
+            .map(|_| ContainerRuntime::Wsl)
+    })
+}
+
+/// Returns the container runtime from the `container` environment variable of the init process,
+/// or from its control groups.
+fn parse_container(name: Option<&str>, cgroup: &str) -> Option<ContainerRuntime> {
+    if let Some(name) = name.map(str::trim).filter(|name| !name.is_empty()) {
+        return Some(match name {
+            "docker" => ContainerRuntime::Docker,
+            "podman" => ContainerRuntime::Podman,
+            "lxc" | "lxc-libvirt" => ContainerRuntime::Lxc,
+            "systemd-nspawn" => ContainerRuntime::SystemdNspawn,
+            "wsl" => ContainerRuntime::Wsl,
+            name => ContainerRuntime::Other(name.to_owned()),
+        });
+    }
+    // With cgroup v1, the control groups of the init process are named after the container.
+    // Lines are formatted as `ID:CONTROLLERS:PATH`.
+    cgroup
+        .lines()
+        .filter_map(|line| line.splitn(3, ':').nth(2))
+        .find_map(|path| {
+            if path.contains("/docker") {
+                Some(ContainerRuntime::Docker)
+            } else if path.contains("/libpod") {
+                Some(ContainerRuntime::Podman)
+            } else if path.starts_with("/lxc") {
+                Some(ContainerRuntime::Lxc)
+            } else if path.contains("/kubepods") {
+                Some(ContainerRuntime::Other("kubernetes".to_owned()))
+            } else {
+                None
+            }
+        })
+}
+
+fn read_meminfo(meminfo: std::io::Result<String>) -> String {
+    meminfo.unwrap_or_else(|_e| {
+        sysinfo_debug!("failed to read `/proc/meminfo`: {:?}", _e);
+        crate::utils::report_io_error("/proc/meminfo", &_e, crate::RefreshErrorKind::Unavailable);
+        String::new()
+    })
+}
+
+fn read_u64<P: AsRef<Path>>(filename: P) -> Option<u64> {
     get_all_data(filename, 16_635)
         .ok()
         .and_then(|d| u64::from_str(d.trim()).ok())
 }
 
-fn read_table<F>(filename: &str, colsep: char, mut f: F)
+fn read_table<P, F>(filename: P, colsep: char, f: F)
 where
+    P: AsRef<Path>,
     F: FnMut(&str, u64),
 {
     if let Ok(content) = get_all_data(filename, 16_635) {
-        content
-            .split('\n')
-            .flat_map(|line| {
-                let mut split = line.split(colsep);
-                let key = split.next()?;
-                let value = split.next()?;
-                let value0 = value.trim_start().split(' ').next()?;
-                let value0_u64 = u64::from_str(value0).ok()?;
-                Some((key, value0_u64))
-            })
-            .for_each(|(k, v)| f(k, v));
+        parse_table(&content, colsep, f);
     }
 }
 
+fn parse_table<F>(content: &str, colsep: char, mut f: F)
+where
+    F: FnMut(&str, u64),
+{
+    content
+        .split('\n')
+        .flat_map(|line| {
+            let mut split = line.split(colsep);
+            let key = split.next()?;
+            let value = split.next()?;
+            let value0 = value.trim_start().split(' ').next()?;
+            let value0_u64 = u64::from_str(value0).ok()?;
+            Some((key, value0_u64))
+        })
+        .for_each(|(k, v)| f(k, v));
+}
+
 impl crate::CGroupLimits {
     fn new(sys: &SystemInner) -> Option<Self> {
         assert!(
             sys.mem_total != 0,
             "You need to call System::refresh_memory before trying to get cgroup limits!",
         );
-        if let (Some(mem_cur), Some(mem_max)) = (
-            read_u64("/sys/fs/cgroup/memory.current"),
-            read_u64("/sys/fs/cgroup/memory.max"),
-        ) {
-            // cgroups v2
-
-            let mut limits = Self {
-                total_memory: sys.mem_total,
-                free_memory: sys.mem_free,
-                free_swap: sys.swap_free,
-            };
+        let cgroups = get_all_data("/proc/self/cgroup", 4_096).unwrap_or_default();
+        let mut limits = Self {
+            total_memory: sys.mem_total,
+            free_memory: sys.mem_free,
+            free_swap: sys.swap_free,
+            cpu_quota: None,
+            cpus: None,
+        };
+        // The limits of the parent cgroups also apply.
+        let min_limit = |dirs: &[PathBuf], file: &str| {
+            dirs.iter().filter_map(|dir| read_u64(dir.join(file))).min()
+        };
+        let min_quota = |dirs: &[PathBuf], read_quota: &dyn Fn(&Path) -> Option<f64>| {
+            dirs.iter()
+                .filter_map(|dir| read_quota(dir))
+                .min_by(|a, b| a.total_cmp(b))
+        };
+        let read = |file: PathBuf| get_all_data(file, 1_024).ok();
 
-            limits.total_memory = min(mem_max, sys.mem_total);
+        let unified = cgroup_dirs(&cgroups, None);
+        if let Some(mem_cur) = read_u64(unified[0].join("memory.current")) {
+            // cgroups v2
+            if let Some(mem_max) = min_limit(&unified, "memory.max") {
+                limits.total_memory = min(mem_max, sys.mem_total);
+            }
             limits.free_memory = limits.total_memory.saturating_sub(mem_cur);
 
-            if let Some(swap_cur) = read_u64("/sys/fs/cgroup/memory.swap.current") {
+            if let Some(swap_cur) = read_u64(unified[0].join("memory.swap.current")) {
                 limits.free_swap = sys.swap_total.saturating_sub(swap_cur);
             }
 
-            read_table("/sys/fs/cgroup/memory.stat", ' ', |_key, value| {
+            read_table(unified[0].join("memory.stat"), ' ', |_key, value| {
                 limits.free_memory = limits.free_memory.saturating_sub(value);
             });
 
+            // Like `150000 100000`, or `max 100000` if there is no limit.
+            limits.cpu_quota = min_quota(&unified, &|dir| {
+                let cpu_max = read(dir.join("cpu.max"))?;
+                let mut parts = cpu_max.split_whitespace();
+                parse_cpu_quota(parts.next()?, parts.next()?)
+            });
+            limits.cpus = read(unified[0].join("cpuset.cpus.effective"))
+                .and_then(|cpus| parse_cpu_list(&cpus));
+
             Some(limits)
-        } else if let (Some(mem_cur), Some(mem_max)) = (
+        } else {
             // cgroups v1
-            read_u64("/sys/fs/cgroup/memory/memory.usage_in_bytes"),
-            read_u64("/sys/fs/cgroup/memory/memory.limit_in_bytes"),
-        ) {
-            let mut limits = Self {
-                total_memory: sys.mem_total,
-                free_memory: sys.mem_free,
-                free_swap: sys.swap_free,
-            };
+            let memory = cgroup_dirs(&cgroups, Some("memory"));
+            let mem_cur = read_u64(memory[0].join("memory.usage_in_bytes"))?;
+            let mem_max = min_limit(&memory, "memory.limit_in_bytes")?;
 
             limits.total_memory = min(mem_max, sys.mem_total);
             limits.free_memory = limits.total_memory.saturating_sub(mem_cur);
 
+            // The quota is `-1` if there is no limit.
+            limits.cpu_quota = min_quota(&cgroup_dirs(&cgroups, Some("cpu")), &|dir| {
+                parse_cpu_quota(
+                    read(dir.join("cpu.cfs_quota_us"))?.trim(),
+                    read(dir.join("cpu.cfs_period_us"))?.trim(),
+                )
+            });
+            limits.cpus =
+                read(cgroup_dirs(&cgroups, Some("cpuset"))[0].join("cpuset.effective_cpus"))
+                    .and_then(|cpus| parse_cpu_list(&cpus));
+
             Some(limits)
+        }
+    }
+}
+
+/// Returns the directories of the cgroup of the current process, from the cgroup itself up to
+/// the root of the hierarchy. `controller` is the cgroup v1 controller, or `None` for the
+/// cgroup v2 unified hierarchy. It always contains at least the root.
+fn cgroup_dirs(cgroups: &str, controller: Option<&str>) -> Vec<PathBuf> {
+    let root = match controller {
+        Some(controller) => Path::new(CGROUP_ROOT).join(controller),
+        None => PathBuf::from(CGROUP_ROOT),
+    };
+    let dir = parse_cgroup_path(cgroups, controller)
+        .map(|path| root.join(path.trim_start_matches('/')))
+        // Without a cgroup namespace, the cgroup of a container is mounted as the root of the
+        // hierarchy.
+        .filter(|dir| dir.is_dir())
+        .unwrap_or_else(|| root.clone());
+    dir.ancestors()
+        .take_while(|dir| dir.starts_with(&root))
+        .map(Path::to_path_buf)
+        .collect()
+}
+
+/// Returns the path of the cgroup from the content of `/proc/self/cgroup`, which has lines like
+/// `4:memory:/docker/3f2a9c` (cgroups v1) or `0::/user.slice` (cgroups v2).
+fn parse_cgroup_path<'a>(cgroups: &'a str, controller: Option<&str>) -> Option<&'a str> {
+    cgroups.lines().find_map(|line| {
+        let mut parts = line.splitn(3, ':');
+        let (_, controllers, path) = (parts.next()?, parts.next()?, parts.next()?);
+        let found = match controller {
+            Some(controller) => controllers.split(',').any(|c| c == controller),
+            None => controllers.is_empty(),
+        };
+        if found {
+            Some(path)
         } else {
             None
         }
+    })
+}
+
+/// Returns the number of CPUs the cgroup can use from its CPU bandwidth `quota` and `period`,
+/// or `None` if it isn't limited.
+fn parse_cpu_quota(quota: &str, period: &str) -> Option<f64> {
+    let quota = quota.parse::<f64>().ok().filter(|quota| *quota > 0.)?;
+    let period = period.parse::<f64>().ok().filter(|period| *period > 0.)?;
+    Some(quota / period)
+}
+
+/// Returns the number of CPUs in a list like `0-3,8,10-11`.
+fn parse_cpu_list(list: &str) -> Option<usize> {
+    let mut count = 0;
+    for range in list.trim().split(',').filter(|range| !range.is_empty()) {
+        count += match range.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (start.parse::<usize>().ok()?, end.parse::<usize>().ok()?);
+                end.checked_sub(start)? + 1
+            }
+            None => {
+                range.parse::<usize>().ok()?;
+                1
+            }
+        };
+    }
+    if count != 0 {
+        Some(count)
+    } else {
+        None
     }
 }
 
@@ -581,7 +1184,7 @@ fn get_system_info_linux(info: InfoType, path: &Path, fallback_path: &Path) -> O
             InfoType::DistributionID => "ID=",
         };
 
-        for line in reader.lines().flatten() {
+        for line in reader.lines().map_while(Result::ok) {
             if let Some(stripped) = line.strip_prefix(info_str) {
                 return Some(stripped.replace('"', ""));
             }
@@ -602,7 +1205,7 @@ fn get_system_info_linux(info: InfoType, path: &Path, fallback_path: &Path) -> O
             return None;
         }
     };
-    for line in reader.lines().flatten() {
+    for line in reader.lines().map_while(Result::ok) {
         if let Some(stripped) = line.strip_prefix(info_str) {
             return Some(stripped.replace('"', ""));
         }
@@ -640,13 +1243,119 @@ fn get_system_info_android(info: InfoType) -> Option<String> {
     }
 }
 
+fn refresh_zram_devices(devices: &mut Vec<ZramDevice>) {
+    devices.clear();
+    let entries = match std::fs::read_dir("/sys/block") {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let name = match entry.file_name().into_string() {
+            Ok(name) if name.starts_with("zram") => name,
+            _ => continue,
+        };
+        let path = entry.path();
+        let mm_stat = match get_all_data(path.join("mm_stat"), 128) {
+            Ok(mm_stat) => mm_stat,
+            Err(_) => continue,
+        };
+        let (uncompressed, compressed, memory_used) = match parse_zram_mm_stat(&mm_stat) {
+            Some(stats) => stats,
+            None => continue,
+        };
+        let disk_size = get_all_data(path.join("disksize"), 32)
+            .ok()
+            .and_then(|size| size.trim().parse().ok())
+            .unwrap_or(0);
+        let algorithm = get_all_data(path.join("comp_algorithm"), 128)
+            .map(|algorithms| parse_zram_algorithm(&algorithms))
+            .unwrap_or_default();
+        devices.push(ZramDevice {
+            name,
+            algorithm,
+            disk_size,
+            uncompressed,
+            compressed,
+            memory_used,
+        });
+    }
+    devices.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+}
+
+// The first fields of `mm_stat` are `orig_data_size`, `compr_data_size` and `mem_used_total`.
+fn parse_zram_mm_stat(mm_stat: &str) -> Option<(u64, u64, u64)> {
+    let mut fields = mm_stat.split_whitespace().map(|field| field.parse().ok());
+    Some((fields.next()??, fields.next()??, fields.next()??))
+}
+
+// `comp_algorithm` lists the available algorithms, the one in use is between brackets:
+//
+// lzo lzo-rle lz4 [zstd]
+fn parse_zram_algorithm(algorithms: &str) -> String {
+    algorithms
+        .split_whitespace()
+        .find_map(|algorithm| algorithm.strip_prefix('[')?.strip_suffix(']'))
+        .unwrap_or_default()
+        .to_owned()
+}
+
+fn read_pressure_stats(path: &str) -> Option<PressureStats> {
+    let content = get_all_data(path, 1024).ok()?;
+    Some(parse_pressure_stats(&content))
+}
+
+// The content looks like this (the "full" line is missing for the CPU before Linux 5.13):
+//
+// some avg10=0.00 avg60=0.00 avg300=0.00 total=0
+// full avg10=0.00 avg60=0.00 avg300=0.00 total=0
+fn parse_pressure_stats(content: &str) -> PressureStats {
+    let mut stats = PressureStats::default();
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let values = match fields.next() {
+            Some("some") => &mut stats.some,
+            Some("full") => &mut stats.full,
+            _ => continue,
+        };
+        for field in fields {
+            match field.split_once('=') {
+                Some(("avg10", value)) => values.avg10 = value.parse().unwrap_or(0.),
+                Some(("avg60", value)) => values.avg60 = value.parse().unwrap_or(0.),
+                Some(("avg300", value)) => values.avg300 = value.parse().unwrap_or(0.),
+                Some(("total", value)) => values.total = value.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+    }
+    stats
+}
+
+/// Reads the machine ID from the files under `root`.
+fn get_machine_id(root: &Path) -> Option<String> {
+    let read = |path: &str| {
+        get_all_data(root.join(path), 64)
+            .ok()
+            .map(|data| data.trim().to_owned())
+            .filter(|data| !data.is_empty())
+    };
+    // The SMBIOS UUID can only be read by root.
+    read("etc/machine-id")
+        .or_else(|| read("var/lib/dbus/machine-id"))
+        .or_else(|| read("sys/class/dmi/id/product_uuid"))
+}
+
 #[cfg(test)]
 mod test {
     #[cfg(target_os = "android")]
     use super::get_system_info_android;
     #[cfg(not(target_os = "android"))]
     use super::get_system_info_linux;
-    use super::InfoType;
+    use super::{
+        get_machine_id, parse_cgroup_path, parse_container, parse_cpu_list, parse_cpu_quota,
+        parse_file_nr, parse_loadavg_threads, parse_pressure_stats, parse_zram_algorithm,
+        parse_zram_mm_stat, InfoType, SystemInner,
+    };
+    use crate::{ContainerRuntime, MemoryBreakdown, PressureValues};
 
     #[test]
     #[cfg(target_os = "android")]
@@ -719,4 +1428,192 @@ DISTRIB_DESCRIPTION="Ubuntu 20.10"
             None
         );
     }
+
+    #[test]
+    fn pressure_stats() {
+        let stats = parse_pressure_stats(
+            "some avg10=1.50 avg60=0.25 avg300=0.00 total=123456\n\
+             full avg10=0.75 avg60=0.10 avg300=0.00 total=6543\n",
+        );
+        assert_eq!(
+            stats.some,
+            PressureValues {
+                avg10: 1.5,
+                avg60: 0.25,
+                avg300: 0.,
+                total: 123456,
+            }
+        );
+        assert_eq!(
+            stats.full,
+            PressureValues {
+                avg10: 0.75,
+                avg60: 0.1,
+                avg300: 0.,
+                total: 6543,
+            }
+        );
+
+        // Before Linux 5.13, there is no "full" line for the CPU.
+        let stats = parse_pressure_stats("some avg10=0.00 avg60=0.00 avg300=0.00 total=42\n");
+        assert_eq!(stats.some.total, 42);
+        assert_eq!(stats.full, PressureValues::default());
+    }
+
+    #[test]
+    fn zram_stats() {
+        assert_eq!(
+            parse_zram_mm_stat(
+                "  4096000   1024000   1200000        0  1300000       12        0        3\n"
+            ),
+            Some((4096000, 1024000, 1200000)),
+        );
+        assert_eq!(parse_zram_mm_stat("4096000\n"), None);
+
+        assert_eq!(parse_zram_algorithm("lzo lzo-rle lz4 [zstd]\n"), "zstd");
+        assert_eq!(parse_zram_algorithm("[lzo-rle] lz4\n"), "lzo-rle");
+        assert_eq!(parse_zram_algorithm(""), "");
+    }
+
+    #[test]
+    fn container() {
+        assert_eq!(
+            parse_container(Some("lxc\n"), ""),
+            Some(ContainerRuntime::Lxc)
+        );
+        assert_eq!(
+            parse_container(Some("oci"), ""),
+            Some(ContainerRuntime::Other("oci".to_owned()))
+        );
+        assert_eq!(
+            parse_container(None, "12:pids:/docker/3f2a9c\n0::/docker/3f2a9c\n"),
+            Some(ContainerRuntime::Docker)
+        );
+        assert_eq!(
+            parse_container(None, "0::/machine.slice/libpod-3f2a9c.scope\n"),
+            Some(ContainerRuntime::Podman)
+        );
+        assert_eq!(parse_container(None, "0::/init.scope\n"), None);
+        assert_eq!(parse_container(Some(""), "0::/\n"), None);
+    }
+
+    #[test]
+    fn check_parse_counters() {
+        assert_eq!(
+            parse_loadavg_threads("0.52 0.58 0.59 3/1078 12345\n"),
+            Some(1078)
+        );
+        assert_eq!(parse_loadavg_threads("0.52 0.58 0.59\n"), None);
+        assert_eq!(
+            parse_file_nr("12416\t0\t9223372036854775807\n"),
+            Some((12416, i64::MAX as u64))
+        );
+        assert_eq!(parse_file_nr("1024\t24\t4096\n"), Some((1000, 4096)));
+        assert_eq!(parse_file_nr("1024\n"), None);
+    }
+
+    #[test]
+    fn check_parse_cgroup() {
+        let cgroups = "12:cpu,cpuacct:/docker/3f2a9c\n4:memory:/docker/3f2a9c\n0::/system.slice\n";
+        assert_eq!(
+            parse_cgroup_path(cgroups, Some("cpu")),
+            Some("/docker/3f2a9c")
+        );
+        assert_eq!(
+            parse_cgroup_path(cgroups, Some("memory")),
+            Some("/docker/3f2a9c")
+        );
+        assert_eq!(parse_cgroup_path(cgroups, Some("cpuset")), None);
+        assert_eq!(parse_cgroup_path(cgroups, None), Some("/system.slice"));
+
+        assert_eq!(parse_cpu_quota("150000", "100000"), Some(1.5));
+        assert_eq!(parse_cpu_quota("max", "100000"), None);
+        assert_eq!(parse_cpu_quota("-1", "100000"), None);
+
+        assert_eq!(parse_cpu_list("0-3,8,10-11\n"), Some(7));
+        assert_eq!(parse_cpu_list("5"), Some(1));
+        assert_eq!(parse_cpu_list(""), None);
+        assert_eq!(parse_cpu_list("3-1"), None);
+    }
+
+    #[test]
+    fn check_update_memory() {
+        let meminfo = "\
+MemTotal:        8000000 kB
+MemFree:         1000000 kB
+MemAvailable:    4000000 kB
+Buffers:          100000 kB
+Cached:          2000000 kB
+Shmem:             50000 kB
+Mapped:           300000 kB
+Dirty:              1234 kB
+Writeback:            56 kB
+SReclaimable:     200000 kB
+SUnreclaim:        80000 kB
+";
+        let mut s = SystemInner::new();
+        s.update_memory(meminfo);
+        assert_eq!(s.total_memory(), 8_000_000 * 1_024);
+        assert_eq!(s.free_memory(), 1_000_000 * 1_024);
+        assert_eq!(s.available_memory(), 4_000_000 * 1_024);
+        assert_eq!(
+            s.memory_breakdown(),
+            MemoryBreakdown {
+                buffers: 100_000 * 1_024,
+                cached: 2_000_000 * 1_024,
+                shared: 50_000 * 1_024,
+                slab_reclaimable: 200_000 * 1_024,
+                slab_unreclaimable: 80_000 * 1_024,
+                mapped: 300_000 * 1_024,
+                dirty: 1_234 * 1_024,
+                writeback: 56 * 1_024,
+            }
+        );
+    }
+
+    #[test]
+    fn check_machine_id() {
+        use std::fs;
+
+        let root = tempfile::tempdir().expect("failed to create temporary directory");
+        let root = root.path();
+        assert_eq!(get_machine_id(root), None);
+
+        let write = |path: &str, content: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).expect("failed to create directory");
+            fs::write(path, content).expect("failed to write file");
+        };
+
+        write(
+            "sys/class/dmi/id/product_uuid",
+            "4c4c4544-0042-3510-8052-b7c04f4e3432\n",
+        );
+        assert_eq!(
+            get_machine_id(root).as_deref(),
+            Some("4c4c4544-0042-3510-8052-b7c04f4e3432"),
+        );
+
+        write(
+            "var/lib/dbus/machine-id",
+            "0123456789abcdef0123456789abcdef\n",
+        );
+        assert_eq!(
+            get_machine_id(root).as_deref(),
+            Some("0123456789abcdef0123456789abcdef"),
+        );
+
+        // An empty `/etc/machine-id` (like in some container images) is ignored.
+        write("etc/machine-id", "\n");
+        assert_eq!(
+            get_machine_id(root).as_deref(),
+            Some("0123456789abcdef0123456789abcdef"),
+        );
+
+        write("etc/machine-id", "fedcba9876543210fedcba9876543210\n");
+        assert_eq!(
+            get_machine_id(root).as_deref(),
+            Some("fedcba9876543210fedcba9876543210"),
+        );
+    }
 }
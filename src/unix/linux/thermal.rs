@@ -0,0 +1,129 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::get_all_data;
+use crate::{CoolingDevice, ThermalZone, TripPoint, TripPointKind};
+
+use std::fs::read_dir;
+
+pub(crate) fn get_thermal_zones(zones: &mut Vec<ThermalZone>) {
+    let dir = match read_dir("/sys/class/thermal") {
+        Ok(dir) => dir,
+        Err(_) => return,
+    };
+    for entry in dir.flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !name.starts_with("thermal_zone") {
+            continue;
+        }
+        let path = entry.path();
+        // The `cdevN` entries are links to the cooling devices, so their attributes can be read
+        // through them.
+        zones.push(parse_thermal_zone(name, |file| {
+            get_all_data(path.join(file), 64)
+                .ok()
+                .map(|data| data.trim().to_owned())
+        }));
+    }
+    zones.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+}
+
+/// Builds a thermal zone from the attributes returned by `read`. Temperatures are given in
+/// millidegree Celsius.
+fn parse_thermal_zone<F: Fn(&str) -> Option<String>>(name: String, read: F) -> ThermalZone {
+    let temperature = |file: &str| {
+        read(file)
+            .and_then(|value| value.parse::<i64>().ok())
+            .map(|value| value as f32 / 1_000.)
+    };
+    let number = |file: &str| read(file).and_then(|value| value.parse::<u64>().ok());
+
+    let mut trip_points = Vec::new();
+    for index in 0.. {
+        let trip_temperature = match temperature(&format!("trip_point_{index}_temp")) {
+            Some(temperature) => temperature,
+            None => break,
+        };
+        let kind = match read(&format!("trip_point_{index}_type")).as_deref() {
+            Some("active") => TripPointKind::Active,
+            Some("passive") => TripPointKind::Passive,
+            Some("hot") => TripPointKind::Hot,
+            Some("critical") => TripPointKind::Critical,
+            _ => continue,
+        };
+        trip_points.push((
+            index,
+            TripPoint {
+                kind,
+                temperature: trip_temperature,
+                hysteresis: temperature(&format!("trip_point_{index}_hyst")),
+                cooling_devices: Vec::new(),
+            },
+        ));
+    }
+    for index in 0.. {
+        let trip_index = match number(&format!("cdev{index}_trip_point")) {
+            Some(trip_index) => trip_index,
+            None => break,
+        };
+        let device = CoolingDevice {
+            name: read(&format!("cdev{index}/type")).unwrap_or_default(),
+            state: number(&format!("cdev{index}/cur_state")).unwrap_or(0),
+            max_state: number(&format!("cdev{index}/max_state")).unwrap_or(0),
+        };
+        if let Some((_, trip_point)) = trip_points.iter_mut().find(|(i, _)| *i == trip_index) {
+            trip_point.cooling_devices.push(device);
+        }
+    }
+
+    ThermalZone {
+        name,
+        kind: read("type"),
+        temperature: temperature("temp"),
+        trip_points: trip_points
+            .into_iter()
+            .map(|(_, trip_point)| trip_point)
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_thermal_zone;
+    use crate::TripPointKind;
+
+    use std::collections::HashMap;
+
+    #[test]
+    fn check_parse_thermal_zone() {
+        let attributes: HashMap<_, _> = [
+            ("type", "acpitz"),
+            ("temp", "45000"),
+            ("trip_point_0_temp", "98000"),
+            ("trip_point_0_type", "critical"),
+            ("trip_point_1_temp", "60500"),
+            ("trip_point_1_type", "active"),
+            ("trip_point_1_hyst", "2000"),
+            ("cdev0_trip_point", "1"),
+            ("cdev0/type", "Fan"),
+            ("cdev0/cur_state", "0"),
+            ("cdev0/max_state", "1"),
+        ]
+        .iter()
+        .copied()
+        .collect();
+        let zone = parse_thermal_zone("thermal_zone0".to_owned(), |file| {
+            attributes.get(file).map(|value| value.to_string())
+        });
+        assert_eq!(zone.kind.as_deref(), Some("acpitz"));
+        assert_eq!(zone.temperature, Some(45.));
+        assert_eq!(zone.trip_points.len(), 2);
+        assert_eq!(zone.trip_points[0].kind, TripPointKind::Critical);
+        assert_eq!(zone.trip_points[0].temperature, 98.);
+        assert!(zone.trip_points[0].cooling_devices.is_empty());
+        assert_eq!(zone.trip_points[1].kind, TripPointKind::Active);
+        assert_eq!(zone.trip_points[1].hysteresis, Some(2.));
+        assert_eq!(zone.trip_points[1].cooling_devices.len(), 1);
+        assert_eq!(zone.trip_points[1].cooling_devices[0].name, "Fan");
+        assert_eq!(zone.trip_points[1].cooling_devices[0].max_state, 1);
+    }
+}
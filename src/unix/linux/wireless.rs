@@ -0,0 +1,214 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::netlink::{
+    align, find_attribute, parse_attributes, read_u16, read_u32, NetlinkSocket,
+};
+use crate::{MacAddr, WirelessInfo};
+
+use std::convert::TryInto;
+use std::ffi::CString;
+use std::path::Path;
+
+// Generic netlink controller.
+const GENL_ID_CTRL: u16 = 0x10;
+const CTRL_CMD_GETFAMILY: u8 = 3;
+const CTRL_ATTR_FAMILY_ID: u16 = 1;
+const CTRL_ATTR_FAMILY_NAME: u16 = 2;
+
+// Commands and attributes from `linux/nl80211.h`.
+const NL80211_CMD_GET_INTERFACE: u8 = 5;
+const NL80211_CMD_GET_STATION: u8 = 17;
+const NL80211_ATTR_IFINDEX: u16 = 3;
+const NL80211_ATTR_MAC: u16 = 6;
+const NL80211_ATTR_STA_INFO: u16 = 21;
+const NL80211_ATTR_WIPHY_FREQ: u16 = 38;
+const NL80211_ATTR_SSID: u16 = 52;
+const NL80211_STA_INFO_SIGNAL: u16 = 7;
+const NL80211_STA_INFO_TX_BITRATE: u16 = 8;
+const NL80211_RATE_INFO_BITRATE: u16 = 1;
+const NL80211_RATE_INFO_BITRATE32: u16 = 5;
+
+// Size of `struct genlmsghdr`.
+const GENL_HDRLEN: usize = 4;
+
+/// Converts a frequency (in MHz) into a channel number.
+fn frequency_to_channel(frequency: u32) -> Option<u32> {
+    match frequency {
+        2484 => Some(14),
+        2412..=2472 => Some((frequency - 2407) / 5),
+        // 6 GHz band.
+        5955..=7115 => Some((frequency - 5950) / 5),
+        5000..=5950 => Some((frequency - 5000) / 5),
+        _ => None,
+    }
+}
+
+/// Generic netlink socket used to send nl80211 requests.
+struct Nl80211Socket {
+    socket: NetlinkSocket,
+    family_id: u16,
+}
+
+impl Nl80211Socket {
+    fn new() -> Option<Self> {
+        let mut socket = Self {
+            socket: NetlinkSocket::new(libc::NETLINK_GENERIC)?,
+            // The ID of the nl80211 family is retrieved from the controller.
+            family_id: GENL_ID_CTRL,
+        };
+        let answers = socket.request(
+            CTRL_CMD_GETFAMILY,
+            false,
+            &[(CTRL_ATTR_FAMILY_NAME, b"nl80211\0")],
+        )?;
+        socket.family_id = answers.iter().find_map(|answer| {
+            read_u16(find_attribute(
+                &parse_attributes(answer),
+                CTRL_ATTR_FAMILY_ID,
+            )?)
+        })?;
+        Some(socket)
+    }
+
+    /// Sends a request and returns the attributes of the answers.
+    fn request(
+        &mut self,
+        command: u8,
+        dump: bool,
+        attributes: &[(u16, &[u8])],
+    ) -> Option<Vec<Vec<u8>>> {
+        let mut buf = Vec::with_capacity(64);
+        buf.extend_from_slice(&[command, 1, 0, 0]);
+        for (kind, payload) in attributes {
+            buf.extend_from_slice(&(4 + payload.len() as u16).to_ne_bytes());
+            buf.extend_from_slice(&kind.to_ne_bytes());
+            buf.extend_from_slice(payload);
+            buf.resize(align(buf.len()), 0);
+        }
+        let answers = self.socket.request(self.family_id, dump, &buf)?;
+        Some(
+            answers
+                .into_iter()
+                .filter_map(|answer| answer.get(GENL_HDRLEN..).map(|payload| payload.to_vec()))
+                .collect(),
+        )
+    }
+}
+
+/// Returns the details of the Wi-Fi connection of `interface`, or `None` if it is not a connected
+/// wireless interface.
+pub(crate) fn get_wireless_info(interface: &str) -> Option<WirelessInfo> {
+    // Only wireless interfaces have a `phy80211` entry.
+    if !Path::new("/sys/class/net")
+        .join(interface)
+        .join("phy80211")
+        .exists()
+    {
+        return None;
+    }
+    let name = CString::new(interface).ok()?;
+    let index = unsafe { libc::if_nametoindex(name.as_ptr()) };
+    if index == 0 {
+        return None;
+    }
+    let index = index.to_ne_bytes();
+
+    let mut socket = Nl80211Socket::new()?;
+    let mut info = WirelessInfo::default();
+    let interfaces = socket.request(
+        NL80211_CMD_GET_INTERFACE,
+        false,
+        &[(NL80211_ATTR_IFINDEX, &index)],
+    )?;
+    if let Some(attributes) = interfaces.first().map(|answer| parse_attributes(answer)) {
+        info.ssid = find_attribute(&attributes, NL80211_ATTR_SSID)
+            .map(|ssid| String::from_utf8_lossy(ssid).into_owned());
+        info.channel = find_attribute(&attributes, NL80211_ATTR_WIPHY_FREQ)
+            .and_then(read_u32)
+            .and_then(frequency_to_channel);
+    }
+    // When connected to an access point, it is the only "station" of the interface.
+    if let Some(stations) = socket.request(
+        NL80211_CMD_GET_STATION,
+        true,
+        &[(NL80211_ATTR_IFINDEX, &index)],
+    ) {
+        if let Some(station) = stations.first() {
+            parse_station(&parse_attributes(station), &mut info);
+        }
+    }
+    if info.ssid.is_none() && info.bssid.is_none() {
+        // The interface is not connected.
+        return None;
+    }
+    Some(info)
+}
+
+fn parse_station(attributes: &[(u16, &[u8])], info: &mut WirelessInfo) {
+    info.bssid = find_attribute(attributes, NL80211_ATTR_MAC)
+        .and_then(|mac| mac.try_into().ok())
+        .map(MacAddr);
+    let station_info = match find_attribute(attributes, NL80211_ATTR_STA_INFO) {
+        Some(station_info) => parse_attributes(station_info),
+        None => return,
+    };
+    info.signal = find_attribute(&station_info, NL80211_STA_INFO_SIGNAL)
+        .and_then(|signal| signal.first())
+        .map(|signal| i32::from(*signal as i8));
+    if let Some(rate_info) = find_attribute(&station_info, NL80211_STA_INFO_TX_BITRATE) {
+        let rate_info = parse_attributes(rate_info);
+        // Bitrates are in units of 100 kbit/s.
+        info.bitrate = find_attribute(&rate_info, NL80211_RATE_INFO_BITRATE32)
+            .and_then(read_u32)
+            .or_else(|| {
+                find_attribute(&rate_info, NL80211_RATE_INFO_BITRATE)
+                    .and_then(read_u16)
+                    .map(u32::from)
+            })
+            .map(|bitrate| u64::from(bitrate) * 100_000);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{frequency_to_channel, parse_attributes, parse_station};
+    use crate::{MacAddr, WirelessInfo};
+
+    fn attribute(kind: u16, payload: &[u8]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&(4 + payload.len() as u16).to_ne_bytes());
+        buf.extend_from_slice(&kind.to_ne_bytes());
+        buf.extend_from_slice(payload);
+        buf.resize((buf.len() + 3) & !3, 0);
+        buf
+    }
+
+    #[test]
+    fn station() {
+        let mut rate_info = attribute(5, &5200u32.to_ne_bytes());
+        rate_info.extend(attribute(1, &520u16.to_ne_bytes()));
+        let mut station_info = attribute(7, &[-52i8 as u8]);
+        // Nested attributes have the `NLA_F_NESTED` flag.
+        station_info.extend(attribute(8 | 0x8000, &rate_info));
+        let mut station = attribute(6, &[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc]);
+        station.extend(attribute(21, &station_info));
+
+        let mut info = WirelessInfo::default();
+        parse_station(&parse_attributes(&station), &mut info);
+        assert_eq!(
+            info.bssid,
+            Some(MacAddr([0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc]))
+        );
+        assert_eq!(info.signal, Some(-52));
+        assert_eq!(info.bitrate, Some(520_000_000));
+    }
+
+    #[test]
+    fn channels() {
+        assert_eq!(frequency_to_channel(2412), Some(1));
+        assert_eq!(frequency_to_channel(2484), Some(14));
+        assert_eq!(frequency_to_channel(5180), Some(36));
+        assert_eq!(frequency_to_channel(5955), Some(1));
+        assert_eq!(frequency_to_channel(60480), None);
+    }
+}
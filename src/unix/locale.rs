@@ -0,0 +1,141 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::TimeZone;
+
+use std::env;
+use std::path::Path;
+
+pub(crate) fn get_time_zone() -> TimeZone {
+    TimeZone {
+        name: get_time_zone_name(),
+        utc_offset: get_utc_offset(),
+    }
+}
+
+pub(crate) fn get_locale() -> Option<String> {
+    let from_env = |name: &str| env::var(name).ok().filter(|value| !value.is_empty());
+    let locale = from_env("LC_ALL").or_else(|| from_env("LANG"));
+    #[cfg(target_os = "linux")]
+    let locale = locale.or_else(|| {
+        // Read by `systemd-localed` on most distributions, and by PAM on Debian-based ones.
+        ["/etc/locale.conf", "/etc/default/locale"]
+            .iter()
+            .find_map(|path| parse_locale_conf(&std::fs::read_to_string(path).ok()?))
+    });
+    locale
+}
+
+fn get_time_zone_name() -> Option<String> {
+    if let Some(tz) = env::var("TZ").ok().filter(|tz| !tz.is_empty()) {
+        let tz = tz.trim_start_matches(':');
+        if tz.starts_with('/') {
+            return time_zone_from_path(Path::new(tz));
+        }
+        return Some(tz.to_owned());
+    }
+    #[cfg(target_os = "linux")]
+    if let Some(name) = std::fs::read_to_string("/etc/timezone")
+        .ok()
+        .map(|name| name.trim().to_owned())
+        .filter(|name| !name.is_empty())
+    {
+        return Some(name);
+    }
+    // It's a link to the zone file (like `/usr/share/zoneinfo/Europe/Paris`).
+    if let Some(name) = std::fs::read_link("/etc/localtime")
+        .ok()
+        .and_then(|path| time_zone_from_path(&path))
+    {
+        return Some(name);
+    }
+    // `tzsetup` copies the zone file instead and saves its name there.
+    #[cfg(target_os = "freebsd")]
+    if let Some(name) = std::fs::read_to_string("/var/db/zoneinfo")
+        .ok()
+        .map(|name| name.trim().to_owned())
+        .filter(|name| !name.is_empty())
+    {
+        return Some(name);
+    }
+    None
+}
+
+/// Returns the IANA name of the zone file at `path`, which is the part after `zoneinfo/`.
+fn time_zone_from_path(path: &Path) -> Option<String> {
+    let path = path.to_str()?;
+    let (_, name) = path.rsplit_once("zoneinfo/")?;
+    // The zone files are duplicated in `posix/` and `right/` on some systems.
+    let name = name
+        .strip_prefix("posix/")
+        .or_else(|| name.strip_prefix("right/"))
+        .unwrap_or(name);
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_owned())
+    }
+}
+
+fn get_utc_offset() -> i32 {
+    unsafe {
+        let now = libc::time(std::ptr::null_mut());
+        let mut tm: libc::tm = std::mem::zeroed();
+        if libc::localtime_r(&now, &mut tm).is_null() {
+            sysinfo_debug!("localtime_r failed");
+            return 0;
+        }
+        tm.tm_gmtoff as i32
+    }
+}
+
+/// Returns the `LANG` value of a `locale.conf` file.
+#[cfg(target_os = "linux")]
+fn parse_locale_conf(content: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let value = line.trim().strip_prefix("LANG=")?;
+        let value = value.trim_matches(|c| c == '"' || c == '\'');
+        if value.is_empty() {
+            None
+        } else {
+            Some(value.to_owned())
+        }
+    })
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod test {
+    use super::{parse_locale_conf, time_zone_from_path};
+
+    use std::path::Path;
+
+    #[test]
+    fn check_time_zone_from_path() {
+        assert_eq!(
+            time_zone_from_path(Path::new("/usr/share/zoneinfo/Europe/Paris")).as_deref(),
+            Some("Europe/Paris")
+        );
+        assert_eq!(
+            time_zone_from_path(Path::new("/var/db/timezone/zoneinfo/America/New_York")).as_deref(),
+            Some("America/New_York")
+        );
+        assert_eq!(
+            time_zone_from_path(Path::new("../usr/share/zoneinfo/posix/UTC")).as_deref(),
+            Some("UTC")
+        );
+        assert_eq!(time_zone_from_path(Path::new("/etc/zoneinfo/")), None);
+        assert_eq!(time_zone_from_path(Path::new("/etc/localtime")), None);
+    }
+
+    #[test]
+    fn check_parse_locale_conf() {
+        assert_eq!(
+            parse_locale_conf("# Generated\nLANG=\"en_US.UTF-8\"\nLC_TIME=C\n").as_deref(),
+            Some("en_US.UTF-8")
+        );
+        assert_eq!(
+            parse_locale_conf("LANG=fr_FR.UTF-8").as_deref(),
+            Some("fr_FR.UTF-8")
+        );
+        assert_eq!(parse_locale_conf("LANGUAGE=en_US:en\nLANG=\n"), None);
+    }
+}
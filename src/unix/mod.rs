@@ -24,20 +24,47 @@ cfg_if::cfg_if! {
     }
 }
 
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+pub(crate) mod disk_events;
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+pub(crate) mod kernel_parameters;
+pub(crate) mod locale;
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+pub(crate) mod neighbors;
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+pub(crate) mod network_events;
 pub(crate) mod network_helper;
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+pub(crate) mod os_release;
+pub(crate) mod performance_counters;
+pub(crate) mod sessions;
 pub(crate) mod users;
 pub(crate) mod utils;
 
 pub(crate) struct DisksInner {
     pub(crate) disks: Vec<crate::Disk>,
+    pub(crate) physical_disks: Vec<crate::PhysicalDisk>,
+    pub(crate) storage_pools: Vec<crate::StoragePool>,
 }
 
 impl DisksInner {
     pub(crate) fn from_vec(disks: Vec<crate::Disk>) -> Self {
-        Self { disks }
+        Self {
+            disks,
+            physical_disks: Vec::new(),
+            storage_pools: Vec::new(),
+        }
     }
 
     pub(crate) fn into_vec(self) -> Vec<crate::Disk> {
         self.disks
     }
+
+    pub(crate) fn physical_disks(&self) -> &[crate::PhysicalDisk] {
+        &self.physical_disks
+    }
+
+    pub(crate) fn storage_pools(&self) -> &[crate::StoragePool] {
+        &self.storage_pools
+    }
 }
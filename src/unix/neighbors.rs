@@ -0,0 +1,180 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{MacAddr, Neighbor, NeighborState};
+
+use std::convert::TryInto;
+use std::ffi::CStr;
+use std::mem;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::ptr::null_mut;
+
+// Values from `net/route.h`, not provided by `libc` on all targets.
+const RTF_LLINFO: libc::c_int = 0x400;
+const RTA_DST: libc::c_int = 0x1;
+const RTA_GATEWAY: libc::c_int = 0x2;
+
+// `struct rt_msghdr` and `struct rt_metrics` aren't provided by `libc` on all targets, so only
+// the offsets of the fields we need are used.
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+mod header {
+    pub(super) const RT_MSGHDR_LEN: usize = 92;
+    pub(super) const RMX_EXPIRE: usize = 48;
+    // Socket addresses are aligned on 4 bytes.
+    pub(super) const SOCKADDR_ALIGN: usize = std::mem::size_of::<u32>();
+
+    pub(super) fn read_expire(message: &[u8]) -> Option<u64> {
+        super::read::<4>(message, RMX_EXPIRE).map(|v| i32::from_ne_bytes(v) as _)
+    }
+}
+
+#[cfg(target_os = "freebsd")]
+mod header {
+    const ULONG: usize = std::mem::size_of::<libc::c_ulong>();
+    // `rtm_rmx` comes after `rtm_inits`, which is the first `u_long` field.
+    const RTM_RMX: usize = 32 + ULONG;
+    // `struct rt_metrics` contains 14 `u_long`.
+    pub(super) const RT_MSGHDR_LEN: usize = RTM_RMX + 14 * ULONG;
+    const RMX_EXPIRE: usize = RTM_RMX + 3 * ULONG;
+    // Socket addresses are aligned on `sizeof(long)`.
+    pub(super) const SOCKADDR_ALIGN: usize = ULONG;
+
+    pub(super) fn read_expire(message: &[u8]) -> Option<u64> {
+        super::read::<ULONG>(message, RMX_EXPIRE).map(|v| libc::c_ulong::from_ne_bytes(v) as _)
+    }
+}
+
+fn read<const N: usize>(data: &[u8], offset: usize) -> Option<[u8; N]> {
+    data.get(offset..offset + N)?.try_into().ok()
+}
+
+fn get_interface_name(index: u16) -> Option<String> {
+    let mut name = [0; libc::IF_NAMESIZE];
+    unsafe {
+        if libc::if_indextoname(index.into(), name.as_mut_ptr()).is_null() {
+            return None;
+        }
+        Some(CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned())
+    }
+}
+
+fn parse_ip_address(sockaddr: &[u8]) -> Option<IpAddr> {
+    match i32::from(*sockaddr.get(1)?) {
+        libc::AF_INET => Some(IpAddr::V4(Ipv4Addr::from(read::<4>(sockaddr, 4)?))),
+        libc::AF_INET6 => {
+            let mut address = read::<16>(sockaddr, 8)?;
+            // The scope of link-local addresses is embedded in the address by the kernel.
+            if address[0] == 0xfe && address[1] & 0xc0 == 0x80 {
+                address[2] = 0;
+                address[3] = 0;
+            }
+            Some(IpAddr::V6(Ipv6Addr::from(address)))
+        }
+        _ => None,
+    }
+}
+
+/// Parses a `rt_msghdr` followed by the destination (`sockaddr_in` or `sockaddr_in6`) and
+/// the gateway (`sockaddr_dl`).
+fn parse_neighbor(message: &[u8]) -> Option<Neighbor> {
+    let addrs = i32::from_ne_bytes(read::<4>(message, 12)?);
+    if addrs & RTA_DST == 0 || addrs & RTA_GATEWAY == 0 {
+        return None;
+    }
+    let destination = message.get(header::RT_MSGHDR_LEN..)?;
+    let len = usize::from(*destination.first()?);
+    let ip_address = parse_ip_address(destination.get(..len)?)?;
+    let len = if len == 0 {
+        header::SOCKADDR_ALIGN
+    } else {
+        (len + header::SOCKADDR_ALIGN - 1) & !(header::SOCKADDR_ALIGN - 1)
+    };
+
+    let gateway = destination.get(len..)?;
+    if i32::from(*gateway.get(1)?) != libc::AF_LINK {
+        return None;
+    }
+    let index = u16::from_ne_bytes(read::<2>(gateway, 2)?);
+    let name_len = usize::from(*gateway.get(5)?);
+    let address_len = usize::from(*gateway.get(6)?);
+    // Only Ethernet-like link layer addresses are supported.
+    let mac_address = if address_len == 6 {
+        MacAddr(read::<6>(gateway, 8 + name_len)?)
+    } else {
+        MacAddr::UNSPECIFIED
+    };
+    let state = if address_len == 0 {
+        NeighborState::Incomplete
+    } else if header::read_expire(message)? == 0 {
+        NeighborState::Permanent
+    } else {
+        NeighborState::Reachable
+    };
+    Some(Neighbor {
+        ip_address,
+        mac_address,
+        state,
+        interface: get_interface_name(index)?,
+    })
+}
+
+unsafe fn get_table(family: libc::c_int) -> Option<Vec<u8>> {
+    let mut mib = [
+        libc::CTL_NET,
+        libc::PF_ROUTE,
+        0,
+        family,
+        libc::NET_RT_FLAGS,
+        RTF_LLINFO,
+    ];
+    let mut len = 0;
+    if libc::sysctl(
+        mib.as_mut_ptr(),
+        mib.len() as _,
+        null_mut(),
+        &mut len,
+        null_mut(),
+        0,
+    ) < 0
+    {
+        return None;
+    }
+    // The table can grow between the calls so a bit more is allocated.
+    len += len / 8;
+    let mut buf = vec![0u8; len];
+    if libc::sysctl(
+        mib.as_mut_ptr(),
+        mib.len() as _,
+        buf.as_mut_ptr() as *mut _,
+        &mut len,
+        null_mut(),
+        0,
+    ) < 0
+    {
+        return None;
+    }
+    buf.truncate(len);
+    Some(buf)
+}
+
+pub(crate) fn get_neighbors(neighbors: &mut Vec<Neighbor>) {
+    for family in [libc::AF_INET, libc::AF_INET6] {
+        let table = match unsafe { get_table(family) } {
+            Some(table) => table,
+            None => {
+                sysinfo_debug!("failed to retrieve the neighbor table");
+                continue;
+            }
+        };
+        let mut data = &table[..];
+        while data.len() >= mem::size_of::<u16>() {
+            let len = usize::from(u16::from_ne_bytes([data[0], data[1]]));
+            if len == 0 || len > data.len() {
+                break;
+            }
+            if let Some(neighbor) = parse_neighbor(&data[..len]) {
+                neighbors.push(neighbor);
+            }
+            data = &data[len..];
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::time::Duration;
+
+pub(crate) struct NetworkEventsInner {
+    // Routing socket, which receives a message every time an interface or an address changes.
+    socket: libc::c_int,
+}
+
+impl NetworkEventsInner {
+    pub(crate) fn new() -> Option<Self> {
+        let socket = unsafe { libc::socket(libc::PF_ROUTE, libc::SOCK_RAW, libc::AF_UNSPEC) };
+        if socket < 0 {
+            sysinfo_debug!("failed to create routing socket");
+            return None;
+        }
+        Some(Self { socket })
+    }
+
+    pub(crate) fn wait(&mut self, timeout: Option<Duration>) -> bool {
+        let mut fd = libc::pollfd {
+            fd: self.socket,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout = timeout.map_or(-1, |timeout| {
+            timeout.as_millis().min(libc::c_int::MAX as u128) as libc::c_int
+        });
+        unsafe {
+            if retry_eintr!(libc::poll(&mut fd, 1, timeout)) <= 0 || fd.revents & libc::POLLIN == 0
+            {
+                return false;
+            }
+            // The messages are also sent for route changes, so we don't look at them and let
+            // the caller check what changed.
+            let mut buf = [0u8; 2048];
+            while libc::recv(
+                self.socket,
+                buf.as_mut_ptr() as *mut _,
+                buf.len(),
+                libc::MSG_DONTWAIT,
+            ) > 0
+            {}
+        }
+        true
+    }
+}
+
+impl Drop for NetworkEventsInner {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.socket);
+        }
+    }
+}
@@ -1,6 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::common::MacAddr;
+use crate::common::{IpNetwork, MacAddr};
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::ptr::null_mut;
 
 /// This iterator yields an interface name and address.
@@ -116,3 +119,221 @@ pub(crate) fn get_interface_address() -> Result<InterfaceAddressIterator, String
         }
     }
 }
+
+unsafe fn parse_ip_network(ifap: *const libc::ifaddrs) -> Option<IpNetwork> {
+    let sock_addr = (*ifap).ifa_addr;
+    if sock_addr.is_null() {
+        return None;
+    }
+    let netmask = (*ifap).ifa_netmask;
+    match (*sock_addr).sa_family as libc::c_int {
+        libc::AF_INET => {
+            let addr = &*(sock_addr as *const libc::sockaddr_in);
+            let prefix = if netmask.is_null() {
+                32
+            } else {
+                let netmask = &*(netmask as *const libc::sockaddr_in);
+                netmask.sin_addr.s_addr.count_ones() as u8
+            };
+            Some(IpNetwork {
+                addr: IpAddr::V4(Ipv4Addr::from(u32::from_be(addr.sin_addr.s_addr))),
+                prefix,
+            })
+        }
+        libc::AF_INET6 => {
+            let addr = &*(sock_addr as *const libc::sockaddr_in6);
+            let prefix = if netmask.is_null() {
+                128
+            } else {
+                let netmask = &*(netmask as *const libc::sockaddr_in6);
+                netmask
+                    .sin6_addr
+                    .s6_addr
+                    .iter()
+                    .map(|byte| byte.count_ones() as u8)
+                    .sum()
+            };
+            Some(IpNetwork {
+                addr: IpAddr::V6(Ipv6Addr::from(addr.sin6_addr.s6_addr)),
+                prefix,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Returns the IPv4 and IPv6 networks of each interface.
+pub(crate) fn get_interface_ip_networks() -> Result<HashMap<String, Vec<IpNetwork>>, String> {
+    let mut ifap = null_mut();
+    unsafe {
+        if retry_eintr!(libc::getifaddrs(&mut ifap)) != 0 || ifap.is_null() {
+            return Err("failed to call getifaddrs()".to_string());
+        }
+        let mut networks: HashMap<String, Vec<IpNetwork>> = HashMap::new();
+        let mut current = ifap;
+        while !current.is_null() {
+            let ifa = current;
+            current = (*ifa).ifa_next;
+
+            if (*ifa).ifa_name.is_null() {
+                continue;
+            }
+            if let Some(ip_network) = parse_ip_network(ifa) {
+                let name = CStr::from_ptr((*ifa).ifa_name)
+                    .to_string_lossy()
+                    .into_owned();
+                networks.entry(name).or_default().push(ip_network);
+            }
+        }
+        libc::freeifaddrs(ifap);
+        Ok(networks)
+    }
+}
+
+/// `struct ifmediareq`, which has the same layout on macOS and FreeBSD.
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "ios"))]
+#[repr(C)]
+struct IfMediaReq {
+    ifm_name: [libc::c_char; libc::IFNAMSIZ],
+    ifm_current: libc::c_int,
+    ifm_mask: libc::c_int,
+    ifm_status: libc::c_int,
+    ifm_active: libc::c_int,
+    ifm_count: libc::c_int,
+    ifm_ulist: *mut libc::c_int,
+}
+
+/// Returns the duplex mode of the interface with the `SIOCGIFMEDIA` ioctl.
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "ios"))]
+pub(crate) fn get_duplex_mode(name: &str) -> crate::DuplexMode {
+    use crate::DuplexMode;
+
+    // `_IOWR('i', 56, struct ifmediareq)`
+    const SIOCGIFMEDIA: libc::c_ulong = 0xc000_0000
+        | ((std::mem::size_of::<IfMediaReq>() as libc::c_ulong & 0x1fff) << 16)
+        | ((b'i' as libc::c_ulong) << 8)
+        | 56;
+    const IFM_FDX: libc::c_int = 0x0010_0000;
+    const IFM_HDX: libc::c_int = 0x0020_0000;
+
+    if name.len() >= libc::IFNAMSIZ {
+        return DuplexMode::Unknown;
+    }
+    unsafe {
+        let mut req: IfMediaReq = std::mem::zeroed();
+        for (dst, src) in req.ifm_name.iter_mut().zip(name.bytes()) {
+            *dst = src as _;
+        }
+        let fd = libc::socket(libc::AF_INET, libc::SOCK_DGRAM, 0);
+        if fd < 0 {
+            sysinfo_debug!("get_duplex_mode: failed to create socket");
+            return DuplexMode::Unknown;
+        }
+        // Interfaces which don't support media selection (like loopback) make this call fail.
+        let ret = libc::ioctl(fd, SIOCGIFMEDIA, &mut req);
+        libc::close(fd);
+        if ret < 0 {
+            DuplexMode::Unknown
+        } else if req.ifm_active & IFM_FDX != 0 {
+            DuplexMode::Full
+        } else if req.ifm_active & IFM_HDX != 0 {
+            DuplexMode::Half
+        } else {
+            DuplexMode::Unknown
+        }
+    }
+}
+
+/// `struct ifreq` with the `ifr_data` member of the union.
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "ios"))]
+#[repr(C)]
+struct IfReqData {
+    ifr_name: [libc::c_char; libc::IFNAMSIZ],
+    ifr_data: *mut libc::c_void,
+    // The union is 16 bytes long.
+    _padding: [u8; 16 - std::mem::size_of::<*mut libc::c_void>()],
+}
+
+/// `struct vlanreq`. On FreeBSD, it also contains the VLAN protocol after the tag.
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "ios"))]
+#[repr(C)]
+#[allow(dead_code)]
+struct VlanReq {
+    vlr_parent: [libc::c_char; libc::IFNAMSIZ],
+    vlr_tag: libc::c_ushort,
+    vlr_proto: libc::c_ushort,
+}
+
+/// Returns the name of the parent interface of a VLAN with the `SIOCGETVLAN` ioctl.
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "ios"))]
+pub(crate) fn get_vlan_parent(name: &str) -> Option<String> {
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    const COMMAND: libc::c_ulong = 127;
+    // `SIOCGETVLAN` is an alias of `SIOCGIFGENERIC`.
+    #[cfg(target_os = "freebsd")]
+    const COMMAND: libc::c_ulong = 58;
+    // `_IOWR('i', COMMAND, struct ifreq)`
+    const SIOCGETVLAN: libc::c_ulong = 0xc000_0000
+        | ((std::mem::size_of::<IfReqData>() as libc::c_ulong & 0x1fff) << 16)
+        | ((b'i' as libc::c_ulong) << 8)
+        | COMMAND;
+
+    if name.len() >= libc::IFNAMSIZ {
+        return None;
+    }
+    unsafe {
+        let mut vlan: VlanReq = std::mem::zeroed();
+        let mut req: IfReqData = std::mem::zeroed();
+        for (dst, src) in req.ifr_name.iter_mut().zip(name.bytes()) {
+            *dst = src as _;
+        }
+        req.ifr_data = &mut vlan as *mut VlanReq as *mut _;
+        let fd = libc::socket(libc::AF_INET, libc::SOCK_DGRAM, 0);
+        if fd < 0 {
+            sysinfo_debug!("get_vlan_parent: failed to create socket");
+            return None;
+        }
+        let ret = libc::ioctl(fd, SIOCGETVLAN, &mut req);
+        libc::close(fd);
+        if ret < 0 {
+            return None;
+        }
+        let parent = std::ffi::CStr::from_ptr(vlan.vlr_parent.as_ptr())
+            .to_str()
+            .ok()?;
+        // The parent is empty if the VLAN isn't configured.
+        if parent.is_empty() {
+            None
+        } else {
+            Some(parent.to_owned())
+        }
+    }
+}
+
+/// Returns the kind of an interface from its `ifi_type`.
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "ios"))]
+pub(crate) fn get_interface_kind(ifi_type: u8) -> crate::InterfaceKind {
+    use crate::InterfaceKind;
+
+    // Values from `net/if_types.h`.
+    const IFT_L2VLAN: u8 = 0x87;
+    const IFT_IEEE8023ADLAG: u8 = 0x88;
+    const IFT_BRIDGE: u8 = 0xd1;
+
+    match ifi_type {
+        IFT_L2VLAN => InterfaceKind::Vlan,
+        IFT_IEEE8023ADLAG => InterfaceKind::Bond,
+        IFT_BRIDGE => InterfaceKind::Bridge,
+        _ => InterfaceKind::Other,
+    }
+}
+
+/// Returns the index of the interface called `name`.
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "ios"))]
+pub(crate) fn get_interface_index(name: &str) -> Option<u32> {
+    let name = std::ffi::CString::new(name).ok()?;
+    match unsafe { libc::if_nametoindex(name.as_ptr()) } {
+        0 => None,
+        index => Some(index),
+    }
+}
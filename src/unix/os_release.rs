@@ -0,0 +1,122 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::OsRelease;
+
+use std::collections::HashMap;
+
+pub(crate) fn get_os_release() -> Option<OsRelease> {
+    // `/etc/os-release` takes precedence, `/usr/lib/os-release` is the vendor default.
+    // FreeBSD generates it in `/var/run/os-release` (`/etc/os-release` links to it).
+    [
+        "/etc/os-release",
+        "/usr/lib/os-release",
+        "/var/run/os-release",
+    ]
+    .iter()
+    .find_map(|path| std::fs::read_to_string(path).ok())
+    .map(|content| parse_os_release(&content))
+}
+
+/// Parses an `os-release` file, which is made of shell-compatible variable assignments.
+pub(crate) fn parse_os_release(content: &str) -> OsRelease {
+    let fields = content
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                return None;
+            }
+            let (key, value) = line.split_once('=')?;
+            Some((key.trim().to_owned(), unquote(value.trim())))
+        })
+        .collect::<HashMap<_, _>>();
+    let get = |key: &str| fields.get(key).filter(|value| !value.is_empty()).cloned();
+
+    OsRelease {
+        id: get("ID"),
+        id_like: get("ID_LIKE")
+            .map(|ids| ids.split_whitespace().map(str::to_owned).collect())
+            .unwrap_or_default(),
+        name: get("NAME"),
+        pretty_name: get("PRETTY_NAME"),
+        version: get("VERSION"),
+        version_id: get("VERSION_ID"),
+        version_codename: get("VERSION_CODENAME"),
+        variant: get("VARIANT"),
+        variant_id: get("VARIANT_ID"),
+        build_id: get("BUILD_ID"),
+        fields,
+    }
+}
+
+/// Removes the quotes around `value` and, for double quotes, the backslash escapes.
+fn unquote(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some('\'') => value[1..]
+            .strip_suffix('\'')
+            .unwrap_or(&value[1..])
+            .to_owned(),
+        Some('"') => {
+            let mut unquoted = String::with_capacity(value.len());
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' => break,
+                    '\\' => {
+                        if let Some(c) = chars.next() {
+                            unquoted.push(c);
+                        }
+                    }
+                    c => unquoted.push(c),
+                }
+            }
+            unquoted
+        }
+        _ => value.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_os_release;
+
+    #[test]
+    fn check_parse_os_release() {
+        let release = parse_os_release(
+            r#"# Generated
+NAME="Fedora Linux"
+VERSION="39 (Workstation Edition)"
+ID=fedora
+ID_LIKE="rhel centos"
+VERSION_ID=39
+VERSION_CODENAME=""
+PRETTY_NAME='Fedora Linux 39 (Workstation Edition)'
+VARIANT="Workstation Edition"
+VARIANT_ID=workstation
+HOME_URL="https://fedoraproject.org/"
+LOGO="fedora-\"logo\"-icon"
+"#,
+        );
+        assert_eq!(release.id.as_deref(), Some("fedora"));
+        assert_eq!(release.id_like, ["rhel", "centos"]);
+        assert_eq!(release.name.as_deref(), Some("Fedora Linux"));
+        assert_eq!(
+            release.pretty_name.as_deref(),
+            Some("Fedora Linux 39 (Workstation Edition)")
+        );
+        assert_eq!(release.version_id.as_deref(), Some("39"));
+        assert_eq!(release.version_codename, None);
+        assert_eq!(release.variant.as_deref(), Some("Workstation Edition"));
+        assert_eq!(release.variant_id.as_deref(), Some("workstation"));
+        assert_eq!(release.build_id, None);
+        assert_eq!(
+            release.fields.get("HOME_URL").map(String::as_str),
+            Some("https://fedoraproject.org/")
+        );
+        assert_eq!(
+            release.fields.get("LOGO").map(String::as_str),
+            Some("fedora-\"logo\"-icon")
+        );
+        assert_eq!(release.fields.len(), 11);
+    }
+}
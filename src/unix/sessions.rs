@@ -0,0 +1,96 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Session;
+
+#[cfg(not(target_os = "android"))]
+pub(crate) fn get_sessions(sessions: &mut Vec<Session>) {
+    use libc::{endutxent, getutxent, setutxent, USER_PROCESS};
+
+    unsafe {
+        setutxent();
+        loop {
+            let entry = getutxent();
+            if entry.is_null() {
+                break;
+            }
+            let entry = &*entry;
+            if entry.ut_type != USER_PROCESS {
+                continue;
+            }
+            let user_name = match from_c_chars(&entry.ut_user) {
+                Some(user_name) => user_name,
+                None => continue,
+            };
+            let line = from_c_chars(&entry.ut_line);
+            let host = from_c_chars(&entry.ut_host);
+            let (terminal, host) = match host {
+                // Graphical sessions store their X display, like `:0`, as host.
+                Some(display) if display.starts_with(':') => (line.or(Some(display)), None),
+                host => (line, host),
+            };
+            sessions.push(Session {
+                user_name,
+                login_time: entry.ut_tv.tv_sec as _,
+                terminal,
+                // Local logins only have a display as host.
+                remote: host.is_some(),
+                host,
+            });
+        }
+        endutxent();
+    }
+}
+
+// There is no `utmpx` database on Android.
+#[cfg(target_os = "android")]
+pub(crate) fn get_sessions(_: &mut Vec<Session>) {}
+
+/// Returns `true` if the environment has been set by the SSH server.
+pub(crate) fn is_remote_environ(environ: &[String]) -> bool {
+    environ
+        .iter()
+        .any(|var| var.starts_with("SSH_CONNECTION=") || var.starts_with("SSH_CLIENT="))
+}
+
+/// Converts a fixed-size `utmpx` field, which isn't NUL-terminated if it is full.
+#[cfg(any(not(target_os = "android"), test))]
+fn from_c_chars(chars: &[libc::c_char]) -> Option<String> {
+    let bytes = chars
+        .iter()
+        .take_while(|c| **c != 0)
+        .map(|c| *c as u8)
+        .collect::<Vec<_>>();
+    if bytes.is_empty() {
+        None
+    } else {
+        Some(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{from_c_chars, is_remote_environ};
+
+    #[test]
+    fn check_from_c_chars() {
+        let field = |s: &[u8]| s.iter().map(|c| *c as libc::c_char).collect::<Vec<_>>();
+        assert_eq!(
+            from_c_chars(&field(b"pts/0\0\0\0")).as_deref(),
+            Some("pts/0")
+        );
+        // Full fields aren't NUL-terminated.
+        assert_eq!(from_c_chars(&field(b"root")).as_deref(), Some("root"));
+        assert_eq!(from_c_chars(&field(b"\0\0\0\0")), None);
+    }
+
+    #[test]
+    fn check_is_remote_environ() {
+        let environ = |vars: &[&str]| vars.iter().map(|var| var.to_string()).collect::<Vec<_>>();
+        assert!(is_remote_environ(&environ(&[
+            "HOME=/root",
+            "SSH_CONNECTION=10.0.0.1 51234 10.0.0.2 22",
+        ])));
+        assert!(!is_remote_environ(&environ(&["HOME=/root", "DISPLAY=:0"])));
+        assert!(!is_remote_environ(&environ(&["MY_SSH_CLIENT=1"])));
+    }
+}
@@ -2,30 +2,43 @@
 
 use crate::{
     common::{Gid, Uid},
-    Group,
+    Group, GroupRefreshKind,
 };
 
 #[cfg(not(any(target_os = "macos", target_os = "ios")))]
-use crate::User;
+use crate::{User, UserRefreshKind};
 
-use libc::{getgrgid_r, getgrouplist};
+use libc::{getgrgid_r, getgrouplist, getpwuid_r};
+use std::path::{Path, PathBuf};
 
 pub(crate) struct UserInner {
     pub(crate) uid: Uid,
     pub(crate) gid: Gid,
     pub(crate) name: String,
     c_user: Vec<u8>,
+    home_dir: Option<PathBuf>,
+    shell: Option<PathBuf>,
 }
 
 impl UserInner {
-    pub(crate) fn new(uid: Uid, gid: Gid, name: String) -> Self {
+    /// `home_dir` and `shell` are ignored if they are empty.
+    pub(crate) fn new(uid: Uid, gid: Gid, name: String, home_dir: &str, shell: &str) -> Self {
         let mut c_user = name.as_bytes().to_vec();
         c_user.push(0);
+        let path = |s: &str| {
+            if s.is_empty() {
+                None
+            } else {
+                Some(PathBuf::from(s))
+            }
+        };
         Self {
             uid,
             gid,
             name,
             c_user,
+            home_dir: path(home_dir),
+            shell: path(shell),
         }
     }
 
@@ -41,17 +54,76 @@ impl UserInner {
         &self.name
     }
 
-    pub(crate) fn groups(&self) -> Vec<Group> {
-        unsafe { get_user_groups(self.c_user.as_ptr() as *const _, self.gid.0 as _) }
+    pub(crate) fn groups(&self, refresh_kind: GroupRefreshKind) -> Vec<Group> {
+        unsafe {
+            if refresh_kind.supplementary_groups() {
+                get_user_groups(self.c_user.as_ptr() as *const _, self.gid.0 as _)
+            } else {
+                let mut buffer = Vec::with_capacity(2048);
+                get_group_name(self.gid.0 as _, &mut buffer)
+                    .map(|name| vec![Group { name, id: self.gid }])
+                    .unwrap_or_default()
+            }
+        }
+    }
+
+    pub(crate) fn home_dir(&self) -> Option<&Path> {
+        self.home_dir.as_deref()
+    }
+
+    pub(crate) fn shell(&self) -> Option<&Path> {
+        self.shell.as_deref()
+    }
+
+    pub(crate) fn domain(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn is_domain_account(&self) -> bool {
+        false
+    }
+}
+
+pub(crate) fn resolve_user_name(uid: &Uid) -> Option<String> {
+    let mut buffer: Vec<libc::c_char> = Vec::with_capacity(2048);
+    let mut passwd = std::mem::MaybeUninit::<libc::passwd>::uninit();
+    let mut tmp_ptr = std::ptr::null_mut();
+    unsafe {
+        loop {
+            // Unlike most functions, it returns the error code.
+            match getpwuid_r(
+                uid.0,
+                passwd.as_mut_ptr(),
+                buffer.as_mut_ptr(),
+                buffer.capacity() as _,
+                &mut tmp_ptr,
+            ) {
+                0 => break,
+                libc::EINTR => continue,
+                // If there was not enough memory, we give it more.
+                libc::ERANGE => buffer.reserve(buffer.capacity() * 2),
+                _ => return None,
+            }
+        }
+        // There is no user with this ID.
+        if tmp_ptr.is_null() {
+            return None;
+        }
+        super::utils::cstr_to_rust(passwd.assume_init().pw_name)
     }
 }
 
+pub(crate) fn resolve_group_name(gid: Gid) -> Option<String> {
+    let mut buffer = Vec::with_capacity(2048);
+    unsafe { get_group_name(gid.0, &mut buffer) }
+}
+
 pub(crate) unsafe fn get_group_name(
     id: libc::gid_t,
     buffer: &mut Vec<libc::c_char>,
 ) -> Option<String> {
     let mut g = std::mem::MaybeUninit::<libc::group>::uninit();
-    let mut tmp_ptr = std::ptr::null_mut();
+    let mut tmp_ptr: *mut libc::group = std::ptr::null_mut();
     let mut last_errno = 0;
     loop {
         if retry_eintr!(set_to_0 => last_errno => getgrgid_r(
@@ -63,7 +135,7 @@ pub(crate) unsafe fn get_group_name(
         )) != 0
         {
             // If there was not enough memory, we give it more.
-            if last_errno == libc::ERANGE as _ {
+            if last_errno == libc::ERANGE {
                 buffer.reserve(2048);
                 continue;
             }
@@ -71,6 +143,10 @@ pub(crate) unsafe fn get_group_name(
         }
         break;
     }
+    // There is no group with this ID.
+    if tmp_ptr.is_null() {
+        return None;
+    }
     let g = g.assume_init();
     super::utils::cstr_to_rust(g.gr_name)
 }
@@ -110,20 +186,26 @@ pub(crate) unsafe fn get_user_groups(
 
 // Not used by mac.
 #[cfg(not(any(target_os = "macos", target_os = "ios")))]
-pub(crate) fn get_users(users: &mut Vec<User>) {
+pub(crate) fn get_users(users: &mut Vec<User>, refresh_kind: UserRefreshKind) {
     use std::fs::File;
     use std::io::Read;
 
-    #[inline]
-    fn parse_id(id: &str) -> Option<u32> {
-        id.parse::<u32>().ok()
-    }
-
     users.clear();
 
     let mut s = String::new();
 
     let _ = File::open("/etc/passwd").and_then(|mut f| f.read_to_string(&mut s));
+    parse_passwd(&s, users, refresh_kind);
+}
+
+/// Parses the content of `/etc/passwd` and adds the users it contains to `users`.
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+fn parse_passwd(s: &str, users: &mut Vec<User>, refresh_kind: UserRefreshKind) {
+    #[inline]
+    fn parse_id(id: &str) -> Option<u32> {
+        id.parse::<u32>().ok()
+    }
+
     for line in s.lines() {
         let mut parts = line.split(':');
         if let Some(username) = parts.next() {
@@ -131,8 +213,22 @@ pub(crate) fn get_users(users: &mut Vec<User>) {
             // Skip the user if the uid cannot be parsed correctly
             if let Some(uid) = parts.next().and_then(parse_id) {
                 if let Some(group_id) = parts.next().and_then(parse_id) {
+                    // Then come the GECOS field, the home directory and the shell.
+                    let (home_dir, shell) = if refresh_kind.home_dir() {
+                        let mut parts = parts.skip(1);
+                        (parts.next().unwrap_or(""), parts.next().unwrap_or(""))
+                    } else {
+                        ("", "")
+                    };
                     users.push(User {
-                        inner: UserInner::new(Uid(uid), Gid(group_id), username.to_owned()),
+                        inner: UserInner::new(
+                            Uid(uid),
+                            Gid(group_id),
+                            username.to_owned(),
+                            home_dir,
+                            shell,
+                        ),
+                        groups: None,
                     });
                 }
             }
@@ -142,3 +238,74 @@ pub(crate) fn get_users(users: &mut Vec<User>) {
 
 #[cfg(any(target_os = "macos", target_os = "ios"))]
 pub(crate) use crate::unix::apple::users::get_users;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(not(any(target_os = "macos", target_os = "ios")))]
+    #[test]
+    fn check_parse_passwd() {
+        let passwd = "\
+root:x:0:0:root:/root:/bin/bash
+daemon:x:1:1:daemon:/usr/sbin:/usr/sbin/nologin
+alice:x:1000:1000:Alice,,,:/home/alice:/bin/zsh
+nohome:x:1001:1001:::
+broken:x:abc:1002::/home/broken:/bin/sh
+";
+        let mut users = Vec::new();
+        parse_passwd(passwd, &mut users, UserRefreshKind::new().with_home_dir());
+        // The user with an invalid uid is skipped.
+        assert_eq!(users.len(), 4);
+
+        let alice = &users[2];
+        assert_eq!(alice.name(), "alice");
+        assert_eq!(alice.id(), &Uid(1000));
+        assert_eq!(alice.group_id(), Gid(1000));
+        assert_eq!(alice.home_dir(), Some(Path::new("/home/alice")));
+        assert_eq!(alice.shell(), Some(Path::new("/bin/zsh")));
+
+        // Empty fields are returned as `None`.
+        let nohome = &users[3];
+        assert_eq!(nohome.home_dir(), None);
+        assert_eq!(nohome.shell(), None);
+
+        // They aren't read if they weren't asked for.
+        users.clear();
+        parse_passwd(passwd, &mut users, UserRefreshKind::new());
+        assert_eq!(users.len(), 4);
+        assert_eq!(users[2].name(), "alice");
+        assert_eq!(users[2].home_dir(), None);
+        assert_eq!(users[2].shell(), None);
+    }
+
+    #[test]
+    fn check_groups_specifics() {
+        let root = UserInner::new(Uid(0), Gid(0), "root".to_owned(), "", "");
+
+        // Only the primary group is returned without the supplementary groups.
+        let groups = root.groups(GroupRefreshKind::new());
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].id(), &Gid(0));
+
+        let groups = root.groups(GroupRefreshKind::new().with_supplementary_groups());
+        assert!(groups.iter().any(|group| group.id() == &Gid(0)));
+
+        // Groups can't be nested, so it changes nothing.
+        assert_eq!(
+            root.groups(GroupRefreshKind::new().with_nested_groups()),
+            root.groups(GroupRefreshKind::new()),
+        );
+    }
+
+    #[test]
+    fn check_resolve_name() {
+        assert_eq!(resolve_user_name(&Uid(0)).as_deref(), Some("root"));
+        // The name of the group `0` depends on the system (`root` or `wheel`).
+        assert!(resolve_group_name(Gid(0)).is_some());
+
+        // There is no user or group with these IDs.
+        assert_eq!(resolve_user_name(&Uid(3_999_999_999)), None);
+        assert_eq!(resolve_group_name(Gid(3_999_999_999)), None);
+    }
+}
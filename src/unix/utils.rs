@@ -30,3 +30,107 @@ pub(crate) fn cstr_to_rust_with_size(c: *const c_char, size: Option<usize>) -> O
         String::from_utf8(s).ok()
     }
 }
+
+/// Returns the hardware name reported by `uname` (like `x86_64` or `arm64`).
+pub(crate) fn get_uname_machine() -> Option<String> {
+    let mut raw = std::mem::MaybeUninit::<libc::utsname>::zeroed();
+
+    unsafe {
+        if libc::uname(raw.as_mut_ptr()) != 0 {
+            sysinfo_debug!("uname failed");
+            return None;
+        }
+        let info = raw.assume_init();
+        cstr_to_rust_with_size(info.machine.as_ptr(), Some(info.machine.len()))
+            .filter(|machine| !machine.is_empty())
+    }
+}
+
+/// Returns the canonical name of `host_name`, which is its fully qualified domain name.
+pub(crate) fn get_fqdn(host_name: &str) -> Option<String> {
+    let c_host_name = std::ffi::CString::new(host_name).ok()?;
+
+    unsafe {
+        let mut hints: libc::addrinfo = std::mem::zeroed();
+        hints.ai_family = libc::AF_UNSPEC;
+        hints.ai_flags = libc::AI_CANONNAME;
+        let mut info = std::ptr::null_mut();
+        if libc::getaddrinfo(c_host_name.as_ptr(), std::ptr::null(), &hints, &mut info) != 0 {
+            sysinfo_debug!("getaddrinfo failed for `{}`", host_name);
+            return None;
+        }
+        // Only the first entry has the canonical name.
+        let fqdn = cstr_to_rust((*info).ai_canonname).filter(|fqdn| !fqdn.is_empty());
+        libc::freeaddrinfo(info);
+        fqdn
+    }
+}
+
+/// Returns the architecture of the system userland, which is the one of `/bin/sh`.
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+pub(crate) fn get_userland_arch() -> Option<String> {
+    use std::io::Read;
+
+    ["/bin/sh", "/system/bin/sh"].iter().find_map(|path| {
+        let mut header = [0u8; 20];
+        std::fs::File::open(path)
+            .and_then(|mut file| file.read_exact(&mut header))
+            .ok()?;
+        elf_arch(&header).map(str::to_owned)
+    })
+}
+
+/// Returns the architecture of an ELF file from its header.
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+fn elf_arch(header: &[u8]) -> Option<&'static str> {
+    if header.len() < 20 || !header.starts_with(b"\x7fELF") {
+        return None;
+    }
+    let is_64 = header[4] == 2;
+    let machine = match header[5] {
+        1 => u16::from_le_bytes([header[18], header[19]]),
+        2 => u16::from_be_bytes([header[18], header[19]]),
+        _ => return None,
+    };
+    Some(match (machine, is_64) {
+        (2, _) => "sparc",
+        (3, _) => "x86",
+        (8, false) => "mips",
+        (8, true) => "mips64",
+        (20, _) => "powerpc",
+        (21, _) => "powerpc64",
+        (22, _) => "s390x",
+        (40, _) => "arm",
+        (43, _) => "sparc64",
+        (62, _) => "x86_64",
+        (183, _) => "aarch64",
+        (243, false) => "riscv32",
+        (243, true) => "riscv64",
+        (258, _) => "loongarch64",
+        _ => return None,
+    })
+}
+
+#[cfg(all(test, any(target_os = "linux", target_os = "android")))]
+mod test {
+    use super::elf_arch;
+
+    #[test]
+    fn check_elf_arch() {
+        let header = |class: u8, data: u8, machine: [u8; 2]| {
+            let mut header = [0u8; 20];
+            header[..4].copy_from_slice(b"\x7fELF");
+            header[4] = class;
+            header[5] = data;
+            header[18..].copy_from_slice(&machine);
+            header
+        };
+        assert_eq!(elf_arch(&header(2, 1, [62, 0])), Some("x86_64"));
+        assert_eq!(elf_arch(&header(1, 1, [3, 0])), Some("x86"));
+        assert_eq!(elf_arch(&header(1, 1, [40, 0])), Some("arm"));
+        assert_eq!(elf_arch(&header(2, 2, [0, 22])), Some("s390x"));
+        assert_eq!(elf_arch(&header(2, 1, [243, 0])), Some("riscv64"));
+        assert_eq!(elf_arch(&header(2, 1, [0xff, 0xff])), None);
+        assert_eq!(elf_arch(b"#!/bin/sh\nexec foo\n\n\n"), None);
+    }
+}
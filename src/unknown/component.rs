@@ -1,6 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::Component;
+use crate::{Component, ComponentKind};
 
 pub(crate) struct ComponentInner;
 
@@ -17,10 +17,30 @@ impl ComponentInner {
         None
     }
 
+    pub(crate) fn lower_limit(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn upper_limit(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn raw_value(&self) -> Option<i64> {
+        None
+    }
+
     pub(crate) fn label(&self) -> &str {
         ""
     }
 
+    pub(crate) fn id(&self) -> &str {
+        ""
+    }
+
+    pub(crate) fn kind(&self) -> ComponentKind {
+        ComponentKind::Other
+    }
+
     pub(crate) fn refresh(&mut self) {}
 }
 
@@ -0,0 +1,57 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Container, ContainerRuntime, Pid};
+
+pub(crate) enum ContainerInner {}
+
+impl ContainerInner {
+    pub(crate) fn id(&self) -> &str {
+        match *self {}
+    }
+
+    pub(crate) fn runtime(&self) -> &ContainerRuntime {
+        match *self {}
+    }
+
+    pub(crate) fn cpu_usage(&self) -> f32 {
+        match *self {}
+    }
+
+    pub(crate) fn memory(&self) -> u64 {
+        match *self {}
+    }
+
+    pub(crate) fn memory_limit(&self) -> Option<u64> {
+        match *self {}
+    }
+
+    pub(crate) fn pids(&self) -> &[Pid] {
+        match *self {}
+    }
+}
+
+pub(crate) struct ContainersInner {
+    containers: Vec<Container>,
+}
+
+impl ContainersInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            containers: Vec::new(),
+        }
+    }
+
+    pub(crate) fn list(&self) -> &[Container] {
+        &self.containers
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Container] {
+        &mut self.containers
+    }
+
+    pub(crate) fn refresh(&mut self) {}
+
+    pub(crate) fn refresh_list(&mut self) {
+        // Doesn't do anything.
+    }
+}
@@ -1,5 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::CpuTimes;
+
 pub(crate) struct CpuInner;
 
 impl CpuInner {
@@ -11,6 +13,12 @@ impl CpuInner {
         0.0
     }
 
+    pub(crate) fn set_cpu_usage(&mut self, _cpu_usage: f32) {}
+
+    pub(crate) fn steal_percent(&self) -> f32 {
+        0.0
+    }
+
     pub(crate) fn name(&self) -> &str {
         ""
     }
@@ -19,6 +27,39 @@ impl CpuInner {
         0
     }
 
+    pub(crate) fn temperature(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn times(&self) -> CpuTimes {
+        CpuTimes::default()
+    }
+
+    pub(crate) fn idle_states(&self) -> &[crate::CpuIdleState] {
+        &[]
+    }
+
+    pub(crate) fn governor(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn available_governors(&self) -> &[String] {
+        &[]
+    }
+
+    pub(crate) fn scaling_min_frequency(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn scaling_max_frequency(&self) -> Option<u64> {
+        None
+    }
+
+    #[cfg(feature = "cpufreq-write")]
+    pub(crate) fn set_governor(&self, _governor: &str) -> bool {
+        false
+    }
+
     pub(crate) fn vendor_id(&self) -> &str {
         ""
     }
@@ -26,4 +67,20 @@ impl CpuInner {
     pub(crate) fn brand(&self) -> &str {
         ""
     }
+
+    pub(crate) fn family(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn model(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn stepping(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn microcode_revision(&self) -> Option<u64> {
+        None
+    }
 }
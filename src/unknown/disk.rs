@@ -1,6 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Disk, DiskKind};
+use crate::{BlockDevice, Disk, DiskKind, DiskQuota, Gid, Uid};
 
 use std::{ffi::OsStr, path::Path};
 
@@ -35,6 +35,70 @@ impl DiskInner {
         false
     }
 
+    pub(crate) fn uuid(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn vendor(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn model(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn serial_number(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn physical_disk(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn storage_pool(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn block_device(&self) -> Option<&BlockDevice> {
+        None
+    }
+
+    pub(crate) fn is_rotational(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn logical_sector_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn physical_sector_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn supports_trim(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn user_quota(&self, _user_id: &Uid) -> Option<DiskQuota> {
+        None
+    }
+
+    pub(crate) fn group_quota(&self, _group_id: Gid) -> Option<DiskQuota> {
+        None
+    }
+
+    pub(crate) fn is_network(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn remote_host(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn remote_path(&self) -> Option<&str> {
+        None
+    }
+
     pub(crate) fn refresh(&mut self) -> bool {
         true
     }
@@ -53,11 +117,19 @@ impl DisksInner {
         Self { disks }
     }
 
+    pub(crate) fn physical_disks(&self) -> &[crate::PhysicalDisk] {
+        &[]
+    }
+
+    pub(crate) fn storage_pools(&self) -> &[crate::StoragePool] {
+        &[]
+    }
+
     pub(crate) fn into_vec(self) -> Vec<Disk> {
         self.disks
     }
 
-    pub(crate) fn refresh_list(&mut self) {
+    pub(crate) fn refresh_list(&mut self, _refresh_kind: crate::DiskRefreshKind) {
         // Does nothing.
     }
 
@@ -69,3 +141,15 @@ impl DisksInner {
         &mut self.disks
     }
 }
+
+pub(crate) struct DiskEventsInner;
+
+impl DiskEventsInner {
+    pub(crate) fn new() -> Option<Self> {
+        None
+    }
+
+    pub(crate) fn wait(&mut self, _timeout: Option<std::time::Duration>) -> bool {
+        false
+    }
+}
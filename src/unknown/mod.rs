@@ -1,20 +1,43 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+pub(crate) mod battery;
 pub mod component;
+pub(crate) mod container;
 pub mod cpu;
 pub mod disk;
+pub(crate) mod gpu;
 pub mod network;
+pub(crate) mod pci;
+pub(crate) mod performance_counters;
+#[cfg(feature = "energy")]
+pub(crate) mod power;
 pub mod process;
+pub(crate) mod services;
 pub mod system;
+pub(crate) mod thermal;
 pub mod users;
 
+pub(crate) use self::battery::get_batteries;
 pub(crate) use self::component::{ComponentInner, ComponentsInner};
+pub(crate) use self::container::{ContainerInner, ContainersInner};
 pub(crate) use self::cpu::CpuInner;
-pub(crate) use self::disk::{DiskInner, DisksInner};
-pub(crate) use self::network::{NetworkDataInner, NetworksInner};
+pub(crate) use self::disk::{DiskEventsInner, DiskInner, DisksInner};
+pub(crate) use self::gpu::{GpuInner, GpusInner};
+pub(crate) use self::network::{
+    get_connection_summary, get_connections, get_neighbors, NetworkDataInner, NetworkEventsInner,
+    NetworksInner,
+};
+pub(crate) use self::pci::get_pci_devices;
+pub(crate) use self::performance_counters::PerformanceCountersInner;
+#[cfg(feature = "energy")]
+pub(crate) use self::power::PowerInner;
 pub(crate) use self::process::ProcessInner;
-pub(crate) use self::system::SystemInner;
-pub(crate) use self::users::{get_users, UserInner};
+pub(crate) use self::services::get_services;
+pub(crate) use self::system::{get_locale, get_time_zone, SystemInner};
+pub(crate) use self::thermal::get_thermal_zones;
+pub(crate) use self::users::{
+    get_sessions, get_users, resolve_group_name, resolve_user_name, UserInner,
+};
 
 use std::time::Duration;
 
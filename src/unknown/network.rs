@@ -1,7 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::common::MacAddr;
-use crate::NetworkData;
+use crate::common::{
+    DuplexMode, InterfaceKind, IpNetwork, MacAddr, NetworkCounters, OperationalState,
+};
+use crate::{Connection, ConnectionSummary, Neighbor, NetworkData};
 
 use std::collections::HashMap;
 
@@ -23,6 +25,10 @@ impl NetworksInner {
     pub(crate) fn refresh_list(&mut self) {}
 
     pub(crate) fn refresh(&mut self) {}
+
+    pub(crate) fn refresh_interfaces(&mut self, _interface_names: &[&str]) {}
+
+    pub(crate) fn set_counter_wrap_handling(&mut self, _enabled: bool) {}
 }
 
 pub(crate) struct NetworkDataInner;
@@ -36,6 +42,10 @@ impl NetworkDataInner {
         0
     }
 
+    pub(crate) fn received_rate(&self) -> f64 {
+        0.
+    }
+
     pub(crate) fn transmitted(&self) -> u64 {
         0
     }
@@ -44,6 +54,10 @@ impl NetworkDataInner {
         0
     }
 
+    pub(crate) fn transmitted_rate(&self) -> f64 {
+        0.
+    }
+
     pub(crate) fn packets_received(&self) -> u64 {
         0
     }
@@ -76,7 +90,88 @@ impl NetworkDataInner {
         0
     }
 
+    pub(crate) fn detailed_counters(&self) -> NetworkCounters {
+        NetworkCounters::default()
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         MacAddr::UNSPECIFIED
     }
+
+    pub(crate) fn ip_networks(&self) -> &[IpNetwork] {
+        &[]
+    }
+
+    pub(crate) fn mtu(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn link_speed(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn duplex(&self) -> DuplexMode {
+        DuplexMode::Unknown
+    }
+
+    pub(crate) fn operational_state(&self) -> OperationalState {
+        OperationalState::Unknown
+    }
+
+    pub(crate) fn is_admin_up(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn has_carrier(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn carrier_changes(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn last_state_change(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn kind(&self) -> InterfaceKind {
+        InterfaceKind::Other
+    }
+
+    pub(crate) fn description(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn master(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn lower_interfaces(&self) -> &[String] {
+        &[]
+    }
+
+    #[cfg(feature = "wireless")]
+    pub(crate) fn wireless(&self) -> Option<&crate::WirelessInfo> {
+        None
+    }
+}
+
+pub(crate) fn get_connections(_: &mut Vec<Connection>) {}
+
+pub(crate) fn get_connection_summary() -> ConnectionSummary {
+    ConnectionSummary::default()
+}
+
+pub(crate) fn get_neighbors(_: &mut Vec<Neighbor>) {}
+
+pub(crate) struct NetworkEventsInner;
+
+impl NetworkEventsInner {
+    pub(crate) fn new() -> Option<Self> {
+        None
+    }
+
+    pub(crate) fn wait(&mut self, _timeout: Option<std::time::Duration>) -> bool {
+        false
+    }
 }
@@ -0,0 +1,27 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+pub(crate) struct PerformanceCountersInner;
+
+impl PerformanceCountersInner {
+    pub(crate) fn new() -> Self {
+        Self
+    }
+
+    pub(crate) fn add(&mut self, _path: &str) -> bool {
+        false
+    }
+
+    pub(crate) fn remove(&mut self, _path: &str) -> bool {
+        false
+    }
+
+    pub(crate) fn refresh(&mut self) {}
+
+    pub(crate) fn get(&self, _path: &str) -> Option<f64> {
+        None
+    }
+
+    pub(crate) fn instances(&self, _path: &str) -> Vec<(String, f64)> {
+        Vec::new()
+    }
+}
@@ -4,6 +4,7 @@ use crate::{DiskUsage, Gid, Pid, ProcessStatus, Signal, Uid};
 
 use std::fmt;
 use std::path::Path;
+use std::time::Duration;
 
 impl fmt::Display for ProcessStatus {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -77,6 +78,20 @@ impl ProcessInner {
         0.0
     }
 
+    pub(crate) fn normalized_cpu_usage(&self) -> f32 {
+        0.0
+    }
+
+    pub(crate) fn set_cpu_usage(&mut self, _cpu_usage: f32, _normalized_cpu_usage: f32) {}
+
+    pub(crate) fn user_time(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    pub(crate) fn kernel_time(&self) -> Duration {
+        Duration::ZERO
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage::default()
     }
@@ -102,4 +117,12 @@ impl ProcessInner {
     pub(crate) fn session_id(&self) -> Option<Pid> {
         None
     }
+
+    pub(crate) fn is_translated(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_remote_session(&self) -> bool {
+        false
+    }
 }
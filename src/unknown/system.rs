@@ -1,8 +1,21 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Cpu, CpuInner, CpuRefreshKind, LoadAvg, Pid, Process, ProcessRefreshKind};
+use crate::{
+    Cpu, CpuActivity, CpuInner, CpuRefreshKind, Entropy, FileHandles, HardwareInfo,
+    KernelParameter, LoadAvg, MemoryBreakdown, OsRelease, Pid, Process, ProcessRefreshKind,
+    SystemCounts, TimeZone, Virtualization, WindowsVersion,
+};
 
 use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "parallel")]
+pub(crate) struct MemoryReader;
+
+#[cfg(feature = "parallel")]
+impl MemoryReader {
+    pub(crate) fn read(&mut self) {}
+}
 
 pub(crate) struct SystemInner {
     processes_list: HashMap<Pid, Process>,
@@ -19,14 +32,28 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn set_string_encoding_policy(&mut self, _policy: crate::StringEncodingPolicy) {}
+
+    pub(crate) fn set_keep_open_files(&mut self, _enabled: bool) {}
+
     pub(crate) fn refresh_memory(&mut self) {}
 
+    #[cfg(feature = "parallel")]
+    pub(crate) fn memory_reader(&mut self) -> MemoryReader {
+        MemoryReader
+    }
+
+    #[cfg(feature = "parallel")]
+    pub(crate) fn apply_memory(&mut self, _reader: MemoryReader) {}
+
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
         None
     }
 
     pub(crate) fn refresh_cpu_specifics(&mut self, _refresh_kind: CpuRefreshKind) {}
 
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {}
+
     pub(crate) fn refresh_processes_specifics(&mut self, _refresh_kind: ProcessRefreshKind) {}
 
     pub(crate) fn refresh_process_specifics(
@@ -53,14 +80,46 @@ impl SystemInner {
         &self.global_cpu
     }
 
+    #[cfg(feature = "energy")]
+    pub(crate) fn refresh_energy(&mut self) {}
+
+    #[cfg(feature = "energy")]
+    pub(crate) fn energy_domains(&self) -> &[crate::EnergyDomain] {
+        &[]
+    }
+
+    pub(crate) fn cpu_activity(&self) -> CpuActivity {
+        CpuActivity::default()
+    }
+
     pub(crate) fn cpus(&self) -> &[Cpu] {
         &[]
     }
 
+    pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
+        &mut self.processes_list
+    }
+
+    pub(crate) fn global_cpu_info_mut(&mut self) -> &mut Cpu {
+        &mut self.global_cpu
+    }
+
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut []
+    }
+
     pub(crate) fn physical_core_count(&self) -> Option<usize> {
         None
     }
 
+    pub(crate) fn socket_count(&self) -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn cpu_features(&self) -> Vec<String> {
+        Vec::new()
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         0
     }
@@ -73,10 +132,50 @@ impl SystemInner {
         0
     }
 
+    pub(crate) fn available_memory_breakdown(&self) -> Option<crate::AvailableMemory> {
+        None
+    }
+
     pub(crate) fn used_memory(&self) -> u64 {
         0
     }
 
+    pub(crate) fn memory_breakdown(&self) -> MemoryBreakdown {
+        MemoryBreakdown::default()
+    }
+
+    pub(crate) fn commit_charge(&self) -> crate::CommitCharge {
+        crate::CommitCharge::default()
+    }
+
+    pub(crate) fn compressed_memory(&self) -> crate::CompressedMemory {
+        crate::CompressedMemory::default()
+    }
+
+    pub(crate) fn memory_pressure_level(&self) -> Option<crate::MemoryPressureLevel> {
+        None
+    }
+
+    pub(crate) fn zram_devices(&self) -> &[crate::ZramDevice] {
+        &[]
+    }
+
+    pub(crate) fn ksm(&self) -> Option<crate::Ksm> {
+        None
+    }
+
+    pub(crate) fn balloon_memory(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn oom_kills(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn memory_modules(&self) -> Vec<crate::MemoryModule> {
+        Vec::new()
+    }
+
     pub(crate) fn total_swap(&self) -> u64 {
         0
     }
@@ -93,10 +192,22 @@ impl SystemInner {
         0
     }
 
+    pub(crate) fn uptime_duration(&self) -> Duration {
+        Duration::default()
+    }
+
+    pub(crate) fn boot_time_sys(&self) -> SystemTime {
+        UNIX_EPOCH
+    }
+
     pub(crate) fn boot_time(&self) -> u64 {
         0
     }
 
+    pub(crate) fn pressure(&self) -> Option<crate::Pressure> {
+        None
+    }
+
     pub(crate) fn load_average(&self) -> LoadAvg {
         LoadAvg {
             one: 0.,
@@ -128,4 +239,68 @@ impl SystemInner {
     pub(crate) fn host_name(&self) -> Option<String> {
         None
     }
+
+    pub(crate) fn hardware_info(&self) -> HardwareInfo {
+        HardwareInfo::default()
+    }
+
+    pub(crate) fn machine_id(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn virtualization(&self) -> Virtualization {
+        Virtualization::default()
+    }
+
+    pub(crate) fn counts(&self) -> SystemCounts {
+        SystemCounts::default()
+    }
+
+    pub(crate) fn file_handles(&self) -> Option<FileHandles> {
+        None
+    }
+
+    pub(crate) fn entropy(&self) -> Option<Entropy> {
+        None
+    }
+
+    pub(crate) fn fqdn(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn domain_name(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn workgroup(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn kernel_arch(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn userland_arch(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn os_release(&self) -> Option<OsRelease> {
+        None
+    }
+
+    pub(crate) fn windows_version(&self) -> Option<WindowsVersion> {
+        None
+    }
+
+    pub(crate) fn kernel_parameter(&self, _name: &str) -> Option<KernelParameter> {
+        None
+    }
+}
+
+pub(crate) fn get_time_zone() -> TimeZone {
+    TimeZone::default()
+}
+
+pub(crate) fn get_locale() -> Option<String> {
+    None
 }
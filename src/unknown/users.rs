@@ -1,6 +1,8 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Gid, Group, Uid, User};
+use crate::{Gid, Group, GroupRefreshKind, Session, Uid, User, UserRefreshKind};
+
+use std::path::Path;
 
 pub(crate) struct UserInner;
 
@@ -17,9 +19,35 @@ impl UserInner {
         ""
     }
 
-    pub(crate) fn groups(&self) -> Vec<Group> {
+    pub(crate) fn groups(&self, _refresh_kind: GroupRefreshKind) -> Vec<Group> {
         Vec::new()
     }
+
+    pub(crate) fn home_dir(&self) -> Option<&Path> {
+        None
+    }
+
+    pub(crate) fn shell(&self) -> Option<&Path> {
+        None
+    }
+
+    pub(crate) fn domain(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn is_domain_account(&self) -> bool {
+        false
+    }
 }
 
-pub(crate) fn get_users(_: &mut Vec<User>) {}
+pub(crate) fn get_users(_: &mut Vec<User>, _: UserRefreshKind) {}
+
+pub(crate) fn get_sessions(_: &mut Vec<Session>) {}
+
+pub(crate) fn resolve_user_name(_: &Uid) -> Option<String> {
+    None
+}
+
+pub(crate) fn resolve_group_name(_: Gid) -> Option<String> {
+    None
+}
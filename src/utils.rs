@@ -45,3 +45,681 @@ where
 {
     val.into_iter()
 }
+
+/// Returns the instruction set extensions supported by the CPU (and enabled by the OS).
+///
+/// It uses `cpuid` on x86 and the OS-provided information on ARM (like `hwcap` on Linux).
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "windows",
+        target_os = "freebsd",
+    ),
+    not(feature = "unknown-ci"),
+))]
+pub(crate) fn get_cpu_features() -> Vec<String> {
+    #[allow(unused_macros)]
+    macro_rules! detect_features {
+        ($detect:ident, $($feature:tt),+ $(,)?) => {{
+            let mut features = Vec::new();
+            $(
+                if $detect!($feature) {
+                    features.push($feature.to_owned());
+                }
+            )+
+            features
+        }};
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        detect_features!(
+            is_x86_feature_detected,
+            "aes",
+            "pclmulqdq",
+            "rdrand",
+            "rdseed",
+            "tsc",
+            "mmx",
+            "sse",
+            "sse2",
+            "sse3",
+            "ssse3",
+            "sse4.1",
+            "sse4.2",
+            "sse4a",
+            "sha",
+            "avx",
+            "avx2",
+            "avx512f",
+            "avx512cd",
+            "avx512bw",
+            "avx512dq",
+            "avx512vl",
+            "avx512ifma",
+            "avx512vbmi",
+            "avx512vpopcntdq",
+            "f16c",
+            "fma",
+            "bmi1",
+            "bmi2",
+            "lzcnt",
+            "tbm",
+            "popcnt",
+            "fxsr",
+            "xsave",
+            "xsaveopt",
+            "xsaves",
+            "xsavec",
+            "cmpxchg16b",
+            "adx",
+            "rtm",
+        )
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        detect_features!(
+            is_aarch64_feature_detected,
+            "neon",
+            "pmull",
+            "fp",
+            "fp16",
+            "sve",
+            "sve2",
+            "crc",
+            "lse",
+            "lse2",
+            "rdm",
+            "rcpc",
+            "rcpc2",
+            "dotprod",
+            "tme",
+            "fhm",
+            "dit",
+            "flagm",
+            "ssbs",
+            "sb",
+            "paca",
+            "pacg",
+            "dpb",
+            "dpb2",
+            "frintts",
+            "i8mm",
+            "f32mm",
+            "f64mm",
+            "bf16",
+            "rand",
+            "bti",
+            "mte",
+            "jsconv",
+            "fcma",
+            "aes",
+            "sha2",
+            "sha3",
+            "sm4",
+        )
+    }
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+    {
+        Vec::new()
+    }
+}
+
+/// Returns the family, model and stepping of the CPU, read from the `cpuid` leaf 1.
+///
+/// The extended family and model are taken into account, so the returned values match what
+/// the OS tools display.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    any(target_os = "windows", target_os = "freebsd"),
+    not(feature = "unknown-ci"),
+))]
+pub(crate) fn get_cpu_signature() -> (u32, u32, u32) {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::__cpuid;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::__cpuid;
+
+    #[allow(unused_unsafe)]
+    let eax = unsafe { __cpuid(1).eax };
+    let stepping = eax & 0xF;
+    let base_model = (eax >> 4) & 0xF;
+    let base_family = (eax >> 8) & 0xF;
+    let extended_model = (eax >> 16) & 0xF;
+    let extended_family = (eax >> 20) & 0xFF;
+
+    let family = if base_family == 0xF {
+        base_family + extended_family
+    } else {
+        base_family
+    };
+    let model = if base_family == 0x6 || base_family == 0xF {
+        (extended_model << 4) + base_model
+    } else {
+        base_model
+    };
+    (family, model, stepping)
+}
+
+/// Returns the hypervisor the system is running on.
+///
+/// It uses the `cpuid` hypervisor leaves on x86, and the given SMBIOS information otherwise (or
+/// if the hypervisor hides itself).
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "windows",
+        target_os = "freebsd",
+    ),
+    not(feature = "unknown-ci"),
+))]
+pub(crate) fn get_hypervisor(info: &crate::HardwareInfo) -> Option<crate::Hypervisor> {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if let Some(hypervisor) = get_cpuid_hypervisor() {
+            return Some(hypervisor);
+        }
+    }
+    hypervisor_from_hardware_info(info)
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "windows",
+        target_os = "freebsd",
+    ),
+    not(feature = "unknown-ci"),
+))]
+fn get_cpuid_hypervisor() -> Option<crate::Hypervisor> {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::__cpuid;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::__cpuid;
+
+    #[allow(unused_unsafe)]
+    unsafe {
+        // Bit 31 of ECX is reserved for the hypervisors to signal their presence.
+        if __cpuid(1).ecx & (1 << 31) == 0 {
+            return None;
+        }
+        let leaf = __cpuid(0x4000_0000);
+        let mut signature = [0u8; 12];
+        signature[..4].copy_from_slice(&leaf.ebx.to_le_bytes());
+        signature[4..8].copy_from_slice(&leaf.ecx.to_le_bytes());
+        signature[8..].copy_from_slice(&leaf.edx.to_le_bytes());
+        let hypervisor = hypervisor_from_cpuid_signature(&signature)?;
+        // When Hyper-V is enabled, Windows itself runs in the root partition, which is the only
+        // one allowed to create partitions.
+        if hypervisor == crate::Hypervisor::HyperV
+            && leaf.eax >= 0x4000_0003
+            && __cpuid(0x4000_0003).ebx & 1 != 0
+        {
+            return None;
+        }
+        Some(hypervisor)
+    }
+}
+
+/// Converts the vendor signature returned by the `cpuid` leaf `0x40000000`.
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "windows",
+        target_os = "freebsd",
+    ),
+    not(feature = "unknown-ci"),
+))]
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", test)),
+    allow(dead_code)
+)]
+fn hypervisor_from_cpuid_signature(signature: &[u8]) -> Option<crate::Hypervisor> {
+    use crate::Hypervisor;
+
+    Some(match signature {
+        b"KVMKVMKVM\0\0\0" | b"Linux KVM Hv" => Hypervisor::Kvm,
+        b"TCGTCGTCGTCG" => Hypervisor::Qemu,
+        b"Microsoft Hv" => Hypervisor::HyperV,
+        b"VMwareVMware" => Hypervisor::VMware,
+        b"VBoxVBoxVBox" => Hypervisor::VirtualBox,
+        b"XenVMMXenVMM" => Hypervisor::Xen,
+        b"prl hyperv  " | b" lrpepyh  vr" => Hypervisor::Parallels,
+        b"bhyve bhyve " => Hypervisor::Bhyve,
+        signature => {
+            let vendor = String::from_utf8_lossy(signature);
+            let vendor = vendor.trim_matches(|c: char| c == '\0' || c.is_whitespace());
+            if vendor.is_empty() {
+                return None;
+            }
+            Hypervisor::Other(vendor.to_owned())
+        }
+    })
+}
+
+/// Guesses the hypervisor from the manufacturer and product strings set by the virtual firmware.
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "windows",
+        target_os = "freebsd",
+    ),
+    not(feature = "unknown-ci"),
+))]
+fn hypervisor_from_hardware_info(info: &crate::HardwareInfo) -> Option<crate::Hypervisor> {
+    use crate::Hypervisor;
+
+    let vendors = [&info.manufacturer, &info.board_vendor, &info.bios_vendor]
+        .iter()
+        .map(|s| s.to_lowercase())
+        .collect::<Vec<_>>();
+    let product = info.product_name.to_lowercase();
+    let is_vendor = |name: &str| vendors.iter().any(|vendor| vendor.contains(name));
+
+    Some(if is_vendor("vmware") || product.starts_with("vmware") {
+        Hypervisor::VMware
+    } else if is_vendor("innotek") || is_vendor("virtualbox") || product == "virtualbox" {
+        Hypervisor::VirtualBox
+    } else if is_vendor("parallels") || product.starts_with("parallels") {
+        Hypervisor::Parallels
+    } else if is_vendor("xen") || product.contains("hvm domu") {
+        Hypervisor::Xen
+    } else if is_vendor("bhyve") || product == "bhyve" {
+        Hypervisor::Bhyve
+    } else if product == "kvm" || is_vendor("amazon ec2") || product == "google compute engine" {
+        Hypervisor::Kvm
+    } else if is_vendor("qemu") {
+        Hypervisor::Qemu
+    } else if is_vendor("microsoft corporation") && product == "virtual machine" {
+        Hypervisor::HyperV
+    } else if is_vendor("apple") && product.starts_with("virtualmac") {
+        Hypervisor::Apple
+    } else {
+        return None;
+    })
+}
+
+/// Splits the source of a network disk into its remote host and its remote path.
+///
+/// It handles the `[user@]host:/path` (NFS, SSHFS), `//[user@]host/share` (SMB) and
+/// `\\host\share` (Windows) formats. IPv6 hosts are between brackets in the first format.
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "windows",
+        target_os = "freebsd",
+    ),
+    not(feature = "unknown-ci"),
+))]
+pub(crate) fn parse_remote_location(source: &str) -> Option<(String, String)> {
+    let (host, path) = if let Some(rest) = source
+        .strip_prefix("//")
+        .or_else(|| source.strip_prefix("\\\\"))
+    {
+        rest.split_at(rest.find(['/', '\\']).unwrap_or(rest.len()))
+    } else {
+        let end = if source.contains('[') {
+            source.find("]:")? + 1
+        } else {
+            source.find(':')?
+        };
+        (&source[..end], &source[end + 1..])
+    };
+    // The user name (if any) isn't part of the host.
+    let host = host.rsplit_once('@').map_or(host, |(_, host)| host);
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    if host.is_empty() {
+        None
+    } else {
+        Some((host.to_owned(), path.to_owned()))
+    }
+}
+
+/// Converts the architecture names used by the systems (like `amd64`, `i686` or `ARM64`) into
+/// the ones used by Rust (see [`std::env::consts::ARCH`]). Unknown names are kept as is.
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "windows",
+        target_os = "freebsd",
+    ),
+    not(feature = "unknown-ci"),
+))]
+pub(crate) fn normalize_arch(name: &str) -> String {
+    let arch = match name.to_ascii_lowercase().as_str() {
+        "x86_64" | "amd64" | "x64" => "x86_64",
+        "x86" | "i386" | "i486" | "i586" | "i686" | "i86pc" => "x86",
+        "aarch64" | "arm64" | "aarch64_be" => "aarch64",
+        "ppc64" | "ppc64le" | "powerpc64" | "powerpc64le" => "powerpc64",
+        "ppc" | "powerpc" => "powerpc",
+        "mips64" | "mips64el" => "mips64",
+        "mips" | "mipsel" => "mips",
+        "riscv64" | "riscv" => "riscv64",
+        "s390x" => "s390x",
+        "loongarch64" => "loongarch64",
+        "sparc64" => "sparc64",
+        lower if lower == "arm" || lower.starts_with("armv") => "arm",
+        _ => return name.to_owned(),
+    };
+    arch.to_owned()
+}
+
+/// Converts the chassis type of the SMBIOS "System Enclosure" table.
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "windows",
+        target_os = "freebsd",
+    ),
+    not(feature = "unknown-ci"),
+))]
+pub(crate) fn chassis_from_smbios(kind: u8) -> crate::ChassisKind {
+    use crate::ChassisKind;
+
+    // The highest bit tells whether the chassis has a lock.
+    match kind & 0x7F {
+        // Desktop, low profile desktop, pizza box, mini tower, tower, space-saving, lunch box,
+        // mini PC and stick PC.
+        0x03..=0x07 | 0x0F | 0x10 | 0x23 | 0x24 => ChassisKind::Desktop,
+        // Portable, laptop, notebook, sub notebook, convertible and detachable.
+        0x08..=0x0A | 0x0E | 0x1F | 0x20 => ChassisKind::Laptop,
+        // Hand held and tablet.
+        0x0B | 0x1E => ChassisKind::Tablet,
+        0x0D => ChassisKind::AllInOne,
+        // Main server chassis, rack mount chassis, multi-system chassis, blade and blade
+        // enclosure.
+        0x11 | 0x17 | 0x19 | 0x1C | 0x1D => ChassisKind::Server,
+        _ => ChassisKind::Unknown,
+    }
+}
+
+/// Sets the chassis of virtual machines, whose firmware usually reports a generic (or a
+/// desktop) chassis.
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "windows",
+        target_os = "freebsd",
+    ),
+    not(feature = "unknown-ci"),
+))]
+pub(crate) fn set_virtual_chassis(info: &mut crate::HardwareInfo) {
+    if get_hypervisor(info).is_some() {
+        info.chassis = crate::ChassisKind::VirtualMachine;
+    }
+}
+
+thread_local! {
+    // Set while a `try_refresh_*` method runs on this thread.
+    static ERRORS: std::cell::RefCell<ErrorCollector> =
+        const { std::cell::RefCell::new(ErrorCollector(None)) };
+}
+
+/// Collects the errors reported with [`report_error`] during a `try_refresh_*` call.
+///
+/// The collector is per thread, so it has to be retrieved with [`ErrorCollector::current`] and
+/// passed to [`ErrorCollector::run`] to collect the errors happening on other threads (like in
+/// `rayon` iterators).
+#[derive(Clone)]
+pub(crate) struct ErrorCollector(
+    Option<std::sync::Arc<std::sync::Mutex<Vec<crate::RefreshError>>>>,
+);
+
+impl ErrorCollector {
+    #[cfg(any(
+        test,
+        feature = "parallel",
+        all(
+            any(target_os = "linux", target_os = "android", target_os = "windows"),
+            not(feature = "unknown-ci"),
+        ),
+    ))]
+    pub(crate) fn current() -> Self {
+        ERRORS.with(|errors| errors.borrow().clone())
+    }
+
+    /// Runs `f` with the errors reported on the current thread going to this collector.
+    pub(crate) fn run<R, F: FnOnce() -> R>(&self, f: F) -> R {
+        if self.0.is_none() {
+            return f();
+        }
+        let _previous = RestoreCollector(ERRORS.with(|errors| errors.replace(self.clone())));
+        f()
+    }
+}
+
+/// Puts the collector it holds back when dropped, even if the code run with
+/// [`ErrorCollector::run`] panicked: the thread may be reused after the panic was caught (like the
+/// async workers do).
+struct RestoreCollector(ErrorCollector);
+
+impl Drop for RestoreCollector {
+    fn drop(&mut self) {
+        let previous = std::mem::replace(&mut self.0, ErrorCollector(None));
+        ERRORS.with(|errors| *errors.borrow_mut() = previous);
+    }
+}
+
+/// Runs `f` and returns the errors it reported.
+pub(crate) fn collect_errors<F: FnOnce()>(f: F) -> Result<(), crate::RefreshReport> {
+    let errors = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    ErrorCollector(Some(std::sync::Arc::clone(&errors))).run(f);
+    let mut errors = std::mem::take(&mut *errors.lock().unwrap_or_else(|e| e.into_inner()));
+    if !crate::IS_SUPPORTED {
+        errors.push(crate::RefreshError {
+            kind: crate::RefreshErrorKind::Unavailable,
+            target: std::env::consts::OS.to_owned(),
+            message: "this system isn't supported".to_owned(),
+        });
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(crate::RefreshReport { errors })
+    }
+}
+
+/// Reports a failure to the current `try_refresh_*` call, if any.
+#[cfg(any(
+    test,
+    all(
+        any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "windows",
+            target_os = "freebsd",
+        ),
+        not(feature = "unknown-ci"),
+    ),
+))]
+pub(crate) fn report_error(
+    kind: crate::RefreshErrorKind,
+    target: impl std::fmt::Display,
+    message: impl std::fmt::Display,
+) {
+    ERRORS.with(|errors| {
+        if let Some(ref errors) = errors.borrow().0 {
+            errors
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(crate::RefreshError {
+                    kind,
+                    target: target.to_string(),
+                    message: message.to_string(),
+                });
+        }
+    });
+}
+
+/// Reports an I/O failure to the current `try_refresh_*` call, if any. `not_found` is the kind
+/// used if `target` doesn't exist.
+#[cfg(all(
+    any(target_os = "linux", target_os = "android"),
+    not(feature = "unknown-ci")
+))]
+pub(crate) fn report_io_error(
+    target: impl std::fmt::Display,
+    error: &std::io::Error,
+    not_found: crate::RefreshErrorKind,
+) {
+    let kind = match error.kind() {
+        std::io::ErrorKind::PermissionDenied => crate::RefreshErrorKind::PermissionDenied,
+        std::io::ErrorKind::NotFound => not_found,
+        // `ESRCH`, returned when reading the files of a process which stopped.
+        #[cfg(unix)]
+        _ if error.raw_os_error() == Some(libc::ESRCH) => crate::RefreshErrorKind::Vanished,
+        _ => crate::RefreshErrorKind::Other,
+    };
+    report_error(kind, target, error);
+}
+
+#[cfg(all(
+    test,
+    any(target_os = "linux", target_os = "android"),
+    not(feature = "unknown-ci"),
+))]
+mod test {
+    use super::{
+        chassis_from_smbios, hypervisor_from_cpuid_signature, hypervisor_from_hardware_info,
+        normalize_arch, parse_remote_location,
+    };
+    use crate::{ChassisKind, HardwareInfo, Hypervisor};
+
+    #[test]
+    fn remote_location() {
+        let parse = |source| parse_remote_location(source);
+        let location = |host: &str, path: &str| Some((host.to_owned(), path.to_owned()));
+
+        assert_eq!(parse("nas:/export/home"), location("nas", "/export/home"));
+        assert_eq!(parse("user@nas:"), location("nas", ""));
+        assert_eq!(parse("[fe80::1]:/export"), location("fe80::1", "/export"));
+        assert_eq!(parse("user@[::1]:/data"), location("::1", "/data"));
+        assert_eq!(parse("//nas/share/dir"), location("nas", "/share/dir"));
+        assert_eq!(
+            parse("//user;WORKGROUP@nas/share"),
+            location("nas", "/share")
+        );
+        assert_eq!(parse("\\\\nas\\share"), location("nas", "\\share"));
+        assert_eq!(parse("/dev/sda1"), None);
+        assert_eq!(parse(":/export"), None);
+    }
+
+    #[test]
+    fn hypervisor_signature() {
+        let parse = |signature: &[u8]| hypervisor_from_cpuid_signature(signature);
+
+        assert_eq!(parse(b"KVMKVMKVM\0\0\0"), Some(Hypervisor::Kvm));
+        assert_eq!(parse(b"Microsoft Hv"), Some(Hypervisor::HyperV));
+        assert_eq!(parse(b"VMwareVMware"), Some(Hypervisor::VMware));
+        assert_eq!(
+            parse(b"ACRNACRNACRN"),
+            Some(Hypervisor::Other("ACRNACRNACRN".to_owned()))
+        );
+        assert_eq!(parse(&[0; 12]), None);
+    }
+
+    #[test]
+    fn hypervisor_hardware_info() {
+        let info = |manufacturer: &str, product_name: &str| HardwareInfo {
+            manufacturer: manufacturer.to_owned(),
+            product_name: product_name.to_owned(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            hypervisor_from_hardware_info(&info("QEMU", "Standard PC (Q35 + ICH9, 2009)")),
+            Some(Hypervisor::Qemu)
+        );
+        assert_eq!(
+            hypervisor_from_hardware_info(&info("innotek GmbH", "VirtualBox")),
+            Some(Hypervisor::VirtualBox)
+        );
+        assert_eq!(
+            hypervisor_from_hardware_info(&info("Microsoft Corporation", "Virtual Machine")),
+            Some(Hypervisor::HyperV)
+        );
+        assert_eq!(
+            hypervisor_from_hardware_info(&info("Microsoft Corporation", "Surface Pro 9")),
+            None
+        );
+        assert_eq!(
+            hypervisor_from_hardware_info(&info("LENOVO", "20XWCTO1WW")),
+            None
+        );
+    }
+
+    #[test]
+    fn arch_names() {
+        assert_eq!(normalize_arch("amd64"), "x86_64");
+        assert_eq!(normalize_arch("AMD64"), "x86_64");
+        assert_eq!(normalize_arch("i686"), "x86");
+        assert_eq!(normalize_arch("arm64"), "aarch64");
+        assert_eq!(normalize_arch("ARM64"), "aarch64");
+        assert_eq!(normalize_arch("armv7l"), "arm");
+        assert_eq!(normalize_arch("armv8l"), "arm");
+        assert_eq!(normalize_arch("ppc64le"), "powerpc64");
+        assert_eq!(normalize_arch("IA64"), "IA64");
+    }
+
+    #[test]
+    fn smbios_chassis() {
+        assert_eq!(chassis_from_smbios(0x03), ChassisKind::Desktop);
+        assert_eq!(chassis_from_smbios(0x0A), ChassisKind::Laptop);
+        // With the lock bit set.
+        assert_eq!(chassis_from_smbios(0x89), ChassisKind::Laptop);
+        assert_eq!(chassis_from_smbios(0x17), ChassisKind::Server);
+        assert_eq!(chassis_from_smbios(0x0D), ChassisKind::AllInOne);
+        assert_eq!(chassis_from_smbios(0x1E), ChassisKind::Tablet);
+        assert_eq!(chassis_from_smbios(0x01), ChassisKind::Unknown);
+    }
+
+    #[test]
+    fn hardware_info_model() {
+        let info = |manufacturer: &str, product_name: &str, product_version: &str| HardwareInfo {
+            manufacturer: manufacturer.to_owned(),
+            product_name: product_name.to_owned(),
+            product_version: product_version.to_owned(),
+            board_name: "B650M".to_owned(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            info("LENOVO", "21HMCTO1WW", "ThinkPad X1 Carbon Gen 11").model(),
+            "ThinkPad X1 Carbon Gen 11"
+        );
+        assert_eq!(info("LENOVO", "21HMCTO1WW", "").model(), "21HMCTO1WW");
+        assert_eq!(info("Dell Inc.", "XPS 13 9310", "").model(), "XPS 13 9310");
+        assert_eq!(
+            info("ASUS", "To Be Filled By O.E.M.", "To Be Filled By O.E.M.").model(),
+            "B650M"
+        );
+    }
+}
@@ -0,0 +1,185 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::get_device_interface_paths;
+use crate::{Battery, BatteryState};
+
+use std::ffi::c_void;
+use std::mem::size_of;
+use std::time::Duration;
+
+use windows::core::PCWSTR;
+use windows::Win32::Devices::DeviceAndDriverInstallation::GUID_DEVCLASS_BATTERY;
+use windows::Win32::Foundation::{CloseHandle, HANDLE};
+use windows::Win32::Storage::FileSystem::{
+    CreateFileW, FILE_GENERIC_READ, FILE_SHARE_READ, FILE_SHARE_WRITE, OPEN_EXISTING,
+};
+use windows::Win32::System::Power::{
+    BatteryDeviceName, BatteryEstimatedTime, BatteryInformation, BATTERY_CAPACITY_RELATIVE,
+    BATTERY_CHARGING, BATTERY_DISCHARGING, BATTERY_INFORMATION, BATTERY_POWER_ON_LINE,
+    BATTERY_QUERY_INFORMATION, BATTERY_QUERY_INFORMATION_LEVEL, BATTERY_STATUS,
+    BATTERY_SYSTEM_BATTERY, BATTERY_UNKNOWN_CAPACITY, BATTERY_UNKNOWN_TIME, BATTERY_WAIT_STATUS,
+    IOCTL_BATTERY_QUERY_INFORMATION, IOCTL_BATTERY_QUERY_STATUS, IOCTL_BATTERY_QUERY_TAG,
+};
+use windows::Win32::System::IO::DeviceIoControl;
+
+struct HandleWrapper(HANDLE);
+
+impl Drop for HandleWrapper {
+    fn drop(&mut self) {
+        let _err = unsafe { CloseHandle(self.0) };
+    }
+}
+
+pub(crate) fn get_batteries(batteries: &mut Vec<Battery>) {
+    unsafe {
+        for path in get_device_interface_paths(&GUID_DEVCLASS_BATTERY) {
+            if let Some(battery) = get_battery(&path, batteries.len()) {
+                batteries.push(battery);
+            }
+        }
+    }
+}
+
+unsafe fn device_io_control<I, O>(
+    handle: &HandleWrapper,
+    code: u32,
+    input: &I,
+    output: &mut O,
+) -> bool {
+    let mut returned = 0;
+    DeviceIoControl(
+        handle.0,
+        code,
+        Some(input as *const I as *const c_void),
+        size_of::<I>() as _,
+        Some(output as *mut O as *mut c_void),
+        size_of::<O>() as _,
+        Some(&mut returned),
+        None,
+    )
+    .is_ok()
+}
+
+unsafe fn query_information<O>(
+    handle: &HandleWrapper,
+    tag: u32,
+    level: BATTERY_QUERY_INFORMATION_LEVEL,
+    output: &mut O,
+) -> bool {
+    let query = BATTERY_QUERY_INFORMATION {
+        BatteryTag: tag,
+        InformationLevel: level,
+        AtRate: 0,
+    };
+    device_io_control(handle, IOCTL_BATTERY_QUERY_INFORMATION, &query, output)
+}
+
+unsafe fn get_battery(path: &[u16], index: usize) -> Option<Battery> {
+    let handle = match CreateFileW(
+        PCWSTR::from_raw(path.as_ptr()),
+        FILE_GENERIC_READ.0,
+        FILE_SHARE_READ | FILE_SHARE_WRITE,
+        None,
+        OPEN_EXISTING,
+        Default::default(),
+        HANDLE::default(),
+    ) {
+        Ok(handle) => HandleWrapper(handle),
+        Err(_e) => {
+            sysinfo_debug!("failed to open battery: {:?}", _e);
+            return None;
+        }
+    };
+    // The tag identifies the battery currently in the slot, it is `0` if there is none.
+    let mut tag = 0u32;
+    if !device_io_control(&handle, IOCTL_BATTERY_QUERY_TAG, &0u32, &mut tag) || tag == 0 {
+        return None;
+    }
+    let mut info: BATTERY_INFORMATION = std::mem::zeroed();
+    // UPS are also listed as batteries.
+    if !query_information(&handle, tag, BatteryInformation, &mut info)
+        || info.Capabilities & BATTERY_SYSTEM_BATTERY == 0
+    {
+        return None;
+    }
+    let wait = BATTERY_WAIT_STATUS {
+        BatteryTag: tag,
+        ..std::mem::zeroed()
+    };
+    let mut status: BATTERY_STATUS = std::mem::zeroed();
+    if !device_io_control(&handle, IOCTL_BATTERY_QUERY_STATUS, &wait, &mut status) {
+        return None;
+    }
+
+    let full = info.FullChargedCapacity;
+    let capacity = status.Capacity;
+    let state = if status.PowerState & BATTERY_CHARGING != 0 {
+        BatteryState::Charging
+    } else if status.PowerState & BATTERY_DISCHARGING != 0 {
+        BatteryState::Discharging
+    } else if status.PowerState & BATTERY_POWER_ON_LINE != 0 {
+        if capacity >= full {
+            BatteryState::Full
+        } else {
+            BatteryState::NotCharging
+        }
+    } else {
+        BatteryState::Unknown
+    };
+    // Capacities are in mWh, unless the battery only reports percentages.
+    let relative = info.Capabilities & BATTERY_CAPACITY_RELATIVE != 0;
+    let mwh = |value: u32| {
+        if relative || value == BATTERY_UNKNOWN_CAPACITY {
+            None
+        } else {
+            Some(u64::from(value))
+        }
+    };
+
+    let mut time_to_empty = None;
+    if state == BatteryState::Discharging {
+        // Estimated at the current discharge rate.
+        let mut seconds = 0u32;
+        if query_information(&handle, tag, BatteryEstimatedTime, &mut seconds)
+            && seconds != BATTERY_UNKNOWN_TIME
+        {
+            time_to_empty = Some(Duration::from_secs(u64::from(seconds)));
+        }
+    }
+    // The rate is in mW, positive when charging. `BATTERY_UNKNOWN_RATE` is negative once
+    // converted to `i32`.
+    let time_to_full = match (state, mwh(full), mwh(capacity)) {
+        (BatteryState::Charging, Some(full), Some(capacity)) if status.Rate > 0 => Some(
+            Duration::from_secs(full.saturating_sub(capacity) * 3_600 / status.Rate as u64),
+        ),
+        _ => None,
+    };
+
+    let mut name = [0u16; 128];
+    let name = if query_information(&handle, tag, BatteryDeviceName, &mut name) {
+        let end = name.iter().position(|c| *c == 0).unwrap_or(name.len());
+        String::from_utf16_lossy(&name[..end])
+    } else {
+        String::new()
+    };
+
+    Some(Battery {
+        name: if name.is_empty() {
+            format!("Battery {index}")
+        } else {
+            name
+        },
+        state,
+        charge: if full != 0 && full != BATTERY_UNKNOWN_CAPACITY {
+            (capacity as f32 * 100. / full as f32).min(100.)
+        } else {
+            0.
+        },
+        energy: mwh(capacity),
+        full_capacity: mwh(full),
+        design_capacity: mwh(info.DesignedCapacity),
+        cycle_count: Some(info.CycleCount).filter(|count| *count != 0),
+        time_to_empty,
+        time_to_full,
+    })
+}
@@ -1,8 +1,19 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::Component;
-
-use windows::core::w;
+use crate::sys::disk::{get_physical_drive_temperature, MAX_PHYSICAL_DRIVES};
+use crate::{Component, ComponentKind, ThermalZone, TripPoint, TripPointKind};
+
+use std::ffi::c_void;
+
+use windows::core::{w, PCWSTR};
+use windows::Wdk::Graphics::Direct3D::{
+    D3DKMTCloseAdapter, D3DKMTEnumAdapters2, D3DKMTQueryAdapterInfo, D3DKMT_ADAPTERADDRESS,
+    D3DKMT_ADAPTERINFO, D3DKMT_ADAPTERREGISTRYINFO, D3DKMT_ADAPTERTYPE, D3DKMT_ADAPTER_PERFDATA,
+    D3DKMT_ADAPTER_PERFDATACAPS, D3DKMT_CLOSEADAPTER, D3DKMT_ENUMADAPTERS2,
+    D3DKMT_QUERYADAPTERINFO, KMTQAITYPE_ADAPTERADDRESS, KMTQAITYPE_ADAPTERPERFDATA,
+    KMTQAITYPE_ADAPTERPERFDATA_CAPS, KMTQAITYPE_ADAPTERREGISTRYINFO, KMTQAITYPE_ADAPTERTYPE,
+    KMTQUERYADAPTERINFOTYPE,
+};
 use windows::Win32::Foundation::{SysAllocString, SysFreeString};
 use windows::Win32::Security::PSECURITY_DESCRIPTOR;
 use windows::Win32::System::Com::{
@@ -10,39 +21,120 @@ use windows::Win32::System::Com::{
     CLSCTX_INPROC_SERVER, EOAC_NONE, RPC_C_AUTHN_LEVEL_CALL, RPC_C_AUTHN_LEVEL_DEFAULT,
     RPC_C_IMP_LEVEL_IMPERSONATE,
 };
+use windows::Win32::System::Ole::{SafeArrayGetElement, SafeArrayGetLBound, SafeArrayGetUBound};
 use windows::Win32::System::Rpc::{RPC_C_AUTHN_WINNT, RPC_C_AUTHZ_NONE};
-use windows::Win32::System::Variant::{VariantClear, VARIANT};
+use windows::Win32::System::Variant::{VariantClear, VARIANT, VT_ARRAY};
 use windows::Win32::System::Wmi::{
-    IEnumWbemClassObject, IWbemLocator, IWbemServices, WbemLocator, WBEM_FLAG_FORWARD_ONLY,
-    WBEM_FLAG_NONSYSTEM_ONLY, WBEM_FLAG_RETURN_IMMEDIATELY, WBEM_INFINITE,
+    IEnumWbemClassObject, IWbemClassObject, IWbemLocator, IWbemServices, WbemLocator,
+    WBEM_FLAG_FORWARD_ONLY, WBEM_FLAG_NONSYSTEM_ONLY, WBEM_FLAG_RETURN_IMMEDIATELY, WBEM_INFINITE,
 };
 
+const ACPI_NAMESPACE: PCWSTR = w!("root\\WMI");
+const ACPI_QUERY: PCWSTR = w!("SELECT * FROM MSAcpi_ThermalZoneTemperature");
+const PERF_NAMESPACE: PCWSTR = w!("root\\CIMV2");
+const PERF_QUERY: PCWSTR =
+    w!("SELECT Name, Temperature FROM Win32_PerfFormattedData_Counters_ThermalZoneInformation");
+
+/// Where the temperature of a component comes from.
+enum Source {
+    /// `MSAcpi_ThermalZoneTemperature`, which often requires to be administrator.
+    AcpiThermalZone(Option<Connection>),
+    /// The thermal zone performance counter named `name`, available to all users.
+    PerfThermalZone {
+        connection: Option<Connection>,
+        name: String,
+    },
+    /// The physical drive whose number is the given one.
+    Drive(u32),
+    /// A graphics adapter.
+    Gpu(Adapter),
+}
+
 pub(crate) struct ComponentInner {
     temperature: f32,
     max: f32,
     critical: Option<f32>,
     label: String,
-    connection: Option<Connection>,
+    id: String,
+    source: Source,
 }
 
 impl ComponentInner {
     /// Creates a new `ComponentInner` with the given information.
     fn new() -> Option<Self> {
-        let mut c = Connection::new()
-            .and_then(|x| x.initialize_security())
-            .and_then(|x| x.create_instance())
-            .and_then(|x| x.connect_server())
-            .and_then(|x| x.set_proxy_blanket())
-            .and_then(|x| x.exec_query())?;
+        let mut c = Connection::open(ACPI_NAMESPACE).and_then(|x| x.exec_query(ACPI_QUERY))?;
 
         c.temperature(true)
-            .map(|(temperature, critical)| ComponentInner {
+            .map(|(temperature, critical, instance)| ComponentInner {
                 temperature,
                 label: "Computer".to_owned(),
+                // Like `ACPI\ThermalZone\TZ00_0`.
+                id: instance.unwrap_or_else(|| "ACPI\\ThermalZone".to_owned()),
                 max: temperature,
                 critical,
-                connection: Some(c),
+                source: Source::AcpiThermalZone(Some(c)),
+            })
+    }
+
+    /// Returns the thermal zones provided by the performance counters.
+    fn perf_thermal_zones() -> Vec<Self> {
+        let mut c = match Connection::open(PERF_NAMESPACE).and_then(|x| x.exec_query(PERF_QUERY)) {
+            Some(c) => c,
+            None => return Vec::new(),
+        };
+        c.zone_temperatures()
+            .into_iter()
+            .map(|(name, temperature)| ComponentInner {
+                temperature,
+                label: get_thermal_zone_label(&name),
+                // Like `\_TZ.TZ00`.
+                id: name.clone(),
+                max: temperature,
+                critical: None,
+                source: Source::PerfThermalZone {
+                    connection: None,
+                    name,
+                },
             })
+            .collect()
+    }
+
+    fn gpus() -> Vec<Self> {
+        unsafe {
+            get_adapters()
+                .into_iter()
+                .filter_map(|adapter| {
+                    let temperature = adapter.temperature()?;
+                    let label = adapter.name().unwrap_or_else(|| "GPU".to_owned());
+                    Some(ComponentInner {
+                        temperature,
+                        // The handle and LUID of the adapter change across reboots, unlike its
+                        // location on the PCI bus.
+                        id: adapter.address().unwrap_or_else(|| format!("GPU {label}")),
+                        label,
+                        max: temperature,
+                        critical: adapter.critical(),
+                        source: Source::Gpu(adapter),
+                    })
+                })
+                .collect()
+        }
+    }
+
+    fn drives() -> Vec<Self> {
+        (0..MAX_PHYSICAL_DRIVES)
+            .filter_map(|number| {
+                let drive = unsafe { get_physical_drive_temperature(number)? };
+                Some(ComponentInner {
+                    temperature: drive.temperature,
+                    label: drive.label,
+                    id: format!("PhysicalDrive{number}"),
+                    max: drive.temperature,
+                    critical: drive.critical,
+                    source: Source::Drive(number),
+                })
+            })
+            .collect()
     }
 
     pub(crate) fn temperature(&self) -> f32 {
@@ -57,34 +149,98 @@ impl ComponentInner {
         self.critical
     }
 
+    pub(crate) fn lower_limit(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn upper_limit(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn raw_value(&self) -> Option<i64> {
+        None
+    }
+
     pub(crate) fn label(&self) -> &str {
         &self.label
     }
 
-    pub(crate) fn refresh(&mut self) {
-        if self.connection.is_none() {
-            self.connection = Connection::new()
-                .and_then(|x| x.initialize_security())
-                .and_then(|x| x.create_instance())
-                .and_then(|x| x.connect_server())
-                .and_then(|x| x.set_proxy_blanket());
+    pub(crate) fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub(crate) fn kind(&self) -> ComponentKind {
+        match self.source {
+            Source::AcpiThermalZone(_) | Source::PerfThermalZone { .. } => ComponentKind::Other,
+            Source::Drive(_) => ComponentKind::Storage,
+            Source::Gpu(_) => ComponentKind::Gpu,
         }
-        self.connection = if let Some(x) = self.connection.take() {
-            x.exec_query()
-        } else {
-            None
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        let temperature = match self.source {
+            Source::AcpiThermalZone(ref mut connection) => {
+                refresh_connection(connection, ACPI_NAMESPACE, ACPI_QUERY);
+                connection
+                    .as_mut()
+                    .and_then(|connection| connection.temperature(false))
+                    .map(|(temperature, _, _)| temperature)
+            }
+            Source::PerfThermalZone {
+                ref mut connection,
+                ref name,
+            } => {
+                refresh_connection(connection, PERF_NAMESPACE, PERF_QUERY);
+                connection.as_mut().and_then(|connection| {
+                    connection
+                        .zone_temperatures()
+                        .into_iter()
+                        .find(|(zone, _)| zone == name)
+                        .map(|(_, temperature)| temperature)
+                })
+            }
+            Source::Drive(number) => unsafe {
+                get_physical_drive_temperature(number).map(|drive| drive.temperature)
+            },
+            Source::Gpu(ref adapter) => unsafe { adapter.temperature() },
         };
-        if let Some(ref mut connection) = self.connection {
-            if let Some((temperature, _)) = connection.temperature(false) {
-                self.temperature = temperature;
-                if self.temperature > self.max {
-                    self.max = self.temperature;
-                }
+        if let Some(temperature) = temperature {
+            self.temperature = temperature;
+            if self.temperature > self.max {
+                self.max = self.temperature;
             }
         }
     }
 }
 
+/// Returns the label of a thermal zone from its performance counter name (like `\_TZ.TZ00`).
+fn get_thermal_zone_label(name: &str) -> String {
+    format!("Thermal zone {}", name.trim_start_matches("\\_TZ."))
+}
+
+/// Runs `query` again, connecting to `namespace` first if needed.
+fn refresh_connection(connection: &mut Option<Connection>, namespace: PCWSTR, query: PCWSTR) {
+    if connection.is_none() {
+        *connection = Connection::open(namespace);
+    }
+    *connection = connection.take().and_then(|x| x.exec_query(query));
+}
+
+pub(crate) fn get_thermal_zones(zones: &mut Vec<ThermalZone>) {
+    if let Some(mut c) = Connection::open(ACPI_NAMESPACE).and_then(|x| x.exec_query(ACPI_QUERY)) {
+        zones.extend(c.thermal_zones());
+    }
+}
+
+/// Converts a temperature given in tenth of degrees Kelvin, `0` meaning it isn't defined.
+fn from_deci_kelvin(value: i32) -> Option<f32> {
+    if value > 0 {
+        Some(value as f32 / 10. - 273.15)
+    } else {
+        None
+    }
+}
+
 pub(crate) struct ComponentsInner {
     components: Vec<Component>,
 }
@@ -113,17 +269,109 @@ impl ComponentsInner {
     }
 
     pub(crate) fn refresh_list(&mut self) {
-        self.components = match ComponentInner::new() {
-            Some(c) => vec![Component { inner: c }],
-            None => Vec::new(),
+        // `MSAcpi_ThermalZoneTemperature` returns nothing when we're not administrator and on a
+        // lot of consumer motherboards, in which case the same thermal zones are read from the
+        // performance counters. Reading the embedded controller directly would require a driver
+        // and isn't safe to do from user space, so it isn't done.
+        let mut components = match ComponentInner::new() {
+            Some(c) => vec![c],
+            None => ComponentInner::perf_thermal_zones(),
         };
+        components.extend(ComponentInner::gpus());
+        components.extend(ComponentInner::drives());
+        self.components = components
+            .into_iter()
+            .map(|inner| Component { inner })
+            .collect();
+    }
+}
+
+/// Graphics adapter opened by [`get_adapters`].
+struct Adapter(u32);
+
+impl Adapter {
+    unsafe fn query<T>(&self, kind: KMTQUERYADAPTERINFOTYPE, data: &mut T) -> bool {
+        let mut info = D3DKMT_QUERYADAPTERINFO {
+            hAdapter: self.0,
+            Type: kind,
+            pPrivateDriverData: data as *mut T as *mut c_void,
+            PrivateDriverDataSize: std::mem::size_of::<T>() as _,
+        };
+        D3DKMTQueryAdapterInfo(&mut info).is_ok()
+    }
+
+    unsafe fn name(&self) -> Option<String> {
+        let mut info: D3DKMT_ADAPTERREGISTRYINFO = std::mem::zeroed();
+        if !self.query(KMTQAITYPE_ADAPTERREGISTRYINFO, &mut info) {
+            return None;
+        }
+        let name = &info.AdapterString;
+        let end = name.iter().position(|c| *c == 0).unwrap_or(name.len());
+        Some(String::from_utf16_lossy(&name[..end])).filter(|name| !name.is_empty())
+    }
+
+    /// Temperatures are given in tenth of degrees Celsius, `0` meaning that the driver doesn't
+    /// report it.
+    unsafe fn temperature(&self) -> Option<f32> {
+        let mut data: D3DKMT_ADAPTER_PERFDATA = std::mem::zeroed();
+        if !self.query(KMTQAITYPE_ADAPTERPERFDATA, &mut data) || data.Temperature == 0 {
+            return None;
+        }
+        Some(data.Temperature as f32 / 10.)
+    }
+
+    unsafe fn critical(&self) -> Option<f32> {
+        let mut caps: D3DKMT_ADAPTER_PERFDATACAPS = std::mem::zeroed();
+        if !self.query(KMTQAITYPE_ADAPTERPERFDATA_CAPS, &mut caps) || caps.TemperatureMax == 0 {
+            return None;
+        }
+        Some(caps.TemperatureMax as f32 / 10.)
+    }
+
+    unsafe fn address(&self) -> Option<String> {
+        let mut address: D3DKMT_ADAPTERADDRESS = std::mem::zeroed();
+        if !self.query(KMTQAITYPE_ADAPTERADDRESS, &mut address) {
+            return None;
+        }
+        Some(format!(
+            "PCI {:02x}:{:02x}.{}",
+            address.BusNumber, address.DeviceNumber, address.FunctionNumber
+        ))
+    }
+
+    unsafe fn is_software(&self) -> bool {
+        let mut kind: D3DKMT_ADAPTERTYPE = std::mem::zeroed();
+        // The third bit is `SoftwareDevice`, set for the "Microsoft Basic Render Driver".
+        self.query(KMTQAITYPE_ADAPTERTYPE, &mut kind) && kind.Anonymous.Value & (1 << 2) != 0
+    }
+}
+
+impl Drop for Adapter {
+    fn drop(&mut self) {
+        let _err = unsafe { D3DKMTCloseAdapter(&D3DKMT_CLOSEADAPTER { hAdapter: self.0 }) };
     }
 }
 
-macro_rules! bstr {
-    ($x:literal) => {{
-        SysAllocString(w!($x))
-    }};
+/// Returns the hardware graphics adapters. Their temperature is provided by WDDM 2.4 (and
+/// later) drivers, for both discrete and integrated GPUs.
+unsafe fn get_adapters() -> Vec<Adapter> {
+    let mut enum_adapters: D3DKMT_ENUMADAPTERS2 = std::mem::zeroed();
+    // The first call returns the number of adapters.
+    if D3DKMTEnumAdapters2(&mut enum_adapters).is_err() {
+        return Vec::new();
+    }
+    let mut infos: Vec<D3DKMT_ADAPTERINFO> =
+        vec![std::mem::zeroed(); enum_adapters.NumAdapters as usize];
+    enum_adapters.pAdapters = infos.as_mut_ptr();
+    if D3DKMTEnumAdapters2(&mut enum_adapters).is_err() {
+        return Vec::new();
+    }
+    infos.truncate(enum_adapters.NumAdapters as usize);
+    infos
+        .into_iter()
+        .map(|info| Adapter(info.hAdapter))
+        .filter(|adapter| !adapter.is_software())
+        .collect()
 }
 
 struct Connection {
@@ -138,6 +386,15 @@ unsafe impl Send for Connection {}
 unsafe impl Sync for Connection {}
 
 impl Connection {
+    /// Connects to the WMI `namespace`.
+    fn open(namespace: PCWSTR) -> Option<Connection> {
+        Connection::new()
+            .and_then(|x| x.initialize_security())
+            .and_then(|x| x.create_instance())
+            .and_then(|x| x.connect_server(namespace))
+            .and_then(|x| x.set_proxy_blanket())
+    }
+
     #[allow(clippy::unnecessary_wraps)]
     fn new() -> Option<Connection> {
         let val = unsafe { CoInitializeEx(None, Default::default()) };
@@ -149,8 +406,11 @@ impl Connection {
         })
     }
 
+    #[allow(clippy::unnecessary_wraps)]
     fn initialize_security(self) -> Option<Connection> {
-        unsafe {
+        // It can only be called once per process, so it fails if a connection was already
+        // opened (or if the application called it), which isn't a problem.
+        let _err = unsafe {
             CoInitializeSecurity(
                 PSECURITY_DESCRIPTOR::default(),
                 -1,
@@ -162,8 +422,8 @@ impl Connection {
                 EOAC_NONE,
                 None,
             )
-        }
-        .map_or(None, |_| Some(self))
+        };
+        Some(self)
     }
 
     fn create_instance(mut self) -> Option<Connection> {
@@ -173,10 +433,10 @@ impl Connection {
         Some(self)
     }
 
-    fn connect_server(mut self) -> Option<Connection> {
+    fn connect_server(mut self, namespace: PCWSTR) -> Option<Connection> {
         let instance = self.instance.as_ref()?;
         let svc = unsafe {
-            let s = bstr!("root\\WMI");
+            let s = SysAllocString(namespace);
             let res = instance.ConnectServer(
                 &s,
                 &Default::default(),
@@ -189,6 +449,10 @@ impl Connection {
             SysFreeString(&s);
             res
         }
+        .map_err(|_e| {
+            sysinfo_debug!("failed to connect to WMI: {:?}", _e);
+            crate::utils::report_error(crate::RefreshErrorKind::Unavailable, "WMI", _e);
+        })
         .ok()?;
 
         self.server_connection = Some(svc);
@@ -213,12 +477,12 @@ impl Connection {
         Some(self)
     }
 
-    fn exec_query(mut self) -> Option<Connection> {
+    fn exec_query(mut self, query: PCWSTR) -> Option<Connection> {
         let server_connection = self.server_connection.as_ref()?;
 
         let enumerator = unsafe {
-            let s = bstr!("WQL"); // query kind
-            let query = bstr!("SELECT * FROM MSAcpi_ThermalZoneTemperature");
+            let s = SysAllocString(w!("WQL")); // query kind
+            let query = SysAllocString(query);
             let hres = server_connection.ExecQuery(
                 &s,
                 &query,
@@ -235,29 +499,34 @@ impl Connection {
         Some(self)
     }
 
-    fn temperature(&mut self, get_critical: bool) -> Option<(f32, Option<f32>)> {
-        let enumerator = self.enumerator.take()?;
-
+    /// Returns the next object returned by the query.
+    unsafe fn next_object(enumerator: &IEnumWbemClassObject) -> Option<IWbemClassObject> {
         let mut nb_returned = 0;
         let mut obj = [None; 1];
 
-        unsafe {
-            let _r = enumerator.Next(
-                WBEM_INFINITE, // Time out
-                obj.as_mut_slice(),
-                &mut nb_returned,
-            );
+        let _r = enumerator.Next(
+            WBEM_INFINITE, // Time out
+            obj.as_mut_slice(),
+            &mut nb_returned,
+        );
 
-            if nb_returned == 0 {
-                return None; // not enough rights I suppose...
-            }
+        if nb_returned == 0 {
+            return None; // not enough rights I suppose...
+        }
 
-            let class_obj = match &mut obj {
-                [Some(co)] => co,
-                _ => return None,
-            };
+        let [class_obj] = obj;
+        let class_obj = class_obj?;
+        let _r = class_obj.BeginEnumeration(WBEM_FLAG_NONSYSTEM_ONLY.0);
+        Some(class_obj)
+    }
 
-            let _r = class_obj.BeginEnumeration(WBEM_FLAG_NONSYSTEM_ONLY.0);
+    /// Returns the temperature of the thermal zone and, if `get_details` is `true`, its critical
+    /// temperature and instance name.
+    fn temperature(&mut self, get_details: bool) -> Option<(f32, Option<f32>, Option<String>)> {
+        let enumerator = self.enumerator.take()?;
+
+        unsafe {
+            let class_obj = Self::next_object(&enumerator)?;
 
             let mut variant = std::mem::MaybeUninit::<VARIANT>::uninit();
             // `Get` only initializes the variant if it succeeds, early returning is not a problem
@@ -280,7 +549,8 @@ impl Connection {
             let _r = VariantClear(&mut variant);
 
             let mut critical = None;
-            if get_critical {
+            let mut instance = None;
+            if get_details {
                 class_obj
                     .Get(w!("CriticalTripPoint"), 0, &mut variant, None, None)
                     .ok()?;
@@ -288,10 +558,149 @@ impl Connection {
                 // temperature is given in tenth of degrees Kelvin
                 critical = Some((variant.Anonymous.decVal.Anonymous2.Lo64 / 10) as f32 - 273.15);
                 let _r = VariantClear(&mut variant);
+
+                if class_obj
+                    .Get(w!("InstanceName"), 0, &mut variant, None, None)
+                    .is_ok()
+                {
+                    instance = Some(variant.Anonymous.Anonymous.Anonymous.bstrVal.to_string());
+                    let _r = VariantClear(&mut variant);
+                }
+            }
+
+            Some((temp, critical, instance))
+        }
+    }
+
+    /// Returns the name and temperature of all the thermal zones returned by the performance
+    /// counters query.
+    fn zone_temperatures(&mut self) -> Vec<(String, f32)> {
+        let enumerator = match self.enumerator.take() {
+            Some(enumerator) => enumerator,
+            None => return Vec::new(),
+        };
+        let mut zones = Vec::new();
+
+        unsafe {
+            while let Some(class_obj) = Self::next_object(&enumerator) {
+                let mut variant = std::mem::MaybeUninit::<VARIANT>::uninit();
+                if class_obj
+                    .Get(w!("Name"), 0, variant.as_mut_ptr(), None, None)
+                    .is_err()
+                {
+                    continue;
+                }
+                let mut variant = variant.assume_init();
+                let name = variant.Anonymous.Anonymous.Anonymous.bstrVal.to_string();
+                let _r = VariantClear(&mut variant);
+
+                if class_obj
+                    .Get(w!("Temperature"), 0, &mut variant, None, None)
+                    .is_err()
+                {
+                    continue;
+                }
+                // temperature is given in degrees Kelvin
+                let temp = variant.Anonymous.Anonymous.Anonymous.lVal as f32 - 273.15;
+                let _r = VariantClear(&mut variant);
+
+                zones.push((name, temp));
             }
+        }
+        zones
+    }
+
+    /// Returns the thermal zones, with their trip points, returned by the ACPI query.
+    fn thermal_zones(&mut self) -> Vec<ThermalZone> {
+        let enumerator = match self.enumerator.take() {
+            Some(enumerator) => enumerator,
+            None => return Vec::new(),
+        };
+        let mut zones = Vec::new();
+
+        unsafe {
+            while let Some(class_obj) = Self::next_object(&enumerator) {
+                let mut variant = std::mem::MaybeUninit::<VARIANT>::uninit();
+                if class_obj
+                    .Get(w!("InstanceName"), 0, variant.as_mut_ptr(), None, None)
+                    .is_err()
+                {
+                    continue;
+                }
+                let mut variant = variant.assume_init();
+                let name = variant.Anonymous.Anonymous.Anonymous.bstrVal.to_string();
+                let _r = VariantClear(&mut variant);
 
-            Some((temp, critical))
+                let mut get = |property: PCWSTR| -> Option<i32> {
+                    class_obj.Get(property, 0, &mut variant, None, None).ok()?;
+                    let value = variant.Anonymous.Anonymous.Anonymous.lVal;
+                    let _r = VariantClear(&mut variant);
+                    Some(value)
+                };
+                let temperature = get(w!("CurrentTemperature")).and_then(from_deci_kelvin);
+                let active_count = get(w!("ActiveTripPointCount")).unwrap_or(0);
+
+                let mut trip_points = Vec::new();
+                for (kind, property) in [
+                    (TripPointKind::Critical, w!("CriticalTripPoint")),
+                    (TripPointKind::Passive, w!("PassiveTripPoint")),
+                ] {
+                    if let Some(temperature) = get(property).and_then(from_deci_kelvin) {
+                        trip_points.push(TripPoint {
+                            kind,
+                            temperature,
+                            hysteresis: None,
+                            cooling_devices: Vec::new(),
+                        });
+                    }
+                }
+                // `ActiveTripPoint` is an array of 10 values, of which only the first
+                // `ActiveTripPointCount` ones are set.
+                if active_count > 0
+                    && class_obj
+                        .Get(w!("ActiveTripPoint"), 0, &mut variant, None, None)
+                        .is_ok()
+                {
+                    let inner = &variant.Anonymous.Anonymous;
+                    if inner.vt.0 & VT_ARRAY.0 != 0 {
+                        let array = inner.Anonymous.parray;
+                        if let (Ok(lower), Ok(upper)) =
+                            (SafeArrayGetLBound(array, 1), SafeArrayGetUBound(array, 1))
+                        {
+                            for index in lower..=upper.min(lower + active_count - 1) {
+                                let mut value = 0i32;
+                                if SafeArrayGetElement(
+                                    array,
+                                    &index,
+                                    &mut value as *mut i32 as *mut c_void,
+                                )
+                                .is_err()
+                                {
+                                    break;
+                                }
+                                if let Some(temperature) = from_deci_kelvin(value) {
+                                    trip_points.push(TripPoint {
+                                        kind: TripPointKind::Active,
+                                        temperature,
+                                        hysteresis: None,
+                                        cooling_devices: Vec::new(),
+                                    });
+                                }
+                            }
+                        }
+                    }
+                    let _r = VariantClear(&mut variant);
+                }
+
+                zones.push(ThermalZone {
+                    name,
+                    kind: None,
+                    temperature,
+                    trip_points,
+                });
+            }
         }
+        zones
     }
 }
 
@@ -306,3 +715,14 @@ impl Drop for Connection {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::get_thermal_zone_label;
+
+    #[test]
+    fn check_thermal_zone_label() {
+        assert_eq!(get_thermal_zone_label("\\_TZ.TZ00"), "Thermal zone TZ00");
+        assert_eq!(get_thermal_zone_label("CPUZ"), "Thermal zone CPUZ");
+    }
+}
@@ -0,0 +1,256 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Connection, ConnectionProtocol, ConnectionSummary, ConnectionTraffic, Pid, TcpState};
+
+use std::ffi::c_void;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use windows::Win32::Foundation::{ERROR_INSUFFICIENT_BUFFER, NO_ERROR};
+use windows::Win32::NetworkManagement::IpHelper::{
+    GetExtendedTcpTable, GetExtendedUdpTable, GetPerTcp6ConnectionEStats,
+    GetPerTcpConnectionEStats, SetPerTcp6ConnectionEStats, SetPerTcpConnectionEStats,
+    TCP_ESTATS_DATA_ROD_v0, TCP_ESTATS_PATH_ROD_v0, TcpConnectionEstatsData,
+    TcpConnectionEstatsPath, MIB_TCP6ROW, MIB_TCP6ROW_OWNER_PID, MIB_TCPROW_LH, MIB_TCPROW_LH_0,
+    MIB_TCPROW_OWNER_PID, MIB_TCP_STATE, MIB_UDP6ROW_OWNER_PID, MIB_UDPROW_OWNER_PID,
+    TCP_ESTATS_TYPE, TCP_TABLE_OWNER_PID_ALL, UDP_TABLE_OWNER_PID,
+};
+use windows::Win32::Networking::WinSock::{AF_INET, AF_INET6, IN6_ADDR, IN6_ADDR_0};
+
+/// Calls `get_table` until the buffer is big enough, then returns the rows of the table.
+///
+/// All the tables start with a `u32` containing the number of rows, followed by the rows.
+unsafe fn get_rows<T: Copy>(get_table: impl Fn(*mut c_void, &mut u32) -> u32) -> Vec<T> {
+    let mut size = 0;
+    // Aligned on 8 bytes so the rows can be read in place.
+    let mut buf: Vec<u64> = Vec::new();
+    loop {
+        match get_table(buf.as_mut_ptr() as *mut c_void, &mut size) {
+            ret if ret == NO_ERROR.0 => break,
+            ret if ret == ERROR_INSUFFICIENT_BUFFER.0 => {
+                // The table can grow between the calls so a bit more is allocated.
+                buf.resize(size as usize / 8 + 64, 0);
+                size = (buf.len() * 8) as u32;
+            }
+            _ => {
+                sysinfo_debug!("failed to retrieve connection table");
+                return Vec::new();
+            }
+        }
+    }
+    if buf.is_empty() {
+        return Vec::new();
+    }
+    let count = *(buf.as_ptr() as *const u32) as usize;
+    // The rows are aligned on their own alignment after the count.
+    let offset = std::mem::align_of::<T>().max(std::mem::size_of::<u32>());
+    let rows = (buf.as_ptr() as *const u8).add(offset) as *const T;
+    let max_count = (buf.len() * 8 - offset) / std::mem::size_of::<T>();
+    std::slice::from_raw_parts(rows, count.min(max_count)).to_vec()
+}
+
+// Values from `MIB_TCP_STATE`.
+fn tcp_state(state: u32) -> Option<TcpState> {
+    Some(match state {
+        1 => TcpState::Closed,
+        2 => TcpState::Listen,
+        3 => TcpState::SynSent,
+        4 => TcpState::SynReceived,
+        5 => TcpState::Established,
+        6 => TcpState::FinWait1,
+        7 => TcpState::FinWait2,
+        8 => TcpState::CloseWait,
+        9 => TcpState::Closing,
+        10 => TcpState::LastAck,
+        11 => TcpState::TimeWait,
+        // `MIB_TCP_STATE_DELETE_TCB`: the connection is being deleted.
+        12 => TcpState::Closed,
+        _ => return None,
+    })
+}
+
+// Ports are stored in network byte order in the lower 16 bits.
+fn port(port: u32) -> u16 {
+    u16::from_be(port as u16)
+}
+
+fn ipv4(addr: u32, port: u32) -> SocketAddr {
+    // Addresses are stored in network byte order.
+    SocketAddr::new(
+        IpAddr::V4(Ipv4Addr::from(addr.to_ne_bytes())),
+        self::port(port),
+    )
+}
+
+fn ipv6(addr: [u8; 16], port: u32) -> SocketAddr {
+    SocketAddr::new(IpAddr::V6(Ipv6Addr::from(addr)), self::port(port))
+}
+
+fn remote_address(state: Option<TcpState>, addr: SocketAddr) -> Option<SocketAddr> {
+    if state == Some(TcpState::Listen) || (addr.ip().is_unspecified() && addr.port() == 0) {
+        None
+    } else {
+        Some(addr)
+    }
+}
+
+/// Reads the extended statistics of type `kind` of a connection. If their collection isn't
+/// enabled yet, it is enabled for the next refreshes and `None` is returned.
+unsafe fn get_estats<Rod: Default>(
+    kind: TCP_ESTATS_TYPE,
+    get: &impl Fn(TCP_ESTATS_TYPE, &mut [u8], &mut [u8]) -> u32,
+    set: &impl Fn(TCP_ESTATS_TYPE, &[u8]) -> u32,
+) -> Option<Rod> {
+    // Both `TCP_ESTATS_DATA_RW_v0` and `TCP_ESTATS_PATH_RW_v0` only contain the
+    // `EnableCollection` boolean.
+    let mut rw = [0u8];
+    let mut rod = Rod::default();
+    let rod_bytes =
+        std::slice::from_raw_parts_mut(&mut rod as *mut Rod as *mut u8, std::mem::size_of::<Rod>());
+    if get(kind, &mut rw, rod_bytes) != NO_ERROR.0 {
+        return None;
+    }
+    if rw[0] == 0 {
+        // It fails if we're not administrator.
+        if set(kind, &[1]) != NO_ERROR.0 {
+            sysinfo_debug!("failed to enable TCP statistics collection");
+        }
+        return None;
+    }
+    Some(rod)
+}
+
+unsafe fn get_traffic(
+    get: impl Fn(TCP_ESTATS_TYPE, &mut [u8], &mut [u8]) -> u32,
+    set: impl Fn(TCP_ESTATS_TYPE, &[u8]) -> u32,
+) -> Option<ConnectionTraffic> {
+    let data: Option<TCP_ESTATS_DATA_ROD_v0> = get_estats(TcpConnectionEstatsData, &get, &set);
+    let path: Option<TCP_ESTATS_PATH_ROD_v0> = get_estats(TcpConnectionEstatsPath, &get, &set);
+    let data = data?;
+    Some(ConnectionTraffic {
+        bytes_sent: data.DataBytesOut,
+        bytes_received: data.DataBytesIn,
+        retransmitted_segments: path.map(|path| u64::from(path.PktsRetrans)).unwrap_or(0),
+    })
+}
+
+unsafe fn get_traffic_v4(row: &MIB_TCPROW_OWNER_PID) -> Option<ConnectionTraffic> {
+    let row = MIB_TCPROW_LH {
+        Anonymous: MIB_TCPROW_LH_0 {
+            dwState: row.dwState,
+        },
+        dwLocalAddr: row.dwLocalAddr,
+        dwLocalPort: row.dwLocalPort,
+        dwRemoteAddr: row.dwRemoteAddr,
+        dwRemotePort: row.dwRemotePort,
+    };
+    get_traffic(
+        |kind, rw, rod| GetPerTcpConnectionEStats(&row, kind, Some(rw), 0, None, 0, Some(rod), 0),
+        |kind, rw| SetPerTcpConnectionEStats(&row, kind, rw, 0, 0),
+    )
+}
+
+unsafe fn get_traffic_v6(row: &MIB_TCP6ROW_OWNER_PID) -> Option<ConnectionTraffic> {
+    let row = MIB_TCP6ROW {
+        State: MIB_TCP_STATE(row.dwState as _),
+        LocalAddr: IN6_ADDR {
+            u: IN6_ADDR_0 {
+                Byte: row.ucLocalAddr,
+            },
+        },
+        dwLocalScopeId: row.dwLocalScopeId,
+        dwLocalPort: row.dwLocalPort,
+        RemoteAddr: IN6_ADDR {
+            u: IN6_ADDR_0 {
+                Byte: row.ucRemoteAddr,
+            },
+        },
+        dwRemoteScopeId: row.dwRemoteScopeId,
+        dwRemotePort: row.dwRemotePort,
+    };
+    get_traffic(
+        |kind, rw, rod| GetPerTcp6ConnectionEStats(&row, kind, Some(rw), 0, None, 0, Some(rod), 0),
+        |kind, rw| SetPerTcp6ConnectionEStats(&row, kind, rw, 0, 0),
+    )
+}
+
+unsafe fn tcp(family: u32) -> impl Fn(*mut c_void, &mut u32) -> u32 {
+    move |table: *mut c_void, size: &mut u32| {
+        GetExtendedTcpTable(Some(table), size, false, family, TCP_TABLE_OWNER_PID_ALL, 0)
+    }
+}
+
+unsafe fn udp(family: u32) -> impl Fn(*mut c_void, &mut u32) -> u32 {
+    move |table: *mut c_void, size: &mut u32| {
+        GetExtendedUdpTable(Some(table), size, false, family, UDP_TABLE_OWNER_PID, 0)
+    }
+}
+
+pub(crate) fn get_connection_summary() -> ConnectionSummary {
+    let mut summary = ConnectionSummary::default();
+    unsafe {
+        for row in get_rows::<MIB_TCPROW_OWNER_PID>(tcp(AF_INET.0.into())) {
+            summary.add(ConnectionProtocol::Tcp, tcp_state(row.dwState));
+        }
+        for row in get_rows::<MIB_TCP6ROW_OWNER_PID>(tcp(AF_INET6.0.into())) {
+            summary.add(ConnectionProtocol::Tcp, tcp_state(row.dwState));
+        }
+        summary.udp += get_rows::<MIB_UDPROW_OWNER_PID>(udp(AF_INET.0.into())).len();
+        summary.udp += get_rows::<MIB_UDP6ROW_OWNER_PID>(udp(AF_INET6.0.into())).len();
+    }
+    summary
+}
+
+pub(crate) fn get_connections(connections: &mut Vec<Connection>) {
+    unsafe {
+        for row in get_rows::<MIB_TCPROW_OWNER_PID>(tcp(AF_INET.0.into())) {
+            let state = tcp_state(row.dwState);
+            connections.push(Connection {
+                protocol: ConnectionProtocol::Tcp,
+                local_address: ipv4(row.dwLocalAddr, row.dwLocalPort),
+                remote_address: remote_address(state, ipv4(row.dwRemoteAddr, row.dwRemotePort)),
+                state,
+                pid: Some(Pid(row.dwOwningPid as _)),
+                traffic: if state == Some(TcpState::Established) {
+                    get_traffic_v4(&row)
+                } else {
+                    None
+                },
+            });
+        }
+        for row in get_rows::<MIB_TCP6ROW_OWNER_PID>(tcp(AF_INET6.0.into())) {
+            let state = tcp_state(row.dwState);
+            connections.push(Connection {
+                protocol: ConnectionProtocol::Tcp,
+                local_address: ipv6(row.ucLocalAddr, row.dwLocalPort),
+                remote_address: remote_address(state, ipv6(row.ucRemoteAddr, row.dwRemotePort)),
+                state,
+                pid: Some(Pid(row.dwOwningPid as _)),
+                traffic: if state == Some(TcpState::Established) {
+                    get_traffic_v6(&row)
+                } else {
+                    None
+                },
+            });
+        }
+        for row in get_rows::<MIB_UDPROW_OWNER_PID>(udp(AF_INET.0.into())) {
+            connections.push(Connection {
+                protocol: ConnectionProtocol::Udp,
+                local_address: ipv4(row.dwLocalAddr, row.dwLocalPort),
+                remote_address: None,
+                state: None,
+                pid: Some(Pid(row.dwOwningPid as _)),
+                traffic: None,
+            });
+        }
+        for row in get_rows::<MIB_UDP6ROW_OWNER_PID>(udp(AF_INET6.0.into())) {
+            connections.push(Connection {
+                protocol: ConnectionProtocol::Udp,
+                local_address: ipv6(row.ucLocalAddr, row.dwLocalPort),
+                remote_address: None,
+                state: None,
+                pid: Some(Pid(row.dwOwningPid as _)),
+                traffic: None,
+            });
+        }
+    }
+}
@@ -1,7 +1,8 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::sys::tools::KeyHandler;
-use crate::{Cpu, CpuRefreshKind, LoadAvg};
+use crate::sys::utils::filetime_to_u64;
+use crate::{Cpu, CpuActivity, CpuIdleState, CpuRefreshKind, CpuTimes, LoadAvg};
 
 use std::collections::HashMap;
 use std::ffi::c_void;
@@ -9,10 +10,17 @@ use std::io::Error;
 use std::mem;
 use std::ops::DerefMut;
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use ntapi::ntexapi::{
+    SystemInterruptInformation, SystemProcessorPerformanceInformation,
+    SYSTEM_INTERRUPT_INFORMATION, SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION,
+};
 
 use windows::core::{s, PCSTR, PCWSTR};
+use windows::Wdk::System::SystemInformation::{NtQuerySystemInformation, SYSTEM_INFORMATION_CLASS};
 use windows::Win32::Foundation::{
-    CloseHandle, BOOLEAN, ERROR_INSUFFICIENT_BUFFER, ERROR_SUCCESS, FALSE, HANDLE,
+    CloseHandle, BOOLEAN, ERROR_INSUFFICIENT_BUFFER, ERROR_SUCCESS, FALSE, FILETIME, HANDLE,
 };
 use windows::Win32::System::Performance::{
     PdhAddEnglishCounterA, PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData,
@@ -25,10 +33,11 @@ use windows::Win32::System::Power::{
 use windows::Win32::System::SystemInformation;
 use windows::Win32::System::SystemInformation::{
     GetLogicalProcessorInformationEx, GetSystemInfo, RelationAll, RelationProcessorCore,
-    SYSTEM_INFO, SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
+    RelationProcessorPackage, LOGICAL_PROCESSOR_RELATIONSHIP, SYSTEM_INFO,
+    SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
 };
 use windows::Win32::System::Threading::{
-    CreateEventA, RegisterWaitForSingleObject, INFINITE, WT_EXECUTEDEFAULT,
+    CreateEventA, GetSystemTimes, RegisterWaitForSingleObject, INFINITE, WT_EXECUTEDEFAULT,
 };
 
 // This formula comes from Linux's include/linux/sched/loadavg.h
@@ -246,7 +255,11 @@ impl Query {
 pub(crate) struct CpusWrapper {
     global: Cpu,
     cpus: Vec<Cpu>,
-    got_cpu_frequency: bool,
+    activity: CpuActivity,
+    // Last time `activity` was updated, used to compute the rates.
+    last_activity_update: Option<Instant>,
+    // Idle and total times of the previous `refresh_global_cpu_usage` call.
+    global_times: Option<(u64, u64)>,
 }
 
 impl CpusWrapper {
@@ -261,7 +274,9 @@ impl CpusWrapper {
                 ),
             },
             cpus: Vec::new(),
-            got_cpu_frequency: false,
+            activity: CpuActivity::default(),
+            last_activity_update: None,
+            global_times: None,
         }
     }
 
@@ -277,10 +292,13 @@ impl CpusWrapper {
         &self.cpus
     }
 
+    pub fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus
+    }
+
     fn init_if_needed(&mut self, refresh_kind: CpuRefreshKind) {
         if self.cpus.is_empty() {
             self.cpus = init_cpus(refresh_kind);
-            self.got_cpu_frequency = refresh_kind.frequency();
         }
     }
 
@@ -294,10 +312,33 @@ impl CpusWrapper {
         self.cpus.iter_mut()
     }
 
-    pub fn get_frequencies(&mut self) {
-        if self.got_cpu_frequency {
-            return;
+    /// Uses `GetSystemTimes` to update the global CPU usage without going through the
+    /// performance counters.
+    pub fn refresh_global_cpu_usage(&mut self) {
+        let mut idle = FILETIME::default();
+        let mut kernel = FILETIME::default();
+        let mut user = FILETIME::default();
+        unsafe {
+            if GetSystemTimes(Some(&mut idle), Some(&mut kernel), Some(&mut user)).is_err() {
+                sysinfo_debug!("GetSystemTimes failed");
+                return;
+            }
+        }
+        let idle = filetime_to_u64(idle);
+        // The kernel time includes the idle time.
+        let total = filetime_to_u64(kernel).saturating_add(filetime_to_u64(user));
+        if let Some((old_idle, old_total)) = self.global_times.replace((idle, total)) {
+            let total_diff = total.saturating_sub(old_total);
+            if total_diff > 0 {
+                let idle_diff = idle.saturating_sub(old_idle).min(total_diff);
+                self.global
+                    .inner
+                    .set_cpu_usage((total_diff - idle_diff) as f32 / total_diff as f32 * 100.);
+            }
         }
+    }
+
+    pub fn get_frequencies(&mut self) {
         let frequencies = get_frequencies(self.cpus.len());
 
         for (cpu, frequency) in self.cpus.iter_mut().zip(frequencies) {
@@ -306,7 +347,35 @@ impl CpusWrapper {
         self.global
             .inner
             .set_frequency(self.cpus.first().map(|cpu| cpu.frequency()).unwrap_or(0));
-        self.got_cpu_frequency = true;
+    }
+
+    pub fn get_times(&mut self) {
+        let mut global = CpuTimes::default();
+        let (times, interrupts) = get_times(self.cpus.len());
+        for (cpu, times) in self.cpus.iter_mut().zip(times) {
+            global.user += times.user;
+            global.system += times.system;
+            global.idle += times.idle;
+            global.irq += times.irq;
+            global.softirq += times.softirq;
+            cpu.inner.times = times;
+        }
+        self.global.inner.times = global;
+
+        let (dpcs, context_switches) = get_interrupt_information(self.cpus.len());
+        let now = Instant::now();
+        self.activity.update(
+            interrupts,
+            dpcs,
+            context_switches,
+            self.last_activity_update
+                .map(|last_update| now.duration_since(last_update)),
+        );
+        self.last_activity_update = Some(now);
+    }
+
+    pub fn activity(&self) -> CpuActivity {
+        self.activity
     }
 }
 
@@ -316,7 +385,13 @@ pub(crate) struct CpuInner {
     key_used: Option<KeyHandler>,
     vendor_id: String,
     brand: String,
+    family: Option<u32>,
+    model: Option<u32>,
+    stepping: Option<u32>,
+    microcode_revision: Option<u64>,
     frequency: u64,
+    times: CpuTimes,
+    pub(crate) idle_states: Vec<CpuIdleState>,
 }
 
 impl CpuInner {
@@ -324,6 +399,11 @@ impl CpuInner {
         self.cpu_usage
     }
 
+    pub(crate) fn steal_percent(&self) -> f32 {
+        // Steal time is only reported by Linux guests.
+        0.0
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
@@ -332,6 +412,40 @@ impl CpuInner {
         self.frequency
     }
 
+    pub(crate) fn temperature(&self) -> Option<f32> {
+        // Per-core temperatures are only available through MSRs, which require a kernel driver.
+        None
+    }
+
+    pub(crate) fn times(&self) -> CpuTimes {
+        self.times
+    }
+
+    pub(crate) fn idle_states(&self) -> &[CpuIdleState] {
+        &self.idle_states
+    }
+
+    pub(crate) fn governor(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn available_governors(&self) -> &[String] {
+        &[]
+    }
+
+    pub(crate) fn scaling_min_frequency(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn scaling_max_frequency(&self) -> Option<u64> {
+        None
+    }
+
+    #[cfg(feature = "cpufreq-write")]
+    pub(crate) fn set_governor(&self, _governor: &str) -> bool {
+        false
+    }
+
     pub(crate) fn vendor_id(&self) -> &str {
         &self.vendor_id
     }
@@ -340,6 +454,22 @@ impl CpuInner {
         &self.brand
     }
 
+    pub(crate) fn family(&self) -> Option<u32> {
+        self.family
+    }
+
+    pub(crate) fn model(&self) -> Option<u32> {
+        self.model
+    }
+
+    pub(crate) fn stepping(&self) -> Option<u32> {
+        self.stepping
+    }
+
+    pub(crate) fn microcode_revision(&self) -> Option<u64> {
+        self.microcode_revision
+    }
+
     pub(crate) fn new_with_values(
         name: String,
         vendor_id: String,
@@ -352,7 +482,13 @@ impl CpuInner {
             key_used: None,
             vendor_id,
             brand,
+            family: None,
+            model: None,
+            stepping: None,
+            microcode_revision: None,
             frequency,
+            times: CpuTimes::default(),
+            idle_states: Vec::new(),
         }
     }
 
@@ -465,6 +601,37 @@ pub(crate) fn get_vendor_id_and_brand(info: &SYSTEM_INFO) -> (String, String) {
     (get_vendor_id_not_great(info), String::new())
 }
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn get_cpu_signature() -> Option<(u32, u32, u32)> {
+    Some(crate::utils::get_cpu_signature())
+}
+
+#[cfg(all(not(target_arch = "x86_64"), not(target_arch = "x86")))]
+fn get_cpu_signature() -> Option<(u32, u32, u32)> {
+    None
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn get_microcode_revision(cpu: usize) -> Option<u64> {
+    use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+
+    let value = u64::from_le_bytes(crate::sys::utils::get_reg_value_u64(
+        HKEY_LOCAL_MACHINE,
+        &format!("HARDWARE\\DESCRIPTION\\System\\CentralProcessor\\{cpu}"),
+        "Update Revision",
+    )?);
+    // Intel stores the revision in the upper 32 bits whereas AMD stores it in the lower ones.
+    match value >> 32 {
+        0 => Some(value & 0xFFFF_FFFF),
+        revision => Some(revision),
+    }
+}
+
+#[cfg(all(not(target_arch = "x86_64"), not(target_arch = "x86")))]
+fn get_microcode_revision(_cpu: usize) -> Option<u64> {
+    None
+}
+
 pub(crate) fn get_key_used(p: &mut Cpu) -> &mut Option<KeyHandler> {
     &mut p.inner.key_used
 }
@@ -500,7 +667,95 @@ pub(crate) fn get_frequencies(nb_cpus: usize) -> Vec<u64> {
     vec![0; nb_cpus]
 }
 
+/// Returns the times of each CPU and the total number of interrupts.
+pub(crate) fn get_times(nb_cpus: usize) -> (Vec<CpuTimes>, u64) {
+    let size = nb_cpus * mem::size_of::<SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION>();
+    let mut infos: Vec<SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION> = Vec::with_capacity(nb_cpus);
+    let mut returned_size = 0;
+
+    unsafe {
+        if let Err(_err) = NtQuerySystemInformation(
+            SYSTEM_INFORMATION_CLASS(SystemProcessorPerformanceInformation as _),
+            infos.as_mut_ptr() as *mut _,
+            size as _,
+            &mut returned_size,
+        ) {
+            sysinfo_debug!("get_times: NtQuerySystemInformation failed: {}", _err);
+            return (Vec::new(), 0);
+        }
+        infos.set_len(
+            (returned_size as usize / mem::size_of::<SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION>())
+                .min(nb_cpus),
+        );
+
+        let interrupts = infos.iter().map(|info| info.InterruptCount as u64).sum();
+        // Values are in 100-nanosecond intervals.
+        let to_duration = |value: i64| Duration::from_nanos((value as u64).saturating_mul(100));
+        let times = infos
+            .iter()
+            .map(|info| {
+                let idle = to_duration(*info.IdleTime.QuadPart());
+                let irq = to_duration(*info.InterruptTime.QuadPart());
+                let softirq = to_duration(*info.DpcTime.QuadPart());
+                // Kernel time includes the idle time and the time spent handling interrupts.
+                let system = to_duration(*info.KernelTime.QuadPart())
+                    .saturating_sub(idle)
+                    .saturating_sub(irq)
+                    .saturating_sub(softirq);
+                CpuTimes {
+                    user: to_duration(*info.UserTime.QuadPart()),
+                    system,
+                    idle,
+                    irq,
+                    softirq,
+                    ..Default::default()
+                }
+            })
+            .collect();
+        (times, interrupts)
+    }
+}
+
+/// Returns the total number of deferred procedure calls and of context switches.
+pub(crate) fn get_interrupt_information(nb_cpus: usize) -> (u64, u64) {
+    let size = nb_cpus * mem::size_of::<SYSTEM_INTERRUPT_INFORMATION>();
+    let mut infos: Vec<SYSTEM_INTERRUPT_INFORMATION> = Vec::with_capacity(nb_cpus);
+    let mut returned_size = 0;
+
+    unsafe {
+        if let Err(_err) = NtQuerySystemInformation(
+            SYSTEM_INFORMATION_CLASS(SystemInterruptInformation as _),
+            infos.as_mut_ptr() as *mut _,
+            size as _,
+            &mut returned_size,
+        ) {
+            sysinfo_debug!(
+                "get_interrupt_information: NtQuerySystemInformation failed: {}",
+                _err
+            );
+            return (0, 0);
+        }
+        infos.set_len(
+            (returned_size as usize / mem::size_of::<SYSTEM_INTERRUPT_INFORMATION>()).min(nb_cpus),
+        );
+    }
+    infos.iter().fold((0, 0), |(dpcs, context_switches), info| {
+        (
+            dpcs + info.DpcCount as u64,
+            context_switches + info.ContextSwitches as u64,
+        )
+    })
+}
+
 pub(crate) fn get_physical_core_count() -> Option<usize> {
+    count_processor_relationships(RelationProcessorCore)
+}
+
+pub(crate) fn get_socket_count() -> Option<usize> {
+    count_processor_relationships(RelationProcessorPackage)
+}
+
+fn count_processor_relationships(relationship: LOGICAL_PROCESSOR_RELATIONSHIP) -> Option<usize> {
     // we cannot use the number of cpus here to pre calculate the buf size
     // GetLogicalCpuInformationEx with RelationProcessorCore passed to it not only returns
     // the logical cores but also numa nodes
@@ -529,7 +784,7 @@ pub(crate) fn get_physical_core_count() -> Option<usize> {
                     Some(value) if value == ERROR_INSUFFICIENT_BUFFER.0 as i32 => {}
                     _ => {
                         sysinfo_debug!(
-                            "get_physical_core_count: GetLogicalCpuInformationEx failed"
+                            "count_processor_relationships: GetLogicalCpuInformationEx failed"
                         );
                         return None;
                     }
@@ -551,8 +806,7 @@ pub(crate) fn get_physical_core_count() -> Option<usize> {
         while i < buf.len() {
             let p = &*(raw_buf.add(i) as *const SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX);
             i += p.Size as usize;
-            if p.Relationship == RelationProcessorCore {
-                // Only count the physical cores.
+            if p.Relationship == relationship {
                 count += 1;
             }
         }
@@ -565,6 +819,7 @@ fn init_cpus(refresh_kind: CpuRefreshKind) -> Vec<Cpu> {
         let mut sys_info = SYSTEM_INFO::default();
         GetSystemInfo(&mut sys_info);
         let (vendor_id, brand) = get_vendor_id_and_brand(&sys_info);
+        let signature = get_cpu_signature();
         let nb_cpus = sys_info.dwNumberOfProcessors as usize;
         let frequencies = if refresh_kind.frequency() {
             get_frequencies(nb_cpus)
@@ -573,14 +828,19 @@ fn init_cpus(refresh_kind: CpuRefreshKind) -> Vec<Cpu> {
         };
         let mut ret = Vec::with_capacity(nb_cpus + 1);
         for (nb, frequency) in frequencies.iter().enumerate() {
-            ret.push(Cpu {
-                inner: CpuInner::new_with_values(
-                    format!("CPU {}", nb + 1),
-                    vendor_id.clone(),
-                    brand.clone(),
-                    *frequency,
-                ),
-            });
+            let mut inner = CpuInner::new_with_values(
+                format!("CPU {}", nb + 1),
+                vendor_id.clone(),
+                brand.clone(),
+                *frequency,
+            );
+            if let Some((family, model, stepping)) = signature {
+                inner.family = Some(family);
+                inner.model = Some(model);
+                inner.stepping = Some(stepping);
+            }
+            inner.microcode_revision = get_microcode_revision(nb);
+            ret.push(Cpu { inner });
         }
         ret
     }
@@ -1,24 +1,42 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Disk, DiskKind};
+use crate::utils::parse_remote_location;
+use crate::{BlockDevice, Disk, DiskKind, DiskQuota, DiskRefreshKind, Gid, PhysicalDisk, Uid};
 
 use std::ffi::{c_void, OsStr, OsString};
 use std::mem::size_of;
 use std::os::windows::ffi::OsStringExt;
 use std::path::Path;
+use std::time::Duration;
 
-use windows::core::{Error, HRESULT, PCWSTR};
-use windows::Win32::Foundation::{CloseHandle, HANDLE, MAX_PATH};
+use windows::core::{Error, HRESULT, PCWSTR, PWSTR};
+use windows::Win32::Devices::DeviceAndDriverInstallation::{
+    CM_Register_Notification, CM_Unregister_Notification, CM_NOTIFY_ACTION, CM_NOTIFY_EVENT_DATA,
+    CM_NOTIFY_FILTER, CM_NOTIFY_FILTER_TYPE_DEVICEINTERFACE, CR_SUCCESS, HCMNOTIFICATION,
+};
+use windows::Win32::Foundation::{CloseHandle, BOOL, HANDLE, MAX_PATH, NO_ERROR, WAIT_OBJECT_0};
+use windows::Win32::NetworkManagement::WNet::WNetGetConnectionW;
 use windows::Win32::Storage::FileSystem::{
-    CreateFileW, FindFirstVolumeW, FindNextVolumeW, FindVolumeClose, GetDiskFreeSpaceExW,
-    GetDriveTypeW, GetVolumeInformationW, GetVolumePathNamesForVolumeNameW, FILE_ACCESS_RIGHTS,
-    FILE_SHARE_READ, FILE_SHARE_WRITE, OPEN_EXISTING,
+    CreateFileW, DiskQuotaControl, FindFirstVolumeW, FindNextVolumeW, FindVolumeClose,
+    GetDiskFreeSpaceExW, GetDriveTypeW, GetLogicalDrives, GetVolumeInformationW,
+    GetVolumePathNamesForVolumeNameW, IDiskQuotaControl, DISKQUOTA_STATE_DISABLED,
+    DISKQUOTA_STATE_MASK, DISKQUOTA_USERNAME_RESOLVE_NONE, FILE_ACCESS_RIGHTS, FILE_SHARE_READ,
+    FILE_SHARE_WRITE, OPEN_EXISTING,
+};
+use windows::Win32::System::Com::{
+    CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_INPROC_SERVER,
 };
 use windows::Win32::System::Ioctl::{
-    PropertyStandardQuery, StorageDeviceSeekPenaltyProperty, DEVICE_SEEK_PENALTY_DESCRIPTOR,
-    IOCTL_STORAGE_QUERY_PROPERTY, STORAGE_PROPERTY_QUERY,
+    PropertyStandardQuery, StorageAccessAlignmentProperty, StorageDeviceProperty,
+    StorageDeviceSeekPenaltyProperty, StorageDeviceTemperatureProperty, StorageDeviceTrimProperty,
+    DEVICE_SEEK_PENALTY_DESCRIPTOR, DEVICE_TRIM_DESCRIPTOR, GET_LENGTH_INFORMATION,
+    GUID_DEVINTERFACE_VOLUME, IOCTL_DISK_GET_LENGTH_INFO, IOCTL_STORAGE_QUERY_PROPERTY,
+    IOCTL_VOLUME_GET_VOLUME_DISK_EXTENTS, STORAGE_ACCESS_ALIGNMENT_DESCRIPTOR,
+    STORAGE_DEVICE_DESCRIPTOR, STORAGE_PROPERTY_ID, STORAGE_PROPERTY_QUERY,
+    STORAGE_TEMPERATURE_DATA_DESCRIPTOR, VOLUME_DISK_EXTENTS,
 };
-use windows::Win32::System::WindowsProgramming::{DRIVE_FIXED, DRIVE_REMOVABLE};
+use windows::Win32::System::Threading::{CreateEventW, SetEvent, WaitForSingleObject, INFINITE};
+use windows::Win32::System::WindowsProgramming::{DRIVE_FIXED, DRIVE_REMOTE, DRIVE_REMOVABLE};
 use windows::Win32::System::IO::DeviceIoControl;
 
 /// Creates a copy of the first zero-terminated wide string in `buf`.
@@ -36,6 +54,13 @@ const VOLUME_NAME_SIZE: usize = MAX_PATH as usize + 1;
 const ERROR_NO_MORE_FILES: HRESULT = windows::Win32::Foundation::ERROR_NO_MORE_FILES.to_hresult();
 const ERROR_MORE_DATA: HRESULT = windows::Win32::Foundation::ERROR_MORE_DATA.to_hresult();
 
+// Physical drives are numbered from `0` but there can be holes in the numbering when a drive is
+// removed, so we can't stop at the first missing one.
+pub(crate) const MAX_PHYSICAL_DRIVES: u32 = 64;
+
+// Volumes get their mount points a bit after their arrival is notified.
+const VOLUME_MOUNT_DELAY: Duration = Duration::from_millis(500);
+
 /// Returns a list of zero-terminated wide strings containing volume GUID paths.
 /// Volume GUID paths have the form `\\?\{xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx}\`.
 ///
@@ -125,6 +150,18 @@ pub(crate) struct DiskInner {
     total_space: u64,
     available_space: u64,
     is_removable: bool,
+    uuid: Option<String>,
+    vendor: Option<String>,
+    model: Option<String>,
+    serial_number: Option<String>,
+    physical_disk: Option<String>,
+    is_rotational: Option<bool>,
+    logical_sector_size: Option<u64>,
+    physical_sector_size: Option<u64>,
+    supports_trim: Option<bool>,
+    is_network: bool,
+    remote_host: Option<String>,
+    remote_path: Option<String>,
 }
 
 impl DiskInner {
@@ -156,6 +193,74 @@ impl DiskInner {
         self.is_removable
     }
 
+    pub(crate) fn uuid(&self) -> Option<&str> {
+        self.uuid.as_deref()
+    }
+
+    pub(crate) fn vendor(&self) -> Option<&str> {
+        self.vendor.as_deref()
+    }
+
+    pub(crate) fn model(&self) -> Option<&str> {
+        self.model.as_deref()
+    }
+
+    pub(crate) fn serial_number(&self) -> Option<&str> {
+        self.serial_number.as_deref()
+    }
+
+    pub(crate) fn physical_disk(&self) -> Option<&str> {
+        self.physical_disk.as_deref()
+    }
+
+    pub(crate) fn storage_pool(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn block_device(&self) -> Option<&BlockDevice> {
+        None
+    }
+
+    pub(crate) fn is_rotational(&self) -> Option<bool> {
+        self.is_rotational
+    }
+
+    pub(crate) fn logical_sector_size(&self) -> Option<u64> {
+        self.logical_sector_size
+    }
+
+    pub(crate) fn physical_sector_size(&self) -> Option<u64> {
+        self.physical_sector_size
+    }
+
+    pub(crate) fn supports_trim(&self) -> Option<bool> {
+        self.supports_trim
+    }
+
+    pub(crate) fn user_quota(&self, user_id: &Uid) -> Option<DiskQuota> {
+        if self.is_network {
+            return None;
+        }
+        unsafe { get_user_quota(&self.mount_point, user_id) }
+    }
+
+    pub(crate) fn group_quota(&self, _group_id: Gid) -> Option<DiskQuota> {
+        // NTFS quotas are only tracked per user.
+        None
+    }
+
+    pub(crate) fn is_network(&self) -> bool {
+        self.is_network
+    }
+
+    pub(crate) fn remote_host(&self) -> Option<&str> {
+        self.remote_host.as_deref()
+    }
+
+    pub(crate) fn remote_path(&self) -> Option<&str> {
+        self.remote_path.as_deref()
+    }
+
     pub(crate) fn refresh(&mut self) -> bool {
         if self.total_space != 0 {
             unsafe {
@@ -173,29 +278,47 @@ impl DiskInner {
 
 pub(crate) struct DisksInner {
     pub(crate) disks: Vec<Disk>,
+    physical_disks: Vec<PhysicalDisk>,
 }
 
 impl DisksInner {
     pub(crate) fn new() -> Self {
         Self {
             disks: Vec::with_capacity(2),
+            physical_disks: Vec::new(),
         }
     }
 
     pub(crate) fn from_vec(disks: Vec<Disk>) -> Self {
-        Self { disks }
+        Self {
+            disks,
+            physical_disks: Vec::new(),
+        }
     }
 
     pub(crate) fn into_vec(self) -> Vec<Disk> {
         self.disks
     }
 
-    pub(crate) fn refresh_list(&mut self) {
+    pub(crate) fn refresh_list(&mut self, refresh_kind: DiskRefreshKind) {
         unsafe {
             self.disks = get_list();
+            if refresh_kind.network_disks() {
+                self.disks
+                    .extend(get_network_disks(refresh_kind.network_disks_space()));
+            }
+            self.physical_disks = get_physical_disks();
         }
     }
 
+    pub(crate) fn physical_disks(&self) -> &[PhysicalDisk] {
+        &self.physical_disks
+    }
+
+    pub(crate) fn storage_pools(&self) -> &[crate::StoragePool] {
+        &[]
+    }
+
     pub(crate) fn list(&self) -> &[Disk] {
         &self.disks
     }
@@ -302,38 +425,29 @@ pub(crate) unsafe fn get_list() -> Vec<Disk> {
                 sysinfo_debug!("total_space == 0");
                 return Vec::new();
             }
-            let spq_trim = STORAGE_PROPERTY_QUERY {
-                PropertyId: StorageDeviceSeekPenaltyProperty,
-                QueryType: PropertyStandardQuery,
-                AdditionalParameters: [0],
-            };
-            let mut result: DEVICE_SEEK_PENALTY_DESCRIPTOR = std::mem::zeroed();
-
-            let mut dw_size = 0;
-            let device_io_control = DeviceIoControl(
-                handle.0,
-                IOCTL_STORAGE_QUERY_PROPERTY,
-                Some(&spq_trim as *const STORAGE_PROPERTY_QUERY as *const c_void),
-                size_of::<STORAGE_PROPERTY_QUERY>() as u32,
-                Some(&mut result as *mut DEVICE_SEEK_PENALTY_DESCRIPTOR as *mut c_void),
-                size_of::<DEVICE_SEEK_PENALTY_DESCRIPTOR>() as u32,
-                Some(&mut dw_size),
-                None,
+            let is_rotational = get_seek_penalty(&handle);
+            let type_ = get_disk_kind(is_rotational);
+            let alignment = query_storage_property::<STORAGE_ACCESS_ALIGNMENT_DESCRIPTOR>(
+                &handle,
+                StorageAccessAlignmentProperty,
+            );
+            let supports_trim = query_storage_property::<DEVICE_TRIM_DESCRIPTOR>(
+                &handle,
+                StorageDeviceTrimProperty,
             )
-            .is_ok();
-            let type_ = if !device_io_control
-                || dw_size != size_of::<DEVICE_SEEK_PENALTY_DESCRIPTOR>() as u32
-            {
-                DiskKind::Unknown(-1)
-            } else {
-                let is_hdd = result.IncursSeekPenalty.as_bool();
-                if is_hdd {
-                    DiskKind::HDD
-                } else {
-                    DiskKind::SSD
-                }
+            .map(|trim| trim.TrimEnabled.as_bool());
+            let (vendor, model, serial_number) = match get_device_descriptor(&handle) {
+                Some(descriptor) => (
+                    descriptor.vendor,
+                    descriptor.model,
+                    descriptor.serial_number,
+                ),
+                None => (None, None, None),
             };
+            let physical_disk =
+                get_physical_drive_number(&handle).map(|number| format!("PhysicalDrive{}", number));
 
+            let uuid = get_volume_guid(&volume_name);
             let name = os_string_from_zero_terminated(&name);
             let file_system = os_string_from_zero_terminated(&file_system);
             mount_paths
@@ -348,6 +462,22 @@ pub(crate) unsafe fn get_list() -> Vec<Disk> {
                         total_space,
                         available_space,
                         is_removable,
+                        uuid: uuid.clone(),
+                        vendor: vendor.clone(),
+                        model: model.clone(),
+                        serial_number: serial_number.clone(),
+                        physical_disk: physical_disk.clone(),
+                        is_rotational,
+                        logical_sector_size: alignment
+                            .as_ref()
+                            .map(|alignment| u64::from(alignment.BytesPerLogicalSector)),
+                        physical_sector_size: alignment
+                            .as_ref()
+                            .map(|alignment| u64::from(alignment.BytesPerPhysicalSector)),
+                        supports_trim,
+                        is_network: false,
+                        remote_host: None,
+                        remote_path: None,
                     },
                 })
                 .collect::<Vec<_>>()
@@ -355,7 +485,474 @@ pub(crate) unsafe fn get_list() -> Vec<Disk> {
         .collect::<Vec<_>>()
 }
 
+/// Returns the network drives (which aren't volumes, so they're not returned by `get_list`).
+unsafe fn get_network_disks(refresh_space: bool) -> Vec<Disk> {
+    let drives = GetLogicalDrives();
+    (0..26)
+        .filter(|index| drives & (1 << index) != 0)
+        .filter_map(|index| {
+            let letter = char::from(b'A' + index as u8);
+            let mount_point = format!("{}:\\", letter)
+                .encode_utf16()
+                .chain([0])
+                .collect::<Vec<_>>();
+            if GetDriveTypeW(PCWSTR::from_raw(mount_point.as_ptr())) != DRIVE_REMOTE {
+                return None;
+            }
+            // The local name must not have a trailing backslash.
+            let local_name = format!("{}:", letter)
+                .encode_utf16()
+                .chain([0])
+                .collect::<Vec<_>>();
+            let mut remote_name = [0u16; MAX_PATH as usize + 1];
+            let mut len = remote_name.len() as u32;
+            let remote_name = if WNetGetConnectionW(
+                PCWSTR::from_raw(local_name.as_ptr()),
+                PWSTR::from_raw(remote_name.as_mut_ptr()),
+                &mut len,
+            ) == NO_ERROR
+            {
+                os_string_from_zero_terminated(&remote_name)
+            } else {
+                sysinfo_debug!("Error: WNetGetConnectionW failed for {}", letter);
+                OsString::new()
+            };
+            let (remote_host, remote_path) =
+                match remote_name.to_str().and_then(parse_remote_location) {
+                    Some((host, path)) => (Some(host), Some(path)),
+                    None => (None, None),
+                };
+
+            let mut file_system = OsString::new();
+            let mut total_space = 0;
+            let mut available_space = 0;
+            // Both calls need to contact the server, which may hang.
+            if refresh_space {
+                let mut file_system_buf = [0u16; 32];
+                if GetVolumeInformationW(
+                    PCWSTR::from_raw(mount_point.as_ptr()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(&mut file_system_buf),
+                )
+                .is_ok()
+                {
+                    file_system = os_string_from_zero_terminated(&file_system_buf);
+                }
+                (total_space, available_space) = get_drive_size(&mount_point)?;
+            }
+
+            Some(Disk {
+                inner: DiskInner {
+                    type_: DiskKind::Unknown(-1),
+                    name: remote_name,
+                    file_system,
+                    s_mount_point: OsString::from_wide(&mount_point[..mount_point.len() - 1]),
+                    mount_point,
+                    total_space,
+                    available_space,
+                    is_removable: false,
+                    uuid: None,
+                    vendor: None,
+                    model: None,
+                    serial_number: None,
+                    physical_disk: None,
+                    is_rotational: None,
+                    logical_sector_size: None,
+                    physical_sector_size: None,
+                    supports_trim: None,
+                    is_network: true,
+                    remote_host,
+                    remote_path,
+                },
+            })
+        })
+        .collect()
+}
+
+unsafe fn get_physical_disks() -> Vec<PhysicalDisk> {
+    (0..MAX_PHYSICAL_DRIVES)
+        .filter_map(|number| {
+            let name = format!("PhysicalDrive{}", number);
+            let path = format!("\\\\.\\{}", name)
+                .encode_utf16()
+                .chain([0])
+                .collect::<Vec<_>>();
+            let handle = HandleWrapper::new(&path, Default::default())?;
+
+            let mut length_info: GET_LENGTH_INFORMATION = std::mem::zeroed();
+            let mut dw_size = 0;
+            if DeviceIoControl(
+                handle.0,
+                IOCTL_DISK_GET_LENGTH_INFO,
+                None,
+                0,
+                Some(&mut length_info as *mut GET_LENGTH_INFORMATION as *mut c_void),
+                size_of::<GET_LENGTH_INFORMATION>() as u32,
+                Some(&mut dw_size),
+                None,
+            )
+            .is_err()
+            {
+                sysinfo_debug!("Error: failed to get the length of {}", name);
+                return None;
+            }
+            Some(PhysicalDisk {
+                kind: get_disk_kind(get_seek_penalty(&handle)),
+                total_space: length_info.Length as u64,
+                is_removable: get_device_descriptor(&handle)
+                    .map(|descriptor| descriptor.is_removable)
+                    .unwrap_or(false),
+                name,
+            })
+        })
+        .collect()
+}
+
+pub(crate) struct DriveTemperature {
+    pub(crate) label: String,
+    /// In degrees Celsius.
+    pub(crate) temperature: f32,
+    /// In degrees Celsius.
+    pub(crate) critical: Option<f32>,
+}
+
+/// Returns the temperature reported by the physical drive `number`. Like for
+/// [`get_physical_disks`], the drive is opened without any access right so it doesn't require
+/// to be administrator.
+pub(crate) unsafe fn get_physical_drive_temperature(number: u32) -> Option<DriveTemperature> {
+    let name = format!("PhysicalDrive{}", number);
+    let path = format!("\\\\.\\{}", name)
+        .encode_utf16()
+        .chain([0])
+        .collect::<Vec<_>>();
+    let handle = HandleWrapper::new(&path, Default::default())?;
+
+    let query = STORAGE_PROPERTY_QUERY {
+        PropertyId: StorageDeviceTemperatureProperty,
+        QueryType: PropertyStandardQuery,
+        AdditionalParameters: [0],
+    };
+    // NVMe drives can have more than one sensor, their information is stored after the
+    // descriptor. `u32` is used to get the alignment of `STORAGE_TEMPERATURE_DATA_DESCRIPTOR`.
+    let mut buf = [0u32; 64];
+    let mut dw_size = 0;
+    if DeviceIoControl(
+        handle.0,
+        IOCTL_STORAGE_QUERY_PROPERTY,
+        Some(&query as *const STORAGE_PROPERTY_QUERY as *const c_void),
+        size_of::<STORAGE_PROPERTY_QUERY>() as u32,
+        Some(buf.as_mut_ptr() as *mut c_void),
+        (buf.len() * size_of::<u32>()) as u32,
+        Some(&mut dw_size),
+        None,
+    )
+    .is_err()
+        || (dw_size as usize) < size_of::<STORAGE_TEMPERATURE_DATA_DESCRIPTOR>()
+    {
+        return None;
+    }
+    let descriptor = &*(buf.as_ptr() as *const STORAGE_TEMPERATURE_DATA_DESCRIPTOR);
+    let (temperature, critical) = get_drive_temperatures(descriptor)?;
+    let label = get_device_descriptor(&handle)
+        .and_then(|descriptor| descriptor.model)
+        .unwrap_or(name);
+    Some(DriveTemperature {
+        label,
+        temperature,
+        critical,
+    })
+}
+
+/// Returns the temperature and the critical temperature of the drive.
+fn get_drive_temperatures(
+    descriptor: &STORAGE_TEMPERATURE_DATA_DESCRIPTOR,
+) -> Option<(f32, Option<f32>)> {
+    if descriptor.InfoCount == 0 {
+        return None;
+    }
+    // The first sensor is the one reporting the temperature of the whole drive.
+    let temperature = descriptor.TemperatureInfo[0].Temperature;
+    // Temperatures are `0` when they aren't reported.
+    let critical = Some(descriptor.CriticalTemperature)
+        .filter(|critical| *critical != 0)
+        .map(f32::from);
+    Some((f32::from(temperature), critical))
+}
+
+/// Returns the number of the physical drive containing the volume. If the volume spans more
+/// than one drive, `None` is returned.
+unsafe fn get_physical_drive_number(volume_handle: &HandleWrapper) -> Option<u32> {
+    // If there is more than one extent, this call fails with `ERROR_MORE_DATA`.
+    let mut extents: VOLUME_DISK_EXTENTS = std::mem::zeroed();
+    let mut dw_size = 0;
+    DeviceIoControl(
+        volume_handle.0,
+        IOCTL_VOLUME_GET_VOLUME_DISK_EXTENTS,
+        None,
+        0,
+        Some(&mut extents as *mut VOLUME_DISK_EXTENTS as *mut c_void),
+        size_of::<VOLUME_DISK_EXTENTS>() as u32,
+        Some(&mut dw_size),
+        None,
+    )
+    .ok()?;
+    if extents.NumberOfDiskExtents == 1 {
+        Some(extents.Extents[0].DiskNumber)
+    } else {
+        None
+    }
+}
+
+/// Returns the GUID of a volume from its name (like `\\?\Volume{GUID}\`).
+fn get_volume_guid(volume_name: &[u16]) -> Option<String> {
+    let volume_name = String::from_utf16_lossy(volume_name);
+    let start = volume_name.find('{')?;
+    let end = volume_name[start..].find('}')?;
+    Some(volume_name[start + 1..start + end].to_owned())
+}
+
+/// Queries a fixed-size storage property of the device.
+unsafe fn query_storage_property<T>(
+    handle: &HandleWrapper,
+    property_id: STORAGE_PROPERTY_ID,
+) -> Option<T> {
+    let query = STORAGE_PROPERTY_QUERY {
+        PropertyId: property_id,
+        QueryType: PropertyStandardQuery,
+        AdditionalParameters: [0],
+    };
+    let mut result: T = std::mem::zeroed();
+
+    let mut dw_size = 0;
+    let device_io_control = DeviceIoControl(
+        handle.0,
+        IOCTL_STORAGE_QUERY_PROPERTY,
+        Some(&query as *const STORAGE_PROPERTY_QUERY as *const c_void),
+        size_of::<STORAGE_PROPERTY_QUERY>() as u32,
+        Some(&mut result as *mut T as *mut c_void),
+        size_of::<T>() as u32,
+        Some(&mut dw_size),
+        None,
+    )
+    .is_ok();
+    if !device_io_control || dw_size != size_of::<T>() as u32 {
+        None
+    } else {
+        Some(result)
+    }
+}
+
+/// Returns `true` if the device incurs a seek penalty, meaning that it is rotational.
+unsafe fn get_seek_penalty(handle: &HandleWrapper) -> Option<bool> {
+    query_storage_property::<DEVICE_SEEK_PENALTY_DESCRIPTOR>(
+        handle,
+        StorageDeviceSeekPenaltyProperty,
+    )
+    .map(|result| result.IncursSeekPenalty.as_bool())
+}
+
+fn get_disk_kind(is_rotational: Option<bool>) -> DiskKind {
+    match is_rotational {
+        Some(true) => DiskKind::HDD,
+        Some(false) => DiskKind::SSD,
+        None => DiskKind::Unknown(-1),
+    }
+}
+
+struct DeviceDescriptor {
+    vendor: Option<String>,
+    model: Option<String>,
+    serial_number: Option<String>,
+    is_removable: bool,
+}
+
+unsafe fn get_device_descriptor(handle: &HandleWrapper) -> Option<DeviceDescriptor> {
+    let query = STORAGE_PROPERTY_QUERY {
+        PropertyId: StorageDeviceProperty,
+        QueryType: PropertyStandardQuery,
+        AdditionalParameters: [0],
+    };
+    // The strings are stored after the `STORAGE_DEVICE_DESCRIPTOR` and 1024 bytes is more than
+    // enough for them. `u32` is used to get the alignment of `STORAGE_DEVICE_DESCRIPTOR`.
+    let mut buf = [0u32; 256];
+    let mut dw_size = 0;
+    if DeviceIoControl(
+        handle.0,
+        IOCTL_STORAGE_QUERY_PROPERTY,
+        Some(&query as *const STORAGE_PROPERTY_QUERY as *const c_void),
+        size_of::<STORAGE_PROPERTY_QUERY>() as u32,
+        Some(buf.as_mut_ptr() as *mut c_void),
+        (buf.len() * size_of::<u32>()) as u32,
+        Some(&mut dw_size),
+        None,
+    )
+    .is_err()
+        || (dw_size as usize) < size_of::<STORAGE_DEVICE_DESCRIPTOR>()
+    {
+        sysinfo_debug!("Error: failed to get the storage device descriptor");
+        return None;
+    }
+    let descriptor = &*(buf.as_ptr() as *const STORAGE_DEVICE_DESCRIPTOR);
+    let bytes = std::slice::from_raw_parts(buf.as_ptr() as *const u8, dw_size as usize);
+    // Offsets are `0` when the information isn't available.
+    let get_string = |offset: u32| -> Option<String> {
+        let s = bytes.get(offset as usize..).filter(|_| offset != 0)?;
+        let end = s.iter().position(|c| *c == 0).unwrap_or(s.len());
+        let s = String::from_utf8_lossy(&s[..end]).trim().to_owned();
+        if s.is_empty() {
+            None
+        } else {
+            Some(s)
+        }
+    };
+    Some(DeviceDescriptor {
+        vendor: get_string(descriptor.VendorIdOffset),
+        model: get_string(descriptor.ProductIdOffset),
+        serial_number: get_string(descriptor.SerialNumberOffset),
+        is_removable: descriptor.RemovableMedia.as_bool(),
+    })
+}
+
+struct ComInitializer(bool);
+
+impl Drop for ComInitializer {
+    fn drop(&mut self) {
+        if self.0 {
+            unsafe { CoUninitialize() };
+        }
+    }
+}
+
+/// Returns the NTFS quota of the user on the volume mounted on `mount_point`.
+unsafe fn get_user_quota(mount_point: &[u16], user_id: &Uid) -> Option<DiskQuota> {
+    let _com = ComInitializer(CoInitializeEx(None, Default::default()).is_ok());
+    let control: IDiskQuotaControl =
+        match CoCreateInstance(&DiskQuotaControl, None, CLSCTX_INPROC_SERVER) {
+            Ok(control) => control,
+            Err(_e) => {
+                sysinfo_debug!("failed to create disk quota control: {:?}", _e);
+                return None;
+            }
+        };
+    // Quotas are only read so the volume doesn't need to be opened in read-write mode.
+    control
+        .Initialize(PCWSTR::from_raw(mount_point.as_ptr()), BOOL::from(false))
+        .ok()?;
+    let mut state = 0;
+    control.GetQuotaState(&mut state).ok()?;
+    if state & DISKQUOTA_STATE_MASK == DISKQUOTA_STATE_DISABLED {
+        return None;
+    }
+    let user = control
+        .FindUserSid(user_id.0.as_psid(), DISKQUOTA_USERNAME_RESOLVE_NONE)
+        .ok()?;
+    let (mut used, mut threshold, mut limit) = (0i64, 0i64, 0i64);
+    user.GetQuotaUsed(&mut used).ok()?;
+    user.GetQuotaThreshold(&mut threshold).ok()?;
+    user.GetQuotaLimit(&mut limit).ok()?;
+    // Negative values (`-1` usually) mean that there is no limit.
+    let to_limit = |value: i64| u64::try_from(value).ok();
+    Some(DiskQuota {
+        used_space: used.max(0) as u64,
+        soft_space_limit: to_limit(threshold),
+        hard_space_limit: to_limit(limit),
+        used_inodes: None,
+        soft_inode_limit: None,
+        hard_inode_limit: None,
+    })
+}
+
 fn os_string_from_zero_terminated(name: &[u16]) -> OsString {
     let len = name.iter().position(|&x| x == 0).unwrap_or(name.len());
     OsString::from_wide(&name[..len])
 }
+
+pub(crate) struct DiskEventsInner {
+    // Signaled every time a volume is added or removed.
+    event: HandleWrapper,
+    notification: HCMNOTIFICATION,
+}
+
+impl DiskEventsInner {
+    pub(crate) fn new() -> Option<Self> {
+        unsafe {
+            let event = match CreateEventW(None, false, false, PCWSTR::null()) {
+                Ok(event) => HandleWrapper(event),
+                Err(_e) => {
+                    sysinfo_debug!("CreateEventW failed: {:?}", _e);
+                    return None;
+                }
+            };
+            let mut filter: CM_NOTIFY_FILTER = std::mem::zeroed();
+            filter.cbSize = size_of::<CM_NOTIFY_FILTER>() as u32;
+            filter.FilterType = CM_NOTIFY_FILTER_TYPE_DEVICEINTERFACE;
+            filter.u.DeviceInterface.ClassGuid = GUID_DEVINTERFACE_VOLUME;
+            let mut notification = HCMNOTIFICATION::default();
+            let ret = CM_Register_Notification(
+                &filter,
+                Some(event.0 .0 as *const c_void),
+                Some(on_volume_notification),
+                &mut notification,
+            );
+            if ret != CR_SUCCESS {
+                sysinfo_debug!("CM_Register_Notification failed: {:?}", ret);
+                return None;
+            }
+            Some(Self {
+                event,
+                notification,
+            })
+        }
+    }
+
+    pub(crate) fn wait(&mut self, timeout: Option<Duration>) -> bool {
+        let timeout = timeout.map_or(INFINITE, |timeout| {
+            timeout.as_millis().min(u128::from(INFINITE - 1)) as u32
+        });
+        if unsafe { WaitForSingleObject(self.event.0, timeout) } != WAIT_OBJECT_0 {
+            return false;
+        }
+        std::thread::sleep(VOLUME_MOUNT_DELAY);
+        true
+    }
+}
+
+impl Drop for DiskEventsInner {
+    fn drop(&mut self) {
+        // It waits for the running callbacks, so the event is still valid for them.
+        let _err = unsafe { CM_Unregister_Notification(self.notification) };
+    }
+}
+
+unsafe extern "system" fn on_volume_notification(
+    _notification: HCMNOTIFICATION,
+    context: *const c_void,
+    _action: CM_NOTIFY_ACTION,
+    _event_data: *const CM_NOTIFY_EVENT_DATA,
+    _event_data_size: u32,
+) -> u32 {
+    let _err = SetEvent(HANDLE(context as isize));
+    0
+}
+
+#[cfg(test)]
+mod test {
+    use super::get_drive_temperatures;
+    use windows::Win32::System::Ioctl::STORAGE_TEMPERATURE_DATA_DESCRIPTOR;
+
+    #[test]
+    fn check_drive_temperatures() {
+        let mut descriptor: STORAGE_TEMPERATURE_DATA_DESCRIPTOR = unsafe { std::mem::zeroed() };
+        descriptor.TemperatureInfo[0].Temperature = 38;
+        // No sensor.
+        assert_eq!(get_drive_temperatures(&descriptor), None);
+
+        descriptor.InfoCount = 1;
+        assert_eq!(get_drive_temperatures(&descriptor), Some((38., None)));
+        descriptor.CriticalTemperature = 84;
+        assert_eq!(get_drive_temperatures(&descriptor), Some((38., Some(84.))));
+    }
+}
@@ -0,0 +1,241 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::get_device_interface_paths;
+use crate::EnergyDomain;
+
+use std::ffi::c_void;
+use std::mem::size_of;
+use std::time::Instant;
+
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::{CloseHandle, HANDLE};
+use windows::Win32::Storage::FileSystem::{
+    CreateFileW, FILE_GENERIC_READ, FILE_SHARE_READ, FILE_SHARE_WRITE, OPEN_EXISTING,
+};
+use windows::Win32::System::Power::{
+    GUID_DEVICE_ENERGY_METER, IOCTL_EMI_GET_MEASUREMENT, IOCTL_EMI_GET_METADATA,
+    IOCTL_EMI_GET_METADATA_SIZE, IOCTL_EMI_GET_VERSION,
+};
+use windows::Win32::System::IO::DeviceIoControl;
+
+// Version of the Energy Meter Interface supporting multiple channels.
+const EMI_VERSION_V2: u16 = 2;
+// Offset of `ChannelCount` in `EMI_METADATA_V2` (after `HardwareOEM`, `HardwareModel` and
+// `HardwareRevision`).
+const CHANNEL_COUNT_OFFSET: usize = 16 * 2 + 16 * 2 + 2;
+// Offset of `ChannelName` in `EMI_CHANNEL_V2` (after `MeasurementUnit` and `ChannelNameSize`).
+const CHANNEL_NAME_OFFSET: usize = 4 + 2;
+
+struct HandleWrapper(HANDLE);
+
+impl Drop for HandleWrapper {
+    fn drop(&mut self) {
+        let _err = unsafe { CloseHandle(self.0) };
+    }
+}
+
+struct EnergyMeter {
+    handle: HandleWrapper,
+    nb_channels: usize,
+    // `(channel index, domain index)` of the RAPL channels.
+    channels: Vec<(usize, usize)>,
+    // Last value (in picowatt-hours) of each channel.
+    last_energy: Vec<u64>,
+}
+
+pub(crate) struct EnergyWrapper {
+    domains: Vec<EnergyDomain>,
+    meters: Vec<EnergyMeter>,
+    last_update: Option<Instant>,
+}
+
+impl EnergyWrapper {
+    pub(crate) fn new() -> Self {
+        Self {
+            domains: Vec::new(),
+            meters: Vec::new(),
+            last_update: None,
+        }
+    }
+
+    pub(crate) fn domains(&self) -> &[EnergyDomain] {
+        &self.domains
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        let now = Instant::now();
+        if self.last_update.is_none() {
+            unsafe { self.init() };
+            self.last_update = Some(now);
+            return;
+        }
+        let elapsed = self.last_update.map(|last| now.duration_since(last));
+        for meter in self.meters.iter_mut() {
+            let measurements = match unsafe { get_measurements(&meter.handle, meter.nb_channels) } {
+                Some(measurements) => measurements,
+                None => continue,
+            };
+            for (channel, domain) in meter.channels.iter() {
+                let energy = measurements[*channel];
+                let consumed = energy.saturating_sub(meter.last_energy[*channel]);
+                meter.last_energy[*channel] = energy;
+                // 1 picowatt-hour is 3.6 nanojoules.
+                self.domains[*domain].update(consumed as f64 * 3.6e-9, elapsed);
+            }
+        }
+        self.last_update = Some(now);
+    }
+
+    unsafe fn init(&mut self) {
+        for path in get_device_interface_paths(&GUID_DEVICE_ENERGY_METER) {
+            self.add_meter(&path);
+        }
+    }
+
+    unsafe fn add_meter(&mut self, path: &[u16]) {
+        let handle = match CreateFileW(
+            PCWSTR::from_raw(path.as_ptr()),
+            FILE_GENERIC_READ.0,
+            FILE_SHARE_READ | FILE_SHARE_WRITE,
+            None,
+            OPEN_EXISTING,
+            Default::default(),
+            HANDLE::default(),
+        ) {
+            Ok(handle) => HandleWrapper(handle),
+            Err(_e) => {
+                sysinfo_debug!("failed to open energy meter: {:?}", _e);
+                return;
+            }
+        };
+        let mut version = 0u16;
+        if !device_io_control(
+            &handle,
+            IOCTL_EMI_GET_VERSION,
+            &mut version as *mut u16 as *mut c_void,
+            size_of::<u16>(),
+        ) || version != EMI_VERSION_V2
+        {
+            return;
+        }
+        let names = match get_channel_names(&handle) {
+            Some(names) => names,
+            None => return,
+        };
+        let last_energy = match get_measurements(&handle, names.len()) {
+            Some(measurements) => measurements,
+            None => return,
+        };
+        let mut channels = Vec::new();
+        for (channel, name) in names.iter().enumerate() {
+            if let Some((name, package)) = parse_rapl_channel_name(name) {
+                channels.push((channel, self.domains.len()));
+                self.domains.push(EnergyDomain::new(name, package));
+            }
+        }
+        if !channels.is_empty() {
+            self.meters.push(EnergyMeter {
+                handle,
+                nb_channels: names.len(),
+                channels,
+                last_energy,
+            });
+        }
+    }
+}
+
+unsafe fn device_io_control(
+    handle: &HandleWrapper,
+    code: u32,
+    out: *mut c_void,
+    out_size: usize,
+) -> bool {
+    let mut returned = 0;
+    DeviceIoControl(
+        handle.0,
+        code,
+        None,
+        0,
+        Some(out),
+        out_size as _,
+        Some(&mut returned),
+        None,
+    )
+    .is_ok()
+}
+
+unsafe fn get_channel_names(handle: &HandleWrapper) -> Option<Vec<String>> {
+    let mut metadata_size = 0u32;
+    if !device_io_control(
+        handle,
+        IOCTL_EMI_GET_METADATA_SIZE,
+        &mut metadata_size as *mut u32 as *mut c_void,
+        size_of::<u32>(),
+    ) {
+        return None;
+    }
+    let mut metadata = vec![0u8; metadata_size as usize];
+    if metadata.len() < CHANNEL_COUNT_OFFSET + 2
+        || !device_io_control(
+            handle,
+            IOCTL_EMI_GET_METADATA,
+            metadata.as_mut_ptr() as *mut c_void,
+            metadata.len(),
+        )
+    {
+        return None;
+    }
+    let read_u16 = |offset: usize| -> Option<u16> {
+        metadata
+            .get(offset..offset + 2)
+            .map(|bytes| u16::from_ne_bytes([bytes[0], bytes[1]]))
+    };
+
+    let nb_channels = read_u16(CHANNEL_COUNT_OFFSET)? as usize;
+    let mut names = Vec::with_capacity(nb_channels);
+    // Channels have a variable size since they contain their name.
+    let mut offset = CHANNEL_COUNT_OFFSET + 2;
+    for _ in 0..nb_channels {
+        let name_size = read_u16(offset + 4)? as usize;
+        let name_start = offset + CHANNEL_NAME_OFFSET;
+        let name = metadata
+            .get(name_start..name_start + name_size)?
+            .chunks_exact(2)
+            .map(|c| u16::from_ne_bytes([c[0], c[1]]))
+            .take_while(|c| *c != 0)
+            .collect::<Vec<_>>();
+        names.push(String::from_utf16_lossy(&name));
+        offset = name_start + name_size;
+    }
+    Some(names)
+}
+
+/// Returns the absolute energy (in picowatt-hours) of each channel.
+unsafe fn get_measurements(handle: &HandleWrapper, nb_channels: usize) -> Option<Vec<u64>> {
+    // Each channel is an `EMI_CHANNEL_MEASUREMENT_DATA`: the absolute energy followed by the
+    // absolute time.
+    let mut measurements = vec![0u64; nb_channels * 2];
+    if !device_io_control(
+        handle,
+        IOCTL_EMI_GET_MEASUREMENT,
+        measurements.as_mut_ptr() as *mut c_void,
+        measurements.len() * size_of::<u64>(),
+    ) {
+        return None;
+    }
+    Some(measurements.chunks_exact(2).map(|c| c[0]).collect())
+}
+
+/// Parses RAPL channel names like `RAPL_Package0_PKG` or `RAPL_Package1_DRAM`.
+fn parse_rapl_channel_name(name: &str) -> Option<(String, usize)> {
+    let (package, domain) = name.strip_prefix("RAPL_Package")?.split_once('_')?;
+    let name = match domain {
+        "PKG" => "package",
+        "PP0" | "CORE" => "core",
+        "PP1" => "uncore",
+        "DRAM" => "dram",
+        "PSYS" => "psys",
+        _ => return None,
+    };
+    Some((name.to_owned(), package.parse().ok()?))
+}
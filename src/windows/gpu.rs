@@ -0,0 +1,121 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Gpu, GpuRefreshKind};
+
+use windows::core::ComInterface;
+use windows::Win32::Graphics::Dxgi::{
+    CreateDXGIFactory1, IDXGIAdapter1, IDXGIDevice, IDXGIFactory1, DXGI_ADAPTER_DESC1,
+    DXGI_ADAPTER_FLAG_SOFTWARE,
+};
+
+pub(crate) struct GpuInner {
+    name: String,
+    driver_version: Option<String>,
+    total_memory: Option<u64>,
+}
+
+impl GpuInner {
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn driver_version(&self) -> Option<&str> {
+        self.driver_version.as_deref()
+    }
+
+    pub(crate) fn usage(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn total_memory(&self) -> Option<u64> {
+        self.total_memory
+    }
+
+    pub(crate) fn used_memory(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn temperature(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn power(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn refresh(&mut self, _refresh_kind: GpuRefreshKind) {
+        // Nothing changes between two refreshes.
+    }
+}
+
+pub(crate) struct GpusInner {
+    gpus: Vec<Gpu>,
+}
+
+impl GpusInner {
+    pub(crate) fn new() -> Self {
+        Self { gpus: Vec::new() }
+    }
+
+    pub(crate) fn list(&self) -> &[Gpu] {
+        &self.gpus
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Gpu] {
+        &mut self.gpus
+    }
+
+    pub(crate) fn refresh_list(&mut self, _refresh_kind: GpuRefreshKind) {
+        self.gpus.clear();
+        unsafe {
+            let factory = match CreateDXGIFactory1::<IDXGIFactory1>() {
+                Ok(factory) => factory,
+                Err(_e) => {
+                    sysinfo_debug!("CreateDXGIFactory1 failed: {:?}", _e);
+                    return;
+                }
+            };
+            // `EnumAdapters1` fails with `DXGI_ERROR_NOT_FOUND` once all adapters were listed.
+            let mut index = 0;
+            while let Ok(adapter) = factory.EnumAdapters1(index) {
+                index += 1;
+                if let Some(gpu) = get_gpu(&adapter) {
+                    self.gpus.push(Gpu { inner: gpu });
+                }
+            }
+        }
+    }
+}
+
+unsafe fn get_gpu(adapter: &IDXGIAdapter1) -> Option<GpuInner> {
+    let mut desc = DXGI_ADAPTER_DESC1::default();
+    adapter.GetDesc1(&mut desc).ok()?;
+    // Like the "Microsoft Basic Render Driver".
+    if desc.Flags & DXGI_ADAPTER_FLAG_SOFTWARE.0 != 0 {
+        return None;
+    }
+    let end = desc
+        .Description
+        .iter()
+        .position(|c| *c == 0)
+        .unwrap_or(desc.Description.len());
+    // The user-mode driver version is packed in four 16-bit parts, like `31.0.15.3623`.
+    let driver_version = adapter
+        .CheckInterfaceSupport(&IDXGIDevice::IID)
+        .ok()
+        .map(|version| {
+            let version = version as u64;
+            format!(
+                "{}.{}.{}.{}",
+                version >> 48,
+                (version >> 32) & 0xffff,
+                (version >> 16) & 0xffff,
+                version & 0xffff,
+            )
+        });
+    Some(GpuInner {
+        name: String::from_utf16_lossy(&desc.Description[..end]),
+        driver_version,
+        total_memory: Some(desc.DedicatedVideoMemory as u64).filter(|memory| *memory != 0),
+    })
+}
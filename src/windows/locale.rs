@@ -0,0 +1,49 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::TimeZone;
+
+use windows::Win32::Globalization::GetUserDefaultLocaleName;
+use windows::Win32::System::SystemServices::LOCALE_NAME_MAX_LENGTH;
+use windows::Win32::System::Time::{
+    GetDynamicTimeZoneInformation, DYNAMIC_TIME_ZONE_INFORMATION, TIME_ZONE_ID_INVALID,
+};
+
+// Not exported by the `windows` crate.
+const TIME_ZONE_ID_DAYLIGHT: u32 = 2;
+
+fn from_wide(buf: &[u16]) -> String {
+    let end = buf.iter().position(|c| *c == 0).unwrap_or(buf.len());
+    String::from_utf16_lossy(&buf[..end])
+}
+
+pub(crate) fn get_time_zone() -> TimeZone {
+    unsafe {
+        let mut info: DYNAMIC_TIME_ZONE_INFORMATION = std::mem::zeroed();
+        let kind = GetDynamicTimeZoneInformation(&mut info);
+        if kind == TIME_ZONE_ID_INVALID {
+            sysinfo_debug!("GetDynamicTimeZoneInformation failed");
+            return TimeZone::default();
+        }
+        // The bias is in minutes, and is subtracted from the local time to get UTC.
+        let bias = if kind == TIME_ZONE_ID_DAYLIGHT {
+            info.Bias + info.DaylightBias
+        } else {
+            info.Bias + info.StandardBias
+        };
+        TimeZone {
+            name: Some(from_wide(&info.TimeZoneKeyName)).filter(|name| !name.is_empty()),
+            utc_offset: -bias * 60,
+        }
+    }
+}
+
+pub(crate) fn get_locale() -> Option<String> {
+    let mut buf = [0u16; LOCALE_NAME_MAX_LENGTH as usize];
+    unsafe {
+        if GetUserDefaultLocaleName(&mut buf) == 0 {
+            sysinfo_debug!("GetUserDefaultLocaleName failed");
+            return None;
+        }
+    }
+    Some(from_wide(&buf)).filter(|locale| !locale.is_empty())
+}
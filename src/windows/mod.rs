@@ -1,26 +1,52 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+mod battery;
 mod component;
+mod connections;
+mod container;
 mod cpu;
 mod disk;
+#[cfg(feature = "energy")]
+mod energy;
+mod gpu;
+mod locale;
+mod neighbors;
 mod network;
 pub(crate) mod network_helper;
+mod pci;
+mod performance_counters;
+#[cfg(feature = "energy")]
+mod power;
 mod process;
+mod services;
 mod sid;
 mod system;
 mod tools;
 mod users;
 mod utils;
+#[cfg(feature = "wireless")]
+mod wireless;
 
-pub(crate) use self::component::{ComponentInner, ComponentsInner};
+pub(crate) use self::battery::get_batteries;
+pub(crate) use self::component::{get_thermal_zones, ComponentInner, ComponentsInner};
+pub(crate) use self::connections::{get_connection_summary, get_connections};
+pub(crate) use self::container::{ContainerInner, ContainersInner};
 pub(crate) use self::cpu::CpuInner;
-pub(crate) use self::disk::{DiskInner, DisksInner};
-pub(crate) use self::network::{NetworkDataInner, NetworksInner};
+pub(crate) use self::disk::{DiskEventsInner, DiskInner, DisksInner};
+pub(crate) use self::gpu::{GpuInner, GpusInner};
+pub(crate) use self::locale::{get_locale, get_time_zone};
+pub(crate) use self::neighbors::get_neighbors;
+pub(crate) use self::network::{NetworkDataInner, NetworkEventsInner, NetworksInner};
+pub(crate) use self::pci::get_pci_devices;
+pub(crate) use self::performance_counters::PerformanceCountersInner;
+#[cfg(feature = "energy")]
+pub(crate) use self::power::PowerInner;
 pub(crate) use self::process::ProcessInner;
+pub(crate) use self::services::get_services;
 pub use self::sid::Sid;
 pub(crate) use self::system::SystemInner;
-pub(crate) use self::users::get_users;
 pub(crate) use self::users::UserInner;
+pub(crate) use self::users::{get_sessions, get_users, resolve_group_name, resolve_user_name};
 
 use std::time::Duration;
 
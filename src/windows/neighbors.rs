@@ -0,0 +1,86 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{MacAddr, Neighbor, NeighborState};
+
+use std::convert::TryInto;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use windows::Win32::NetworkManagement::IpHelper::{
+    ConvertInterfaceLuidToAlias, FreeMibTable, GetIpNetTable2, MIB_IPNET_ROW2, MIB_IPNET_TABLE2,
+};
+use windows::Win32::NetworkManagement::Ndis::NET_LUID_LH;
+use windows::Win32::Networking::WinSock::{
+    NlnsDelay, NlnsIncomplete, NlnsPermanent, NlnsProbe, NlnsReachable, NlnsStale, NlnsUnreachable,
+    AF_INET, AF_INET6, AF_UNSPEC, NL_NEIGHBOR_STATE,
+};
+
+fn neighbor_state(state: NL_NEIGHBOR_STATE) -> NeighborState {
+    match state {
+        NlnsUnreachable => NeighborState::Failed,
+        NlnsIncomplete => NeighborState::Incomplete,
+        NlnsProbe => NeighborState::Probe,
+        NlnsDelay => NeighborState::Delay,
+        NlnsStale => NeighborState::Stale,
+        NlnsReachable => NeighborState::Reachable,
+        NlnsPermanent => NeighborState::Permanent,
+        _ => NeighborState::Unknown,
+    }
+}
+
+unsafe fn get_interface_alias(luid: &NET_LUID_LH) -> Option<String> {
+    // `NDIS_IF_MAX_STRING_SIZE + 1`.
+    let mut alias = [0u16; 257];
+    if ConvertInterfaceLuidToAlias(luid, &mut alias).is_err() {
+        return None;
+    }
+    let len = alias.iter().position(|&c| c == 0).unwrap_or(alias.len());
+    String::from_utf16(&alias[..len]).ok()
+}
+
+unsafe fn get_ip_address(row: &MIB_IPNET_ROW2) -> Option<IpAddr> {
+    match row.Address.si_family {
+        AF_INET => Some(IpAddr::V4(Ipv4Addr::from(u32::from_be(
+            row.Address.Ipv4.sin_addr.S_un.S_addr,
+        )))),
+        AF_INET6 => Some(IpAddr::V6(Ipv6Addr::from(
+            row.Address.Ipv6.sin6_addr.u.Byte,
+        ))),
+        _ => None,
+    }
+}
+
+pub(crate) fn get_neighbors(neighbors: &mut Vec<Neighbor>) {
+    unsafe {
+        let mut table: *mut MIB_IPNET_TABLE2 = std::ptr::null_mut();
+        if GetIpNetTable2(AF_UNSPEC, &mut table).is_err() {
+            sysinfo_debug!("GetIpNetTable2 failed");
+            return;
+        }
+        let rows = std::slice::from_raw_parts((*table).Table.as_ptr(), (*table).NumEntries as _);
+        for row in rows {
+            let ip_address = match get_ip_address(row) {
+                Some(ip_address) => ip_address,
+                None => continue,
+            };
+            let interface = match get_interface_alias(&row.InterfaceLuid) {
+                Some(interface) => interface,
+                None => continue,
+            };
+            // Only Ethernet-like link layer addresses are supported.
+            let mac_address = if row.PhysicalAddressLength == 6 {
+                row.PhysicalAddress[..6]
+                    .try_into()
+                    .map_or(MacAddr::UNSPECIFIED, MacAddr)
+            } else {
+                MacAddr::UNSPECIFIED
+            };
+            neighbors.push(Neighbor {
+                ip_address,
+                mac_address,
+                state: neighbor_state(row.State),
+                interface,
+            });
+        }
+        let _err = FreeMibTable(table as _);
+    }
+}
@@ -1,15 +1,144 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::common::MacAddr;
-use crate::network::refresh_networks_addresses;
+use crate::common::{
+    DuplexMode, InterfaceKind, IpNetwork, MacAddr, NetworkCounters, OperationalState,
+};
+use crate::network::{counter_delta, refresh_networks_addresses, LinkState, RefreshTimes};
 use crate::NetworkData;
+#[cfg(feature = "wireless")]
+use crate::WirelessInfo;
 
-use std::collections::{hash_map, HashMap};
+use std::collections::{hash_map, HashMap, HashSet};
+use std::ffi::c_void;
+use std::time::Duration;
 
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::{CloseHandle, BOOLEAN, HANDLE, WAIT_OBJECT_0};
 use windows::Win32::NetworkManagement::IpHelper::{
-    FreeMibTable, GetIfEntry2, GetIfTable2, MIB_IF_ROW2, MIB_IF_TABLE2,
+    CancelMibChangeNotify2, FreeMibTable, GetIfEntry2, GetIfStackTable, GetIfTable2,
+    NotifyIpInterfaceChange, NotifyUnicastIpAddressChange, MIB_IFSTACK_TABLE, MIB_IF_ROW2,
+    MIB_IF_TABLE2, MIB_IPINTERFACE_ROW, MIB_NOTIFICATION_TYPE, MIB_UNICASTIPADDRESS_ROW,
+};
+use windows::Win32::NetworkManagement::Ndis::{
+    IfOperStatusDormant, IfOperStatusDown, IfOperStatusLowerLayerDown, IfOperStatusNotPresent,
+    IfOperStatusTesting, IfOperStatusUp, MediaConnectStateConnected, MediaConnectStateDisconnected,
+    NET_IF_ADMIN_STATUS_UP, NET_LUID_LH,
 };
-use windows::Win32::NetworkManagement::Ndis::{MediaConnectStateDisconnected, NET_LUID_LH};
+use windows::Win32::Networking::WinSock::AF_UNSPEC;
+use windows::Win32::System::Threading::{CreateEventW, SetEvent, WaitForSingleObject, INFINITE};
+
+fn from_wide(s: &[u16]) -> Option<String> {
+    let len = s.iter().position(|&c| c == 0).unwrap_or(s.len());
+    String::from_utf16(&s[..len]).ok().filter(|s| !s.is_empty())
+}
+
+// `FilterInterface` bit of `InterfaceAndOperStatusFlags`.
+fn is_filter_interface(row: &MIB_IF_ROW2) -> bool {
+    row.InterfaceAndOperStatusFlags._bitfield & (1 << 1) != 0
+}
+
+// Speeds are in bits per second, `u64::MAX` meaning unknown.
+fn get_link_speed(row: &MIB_IF_ROW2) -> Option<u64> {
+    Some(row.ReceiveLinkSpeed.max(row.TransmitLinkSpeed))
+        .filter(|speed| *speed != 0 && *speed != u64::MAX)
+}
+
+fn get_counters(row: &MIB_IF_ROW2) -> NetworkCounters {
+    NetworkCounters {
+        dropped_on_received: Some(row.InDiscards),
+        dropped_on_transmitted: Some(row.OutDiscards),
+        ..NetworkCounters::default()
+    }
+}
+
+fn get_link_state(row: &MIB_IF_ROW2) -> LinkState {
+    let operational_state = match row.OperStatus {
+        IfOperStatusUp => OperationalState::Up,
+        IfOperStatusDown => OperationalState::Down,
+        IfOperStatusTesting => OperationalState::Testing,
+        IfOperStatusDormant => OperationalState::Dormant,
+        IfOperStatusNotPresent => OperationalState::NotPresent,
+        IfOperStatusLowerLayerDown => OperationalState::LowerLayerDown,
+        _ => OperationalState::Unknown,
+    };
+    let has_carrier = match row.MediaConnectState {
+        MediaConnectStateConnected => Some(true),
+        MediaConnectStateDisconnected => Some(false),
+        _ => None,
+    };
+    LinkState::new(
+        operational_state,
+        row.AdminStatus == NET_IF_ADMIN_STATUS_UP,
+        has_carrier,
+    )
+}
+
+// Values from `ipifcons.h`.
+const IF_TYPE_L2_VLAN: u32 = 135;
+const IF_TYPE_IEEE8023AD_LAG: u32 = 161;
+const IF_TYPE_BRIDGE: u32 = 209;
+
+fn get_interface_kind(row: &MIB_IF_ROW2) -> InterfaceKind {
+    match row.Type {
+        IF_TYPE_L2_VLAN => InterfaceKind::Vlan,
+        IF_TYPE_IEEE8023AD_LAG => InterfaceKind::Bond,
+        IF_TYPE_BRIDGE => InterfaceKind::Bridge,
+        _ => InterfaceKind::Other,
+    }
+}
+
+/// Fills the master and the lower interfaces of the interfaces from the interface stack table,
+/// which contains the relations between the higher and the lower layer interfaces.
+unsafe fn refresh_relations(interfaces: &mut HashMap<String, NetworkData>) {
+    for interface in interfaces.values_mut() {
+        interface.inner.master = None;
+        interface.inner.lower_interfaces.clear();
+    }
+    let mut table: *mut MIB_IFSTACK_TABLE = std::ptr::null_mut();
+    if GetIfStackTable(&mut table).is_err() {
+        sysinfo_debug!("GetIfStackTable failed");
+        return;
+    }
+    let names: HashMap<u32, (String, InterfaceKind)> = interfaces
+        .iter()
+        .map(|(name, interface)| (interface.inner.index, (name.clone(), interface.inner.kind)))
+        .collect();
+    let rows = std::slice::from_raw_parts((*table).Table.as_ptr(), (*table).NumEntries as _);
+    for row in rows {
+        let (higher, higher_kind) = match names.get(&row.HigherLayerInterfaceIndex) {
+            Some(higher) => higher,
+            None => continue,
+        };
+        let (lower, _) = match names.get(&row.LowerLayerInterfaceIndex) {
+            Some(lower) => lower,
+            None => continue,
+        };
+        if let Some(interface) = interfaces.get_mut(higher) {
+            interface.inner.lower_interfaces.push(lower.clone());
+        }
+        if matches!(higher_kind, InterfaceKind::Bond | InterfaceKind::Bridge) {
+            if let Some(interface) = interfaces.get_mut(lower) {
+                interface.inner.master = Some(higher.clone());
+            }
+        }
+    }
+    let _err = FreeMibTable(table as _);
+    for interface in interfaces.values_mut() {
+        interface.inner.lower_interfaces.sort_unstable();
+    }
+}
+
+#[cfg(feature = "wireless")]
+fn refresh_wireless<'a>(interfaces: impl Iterator<Item = &'a mut NetworkData>) {
+    let mut infos = super::wireless::get_wireless_infos();
+    for interface in interfaces {
+        let interface = &mut interface.inner;
+        interface.wireless = infos
+            .iter()
+            .position(|(guid, _)| *guid == interface.guid)
+            .map(|pos| infos.swap_remove(pos).1);
+    }
+}
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident, $new_val:expr) => {{
@@ -20,12 +149,21 @@ macro_rules! old_and_new {
 
 pub(crate) struct NetworksInner {
     pub(crate) interfaces: HashMap<String, NetworkData>,
+    handle_counter_wrap: bool,
 }
 
 impl NetworksInner {
     pub(crate) fn new() -> Self {
         Self {
             interfaces: HashMap::new(),
+            handle_counter_wrap: true,
+        }
+    }
+
+    pub(crate) fn set_counter_wrap_handling(&mut self, enabled: bool) {
+        self.handle_counter_wrap = enabled;
+        for data in self.interfaces.values_mut() {
+            data.inner.handle_counter_wrap = enabled;
         }
     }
 
@@ -47,9 +185,11 @@ impl NetworksInner {
 
             // In here, this is tricky: we have to filter out the software interfaces to only keep
             // the hardware ones. To do so, we first check the connection potential speed (if 0, not
-            // interesting), then we check its state: if not open, not interesting either. And finally,
-            // we count the members of a same group: if there is more than 1, then it's software level.
-            let mut groups = HashMap::new();
+            // interesting), then we check its state: if not open, not interesting either. Filter
+            // drivers (like the "WFP Native MAC Layer LightWeight Filter") are stacked on top of the
+            // adapters with the same GUID but another name, so they are skipped and only the first
+            // interface of a same group is kept: its alias is the name displayed in the settings.
+            let mut groups = HashSet::new();
             let mut indexes = Vec::new();
             let ptr = (*table).Table.as_ptr();
             for i in 0..(*table).NumEntries {
@@ -57,6 +197,7 @@ impl NetworksInner {
                 if (ptr.TransmitLinkSpeed == 0 && ptr.ReceiveLinkSpeed == 0)
                     || ptr.MediaConnectState == MediaConnectStateDisconnected
                     || ptr.PhysicalAddressLength == 0
+                    || is_filter_interface(ptr)
                 {
                     continue;
                 }
@@ -72,28 +213,16 @@ impl NetworksInner {
                     ptr.InterfaceGuid.data4[6] as _,
                     ptr.InterfaceGuid.data4[7] as _,
                 ];
-                let entry = groups.entry(id.clone()).or_insert(0);
-                *entry += 1;
-                if *entry > 1 {
+                if !groups.insert(id) {
                     continue;
                 }
-                indexes.push((i, id));
+                indexes.push(i);
             }
-            for (i, id) in indexes {
+            for i in indexes {
                 let ptr = &*ptr.offset(i as _);
-                if *groups.get(&id).unwrap_or(&0) > 1 {
-                    continue;
-                }
-                let mut pos = 0;
-                for x in ptr.Alias.iter() {
-                    if *x == 0 {
-                        break;
-                    }
-                    pos += 1;
-                }
-                let interface_name = match String::from_utf16(&ptr.Alias[..pos]) {
-                    Ok(s) => s,
-                    _ => continue,
+                let interface_name = match from_wide(&ptr.Alias) {
+                    Some(s) => s,
+                    None => continue,
                 };
                 match self.interfaces.entry(interface_name) {
                     hash_map::Entry::Occupied(mut e) => {
@@ -115,6 +244,14 @@ impl NetworksInner {
                         );
                         old_and_new!(interface, errors_in, old_errors_in, ptr.InErrors);
                         old_and_new!(interface, errors_out, old_errors_out, ptr.OutErrors);
+                        interface.refresh_times.update();
+                        interface.counters = get_counters(ptr);
+                        interface.mtu = ptr.Mtu as _;
+                        interface.link_speed = get_link_speed(ptr);
+                        interface.link_state.update(get_link_state(ptr));
+                        interface.index = ptr.InterfaceIndex;
+                        interface.kind = get_interface_kind(ptr);
+                        interface.description = from_wide(&ptr.Description);
                         interface.updated = true;
                     }
                     hash_map::Entry::Vacant(e) => {
@@ -124,6 +261,7 @@ impl NetworksInner {
                         e.insert(NetworkData {
                             inner: NetworkDataInner {
                                 id: ptr.InterfaceLuid,
+                                index: ptr.InterfaceIndex,
                                 current_out: ptr.OutOctets,
                                 old_out: ptr.OutOctets,
                                 current_in: ptr.InOctets,
@@ -136,7 +274,24 @@ impl NetworksInner {
                                 old_errors_in: ptr.InErrors,
                                 errors_out: ptr.OutErrors,
                                 old_errors_out: ptr.OutErrors,
+                                refresh_times: RefreshTimes::new(),
+                                handle_counter_wrap: self.handle_counter_wrap,
+                                counters: get_counters(ptr),
                                 mac_addr: MacAddr::UNSPECIFIED,
+                                ip_networks: Vec::new(),
+                                mtu: ptr.Mtu as _,
+                                link_speed: get_link_speed(ptr),
+                                // `MIB_IF_ROW2` doesn't provide the duplex mode.
+                                duplex: DuplexMode::Unknown,
+                                link_state: get_link_state(ptr),
+                                kind: get_interface_kind(ptr),
+                                description: from_wide(&ptr.Description),
+                                master: None,
+                                lower_interfaces: Vec::new(),
+                                #[cfg(feature = "wireless")]
+                                guid: ptr.InterfaceGuid,
+                                #[cfg(feature = "wireless")]
+                                wireless: None,
                                 updated: true,
                             },
                         });
@@ -147,45 +302,42 @@ impl NetworksInner {
         }
         // Remove interfaces which are gone.
         self.interfaces.retain(|_, d| d.inner.updated);
+        unsafe {
+            refresh_relations(&mut self.interfaces);
+        }
         // Refresh all interfaces' addresses.
         refresh_networks_addresses(&mut self.interfaces);
+        #[cfg(feature = "wireless")]
+        refresh_wireless(self.interfaces.values_mut());
     }
 
     pub(crate) fn refresh(&mut self) {
-        let entry = std::mem::MaybeUninit::<MIB_IF_ROW2>::zeroed();
+        for interface in self.interfaces.values_mut() {
+            interface.inner.update();
+        }
+        #[cfg(feature = "wireless")]
+        refresh_wireless(self.interfaces.values_mut());
+    }
 
-        unsafe {
-            let mut entry = entry.assume_init();
-            for (_, interface) in self.interfaces.iter_mut() {
-                let interface = &mut interface.inner;
-                entry.InterfaceLuid = interface.id;
-                entry.InterfaceIndex = 0; // to prevent the function to pick this one as index
-                if GetIfEntry2(&mut entry).is_err() {
-                    continue;
-                }
-                old_and_new!(interface, current_out, old_out, entry.OutOctets);
-                old_and_new!(interface, current_in, old_in, entry.InOctets);
-                old_and_new!(
-                    interface,
-                    packets_in,
-                    old_packets_in,
-                    entry.InUcastPkts.saturating_add(entry.InNUcastPkts)
-                );
-                old_and_new!(
-                    interface,
-                    packets_out,
-                    old_packets_out,
-                    entry.OutUcastPkts.saturating_add(entry.OutNUcastPkts)
-                );
-                old_and_new!(interface, errors_in, old_errors_in, entry.InErrors);
-                old_and_new!(interface, errors_out, old_errors_out, entry.OutErrors);
+    pub(crate) fn refresh_interfaces(&mut self, interface_names: &[&str]) {
+        for interface_name in interface_names {
+            if let Some(interface) = self.interfaces.get_mut(*interface_name) {
+                interface.inner.update();
             }
         }
+        #[cfg(feature = "wireless")]
+        refresh_wireless(
+            self.interfaces
+                .iter_mut()
+                .filter(|(name, _)| interface_names.contains(&name.as_str()))
+                .map(|(_, interface)| interface),
+        );
     }
 }
 
 pub(crate) struct NetworkDataInner {
     id: NET_LUID_LH,
+    index: u32,
     current_out: u64,
     old_out: u64,
     current_in: u64,
@@ -198,29 +350,90 @@ pub(crate) struct NetworkDataInner {
     old_errors_in: u64,
     errors_out: u64,
     old_errors_out: u64,
+    refresh_times: RefreshTimes,
+    /// Whether the deltas of the counters take 32-bit wraps into account
+    handle_counter_wrap: bool,
+    counters: NetworkCounters,
     updated: bool,
     pub(crate) mac_addr: MacAddr,
+    pub(crate) ip_networks: Vec<IpNetwork>,
+    mtu: u64,
+    link_speed: Option<u64>,
+    duplex: DuplexMode,
+    link_state: LinkState,
+    kind: InterfaceKind,
+    description: Option<String>,
+    /// Bond or bridge this interface is a member of
+    master: Option<String>,
+    /// Interfaces this interface sits on
+    lower_interfaces: Vec<String>,
+    #[cfg(feature = "wireless")]
+    guid: windows::core::GUID,
+    #[cfg(feature = "wireless")]
+    wireless: Option<WirelessInfo>,
 }
 
 impl NetworkDataInner {
+    fn update(&mut self) {
+        unsafe {
+            let mut entry = std::mem::MaybeUninit::<MIB_IF_ROW2>::zeroed().assume_init();
+            entry.InterfaceLuid = self.id;
+            entry.InterfaceIndex = 0; // to prevent the function to pick this one as index
+            if GetIfEntry2(&mut entry).is_err() {
+                return;
+            }
+            old_and_new!(self, current_out, old_out, entry.OutOctets);
+            old_and_new!(self, current_in, old_in, entry.InOctets);
+            old_and_new!(
+                self,
+                packets_in,
+                old_packets_in,
+                entry.InUcastPkts.saturating_add(entry.InNUcastPkts)
+            );
+            old_and_new!(
+                self,
+                packets_out,
+                old_packets_out,
+                entry.OutUcastPkts.saturating_add(entry.OutNUcastPkts)
+            );
+            old_and_new!(self, errors_in, old_errors_in, entry.InErrors);
+            old_and_new!(self, errors_out, old_errors_out, entry.OutErrors);
+            self.refresh_times.update();
+            self.counters = get_counters(&entry);
+            self.link_state.update(get_link_state(&entry));
+        }
+    }
+
     pub(crate) fn received(&self) -> u64 {
-        self.current_in.saturating_sub(self.old_in)
+        counter_delta(self.old_in, self.current_in, self.handle_counter_wrap)
     }
 
     pub(crate) fn total_received(&self) -> u64 {
         self.current_in
     }
 
+    pub(crate) fn received_rate(&self) -> f64 {
+        self.refresh_times.rate(self.received())
+    }
+
     pub(crate) fn transmitted(&self) -> u64 {
-        self.current_out.saturating_sub(self.old_out)
+        counter_delta(self.old_out, self.current_out, self.handle_counter_wrap)
     }
 
     pub(crate) fn total_transmitted(&self) -> u64 {
         self.current_out
     }
 
+    pub(crate) fn transmitted_rate(&self) -> f64 {
+        self.refresh_times.rate(self.transmitted())
+    }
+
     pub(crate) fn packets_received(&self) -> u64 {
-        self.packets_in.saturating_sub(self.old_packets_in)
+        counter_delta(
+            self.old_packets_in,
+            self.packets_in,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_packets_received(&self) -> u64 {
@@ -228,7 +441,11 @@ impl NetworkDataInner {
     }
 
     pub(crate) fn packets_transmitted(&self) -> u64 {
-        self.packets_out.saturating_sub(self.old_packets_out)
+        counter_delta(
+            self.old_packets_out,
+            self.packets_out,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_packets_transmitted(&self) -> u64 {
@@ -236,7 +453,7 @@ impl NetworkDataInner {
     }
 
     pub(crate) fn errors_on_received(&self) -> u64 {
-        self.errors_in.saturating_sub(self.old_errors_in)
+        counter_delta(self.old_errors_in, self.errors_in, self.handle_counter_wrap)
     }
 
     pub(crate) fn total_errors_on_received(&self) -> u64 {
@@ -244,14 +461,207 @@ impl NetworkDataInner {
     }
 
     pub(crate) fn errors_on_transmitted(&self) -> u64 {
-        self.errors_out.saturating_sub(self.old_errors_out)
+        counter_delta(
+            self.old_errors_out,
+            self.errors_out,
+            self.handle_counter_wrap,
+        )
     }
 
     pub(crate) fn total_errors_on_transmitted(&self) -> u64 {
         self.errors_out
     }
 
+    pub(crate) fn detailed_counters(&self) -> NetworkCounters {
+        self.counters
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
+
+    pub(crate) fn ip_networks(&self) -> &[IpNetwork] {
+        &self.ip_networks
+    }
+
+    pub(crate) fn mtu(&self) -> u64 {
+        self.mtu
+    }
+
+    pub(crate) fn link_speed(&self) -> Option<u64> {
+        self.link_speed
+    }
+
+    pub(crate) fn duplex(&self) -> DuplexMode {
+        self.duplex
+    }
+
+    pub(crate) fn operational_state(&self) -> OperationalState {
+        self.link_state.operational_state
+    }
+
+    pub(crate) fn is_admin_up(&self) -> bool {
+        self.link_state.is_admin_up
+    }
+
+    pub(crate) fn has_carrier(&self) -> Option<bool> {
+        self.link_state.has_carrier
+    }
+
+    pub(crate) fn carrier_changes(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn last_state_change(&self) -> Option<u64> {
+        self.link_state.last_change
+    }
+
+    pub(crate) fn kind(&self) -> InterfaceKind {
+        self.kind
+    }
+
+    pub(crate) fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub(crate) fn master(&self) -> Option<&str> {
+        self.master.as_deref()
+    }
+
+    pub(crate) fn lower_interfaces(&self) -> &[String] {
+        &self.lower_interfaces
+    }
+
+    #[cfg(feature = "wireless")]
+    pub(crate) fn wireless(&self) -> Option<&WirelessInfo> {
+        self.wireless.as_ref()
+    }
+}
+
+pub(crate) struct NetworkEventsInner {
+    // Signaled by the callbacks every time an interface or a unicast address changes.
+    event: HANDLE,
+    interface_notification: HANDLE,
+    address_notification: HANDLE,
+}
+
+impl NetworkEventsInner {
+    pub(crate) fn new() -> Option<Self> {
+        unsafe {
+            let event = match CreateEventW(None, false, false, PCWSTR::null()) {
+                Ok(event) => event,
+                Err(_e) => {
+                    sysinfo_debug!("CreateEventW failed: {:?}", _e);
+                    return None;
+                }
+            };
+            let mut events = Self {
+                event,
+                interface_notification: HANDLE::default(),
+                address_notification: HANDLE::default(),
+            };
+            let context = event.0 as *const c_void;
+            if NotifyIpInterfaceChange(
+                AF_UNSPEC,
+                Some(on_interface_change),
+                Some(context),
+                BOOLEAN(0),
+                &mut events.interface_notification,
+            )
+            .is_err()
+            {
+                sysinfo_debug!("NotifyIpInterfaceChange failed");
+                return None;
+            }
+            if NotifyUnicastIpAddressChange(
+                AF_UNSPEC,
+                Some(on_address_change),
+                Some(context),
+                BOOLEAN(0),
+                &mut events.address_notification,
+            )
+            .is_err()
+            {
+                sysinfo_debug!("NotifyUnicastIpAddressChange failed");
+                return None;
+            }
+            Some(events)
+        }
+    }
+
+    pub(crate) fn wait(&mut self, timeout: Option<Duration>) -> bool {
+        let timeout = timeout.map_or(INFINITE, |timeout| {
+            timeout.as_millis().min(u128::from(INFINITE - 1)) as u32
+        });
+        unsafe { WaitForSingleObject(self.event, timeout) == WAIT_OBJECT_0 }
+    }
+}
+
+impl Drop for NetworkEventsInner {
+    fn drop(&mut self) {
+        unsafe {
+            // It waits for the running callbacks, so the event is still valid for them.
+            if !self.interface_notification.is_invalid() {
+                let _err = CancelMibChangeNotify2(self.interface_notification);
+            }
+            if !self.address_notification.is_invalid() {
+                let _err = CancelMibChangeNotify2(self.address_notification);
+            }
+            let _err = CloseHandle(self.event);
+        }
+    }
+}
+
+unsafe extern "system" fn on_interface_change(
+    context: *const c_void,
+    _row: *const MIB_IPINTERFACE_ROW,
+    _notification_type: MIB_NOTIFICATION_TYPE,
+) {
+    let _err = SetEvent(HANDLE(context as isize));
+}
+
+unsafe extern "system" fn on_address_change(
+    context: *const c_void,
+    _row: *const MIB_UNICASTIPADDRESS_ROW,
+    _notification_type: MIB_NOTIFICATION_TYPE,
+) {
+    let _err = SetEvent(HANDLE(context as isize));
+}
+
+#[cfg(test)]
+mod test {
+    use super::{from_wide, get_link_speed, is_filter_interface};
+    use windows::Win32::NetworkManagement::IpHelper::MIB_IF_ROW2;
+
+    #[test]
+    fn check_from_wide() {
+        let mut alias = [0u16; 8];
+        for (c, w) in alias.iter_mut().zip("Wi-Fi".encode_utf16()) {
+            *c = w;
+        }
+        assert_eq!(from_wide(&alias).as_deref(), Some("Wi-Fi"));
+        assert_eq!(from_wide(&[0; 4]), None);
+        // Invalid UTF-16.
+        assert_eq!(from_wide(&[0xD800, 0]), None);
+    }
+
+    #[test]
+    fn check_interface_row() {
+        let mut row: MIB_IF_ROW2 = unsafe { std::mem::zeroed() };
+        assert!(!is_filter_interface(&row));
+        assert_eq!(get_link_speed(&row), None);
+
+        // Only the `FilterInterface` bit matters, not the `HardwareInterface` one.
+        row.InterfaceAndOperStatusFlags._bitfield = 1;
+        assert!(!is_filter_interface(&row));
+        row.InterfaceAndOperStatusFlags._bitfield |= 1 << 1;
+        assert!(is_filter_interface(&row));
+
+        row.ReceiveLinkSpeed = 1_000_000_000;
+        row.TransmitLinkSpeed = 100_000_000;
+        assert_eq!(get_link_speed(&row), Some(1_000_000_000));
+        row.ReceiveLinkSpeed = u64::MAX;
+        row.TransmitLinkSpeed = u64::MAX;
+        assert_eq!(get_link_speed(&row), None);
+    }
 }
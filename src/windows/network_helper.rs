@@ -1,5 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::ptr::null_mut;
 
 use windows::Win32::Foundation::{ERROR_BUFFER_OVERFLOW, ERROR_SUCCESS};
@@ -7,9 +9,11 @@ use windows::Win32::NetworkManagement::IpHelper::{
     GetAdaptersAddresses, GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_DNS_SERVER, GAA_FLAG_SKIP_MULTICAST,
     IP_ADAPTER_ADDRESSES_LH,
 };
-use windows::Win32::Networking::WinSock::AF_UNSPEC;
+use windows::Win32::Networking::WinSock::{
+    AF_INET, AF_INET6, AF_UNSPEC, SOCKADDR_IN, SOCKADDR_IN6,
+};
 
-use crate::common::MacAddr;
+use crate::common::{IpNetwork, MacAddr};
 
 /// this iterator yields an interface name and address
 pub(crate) struct InterfaceAddressIterator {
@@ -105,3 +109,49 @@ pub(crate) fn get_interface_address() -> Result<InterfaceAddressIterator, String
         Err(format!("GetAdaptersAddresses() failed with code {ret}"))
     }
 }
+
+/// Returns the IPv4 and IPv6 networks of each interface.
+pub(crate) fn get_interface_ip_networks() -> Result<HashMap<String, Vec<IpNetwork>>, String> {
+    let iterator = get_interface_address()?;
+    let mut networks = HashMap::new();
+    unsafe {
+        let mut adapter = iterator.buf;
+        while !adapter.is_null() {
+            let current = &*adapter;
+            adapter = current.Next;
+
+            let interface_name = match current.FriendlyName.to_string() {
+                Ok(interface_name) => interface_name,
+                Err(_) => continue,
+            };
+            let mut ip_networks = Vec::new();
+            let mut address = current.FirstUnicastAddress;
+            while !address.is_null() {
+                let unicast = &*address;
+                address = unicast.Next;
+
+                let sock_addr = unicast.Address.lpSockaddr;
+                if sock_addr.is_null() {
+                    continue;
+                }
+                let addr = match (*sock_addr).sa_family {
+                    AF_INET => {
+                        let sock_addr = &*(sock_addr as *const SOCKADDR_IN);
+                        IpAddr::V4(Ipv4Addr::from(u32::from_be(sock_addr.sin_addr.S_un.S_addr)))
+                    }
+                    AF_INET6 => {
+                        let sock_addr = &*(sock_addr as *const SOCKADDR_IN6);
+                        IpAddr::V6(Ipv6Addr::from(sock_addr.sin6_addr.u.Byte))
+                    }
+                    _ => continue,
+                };
+                ip_networks.push(IpNetwork {
+                    addr,
+                    prefix: unicast.OnLinkPrefixLength,
+                });
+            }
+            networks.insert(interface_name, ip_networks);
+        }
+    }
+    Ok(networks)
+}
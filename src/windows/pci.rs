@@ -0,0 +1,155 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::DeviceInfoList;
+use crate::PciDevice;
+
+use windows::core::{w, GUID};
+use windows::Win32::Devices::DeviceAndDriverInstallation::{
+    SetupDiEnumDeviceInfo, SetupDiGetClassDevsW, SetupDiGetDevicePropertyW,
+    SetupDiGetDeviceRegistryPropertyW, DIGCF_ALLCLASSES, DIGCF_PRESENT, SPDRP_ADDRESS,
+    SPDRP_BUSNUMBER, SPDRP_DEVICEDESC, SPDRP_HARDWAREID, SPDRP_MFG, SPDRP_SERVICE, SP_DEVINFO_DATA,
+};
+use windows::Win32::Devices::Properties::{DEVPROPKEY, DEVPROPTYPE};
+use windows::Win32::Foundation::HWND;
+
+use std::convert::{TryFrom, TryInto};
+use std::mem::size_of;
+
+// `DEVPKEY_PciDevice_CurrentLinkSpeed` and `DEVPKEY_PciDevice_CurrentLinkWidth`, from
+// `pciprop.h`.
+const PCI_DEVICE_PROPERTIES: GUID = GUID::from_u128(0x3ab22e31_8264_4b4e_9af5_a8d2d8e33e62);
+const CURRENT_LINK_SPEED: u32 = 9;
+const CURRENT_LINK_WIDTH: u32 = 10;
+
+pub(crate) fn get_pci_devices(devices: &mut Vec<PciDevice>) {
+    unsafe {
+        let list = match SetupDiGetClassDevsW(
+            None,
+            w!("PCI"),
+            HWND::default(),
+            DIGCF_PRESENT | DIGCF_ALLCLASSES,
+        ) {
+            Ok(list) => DeviceInfoList(list),
+            Err(_e) => {
+                sysinfo_debug!("SetupDiGetClassDevsW failed: {:?}", _e);
+                return;
+            }
+        };
+        let mut index = 0;
+        loop {
+            let mut info = SP_DEVINFO_DATA {
+                cbSize: size_of::<SP_DEVINFO_DATA>() as _,
+                ..Default::default()
+            };
+            if SetupDiEnumDeviceInfo(list.0, index, &mut info).is_err() {
+                break;
+            }
+            index += 1;
+            if let Some(device) = get_pci_device(&list, &info) {
+                devices.push(device);
+            }
+        }
+    }
+}
+
+unsafe fn get_pci_device(list: &DeviceInfoList, info: &SP_DEVINFO_DATA) -> Option<PciDevice> {
+    let (vendor_id, device_id, class) =
+        parse_hardware_ids(&get_registry_property(list, info, SPDRP_HARDWAREID)?)?;
+    let number = |property| {
+        let value = get_registry_property(list, info, property)?;
+        Some(u32::from_le_bytes(value.get(..4)?.try_into().ok()?))
+    };
+    // The address is `(device << 16) | function`.
+    let address = match (number(SPDRP_BUSNUMBER), number(SPDRP_ADDRESS)) {
+        (Some(bus), Some(address)) => Some(format!(
+            "0000:{:02x}:{:02x}.{:x}",
+            bus,
+            address >> 16,
+            address & 0xffff
+        )),
+        _ => None,
+    };
+    let string =
+        |property| get_registry_property(list, info, property).map(|value| to_string(&value));
+    // The speed is given as the PCIe generation.
+    let link_speed = get_device_property(list, info, CURRENT_LINK_SPEED).and_then(|speed| {
+        Some(match speed {
+            1 => 2.5,
+            2 => 5.,
+            3 => 8.,
+            4 => 16.,
+            5 => 32.,
+            6 => 64.,
+            _ => return None,
+        })
+    });
+    Some(PciDevice {
+        address,
+        class: class.unwrap_or(0),
+        vendor_id,
+        device_id,
+        vendor_name: string(SPDRP_MFG).filter(|name| !name.is_empty()),
+        device_name: string(SPDRP_DEVICEDESC).filter(|name| !name.is_empty()),
+        driver: string(SPDRP_SERVICE).filter(|name| !name.is_empty()),
+        link_width: get_device_property(list, info, CURRENT_LINK_WIDTH)
+            .and_then(|width| u8::try_from(width).ok())
+            .filter(|width| *width != 0),
+        link_speed,
+    })
+}
+
+/// Returns the vendor ID, the device ID and the class code from the hardware IDs of the device,
+/// like `PCI\VEN_8086&DEV_9A49&SUBSYS_00000000&REV_01` and `PCI\VEN_8086&DEV_9A49&CC_030000`.
+fn parse_hardware_ids(ids: &[u16]) -> Option<(u16, u16, Option<u32>)> {
+    let ids = String::from_utf16_lossy(ids);
+    let mut ids = ids.split('\0').filter(|id| !id.is_empty());
+    let first = ids.next()?;
+    let hex = |id: &str, prefix: &str, len: usize| {
+        let (_, value) = id.split_once(prefix)?;
+        u32::from_str_radix(value.get(..len)?, 16).ok()
+    };
+    let vendor_id = hex(first, "VEN_", 4)? as u16;
+    let device_id = hex(first, "DEV_", 4)? as u16;
+    let class = std::iter::once(first)
+        .chain(ids)
+        .find_map(|id| hex(id, "CC_", 6));
+    Some((vendor_id, device_id, class))
+}
+
+/// Returns the raw value of a registry property of the device, as UTF-16 units.
+unsafe fn get_registry_property(
+    list: &DeviceInfoList,
+    info: &SP_DEVINFO_DATA,
+    property: u32,
+) -> Option<Vec<u16>> {
+    let mut needed = 0;
+    let _err =
+        SetupDiGetDeviceRegistryPropertyW(list.0, info, property, None, None, Some(&mut needed));
+    if needed == 0 {
+        return None;
+    }
+    let mut buf = vec![0u16; (needed as usize + 1) / 2];
+    let bytes = std::slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut u8, buf.len() * 2);
+    SetupDiGetDeviceRegistryPropertyW(list.0, info, property, None, Some(bytes), None).ok()?;
+    Some(buf)
+}
+
+unsafe fn get_device_property(
+    list: &DeviceInfoList,
+    info: &SP_DEVINFO_DATA,
+    pid: u32,
+) -> Option<u32> {
+    let key = DEVPROPKEY {
+        fmtid: PCI_DEVICE_PROPERTIES,
+        pid,
+    };
+    let mut kind = DEVPROPTYPE::default();
+    let mut value = [0u8; 4];
+    SetupDiGetDevicePropertyW(list.0, info, &key, &mut kind, Some(&mut value), None, 0).ok()?;
+    Some(u32::from_le_bytes(value))
+}
+
+fn to_string(value: &[u16]) -> String {
+    let end = value.iter().position(|c| *c == 0).unwrap_or(value.len());
+    String::from_utf16_lossy(&value[..end])
+}
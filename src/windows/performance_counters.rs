@@ -0,0 +1,157 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::collections::HashMap;
+use std::mem::{size_of, MaybeUninit};
+
+use windows::core::{PCWSTR, PWSTR};
+use windows::Win32::Foundation::ERROR_SUCCESS;
+use windows::Win32::System::Performance::{
+    PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData, PdhGetFormattedCounterArrayW,
+    PdhGetFormattedCounterValue, PdhOpenQueryW, PdhRemoveCounter, PDH_CSTATUS_NEW_DATA,
+    PDH_CSTATUS_VALID_DATA, PDH_FMT_COUNTERVALUE, PDH_FMT_COUNTERVALUE_ITEM_W, PDH_FMT_DOUBLE,
+    PDH_MORE_DATA,
+};
+
+pub(crate) struct PerformanceCountersInner {
+    query: Option<isize>,
+    counters: HashMap<String, isize>,
+}
+
+// The PDH handles can be used from any thread.
+unsafe impl Send for PerformanceCountersInner {}
+unsafe impl Sync for PerformanceCountersInner {}
+
+impl Drop for PerformanceCountersInner {
+    fn drop(&mut self) {
+        unsafe {
+            for counter in self.counters.values() {
+                PdhRemoveCounter(*counter);
+            }
+            if let Some(query) = self.query {
+                PdhCloseQuery(query);
+            }
+        }
+    }
+}
+
+impl PerformanceCountersInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            query: None,
+            counters: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn add(&mut self, path: &str) -> bool {
+        if self.counters.contains_key(path) {
+            return true;
+        }
+        unsafe {
+            let query = match self.query {
+                Some(query) => query,
+                None => {
+                    let mut query = 0;
+                    if PdhOpenQueryW(PCWSTR::null(), 0, &mut query) != ERROR_SUCCESS.0 {
+                        sysinfo_debug!("PdhOpenQueryW failed");
+                        return false;
+                    }
+                    self.query = Some(query);
+                    query
+                }
+            };
+            let wide = path.encode_utf16().chain(Some(0)).collect::<Vec<_>>();
+            let mut counter = 0;
+            let ret =
+                PdhAddEnglishCounterW(query, PCWSTR::from_raw(wide.as_ptr()), 0, &mut counter);
+            if ret != ERROR_SUCCESS.0 {
+                sysinfo_debug!("failed to add counter `{}`: {:x}", path, ret);
+                return false;
+            }
+            self.counters.insert(path.to_owned(), counter);
+        }
+        true
+    }
+
+    pub(crate) fn remove(&mut self, path: &str) -> bool {
+        match self.counters.remove(path) {
+            Some(counter) => {
+                unsafe { PdhRemoveCounter(counter) };
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        if let Some(query) = self.query {
+            if unsafe { PdhCollectQueryData(query) } != ERROR_SUCCESS.0 {
+                sysinfo_debug!("PdhCollectQueryData failed");
+            }
+        }
+    }
+
+    pub(crate) fn get(&self, path: &str) -> Option<f64> {
+        let counter = *self.counters.get(path)?;
+        unsafe {
+            let mut value = MaybeUninit::<PDH_FMT_COUNTERVALUE>::uninit();
+            // Rate counters fail until they were collected twice.
+            if PdhGetFormattedCounterValue(counter, PDH_FMT_DOUBLE, None, value.as_mut_ptr())
+                != ERROR_SUCCESS.0
+            {
+                return None;
+            }
+            let value = value.assume_init();
+            if is_valid(value.CStatus) {
+                Some(value.Anonymous.doubleValue)
+            } else {
+                None
+            }
+        }
+    }
+
+    pub(crate) fn instances(&self, path: &str) -> Vec<(String, f64)> {
+        let counter = match self.counters.get(path) {
+            Some(counter) => *counter,
+            None => return Vec::new(),
+        };
+        unsafe {
+            let mut size = 0;
+            let mut count = 0;
+            if PdhGetFormattedCounterArrayW(counter, PDH_FMT_DOUBLE, &mut size, &mut count, None)
+                != PDH_MORE_DATA
+            {
+                return Vec::new();
+            }
+            // The names of the instances are stored after the items, in the same buffer.
+            let mut buf: Vec<u64> = vec![0; size as usize / size_of::<u64>() + 1];
+            let items = buf.as_mut_ptr() as *mut PDH_FMT_COUNTERVALUE_ITEM_W;
+            if PdhGetFormattedCounterArrayW(
+                counter,
+                PDH_FMT_DOUBLE,
+                &mut size,
+                &mut count,
+                Some(items),
+            ) != ERROR_SUCCESS.0
+            {
+                return Vec::new();
+            }
+            std::slice::from_raw_parts(items, count as usize)
+                .iter()
+                .filter(|item| is_valid(item.FmtValue.CStatus))
+                .map(|item| (to_string(item.szName), item.FmtValue.Anonymous.doubleValue))
+                .collect()
+        }
+    }
+}
+
+fn is_valid(status: u32) -> bool {
+    status == PDH_CSTATUS_VALID_DATA || status == PDH_CSTATUS_NEW_DATA
+}
+
+unsafe fn to_string(name: PWSTR) -> String {
+    if name.is_null() {
+        String::new()
+    } else {
+        name.to_string().unwrap_or_default()
+    }
+}
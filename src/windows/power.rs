@@ -0,0 +1,33 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::energy::EnergyWrapper;
+use crate::{EnergyDomain, PowerRail};
+
+pub(crate) struct PowerInner {
+    energy: EnergyWrapper,
+    rails: Vec<PowerRail>,
+}
+
+impl PowerInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            energy: EnergyWrapper::new(),
+            rails: Vec::new(),
+        }
+    }
+
+    pub(crate) fn rails(&self) -> &[PowerRail] {
+        &self.rails
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        self.energy.refresh();
+        self.rails.clear();
+        self.rails.extend(
+            self.energy
+                .domains()
+                .iter()
+                .map(EnergyDomain::to_power_rail),
+        );
+    }
+}
@@ -1,6 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::sys::system::is_proc_running;
+use crate::sys::users::is_remote_session;
 use crate::windows::Sid;
 use crate::{DiskUsage, Gid, Pid, ProcessRefreshKind, ProcessStatus, Signal, Uid};
 
@@ -17,13 +18,14 @@ use std::process;
 use std::ptr::null_mut;
 use std::str;
 use std::sync::Arc;
+use std::time::Duration;
 
 use libc::c_void;
 use ntapi::ntexapi::{SystemProcessIdInformation, SYSTEM_PROCESS_ID_INFORMATION};
 use ntapi::ntrtl::RTL_USER_PROCESS_PARAMETERS;
 use ntapi::ntwow64::{PEB32, RTL_USER_PROCESS_PARAMETERS32};
 use once_cell::sync::Lazy;
-use windows::core::PCWSTR;
+use windows::core::{s, w, PCWSTR};
 use windows::Wdk::System::SystemInformation::{NtQuerySystemInformation, SYSTEM_INFORMATION_CLASS};
 use windows::Wdk::System::SystemServices::RtlGetVersion;
 use windows::Wdk::System::Threading::{
@@ -31,12 +33,13 @@ use windows::Wdk::System::Threading::{
     ProcessWow64Information, PROCESSINFOCLASS,
 };
 use windows::Win32::Foundation::{
-    CloseHandle, LocalFree, ERROR_INSUFFICIENT_BUFFER, FILETIME, HANDLE, HINSTANCE, HLOCAL,
+    CloseHandle, LocalFree, BOOL, ERROR_INSUFFICIENT_BUFFER, FILETIME, HANDLE, HINSTANCE, HLOCAL,
     MAX_PATH, STATUS_BUFFER_OVERFLOW, STATUS_BUFFER_TOO_SMALL, STATUS_INFO_LENGTH_MISMATCH,
     UNICODE_STRING,
 };
 use windows::Win32::Security::{GetTokenInformation, TokenUser, TOKEN_QUERY, TOKEN_USER};
 use windows::Win32::System::Diagnostics::Debug::ReadProcessMemory;
+use windows::Win32::System::LibraryLoader::{GetModuleHandleW, GetProcAddress};
 use windows::Win32::System::Memory::{
     GetProcessHeap, HeapAlloc, HeapFree, LocalAlloc, VirtualQueryEx, HEAP_ZERO_MEMORY, LMEM_FIXED,
     LMEM_ZEROINIT, MEMORY_BASIC_INFORMATION,
@@ -45,11 +48,14 @@ use windows::Win32::System::ProcessStatus::{
     GetModuleFileNameExW, GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS_EX,
 };
 use windows::Win32::System::RemoteDesktop::ProcessIdToSessionId;
-use windows::Win32::System::SystemInformation::OSVERSIONINFOEXW;
+use windows::Win32::System::SystemInformation::{
+    IMAGE_FILE_MACHINE, IMAGE_FILE_MACHINE_UNKNOWN, OSVERSIONINFOEXW,
+};
 use windows::Win32::System::Threading::{
-    GetProcessIoCounters, GetProcessTimes, GetSystemTimes, OpenProcess, OpenProcessToken,
-    CREATE_NO_WINDOW, IO_COUNTERS, PEB, PROCESS_BASIC_INFORMATION, PROCESS_QUERY_INFORMATION,
-    PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_READ,
+    GetProcessIoCounters, GetProcessTimes, GetSystemTimes, IsWow64Process, OpenProcess,
+    OpenProcessToken, ProcessMachineTypeInfo, CREATE_NO_WINDOW, IO_COUNTERS, PEB,
+    PROCESS_BASIC_INFORMATION, PROCESS_INFORMATION_CLASS, PROCESS_MACHINE_INFORMATION,
+    PROCESS_QUERY_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_READ,
 };
 use windows::Win32::UI::Shell::CommandLineToArgvW;
 
@@ -80,10 +86,17 @@ fn get_process_handler(pid: Pid) -> Option<HandleWrapper> {
             })
         })
         .or_else(|| {
-            sysinfo_debug!(
-                "OpenProcess limited failed, error: {:?}",
-                io::Error::last_os_error()
-            );
+            let _e = io::Error::last_os_error();
+            sysinfo_debug!("OpenProcess limited failed, error: {:?}", _e);
+            // `ERROR_INVALID_PARAMETER` is returned if the process doesn't exist anymore.
+            let kind = match _e.raw_os_error() {
+                Some(87) => crate::RefreshErrorKind::Vanished,
+                _ if _e.kind() == io::ErrorKind::PermissionDenied => {
+                    crate::RefreshErrorKind::PermissionDenied
+                }
+                _ => crate::RefreshErrorKind::Other,
+            };
+            crate::utils::report_error(kind, format_args!("process {}", pid), _e);
             None
         })
 }
@@ -201,6 +214,7 @@ pub(crate) struct ProcessInner {
     start_time: u64,
     pub(crate) run_time: u64,
     cpu_usage: f32,
+    normalized_cpu_usage: f32,
     pub(crate) updated: bool,
     old_read_bytes: u64,
     old_written_bytes: u64,
@@ -389,6 +403,7 @@ impl ProcessInner {
                 memory: 0,
                 virtual_memory: 0,
                 cpu_usage: 0.,
+                normalized_cpu_usage: 0.,
                 cpu_calc_values: CPUsageCalculationValues::new(),
                 start_time,
                 run_time,
@@ -439,6 +454,7 @@ impl ProcessInner {
                     memory,
                     virtual_memory,
                     cpu_usage: 0.,
+                    normalized_cpu_usage: 0.,
                     cpu_calc_values: CPUsageCalculationValues::new(),
                     start_time,
                     run_time,
@@ -465,6 +481,7 @@ impl ProcessInner {
                 memory,
                 virtual_memory,
                 cpu_usage: 0.,
+                normalized_cpu_usage: 0.,
                 cpu_calc_values: CPUsageCalculationValues::new(),
                 start_time: 0,
                 run_time: 0,
@@ -569,6 +586,28 @@ impl ProcessInner {
         self.cpu_usage
     }
 
+    pub(crate) fn normalized_cpu_usage(&self) -> f32 {
+        self.normalized_cpu_usage
+    }
+
+    pub(crate) fn set_cpu_usage(&mut self, cpu_usage: f32, normalized_cpu_usage: f32) {
+        self.cpu_usage = cpu_usage;
+        self.normalized_cpu_usage = normalized_cpu_usage;
+    }
+
+    pub(crate) fn user_time(&self) -> Duration {
+        // `FILETIME` values are in 100-nanosecond intervals.
+        Duration::from_nanos(
+            self.cpu_calc_values
+                .old_process_user_cpu
+                .saturating_mul(100),
+        )
+    }
+
+    pub(crate) fn kernel_time(&self) -> Duration {
+        Duration::from_nanos(self.cpu_calc_values.old_process_sys_cpu.saturating_mul(100))
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
@@ -622,6 +661,89 @@ impl ProcessInner {
             None
         }
     }
+
+    pub(crate) fn is_translated(&self) -> bool {
+        match self.get_handle() {
+            Some(handle) => unsafe { is_process_translated(handle) },
+            None => false,
+        }
+    }
+
+    pub(crate) fn is_remote_session(&self) -> bool {
+        self.session_id()
+            .map(|session_id| is_remote_session(session_id.as_u32()))
+            .unwrap_or(false)
+    }
+}
+
+type IsWow64Process2Fn =
+    unsafe extern "system" fn(HANDLE, *mut IMAGE_FILE_MACHINE, *mut IMAGE_FILE_MACHINE) -> BOOL;
+type GetProcessInformationFn =
+    unsafe extern "system" fn(HANDLE, PROCESS_INFORMATION_CLASS, *mut c_void, u32) -> BOOL;
+
+// `IsWow64Process2` (Windows 10 1709) and `GetProcessInformation` (Windows 8) are not available
+// on all the supported Windows versions, so they're looked up at runtime instead of being
+// imported, otherwise the binary wouldn't load on older versions.
+static MACHINE_FUNCTIONS: Lazy<(Option<IsWow64Process2Fn>, Option<GetProcessInformationFn>)> =
+    Lazy::new(|| unsafe {
+        let kernel32 = match GetModuleHandleW(w!("kernel32.dll")) {
+            Ok(kernel32) => kernel32,
+            Err(_err) => {
+                sysinfo_debug!("GetModuleHandleW failed: {:?}", _err);
+                return (None, None);
+            }
+        };
+        (
+            GetProcAddress(kernel32, s!("IsWow64Process2"))
+                .map(|f| std::mem::transmute::<_, IsWow64Process2Fn>(f)),
+            GetProcAddress(kernel32, s!("GetProcessInformation"))
+                .map(|f| std::mem::transmute::<_, GetProcessInformationFn>(f)),
+        )
+    });
+
+unsafe fn is_process_translated(handle: HANDLE) -> bool {
+    let (is_wow64_process2, get_process_information) = *MACHINE_FUNCTIONS;
+    let is_wow64_process2 = match is_wow64_process2 {
+        Some(is_wow64_process2) => is_wow64_process2,
+        None => {
+            // Before Windows 10 1709, WOW64 is the only emulation layer.
+            let mut is_wow64 = BOOL(0);
+            if let Err(_err) = IsWow64Process(handle, &mut is_wow64) {
+                sysinfo_debug!("IsWow64Process failed: {:?}", _err);
+                return false;
+            }
+            return is_wow64.as_bool();
+        }
+    };
+    let mut process_machine = IMAGE_FILE_MACHINE_UNKNOWN;
+    let mut native_machine = IMAGE_FILE_MACHINE_UNKNOWN;
+    if !is_wow64_process2(handle, &mut process_machine, &mut native_machine).as_bool() {
+        sysinfo_debug!("IsWow64Process2 failed: {:?}", io::Error::last_os_error());
+        return false;
+    }
+    if process_machine != IMAGE_FILE_MACHINE_UNKNOWN {
+        // This is a WOW64 process.
+        return true;
+    }
+    // x64 processes emulated on ARM64 are not running under WOW64 so we need to compare the
+    // process architecture with the native one. `ProcessMachineTypeInfo` is only supported
+    // since Windows 11, where this emulation was introduced.
+    let get_process_information = match get_process_information {
+        Some(get_process_information) => get_process_information,
+        None => return false,
+    };
+    let mut info: PROCESS_MACHINE_INFORMATION = zeroed();
+    if !get_process_information(
+        handle,
+        ProcessMachineTypeInfo,
+        (&mut info as *mut PROCESS_MACHINE_INFORMATION).cast(),
+        size_of::<PROCESS_MACHINE_INFORMATION>() as _,
+    )
+    .as_bool()
+    {
+        return false;
+    }
+    info.ProcessMachine != native_machine
 }
 
 #[inline]
@@ -1068,12 +1190,13 @@ pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, nb_cpus: u64) {
 
         if denominator < 0.00001 {
             p.cpu_usage = 0.;
+            p.normalized_cpu_usage = 0.;
             return;
         }
 
-        p.cpu_usage = 100.0
-            * (delta_user_time.saturating_add(delta_sys_time) as f32 / denominator)
-            * nb_cpus as f32;
+        p.normalized_cpu_usage =
+            100.0 * (delta_user_time.saturating_add(delta_sys_time) as f32 / denominator);
+        p.cpu_usage = p.normalized_cpu_usage * nb_cpus as f32;
     }
 }
 
@@ -0,0 +1,151 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Pid, Service, ServiceStartKind, ServiceState};
+
+use windows::core::{PCWSTR, PWSTR};
+use windows::Win32::Foundation::ERROR_MORE_DATA;
+use windows::Win32::Security::SC_HANDLE;
+use windows::Win32::System::Services::{
+    CloseServiceHandle, EnumServicesStatusExW, OpenSCManagerW, OpenServiceW, QueryServiceConfigW,
+    ENUM_SERVICE_STATUS_PROCESSW, QUERY_SERVICE_CONFIGW, SC_ENUM_PROCESS_INFO, SC_MANAGER_CONNECT,
+    SC_MANAGER_ENUMERATE_SERVICE, SERVICE_AUTO_START, SERVICE_BOOT_START, SERVICE_CONTINUE_PENDING,
+    SERVICE_DEMAND_START, SERVICE_DISABLED, SERVICE_PAUSED, SERVICE_PAUSE_PENDING,
+    SERVICE_QUERY_CONFIG, SERVICE_RUNNING, SERVICE_START_PENDING, SERVICE_STATE_ALL,
+    SERVICE_STATUS_CURRENT_STATE, SERVICE_STOPPED, SERVICE_STOP_PENDING, SERVICE_SYSTEM_START,
+    SERVICE_WIN32,
+};
+
+struct ServiceHandle(SC_HANDLE);
+
+impl Drop for ServiceHandle {
+    fn drop(&mut self) {
+        let _err = unsafe { CloseServiceHandle(self.0) };
+    }
+}
+
+pub(crate) fn get_services(services: &mut Vec<Service>) {
+    unsafe {
+        let manager = match OpenSCManagerW(
+            PCWSTR::null(),
+            PCWSTR::null(),
+            SC_MANAGER_CONNECT | SC_MANAGER_ENUMERATE_SERVICE,
+        ) {
+            Ok(manager) => ServiceHandle(manager),
+            Err(_e) => {
+                sysinfo_debug!("OpenSCManagerW failed: {:?}", _e);
+                return;
+            }
+        };
+        let mut needed = 0;
+        let mut count = 0;
+        let mut resume = 0;
+        // Aligned on 8 bytes so the entries can be read in place.
+        let mut buf: Vec<u64> = Vec::new();
+        loop {
+            let bytes = std::slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut u8, buf.len() * 8);
+            let ret = EnumServicesStatusExW(
+                manager.0,
+                SC_ENUM_PROCESS_INFO,
+                SERVICE_WIN32,
+                SERVICE_STATE_ALL,
+                Some(bytes),
+                &mut needed,
+                &mut count,
+                Some(&mut resume),
+                PCWSTR::null(),
+            );
+            let entries = std::slice::from_raw_parts(
+                buf.as_ptr() as *const ENUM_SERVICE_STATUS_PROCESSW,
+                count as usize,
+            );
+            for entry in entries {
+                services.push(get_service(&manager, entry));
+            }
+            match ret {
+                Ok(()) => break,
+                // The remaining entries are returned by the next calls, thanks to `resume`.
+                Err(err) if err.code() == ERROR_MORE_DATA.to_hresult() => {
+                    buf.resize(needed as usize / 8 + 1, 0);
+                }
+                Err(_e) => {
+                    sysinfo_debug!("EnumServicesStatusExW failed: {:?}", _e);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+unsafe fn get_service(manager: &ServiceHandle, entry: &ENUM_SERVICE_STATUS_PROCESSW) -> Service {
+    let status = &entry.ServiceStatusProcess;
+    let (start_kind, command) = get_service_config(manager, entry.lpServiceName);
+    Service {
+        name: entry.lpServiceName.to_string().unwrap_or_default(),
+        description: entry
+            .lpDisplayName
+            .to_string()
+            .ok()
+            .filter(|name| !name.is_empty()),
+        state: service_state(status.dwCurrentState),
+        start_kind,
+        command,
+        pid: if status.dwProcessId != 0 {
+            Some(Pid::from_u32(status.dwProcessId))
+        } else {
+            None
+        },
+    }
+}
+
+/// Returns the start kind and the command line (`ImagePath`) of the service.
+unsafe fn get_service_config(
+    manager: &ServiceHandle,
+    name: PWSTR,
+) -> (ServiceStartKind, Option<String>) {
+    let unknown = (ServiceStartKind::Unknown, None);
+    let service = match OpenServiceW(manager.0, PCWSTR::from_raw(name.0), SERVICE_QUERY_CONFIG) {
+        Ok(service) => ServiceHandle(service),
+        Err(_) => return unknown,
+    };
+    let mut needed = 0;
+    // The first call fails and returns the needed size, which includes the strings.
+    let _err = QueryServiceConfigW(service.0, None, 0, &mut needed);
+    if needed == 0 {
+        return unknown;
+    }
+    let mut buf: Vec<u64> = vec![0; needed as usize / 8 + 1];
+    let config = buf.as_mut_ptr() as *mut QUERY_SERVICE_CONFIGW;
+    if QueryServiceConfigW(service.0, Some(config), (buf.len() * 8) as _, &mut needed).is_err() {
+        return unknown;
+    }
+    let config = &*config;
+    let start_kind = match config.dwStartType {
+        SERVICE_AUTO_START | SERVICE_BOOT_START | SERVICE_SYSTEM_START => {
+            ServiceStartKind::Automatic
+        }
+        SERVICE_DEMAND_START => ServiceStartKind::Manual,
+        SERVICE_DISABLED => ServiceStartKind::Disabled,
+        _ => ServiceStartKind::Unknown,
+    };
+    let command = if config.lpBinaryPathName.is_null() {
+        None
+    } else {
+        config
+            .lpBinaryPathName
+            .to_string()
+            .ok()
+            .filter(|command| !command.is_empty())
+    };
+    (start_kind, command)
+}
+
+fn service_state(state: SERVICE_STATUS_CURRENT_STATE) -> ServiceState {
+    match state {
+        SERVICE_RUNNING => ServiceState::Running,
+        SERVICE_STOPPED => ServiceState::Stopped,
+        SERVICE_START_PENDING => ServiceState::Starting,
+        SERVICE_STOP_PENDING => ServiceState::Stopping,
+        SERVICE_PAUSED | SERVICE_PAUSE_PENDING | SERVICE_CONTINUE_PENDING => ServiceState::Paused,
+        _ => ServiceState::Unknown,
+    }
+}
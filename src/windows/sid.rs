@@ -55,8 +55,75 @@ impl Sid {
         Some(Self { sid })
     }
 
+    /// Returns a pointer to the raw SID, valid as long as `self` is.
+    pub(crate) fn as_psid(&self) -> PSID {
+        PSID((self.sid.as_ptr() as *mut u8).cast())
+    }
+
+    /// Returns the identifier authority of this SID, like `5` for `NT AUTHORITY`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     println!("{}: {}", user.name(), user.id().identifier_authority());
+    /// }
+    /// ```
+    pub fn identifier_authority(&self) -> u64 {
+        self.sid[2..8]
+            .iter()
+            .fold(0, |authority, byte| (authority << 8) | u64::from(*byte))
+    }
+
+    /// Returns the sub-authorities of this SID. For an account, the last one is its relative
+    /// identifier (RID) and the previous ones identify its domain.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     println!("{}: {:?}", user.name(), user.id().sub_authorities());
+    /// }
+    /// ```
+    pub fn sub_authorities(&self) -> Vec<u32> {
+        let count = self.sid[1] as usize;
+        self.sid[8..]
+            .chunks_exact(4)
+            .take(count)
+            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect()
+    }
+
+    /// Returns the relative identifier (RID) of this SID, which identifies the account in its
+    /// domain. For example, it is `500` for the built-in administrator account.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     println!("{}: {:?}", user.name(), user.id().relative_id());
+    /// }
+    /// ```
+    pub fn relative_id(&self) -> Option<u32> {
+        self.sub_authorities().last().copied()
+    }
+
+    /// Returns `true` if this SID belongs to a Windows domain (local or not), meaning that its
+    /// prefix is `S-1-5-21`. Well-known accounts like `SYSTEM` don't.
+    pub(crate) fn is_domain_sid(&self) -> bool {
+        self.identifier_authority() == 5 && self.sub_authorities().first() == Some(&21)
+    }
+
     /// Retrieves the account name of this SID.
     pub(crate) fn account_name(&self) -> Option<String> {
+        self.lookup_account().map(|(name, _)| name)
+    }
+
+    /// Retrieves the account name of this SID and the name of the domain it belongs to.
+    pub(crate) fn lookup_account(&self) -> Option<(String, String)> {
         unsafe {
             let mut name_len = 0;
             let mut domain_len = 0;
@@ -79,17 +146,14 @@ impl Sid {
             }
 
             let mut name = vec![0; name_len as usize];
-
-            // Reset length to 0 since we're still passing a NULL pointer
-            // for the domain.
-            domain_len = 0;
+            let mut domain = vec![0; domain_len as usize];
 
             if LookupAccountSidW(
                 PCWSTR::null(),
                 sid,
                 PWSTR::from_raw(name.as_mut_ptr()),
                 &mut name_len,
-                PWSTR::null(),
+                PWSTR::from_raw(domain.as_mut_ptr()),
                 &mut domain_len,
                 &mut name_use,
             )
@@ -102,7 +166,10 @@ impl Sid {
                 return None;
             }
 
-            Some(to_str(PWSTR::from_raw(name.as_mut_ptr())))
+            Some((
+                to_str(PWSTR::from_raw(name.as_mut_ptr())),
+                to_str(PWSTR::from_raw(domain.as_mut_ptr())),
+            ))
         }
     }
 }
@@ -152,3 +219,41 @@ impl FromStr for Sid {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Sid;
+
+    #[test]
+    fn check_sid_components() {
+        let sid: Sid = "S-1-5-21-1004336348-1177238915-682003330-500"
+            .parse()
+            .unwrap();
+        assert_eq!(sid.identifier_authority(), 5);
+        assert_eq!(
+            sid.sub_authorities(),
+            [21, 1004336348, 1177238915, 682003330, 500],
+        );
+        assert_eq!(sid.relative_id(), Some(500));
+        assert!(sid.is_domain_sid());
+        assert_eq!(
+            sid.to_string(),
+            "S-1-5-21-1004336348-1177238915-682003330-500",
+        );
+
+        // `SYSTEM`.
+        let sid: Sid = "S-1-5-18".parse().unwrap();
+        assert_eq!(sid.identifier_authority(), 5);
+        assert_eq!(sid.sub_authorities(), [18]);
+        assert_eq!(sid.relative_id(), Some(18));
+        assert!(!sid.is_domain_sid());
+
+        // `Everyone`.
+        let sid: Sid = "S-1-1-0".parse().unwrap();
+        assert_eq!(sid.identifier_authority(), 1);
+        assert_eq!(sid.relative_id(), Some(0));
+        assert!(!sid.is_domain_sid());
+
+        assert!("S-1-5-abc".parse::<Sid>().is_err());
+    }
+}
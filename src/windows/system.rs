@@ -1,35 +1,85 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Cpu, CpuRefreshKind, LoadAvg, Pid, ProcessRefreshKind};
+use crate::{
+    AvailableMemory, AvailableMemorySource, CommitCharge, Cpu, CpuActivity, CpuIdleState,
+    CpuRefreshKind, Entropy, FileHandles, HardwareInfo, KernelParameter, LoadAvg, MemoryBreakdown,
+    OsRelease, Pid, ProcessRefreshKind, SystemCounts, Virtualization, WindowsVersion,
+};
 
+use crate::smbios::{parse_hardware_info, parse_memory_modules};
 use crate::sys::cpu::*;
 use crate::sys::process::get_start_time;
 use crate::sys::tools::*;
-use crate::sys::utils::{get_now, get_reg_string_value, get_reg_value_u32};
+use crate::sys::utils::{
+    get_computer_name, get_now, get_reg_string_value, get_reg_value, get_reg_value_u32, to_str,
+};
 use crate::{Process, ProcessInner};
 
-use crate::utils::into_iter;
+use crate::utils::{into_iter, normalize_arch};
 
 use std::cell::UnsafeCell;
 use std::collections::HashMap;
 use std::mem::{size_of, zeroed};
 use std::ptr;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
-use ntapi::ntexapi::SYSTEM_PROCESS_INFORMATION;
-use windows::core::PWSTR;
-use windows::Wdk::System::SystemInformation::{NtQuerySystemInformation, SystemProcessInformation};
+use ntapi::ntexapi::{
+    SystemMemoryListInformation, SYSTEM_MEMORY_LIST_INFORMATION, SYSTEM_PROCESS_INFORMATION,
+};
+use windows::core::{PCWSTR, PWSTR};
+use windows::Wdk::System::SystemInformation::{
+    NtQuerySystemInformation, SystemProcessInformation, SYSTEM_INFORMATION_CLASS,
+};
+use windows::Wdk::System::SystemServices::RtlGetVersion;
 use windows::Win32::Foundation::{HANDLE, STATUS_INFO_LENGTH_MISMATCH, STILL_ACTIVE};
+use windows::Win32::NetworkManagement::NetManagement::{
+    NERR_Success, NetApiBufferFree, NetGetJoinInformation, NetSetupDomainName,
+    NetSetupUnknownStatus, NetSetupWorkgroupName, NETSETUP_JOIN_STATUS,
+};
+use windows::Win32::Security::Cryptography::{
+    BCryptGenRandom, BCRYPT_ALG_HANDLE, BCRYPT_USE_SYSTEM_PREFERRED_RNG,
+};
 use windows::Win32::System::ProcessStatus::{K32GetPerformanceInfo, PERFORMANCE_INFORMATION};
-use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+use windows::Win32::System::Registry::{
+    HKEY_LOCAL_MACHINE, REG_DWORD, REG_EXPAND_SZ, REG_MULTI_SZ, REG_QWORD, REG_SZ,
+};
 use windows::Win32::System::SystemInformation::{
-    ComputerNamePhysicalDnsHostname, GetComputerNameExW, GetTickCount64, GlobalMemoryStatusEx,
-    MEMORYSTATUSEX,
+    ComputerNamePhysicalDnsDomain, ComputerNamePhysicalDnsFullyQualified,
+    ComputerNamePhysicalDnsHostname, GetComputerNameExW, GetNativeSystemInfo,
+    GetSystemFirmwareTable, GetTickCount64, GlobalMemoryStatusEx, MEMORYSTATUSEX, OSVERSIONINFOEXW,
+    OSVERSIONINFOW, PROCESSOR_ARCHITECTURE_AMD64, PROCESSOR_ARCHITECTURE_ARM,
+    PROCESSOR_ARCHITECTURE_ARM64, PROCESSOR_ARCHITECTURE_INTEL, RSMB, SYSTEM_INFO,
 };
 use windows::Win32::System::Threading::GetExitCodeProcess;
 
 const WINDOWS_ELEVEN_BUILD_NUMBER: u32 = 22000;
 
+/// Reads the memory information without needing the `SystemInner`, so it can be done on another
+/// thread. The result is applied with `SystemInner::apply_memory`.
+pub(crate) struct MemoryReader {
+    mem_info: Option<MEMORYSTATUSEX>,
+    perf_info: Option<PERFORMANCE_INFORMATION>,
+    memory_lists: Option<SYSTEM_MEMORY_LIST_INFORMATION>,
+}
+
+impl MemoryReader {
+    pub(crate) fn read(&mut self) {
+        unsafe {
+            let mut mem_info: MEMORYSTATUSEX = zeroed();
+            mem_info.dwLength = size_of::<MEMORYSTATUSEX>() as u32;
+            let _err = GlobalMemoryStatusEx(&mut mem_info);
+            self.mem_info = Some(mem_info);
+            let mut perf_info: PERFORMANCE_INFORMATION = zeroed();
+            if K32GetPerformanceInfo(&mut perf_info, size_of::<PERFORMANCE_INFORMATION>() as u32)
+                .as_bool()
+            {
+                self.perf_info = Some(perf_info);
+                self.memory_lists = get_memory_list_information();
+            }
+        }
+    }
+}
+
 impl SystemInner {
     fn is_windows_eleven(&self) -> bool {
         WINDOWS_ELEVEN_BUILD_NUMBER
@@ -58,15 +108,26 @@ unsafe fn boot_time() -> u64 {
     }
 }
 
+// Idle states reported by the `Processor` performance counters.
+const IDLE_STATES: &[&str] = &["C1", "C2", "C3"];
+
 pub(crate) struct SystemInner {
     process_list: HashMap<Pid, Process>,
     mem_total: u64,
     mem_available: u64,
+    // Zero and free page lists, only available with administrator privileges.
+    mem_free: Option<u64>,
+    mem_breakdown: MemoryBreakdown,
+    commit_charge: CommitCharge,
     swap_total: u64,
     swap_used: u64,
     cpus: CpusWrapper,
     query: Option<Query>,
+    // Whether the idle states counters were added to `query`.
+    idle_states_counters: bool,
     boot_time: u64,
+    #[cfg(feature = "energy")]
+    energy: crate::sys::energy::EnergyWrapper,
 }
 
 impl SystemInner {
@@ -75,11 +136,17 @@ impl SystemInner {
             process_list: HashMap::with_capacity(500),
             mem_total: 0,
             mem_available: 0,
+            mem_free: None,
+            mem_breakdown: MemoryBreakdown::default(),
+            commit_charge: CommitCharge::default(),
             swap_total: 0,
             swap_used: 0,
             cpus: CpusWrapper::new(),
             query: None,
+            idle_states_counters: false,
             boot_time: unsafe { boot_time() },
+            #[cfg(feature = "energy")]
+            energy: crate::sys::energy::EnergyWrapper::new(),
         }
     }
 
@@ -103,6 +170,20 @@ impl SystemInner {
                 }
             }
         }
+        if refresh_kind.idle_states() && !self.idle_states_counters {
+            if let Some(ref mut query) = self.query {
+                for pos in 0..self.cpus.cpus().len() {
+                    for state in IDLE_STATES {
+                        let mut counter = format!(r"\Processor({pos})\% {state} Time")
+                            .encode_utf16()
+                            .collect::<Vec<_>>();
+                        counter.push(0);
+                        query.add_english_counter(&format!("{pos}_{state}"), counter);
+                    }
+                }
+                self.idle_states_counters = true;
+            }
+        }
         if let Some(ref mut query) = self.query {
             query.refresh();
             let mut total_idle_time = None;
@@ -132,36 +213,89 @@ impl SystemInner {
                     p.inner.set_cpu_usage(100.0 - idle_time);
                 }
             }
+            if refresh_kind.cpu_usage() {
+                self.cpus.get_times();
+            }
             if refresh_kind.frequency() {
                 self.cpus.get_frequencies();
             }
+            if refresh_kind.idle_states() {
+                for (pos, cpu) in self.cpus.cpus_mut().iter_mut().enumerate() {
+                    cpu.inner.idle_states = IDLE_STATES
+                        .iter()
+                        .filter_map(|state| {
+                            Some(CpuIdleState {
+                                name: state.to_string(),
+                                residency: query.get(&format!("{pos}_{state}"))?,
+                            })
+                        })
+                        .collect();
+                }
+            }
         }
     }
 
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        self.cpus.refresh_global_cpu_usage();
+    }
+
+    pub(crate) fn set_string_encoding_policy(&mut self, _policy: crate::StringEncodingPolicy) {}
+
+    pub(crate) fn set_keep_open_files(&mut self, _enabled: bool) {}
+
     pub(crate) fn refresh_memory(&mut self) {
-        unsafe {
-            let mut mem_info: MEMORYSTATUSEX = zeroed();
-            mem_info.dwLength = size_of::<MEMORYSTATUSEX>() as u32;
-            let _err = GlobalMemoryStatusEx(&mut mem_info);
+        let mut reader = self.memory_reader();
+        reader.read();
+        self.apply_memory(reader);
+    }
+
+    pub(crate) fn memory_reader(&mut self) -> MemoryReader {
+        MemoryReader {
+            mem_info: None,
+            perf_info: None,
+            memory_lists: None,
+        }
+    }
+
+    pub(crate) fn apply_memory(&mut self, reader: MemoryReader) {
+        if let Some(mem_info) = reader.mem_info {
             self.mem_total = mem_info.ullTotalPhys as _;
             self.mem_available = mem_info.ullAvailPhys as _;
-            let mut perf_info: PERFORMANCE_INFORMATION = zeroed();
-            if K32GetPerformanceInfo(&mut perf_info, size_of::<PERFORMANCE_INFORMATION>() as u32)
-                .as_bool()
-            {
-                let swap_total = perf_info.PageSize.saturating_mul(
-                    perf_info
-                        .CommitLimit
-                        .saturating_sub(perf_info.PhysicalTotal),
-                );
-                let swap_used = perf_info.PageSize.saturating_mul(
-                    perf_info
-                        .CommitTotal
-                        .saturating_sub(perf_info.PhysicalTotal),
-                );
-                self.swap_total = swap_total as _;
-                self.swap_used = swap_used as _;
-            }
+        }
+        if let Some(perf_info) = reader.perf_info {
+            let swap_total = perf_info.PageSize.saturating_mul(
+                perf_info
+                    .CommitLimit
+                    .saturating_sub(perf_info.PhysicalTotal),
+            );
+            let swap_used = perf_info.PageSize.saturating_mul(
+                perf_info
+                    .CommitTotal
+                    .saturating_sub(perf_info.PhysicalTotal),
+            );
+            self.swap_total = swap_total as _;
+            self.swap_used = swap_used as _;
+            let to_bytes = |pages: usize| perf_info.PageSize.saturating_mul(pages) as u64;
+            let memory_lists = reader.memory_lists;
+            self.mem_free = memory_lists
+                .as_ref()
+                .map(|lists| to_bytes(lists.ZeroPageCount.saturating_add(lists.FreePageCount)));
+            self.mem_breakdown = MemoryBreakdown {
+                cached: to_bytes(perf_info.SystemCache),
+                // The paged pool can be written to the disk, the non-paged one cannot.
+                slab_reclaimable: to_bytes(perf_info.KernelPaged),
+                slab_unreclaimable: to_bytes(perf_info.KernelNonpaged),
+                dirty: memory_lists
+                    .as_ref()
+                    .map(|lists| to_bytes(lists.ModifiedPageCount))
+                    .unwrap_or(0),
+                ..MemoryBreakdown::default()
+            };
+            self.commit_charge = CommitCharge {
+                total: to_bytes(perf_info.CommitTotal),
+                limit: to_bytes(perf_info.CommitLimit),
+                peak: to_bytes(perf_info.CommitPeak),
+            };
         }
     }
 
@@ -283,42 +417,45 @@ impl SystemInner {
 
         // TODO: instead of using parallel iterator only here, would be better to be
         //       able to run it over `process_information` directly!
+        let errors = crate::utils::ErrorCollector::current();
         let processes = into_iter(process_ids)
             .filter_map(|pi| {
-                // as above, read_unaligned is necessary
-                let pi = unsafe { ptr::read_unaligned(pi.0) };
-                let pid = Pid(pi.UniqueProcessId as _);
-                if let Some(proc_) = unsafe { (*process_list.0.get()).get_mut(&pid) } {
-                    let proc_ = &mut proc_.inner;
-                    if proc_
-                        .get_start_time()
-                        .map(|start| start == proc_.start_time())
-                        .unwrap_or(true)
-                    {
-                        proc_.memory = pi.WorkingSetSize as _;
-                        proc_.virtual_memory = pi.VirtualSize as _;
-                        proc_.update(refresh_kind, nb_cpus, now);
-                        return None;
+                errors.run(|| {
+                    // as above, read_unaligned is necessary
+                    let pi = unsafe { ptr::read_unaligned(pi.0) };
+                    let pid = Pid(pi.UniqueProcessId as _);
+                    if let Some(proc_) = unsafe { (*process_list.0.get()).get_mut(&pid) } {
+                        let proc_ = &mut proc_.inner;
+                        if proc_
+                            .get_start_time()
+                            .map(|start| start == proc_.start_time())
+                            .unwrap_or(true)
+                        {
+                            proc_.memory = pi.WorkingSetSize as _;
+                            proc_.virtual_memory = pi.VirtualSize as _;
+                            proc_.update(refresh_kind, nb_cpus, now);
+                            return None;
+                        }
+                        // If the PID owner changed, we need to recompute the whole process.
+                        sysinfo_debug!("owner changed for PID {}", proc_.pid());
                     }
-                    // If the PID owner changed, we need to recompute the whole process.
-                    sysinfo_debug!("owner changed for PID {}", proc_.pid());
-                }
-                let name = get_process_name(&pi, pid);
-                let mut p = ProcessInner::new_full(
-                    pid,
-                    if pi.InheritedFromUniqueProcessId as usize != 0 {
-                        Some(Pid(pi.InheritedFromUniqueProcessId as _))
-                    } else {
-                        None
-                    },
-                    pi.WorkingSetSize as _,
-                    pi.VirtualSize as _,
-                    name,
-                    now,
-                    refresh_kind,
-                );
-                p.update(refresh_kind, nb_cpus, now);
-                Some(Process { inner: p })
+                    let name = get_process_name(&pi, pid);
+                    let mut p = ProcessInner::new_full(
+                        pid,
+                        if pi.InheritedFromUniqueProcessId as usize != 0 {
+                            Some(Pid(pi.InheritedFromUniqueProcessId as _))
+                        } else {
+                            None
+                        },
+                        pi.WorkingSetSize as _,
+                        pi.VirtualSize as _,
+                        name,
+                        now,
+                        refresh_kind,
+                    );
+                    p.update(refresh_kind, nb_cpus, now);
+                    Some(Process { inner: p })
+                })
             })
             .collect::<Vec<_>>();
         for p in processes.into_iter() {
@@ -343,14 +480,48 @@ impl SystemInner {
         self.cpus.global_cpu()
     }
 
+    pub(crate) fn cpu_activity(&self) -> CpuActivity {
+        self.cpus.activity()
+    }
+
+    #[cfg(feature = "energy")]
+    pub(crate) fn refresh_energy(&mut self) {
+        self.energy.refresh();
+    }
+
+    #[cfg(feature = "energy")]
+    pub(crate) fn energy_domains(&self) -> &[crate::EnergyDomain] {
+        self.energy.domains()
+    }
+
     pub(crate) fn cpus(&self) -> &[Cpu] {
         self.cpus.cpus()
     }
 
+    pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
+        &mut self.process_list
+    }
+
+    pub(crate) fn global_cpu_info_mut(&mut self) -> &mut Cpu {
+        self.cpus.global_cpu_mut()
+    }
+
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        self.cpus.cpus_mut()
+    }
+
     pub(crate) fn physical_core_count(&self) -> Option<usize> {
         get_physical_core_count()
     }
 
+    pub(crate) fn socket_count(&self) -> Option<usize> {
+        get_socket_count()
+    }
+
+    pub(crate) fn cpu_features(&self) -> Vec<String> {
+        crate::utils::get_cpu_features()
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -364,10 +535,59 @@ impl SystemInner {
         self.mem_available
     }
 
+    pub(crate) fn available_memory_breakdown(&self) -> Option<AvailableMemory> {
+        // The available memory is the sum of the free, zero and standby lists.
+        let free = self.mem_free.unwrap_or(self.mem_available);
+        Some(AvailableMemory {
+            free,
+            reclaimable: self.mem_available.saturating_sub(free),
+            available: self.mem_available,
+            source: AvailableMemorySource::Kernel,
+        })
+    }
+
     pub(crate) fn used_memory(&self) -> u64 {
         self.mem_total - self.mem_available
     }
 
+    pub(crate) fn memory_breakdown(&self) -> MemoryBreakdown {
+        self.mem_breakdown
+    }
+
+    pub(crate) fn commit_charge(&self) -> CommitCharge {
+        self.commit_charge
+    }
+
+    pub(crate) fn compressed_memory(&self) -> crate::CompressedMemory {
+        crate::CompressedMemory::default()
+    }
+
+    pub(crate) fn memory_pressure_level(&self) -> Option<crate::MemoryPressureLevel> {
+        None
+    }
+
+    pub(crate) fn zram_devices(&self) -> &[crate::ZramDevice] {
+        &[]
+    }
+
+    pub(crate) fn ksm(&self) -> Option<crate::Ksm> {
+        None
+    }
+
+    pub(crate) fn balloon_memory(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn oom_kills(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn memory_modules(&self) -> Vec<crate::MemoryModule> {
+        get_smbios_table()
+            .map(|table| parse_memory_modules(&table))
+            .unwrap_or_default()
+    }
+
     pub(crate) fn total_swap(&self) -> u64 {
         self.swap_total
     }
@@ -388,6 +608,22 @@ impl SystemInner {
         self.boot_time
     }
 
+    pub(crate) fn uptime_duration(&self) -> Duration {
+        // `GetTickCount64` keeps running while the system is asleep or hibernated, unlike
+        // `QueryUnbiasedInterruptTime`.
+        Duration::from_millis(unsafe { GetTickCount64() })
+    }
+
+    pub(crate) fn boot_time_sys(&self) -> SystemTime {
+        SystemTime::now()
+            .checked_sub(self.uptime_duration())
+            .unwrap_or(SystemTime::UNIX_EPOCH)
+    }
+
+    pub(crate) fn pressure(&self) -> Option<crate::Pressure> {
+        None
+    }
+
     pub(crate) fn load_average(&self) -> LoadAvg {
         get_load_average()
     }
@@ -412,10 +648,197 @@ impl SystemInner {
         )
     }
 
+    pub(crate) fn kernel_arch(&self) -> Option<String> {
+        // Unlike `GetNativeSystemInfo`, it isn't affected by the x64 emulation on ARM64.
+        if let Some(arch) = get_reg_string_value(
+            HKEY_LOCAL_MACHINE,
+            "SYSTEM\\CurrentControlSet\\Control\\Session Manager\\Environment",
+            "PROCESSOR_ARCHITECTURE",
+        ) {
+            return Some(normalize_arch(&arch));
+        }
+        let mut info: SYSTEM_INFO = unsafe { zeroed() };
+        unsafe { GetNativeSystemInfo(&mut info) };
+        let arch = match unsafe { info.Anonymous.Anonymous.wProcessorArchitecture } {
+            PROCESSOR_ARCHITECTURE_INTEL => "x86",
+            PROCESSOR_ARCHITECTURE_AMD64 => "x86_64",
+            PROCESSOR_ARCHITECTURE_ARM => "arm",
+            PROCESSOR_ARCHITECTURE_ARM64 => "aarch64",
+            _ => return None,
+        };
+        Some(arch.to_owned())
+    }
+
+    pub(crate) fn userland_arch(&self) -> Option<String> {
+        // 32-bit processes run on top of the native userland with WOW64.
+        self.kernel_arch()
+    }
+
+    pub(crate) fn os_release(&self) -> Option<OsRelease> {
+        None
+    }
+
+    pub(crate) fn windows_version(&self) -> Option<WindowsVersion> {
+        let mut info: OSVERSIONINFOEXW = unsafe { zeroed() };
+        info.dwOSVersionInfoSize = size_of::<OSVERSIONINFOEXW>() as _;
+        // `GetVersionEx` would return the version the application is manifested for.
+        if let Err(_e) = unsafe { RtlGetVersion(&mut info as *mut _ as *mut OSVERSIONINFOW) } {
+            sysinfo_debug!("RtlGetVersion failed: {:?}", _e);
+            return None;
+        }
+        let key = "SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion";
+        let string_value = |field_name| {
+            get_reg_string_value(HKEY_LOCAL_MACHINE, key, field_name).filter(|s| !s.is_empty())
+        };
+        let end = info
+            .szCSDVersion
+            .iter()
+            .position(|c| *c == 0)
+            .unwrap_or(info.szCSDVersion.len());
+        let service_pack = String::from_utf16_lossy(&info.szCSDVersion[..end]);
+
+        Some(WindowsVersion {
+            major: info.dwMajorVersion,
+            minor: info.dwMinorVersion,
+            build: info.dwBuildNumber,
+            revision: get_reg_value_u32(HKEY_LOCAL_MACHINE, key, "UBR").map(u32::from_le_bytes),
+            // `ReleaseId` was replaced by `DisplayVersion` in 20H2, and is stuck at `2009` since.
+            display_version: string_value("DisplayVersion").or_else(|| string_value("ReleaseId")),
+            edition: string_value("EditionID"),
+            service_pack: Some(service_pack).filter(|s| !s.is_empty()),
+            service_pack_major: info.wServicePackMajor,
+            service_pack_minor: info.wServicePackMinor,
+            suite_mask: info.wSuiteMask,
+            product_type: info.wProductType,
+        })
+    }
+
     pub(crate) fn host_name(&self) -> Option<String> {
         get_dns_hostname()
     }
 
+    pub(crate) fn fqdn(&self) -> Option<String> {
+        get_computer_name(ComputerNamePhysicalDnsFullyQualified)
+    }
+
+    pub(crate) fn domain_name(&self) -> Option<String> {
+        get_computer_name(ComputerNamePhysicalDnsDomain).or_else(|| match get_join_information() {
+            Some((name, NetSetupDomainName)) => Some(name),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn workgroup(&self) -> Option<String> {
+        match get_join_information() {
+            Some((name, NetSetupWorkgroupName)) => Some(name),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn counts(&self) -> SystemCounts {
+        unsafe {
+            let mut perf_info: PERFORMANCE_INFORMATION = zeroed();
+            if !K32GetPerformanceInfo(&mut perf_info, size_of::<PERFORMANCE_INFORMATION>() as u32)
+                .as_bool()
+            {
+                sysinfo_debug!("GetPerformanceInfo failed");
+                return SystemCounts::default();
+            }
+            SystemCounts {
+                processes: Some(perf_info.ProcessCount as _),
+                threads: Some(perf_info.ThreadCount as _),
+                handles: Some(perf_info.HandleCount as _),
+            }
+        }
+    }
+
+    pub(crate) fn file_handles(&self) -> Option<FileHandles> {
+        Some(FileHandles {
+            allocated: self.counts().handles?,
+            limit: None,
+        })
+    }
+
+    pub(crate) fn entropy(&self) -> Option<Entropy> {
+        let mut byte = [0u8];
+        let ready = unsafe {
+            BCryptGenRandom(
+                BCRYPT_ALG_HANDLE::default(),
+                &mut byte,
+                BCRYPT_USE_SYSTEM_PREFERRED_RNG,
+            )
+        }
+        .is_ok();
+        Some(Entropy {
+            ready,
+            available: None,
+            pool_size: None,
+        })
+    }
+
+    pub(crate) fn hardware_info(&self) -> HardwareInfo {
+        let mut info = get_smbios_table()
+            .map(|table| parse_hardware_info(&table))
+            .unwrap_or_default();
+        crate::utils::set_virtual_chassis(&mut info);
+        info
+    }
+
+    pub(crate) fn virtualization(&self) -> Virtualization {
+        Virtualization {
+            hypervisor: crate::utils::get_hypervisor(&self.hardware_info()),
+            container: None,
+        }
+    }
+
+    pub(crate) fn machine_id(&self) -> Option<String> {
+        get_reg_string_value(
+            HKEY_LOCAL_MACHINE,
+            "SOFTWARE\\Microsoft\\Cryptography",
+            "MachineGuid",
+        )
+    }
+
+    pub(crate) fn kernel_parameter(&self, name: &str) -> Option<KernelParameter> {
+        let (path, field_name) = name.rsplit_once(|c| c == '\\' || c == '/')?;
+        let path = format!("SYSTEM\\CurrentControlSet\\{}", path.replace('/', "\\"));
+        let (kind, data) = get_reg_value(HKEY_LOCAL_MACHINE, &path, field_name)?;
+        let words = || {
+            let words = data
+                .chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .collect::<Vec<_>>();
+            String::from_utf16_lossy(&words)
+        };
+        match kind {
+            REG_DWORD if data.len() == 4 => {
+                Some(KernelParameter::Integer(i64::from(u32::from_le_bytes([
+                    data[0], data[1], data[2], data[3],
+                ]))))
+            }
+            REG_QWORD if data.len() == 8 => {
+                let mut bytes = [0u8; 8];
+                bytes.copy_from_slice(&data);
+                Some(KernelParameter::Integer(i64::from_le_bytes(bytes)))
+            }
+            REG_SZ | REG_EXPAND_SZ => Some(KernelParameter::String(
+                words().trim_end_matches('\0').to_owned(),
+            )),
+            // Strings are separated by a NUL character, the list ends with an empty one.
+            REG_MULTI_SZ => Some(KernelParameter::String(
+                words()
+                    .split('\0')
+                    .filter(|s| !s.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            )),
+            _ => {
+                sysinfo_debug!("unsupported type for kernel parameter `{}`", name);
+                None
+            }
+        }
+    }
+
     pub(crate) fn kernel_version(&self) -> Option<String> {
         get_reg_string_value(
             HKEY_LOCAL_MACHINE,
@@ -540,3 +963,62 @@ fn get_dns_hostname() -> Option<String> {
     sysinfo_debug!("Failed to get computer hostname");
     None
 }
+
+/// Returns the name of the domain or workgroup the computer is joined to, and which one it is.
+fn get_join_information() -> Option<(String, NETSETUP_JOIN_STATUS)> {
+    unsafe {
+        let mut name = PWSTR::null();
+        let mut status = NetSetupUnknownStatus;
+        let ret = NetGetJoinInformation(PCWSTR::null(), &mut name, &mut status);
+        if ret != NERR_Success {
+            sysinfo_debug!("NetGetJoinInformation failed: {}", ret);
+            return None;
+        }
+        let join_name = to_str(name);
+        NetApiBufferFree(Some(name.0 as *const _));
+        Some((join_name, status))
+    }
+}
+
+/// Returns the number of pages in each memory list (free, standby, modified, ...).
+fn get_memory_list_information() -> Option<SYSTEM_MEMORY_LIST_INFORMATION> {
+    unsafe {
+        let mut info: SYSTEM_MEMORY_LIST_INFORMATION = zeroed();
+        // It fails if the process doesn't have the `SeProfileSingleProcessPrivilege` privilege.
+        if let Err(_err) = NtQuerySystemInformation(
+            SYSTEM_INFORMATION_CLASS(SystemMemoryListInformation as _),
+            &mut info as *mut _ as *mut _,
+            size_of::<SYSTEM_MEMORY_LIST_INFORMATION>() as _,
+            ptr::null_mut(),
+        ) {
+            sysinfo_debug!(
+                "get_memory_list_information: NtQuerySystemInformation failed: {}",
+                _err
+            );
+            return None;
+        }
+        Some(info)
+    }
+}
+
+/// Returns the raw SMBIOS table.
+fn get_smbios_table() -> Option<Vec<u8>> {
+    // The table is preceded by a `RawSMBIOSData` header.
+    const HEADER_SIZE: usize = 8;
+
+    unsafe {
+        let size = GetSystemFirmwareTable(RSMB, 0, None);
+        if (size as usize) <= HEADER_SIZE {
+            sysinfo_debug!("GetSystemFirmwareTable failed");
+            return None;
+        }
+        let mut buf = vec![0u8; size as usize];
+        let size = GetSystemFirmwareTable(RSMB, 0, Some(&mut buf));
+        if (size as usize) <= HEADER_SIZE || size as usize > buf.len() {
+            sysinfo_debug!("GetSystemFirmwareTable failed");
+            return None;
+        }
+        buf.truncate(size as usize);
+        Some(buf.split_off(HEADER_SIZE))
+    }
+}
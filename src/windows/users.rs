@@ -1,24 +1,38 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::sys::utils::to_str;
+use crate::sys::utils::{get_computer_name, get_reg_string_value, to_str};
 use crate::{
     common::{Gid, Uid},
     windows::sid::Sid,
-    Group, User,
+    Group, GroupRefreshKind, Session, User, UserRefreshKind,
 };
 
+use std::path::{Path, PathBuf};
 use std::ptr::null_mut;
-use windows::core::{w, PCWSTR};
+use windows::core::{w, PCWSTR, PWSTR};
 use windows::Win32::Foundation::{ERROR_MORE_DATA, LUID};
 use windows::Win32::NetworkManagement::NetManagement::{
     NERR_Success, NetApiBufferFree, NetUserEnum, NetUserGetInfo, NetUserGetLocalGroups,
     FILTER_NORMAL_ACCOUNT, LG_INCLUDE_INDIRECT, LOCALGROUP_USERS_INFO_0, MAX_PREFERRED_LENGTH,
-    USER_INFO_0, USER_INFO_23,
+    USER_INFO_0, USER_INFO_1, USER_INFO_23,
 };
 use windows::Win32::Security::Authentication::Identity::{
-    LsaEnumerateLogonSessions, LsaFreeReturnBuffer, LsaGetLogonSessionData,
+    LsaEnumerateLogonSessions, LsaFreeReturnBuffer, LsaGetLogonSessionData, LSA_UNICODE_STRING,
     SECURITY_LOGON_SESSION_DATA, SECURITY_LOGON_TYPE,
 };
+use windows::Win32::Security::Authorization::{
+    AuthzContextInfoGroupsSids, AuthzFreeContext, AuthzFreeResourceManager,
+    AuthzGetInformationFromContext, AuthzInitializeContextFromSid, AuthzInitializeResourceManager,
+    AUTHZ_CLIENT_CONTEXT_HANDLE, AUTHZ_RESOURCE_MANAGER_HANDLE, AUTHZ_RM_FLAG_NO_AUDIT,
+};
+use windows::Win32::Security::TOKEN_GROUPS;
+use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+use windows::Win32::System::RemoteDesktop::{
+    WTSClientName, WTSClientProtocolType, WTSEnumerateSessionsW, WTSFreeMemory,
+    WTSQuerySessionInformationW, WTSSessionInfo, WTSINFOW, WTS_CURRENT_SERVER_HANDLE,
+    WTS_SESSION_INFOW,
+};
+use windows::Win32::System::SystemInformation::ComputerNameNetBIOS;
 
 pub(crate) struct UserInner {
     pub(crate) uid: Uid,
@@ -26,21 +40,41 @@ pub(crate) struct UserInner {
     pub(crate) name: String,
     c_user_name: Option<Vec<u16>>,
     is_local: bool,
+    home_dir: Option<PathBuf>,
+    domain: Option<String>,
+    is_domain_account: bool,
 }
 
 impl UserInner {
-    fn new(uid: Uid, name: String, c_name: PCWSTR, is_local: bool) -> Self {
+    fn new(
+        uid: Uid,
+        name: String,
+        domain: Option<String>,
+        c_name: PCWSTR,
+        is_local: bool,
+        computer_name: Option<&str>,
+        refresh_kind: UserRefreshKind,
+    ) -> Self {
         let c_user_name = if c_name.is_null() {
             None
         } else {
             Some(unsafe { c_name.as_wide() }.into())
         };
+        let home_dir = if refresh_kind.home_dir() {
+            unsafe { get_home_dir(&uid.0, c_name) }
+        } else {
+            None
+        };
+        let is_domain_account = is_domain_account(&uid.0, domain.as_deref(), computer_name);
         Self {
             uid,
             gid: Gid(0),
             name,
             c_user_name,
             is_local,
+            home_dir,
+            domain,
+            is_domain_account,
         }
     }
 
@@ -56,13 +90,46 @@ impl UserInner {
         &self.name
     }
 
-    pub(crate) fn groups(&self) -> Vec<Group> {
+    pub(crate) fn groups(&self, refresh_kind: GroupRefreshKind) -> Vec<Group> {
+        if refresh_kind.nested_groups() {
+            if let Some(groups) = unsafe { get_nested_groups(&self.uid.0) } {
+                return groups;
+            }
+        } else if !refresh_kind.supplementary_groups() {
+            return Vec::new();
+        }
         if let (Some(c_user_name), true) = (&self.c_user_name, self.is_local) {
             unsafe { get_groups_for_user(PCWSTR(c_user_name.as_ptr())) }
         } else {
             Vec::new()
         }
     }
+
+    pub(crate) fn home_dir(&self) -> Option<&Path> {
+        self.home_dir.as_deref()
+    }
+
+    pub(crate) fn shell(&self) -> Option<&Path> {
+        None
+    }
+
+    pub(crate) fn domain(&self) -> Option<&str> {
+        self.domain.as_deref()
+    }
+
+    pub(crate) fn is_domain_account(&self) -> bool {
+        self.is_domain_account
+    }
+}
+
+/// Returns `true` if `sid` is an account of a Windows domain other than the computer itself.
+fn is_domain_account(sid: &Sid, domain: Option<&str>, computer_name: Option<&str>) -> bool {
+    // The domain of the local accounts is the name of the computer.
+    sid.is_domain_sid()
+        && match (domain, computer_name) {
+            (Some(domain), Some(computer_name)) => !domain.eq_ignore_ascii_case(computer_name),
+            _ => false,
+        }
 }
 
 struct NetApiBuffer<T>(*mut T);
@@ -117,6 +184,145 @@ impl<T> LsaBuffer<T> {
     }
 }
 
+struct WtsBuffer<T>(*mut T);
+
+impl<T> Drop for WtsBuffer<T> {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { WTSFreeMemory(self.0.cast()) };
+        }
+    }
+}
+
+impl<T> Default for WtsBuffer<T> {
+    fn default() -> Self {
+        Self(null_mut())
+    }
+}
+
+impl<T> WtsBuffer<T> {
+    pub fn inner_mut(&mut self) -> &mut *mut T {
+        assert!(self.0.is_null());
+        &mut self.0
+    }
+
+    pub fn inner_mut_as_pwstr(&mut self) -> &mut PWSTR {
+        // `PWSTR` is a transparent wrapper around a pointer.
+        unsafe { &mut *(self.inner_mut() as *mut *mut T as *mut PWSTR) }
+    }
+}
+
+/// Returns the home directory set for the local account `name` or, if there is none, the
+/// directory of the profile of `sid`.
+unsafe fn get_home_dir(sid: &Sid, name: PCWSTR) -> Option<PathBuf> {
+    if !name.is_null() {
+        let mut user: NetApiBuffer<USER_INFO_1> = Default::default();
+        if NetUserGetInfo(PCWSTR::null(), name, 1, user.inner_mut_as_bytes()) == NERR_Success
+            && !user.0.is_null()
+        {
+            let home_dir = to_str((*user.0).usri1_home_dir);
+            if !home_dir.is_empty() {
+                return Some(PathBuf::from(home_dir));
+            }
+        }
+    }
+    get_reg_string_value(
+        HKEY_LOCAL_MACHINE,
+        &format!("SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion\\ProfileList\\{sid}"),
+        "ProfileImagePath",
+    )
+    .filter(|path| !path.is_empty())
+    .map(PathBuf::from)
+}
+
+struct AuthzResourceManager(AUTHZ_RESOURCE_MANAGER_HANDLE);
+
+impl Drop for AuthzResourceManager {
+    fn drop(&mut self) {
+        let _err = unsafe { AuthzFreeResourceManager(self.0) };
+    }
+}
+
+struct AuthzContext(AUTHZ_CLIENT_CONTEXT_HANDLE);
+
+impl Drop for AuthzContext {
+    fn drop(&mut self) {
+        let _err = unsafe { AuthzFreeContext(self.0) };
+    }
+}
+
+/// Returns all the groups `sid` is a member of, directly or through other groups, including the
+/// domain groups.
+unsafe fn get_nested_groups(sid: &Sid) -> Option<Vec<Group>> {
+    let mut manager = Default::default();
+    if let Err(_e) = AuthzInitializeResourceManager(
+        AUTHZ_RM_FLAG_NO_AUDIT.0,
+        None,
+        None,
+        None,
+        PCWSTR::null(),
+        &mut manager,
+    ) {
+        sysinfo_debug!("AuthzInitializeResourceManager failed: {:?}", _e);
+        return None;
+    }
+    let manager = AuthzResourceManager(manager);
+
+    // It computes the groups the same way as when the user logs in, so it may need to contact
+    // the domain controller.
+    let mut context = Default::default();
+    if let Err(_e) = AuthzInitializeContextFromSid(
+        0,
+        sid.as_psid(),
+        manager.0,
+        None,
+        LUID::default(),
+        None,
+        &mut context,
+    ) {
+        sysinfo_debug!("AuthzInitializeContextFromSid failed: {:?}", _e);
+        return None;
+    }
+    let context = AuthzContext(context);
+
+    let mut size = 0;
+    // The first call fails and returns the needed size.
+    let _err = AuthzGetInformationFromContext(
+        context.0,
+        AuthzContextInfoGroupsSids,
+        0,
+        &mut size,
+        null_mut(),
+    );
+    if size == 0 {
+        return None;
+    }
+    // `u64` to get a buffer aligned like `TOKEN_GROUPS`.
+    let mut buffer = vec![0u64; (size as usize + 7) / 8];
+    AuthzGetInformationFromContext(
+        context.0,
+        AuthzContextInfoGroupsSids,
+        size,
+        &mut size,
+        buffer.as_mut_ptr().cast(),
+    )
+    .ok()?;
+    let groups = &*(buffer.as_ptr() as *const TOKEN_GROUPS);
+    let entries = std::slice::from_raw_parts(groups.Groups.as_ptr(), groups.GroupCount as _);
+    Some(
+        entries
+            .iter()
+            .filter_map(|entry| {
+                let sid = Sid::from_psid(entry.Sid)?;
+                Some(Group {
+                    name: sid.account_name()?,
+                    id: Gid(0),
+                })
+            })
+            .collect(),
+    )
+}
+
 unsafe fn get_groups_for_user(username: PCWSTR) -> Vec<Group> {
     let mut buf: NetApiBuffer<LOCALGROUP_USERS_INFO_0> = Default::default();
     let mut nb_entries = 0;
@@ -151,9 +357,26 @@ unsafe fn get_groups_for_user(username: PCWSTR) -> Vec<Group> {
     groups
 }
 
-pub(crate) fn get_users(users: &mut Vec<User>) {
+unsafe fn from_lsa_string(s: &LSA_UNICODE_STRING) -> String {
+    if s.Buffer.is_null() {
+        return String::new();
+    }
+    String::from_utf16(std::slice::from_raw_parts(
+        s.Buffer.as_ptr(),
+        s.Length as usize / std::mem::size_of::<u16>(),
+    ))
+    .unwrap_or_else(|_err| {
+        sysinfo_debug!("Failed to convert from UTF-16 string: {}", _err);
+        String::new()
+    })
+}
+
+pub(crate) fn get_users(users: &mut Vec<User>, refresh_kind: UserRefreshKind) {
     users.clear();
 
+    // The NetBIOS name of the computer is the domain of the local accounts.
+    let computer_name = get_computer_name(ComputerNameNetBIOS);
+
     let mut resume_handle: u32 = 0;
     unsafe {
         loop {
@@ -189,16 +412,21 @@ pub(crate) fn get_users(users: &mut Vec<User>) {
                             // Get the account name from the SID (because it's usually
                             // a better name), but fall back to the name we were given
                             // if this fails.
-                            let name = sid
-                                .account_name()
-                                .unwrap_or_else(|| to_str(entry.usri0_name));
+                            let (name, domain) = match sid.lookup_account() {
+                                Some((name, domain)) => (name, Some(domain)),
+                                None => (to_str(entry.usri0_name), None),
+                            };
                             users.push(User {
                                 inner: UserInner::new(
                                     Uid(sid),
                                     name,
+                                    domain,
                                     PCWSTR(entry.usri0_name.0 as *const _),
                                     true,
+                                    computer_name.as_deref(),
+                                    refresh_kind,
                                 ),
+                                groups: None,
                             });
                         }
                     }
@@ -247,22 +475,160 @@ pub(crate) fn get_users(users: &mut Vec<User>) {
                     // Get the account name from the SID (because it's usually
                     // a better name), but fall back to the name we were given
                     // if this fails.
-                    let name = sid.account_name().unwrap_or_else(|| {
-                        String::from_utf16(std::slice::from_raw_parts(
-                            data.UserName.Buffer.as_ptr(),
-                            data.UserName.Length as usize / std::mem::size_of::<u16>(),
-                        ))
-                        .unwrap_or_else(|_err| {
-                            sysinfo_debug!("Failed to convert from UTF-16 string: {}", _err);
-                            String::new()
-                        })
+                    let (name, domain) = sid.lookup_account().unwrap_or_else(|| {
+                        (
+                            from_lsa_string(&data.UserName),
+                            from_lsa_string(&data.LogonDomain),
+                        )
                     });
+                    let domain = Some(domain).filter(|domain| !domain.is_empty());
 
                     users.push(User {
-                        inner: UserInner::new(Uid(sid), name, PCWSTR::null(), false),
+                        inner: UserInner::new(
+                            Uid(sid),
+                            name,
+                            domain,
+                            PCWSTR::null(),
+                            false,
+                            computer_name.as_deref(),
+                            refresh_kind,
+                        ),
+                        groups: None,
                     });
                 }
             }
         }
     }
 }
+
+pub(crate) fn get_sessions(sessions: &mut Vec<Session>) {
+    unsafe {
+        let mut infos: WtsBuffer<WTS_SESSION_INFOW> = Default::default();
+        let mut count = 0;
+        if let Err(_e) = WTSEnumerateSessionsW(
+            WTS_CURRENT_SERVER_HANDLE,
+            0,
+            1,
+            infos.inner_mut(),
+            &mut count,
+        ) {
+            sysinfo_debug!("WTSEnumerateSessionsW failed: {:?}", _e);
+            return;
+        }
+        for entry in std::slice::from_raw_parts(infos.0, count as _) {
+            let mut info: WtsBuffer<WTSINFOW> = Default::default();
+            let mut size = 0;
+            if WTSQuerySessionInformationW(
+                WTS_CURRENT_SERVER_HANDLE,
+                entry.SessionId,
+                WTSSessionInfo,
+                info.inner_mut_as_pwstr(),
+                &mut size,
+            )
+            .is_err()
+                || info.0.is_null()
+            {
+                continue;
+            }
+            let info = &*info.0;
+            let user_name = from_wide(&info.UserName);
+            // Services and the listening stations don't have a user.
+            if user_name.is_empty() {
+                continue;
+            }
+
+            // The client name is empty for local sessions.
+            let mut client: WtsBuffer<u16> = Default::default();
+            let mut size = 0;
+            let host = if WTSQuerySessionInformationW(
+                WTS_CURRENT_SERVER_HANDLE,
+                entry.SessionId,
+                WTSClientName,
+                client.inner_mut_as_pwstr(),
+                &mut size,
+            )
+            .is_ok()
+            {
+                Some(to_str(PWSTR(client.0))).filter(|host| !host.is_empty())
+            } else {
+                None
+            };
+            let terminal = from_wide(&info.WinStationName);
+
+            sessions.push(Session {
+                user_name,
+                // 11_644_473_600 is the number of seconds between the Windows epoch
+                // (1601-01-01) and the UNIX epoch (1970-01-01).
+                login_time: (info.LogonTime as u64 / 10_000_000).saturating_sub(11_644_473_600),
+                terminal: Some(terminal).filter(|terminal| !terminal.is_empty()),
+                host,
+                remote: is_remote_session(entry.SessionId),
+            });
+        }
+    }
+}
+
+/// Returns `true` if the client of the session is connected through RDP (or ICA).
+pub(crate) fn is_remote_session(session_id: u32) -> bool {
+    unsafe {
+        let mut protocol: WtsBuffer<u16> = Default::default();
+        let mut size = 0;
+        if WTSQuerySessionInformationW(
+            WTS_CURRENT_SERVER_HANDLE,
+            session_id,
+            WTSClientProtocolType,
+            protocol.inner_mut_as_pwstr(),
+            &mut size,
+        )
+        .is_err()
+            || protocol.0.is_null()
+        {
+            return false;
+        }
+        // `0` is the console.
+        *protocol.0 != 0
+    }
+}
+
+/// Converts a fixed-size, NUL-terminated, UTF-16 string.
+fn from_wide(s: &[u16]) -> String {
+    let end = s.iter().position(|c| *c == 0).unwrap_or(s.len());
+    String::from_utf16_lossy(&s[..end])
+}
+
+pub(crate) fn resolve_user_name(uid: &Uid) -> Option<String> {
+    uid.0.account_name()
+}
+
+pub(crate) fn resolve_group_name(_: Gid) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_domain_account, Sid};
+
+    #[test]
+    fn check_is_domain_account() {
+        let sid: Sid = "S-1-5-21-1004336348-1177238915-682003330-1105"
+            .parse()
+            .unwrap();
+        assert!(is_domain_account(&sid, Some("CORP"), Some("DESKTOP-42")));
+        // The local accounts belong to the domain named after the computer.
+        assert!(!is_domain_account(
+            &sid,
+            Some("desktop-42"),
+            Some("DESKTOP-42")
+        ));
+        assert!(!is_domain_account(&sid, None, Some("DESKTOP-42")));
+        assert!(!is_domain_account(&sid, Some("CORP"), None));
+
+        // `SYSTEM` isn't part of a domain.
+        let sid: Sid = "S-1-5-18".parse().unwrap();
+        assert!(!is_domain_account(
+            &sid,
+            Some("NT AUTHORITY"),
+            Some("DESKTOP-42")
+        ));
+    }
+}
@@ -1,12 +1,20 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use windows::core::{PCWSTR, PWSTR};
-use windows::Win32::Foundation::{self, FILETIME};
+use windows::core::{GUID, PCWSTR, PWSTR};
+use windows::Win32::Devices::DeviceAndDriverInstallation::{
+    SetupDiDestroyDeviceInfoList, SetupDiEnumDeviceInterfaces, SetupDiGetClassDevsW,
+    SetupDiGetDeviceInterfaceDetailW, DIGCF_DEVICEINTERFACE, DIGCF_PRESENT, HDEVINFO,
+    SP_DEVICE_INTERFACE_DATA, SP_DEVICE_INTERFACE_DETAIL_DATA_W,
+};
+use windows::Win32::Foundation::{self, FILETIME, HWND};
 use windows::Win32::System::Registry::{
-    RegCloseKey, RegOpenKeyExW, RegQueryValueExW, HKEY, KEY_READ, REG_NONE,
+    RegCloseKey, RegOpenKeyExW, RegQueryValueExW, HKEY, KEY_READ, KEY_WOW64_64KEY, REG_NONE,
+    REG_VALUE_TYPE,
 };
+use windows::Win32::System::SystemInformation::{GetComputerNameExW, COMPUTER_NAME_FORMAT};
 
 use std::ffi::OsStr;
+use std::mem::size_of;
 use std::os::windows::ffi::OsStrExt;
 use std::time::SystemTime;
 
@@ -50,7 +58,9 @@ impl RegKey {
             hkey,
             PCWSTR::from_raw(path.as_ptr()),
             0,
-            KEY_READ,
+            // 32-bit processes would otherwise read the `WOW6432Node` keys, which lack some of
+            // the values (like `MachineGuid`).
+            KEY_READ | KEY_WOW64_64KEY,
             &mut new_hkey,
         )
         .is_err()
@@ -65,7 +75,7 @@ impl RegKey {
         field_name: &[u16],
         buf: &mut [u8],
         buf_len: &mut u32,
-    ) -> windows::core::Result<()> {
+    ) -> windows::core::Result<REG_VALUE_TYPE> {
         let mut buf_type = REG_NONE;
 
         RegQueryValueExW(
@@ -76,6 +86,7 @@ impl RegKey {
             Some(buf.as_mut_ptr()),
             Some(buf_len),
         )
+        .map(|_| buf_type)
     }
 }
 
@@ -96,7 +107,7 @@ pub(crate) fn get_reg_string_value(hkey: HKEY, path: &str, field_name: &str) ->
 
         loop {
             match new_key.get_value(&c_field_name, &mut buf, &mut buf_len) {
-                Ok(()) => break,
+                Ok(_) => break,
                 Err(err) if err.code() == Foundation::ERROR_MORE_DATA.to_hresult() => {
                     buf.reserve(buf_len as _);
                 }
@@ -115,6 +126,35 @@ pub(crate) fn get_reg_string_value(hkey: HKEY, path: &str, field_name: &str) ->
     }
 }
 
+/// Returns the raw data of the value and its type.
+pub(crate) fn get_reg_value(
+    hkey: HKEY,
+    path: &str,
+    field_name: &str,
+) -> Option<(REG_VALUE_TYPE, Vec<u8>)> {
+    let c_path = utf16_str(path);
+    let c_field_name = utf16_str(field_name);
+
+    unsafe {
+        let new_key = RegKey::open(hkey, &c_path)?;
+        let mut buf = vec![0u8; 256];
+
+        loop {
+            let mut buf_len = buf.len() as u32;
+            match new_key.get_value(&c_field_name, &mut buf, &mut buf_len) {
+                Ok(kind) => {
+                    buf.truncate(buf_len as _);
+                    return Some((kind, buf));
+                }
+                Err(err) if err.code() == Foundation::ERROR_MORE_DATA.to_hresult() => {
+                    buf.resize(buf_len as _, 0);
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
 pub(crate) fn get_reg_value_u32(hkey: HKEY, path: &str, field_name: &str) -> Option<[u8; 4]> {
     let c_path = utf16_str(path);
     let c_field_name = utf16_str(field_name);
@@ -130,3 +170,107 @@ pub(crate) fn get_reg_value_u32(hkey: HKEY, path: &str, field_name: &str) -> Opt
             .ok()
     }
 }
+
+pub(crate) fn get_reg_value_u64(hkey: HKEY, path: &str, field_name: &str) -> Option<[u8; 8]> {
+    let c_path = utf16_str(path);
+    let c_field_name = utf16_str(field_name);
+
+    unsafe {
+        let new_key = RegKey::open(hkey, &c_path)?;
+        let mut buf_len: u32 = 8;
+        let mut buf = [0u8; 8];
+
+        new_key
+            .get_value(&c_field_name, &mut buf, &mut buf_len)
+            .map(|_| buf)
+            .ok()
+    }
+}
+
+/// Returns the name of the computer in the given `format`, `None` if it is empty.
+pub(crate) fn get_computer_name(format: COMPUTER_NAME_FORMAT) -> Option<String> {
+    unsafe {
+        let mut size = 0;
+        let _err = GetComputerNameExW(format, PWSTR::null(), &mut size);
+        let mut buffer = vec![0u16; size as usize];
+        if let Err(_e) = GetComputerNameExW(format, PWSTR::from_raw(buffer.as_mut_ptr()), &mut size)
+        {
+            sysinfo_debug!("GetComputerNameExW failed: {:?}", _e);
+            return None;
+        }
+        Some(String::from_utf16_lossy(&buffer[..size as usize])).filter(|name| !name.is_empty())
+    }
+}
+
+pub(crate) struct DeviceInfoList(pub(crate) HDEVINFO);
+
+impl Drop for DeviceInfoList {
+    fn drop(&mut self) {
+        let _err = unsafe { SetupDiDestroyDeviceInfoList(self.0) };
+    }
+}
+
+/// Returns the zero-terminated paths of the present devices exposing the `class` interface,
+/// which can be opened with `CreateFileW`.
+pub(crate) unsafe fn get_device_interface_paths(class: &GUID) -> Vec<Vec<u16>> {
+    let devices = match SetupDiGetClassDevsW(
+        Some(class),
+        PCWSTR::null(),
+        HWND::default(),
+        DIGCF_PRESENT | DIGCF_DEVICEINTERFACE,
+    ) {
+        Ok(devices) => DeviceInfoList(devices),
+        Err(_e) => {
+            sysinfo_debug!("SetupDiGetClassDevsW failed: {:?}", _e);
+            return Vec::new();
+        }
+    };
+
+    let mut paths = Vec::new();
+    let mut index = 0;
+    loop {
+        let mut interface = SP_DEVICE_INTERFACE_DATA {
+            cbSize: size_of::<SP_DEVICE_INTERFACE_DATA>() as _,
+            ..Default::default()
+        };
+        if SetupDiEnumDeviceInterfaces(devices.0, None, class, index, &mut interface).is_err() {
+            break;
+        }
+        index += 1;
+        if let Some(path) = get_device_path(&devices, &interface) {
+            paths.push(path);
+        }
+    }
+    paths
+}
+
+unsafe fn get_device_path(
+    devices: &DeviceInfoList,
+    interface: &SP_DEVICE_INTERFACE_DATA,
+) -> Option<Vec<u16>> {
+    let mut needed_size = 0;
+    let _err = SetupDiGetDeviceInterfaceDetailW(
+        devices.0,
+        interface,
+        None,
+        0,
+        Some(&mut needed_size),
+        None,
+    );
+    if (needed_size as usize) < size_of::<SP_DEVICE_INTERFACE_DETAIL_DATA_W>() {
+        return None;
+    }
+    // `u32` is used to get the alignment of `SP_DEVICE_INTERFACE_DETAIL_DATA_W`.
+    let mut buf = vec![0u32; (needed_size as usize + 3) / 4];
+    let detail = buf.as_mut_ptr() as *mut SP_DEVICE_INTERFACE_DETAIL_DATA_W;
+    (*detail).cbSize = size_of::<SP_DEVICE_INTERFACE_DETAIL_DATA_W>() as _;
+    SetupDiGetDeviceInterfaceDetailW(devices.0, interface, Some(detail), needed_size, None, None)
+        .ok()?;
+    // `DevicePath` is a zero-terminated string following `cbSize`.
+    let path = std::slice::from_raw_parts(
+        (buf.as_ptr() as *const u8).add(size_of::<u32>()) as *const u16,
+        (needed_size as usize - size_of::<u32>()) / 2,
+    );
+    let end = path.iter().position(|c| *c == 0)?;
+    Some(path[..=end].to_vec())
+}
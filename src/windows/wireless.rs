@@ -0,0 +1,113 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{MacAddr, WirelessInfo};
+
+use std::ffi::c_void;
+use std::ptr::null_mut;
+
+use windows::core::GUID;
+use windows::Win32::Foundation::{ERROR_SUCCESS, HANDLE};
+use windows::Win32::NetworkManagement::WiFi::{
+    wlan_interface_state_connected, wlan_intf_opcode_channel_number,
+    wlan_intf_opcode_current_connection, WlanCloseHandle, WlanEnumInterfaces, WlanFreeMemory,
+    WlanOpenHandle, WlanQueryInterface, WLAN_CONNECTION_ATTRIBUTES, WLAN_INTERFACE_INFO_LIST,
+    WLAN_INTF_OPCODE,
+};
+
+// Version of the WLAN API introduced with Windows Vista.
+const WLAN_API_VERSION: u32 = 2;
+
+struct WlanHandle(HANDLE);
+
+impl WlanHandle {
+    fn new() -> Option<Self> {
+        let mut version = 0;
+        let mut handle = HANDLE::default();
+        unsafe {
+            if WlanOpenHandle(WLAN_API_VERSION, None, &mut version, &mut handle) != ERROR_SUCCESS.0
+            {
+                // The WLAN service is not running, for example if there is no wireless adapter.
+                sysinfo_debug!("WlanOpenHandle failed");
+                return None;
+            }
+        }
+        Some(Self(handle))
+    }
+
+    /// Calls `WlanQueryInterface` and returns a copy of the returned data.
+    unsafe fn query<T: Copy>(&self, interface: &GUID, opcode: WLAN_INTF_OPCODE) -> Option<T> {
+        let mut size = 0;
+        let mut data: *mut c_void = null_mut();
+        if WlanQueryInterface(self.0, interface, opcode, None, &mut size, &mut data, None)
+            != ERROR_SUCCESS.0
+            || data.is_null()
+        {
+            return None;
+        }
+        let value = if size as usize >= std::mem::size_of::<T>() {
+            Some(std::ptr::read_unaligned(data as *const T))
+        } else {
+            None
+        };
+        WlanFreeMemory(data);
+        value
+    }
+}
+
+impl Drop for WlanHandle {
+    fn drop(&mut self) {
+        unsafe {
+            WlanCloseHandle(self.0, None);
+        }
+    }
+}
+
+/// Returns the details of the Wi-Fi connections, associated to the GUID of their interface.
+pub(crate) fn get_wireless_infos() -> Vec<(GUID, WirelessInfo)> {
+    let handle = match WlanHandle::new() {
+        Some(handle) => handle,
+        None => return Vec::new(),
+    };
+    let mut infos = Vec::new();
+    unsafe {
+        let mut list: *mut WLAN_INTERFACE_INFO_LIST = null_mut();
+        if WlanEnumInterfaces(handle.0, None, &mut list) != ERROR_SUCCESS.0 || list.is_null() {
+            return infos;
+        }
+        let interfaces = std::slice::from_raw_parts(
+            (*list).InterfaceInfo.as_ptr(),
+            (*list).dwNumberOfItems as usize,
+        );
+        for interface in interfaces {
+            if interface.isState != wlan_interface_state_connected {
+                continue;
+            }
+            let connection: WLAN_CONNECTION_ATTRIBUTES = match handle.query(
+                &interface.InterfaceGuid,
+                wlan_intf_opcode_current_connection,
+            ) {
+                Some(connection) => connection,
+                None => continue,
+            };
+            let association = &connection.wlanAssociationAttributes;
+            let ssid = &association.dot11Ssid;
+            let ssid_len = (ssid.uSSIDLength as usize).min(ssid.ucSSID.len());
+            let channel: Option<u32> =
+                handle.query(&interface.InterfaceGuid, wlan_intf_opcode_channel_number);
+            infos.push((
+                interface.InterfaceGuid,
+                WirelessInfo {
+                    ssid: Some(String::from_utf8_lossy(&ssid.ucSSID[..ssid_len]).into_owned()),
+                    bssid: Some(MacAddr(association.dot11Bssid)),
+                    // The quality goes from `0` (-100 dBm) to `100` (-50 dBm).
+                    signal: Some(association.wlanSignalQuality.min(100) as i32 / 2 - 100),
+                    channel,
+                    // The rate is in kbit/s.
+                    bitrate: Some(u64::from(association.ulTxRate) * 1_000),
+                },
+            ));
+        }
+        WlanFreeMemory(list as *const c_void);
+    }
+    infos
+}
@@ -47,3 +47,152 @@ fn test_global_cpu_info_not_set() {
     assert_eq!(s.global_cpu_info().brand(), "");
     assert_eq!(s.global_cpu_info().frequency(), 0);
 }
+
+#[test]
+fn test_cpu_temperature_not_refreshed() {
+    let mut s = sysinfo::System::new();
+    s.refresh_cpu_specifics(sysinfo::CpuRefreshKind::new().with_cpu_usage());
+    assert!(s.cpus().iter().all(|cpu| cpu.temperature().is_none()));
+}
+
+#[test]
+fn test_cpu_features() {
+    if sysinfo::IS_SUPPORTED && cfg!(target_arch = "x86_64") {
+        let s = sysinfo::System::new();
+        // SSE2 is part of the x86_64 baseline.
+        assert!(s.cpu_features().iter().any(|feature| feature == "sse2"));
+    }
+}
+
+#[test]
+fn test_cpu_times() {
+    use std::time::Duration;
+
+    if sysinfo::IS_SUPPORTED {
+        let mut s = sysinfo::System::new();
+        s.refresh_cpu_usage();
+        for cpu in s.cpus() {
+            assert!(cpu.times().total() > Duration::ZERO);
+        }
+        assert!(s.global_cpu_info().times().total() > Duration::ZERO);
+    }
+}
+
+#[test]
+fn test_cpu_steal_percent() {
+    if sysinfo::IS_SUPPORTED {
+        let mut s = sysinfo::System::new();
+        s.refresh_cpu_usage();
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        s.refresh_cpu_usage();
+        for cpu in s.cpus() {
+            assert!((0. ..=100.).contains(&cpu.steal_percent()));
+        }
+    }
+}
+
+#[test]
+fn test_cpu_scaling_frequencies() {
+    if sysinfo::IS_SUPPORTED {
+        let mut s = sysinfo::System::new();
+        s.refresh_cpu_frequency();
+        for cpu in s.cpus() {
+            if let (Some(min), Some(max)) =
+                (cpu.scaling_min_frequency(), cpu.scaling_max_frequency())
+            {
+                assert!(min <= max);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_cpu_usage_smoothing() {
+    if sysinfo::IS_SUPPORTED {
+        let mut s = sysinfo::System::new();
+        s.set_cpu_usage_smoothing(5);
+        for _ in 0..3 {
+            s.refresh_cpu_usage();
+            std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        }
+        assert!(!s.cpus().is_empty());
+        for cpu in s.cpus() {
+            assert!((0. ..=100.).contains(&cpu.cpu_usage()));
+        }
+        assert!((0. ..=100.).contains(&s.global_cpu_info().cpu_usage()));
+    }
+}
+
+#[test]
+fn test_cpu_activity() {
+    if sysinfo::IS_SUPPORTED && cfg!(any(target_os = "linux", windows, target_os = "freebsd")) {
+        let mut s = sysinfo::System::new();
+        s.refresh_cpu_usage();
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        s.refresh_cpu_usage();
+        let activity = s.cpu_activity();
+        assert!(activity.interrupts > 0);
+        assert!(activity.context_switches > 0);
+        assert!(activity.context_switches_per_second > 0.);
+    }
+}
+
+#[test]
+fn test_cpu_identification() {
+    if sysinfo::IS_SUPPORTED
+        && cfg!(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            any(target_os = "linux", windows, target_os = "freebsd")
+        ))
+    {
+        let s = sysinfo::System::new_with_specifics(
+            sysinfo::RefreshKind::new().with_cpu(sysinfo::CpuRefreshKind::new()),
+        );
+        assert!(s.cpus().iter().all(|cpu| cpu.family().is_some()));
+        assert!(s.cpus().iter().all(|cpu| cpu.model().is_some()));
+        assert!(s.cpus().iter().all(|cpu| cpu.stepping().is_some()));
+    }
+}
+
+#[cfg(feature = "energy")]
+#[test]
+fn test_energy_domains() {
+    let mut s = sysinfo::System::new();
+    s.refresh_energy();
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    s.refresh_energy();
+    for domain in s.energy_domains() {
+        assert!(!domain.name.is_empty());
+        assert!(domain.energy >= 0.);
+        assert!(domain.power >= 0.);
+    }
+}
+
+#[test]
+fn test_cpu_idle_states() {
+    if sysinfo::IS_SUPPORTED {
+        let mut s = sysinfo::System::new();
+        s.refresh_cpu_idle_states();
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        s.refresh_cpu_idle_states();
+        for cpu in s.cpus() {
+            for state in cpu.idle_states() {
+                assert!(!state.name.is_empty());
+                assert!((0. ..=100.).contains(&state.residency));
+            }
+        }
+        assert!(s.global_cpu_info().idle_states().is_empty());
+    }
+}
+
+#[test]
+fn test_global_cpu_usage() {
+    if sysinfo::IS_SUPPORTED {
+        let mut s = sysinfo::System::new();
+        s.refresh_global_cpu_usage();
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        s.refresh_global_cpu_usage();
+        assert!(s.cpus().is_empty());
+        assert!((0. ..=100.).contains(&s.global_cpu_info().cpu_usage()));
+    }
+}
@@ -13,3 +13,54 @@ fn test_disks() {
         }
     }
 }
+
+#[test]
+fn test_physical_disks() {
+    let mut disks = sysinfo::Disks::new();
+    assert!(disks.physical_disks().is_empty());
+    disks.refresh_list();
+    // Every disk backed by a physical disk must refer to one of the listed physical disks.
+    for disk in disks.list() {
+        if let Some(name) = disk.physical_disk() {
+            assert!(disks.physical_disks().iter().any(|d| d.name == name));
+        }
+    }
+}
+
+#[test]
+fn test_sector_sizes() {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    for disk in disks.list() {
+        if let (Some(logical), Some(physical)) =
+            (disk.logical_sector_size(), disk.physical_sector_size())
+        {
+            assert!(logical.is_power_of_two());
+            assert!(logical <= physical);
+        }
+    }
+}
+
+#[test]
+fn test_network_disks() {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    assert!(disks.list().iter().all(|disk| !disk.is_network()));
+
+    let disks = sysinfo::Disks::new_with_refreshed_list_specifics(
+        sysinfo::DiskRefreshKind::new().with_network_disks(),
+    );
+    for disk in disks.list().iter().filter(|disk| disk.is_network()) {
+        // The space of network disks shouldn't have been retrieved.
+        assert_eq!(disk.total_space(), 0);
+        assert_eq!(disk.physical_disk(), None);
+    }
+}
+
+#[test]
+fn test_disk_events_timeout() {
+    if let Some(mut events) = sysinfo::DiskEvents::new() {
+        let start = std::time::Instant::now();
+        // Disks may actually be added or removed in the meantime, so we can't check the events.
+        let _events = events.wait(Some(std::time::Duration::from_millis(50)));
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    }
+}
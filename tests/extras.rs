@@ -1,3 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+#![allow(unknown_lints)]
+#![allow(clippy::useless_borrows_in_formatting)]
+
 mod code_checkers;
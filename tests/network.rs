@@ -15,3 +15,126 @@ fn test_networks() {
         assert!(n.iter().count() > 0);
     }
 }
+
+#[test]
+fn test_loopback_ip_networks() {
+    use std::net::{IpAddr, Ipv4Addr};
+    use sysinfo::{IpNetwork, Networks};
+
+    if !sysinfo::IS_SUPPORTED || cfg!(not(target_os = "linux")) {
+        return;
+    }
+    let networks = Networks::new_with_refreshed_list();
+    if let Some((_, lo)) = networks.iter().find(|(name, _)| name.as_str() == "lo") {
+        assert!(lo.ip_networks().contains(&IpNetwork {
+            addr: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            prefix: 8,
+        }));
+    }
+}
+
+#[test]
+fn test_connections_listener() {
+    use std::net::TcpListener;
+    use sysinfo::{ConnectionProtocol, Connections, TcpState};
+
+    if !sysinfo::IS_SUPPORTED || cfg!(not(any(target_os = "linux", windows))) {
+        return;
+    }
+    let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind listener");
+    let address = listener.local_addr().expect("failed to get local address");
+    let connections = Connections::new_with_refreshed_list();
+    let connection = connections
+        .iter()
+        .find(|connection| {
+            connection.protocol == ConnectionProtocol::Tcp && connection.local_address == address
+        })
+        .expect("listener not found");
+    assert_eq!(connection.state, Some(TcpState::Listen));
+    assert_eq!(connection.remote_address, None);
+    assert_eq!(
+        connection.pid,
+        Some(sysinfo::get_current_pid().expect("failed to get current PID"))
+    );
+}
+
+#[test]
+fn test_connections_traffic() {
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use sysinfo::Connections;
+
+    if !sysinfo::IS_SUPPORTED || cfg!(not(target_os = "linux")) {
+        return;
+    }
+    let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind listener");
+    let mut client = TcpStream::connect(listener.local_addr().unwrap()).expect("failed to connect");
+    let (mut server, _) = listener.accept().expect("failed to accept connection");
+    client.write_all(&[0; 1000]).expect("failed to write");
+    server.read_exact(&mut [0; 1000]).expect("failed to read");
+
+    let address = client.local_addr().unwrap();
+    let connections = Connections::new_with_refreshed_list();
+    let traffic = connections
+        .iter()
+        .find(|connection| connection.local_address == address)
+        .and_then(|connection| connection.traffic)
+        .expect("no traffic for the client connection");
+    assert_eq!(traffic.bytes_sent, 1000);
+}
+
+#[test]
+fn test_network_events_timeout() {
+    if let Some(mut events) = sysinfo::NetworkEvents::new() {
+        let start = std::time::Instant::now();
+        // Interfaces may actually change in the meantime, so we can't check the events.
+        let _events = events.wait(Some(std::time::Duration::from_millis(50)));
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    }
+}
+
+#[test]
+fn test_networks_refresh_interfaces() {
+    use sysinfo::Networks;
+
+    if !sysinfo::IS_SUPPORTED {
+        return;
+    }
+    let mut networks = Networks::new_with_refreshed_list();
+    let count = networks.iter().count();
+    let name = match networks.keys().next() {
+        Some(name) => name.clone(),
+        None => return,
+    };
+    networks.refresh_interfaces(&[&name, "sysinfo-missing0"]);
+    // Unknown interfaces are ignored and not added.
+    assert_eq!(networks.iter().count(), count);
+    assert!(networks.get("sysinfo-missing0").is_none());
+}
+
+#[test]
+fn test_neighbors() {
+    use sysinfo::Neighbors;
+
+    if !sysinfo::IS_SUPPORTED {
+        return;
+    }
+    for neighbor in Neighbors::new_with_refreshed_list().list() {
+        assert!(!neighbor.interface.is_empty());
+        assert!(!neighbor.ip_address.is_unspecified());
+    }
+}
+
+#[test]
+fn test_connections_summary() {
+    use std::net::TcpListener;
+    use sysinfo::Connections;
+
+    if !sysinfo::IS_SUPPORTED || cfg!(not(any(target_os = "linux", windows))) {
+        return;
+    }
+    let _listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind listener");
+    let summary = Connections::summary();
+    assert!(summary.tcp_listening >= 1);
+    assert!(summary.tcp_total >= summary.tcp_established + summary.tcp_listening);
+}
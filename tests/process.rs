@@ -1,5 +1,8 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+#![allow(unknown_lints)]
+#![allow(clippy::zombie_processes, clippy::manual_contains)]
+
 use sysinfo::{Pid, System};
 
 #[test]
@@ -320,6 +323,22 @@ fn test_process_times() {
     }
 }
 
+// Checks that accumulated CPU times are increasing for a busy process.
+#[test]
+fn test_process_accumulated_cpu_time() {
+    if !sysinfo::IS_SUPPORTED || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    // Burn some CPU so the values can't be zero.
+    let start = std::time::Instant::now();
+    while start.elapsed() < std::time::Duration::from_millis(100) {}
+    s.refresh_process_specifics(pid, sysinfo::ProcessRefreshKind::new().with_cpu());
+    let process = s.process(pid).expect("current process not found");
+    assert!(process.user_time() + process.kernel_time() > std::time::Duration::ZERO);
+}
+
 // Checks that `session_id` is working.
 #[test]
 fn test_process_session_id() {
@@ -651,3 +670,40 @@ fn test_process_memory_refresh() {
     assert!(proc.memory() > 0);
     assert!(proc.virtual_memory() > 0);
 }
+
+#[test]
+fn test_process_child() {
+    if !sysinfo::IS_SUPPORTED || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut child = if cfg!(target_os = "windows") {
+        std::process::Command::new("waitfor")
+            .arg("/t")
+            .arg("3")
+            .arg("ChildSignal")
+            .stdout(std::process::Stdio::null())
+            .spawn()
+            .unwrap()
+    } else {
+        std::process::Command::new("sleep")
+            .arg("3")
+            .stdout(std::process::Stdio::null())
+            .spawn()
+            .unwrap()
+    };
+    let pid = Pid::from_u32(child.id());
+    let current_pid = Some(Pid::from_u32(std::process::id()));
+
+    let mut s = System::new();
+    let process = s.refresh_child(&child).expect("child not found");
+    assert_eq!(process.pid(), pid);
+    assert_eq!(process.parent(), current_pid);
+    assert!(s.process(pid).is_some());
+    let process = sysinfo::Process::from_child(&child).expect("child not found");
+    assert_eq!(process.pid(), pid);
+    assert_eq!(process.parent(), current_pid);
+
+    child.kill().expect("Unable to kill process.");
+    child.wait().expect("Unable to wait for process.");
+    assert!(s.refresh_child(&child).is_none());
+}